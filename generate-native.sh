@@ -52,6 +52,168 @@ phosphorus \
     > generated/gl46.rs
 
 cp generated/gl46.rs src/gl46.rs
+
+# Generate the table of promoted-extension aliases used by the native loader to fall back to
+# suffixed entry points when a driver doesn't export the core name.
+python3 - generated/gl.xml src/gl46.rs > src/gl46_aliases.rs <<'PYTHON'
+import re, sys
+import xml.etree.ElementTree as ET
+
+suffixes = ["ARB", "EXT", "OES", "KHR", "APPLE", "ANGLE", "NV"]
+registry = ET.parse(sys.argv[1]).getroot()
+loaded = set(re.findall(r"^\s+(gl\w+)_p: APcv", open(sys.argv[2]).read(), re.M))
+
+# Commands that `gl.xml` declares as aliases but which take different enums than the core command.
+not_aliases = {"glProgramParameteriARB", "glProgramParameteriEXT"}
+# `NV_vertex_program` attributes alias the fixed-function ones, unlike the core attributes.
+nv_vertex_program = re.compile(r"gl(Get)?VertexAttrib(\d|[dfi]v|Pointerv)\w*NV")
+# The GLES query extensions and the macOS legacy vertex array extension are equivalent to the core
+# commands, but `gl.xml` doesn't declare them as aliases.
+extra_aliases = {
+    "glBeginQuery": ["glBeginQueryEXT"],
+    "glBindVertexArray": ["glBindVertexArrayAPPLE"],
+    "glDeleteQueries": ["glDeleteQueriesEXT"],
+    "glDeleteVertexArrays": ["glDeleteVertexArraysAPPLE"],
+    "glEndQuery": ["glEndQueryEXT"],
+    "glGenQueries": ["glGenQueriesEXT"],
+    "glGenVertexArrays": ["glGenVertexArraysAPPLE"],
+    "glGetQueryObjectuiv": ["glGetQueryObjectuivEXT"],
+    "glIsQuery": ["glIsQueryEXT"],
+    "glIsVertexArray": ["glIsVertexArrayAPPLE"],
+}
+aliases = {core: set(names) for core, names in extra_aliases.items()}
+for command in registry.find("commands"):
+    name, alias = command.find("proto/name").text, command.find("alias")
+    if alias is not None and name not in not_aliases and not nv_vertex_program.fullmatch(name):
+        aliases.setdefault(alias.get("name"), set()).add(name)
+
+print("//! Promoted-extension aliases for the core commands loaded by `gl46`.")
+print("//!")
+print("//! Generated from `gl.xml` by `generate-native.sh`, do not edit by hand.")
+print("")
+print("/// Core command names, sorted, together with their suffixed aliases in the order they are tried.")
+print("pub(crate) static ALIASES: &[(&[u8], &[&[u8]])] = &[")
+for core in sorted(aliases):
+    if core not in loaded or any(core.endswith(suffix) for suffix in suffixes):
+        continue
+    names = [core + suffix for suffix in suffixes if core + suffix in aliases[core]]
+    if names:
+        names = ", ".join('b"%s\\0"' % name for name in names)
+        print('    (b"%s", &[%s]),' % (core, names))
+print("];")
+PYTHON
+
+# Generate the `Extension` enum from the extensions in the registry, plus the WebGL extensions.
+python3 - generated/gl.xml > src/extension_names.rs <<'PYTHON'
+import sys
+import xml.etree.ElementTree as ET
+
+# Extensions from the WebGL registry, whose names don't have a `GL_` prefix. Most of these are also
+# OpenGL ES extensions, in which case they share the same variant.
+webgl_extensions = [
+    "ANGLE_instanced_arrays",
+    "EXT_blend_func_extended",
+    "EXT_blend_minmax",
+    "EXT_clip_control",
+    "EXT_color_buffer_float",
+    "EXT_color_buffer_half_float",
+    "EXT_conservative_depth",
+    "EXT_depth_clamp",
+    "EXT_disjoint_timer_query",
+    "EXT_disjoint_timer_query_webgl2",
+    "EXT_float_blend",
+    "EXT_frag_depth",
+    "EXT_polygon_offset_clamp",
+    "EXT_render_snorm",
+    "EXT_shader_texture_lod",
+    "EXT_sRGB",
+    "EXT_texture_compression_bptc",
+    "EXT_texture_compression_rgtc",
+    "EXT_texture_filter_anisotropic",
+    "EXT_texture_mirror_clamp_to_edge",
+    "EXT_texture_norm16",
+    "KHR_parallel_shader_compile",
+    "NV_shader_noperspective_interpolation",
+    "OES_draw_buffers_indexed",
+    "OES_element_index_uint",
+    "OES_fbo_render_mipmap",
+    "OES_sample_variables",
+    "OES_shader_multisample_interpolation",
+    "OES_standard_derivatives",
+    "OES_texture_float",
+    "OES_texture_float_linear",
+    "OES_texture_half_float",
+    "OES_texture_half_float_linear",
+    "OES_vertex_array_object",
+    "OVR_multiview2",
+    "WEBGL_blend_equation_advanced_coherent",
+    "WEBGL_clip_cull_distance",
+    "WEBGL_color_buffer_float",
+    "WEBGL_compressed_texture_astc",
+    "WEBGL_compressed_texture_etc",
+    "WEBGL_compressed_texture_etc1",
+    "WEBGL_compressed_texture_pvrtc",
+    "WEBGL_compressed_texture_s3tc",
+    "WEBGL_compressed_texture_s3tc_srgb",
+    "WEBGL_debug_renderer_info",
+    "WEBGL_debug_shaders",
+    "WEBGL_depth_texture",
+    "WEBGL_draw_buffers",
+    "WEBGL_draw_instanced_base_vertex_base_instance",
+    "WEBGL_lose_context",
+    "WEBGL_multi_draw",
+    "WEBGL_multi_draw_instanced_base_vertex_base_instance",
+    "WEBGL_polygon_mode",
+    "WEBGL_provoking_vertex",
+    "WEBGL_render_shared_exponent",
+    "WEBGL_stencil_texturing",
+]
+
+registry = ET.parse(sys.argv[1]).getroot()
+names = {extension.get("name") for extension in registry.find("extensions")}
+names |= {name for name in webgl_extensions if "GL_" + name not in names}
+names = sorted(names)
+
+
+def variant(name):
+    parts = name[3:].split("_") if name.startswith("GL_") else name.split("_")
+    variant = "".join(part[:1].upper() + part[1:].lower() for part in parts)
+    return "Gl" + variant if variant[0].isdigit() else variant
+
+
+variants = [variant(name) for name in names]
+assert len(set(variants)) == len(variants)
+
+print("//! Names of the extensions in the OpenGL, OpenGL ES and WebGL registries.")
+print("//!")
+print("//! Generated from `gl.xml` by `generate-native.sh`, do not edit by hand.")
+print("")
+print("/// An extension from the OpenGL, OpenGL ES or WebGL registry.")
+print("///")
+print("/// WebGL extensions that are also defined for OpenGL ES use the same variant, e.g.")
+print("/// `OES_texture_float` is reported as [`Extension::OesTextureFloat`].")
+print("#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]")
+print("#[repr(u16)]")
+print("pub enum Extension {")
+for name, v in zip(names, variants):
+    print("    /// `%s`" % name)
+    print("    %s," % v)
+print("}")
+print("")
+print("impl Extension {")
+print("    /// All known extensions, sorted by name.")
+print("    pub const ALL: [Extension; %d] = [" % len(names))
+for v in variants:
+    print("        Extension::%s," % v)
+print("    ];")
+print("}")
+print("")
+print("/// The name of each extension in [`Extension::ALL`], in the same order.")
+print("pub(crate) static NAMES: [&str; %d] = [" % len(names))
+for name in names:
+    print('    "%s",' % name)
+print("];")
+PYTHON
 cargo fmt
 # add allow(unused)
 sed -i '1 i\#!\[allow(unused)]' src/gl46.rs
@@ -73,4 +235,146 @@ remove_between '#\[cfg(feature = "global_loader")]' '#\[cfg(feature = "global_lo
 remove_between '#\[cfg(feature = "global_loader")]' '#\[cfg(feature = "struct_loader")]'
 # remove all occurances of #[cfg(feature = "struct_loader")]
 sed -i '/#\[cfg(feature = "struct_loader")]/d' src/gl46.rs
+# Load function pointers on first use when the struct was created with `load_lazily_with`.
+python3 - <<'PYTHON'
+import re, sys
+
+path = "src/gl46.rs"
+source = open(path).read()
+
+# Route every call and `_is_loaded` check through `GlFns::lazy`.
+source, calls = re.subn(
+    r'(call_atomic_ptr_\d+arg\(\s*"(gl\w+)",\s*)&self\.gl\w+_p\b',
+    r'\1self.lazy(&self.\2_p, b"\2\\0")',
+    source,
+)
+source, checks = re.subn(
+    r"!self\s*\.(gl\w+)_p\s*\.load\(RELAX\)\s*\.is_null\(\)",
+    r'!self.lazy(&self.\1_p, b"\1\\0").load(RELAX).is_null()',
+    source,
+)
+assert calls == checks, (calls, checks)
+
+source = source.replace(
+    """            out.load_all_with_dyn(&mut get_proc_address);
+            out
+        }
+""",
+    """            out.load_all_with_dyn(&mut get_proc_address);
+            out
+        }
+
+        /// Constructs a new struct which loads each pointer with the `get_proc_address` given the
+        /// first time that it's used, instead of loading all of them up front.
+        ///
+        /// Lookups that fail are remembered, so missing functions are only looked up once.
+        pub unsafe fn load_lazily_with<F>(get_proc_address: F) -> Self
+        where
+            F: Fn(*const c_char) -> *mut c_void + Send + Sync + 'static,
+        {
+            // Safety: see `load_with`.
+            let out: Self = core::mem::zeroed();
+            let loader: Box<LazyLoader> = Box::new(Box::new(get_proc_address));
+            out.lazy_loader_p
+                .store(Box::into_raw(loader) as *mut c_void, RELAX);
+            out
+        }
+
+        /// Returns `ptr`, loading it first if this struct loads lazily and it isn't loaded yet.
+        ///
+        /// Returns a null pointer if the function is missing.
+        #[inline(always)]
+        fn lazy<'a>(&self, ptr: &'a APcv, fn_name: &[u8]) -> &'a APcv {
+            let p = ptr.load(RELAX);
+            if p as usize == LAZY_MISSING || (p.is_null() && !self.load_lazily(ptr, fn_name)) {
+                return &LAZY_NOT_LOADED;
+            }
+            ptr
+        }
+
+        /// Loads `ptr` and returns whether it was found, storing `LAZY_MISSING` if it wasn't.
+        #[cold]
+        #[inline(never)]
+        fn load_lazily(&self, ptr: &APcv, fn_name: &[u8]) -> bool {
+            let loader = self.lazy_loader_p.load(RELAX) as *const LazyLoader;
+            if loader.is_null() {
+                return false;
+            }
+            // Safety: the loader is owned by `self` and only freed when it's dropped.
+            let loader = unsafe { &*loader };
+            let loaded = load_dyn_name_atomic_ptr(&mut |name| loader(name), fn_name, ptr);
+            if !loaded {
+                ptr.store(LAZY_MISSING as *mut c_void, RELAX);
+            }
+            loaded
+        }
+""",
+)
+
+source = source.replace(
+    """    #[repr(C)]
+    pub struct GlFns {
+""",
+    """    #[repr(C)]
+    pub struct GlFns {
+        /// A `Box<LazyLoader>` if the pointers are loaded on first use, otherwise null.
+        lazy_loader_p: APcv,
+""",
+)
+
+source = source.replace(
+    """    impl core::fmt::Debug for GlFns {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            write!(f, "GlFns")
+        }
+    }
+""",
+    """    impl core::fmt::Debug for GlFns {
+        fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+            write!(f, "GlFns")
+        }
+    }
+    type LazyLoader = Box<dyn Fn(*const c_char) -> *mut c_void + Send + Sync>;
+    /// Stored by the lazy loader in place of a function pointer that couldn't be loaded.
+    const LAZY_MISSING: usize = 1;
+    static LAZY_NOT_LOADED: APcv = ap_null();
+    impl Drop for GlFns {
+        fn drop(&mut self) {
+            let loader = self.lazy_loader_p.load(RELAX) as *mut LazyLoader;
+            if !loader.is_null() {
+                // Safety: this was created by `Box::into_raw` in `load_lazily_with`.
+                drop(unsafe { Box::from_raw(loader) });
+            }
+        }
+    }
+""",
+)
+
+# Only the `println!` fallbacks for tracing need `std`, the C types come from `core` so the
+# crate can be built without `std`.
+source = source.replace(
+    """#[cfg(any(
+    all(
+        not(feature = "log"),
+        any(feature = "debug_trace_calls", feature = "debug_automatic_glGetError")
+    ),
+    not(feature = "chlorine"),
+))]
+extern crate std;
+
+use std::os::raw::*;
+""",
+    """#[cfg(all(
+    not(feature = "log"),
+    any(feature = "debug_trace_calls", feature = "debug_automatic_glGetError")
+))]
+extern crate std;
+
+use alloc::boxed::Box;
+use core::ffi::*;
+""",
+)
+
+open(path, "w").write(source)
+PYTHON
 cargo fmt
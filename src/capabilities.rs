@@ -0,0 +1,313 @@
+use crate::*;
+
+/// Optional functionality supported by a context, derived from its [`Version`] and
+/// [`Extensions`].
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Features {
+    /// `KHR_debug` (object labels, debug groups and message callbacks).
+    pub debug: bool,
+    /// Compute shaders and `dispatch_compute`.
+    pub compute: bool,
+    /// Immutable buffer storage through `buffer_storage`.
+    pub buffer_storage: bool,
+    /// Direct state access, e.g. `create_named_texture` and `named_buffer_data_u8_slice`.
+    pub dsa: bool,
+    /// Uniform buffer objects.
+    pub uniform_buffers: bool,
+    /// Shader storage buffer objects.
+    pub shader_storage_buffers: bool,
+    /// Instanced drawing and `vertex_attrib_divisor`.
+    pub instancing: bool,
+    /// Vertex array objects.
+    pub vertex_arrays: bool,
+    /// Separate vertex attribute formats and bindings, e.g. `vertex_attrib_format_f32`.
+    pub vertex_attrib_binding: bool,
+    /// Immutable texture storage through `tex_storage_*`.
+    pub texture_storage: bool,
+    /// 3D textures.
+    pub texture_3d: bool,
+    /// 2D array textures.
+    pub texture_arrays: bool,
+    /// Sampler objects.
+    pub sampler_objects: bool,
+    /// Framebuffer and renderbuffer objects.
+    pub framebuffer_objects: bool,
+    /// Multiple render targets through `draw_buffers`.
+    pub draw_buffers: bool,
+    /// Multisampled renderbuffers and `blit_framebuffer`.
+    pub multisampling: bool,
+    /// Anisotropic texture filtering.
+    pub anisotropic_filtering: bool,
+    /// Fence sync objects.
+    pub sync: bool,
+    /// `TIME_ELAPSED` and `TIMESTAMP` queries.
+    pub timer_queries: bool,
+    /// Transform feedback.
+    pub transform_feedback: bool,
+    /// Geometry shaders.
+    pub geometry_shaders: bool,
+    /// Tessellation control and evaluation shaders.
+    pub tessellation_shaders: bool,
+    /// `copy_buffer_sub_data`.
+    pub copy_buffer: bool,
+    /// Reading back into `PIXEL_PACK_BUFFER`s.
+    pub pixel_buffers: bool,
+    /// `map_buffer_range`.
+    pub map_buffer_range: bool,
+    /// `get_program_binary` and `program_binary`.
+    pub program_binary: bool,
+    /// `get_program_resource_*` queries.
+    pub program_interface_query: bool,
+    /// `KHR_parallel_shader_compile`.
+    pub parallel_shader_compile: bool,
+}
+
+impl Features {
+    pub(crate) fn new(version: &Version, extensions: &Extensions, is_web: bool) -> Self {
+        let gl =
+            |major, minor| !version.is_embedded && (version.major, version.minor) >= (major, minor);
+        let es =
+            |major, minor| version.is_embedded && (version.major, version.minor) >= (major, minor);
+        let ext = |extension| extensions.has(extension);
+        // WebGL reports its version as the OpenGL ES version it's based on, but only exposes a
+        // subset of it
+        let native = !is_web;
+
+        Features {
+            debug: native && (gl(4, 3) || es(3, 2) || ext(Extension::KhrDebug)),
+            compute: native && (gl(4, 3) || es(3, 1) || ext(Extension::ArbComputeShader)),
+            buffer_storage: native
+                && (gl(4, 4)
+                    || ext(Extension::ArbBufferStorage)
+                    || ext(Extension::ExtBufferStorage)),
+            dsa: native && (gl(4, 5) || ext(Extension::ArbDirectStateAccess)),
+            uniform_buffers: gl(3, 1) || es(3, 0) || ext(Extension::ArbUniformBufferObject),
+            shader_storage_buffers: native
+                && (gl(4, 3) || es(3, 1) || ext(Extension::ArbShaderStorageBufferObject)),
+            instancing: gl(3, 1)
+                || es(3, 0)
+                || ext(Extension::ArbInstancedArrays)
+                || ext(Extension::AngleInstancedArrays)
+                || ext(Extension::ExtInstancedArrays),
+            vertex_arrays: gl(3, 0)
+                || es(3, 0)
+                || ext(Extension::ArbVertexArrayObject)
+                || ext(Extension::OesVertexArrayObject)
+                || ext(Extension::AppleVertexArrayObject),
+            vertex_attrib_binding: native
+                && (gl(4, 3) || es(3, 1) || ext(Extension::ArbVertexAttribBinding)),
+            texture_storage: gl(4, 2)
+                || es(3, 0)
+                || ext(Extension::ArbTextureStorage)
+                || ext(Extension::ExtTextureStorage),
+            texture_3d: gl(1, 2) || es(3, 0),
+            texture_arrays: gl(3, 0) || es(3, 0) || ext(Extension::ExtTextureArray),
+            sampler_objects: gl(3, 3) || es(3, 0) || ext(Extension::ArbSamplerObjects),
+            framebuffer_objects: gl(3, 0)
+                || es(2, 0)
+                || ext(Extension::ArbFramebufferObject)
+                || ext(Extension::ExtFramebufferObject),
+            draw_buffers: gl(2, 0)
+                || es(3, 0)
+                || ext(Extension::ExtDrawBuffers)
+                || ext(Extension::WebglDrawBuffers),
+            multisampling: gl(3, 0) || es(3, 0) || ext(Extension::ArbFramebufferObject),
+            anisotropic_filtering: gl(4, 6)
+                || ext(Extension::ArbTextureFilterAnisotropic)
+                || ext(Extension::ExtTextureFilterAnisotropic),
+            sync: gl(3, 2) || es(3, 0) || ext(Extension::ArbSync),
+            timer_queries: gl(3, 3)
+                || ext(Extension::ArbTimerQuery)
+                || ext(Extension::ExtDisjointTimerQuery)
+                || ext(Extension::ExtDisjointTimerQueryWebgl2),
+            transform_feedback: gl(3, 0) || es(3, 0),
+            geometry_shaders: native
+                && (gl(3, 2)
+                    || es(3, 2)
+                    || ext(Extension::ExtGeometryShader)
+                    || ext(Extension::OesGeometryShader)),
+            tessellation_shaders: native
+                && (gl(4, 0)
+                    || es(3, 2)
+                    || ext(Extension::ArbTessellationShader)
+                    || ext(Extension::ExtTessellationShader)
+                    || ext(Extension::OesTessellationShader)),
+            copy_buffer: gl(3, 1)
+                || es(3, 0)
+                || ext(Extension::ArbCopyBuffer)
+                || ext(Extension::NvCopyBuffer),
+            pixel_buffers: gl(2, 1) || es(3, 0) || ext(Extension::ArbPixelBufferObject),
+            map_buffer_range: native
+                && (gl(3, 0)
+                    || es(3, 0)
+                    || ext(Extension::ArbMapBufferRange)
+                    || ext(Extension::ExtMapBufferRange)),
+            program_binary: native
+                && (gl(4, 1)
+                    || es(3, 0)
+                    || ext(Extension::ArbGetProgramBinary)
+                    || ext(Extension::OesGetProgramBinary)),
+            program_interface_query: native
+                && (gl(4, 3) || es(3, 1) || ext(Extension::ArbProgramInterfaceQuery)),
+            parallel_shader_compile: ext(Extension::KhrParallelShaderCompile)
+                || ext(Extension::ArbParallelShaderCompile),
+        }
+    }
+}
+
+/// Implementation limits, queried once when the context is created.
+///
+/// Limits that depend on a feature that isn't in [`Features`] are left as zero.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Limits {
+    pub max_texture_size: i32,
+    pub max_3d_texture_size: i32,
+    pub max_cube_map_texture_size: i32,
+    pub max_array_texture_layers: i32,
+    pub max_renderbuffer_size: i32,
+    pub max_viewport_dims: [i32; 2],
+    pub max_samples: i32,
+    pub max_color_attachments: i32,
+    pub max_draw_buffers: i32,
+    pub max_vertex_attribs: i32,
+    pub max_vertex_attrib_bindings: i32,
+    pub max_texture_image_units: i32,
+    pub max_vertex_texture_image_units: i32,
+    pub max_combined_texture_image_units: i32,
+    pub max_uniform_block_size: i32,
+    pub max_uniform_buffer_bindings: i32,
+    pub max_vertex_uniform_blocks: i32,
+    pub max_fragment_uniform_blocks: i32,
+    pub uniform_buffer_offset_alignment: i32,
+    pub max_shader_storage_block_size: i32,
+    pub max_shader_storage_buffer_bindings: i32,
+    pub shader_storage_buffer_offset_alignment: i32,
+    pub max_compute_work_group_count: [i32; 3],
+    pub max_compute_work_group_size: [i32; 3],
+    pub max_compute_work_group_invocations: i32,
+    pub max_compute_shared_memory_size: i32,
+    pub max_transform_feedback_separate_attribs: i32,
+    pub max_texture_max_anisotropy: f32,
+    pub max_label_length: i32,
+}
+
+impl Limits {
+    pub(crate) unsafe fn query<C: HasContext>(gl: &C, features: &Features) -> Self {
+        let get = |parameter| gl.get_parameter_i32(parameter);
+        let get_if = |supported: bool, parameter| if supported { get(parameter) } else { 0 };
+        let get_indexed = |supported: bool, parameter| {
+            let mut values = [0; 3];
+            if supported {
+                for (index, value) in values.iter_mut().enumerate() {
+                    *value = gl.get_parameter_indexed_i32(parameter, index as u32);
+                }
+            }
+            values
+        };
+
+        let mut max_viewport_dims = [0; 2];
+        gl.get_parameter_i32_slice(MAX_VIEWPORT_DIMS, &mut max_viewport_dims);
+
+        Limits {
+            max_texture_size: get(MAX_TEXTURE_SIZE),
+            max_3d_texture_size: get_if(features.texture_3d, MAX_3D_TEXTURE_SIZE),
+            max_cube_map_texture_size: get(MAX_CUBE_MAP_TEXTURE_SIZE),
+            max_array_texture_layers: get_if(features.texture_arrays, MAX_ARRAY_TEXTURE_LAYERS),
+            max_renderbuffer_size: get_if(features.framebuffer_objects, MAX_RENDERBUFFER_SIZE),
+            max_viewport_dims,
+            max_samples: get_if(features.multisampling, MAX_SAMPLES),
+            max_color_attachments: get_if(
+                features.framebuffer_objects && features.draw_buffers,
+                MAX_COLOR_ATTACHMENTS,
+            ),
+            max_draw_buffers: get_if(features.draw_buffers, MAX_DRAW_BUFFERS),
+            max_vertex_attribs: get(MAX_VERTEX_ATTRIBS),
+            max_vertex_attrib_bindings: get_if(
+                features.vertex_attrib_binding,
+                MAX_VERTEX_ATTRIB_BINDINGS,
+            ),
+            max_texture_image_units: get(MAX_TEXTURE_IMAGE_UNITS),
+            max_vertex_texture_image_units: get(MAX_VERTEX_TEXTURE_IMAGE_UNITS),
+            max_combined_texture_image_units: get(MAX_COMBINED_TEXTURE_IMAGE_UNITS),
+            max_uniform_block_size: get_if(features.uniform_buffers, MAX_UNIFORM_BLOCK_SIZE),
+            max_uniform_buffer_bindings: get_if(
+                features.uniform_buffers,
+                MAX_UNIFORM_BUFFER_BINDINGS,
+            ),
+            max_vertex_uniform_blocks: get_if(features.uniform_buffers, MAX_VERTEX_UNIFORM_BLOCKS),
+            max_fragment_uniform_blocks: get_if(
+                features.uniform_buffers,
+                MAX_FRAGMENT_UNIFORM_BLOCKS,
+            ),
+            uniform_buffer_offset_alignment: get_if(
+                features.uniform_buffers,
+                UNIFORM_BUFFER_OFFSET_ALIGNMENT,
+            ),
+            max_shader_storage_block_size: get_if(
+                features.shader_storage_buffers,
+                MAX_SHADER_STORAGE_BLOCK_SIZE,
+            ),
+            max_shader_storage_buffer_bindings: get_if(
+                features.shader_storage_buffers,
+                MAX_SHADER_STORAGE_BUFFER_BINDINGS,
+            ),
+            shader_storage_buffer_offset_alignment: get_if(
+                features.shader_storage_buffers,
+                SHADER_STORAGE_BUFFER_OFFSET_ALIGNMENT,
+            ),
+            max_compute_work_group_count: get_indexed(
+                features.compute,
+                MAX_COMPUTE_WORK_GROUP_COUNT,
+            ),
+            max_compute_work_group_size: get_indexed(features.compute, MAX_COMPUTE_WORK_GROUP_SIZE),
+            max_compute_work_group_invocations: get_if(
+                features.compute,
+                MAX_COMPUTE_WORK_GROUP_INVOCATIONS,
+            ),
+            max_compute_shared_memory_size: get_if(
+                features.compute,
+                MAX_COMPUTE_SHARED_MEMORY_SIZE,
+            ),
+            max_transform_feedback_separate_attribs: get_if(
+                features.transform_feedback,
+                MAX_TRANSFORM_FEEDBACK_SEPARATE_ATTRIBS,
+            ),
+            max_texture_max_anisotropy: if features.anisotropic_filtering {
+                gl.get_parameter_f32(MAX_TEXTURE_MAX_ANISOTROPY)
+            } else {
+                0.0
+            },
+            max_label_length: get_if(features.debug, MAX_LABEL_LENGTH),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_features() {
+        let extensions = Extensions::from_names(["GL_EXT_buffer_storage"]);
+
+        let gl = Features::new(&Version::new(4, 5, None, String::new()), &extensions, false);
+        assert!(gl.compute && gl.buffer_storage && gl.dsa && gl.debug);
+
+        let es = Features::new(
+            &Version::new_embedded(3, 0, String::new()),
+            &extensions,
+            false,
+        );
+        assert!(!es.compute && es.buffer_storage && !es.dsa && es.uniform_buffers);
+
+        let webgl = Features::new(
+            &Version::new_embedded(3, 0, String::new()),
+            &extensions,
+            true,
+        );
+        assert!(!webgl.buffer_storage && !webgl.program_binary && webgl.uniform_buffers);
+
+        let gl2 = Features::new(&Version::new(2, 1, None, String::new()), &extensions, false);
+        assert!(gl2.texture_3d && !gl2.texture_arrays && !gl2.framebuffer_objects);
+    }
+}
@@ -0,0 +1,887 @@
+//! Loading of textures from KTX, KTX2 and DDS containers.
+//!
+//! [`Container::parse`] reads the header of a container and splits its data into one slice per
+//! mip level, array layer and cube face, without copying. [`Container::upload`] creates an
+//! immutable texture for it with `tex_storage_*` and uploads every image.
+//!
+//! Only the image data is read: KTX key/value data is skipped, KTX2 files must not be
+//! supercompressed, and DDS files must use a format that maps directly to an OpenGL format.
+
+use crate::format::{self, PixelStore};
+use crate::*;
+
+/// The file format of a [`Container`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ContainerKind {
+    /// Khronos KTX 1.1.
+    Ktx1,
+    /// Khronos KTX 2.0.
+    Ktx2,
+    /// DirectDraw Surface, with or without the DX10 header extension.
+    Dds,
+}
+
+/// A parsed texture container.
+///
+/// The images borrow from the data passed to [`Container::parse`].
+#[derive(Clone, Debug)]
+pub struct Container<'a> {
+    kind: ContainerKind,
+    internal_format: u32,
+    format: u32,
+    ty: u32,
+    width: u32,
+    height: u32,
+    depth: u32,
+    layers: u32,
+    faces: u32,
+    levels: u32,
+    row_alignment: u32,
+    /// Indexed by level, then layer, then face.
+    images: Vec<&'a [u8]>,
+}
+
+const KTX1_IDENTIFIER: [u8; 12] = [
+    0xAB, 0x4B, 0x54, 0x58, 0x20, 0x31, 0x31, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A,
+];
+const KTX2_IDENTIFIER: [u8; 12] = [
+    0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A,
+];
+const DDS_MAGIC: [u8; 4] = *b"DDS ";
+
+impl<'a> Container<'a> {
+    /// Parses a KTX, KTX2 or DDS container, detected from its identifier.
+    pub fn parse(data: &'a [u8]) -> Result<Self, String> {
+        if data.starts_with(&KTX1_IDENTIFIER) {
+            parse_ktx1(data)
+        } else if data.starts_with(&KTX2_IDENTIFIER) {
+            parse_ktx2(data)
+        } else if data.starts_with(&DDS_MAGIC) {
+            parse_dds(data)
+        } else {
+            Err("Unknown texture container".to_string())
+        }
+    }
+
+    pub fn kind(&self) -> ContainerKind {
+        self.kind
+    }
+
+    pub fn internal_format(&self) -> u32 {
+        self.internal_format
+    }
+
+    /// The pixel format for `tex_sub_image_*`, or 0 for compressed formats.
+    pub fn format(&self) -> u32 {
+        self.format
+    }
+
+    /// The pixel type for `tex_sub_image_*`, or 0 for compressed formats.
+    pub fn ty(&self) -> u32 {
+        self.ty
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The depth of a 3D texture, or 1.
+    pub fn depth(&self) -> u32 {
+        self.depth
+    }
+
+    /// The number of array layers, or 0 if the texture isn't an array texture.
+    pub fn layers(&self) -> u32 {
+        self.layers
+    }
+
+    /// 6 for cube maps, otherwise 1.
+    pub fn faces(&self) -> u32 {
+        self.faces
+    }
+
+    pub fn levels(&self) -> u32 {
+        self.levels
+    }
+
+    /// The texture target, e.g. `TEXTURE_2D_ARRAY` or `TEXTURE_CUBE_MAP`.
+    pub fn target(&self) -> u32 {
+        match (self.faces == 6, self.layers > 0) {
+            (true, true) => TEXTURE_CUBE_MAP_ARRAY,
+            (true, false) => TEXTURE_CUBE_MAP,
+            (false, true) => TEXTURE_2D_ARRAY,
+            (false, false) if self.depth > 1 => TEXTURE_3D,
+            (false, false) => TEXTURE_2D,
+        }
+    }
+
+    /// Returns the data of one image, with all slices of a 3D texture.
+    pub fn image(&self, level: u32, layer: u32, face: u32) -> Option<&'a [u8]> {
+        if level >= self.levels || layer >= self.layers.max(1) || face >= self.faces {
+            return None;
+        }
+        self.images
+            .get(self.image_index(level, layer, face))
+            .copied()
+    }
+
+    /// Returns the size of `level` as width, height and depth.
+    pub fn level_size(&self, level: u32) -> (u32, u32, u32) {
+        (
+            (self.width >> level).max(1),
+            (self.height >> level).max(1),
+            (self.depth >> level).max(1),
+        )
+    }
+
+    /// Creates an immutable texture with all images of the container.
+    ///
+    /// Returns an error without creating a texture if the format isn't supported by the context
+    /// (see [`format::is_supported`]) or immutable texture storage is unavailable. The texture
+    /// binding of [`Self::target`], the unpack parameters and the `PIXEL_UNPACK_BUFFER` binding
+    /// are restored afterwards.
+    pub unsafe fn upload<C: HasContext>(&self, gl: &C) -> Result<C::Texture, String> {
+        if !format::is_supported(gl, self.internal_format) {
+            return Err(format!(
+                "Texture format {:#06x} is not supported by this context",
+                self.internal_format
+            ));
+        }
+        let features = gl.features();
+        if !features.texture_storage {
+            return Err("Uploading a container requires immutable texture storage".to_string());
+        }
+        let target = self.target();
+        if target == TEXTURE_3D && !features.texture_3d {
+            return Err("3D textures are not supported by this context".to_string());
+        }
+        if target == TEXTURE_2D_ARRAY && !features.texture_arrays {
+            return Err("Array textures are not supported by this context".to_string());
+        }
+
+        let previous = gl.get_parameter_texture(texture_binding(target));
+        let unpack = gl.unpack_parameters();
+        let unpack_buffer = if features.pixel_buffers {
+            gl.get_parameter_buffer(PIXEL_UNPACK_BUFFER_BINDING)
+        } else {
+            None
+        };
+        if unpack_buffer.is_some() {
+            gl.bind_buffer(PIXEL_UNPACK_BUFFER, None);
+        }
+        PixelStore {
+            alignment: self.row_alignment,
+            ..PixelStore::default()
+        }
+        .apply_unpack(gl);
+
+        let result = gl.create_texture().and_then(|texture| {
+            gl.bind_texture(target, Some(texture));
+            let result = self.upload_images(gl, target);
+            if result.is_err() {
+                gl.delete_texture(texture);
+            }
+            result.map(|()| texture)
+        });
+
+        gl.bind_texture(target, previous);
+        unpack.apply_unpack(gl);
+        if unpack_buffer.is_some() {
+            gl.bind_buffer(PIXEL_UNPACK_BUFFER, unpack_buffer);
+        }
+        result
+    }
+
+    unsafe fn upload_images<C: HasContext>(&self, gl: &C, target: u32) -> Result<(), String> {
+        let levels = self.levels as i32;
+        let (width, height) = (self.width as i32, self.height as i32);
+        match target {
+            TEXTURE_2D | TEXTURE_CUBE_MAP => {
+                gl.tex_storage_2d(target, levels, self.internal_format, width, height)
+            }
+            TEXTURE_3D => gl.tex_storage_3d(
+                target,
+                levels,
+                self.internal_format,
+                width,
+                height,
+                self.depth as i32,
+            ),
+            _ => gl.tex_storage_3d(
+                target,
+                levels,
+                self.internal_format,
+                width,
+                height,
+                (self.layers * self.faces) as i32,
+            ),
+        }
+
+        for level in 0..self.levels {
+            let (width, height, depth) = self.level_size(level);
+            let (width, height, depth) = (width as i32, height as i32, depth as i32);
+            for layer in 0..self.layers.max(1) {
+                for face in 0..self.faces {
+                    let pixels = self.images[self.image_index(level, layer, face)];
+                    let level = level as i32;
+                    match target {
+                        TEXTURE_2D | TEXTURE_CUBE_MAP => {
+                            let target = if target == TEXTURE_CUBE_MAP {
+                                TEXTURE_CUBE_MAP_POSITIVE_X + face
+                            } else {
+                                target
+                            };
+                            if self.is_compressed() {
+                                gl.compressed_tex_sub_image_2d(
+                                    target,
+                                    level,
+                                    0,
+                                    0,
+                                    width,
+                                    height,
+                                    self.internal_format,
+                                    CompressedPixelUnpackData::Slice(pixels),
+                                );
+                            } else {
+                                gl.tex_sub_image_2d(
+                                    target,
+                                    level,
+                                    0,
+                                    0,
+                                    width,
+                                    height,
+                                    self.format,
+                                    self.ty,
+                                    PixelUnpackData::Slice(Some(pixels)),
+                                );
+                            }
+                        }
+                        _ => {
+                            let (z_offset, depth) = if target == TEXTURE_3D {
+                                (0, depth)
+                            } else {
+                                ((layer * self.faces + face) as i32, 1)
+                            };
+                            if self.is_compressed() {
+                                gl.compressed_tex_sub_image_3d(
+                                    target,
+                                    level,
+                                    0,
+                                    0,
+                                    z_offset,
+                                    width,
+                                    height,
+                                    depth,
+                                    self.internal_format,
+                                    CompressedPixelUnpackData::Slice(pixels),
+                                );
+                            } else {
+                                gl.tex_sub_image_3d(
+                                    target,
+                                    level,
+                                    0,
+                                    0,
+                                    z_offset,
+                                    width,
+                                    height,
+                                    depth,
+                                    self.format,
+                                    self.ty,
+                                    PixelUnpackData::Slice(Some(pixels)),
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn is_compressed(&self) -> bool {
+        self.format == 0
+    }
+
+    fn image_index(&self, level: u32, layer: u32, face: u32) -> usize {
+        let [level, layers, layer, faces, face] =
+            [level, self.layers.max(1), layer, self.faces, face].map(|value| value as usize);
+        (level * layers + layer) * faces + face
+    }
+
+    /// The size of one image of `level`, including the row padding of the container.
+    ///
+    /// Sizes that overflow can't be in the data, so they're reported as truncation.
+    fn image_size(&self, level: u32) -> Result<usize, String> {
+        let (width, height, depth) = self.level_size(level);
+        if self.is_compressed() {
+            // Compressed formats are always sized, `validate` checked that the format is known
+            return format::info(self.internal_format)
+                .and_then(|info| info.image_size(width, height, depth))
+                .ok_or_else(truncated);
+        }
+        let pixel_size = format::pixel_size(self.format, self.ty)
+            .ok_or_else(|| format!("Unknown texture format {:#06x}", self.internal_format))?;
+        PixelStore {
+            alignment: self.row_alignment,
+            ..PixelStore::default()
+        }
+        .transfer_size(width, height, depth, pixel_size)
+        .ok_or_else(truncated)
+    }
+
+    /// Checks the dimensions and fills in `format` and `ty` for uncompressed formats.
+    fn validate(&mut self) -> Result<(), String> {
+        if self.width == 0 || self.height == 0 || self.depth == 0 {
+            return Err("Texture container has a zero size".to_string());
+        }
+        if self.faces != 1 && self.faces != 6 {
+            return Err(format!(
+                "Texture container has {} faces, expected 1 or 6",
+                self.faces
+            ));
+        }
+        if self.layers.checked_mul(self.faces).is_none() {
+            return Err(format!(
+                "Texture container has {} layers, which is too many",
+                self.layers
+            ));
+        }
+        if self.depth > 1 && (self.layers > 0 || self.faces > 1) {
+            return Err("3D array and cube map textures are not supported".to_string());
+        }
+        let max_levels = 32 - self.width.max(self.height).max(self.depth).leading_zeros();
+        if self.levels == 0 || self.levels > max_levels {
+            return Err(format!(
+                "Texture container has {} mip levels, expected 1 to {}",
+                self.levels, max_levels
+            ));
+        }
+        let info = format::info(self.internal_format)
+            .ok_or_else(|| format!("Unknown texture format {:#06x}", self.internal_format))?;
+        if !info.compressed && (self.format == 0 || self.ty == 0) {
+            let (format, ty) = pixel_format(self.internal_format).ok_or_else(|| {
+                format!(
+                    "Texture format {:#06x} has no known pixel format",
+                    self.internal_format
+                )
+            })?;
+            self.format = format;
+            self.ty = ty;
+        } else if info.compressed {
+            self.format = 0;
+            self.ty = 0;
+        }
+        Ok(())
+    }
+
+    fn empty(kind: ContainerKind, internal_format: u32) -> Self {
+        Container {
+            kind,
+            internal_format,
+            format: 0,
+            ty: 0,
+            width: 1,
+            height: 1,
+            depth: 1,
+            layers: 0,
+            faces: 1,
+            levels: 1,
+            row_alignment: 1,
+            images: Vec::new(),
+        }
+    }
+}
+
+fn texture_binding(target: u32) -> u32 {
+    match target {
+        TEXTURE_CUBE_MAP_ARRAY => TEXTURE_BINDING_CUBE_MAP_ARRAY,
+        TEXTURE_CUBE_MAP => TEXTURE_BINDING_CUBE_MAP,
+        TEXTURE_2D_ARRAY => TEXTURE_BINDING_2D_ARRAY,
+        TEXTURE_3D => TEXTURE_BINDING_3D,
+        _ => TEXTURE_BINDING_2D,
+    }
+}
+
+fn u32_at(data: &[u8], offset: usize) -> Result<u32, String> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        .ok_or_else(truncated)
+}
+
+fn u64_at(data: &[u8], offset: usize) -> Result<u64, String> {
+    Ok(u32_at(data, offset)? as u64 | (u32_at(data, offset + 4)? as u64) << 32)
+}
+
+fn slice_at(data: &[u8], offset: usize, len: usize) -> Result<&[u8], String> {
+    offset
+        .checked_add(len)
+        .and_then(|end| data.get(offset..end))
+        .ok_or_else(truncated)
+}
+
+fn truncated() -> String {
+    "Texture container is truncated".to_string()
+}
+
+fn parse_ktx1(data: &[u8]) -> Result<Container<'_>, String> {
+    match u32_at(data, 12)? {
+        0x04030201 => {}
+        0x01020304 => return Err("Big-endian KTX files are not supported".to_string()),
+        _ => return Err("Invalid KTX endianness".to_string()),
+    }
+    let mut container = Container::empty(ContainerKind::Ktx1, u32_at(data, 28)?);
+    container.ty = u32_at(data, 16)?;
+    container.format = u32_at(data, 24)?;
+    container.width = u32_at(data, 36)?;
+    // 1D textures are loaded as 2D textures
+    container.height = u32_at(data, 40)?.max(1);
+    container.depth = u32_at(data, 44)?.max(1);
+    container.layers = u32_at(data, 48)?;
+    container.faces = u32_at(data, 52)?;
+    // 0 means the mip levels should be generated
+    container.levels = u32_at(data, 56)?.max(1);
+    container.row_alignment = 4;
+    container.validate()?;
+
+    let mut offset = (u32_at(data, 60)? as usize)
+        .checked_add(64)
+        .ok_or_else(truncated)?;
+    let images_per_level = container.layers.max(1) * container.faces;
+    for level in 0..container.levels {
+        let image_size = container.image_size(level)?;
+        let level_size = u32_at(data, offset)? as usize;
+        offset += 4;
+        if container.faces == 6 && container.layers == 0 {
+            // `imageSize` is the size of a single face, and each face is padded
+            if level_size < image_size {
+                return Err(truncated());
+            }
+            let face_stride = level_size
+                .checked_next_multiple_of(4)
+                .ok_or_else(truncated)?;
+            for _ in 0..6 {
+                container.images.push(slice_at(data, offset, image_size)?);
+                offset = offset.checked_add(face_stride).ok_or_else(truncated)?;
+            }
+        } else {
+            // Images are padded like the rows, so they're spaced evenly within the level
+            let image_stride = level_size / images_per_level as usize;
+            if image_stride < image_size {
+                return Err(truncated());
+            }
+            for image in 0..images_per_level as usize {
+                let image_offset = offset
+                    .checked_add(image * image_stride)
+                    .ok_or_else(truncated)?;
+                container
+                    .images
+                    .push(slice_at(data, image_offset, image_size)?);
+            }
+            offset = level_size
+                .checked_next_multiple_of(4)
+                .and_then(|level_size| offset.checked_add(level_size))
+                .ok_or_else(truncated)?;
+        }
+    }
+    Ok(container)
+}
+
+fn parse_ktx2(data: &[u8]) -> Result<Container<'_>, String> {
+    let vk_format = u32_at(data, 12)?;
+    let internal_format = vk_format_to_gl(vk_format)
+        .ok_or_else(|| format!("Unsupported KTX2 format VkFormat({})", vk_format))?;
+    let scheme = u32_at(data, 44)?;
+    if scheme != 0 {
+        return Err(format!(
+            "Supercompressed KTX2 files are not supported (scheme {})",
+            scheme
+        ));
+    }
+    let mut container = Container::empty(ContainerKind::Ktx2, internal_format);
+    container.width = u32_at(data, 20)?;
+    container.height = u32_at(data, 24)?.max(1);
+    container.depth = u32_at(data, 28)?.max(1);
+    container.layers = u32_at(data, 32)?;
+    container.faces = u32_at(data, 36)?;
+    container.levels = u32_at(data, 40)?.max(1);
+    container.validate()?;
+
+    let images_per_level = (container.layers.max(1) * container.faces) as usize;
+    for level in 0..container.levels {
+        let index = 80 + level as usize * 24;
+        let offset = usize::try_from(u64_at(data, index)?).map_err(|_| truncated())?;
+        let length = usize::try_from(u64_at(data, index + 8)?).map_err(|_| truncated())?;
+        let image_size = container.image_size(level)?;
+        match image_size.checked_mul(images_per_level) {
+            Some(level_size) if level_size <= length => {}
+            _ => return Err(truncated()),
+        }
+        for image in 0..images_per_level {
+            let image_offset = offset
+                .checked_add(image * image_size)
+                .ok_or_else(truncated)?;
+            container
+                .images
+                .push(slice_at(data, image_offset, image_size)?);
+        }
+    }
+    Ok(container)
+}
+
+const DDPF_FOURCC: u32 = 0x4;
+const DDPF_RGB: u32 = 0x40;
+const DDSD_MIPMAPCOUNT: u32 = 0x20000;
+const DDSCAPS2_CUBEMAP: u32 = 0x200;
+const DDSCAPS2_VOLUME: u32 = 0x200000;
+const D3D10_RESOURCE_DIMENSION_TEXTURE3D: u32 = 4;
+const D3D10_RESOURCE_MISC_TEXTURECUBE: u32 = 0x4;
+
+fn parse_dds(data: &[u8]) -> Result<Container<'_>, String> {
+    let flags = u32_at(data, 8)?;
+    let pixel_flags = u32_at(data, 80)?;
+    let four_cc = u32_at(data, 84)?.to_le_bytes();
+    let caps2 = u32_at(data, 112)?;
+
+    let mut volume = caps2 & DDSCAPS2_VOLUME != 0;
+    let mut cube = caps2 & DDSCAPS2_CUBEMAP != 0;
+    let mut layers = 0;
+    let mut offset = 128;
+    let internal_format = if pixel_flags & DDPF_FOURCC != 0 && &four_cc == b"DX10" {
+        let dxgi_format = u32_at(data, 128)?;
+        volume = u32_at(data, 132)? == D3D10_RESOURCE_DIMENSION_TEXTURE3D;
+        cube = u32_at(data, 136)? & D3D10_RESOURCE_MISC_TEXTURECUBE != 0;
+        let array_size = u32_at(data, 140)?;
+        if array_size > 1 {
+            layers = array_size;
+        }
+        offset = 148;
+        dxgi_format_to_gl(dxgi_format)
+            .ok_or_else(|| format!("Unsupported DDS format DXGI_FORMAT({})", dxgi_format))?
+    } else if pixel_flags & DDPF_FOURCC != 0 {
+        four_cc_to_gl(&four_cc).ok_or_else(|| {
+            format!(
+                "Unsupported DDS format {:?}",
+                String::from_utf8_lossy(&four_cc)
+            )
+        })?
+    } else if pixel_flags & DDPF_RGB != 0
+        && u32_at(data, 88)? == 32
+        && [
+            u32_at(data, 92)?,
+            u32_at(data, 96)?,
+            u32_at(data, 100)?,
+            u32_at(data, 104)?,
+        ] == [0xFF, 0xFF00, 0xFF0000, 0xFF000000]
+    {
+        RGBA8
+    } else {
+        return Err("Unsupported DDS pixel format".to_string());
+    };
+
+    let mut container = Container::empty(ContainerKind::Dds, internal_format);
+    container.height = u32_at(data, 12)?;
+    container.width = u32_at(data, 16)?;
+    if volume {
+        container.depth = u32_at(data, 24)?.max(1);
+    }
+    container.layers = layers;
+    if cube {
+        container.faces = 6;
+    }
+    if flags & DDSD_MIPMAPCOUNT != 0 {
+        container.levels = u32_at(data, 28)?.max(1);
+    }
+    container.validate()?;
+
+    // Each layer and face has a complete mip chain
+    let mut images = Vec::new();
+    for _ in 0..container.layers.max(1) * container.faces {
+        for level in 0..container.levels {
+            let image_size = container.image_size(level)?;
+            images.push(slice_at(data, offset, image_size)?);
+            offset += image_size;
+        }
+    }
+    let levels = container.levels as usize;
+    let images_per_level = images.len() / levels;
+    container.images = (0..images.len())
+        .map(|index| images[(index % images_per_level) * levels + index / images_per_level])
+        .collect();
+    Ok(container)
+}
+
+/// Returns the pixel format and type for uploading an uncompressed internal format.
+fn pixel_format(internal_format: u32) -> Option<(u32, u32)> {
+    Some(match internal_format {
+        R8 => (RED, UNSIGNED_BYTE),
+        RG8 => (RG, UNSIGNED_BYTE),
+        RGB8 | SRGB8 => (RGB, UNSIGNED_BYTE),
+        RGBA8 | SRGB8_ALPHA8 => (RGBA, UNSIGNED_BYTE),
+        R16F => (RED, HALF_FLOAT),
+        RG16F => (RG, HALF_FLOAT),
+        RGBA16F => (RGBA, HALF_FLOAT),
+        R32F => (RED, FLOAT),
+        RG32F => (RG, FLOAT),
+        RGBA32F => (RGBA, FLOAT),
+        R11F_G11F_B10F => (RGB, UNSIGNED_INT_10F_11F_11F_REV),
+        RGB9_E5 => (RGB, UNSIGNED_INT_5_9_9_9_REV),
+        RGB10_A2 => (RGBA, UNSIGNED_INT_2_10_10_10_REV),
+        _ => return None,
+    })
+}
+
+fn vk_format_to_gl(vk_format: u32) -> Option<u32> {
+    Some(match vk_format {
+        9 => R8,
+        16 => RG8,
+        23 => RGB8,
+        29 => SRGB8,
+        37 => RGBA8,
+        43 => SRGB8_ALPHA8,
+        64 => RGB10_A2,
+        76 => R16F,
+        83 => RG16F,
+        97 => RGBA16F,
+        100 => R32F,
+        103 => RG32F,
+        109 => RGBA32F,
+        122 => R11F_G11F_B10F,
+        123 => RGB9_E5,
+        131 => COMPRESSED_RGB_S3TC_DXT1_EXT,
+        132 => COMPRESSED_SRGB_S3TC_DXT1_EXT,
+        133 => COMPRESSED_RGBA_S3TC_DXT1_EXT,
+        134 => COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT,
+        135 => COMPRESSED_RGBA_S3TC_DXT3_EXT,
+        136 => COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT,
+        137 => COMPRESSED_RGBA_S3TC_DXT5_EXT,
+        138 => COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT,
+        139 => COMPRESSED_RED_RGTC1,
+        140 => COMPRESSED_SIGNED_RED_RGTC1,
+        141 => COMPRESSED_RG_RGTC2,
+        142 => COMPRESSED_SIGNED_RG_RGTC2,
+        143 => COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT,
+        144 => COMPRESSED_RGB_BPTC_SIGNED_FLOAT,
+        145 => COMPRESSED_RGBA_BPTC_UNORM,
+        146 => COMPRESSED_SRGB_ALPHA_BPTC_UNORM,
+        147 => COMPRESSED_RGB8_ETC2,
+        148 => COMPRESSED_SRGB8_ETC2,
+        149 => COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2,
+        150 => COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2,
+        151 => COMPRESSED_RGBA8_ETC2_EAC,
+        152 => COMPRESSED_SRGB8_ALPHA8_ETC2_EAC,
+        153 => COMPRESSED_R11_EAC,
+        154 => COMPRESSED_SIGNED_R11_EAC,
+        155 => COMPRESSED_RG11_EAC,
+        156 => COMPRESSED_SIGNED_RG11_EAC,
+        // The ASTC block sizes alternate between UNORM and SRGB, in the same order as the
+        // OpenGL enums
+        157..=184 if vk_format % 2 == 1 => COMPRESSED_RGBA_ASTC_4x4_KHR + (vk_format - 157) / 2,
+        157..=184 => COMPRESSED_SRGB8_ALPHA8_ASTC_4x4_KHR + (vk_format - 158) / 2,
+        _ => return None,
+    })
+}
+
+fn dxgi_format_to_gl(dxgi_format: u32) -> Option<u32> {
+    Some(match dxgi_format {
+        2 => RGBA32F,
+        10 => RGBA16F,
+        16 => RG32F,
+        24 => RGB10_A2,
+        26 => R11F_G11F_B10F,
+        28 => RGBA8,
+        29 => SRGB8_ALPHA8,
+        34 => RG16F,
+        41 => R32F,
+        49 => RG8,
+        54 => R16F,
+        61 => R8,
+        67 => RGB9_E5,
+        71 => COMPRESSED_RGBA_S3TC_DXT1_EXT,
+        72 => COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT,
+        74 => COMPRESSED_RGBA_S3TC_DXT3_EXT,
+        75 => COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT,
+        77 => COMPRESSED_RGBA_S3TC_DXT5_EXT,
+        78 => COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT,
+        80 => COMPRESSED_RED_RGTC1,
+        81 => COMPRESSED_SIGNED_RED_RGTC1,
+        83 => COMPRESSED_RG_RGTC2,
+        84 => COMPRESSED_SIGNED_RG_RGTC2,
+        95 => COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT,
+        96 => COMPRESSED_RGB_BPTC_SIGNED_FLOAT,
+        98 => COMPRESSED_RGBA_BPTC_UNORM,
+        99 => COMPRESSED_SRGB_ALPHA_BPTC_UNORM,
+        _ => return None,
+    })
+}
+
+fn four_cc_to_gl(four_cc: &[u8; 4]) -> Option<u32> {
+    Some(match four_cc {
+        b"DXT1" => COMPRESSED_RGBA_S3TC_DXT1_EXT,
+        b"DXT3" => COMPRESSED_RGBA_S3TC_DXT3_EXT,
+        b"DXT5" => COMPRESSED_RGBA_S3TC_DXT5_EXT,
+        b"ATI1" | b"BC4U" => COMPRESSED_RED_RGTC1,
+        b"BC4S" => COMPRESSED_SIGNED_RED_RGTC1,
+        b"ATI2" | b"BC5U" => COMPRESSED_RG_RGTC2,
+        b"BC5S" => COMPRESSED_SIGNED_RG_RGTC2,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(values: &[u32]) -> Vec<u8> {
+        values
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn test_ktx1() {
+        // A 2x2 RGB8 texture with two levels, so the rows of level 0 are padded to 8 bytes
+        let mut data = KTX1_IDENTIFIER.to_vec();
+        data.extend(words(&[
+            0x04030201,
+            UNSIGNED_BYTE,
+            1,
+            RGB,
+            RGB8,
+            RGB,
+            2,
+            2,
+            0,
+            0,
+            1,
+            2,
+            4,
+        ]));
+        data.extend([0; 4]);
+        data.extend(words(&[16]));
+        data.extend(1..=16);
+        data.extend(words(&[4]));
+        data.extend([20, 21, 22, 0]);
+
+        let container = Container::parse(&data).unwrap();
+        assert_eq!(container.kind(), ContainerKind::Ktx1);
+        assert_eq!(container.target(), TEXTURE_2D);
+        assert_eq!(
+            (
+                container.internal_format(),
+                container.format(),
+                container.ty()
+            ),
+            (RGB8, RGB, UNSIGNED_BYTE)
+        );
+        assert_eq!(
+            (container.width(), container.height(), container.levels()),
+            (2, 2, 2)
+        );
+        assert_eq!(container.image(0, 0, 0).unwrap().len(), 14);
+        assert_eq!(container.image(1, 0, 0), Some(&[20, 21, 22][..]));
+        assert_eq!(container.image(2, 0, 0), None);
+
+        assert_eq!(
+            Container::parse(&data[..data.len() - 2]).unwrap_err(),
+            "Texture container is truncated"
+        );
+    }
+
+    #[test]
+    fn test_ktx2() {
+        // A BC1 cube map with a single 4x4 level
+        let mut data = KTX2_IDENTIFIER.to_vec();
+        data.extend(words(&[133, 1, 4, 4, 0, 0, 6, 1, 0]));
+        data.extend([0; 32]);
+        data.extend(words(&[104, 0, 48, 0, 48, 0]));
+        data.extend((0..6).flat_map(|face| [face; 8]));
+
+        let container = Container::parse(&data).unwrap();
+        assert_eq!(container.target(), TEXTURE_CUBE_MAP);
+        assert_eq!(container.internal_format(), COMPRESSED_RGBA_S3TC_DXT1_EXT);
+        assert_eq!((container.format(), container.ty()), (0, 0));
+        assert_eq!(container.image(0, 0, 5), Some(&[5; 8][..]));
+
+        data[44] = 1;
+        assert!(Container::parse(&data)
+            .unwrap_err()
+            .starts_with("Supercompressed"));
+        data[44] = 0;
+        data[12] = 0;
+        assert_eq!(
+            Container::parse(&data).unwrap_err(),
+            "Unsupported KTX2 format VkFormat(0)"
+        );
+
+        // An RGBA32F image too large to have a size
+        let mut data = KTX2_IDENTIFIER.to_vec();
+        data.extend(words(&[109, 4, u32::MAX, u32::MAX, 0, 0, 1, 1, 0]));
+        data.extend([0; 32]);
+        data.extend(words(&[104, 0, u32::MAX, u32::MAX, 0, 0]));
+        assert_eq!(
+            Container::parse(&data).unwrap_err(),
+            "Texture container is truncated"
+        );
+        data[32..36].copy_from_slice(&u32::MAX.to_le_bytes());
+        data[36..40].copy_from_slice(&6u32.to_le_bytes());
+        assert!(Container::parse(&data).unwrap_err().contains("too many"));
+    }
+
+    #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
+    #[wasm_bindgen_test::wasm_bindgen_test]
+    fn test_upload_unbound() {
+        // A 1x1 RGBA8 texture, uploaded while no texture or unpack buffer is bound
+        let mut data = KTX2_IDENTIFIER.to_vec();
+        data.extend(words(&[37, 1, 1, 1, 0, 0, 1, 1, 0]));
+        data.extend([0; 32]);
+        data.extend(words(&[104, 0, 4, 0, 4, 0]));
+        data.extend([1, 2, 3, 4]);
+
+        let container = Container::parse(&data).unwrap();
+        let gl = crate::web::tests::webgl2_context();
+        unsafe {
+            let texture = container.upload(&gl).unwrap();
+            assert_eq!(gl.get_parameter_texture(TEXTURE_BINDING_2D), None);
+            assert_eq!(gl.get_parameter_buffer(PIXEL_UNPACK_BUFFER_BINDING), None);
+            gl.delete_texture(texture);
+        }
+    }
+
+    #[test]
+    fn test_dds() {
+        // A two layer BC4 array with two levels, 8x8 and 4x4
+        let mut data = DDS_MAGIC.to_vec();
+        let mut header = [0; 31];
+        header[1] = DDSD_MIPMAPCOUNT;
+        header[2] = 8;
+        header[3] = 8;
+        header[6] = 2;
+        header[19] = DDPF_FOURCC;
+        header[20] = u32::from_le_bytes(*b"DX10");
+        data.extend(words(&header));
+        data.extend(words(&[80, 3, 0, 2, 0]));
+        for layer in 0..2u8 {
+            data.extend([layer * 10; 32]);
+            data.extend([layer * 10 + 1; 8]);
+        }
+
+        let container = Container::parse(&data).unwrap();
+        assert_eq!(container.kind(), ContainerKind::Dds);
+        assert_eq!(container.target(), TEXTURE_2D_ARRAY);
+        assert_eq!(container.internal_format(), COMPRESSED_RED_RGTC1);
+        assert_eq!((container.layers(), container.levels()), (2, 2));
+        assert_eq!(container.image(0, 1, 0), Some(&[10; 32][..]));
+        assert_eq!(container.image(1, 0, 0), Some(&[1; 8][..]));
+        assert_eq!(container.image(1, 1, 0), Some(&[11; 8][..]));
+
+        assert_eq!(
+            Container::parse(b"PNG image").unwrap_err(),
+            "Unknown texture container"
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// How serious a [`ShaderDiagnostic`] is.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DiagnosticSeverity {
+    Error,
+    Warning,
+    Info,
+}
+
+/// A message from a shader compiler or program linker, see
+/// [`HasContext::get_shader_diagnostics`](crate::HasContext::get_shader_diagnostics).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ShaderDiagnostic {
+    pub severity: DiagnosticSeverity,
+    /// The index of the string passed to `shader_source` that the message refers to. Always 0
+    /// for glow shaders, since only one string is passed.
+    pub source_index: Option<u32>,
+    /// The 1-based line number, if the driver reported one.
+    pub line: Option<u32>,
+    /// The 1-based column number, if the driver reported one.
+    pub column: Option<u32>,
+    pub message: String,
+}
+
+/// Parses a shader or program info log into diagnostics, one per non-empty line.
+///
+/// The following formats are understood, lines in other formats are returned as a message
+/// without a location:
+///
+/// * `0(12) : error C0000: message` (NVIDIA)
+/// * `ERROR: 0:12: message` (ANGLE, AMD, Apple, ARM, Intel and glslang)
+/// * `0:12(5): error: message` (Mesa)
+pub(crate) fn parse_info_log(log: &str) -> Vec<ShaderDiagnostic> {
+    log.trim_end_matches('\0')
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            parse_prefixed(line)
+                .or_else(|| parse_mesa(line))
+                .or_else(|| parse_nvidia(line))
+                .unwrap_or_else(|| ShaderDiagnostic {
+                    severity: guess_severity(line),
+                    source_index: None,
+                    line: None,
+                    column: None,
+                    message: line.to_string(),
+                })
+        })
+        .collect()
+}
+
+/// Splits a leading decimal number from `s`.
+fn number(s: &str) -> Option<(u32, &str)> {
+    let digits = s.bytes().take_while(u8::is_ascii_digit).count();
+    let value = s[..digits].parse().ok()?;
+    Some((value, &s[digits..]))
+}
+
+fn severity(kind: &str) -> Option<DiagnosticSeverity> {
+    match kind.to_ascii_lowercase().as_str() {
+        "error" | "fatal error" => Some(DiagnosticSeverity::Error),
+        "warning" => Some(DiagnosticSeverity::Warning),
+        "info" | "note" => Some(DiagnosticSeverity::Info),
+        _ => None,
+    }
+}
+
+fn guess_severity(line: &str) -> DiagnosticSeverity {
+    let line = line.to_ascii_lowercase();
+    if line.contains("error") {
+        DiagnosticSeverity::Error
+    } else if line.contains("warning") {
+        DiagnosticSeverity::Warning
+    } else {
+        DiagnosticSeverity::Info
+    }
+}
+
+/// `ERROR: 0:12: message`, or `ERROR: message` for summaries without a location.
+fn parse_prefixed(line: &str) -> Option<ShaderDiagnostic> {
+    let (kind, rest) = line.split_once(':')?;
+    if kind.bytes().any(|b| b.is_ascii_lowercase()) {
+        return None;
+    }
+    let severity = severity(kind)?;
+    let rest = rest.trim_start();
+
+    let location = number(rest).and_then(|(source_index, rest)| {
+        let (line, rest) = number(rest.strip_prefix(':')?)?;
+        Some((source_index, line, rest.strip_prefix(':')?))
+    });
+    Some(match location {
+        Some((source_index, line, message)) => ShaderDiagnostic {
+            severity,
+            source_index: Some(source_index),
+            line: Some(line),
+            column: None,
+            message: message.trim().to_string(),
+        },
+        None => ShaderDiagnostic {
+            severity,
+            source_index: None,
+            line: None,
+            column: None,
+            message: rest.to_string(),
+        },
+    })
+}
+
+/// `0:12(5): error: message`
+fn parse_mesa(line: &str) -> Option<ShaderDiagnostic> {
+    let (source_index, rest) = number(line)?;
+    let (line, rest) = number(rest.strip_prefix(':')?)?;
+    let (column, rest) = number(rest.strip_prefix('(')?)?;
+    let rest = rest.strip_prefix("):")?;
+    let (kind, message) = rest.split_once(':')?;
+    Some(ShaderDiagnostic {
+        severity: severity(kind.trim())?,
+        source_index: Some(source_index),
+        line: Some(line),
+        column: Some(column),
+        message: message.trim().to_string(),
+    })
+}
+
+/// `0(12) : error C0000: message`
+fn parse_nvidia(line: &str) -> Option<ShaderDiagnostic> {
+    let (source_index, rest) = number(line)?;
+    let (line, rest) = number(rest.strip_prefix('(')?)?;
+    let rest = rest.strip_prefix(')')?.trim_start().strip_prefix(':')?;
+    let (kind, message) = rest.trim_start().split_once(' ')?;
+    Some(ShaderDiagnostic {
+        severity: severity(kind)?,
+        source_index: Some(source_index),
+        line: Some(line),
+        column: None,
+        message: message.trim().to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostic(
+        severity: DiagnosticSeverity,
+        location: Option<(u32, u32)>,
+        column: Option<u32>,
+        message: &str,
+    ) -> ShaderDiagnostic {
+        ShaderDiagnostic {
+            severity,
+            source_index: location.map(|(source_index, _)| source_index),
+            line: location.map(|(_, line)| line),
+            column,
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_info_log() {
+        use DiagnosticSeverity::*;
+
+        assert_eq!(
+            parse_info_log(
+                "0(12) : error C0000: syntax error, unexpected '}'\n\
+                 0(3) : warning C7022: unrecognized profile specifier \"foo\"\n"
+            ),
+            [
+                diagnostic(
+                    Error,
+                    Some((0, 12)),
+                    None,
+                    "C0000: syntax error, unexpected '}'"
+                ),
+                diagnostic(
+                    Warning,
+                    Some((0, 3)),
+                    None,
+                    "C7022: unrecognized profile specifier \"foo\""
+                ),
+            ]
+        );
+        assert_eq!(
+            parse_info_log(
+                "ERROR: 0:12: 'foo' : undeclared identifier\n\
+                 ERROR: 1 compilation errors.  No code generated.\n\n\0"
+            ),
+            [
+                diagnostic(Error, Some((0, 12)), None, "'foo' : undeclared identifier"),
+                diagnostic(
+                    Error,
+                    None,
+                    None,
+                    "1 compilation errors.  No code generated."
+                ),
+            ]
+        );
+        assert_eq!(
+            parse_info_log("0:12(5): error: `foo' undeclared\n0:7(10): warning: unused variable"),
+            [
+                diagnostic(Error, Some((0, 12)), Some(5), "`foo' undeclared"),
+                diagnostic(Warning, Some((0, 7)), Some(10), "unused variable"),
+            ]
+        );
+        assert_eq!(
+            parse_info_log("Vertex info\n-----------\nlink error: something broke"),
+            [
+                diagnostic(Info, None, None, "Vertex info"),
+                diagnostic(Info, None, None, "-----------"),
+                diagnostic(Error, None, None, "link error: something broke"),
+            ]
+        );
+    }
+}
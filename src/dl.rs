@@ -0,0 +1,52 @@
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int, c_void};
+
+const RTLD_NOW: c_int = 2;
+const RTLD_LOCAL: c_int = 0;
+
+extern "C" {
+    fn dlopen(filename: *const c_char, flag: c_int) -> *mut c_void;
+    fn dlsym(handle: *mut c_void, symbol: *const c_char) -> *mut c_void;
+    fn dlclose(handle: *mut c_void) -> c_int;
+}
+
+/// A shared library loaded with `dlopen`, which is closed when dropped.
+#[derive(Debug)]
+pub(crate) struct Library(*mut c_void);
+
+impl Library {
+    /// Loads the first of `names` that can be found.
+    pub(crate) unsafe fn open(names: &[&str]) -> Result<Self, String> {
+        for name in names {
+            let c_name = CString::new(*name).unwrap();
+            let handle = dlopen(c_name.as_ptr(), RTLD_NOW | RTLD_LOCAL);
+            if !handle.is_null() {
+                return Ok(Library(handle));
+            }
+        }
+        Err(format!("{} could not be loaded", names.join(" or ")))
+    }
+
+    /// Looks up `name` as a value of `T`, which must be a function pointer type.
+    pub(crate) unsafe fn symbol<T: Copy>(&self, name: &str) -> Result<T, String> {
+        assert_eq!(
+            std::mem::size_of::<T>(),
+            std::mem::size_of::<*mut c_void>(),
+            "glow: symbols must be loaded as pointers"
+        );
+        let c_name = CString::new(name).unwrap();
+        let pointer = dlsym(self.0, c_name.as_ptr());
+        if pointer.is_null() {
+            return Err(format!("{} is missing from the library", name));
+        }
+        Ok(std::mem::transmute_copy(&pointer))
+    }
+}
+
+impl Drop for Library {
+    fn drop(&mut self) {
+        unsafe {
+            dlclose(self.0);
+        }
+    }
+}
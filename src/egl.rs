@@ -0,0 +1,415 @@
+use crate::dl::Library;
+use crate::{Context, ContextCreationError};
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_void};
+use std::ptr;
+
+type EGLDisplay = *mut c_void;
+type EGLConfig = *mut c_void;
+type EGLContext = *mut c_void;
+type EGLSurface = *mut c_void;
+type EGLint = i32;
+type EGLenum = u32;
+type EGLBoolean = u32;
+
+const EGL_FALSE: EGLBoolean = 0;
+const EGL_TRUE: EGLint = 1;
+const EGL_NONE: EGLint = 0x3038;
+const EGL_EXTENSIONS: EGLint = 0x3055;
+const EGL_ALPHA_SIZE: EGLint = 0x3021;
+const EGL_BLUE_SIZE: EGLint = 0x3022;
+const EGL_GREEN_SIZE: EGLint = 0x3023;
+const EGL_RED_SIZE: EGLint = 0x3024;
+const EGL_SURFACE_TYPE: EGLint = 0x3033;
+const EGL_RENDERABLE_TYPE: EGLint = 0x3040;
+const EGL_WIDTH: EGLint = 0x3057;
+const EGL_HEIGHT: EGLint = 0x3056;
+const EGL_PBUFFER_BIT: EGLint = 0x0001;
+const EGL_OPENGL_ES2_BIT: EGLint = 0x0004;
+const EGL_OPENGL_BIT: EGLint = 0x0008;
+const EGL_OPENGL_ES3_BIT: EGLint = 0x0040;
+const EGL_OPENGL_ES_API: EGLenum = 0x30A0;
+const EGL_OPENGL_API: EGLenum = 0x30A2;
+const EGL_CONTEXT_CLIENT_VERSION: EGLint = 0x3098;
+const EGL_CONTEXT_MAJOR_VERSION: EGLint = 0x3098;
+const EGL_CONTEXT_MINOR_VERSION: EGLint = 0x30FB;
+const EGL_CONTEXT_FLAGS_KHR: EGLint = 0x30FC;
+const EGL_CONTEXT_OPENGL_PROFILE_MASK: EGLint = 0x30FD;
+const EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT: EGLint = 0x0001;
+const EGL_CONTEXT_OPENGL_DEBUG_BIT_KHR: EGLint = 0x0001;
+const EGL_CONTEXT_OPENGL_DEBUG: EGLint = 0x31B0;
+const EGL_PLATFORM_SURFACELESS_MESA: EGLenum = 0x31DD;
+
+/// The client API of a context created by [`Context::new_headless_egl`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ContextApi {
+    /// Desktop OpenGL. Versions 3.2 and above request a core profile.
+    OpenGl,
+    /// OpenGL ES.
+    OpenGlEs,
+}
+
+/// The kind of context to create with [`Context::new_headless_egl`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ContextRequest {
+    pub api: ContextApi,
+    /// The major and minor version, e.g. `(3, 3)`.
+    pub version: (u8, u8),
+    /// Whether to request a debug context.
+    pub debug: bool,
+}
+
+impl Default for ContextRequest {
+    fn default() -> Self {
+        ContextRequest {
+            api: ContextApi::OpenGlEs,
+            version: (3, 0),
+            debug: false,
+        }
+    }
+}
+
+struct EglFns {
+    get_proc_address: unsafe extern "C" fn(*const c_char) -> *const c_void,
+    get_error: unsafe extern "C" fn() -> EGLint,
+    query_string: unsafe extern "C" fn(EGLDisplay, EGLint) -> *const c_char,
+    get_display: unsafe extern "C" fn(*mut c_void) -> EGLDisplay,
+    initialize: unsafe extern "C" fn(EGLDisplay, *mut EGLint, *mut EGLint) -> EGLBoolean,
+    terminate: unsafe extern "C" fn(EGLDisplay) -> EGLBoolean,
+    bind_api: unsafe extern "C" fn(EGLenum) -> EGLBoolean,
+    choose_config: unsafe extern "C" fn(
+        EGLDisplay,
+        *const EGLint,
+        *mut EGLConfig,
+        EGLint,
+        *mut EGLint,
+    ) -> EGLBoolean,
+    create_context:
+        unsafe extern "C" fn(EGLDisplay, EGLConfig, EGLContext, *const EGLint) -> EGLContext,
+    destroy_context: unsafe extern "C" fn(EGLDisplay, EGLContext) -> EGLBoolean,
+    create_pbuffer_surface:
+        unsafe extern "C" fn(EGLDisplay, EGLConfig, *const EGLint) -> EGLSurface,
+    destroy_surface: unsafe extern "C" fn(EGLDisplay, EGLSurface) -> EGLBoolean,
+    make_current:
+        unsafe extern "C" fn(EGLDisplay, EGLSurface, EGLSurface, EGLContext) -> EGLBoolean,
+}
+
+impl EglFns {
+    unsafe fn load(library: &Library) -> Result<Self, String> {
+        Ok(EglFns {
+            get_proc_address: library.symbol("eglGetProcAddress")?,
+            get_error: library.symbol("eglGetError")?,
+            query_string: library.symbol("eglQueryString")?,
+            get_display: library.symbol("eglGetDisplay")?,
+            initialize: library.symbol("eglInitialize")?,
+            terminate: library.symbol("eglTerminate")?,
+            bind_api: library.symbol("eglBindAPI")?,
+            choose_config: library.symbol("eglChooseConfig")?,
+            create_context: library.symbol("eglCreateContext")?,
+            destroy_context: library.symbol("eglDestroyContext")?,
+            create_pbuffer_surface: library.symbol("eglCreatePbufferSurface")?,
+            destroy_surface: library.symbol("eglDestroySurface")?,
+            make_current: library.symbol("eglMakeCurrent")?,
+        })
+    }
+
+    unsafe fn error(&self, function: &str) -> String {
+        format!(
+            "{} failed with EGL error 0x{:X}",
+            function,
+            (self.get_error)()
+        )
+    }
+
+    unsafe fn extensions(&self, display: EGLDisplay) -> String {
+        let extensions = (self.query_string)(display, EGL_EXTENSIONS);
+        if extensions.is_null() {
+            String::new()
+        } else {
+            CStr::from_ptr(extensions).to_string_lossy().into_owned()
+        }
+    }
+}
+
+fn has_extension(extensions: &str, name: &str) -> bool {
+    extensions
+        .split_whitespace()
+        .any(|extension| extension == name)
+}
+
+/// A [`Context`] created by [`Context::new_headless_egl`], together with the EGL display,
+/// context and surface behind it. Dereferences to the [`Context`].
+///
+/// Dropping it drops the [`Context`] first, then releases the EGL context and unloads `libEGL`.
+pub struct HeadlessEgl {
+    // Declared first so it's dropped while the EGL context still exists
+    context: Context,
+    egl: EglState,
+}
+
+impl HeadlessEgl {
+    /// Whether the context is current without a surface, rather than with a 1x1 pbuffer.
+    pub fn is_surfaceless(&self) -> bool {
+        self.egl.surface.is_null()
+    }
+}
+
+impl std::ops::Deref for HeadlessEgl {
+    type Target = Context;
+
+    fn deref(&self) -> &Context {
+        &self.context
+    }
+}
+
+impl std::ops::DerefMut for HeadlessEgl {
+    fn deref_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl std::fmt::Debug for HeadlessEgl {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("HeadlessEgl")
+            .field("display", &self.egl.display)
+            .field("context", &self.egl.context)
+            .field("surface", &self.egl.surface)
+            .finish()
+    }
+}
+
+/// The EGL display, context and surface, which are released when dropped.
+struct EglState {
+    fns: EglFns,
+    display: EGLDisplay,
+    context: EGLContext,
+    surface: EGLSurface,
+    _library: Library,
+}
+
+impl EglState {
+    unsafe fn open() -> Result<Self, String> {
+        let library = Library::open(&["libEGL.so.1", "libEGL.so"])?;
+        Ok(EglState {
+            fns: EglFns::load(&library)?,
+            display: ptr::null_mut(),
+            context: ptr::null_mut(),
+            surface: ptr::null_mut(),
+            _library: library,
+        })
+    }
+
+    unsafe fn create(&mut self, request: &ContextRequest) -> Result<(), String> {
+        let fns = &self.fns;
+        let client_extensions = fns.extensions(ptr::null_mut());
+        let get_platform_display =
+            (fns.get_proc_address)(b"eglGetPlatformDisplayEXT\0".as_ptr() as *const c_char);
+        let surfaceless = has_extension(&client_extensions, "EGL_MESA_platform_surfaceless")
+            && !get_platform_display.is_null();
+
+        self.display = if surfaceless {
+            let get_platform_display: unsafe extern "C" fn(
+                EGLenum,
+                *mut c_void,
+                *const EGLint,
+            ) -> EGLDisplay = std::mem::transmute(get_platform_display);
+            get_platform_display(EGL_PLATFORM_SURFACELESS_MESA, ptr::null_mut(), ptr::null())
+        } else {
+            (fns.get_display)(ptr::null_mut())
+        };
+        if self.display.is_null() {
+            return Err(fns.error("eglGetDisplay"));
+        }
+        let (mut major, mut minor) = (0, 0);
+        if (fns.initialize)(self.display, &mut major, &mut minor) == EGL_FALSE {
+            // There's nothing to terminate if initialization failed
+            self.display = ptr::null_mut();
+            return Err(fns.error("eglInitialize"));
+        }
+        let egl_1_5 = (major, minor) >= (1, 5);
+        let display_extensions = fns.extensions(self.display);
+        let create_context =
+            egl_1_5 || has_extension(&display_extensions, "EGL_KHR_create_context");
+        let use_pbuffer = !has_extension(&display_extensions, "EGL_KHR_surfaceless_context");
+
+        let (api, renderable_type) = match request.api {
+            ContextApi::OpenGl => (EGL_OPENGL_API, EGL_OPENGL_BIT),
+            ContextApi::OpenGlEs if request.version.0 >= 3 => {
+                (EGL_OPENGL_ES_API, EGL_OPENGL_ES3_BIT)
+            }
+            ContextApi::OpenGlEs => (EGL_OPENGL_ES_API, EGL_OPENGL_ES2_BIT),
+        };
+        if (fns.bind_api)(api) == EGL_FALSE {
+            return Err(fns.error("eglBindAPI"));
+        }
+
+        let config_attributes = [
+            EGL_RED_SIZE,
+            8,
+            EGL_GREEN_SIZE,
+            8,
+            EGL_BLUE_SIZE,
+            8,
+            EGL_ALPHA_SIZE,
+            8,
+            EGL_RENDERABLE_TYPE,
+            renderable_type,
+            EGL_SURFACE_TYPE,
+            if use_pbuffer { EGL_PBUFFER_BIT } else { 0 },
+            EGL_NONE,
+        ];
+        let mut config = ptr::null_mut();
+        let mut config_count = 0;
+        if (fns.choose_config)(
+            self.display,
+            config_attributes.as_ptr(),
+            &mut config,
+            1,
+            &mut config_count,
+        ) == EGL_FALSE
+        {
+            return Err(fns.error("eglChooseConfig"));
+        }
+        if config_count == 0 {
+            return Err(format!("No EGL config supports {:?}", request.api));
+        }
+
+        let mut context_attributes = Vec::new();
+        let (version_major, version_minor) =
+            (request.version.0 as EGLint, request.version.1 as EGLint);
+        if create_context {
+            context_attributes.extend([
+                EGL_CONTEXT_MAJOR_VERSION,
+                version_major,
+                EGL_CONTEXT_MINOR_VERSION,
+                version_minor,
+            ]);
+            if request.api == ContextApi::OpenGl && request.version >= (3, 2) {
+                context_attributes.extend([
+                    EGL_CONTEXT_OPENGL_PROFILE_MASK,
+                    EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT,
+                ]);
+            }
+            if request.debug && egl_1_5 {
+                context_attributes.extend([EGL_CONTEXT_OPENGL_DEBUG, EGL_TRUE]);
+            } else if request.debug {
+                context_attributes
+                    .extend([EGL_CONTEXT_FLAGS_KHR, EGL_CONTEXT_OPENGL_DEBUG_BIT_KHR]);
+            }
+        } else if request.debug {
+            return Err("Debug contexts need EGL 1.5 or EGL_KHR_create_context".to_string());
+        } else if request.api == ContextApi::OpenGlEs {
+            context_attributes.extend([EGL_CONTEXT_CLIENT_VERSION, version_major]);
+        }
+        context_attributes.push(EGL_NONE);
+
+        self.context = (fns.create_context)(
+            self.display,
+            config,
+            ptr::null_mut(),
+            context_attributes.as_ptr(),
+        );
+        if self.context.is_null() {
+            return Err(fns.error("eglCreateContext"));
+        }
+        if use_pbuffer {
+            let surface_attributes = [EGL_WIDTH, 1, EGL_HEIGHT, 1, EGL_NONE];
+            self.surface =
+                (fns.create_pbuffer_surface)(self.display, config, surface_attributes.as_ptr());
+            if self.surface.is_null() {
+                return Err(fns.error("eglCreatePbufferSurface"));
+            }
+        }
+        if (fns.make_current)(self.display, self.surface, self.surface, self.context) == EGL_FALSE {
+            return Err(fns.error("eglMakeCurrent"));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for EglState {
+    fn drop(&mut self) {
+        if self.display.is_null() {
+            return;
+        }
+        let fns = &self.fns;
+        unsafe {
+            (fns.make_current)(
+                self.display,
+                ptr::null_mut(),
+                ptr::null_mut(),
+                ptr::null_mut(),
+            );
+            if !self.surface.is_null() {
+                (fns.destroy_surface)(self.display, self.surface);
+            }
+            if !self.context.is_null() {
+                (fns.destroy_context)(self.display, self.context);
+            }
+            (fns.terminate)(self.display);
+        }
+    }
+}
+
+impl Context {
+    /// Creates a context without a window or display server through `libEGL`, and makes it
+    /// current on this thread.
+    ///
+    /// The `EGL_MESA_platform_surfaceless` platform is used when available, and the default
+    /// display otherwise. The context is made current without a surface if
+    /// `EGL_KHR_surfaceless_context` is supported, or with a 1x1 pbuffer otherwise, so render to
+    /// a framebuffer object rather than the default framebuffer. With Mesa this works on machines
+    /// without a GPU by setting `LIBGL_ALWAYS_SOFTWARE=1` to select llvmpipe.
+    ///
+    /// # Safety
+    ///
+    /// The context must only be used on this thread, and must stay current while it's used.
+    pub unsafe fn new_headless_egl(
+        request: ContextRequest,
+    ) -> Result<HeadlessEgl, ContextCreationError> {
+        let mut egl = EglState::open().map_err(ContextCreationError::Platform)?;
+        egl.create(&request)
+            .map_err(ContextCreationError::Platform)?;
+        let get_proc_address = egl.fns.get_proc_address;
+        let context =
+            Context::try_from_loader_function_cstr(|name| get_proc_address(name.as_ptr()), None)?;
+        Ok(HeadlessEgl { context, egl })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_extension() {
+        let extensions = "EGL_EXT_platform_base EGL_MESA_platform_surfaceless_extra";
+        assert!(has_extension(extensions, "EGL_EXT_platform_base"));
+        assert!(!has_extension(extensions, "EGL_MESA_platform_surfaceless"));
+        assert!(!has_extension("", "EGL_EXT_platform_base"));
+    }
+
+    #[cfg(feature = "testing")]
+    #[test]
+    fn test_render() {
+        use crate::{HasContext, COLOR_BUFFER_BIT, SCISSOR_TEST};
+
+        let gl = unsafe { Context::new_headless_egl(ContextRequest::default()) }.unwrap();
+        let image = unsafe {
+            crate::testing::render_to_image(&*gl, 4, 4, |gl| {
+                gl.clear_color(1.0, 0.0, 0.0, 1.0);
+                gl.clear(COLOR_BUFFER_BIT);
+                // The bottom left quarter is green
+                gl.enable(SCISSOR_TEST);
+                gl.scissor(0, 0, 2, 2);
+                gl.clear_color(0.0, 1.0, 0.0, 1.0);
+                gl.clear(COLOR_BUFFER_BIT);
+                gl.disable(SCISSOR_TEST);
+            })
+        }
+        .unwrap();
+        assert_eq!(image.pixel(0, 3), [0, 255, 0, 255]);
+        assert_eq!(image.pixel(3, 3), [255, 0, 0, 255]);
+        assert_eq!(image.pixel(0, 0), [255, 0, 0, 255]);
+    }
+}
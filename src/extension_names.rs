@@ -0,0 +1,3390 @@
+//! Names of the extensions in the OpenGL, OpenGL ES and WebGL registries.
+//!
+//! Generated from `gl.xml` by `generate-native.sh`, do not edit by hand.
+
+/// An extension from the OpenGL, OpenGL ES or WebGL registry.
+///
+/// WebGL extensions that are also defined for OpenGL ES use the same variant, e.g.
+/// `OES_texture_float` is reported as [`Extension::OesTextureFloat`].
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[repr(u16)]
+pub enum Extension {
+    /// `EXT_depth_clamp`
+    ExtDepthClamp,
+    /// `EXT_disjoint_timer_query_webgl2`
+    ExtDisjointTimerQueryWebgl2,
+    /// `EXT_frag_depth`
+    ExtFragDepth,
+    /// `GL_3DFX_multisample`
+    Gl3dfxMultisample,
+    /// `GL_3DFX_tbuffer`
+    Gl3dfxTbuffer,
+    /// `GL_3DFX_texture_compression_FXT1`
+    Gl3dfxTextureCompressionFxt1,
+    /// `GL_AMD_blend_minmax_factor`
+    AmdBlendMinmaxFactor,
+    /// `GL_AMD_compressed_3DC_texture`
+    AmdCompressed3dcTexture,
+    /// `GL_AMD_compressed_ATC_texture`
+    AmdCompressedAtcTexture,
+    /// `GL_AMD_conservative_depth`
+    AmdConservativeDepth,
+    /// `GL_AMD_debug_output`
+    AmdDebugOutput,
+    /// `GL_AMD_depth_clamp_separate`
+    AmdDepthClampSeparate,
+    /// `GL_AMD_draw_buffers_blend`
+    AmdDrawBuffersBlend,
+    /// `GL_AMD_framebuffer_multisample_advanced`
+    AmdFramebufferMultisampleAdvanced,
+    /// `GL_AMD_framebuffer_sample_positions`
+    AmdFramebufferSamplePositions,
+    /// `GL_AMD_gcn_shader`
+    AmdGcnShader,
+    /// `GL_AMD_gpu_shader_half_float`
+    AmdGpuShaderHalfFloat,
+    /// `GL_AMD_gpu_shader_int16`
+    AmdGpuShaderInt16,
+    /// `GL_AMD_gpu_shader_int64`
+    AmdGpuShaderInt64,
+    /// `GL_AMD_interleaved_elements`
+    AmdInterleavedElements,
+    /// `GL_AMD_multi_draw_indirect`
+    AmdMultiDrawIndirect,
+    /// `GL_AMD_name_gen_delete`
+    AmdNameGenDelete,
+    /// `GL_AMD_occlusion_query_event`
+    AmdOcclusionQueryEvent,
+    /// `GL_AMD_performance_monitor`
+    AmdPerformanceMonitor,
+    /// `GL_AMD_pinned_memory`
+    AmdPinnedMemory,
+    /// `GL_AMD_program_binary_Z400`
+    AmdProgramBinaryZ400,
+    /// `GL_AMD_query_buffer_object`
+    AmdQueryBufferObject,
+    /// `GL_AMD_sample_positions`
+    AmdSamplePositions,
+    /// `GL_AMD_seamless_cubemap_per_texture`
+    AmdSeamlessCubemapPerTexture,
+    /// `GL_AMD_shader_atomic_counter_ops`
+    AmdShaderAtomicCounterOps,
+    /// `GL_AMD_shader_ballot`
+    AmdShaderBallot,
+    /// `GL_AMD_shader_explicit_vertex_parameter`
+    AmdShaderExplicitVertexParameter,
+    /// `GL_AMD_shader_gpu_shader_half_float_fetch`
+    AmdShaderGpuShaderHalfFloatFetch,
+    /// `GL_AMD_shader_image_load_store_lod`
+    AmdShaderImageLoadStoreLod,
+    /// `GL_AMD_shader_stencil_export`
+    AmdShaderStencilExport,
+    /// `GL_AMD_shader_trinary_minmax`
+    AmdShaderTrinaryMinmax,
+    /// `GL_AMD_sparse_texture`
+    AmdSparseTexture,
+    /// `GL_AMD_stencil_operation_extended`
+    AmdStencilOperationExtended,
+    /// `GL_AMD_texture_gather_bias_lod`
+    AmdTextureGatherBiasLod,
+    /// `GL_AMD_texture_texture4`
+    AmdTextureTexture4,
+    /// `GL_AMD_transform_feedback3_lines_triangles`
+    AmdTransformFeedback3LinesTriangles,
+    /// `GL_AMD_transform_feedback4`
+    AmdTransformFeedback4,
+    /// `GL_AMD_vertex_shader_layer`
+    AmdVertexShaderLayer,
+    /// `GL_AMD_vertex_shader_tessellator`
+    AmdVertexShaderTessellator,
+    /// `GL_AMD_vertex_shader_viewport_index`
+    AmdVertexShaderViewportIndex,
+    /// `GL_ANDROID_extension_pack_es31a`
+    AndroidExtensionPackEs31a,
+    /// `GL_ANGLE_depth_texture`
+    AngleDepthTexture,
+    /// `GL_ANGLE_framebuffer_blit`
+    AngleFramebufferBlit,
+    /// `GL_ANGLE_framebuffer_multisample`
+    AngleFramebufferMultisample,
+    /// `GL_ANGLE_instanced_arrays`
+    AngleInstancedArrays,
+    /// `GL_ANGLE_pack_reverse_row_order`
+    AnglePackReverseRowOrder,
+    /// `GL_ANGLE_program_binary`
+    AngleProgramBinary,
+    /// `GL_ANGLE_texture_compression_dxt3`
+    AngleTextureCompressionDxt3,
+    /// `GL_ANGLE_texture_compression_dxt5`
+    AngleTextureCompressionDxt5,
+    /// `GL_ANGLE_texture_usage`
+    AngleTextureUsage,
+    /// `GL_ANGLE_translated_shader_source`
+    AngleTranslatedShaderSource,
+    /// `GL_APPLE_aux_depth_stencil`
+    AppleAuxDepthStencil,
+    /// `GL_APPLE_client_storage`
+    AppleClientStorage,
+    /// `GL_APPLE_clip_distance`
+    AppleClipDistance,
+    /// `GL_APPLE_color_buffer_packed_float`
+    AppleColorBufferPackedFloat,
+    /// `GL_APPLE_copy_texture_levels`
+    AppleCopyTextureLevels,
+    /// `GL_APPLE_element_array`
+    AppleElementArray,
+    /// `GL_APPLE_fence`
+    AppleFence,
+    /// `GL_APPLE_float_pixels`
+    AppleFloatPixels,
+    /// `GL_APPLE_flush_buffer_range`
+    AppleFlushBufferRange,
+    /// `GL_APPLE_framebuffer_multisample`
+    AppleFramebufferMultisample,
+    /// `GL_APPLE_object_purgeable`
+    AppleObjectPurgeable,
+    /// `GL_APPLE_rgb_422`
+    AppleRgb422,
+    /// `GL_APPLE_row_bytes`
+    AppleRowBytes,
+    /// `GL_APPLE_specular_vector`
+    AppleSpecularVector,
+    /// `GL_APPLE_sync`
+    AppleSync,
+    /// `GL_APPLE_texture_2D_limited_npot`
+    AppleTexture2dLimitedNpot,
+    /// `GL_APPLE_texture_format_BGRA8888`
+    AppleTextureFormatBgra8888,
+    /// `GL_APPLE_texture_max_level`
+    AppleTextureMaxLevel,
+    /// `GL_APPLE_texture_packed_float`
+    AppleTexturePackedFloat,
+    /// `GL_APPLE_texture_range`
+    AppleTextureRange,
+    /// `GL_APPLE_transform_hint`
+    AppleTransformHint,
+    /// `GL_APPLE_vertex_array_object`
+    AppleVertexArrayObject,
+    /// `GL_APPLE_vertex_array_range`
+    AppleVertexArrayRange,
+    /// `GL_APPLE_vertex_program_evaluators`
+    AppleVertexProgramEvaluators,
+    /// `GL_APPLE_ycbcr_422`
+    AppleYcbcr422,
+    /// `GL_ARB_ES2_compatibility`
+    ArbEs2Compatibility,
+    /// `GL_ARB_ES3_1_compatibility`
+    ArbEs31Compatibility,
+    /// `GL_ARB_ES3_2_compatibility`
+    ArbEs32Compatibility,
+    /// `GL_ARB_ES3_compatibility`
+    ArbEs3Compatibility,
+    /// `GL_ARB_arrays_of_arrays`
+    ArbArraysOfArrays,
+    /// `GL_ARB_base_instance`
+    ArbBaseInstance,
+    /// `GL_ARB_bindless_texture`
+    ArbBindlessTexture,
+    /// `GL_ARB_blend_func_extended`
+    ArbBlendFuncExtended,
+    /// `GL_ARB_buffer_storage`
+    ArbBufferStorage,
+    /// `GL_ARB_cl_event`
+    ArbClEvent,
+    /// `GL_ARB_clear_buffer_object`
+    ArbClearBufferObject,
+    /// `GL_ARB_clear_texture`
+    ArbClearTexture,
+    /// `GL_ARB_clip_control`
+    ArbClipControl,
+    /// `GL_ARB_color_buffer_float`
+    ArbColorBufferFloat,
+    /// `GL_ARB_compatibility`
+    ArbCompatibility,
+    /// `GL_ARB_compressed_texture_pixel_storage`
+    ArbCompressedTexturePixelStorage,
+    /// `GL_ARB_compute_shader`
+    ArbComputeShader,
+    /// `GL_ARB_compute_variable_group_size`
+    ArbComputeVariableGroupSize,
+    /// `GL_ARB_conditional_render_inverted`
+    ArbConditionalRenderInverted,
+    /// `GL_ARB_conservative_depth`
+    ArbConservativeDepth,
+    /// `GL_ARB_copy_buffer`
+    ArbCopyBuffer,
+    /// `GL_ARB_copy_image`
+    ArbCopyImage,
+    /// `GL_ARB_cull_distance`
+    ArbCullDistance,
+    /// `GL_ARB_debug_output`
+    ArbDebugOutput,
+    /// `GL_ARB_depth_buffer_float`
+    ArbDepthBufferFloat,
+    /// `GL_ARB_depth_clamp`
+    ArbDepthClamp,
+    /// `GL_ARB_depth_texture`
+    ArbDepthTexture,
+    /// `GL_ARB_derivative_control`
+    ArbDerivativeControl,
+    /// `GL_ARB_direct_state_access`
+    ArbDirectStateAccess,
+    /// `GL_ARB_draw_buffers`
+    ArbDrawBuffers,
+    /// `GL_ARB_draw_buffers_blend`
+    ArbDrawBuffersBlend,
+    /// `GL_ARB_draw_elements_base_vertex`
+    ArbDrawElementsBaseVertex,
+    /// `GL_ARB_draw_indirect`
+    ArbDrawIndirect,
+    /// `GL_ARB_draw_instanced`
+    ArbDrawInstanced,
+    /// `GL_ARB_enhanced_layouts`
+    ArbEnhancedLayouts,
+    /// `GL_ARB_explicit_attrib_location`
+    ArbExplicitAttribLocation,
+    /// `GL_ARB_explicit_uniform_location`
+    ArbExplicitUniformLocation,
+    /// `GL_ARB_fragment_coord_conventions`
+    ArbFragmentCoordConventions,
+    /// `GL_ARB_fragment_layer_viewport`
+    ArbFragmentLayerViewport,
+    /// `GL_ARB_fragment_program`
+    ArbFragmentProgram,
+    /// `GL_ARB_fragment_program_shadow`
+    ArbFragmentProgramShadow,
+    /// `GL_ARB_fragment_shader`
+    ArbFragmentShader,
+    /// `GL_ARB_fragment_shader_interlock`
+    ArbFragmentShaderInterlock,
+    /// `GL_ARB_framebuffer_no_attachments`
+    ArbFramebufferNoAttachments,
+    /// `GL_ARB_framebuffer_object`
+    ArbFramebufferObject,
+    /// `GL_ARB_framebuffer_sRGB`
+    ArbFramebufferSrgb,
+    /// `GL_ARB_geometry_shader4`
+    ArbGeometryShader4,
+    /// `GL_ARB_get_program_binary`
+    ArbGetProgramBinary,
+    /// `GL_ARB_get_texture_sub_image`
+    ArbGetTextureSubImage,
+    /// `GL_ARB_gl_spirv`
+    ArbGlSpirv,
+    /// `GL_ARB_gpu_shader5`
+    ArbGpuShader5,
+    /// `GL_ARB_gpu_shader_fp64`
+    ArbGpuShaderFp64,
+    /// `GL_ARB_gpu_shader_int64`
+    ArbGpuShaderInt64,
+    /// `GL_ARB_half_float_pixel`
+    ArbHalfFloatPixel,
+    /// `GL_ARB_half_float_vertex`
+    ArbHalfFloatVertex,
+    /// `GL_ARB_imaging`
+    ArbImaging,
+    /// `GL_ARB_indirect_parameters`
+    ArbIndirectParameters,
+    /// `GL_ARB_instanced_arrays`
+    ArbInstancedArrays,
+    /// `GL_ARB_internalformat_query`
+    ArbInternalformatQuery,
+    /// `GL_ARB_internalformat_query2`
+    ArbInternalformatQuery2,
+    /// `GL_ARB_invalidate_subdata`
+    ArbInvalidateSubdata,
+    /// `GL_ARB_map_buffer_alignment`
+    ArbMapBufferAlignment,
+    /// `GL_ARB_map_buffer_range`
+    ArbMapBufferRange,
+    /// `GL_ARB_matrix_palette`
+    ArbMatrixPalette,
+    /// `GL_ARB_multi_bind`
+    ArbMultiBind,
+    /// `GL_ARB_multi_draw_indirect`
+    ArbMultiDrawIndirect,
+    /// `GL_ARB_multisample`
+    ArbMultisample,
+    /// `GL_ARB_multitexture`
+    ArbMultitexture,
+    /// `GL_ARB_occlusion_query`
+    ArbOcclusionQuery,
+    /// `GL_ARB_occlusion_query2`
+    ArbOcclusionQuery2,
+    /// `GL_ARB_parallel_shader_compile`
+    ArbParallelShaderCompile,
+    /// `GL_ARB_pipeline_statistics_query`
+    ArbPipelineStatisticsQuery,
+    /// `GL_ARB_pixel_buffer_object`
+    ArbPixelBufferObject,
+    /// `GL_ARB_point_parameters`
+    ArbPointParameters,
+    /// `GL_ARB_point_sprite`
+    ArbPointSprite,
+    /// `GL_ARB_polygon_offset_clamp`
+    ArbPolygonOffsetClamp,
+    /// `GL_ARB_post_depth_coverage`
+    ArbPostDepthCoverage,
+    /// `GL_ARB_program_interface_query`
+    ArbProgramInterfaceQuery,
+    /// `GL_ARB_provoking_vertex`
+    ArbProvokingVertex,
+    /// `GL_ARB_query_buffer_object`
+    ArbQueryBufferObject,
+    /// `GL_ARB_robust_buffer_access_behavior`
+    ArbRobustBufferAccessBehavior,
+    /// `GL_ARB_robustness`
+    ArbRobustness,
+    /// `GL_ARB_robustness_isolation`
+    ArbRobustnessIsolation,
+    /// `GL_ARB_sample_locations`
+    ArbSampleLocations,
+    /// `GL_ARB_sample_shading`
+    ArbSampleShading,
+    /// `GL_ARB_sampler_objects`
+    ArbSamplerObjects,
+    /// `GL_ARB_seamless_cube_map`
+    ArbSeamlessCubeMap,
+    /// `GL_ARB_seamless_cubemap_per_texture`
+    ArbSeamlessCubemapPerTexture,
+    /// `GL_ARB_separate_shader_objects`
+    ArbSeparateShaderObjects,
+    /// `GL_ARB_shader_atomic_counter_ops`
+    ArbShaderAtomicCounterOps,
+    /// `GL_ARB_shader_atomic_counters`
+    ArbShaderAtomicCounters,
+    /// `GL_ARB_shader_ballot`
+    ArbShaderBallot,
+    /// `GL_ARB_shader_bit_encoding`
+    ArbShaderBitEncoding,
+    /// `GL_ARB_shader_clock`
+    ArbShaderClock,
+    /// `GL_ARB_shader_draw_parameters`
+    ArbShaderDrawParameters,
+    /// `GL_ARB_shader_group_vote`
+    ArbShaderGroupVote,
+    /// `GL_ARB_shader_image_load_store`
+    ArbShaderImageLoadStore,
+    /// `GL_ARB_shader_image_size`
+    ArbShaderImageSize,
+    /// `GL_ARB_shader_objects`
+    ArbShaderObjects,
+    /// `GL_ARB_shader_precision`
+    ArbShaderPrecision,
+    /// `GL_ARB_shader_stencil_export`
+    ArbShaderStencilExport,
+    /// `GL_ARB_shader_storage_buffer_object`
+    ArbShaderStorageBufferObject,
+    /// `GL_ARB_shader_subroutine`
+    ArbShaderSubroutine,
+    /// `GL_ARB_shader_texture_image_samples`
+    ArbShaderTextureImageSamples,
+    /// `GL_ARB_shader_texture_lod`
+    ArbShaderTextureLod,
+    /// `GL_ARB_shader_viewport_layer_array`
+    ArbShaderViewportLayerArray,
+    /// `GL_ARB_shading_language_100`
+    ArbShadingLanguage100,
+    /// `GL_ARB_shading_language_420pack`
+    ArbShadingLanguage420pack,
+    /// `GL_ARB_shading_language_include`
+    ArbShadingLanguageInclude,
+    /// `GL_ARB_shading_language_packing`
+    ArbShadingLanguagePacking,
+    /// `GL_ARB_shadow`
+    ArbShadow,
+    /// `GL_ARB_shadow_ambient`
+    ArbShadowAmbient,
+    /// `GL_ARB_sparse_buffer`
+    ArbSparseBuffer,
+    /// `GL_ARB_sparse_texture`
+    ArbSparseTexture,
+    /// `GL_ARB_sparse_texture2`
+    ArbSparseTexture2,
+    /// `GL_ARB_sparse_texture_clamp`
+    ArbSparseTextureClamp,
+    /// `GL_ARB_spirv_extensions`
+    ArbSpirvExtensions,
+    /// `GL_ARB_stencil_texturing`
+    ArbStencilTexturing,
+    /// `GL_ARB_sync`
+    ArbSync,
+    /// `GL_ARB_tessellation_shader`
+    ArbTessellationShader,
+    /// `GL_ARB_texture_barrier`
+    ArbTextureBarrier,
+    /// `GL_ARB_texture_border_clamp`
+    ArbTextureBorderClamp,
+    /// `GL_ARB_texture_buffer_object`
+    ArbTextureBufferObject,
+    /// `GL_ARB_texture_buffer_object_rgb32`
+    ArbTextureBufferObjectRgb32,
+    /// `GL_ARB_texture_buffer_range`
+    ArbTextureBufferRange,
+    /// `GL_ARB_texture_compression`
+    ArbTextureCompression,
+    /// `GL_ARB_texture_compression_bptc`
+    ArbTextureCompressionBptc,
+    /// `GL_ARB_texture_compression_rgtc`
+    ArbTextureCompressionRgtc,
+    /// `GL_ARB_texture_cube_map`
+    ArbTextureCubeMap,
+    /// `GL_ARB_texture_cube_map_array`
+    ArbTextureCubeMapArray,
+    /// `GL_ARB_texture_env_add`
+    ArbTextureEnvAdd,
+    /// `GL_ARB_texture_env_combine`
+    ArbTextureEnvCombine,
+    /// `GL_ARB_texture_env_crossbar`
+    ArbTextureEnvCrossbar,
+    /// `GL_ARB_texture_env_dot3`
+    ArbTextureEnvDot3,
+    /// `GL_ARB_texture_filter_anisotropic`
+    ArbTextureFilterAnisotropic,
+    /// `GL_ARB_texture_filter_minmax`
+    ArbTextureFilterMinmax,
+    /// `GL_ARB_texture_float`
+    ArbTextureFloat,
+    /// `GL_ARB_texture_gather`
+    ArbTextureGather,
+    /// `GL_ARB_texture_mirror_clamp_to_edge`
+    ArbTextureMirrorClampToEdge,
+    /// `GL_ARB_texture_mirrored_repeat`
+    ArbTextureMirroredRepeat,
+    /// `GL_ARB_texture_multisample`
+    ArbTextureMultisample,
+    /// `GL_ARB_texture_non_power_of_two`
+    ArbTextureNonPowerOfTwo,
+    /// `GL_ARB_texture_query_levels`
+    ArbTextureQueryLevels,
+    /// `GL_ARB_texture_query_lod`
+    ArbTextureQueryLod,
+    /// `GL_ARB_texture_rectangle`
+    ArbTextureRectangle,
+    /// `GL_ARB_texture_rg`
+    ArbTextureRg,
+    /// `GL_ARB_texture_rgb10_a2ui`
+    ArbTextureRgb10A2ui,
+    /// `GL_ARB_texture_stencil8`
+    ArbTextureStencil8,
+    /// `GL_ARB_texture_storage`
+    ArbTextureStorage,
+    /// `GL_ARB_texture_storage_multisample`
+    ArbTextureStorageMultisample,
+    /// `GL_ARB_texture_swizzle`
+    ArbTextureSwizzle,
+    /// `GL_ARB_texture_view`
+    ArbTextureView,
+    /// `GL_ARB_timer_query`
+    ArbTimerQuery,
+    /// `GL_ARB_transform_feedback2`
+    ArbTransformFeedback2,
+    /// `GL_ARB_transform_feedback3`
+    ArbTransformFeedback3,
+    /// `GL_ARB_transform_feedback_instanced`
+    ArbTransformFeedbackInstanced,
+    /// `GL_ARB_transform_feedback_overflow_query`
+    ArbTransformFeedbackOverflowQuery,
+    /// `GL_ARB_transpose_matrix`
+    ArbTransposeMatrix,
+    /// `GL_ARB_uniform_buffer_object`
+    ArbUniformBufferObject,
+    /// `GL_ARB_vertex_array_bgra`
+    ArbVertexArrayBgra,
+    /// `GL_ARB_vertex_array_object`
+    ArbVertexArrayObject,
+    /// `GL_ARB_vertex_attrib_64bit`
+    ArbVertexAttrib64bit,
+    /// `GL_ARB_vertex_attrib_binding`
+    ArbVertexAttribBinding,
+    /// `GL_ARB_vertex_blend`
+    ArbVertexBlend,
+    /// `GL_ARB_vertex_buffer_object`
+    ArbVertexBufferObject,
+    /// `GL_ARB_vertex_program`
+    ArbVertexProgram,
+    /// `GL_ARB_vertex_shader`
+    ArbVertexShader,
+    /// `GL_ARB_vertex_type_10f_11f_11f_rev`
+    ArbVertexType10f11f11fRev,
+    /// `GL_ARB_vertex_type_2_10_10_10_rev`
+    ArbVertexType2101010Rev,
+    /// `GL_ARB_viewport_array`
+    ArbViewportArray,
+    /// `GL_ARB_window_pos`
+    ArbWindowPos,
+    /// `GL_ARM_mali_program_binary`
+    ArmMaliProgramBinary,
+    /// `GL_ARM_mali_shader_binary`
+    ArmMaliShaderBinary,
+    /// `GL_ARM_rgba8`
+    ArmRgba8,
+    /// `GL_ARM_shader_framebuffer_fetch`
+    ArmShaderFramebufferFetch,
+    /// `GL_ARM_shader_framebuffer_fetch_depth_stencil`
+    ArmShaderFramebufferFetchDepthStencil,
+    /// `GL_ATI_draw_buffers`
+    AtiDrawBuffers,
+    /// `GL_ATI_element_array`
+    AtiElementArray,
+    /// `GL_ATI_envmap_bumpmap`
+    AtiEnvmapBumpmap,
+    /// `GL_ATI_fragment_shader`
+    AtiFragmentShader,
+    /// `GL_ATI_map_object_buffer`
+    AtiMapObjectBuffer,
+    /// `GL_ATI_meminfo`
+    AtiMeminfo,
+    /// `GL_ATI_pixel_format_float`
+    AtiPixelFormatFloat,
+    /// `GL_ATI_pn_triangles`
+    AtiPnTriangles,
+    /// `GL_ATI_separate_stencil`
+    AtiSeparateStencil,
+    /// `GL_ATI_text_fragment_shader`
+    AtiTextFragmentShader,
+    /// `GL_ATI_texture_env_combine3`
+    AtiTextureEnvCombine3,
+    /// `GL_ATI_texture_float`
+    AtiTextureFloat,
+    /// `GL_ATI_texture_mirror_once`
+    AtiTextureMirrorOnce,
+    /// `GL_ATI_vertex_array_object`
+    AtiVertexArrayObject,
+    /// `GL_ATI_vertex_attrib_array_object`
+    AtiVertexAttribArrayObject,
+    /// `GL_ATI_vertex_streams`
+    AtiVertexStreams,
+    /// `GL_DMP_program_binary`
+    DmpProgramBinary,
+    /// `GL_DMP_shader_binary`
+    DmpShaderBinary,
+    /// `GL_EXT_422_pixels`
+    Ext422Pixels,
+    /// `GL_EXT_EGL_image_array`
+    ExtEglImageArray,
+    /// `GL_EXT_EGL_image_storage`
+    ExtEglImageStorage,
+    /// `GL_EXT_YUV_target`
+    ExtYuvTarget,
+    /// `GL_EXT_abgr`
+    ExtAbgr,
+    /// `GL_EXT_base_instance`
+    ExtBaseInstance,
+    /// `GL_EXT_bgra`
+    ExtBgra,
+    /// `GL_EXT_bindable_uniform`
+    ExtBindableUniform,
+    /// `GL_EXT_blend_color`
+    ExtBlendColor,
+    /// `GL_EXT_blend_equation_separate`
+    ExtBlendEquationSeparate,
+    /// `GL_EXT_blend_func_extended`
+    ExtBlendFuncExtended,
+    /// `GL_EXT_blend_func_separate`
+    ExtBlendFuncSeparate,
+    /// `GL_EXT_blend_logic_op`
+    ExtBlendLogicOp,
+    /// `GL_EXT_blend_minmax`
+    ExtBlendMinmax,
+    /// `GL_EXT_blend_subtract`
+    ExtBlendSubtract,
+    /// `GL_EXT_buffer_storage`
+    ExtBufferStorage,
+    /// `GL_EXT_clear_texture`
+    ExtClearTexture,
+    /// `GL_EXT_clip_control`
+    ExtClipControl,
+    /// `GL_EXT_clip_cull_distance`
+    ExtClipCullDistance,
+    /// `GL_EXT_clip_volume_hint`
+    ExtClipVolumeHint,
+    /// `GL_EXT_cmyka`
+    ExtCmyka,
+    /// `GL_EXT_color_buffer_float`
+    ExtColorBufferFloat,
+    /// `GL_EXT_color_buffer_half_float`
+    ExtColorBufferHalfFloat,
+    /// `GL_EXT_color_subtable`
+    ExtColorSubtable,
+    /// `GL_EXT_compiled_vertex_array`
+    ExtCompiledVertexArray,
+    /// `GL_EXT_conservative_depth`
+    ExtConservativeDepth,
+    /// `GL_EXT_convolution`
+    ExtConvolution,
+    /// `GL_EXT_coordinate_frame`
+    ExtCoordinateFrame,
+    /// `GL_EXT_copy_image`
+    ExtCopyImage,
+    /// `GL_EXT_copy_texture`
+    ExtCopyTexture,
+    /// `GL_EXT_cull_vertex`
+    ExtCullVertex,
+    /// `GL_EXT_debug_label`
+    ExtDebugLabel,
+    /// `GL_EXT_debug_marker`
+    ExtDebugMarker,
+    /// `GL_EXT_depth_bounds_test`
+    ExtDepthBoundsTest,
+    /// `GL_EXT_direct_state_access`
+    ExtDirectStateAccess,
+    /// `GL_EXT_discard_framebuffer`
+    ExtDiscardFramebuffer,
+    /// `GL_EXT_disjoint_timer_query`
+    ExtDisjointTimerQuery,
+    /// `GL_EXT_draw_buffers`
+    ExtDrawBuffers,
+    /// `GL_EXT_draw_buffers2`
+    ExtDrawBuffers2,
+    /// `GL_EXT_draw_buffers_indexed`
+    ExtDrawBuffersIndexed,
+    /// `GL_EXT_draw_elements_base_vertex`
+    ExtDrawElementsBaseVertex,
+    /// `GL_EXT_draw_instanced`
+    ExtDrawInstanced,
+    /// `GL_EXT_draw_range_elements`
+    ExtDrawRangeElements,
+    /// `GL_EXT_draw_transform_feedback`
+    ExtDrawTransformFeedback,
+    /// `GL_EXT_external_buffer`
+    ExtExternalBuffer,
+    /// `GL_EXT_float_blend`
+    ExtFloatBlend,
+    /// `GL_EXT_fog_coord`
+    ExtFogCoord,
+    /// `GL_EXT_framebuffer_blit`
+    ExtFramebufferBlit,
+    /// `GL_EXT_framebuffer_multisample`
+    ExtFramebufferMultisample,
+    /// `GL_EXT_framebuffer_multisample_blit_scaled`
+    ExtFramebufferMultisampleBlitScaled,
+    /// `GL_EXT_framebuffer_object`
+    ExtFramebufferObject,
+    /// `GL_EXT_framebuffer_sRGB`
+    ExtFramebufferSrgb,
+    /// `GL_EXT_geometry_point_size`
+    ExtGeometryPointSize,
+    /// `GL_EXT_geometry_shader`
+    ExtGeometryShader,
+    /// `GL_EXT_geometry_shader4`
+    ExtGeometryShader4,
+    /// `GL_EXT_gpu_program_parameters`
+    ExtGpuProgramParameters,
+    /// `GL_EXT_gpu_shader4`
+    ExtGpuShader4,
+    /// `GL_EXT_gpu_shader5`
+    ExtGpuShader5,
+    /// `GL_EXT_histogram`
+    ExtHistogram,
+    /// `GL_EXT_index_array_formats`
+    ExtIndexArrayFormats,
+    /// `GL_EXT_index_func`
+    ExtIndexFunc,
+    /// `GL_EXT_index_material`
+    ExtIndexMaterial,
+    /// `GL_EXT_index_texture`
+    ExtIndexTexture,
+    /// `GL_EXT_instanced_arrays`
+    ExtInstancedArrays,
+    /// `GL_EXT_light_texture`
+    ExtLightTexture,
+    /// `GL_EXT_map_buffer_range`
+    ExtMapBufferRange,
+    /// `GL_EXT_memory_object`
+    ExtMemoryObject,
+    /// `GL_EXT_memory_object_fd`
+    ExtMemoryObjectFd,
+    /// `GL_EXT_memory_object_win32`
+    ExtMemoryObjectWin32,
+    /// `GL_EXT_misc_attribute`
+    ExtMiscAttribute,
+    /// `GL_EXT_multi_draw_arrays`
+    ExtMultiDrawArrays,
+    /// `GL_EXT_multi_draw_indirect`
+    ExtMultiDrawIndirect,
+    /// `GL_EXT_multisample`
+    ExtMultisample,
+    /// `GL_EXT_multisampled_compatibility`
+    ExtMultisampledCompatibility,
+    /// `GL_EXT_multisampled_render_to_texture`
+    ExtMultisampledRenderToTexture,
+    /// `GL_EXT_multiview_draw_buffers`
+    ExtMultiviewDrawBuffers,
+    /// `GL_EXT_occlusion_query_boolean`
+    ExtOcclusionQueryBoolean,
+    /// `GL_EXT_packed_depth_stencil`
+    ExtPackedDepthStencil,
+    /// `GL_EXT_packed_float`
+    ExtPackedFloat,
+    /// `GL_EXT_packed_pixels`
+    ExtPackedPixels,
+    /// `GL_EXT_paletted_texture`
+    ExtPalettedTexture,
+    /// `GL_EXT_pixel_buffer_object`
+    ExtPixelBufferObject,
+    /// `GL_EXT_pixel_transform`
+    ExtPixelTransform,
+    /// `GL_EXT_pixel_transform_color_table`
+    ExtPixelTransformColorTable,
+    /// `GL_EXT_point_parameters`
+    ExtPointParameters,
+    /// `GL_EXT_polygon_offset`
+    ExtPolygonOffset,
+    /// `GL_EXT_polygon_offset_clamp`
+    ExtPolygonOffsetClamp,
+    /// `GL_EXT_post_depth_coverage`
+    ExtPostDepthCoverage,
+    /// `GL_EXT_primitive_bounding_box`
+    ExtPrimitiveBoundingBox,
+    /// `GL_EXT_protected_textures`
+    ExtProtectedTextures,
+    /// `GL_EXT_provoking_vertex`
+    ExtProvokingVertex,
+    /// `GL_EXT_pvrtc_sRGB`
+    ExtPvrtcSrgb,
+    /// `GL_EXT_raster_multisample`
+    ExtRasterMultisample,
+    /// `GL_EXT_read_format_bgra`
+    ExtReadFormatBgra,
+    /// `GL_EXT_render_snorm`
+    ExtRenderSnorm,
+    /// `GL_EXT_rescale_normal`
+    ExtRescaleNormal,
+    /// `GL_EXT_robustness`
+    ExtRobustness,
+    /// `GL_EXT_sRGB`
+    ExtSrgb,
+    /// `GL_EXT_sRGB_write_control`
+    ExtSrgbWriteControl,
+    /// `GL_EXT_secondary_color`
+    ExtSecondaryColor,
+    /// `GL_EXT_semaphore`
+    ExtSemaphore,
+    /// `GL_EXT_semaphore_fd`
+    ExtSemaphoreFd,
+    /// `GL_EXT_semaphore_win32`
+    ExtSemaphoreWin32,
+    /// `GL_EXT_separate_shader_objects`
+    ExtSeparateShaderObjects,
+    /// `GL_EXT_separate_specular_color`
+    ExtSeparateSpecularColor,
+    /// `GL_EXT_shader_framebuffer_fetch`
+    ExtShaderFramebufferFetch,
+    /// `GL_EXT_shader_framebuffer_fetch_non_coherent`
+    ExtShaderFramebufferFetchNonCoherent,
+    /// `GL_EXT_shader_group_vote`
+    ExtShaderGroupVote,
+    /// `GL_EXT_shader_image_load_formatted`
+    ExtShaderImageLoadFormatted,
+    /// `GL_EXT_shader_image_load_store`
+    ExtShaderImageLoadStore,
+    /// `GL_EXT_shader_implicit_conversions`
+    ExtShaderImplicitConversions,
+    /// `GL_EXT_shader_integer_mix`
+    ExtShaderIntegerMix,
+    /// `GL_EXT_shader_io_blocks`
+    ExtShaderIoBlocks,
+    /// `GL_EXT_shader_non_constant_global_initializers`
+    ExtShaderNonConstantGlobalInitializers,
+    /// `GL_EXT_shader_pixel_local_storage`
+    ExtShaderPixelLocalStorage,
+    /// `GL_EXT_shader_pixel_local_storage2`
+    ExtShaderPixelLocalStorage2,
+    /// `GL_EXT_shader_texture_lod`
+    ExtShaderTextureLod,
+    /// `GL_EXT_shadow_funcs`
+    ExtShadowFuncs,
+    /// `GL_EXT_shadow_samplers`
+    ExtShadowSamplers,
+    /// `GL_EXT_shared_texture_palette`
+    ExtSharedTexturePalette,
+    /// `GL_EXT_sparse_texture`
+    ExtSparseTexture,
+    /// `GL_EXT_sparse_texture2`
+    ExtSparseTexture2,
+    /// `GL_EXT_stencil_clear_tag`
+    ExtStencilClearTag,
+    /// `GL_EXT_stencil_two_side`
+    ExtStencilTwoSide,
+    /// `GL_EXT_stencil_wrap`
+    ExtStencilWrap,
+    /// `GL_EXT_subtexture`
+    ExtSubtexture,
+    /// `GL_EXT_tessellation_point_size`
+    ExtTessellationPointSize,
+    /// `GL_EXT_tessellation_shader`
+    ExtTessellationShader,
+    /// `GL_EXT_texture`
+    ExtTexture,
+    /// `GL_EXT_texture3D`
+    ExtTexture3d,
+    /// `GL_EXT_texture_array`
+    ExtTextureArray,
+    /// `GL_EXT_texture_border_clamp`
+    ExtTextureBorderClamp,
+    /// `GL_EXT_texture_buffer`
+    ExtTextureBuffer,
+    /// `GL_EXT_texture_buffer_object`
+    ExtTextureBufferObject,
+    /// `GL_EXT_texture_compression_astc_decode_mode`
+    ExtTextureCompressionAstcDecodeMode,
+    /// `GL_EXT_texture_compression_bptc`
+    ExtTextureCompressionBptc,
+    /// `GL_EXT_texture_compression_dxt1`
+    ExtTextureCompressionDxt1,
+    /// `GL_EXT_texture_compression_latc`
+    ExtTextureCompressionLatc,
+    /// `GL_EXT_texture_compression_rgtc`
+    ExtTextureCompressionRgtc,
+    /// `GL_EXT_texture_compression_s3tc`
+    ExtTextureCompressionS3tc,
+    /// `GL_EXT_texture_compression_s3tc_srgb`
+    ExtTextureCompressionS3tcSrgb,
+    /// `GL_EXT_texture_cube_map`
+    ExtTextureCubeMap,
+    /// `GL_EXT_texture_cube_map_array`
+    ExtTextureCubeMapArray,
+    /// `GL_EXT_texture_env_add`
+    ExtTextureEnvAdd,
+    /// `GL_EXT_texture_env_combine`
+    ExtTextureEnvCombine,
+    /// `GL_EXT_texture_env_dot3`
+    ExtTextureEnvDot3,
+    /// `GL_EXT_texture_filter_anisotropic`
+    ExtTextureFilterAnisotropic,
+    /// `GL_EXT_texture_filter_minmax`
+    ExtTextureFilterMinmax,
+    /// `GL_EXT_texture_format_BGRA8888`
+    ExtTextureFormatBgra8888,
+    /// `GL_EXT_texture_format_sRGB_override`
+    ExtTextureFormatSrgbOverride,
+    /// `GL_EXT_texture_integer`
+    ExtTextureInteger,
+    /// `GL_EXT_texture_lod_bias`
+    ExtTextureLodBias,
+    /// `GL_EXT_texture_mirror_clamp`
+    ExtTextureMirrorClamp,
+    /// `GL_EXT_texture_mirror_clamp_to_edge`
+    ExtTextureMirrorClampToEdge,
+    /// `GL_EXT_texture_norm16`
+    ExtTextureNorm16,
+    /// `GL_EXT_texture_object`
+    ExtTextureObject,
+    /// `GL_EXT_texture_perturb_normal`
+    ExtTexturePerturbNormal,
+    /// `GL_EXT_texture_rg`
+    ExtTextureRg,
+    /// `GL_EXT_texture_sRGB`
+    ExtTextureSrgb,
+    /// `GL_EXT_texture_sRGB_R8`
+    ExtTextureSrgbR8,
+    /// `GL_EXT_texture_sRGB_RG8`
+    ExtTextureSrgbRg8,
+    /// `GL_EXT_texture_sRGB_decode`
+    ExtTextureSrgbDecode,
+    /// `GL_EXT_texture_shared_exponent`
+    ExtTextureSharedExponent,
+    /// `GL_EXT_texture_snorm`
+    ExtTextureSnorm,
+    /// `GL_EXT_texture_storage`
+    ExtTextureStorage,
+    /// `GL_EXT_texture_swizzle`
+    ExtTextureSwizzle,
+    /// `GL_EXT_texture_type_2_10_10_10_REV`
+    ExtTextureType2101010Rev,
+    /// `GL_EXT_texture_view`
+    ExtTextureView,
+    /// `GL_EXT_timer_query`
+    ExtTimerQuery,
+    /// `GL_EXT_transform_feedback`
+    ExtTransformFeedback,
+    /// `GL_EXT_unpack_subimage`
+    ExtUnpackSubimage,
+    /// `GL_EXT_vertex_array`
+    ExtVertexArray,
+    /// `GL_EXT_vertex_array_bgra`
+    ExtVertexArrayBgra,
+    /// `GL_EXT_vertex_attrib_64bit`
+    ExtVertexAttrib64bit,
+    /// `GL_EXT_vertex_shader`
+    ExtVertexShader,
+    /// `GL_EXT_vertex_weighting`
+    ExtVertexWeighting,
+    /// `GL_EXT_win32_keyed_mutex`
+    ExtWin32KeyedMutex,
+    /// `GL_EXT_window_rectangles`
+    ExtWindowRectangles,
+    /// `GL_EXT_x11_sync_object`
+    ExtX11SyncObject,
+    /// `GL_FJ_shader_binary_GCCSO`
+    FjShaderBinaryGccso,
+    /// `GL_GREMEDY_frame_terminator`
+    GremedyFrameTerminator,
+    /// `GL_GREMEDY_string_marker`
+    GremedyStringMarker,
+    /// `GL_HP_convolution_border_modes`
+    HpConvolutionBorderModes,
+    /// `GL_HP_image_transform`
+    HpImageTransform,
+    /// `GL_HP_occlusion_test`
+    HpOcclusionTest,
+    /// `GL_HP_texture_lighting`
+    HpTextureLighting,
+    /// `GL_IBM_cull_vertex`
+    IbmCullVertex,
+    /// `GL_IBM_multimode_draw_arrays`
+    IbmMultimodeDrawArrays,
+    /// `GL_IBM_rasterpos_clip`
+    IbmRasterposClip,
+    /// `GL_IBM_static_data`
+    IbmStaticData,
+    /// `GL_IBM_texture_mirrored_repeat`
+    IbmTextureMirroredRepeat,
+    /// `GL_IBM_vertex_array_lists`
+    IbmVertexArrayLists,
+    /// `GL_IMG_bindless_texture`
+    ImgBindlessTexture,
+    /// `GL_IMG_framebuffer_downsample`
+    ImgFramebufferDownsample,
+    /// `GL_IMG_multisampled_render_to_texture`
+    ImgMultisampledRenderToTexture,
+    /// `GL_IMG_program_binary`
+    ImgProgramBinary,
+    /// `GL_IMG_read_format`
+    ImgReadFormat,
+    /// `GL_IMG_shader_binary`
+    ImgShaderBinary,
+    /// `GL_IMG_texture_compression_pvrtc`
+    ImgTextureCompressionPvrtc,
+    /// `GL_IMG_texture_compression_pvrtc2`
+    ImgTextureCompressionPvrtc2,
+    /// `GL_IMG_texture_env_enhanced_fixed_function`
+    ImgTextureEnvEnhancedFixedFunction,
+    /// `GL_IMG_texture_filter_cubic`
+    ImgTextureFilterCubic,
+    /// `GL_IMG_user_clip_plane`
+    ImgUserClipPlane,
+    /// `GL_INGR_blend_func_separate`
+    IngrBlendFuncSeparate,
+    /// `GL_INGR_color_clamp`
+    IngrColorClamp,
+    /// `GL_INGR_interlace_read`
+    IngrInterlaceRead,
+    /// `GL_INTEL_blackhole_render`
+    IntelBlackholeRender,
+    /// `GL_INTEL_conservative_rasterization`
+    IntelConservativeRasterization,
+    /// `GL_INTEL_fragment_shader_ordering`
+    IntelFragmentShaderOrdering,
+    /// `GL_INTEL_framebuffer_CMAA`
+    IntelFramebufferCmaa,
+    /// `GL_INTEL_map_texture`
+    IntelMapTexture,
+    /// `GL_INTEL_parallel_arrays`
+    IntelParallelArrays,
+    /// `GL_INTEL_performance_query`
+    IntelPerformanceQuery,
+    /// `GL_KHR_blend_equation_advanced`
+    KhrBlendEquationAdvanced,
+    /// `GL_KHR_blend_equation_advanced_coherent`
+    KhrBlendEquationAdvancedCoherent,
+    /// `GL_KHR_context_flush_control`
+    KhrContextFlushControl,
+    /// `GL_KHR_debug`
+    KhrDebug,
+    /// `GL_KHR_no_error`
+    KhrNoError,
+    /// `GL_KHR_parallel_shader_compile`
+    KhrParallelShaderCompile,
+    /// `GL_KHR_robust_buffer_access_behavior`
+    KhrRobustBufferAccessBehavior,
+    /// `GL_KHR_robustness`
+    KhrRobustness,
+    /// `GL_KHR_texture_compression_astc_hdr`
+    KhrTextureCompressionAstcHdr,
+    /// `GL_KHR_texture_compression_astc_ldr`
+    KhrTextureCompressionAstcLdr,
+    /// `GL_KHR_texture_compression_astc_sliced_3d`
+    KhrTextureCompressionAstcSliced3d,
+    /// `GL_MESAX_texture_stack`
+    MesaxTextureStack,
+    /// `GL_MESA_framebuffer_flip_y`
+    MesaFramebufferFlipY,
+    /// `GL_MESA_pack_invert`
+    MesaPackInvert,
+    /// `GL_MESA_program_binary_formats`
+    MesaProgramBinaryFormats,
+    /// `GL_MESA_resize_buffers`
+    MesaResizeBuffers,
+    /// `GL_MESA_shader_integer_functions`
+    MesaShaderIntegerFunctions,
+    /// `GL_MESA_tile_raster_order`
+    MesaTileRasterOrder,
+    /// `GL_MESA_window_pos`
+    MesaWindowPos,
+    /// `GL_MESA_ycbcr_texture`
+    MesaYcbcrTexture,
+    /// `GL_NVX_blend_equation_advanced_multi_draw_buffers`
+    NvxBlendEquationAdvancedMultiDrawBuffers,
+    /// `GL_NVX_conditional_render`
+    NvxConditionalRender,
+    /// `GL_NVX_cross_process_interop`
+    NvxCrossProcessInterop,
+    /// `GL_NVX_gpu_memory_info`
+    NvxGpuMemoryInfo,
+    /// `GL_NVX_linked_gpu_multicast`
+    NvxLinkedGpuMulticast,
+    /// `GL_NV_alpha_to_coverage_dither_control`
+    NvAlphaToCoverageDitherControl,
+    /// `GL_NV_bindless_multi_draw_indirect`
+    NvBindlessMultiDrawIndirect,
+    /// `GL_NV_bindless_multi_draw_indirect_count`
+    NvBindlessMultiDrawIndirectCount,
+    /// `GL_NV_bindless_texture`
+    NvBindlessTexture,
+    /// `GL_NV_blend_equation_advanced`
+    NvBlendEquationAdvanced,
+    /// `GL_NV_blend_equation_advanced_coherent`
+    NvBlendEquationAdvancedCoherent,
+    /// `GL_NV_blend_minmax_factor`
+    NvBlendMinmaxFactor,
+    /// `GL_NV_blend_square`
+    NvBlendSquare,
+    /// `GL_NV_clip_space_w_scaling`
+    NvClipSpaceWScaling,
+    /// `GL_NV_command_list`
+    NvCommandList,
+    /// `GL_NV_compute_program5`
+    NvComputeProgram5,
+    /// `GL_NV_compute_shader_derivatives`
+    NvComputeShaderDerivatives,
+    /// `GL_NV_conditional_render`
+    NvConditionalRender,
+    /// `GL_NV_conservative_raster`
+    NvConservativeRaster,
+    /// `GL_NV_conservative_raster_dilate`
+    NvConservativeRasterDilate,
+    /// `GL_NV_conservative_raster_pre_snap`
+    NvConservativeRasterPreSnap,
+    /// `GL_NV_conservative_raster_pre_snap_triangles`
+    NvConservativeRasterPreSnapTriangles,
+    /// `GL_NV_conservative_raster_underestimation`
+    NvConservativeRasterUnderestimation,
+    /// `GL_NV_copy_buffer`
+    NvCopyBuffer,
+    /// `GL_NV_copy_depth_to_color`
+    NvCopyDepthToColor,
+    /// `GL_NV_copy_image`
+    NvCopyImage,
+    /// `GL_NV_coverage_sample`
+    NvCoverageSample,
+    /// `GL_NV_deep_texture3D`
+    NvDeepTexture3d,
+    /// `GL_NV_depth_buffer_float`
+    NvDepthBufferFloat,
+    /// `GL_NV_depth_clamp`
+    NvDepthClamp,
+    /// `GL_NV_depth_nonlinear`
+    NvDepthNonlinear,
+    /// `GL_NV_draw_buffers`
+    NvDrawBuffers,
+    /// `GL_NV_draw_instanced`
+    NvDrawInstanced,
+    /// `GL_NV_draw_texture`
+    NvDrawTexture,
+    /// `GL_NV_draw_vulkan_image`
+    NvDrawVulkanImage,
+    /// `GL_NV_evaluators`
+    NvEvaluators,
+    /// `GL_NV_explicit_attrib_location`
+    NvExplicitAttribLocation,
+    /// `GL_NV_explicit_multisample`
+    NvExplicitMultisample,
+    /// `GL_NV_fbo_color_attachments`
+    NvFboColorAttachments,
+    /// `GL_NV_fence`
+    NvFence,
+    /// `GL_NV_fill_rectangle`
+    NvFillRectangle,
+    /// `GL_NV_float_buffer`
+    NvFloatBuffer,
+    /// `GL_NV_fog_distance`
+    NvFogDistance,
+    /// `GL_NV_fragment_coverage_to_color`
+    NvFragmentCoverageToColor,
+    /// `GL_NV_fragment_program`
+    NvFragmentProgram,
+    /// `GL_NV_fragment_program2`
+    NvFragmentProgram2,
+    /// `GL_NV_fragment_program4`
+    NvFragmentProgram4,
+    /// `GL_NV_fragment_program_option`
+    NvFragmentProgramOption,
+    /// `GL_NV_fragment_shader_barycentric`
+    NvFragmentShaderBarycentric,
+    /// `GL_NV_fragment_shader_interlock`
+    NvFragmentShaderInterlock,
+    /// `GL_NV_framebuffer_blit`
+    NvFramebufferBlit,
+    /// `GL_NV_framebuffer_mixed_samples`
+    NvFramebufferMixedSamples,
+    /// `GL_NV_framebuffer_multisample`
+    NvFramebufferMultisample,
+    /// `GL_NV_framebuffer_multisample_coverage`
+    NvFramebufferMultisampleCoverage,
+    /// `GL_NV_generate_mipmap_sRGB`
+    NvGenerateMipmapSrgb,
+    /// `GL_NV_geometry_program4`
+    NvGeometryProgram4,
+    /// `GL_NV_geometry_shader4`
+    NvGeometryShader4,
+    /// `GL_NV_geometry_shader_passthrough`
+    NvGeometryShaderPassthrough,
+    /// `GL_NV_gpu_multicast`
+    NvGpuMulticast,
+    /// `GL_NV_gpu_program4`
+    NvGpuProgram4,
+    /// `GL_NV_gpu_program5`
+    NvGpuProgram5,
+    /// `GL_NV_gpu_program5_mem_extended`
+    NvGpuProgram5MemExtended,
+    /// `GL_NV_gpu_shader5`
+    NvGpuShader5,
+    /// `GL_NV_half_float`
+    NvHalfFloat,
+    /// `GL_NV_image_formats`
+    NvImageFormats,
+    /// `GL_NV_instanced_arrays`
+    NvInstancedArrays,
+    /// `GL_NV_internalformat_sample_query`
+    NvInternalformatSampleQuery,
+    /// `GL_NV_light_max_exponent`
+    NvLightMaxExponent,
+    /// `GL_NV_memory_attachment`
+    NvMemoryAttachment,
+    /// `GL_NV_mesh_shader`
+    NvMeshShader,
+    /// `GL_NV_multisample_coverage`
+    NvMultisampleCoverage,
+    /// `GL_NV_multisample_filter_hint`
+    NvMultisampleFilterHint,
+    /// `GL_NV_non_square_matrices`
+    NvNonSquareMatrices,
+    /// `GL_NV_occlusion_query`
+    NvOcclusionQuery,
+    /// `GL_NV_packed_depth_stencil`
+    NvPackedDepthStencil,
+    /// `GL_NV_parameter_buffer_object`
+    NvParameterBufferObject,
+    /// `GL_NV_parameter_buffer_object2`
+    NvParameterBufferObject2,
+    /// `GL_NV_path_rendering`
+    NvPathRendering,
+    /// `GL_NV_path_rendering_shared_edge`
+    NvPathRenderingSharedEdge,
+    /// `GL_NV_pixel_buffer_object`
+    NvPixelBufferObject,
+    /// `GL_NV_pixel_data_range`
+    NvPixelDataRange,
+    /// `GL_NV_point_sprite`
+    NvPointSprite,
+    /// `GL_NV_polygon_mode`
+    NvPolygonMode,
+    /// `GL_NV_present_video`
+    NvPresentVideo,
+    /// `GL_NV_primitive_restart`
+    NvPrimitiveRestart,
+    /// `GL_NV_query_resource`
+    NvQueryResource,
+    /// `GL_NV_query_resource_tag`
+    NvQueryResourceTag,
+    /// `GL_NV_read_buffer`
+    NvReadBuffer,
+    /// `GL_NV_read_buffer_front`
+    NvReadBufferFront,
+    /// `GL_NV_read_depth`
+    NvReadDepth,
+    /// `GL_NV_read_depth_stencil`
+    NvReadDepthStencil,
+    /// `GL_NV_read_stencil`
+    NvReadStencil,
+    /// `GL_NV_register_combiners`
+    NvRegisterCombiners,
+    /// `GL_NV_register_combiners2`
+    NvRegisterCombiners2,
+    /// `GL_NV_representative_fragment_test`
+    NvRepresentativeFragmentTest,
+    /// `GL_NV_robustness_video_memory_purge`
+    NvRobustnessVideoMemoryPurge,
+    /// `GL_NV_sRGB_formats`
+    NvSrgbFormats,
+    /// `GL_NV_sample_locations`
+    NvSampleLocations,
+    /// `GL_NV_sample_mask_override_coverage`
+    NvSampleMaskOverrideCoverage,
+    /// `GL_NV_scissor_exclusive`
+    NvScissorExclusive,
+    /// `GL_NV_shader_atomic_counters`
+    NvShaderAtomicCounters,
+    /// `GL_NV_shader_atomic_float`
+    NvShaderAtomicFloat,
+    /// `GL_NV_shader_atomic_float64`
+    NvShaderAtomicFloat64,
+    /// `GL_NV_shader_atomic_fp16_vector`
+    NvShaderAtomicFp16Vector,
+    /// `GL_NV_shader_atomic_int64`
+    NvShaderAtomicInt64,
+    /// `GL_NV_shader_buffer_load`
+    NvShaderBufferLoad,
+    /// `GL_NV_shader_buffer_store`
+    NvShaderBufferStore,
+    /// `GL_NV_shader_noperspective_interpolation`
+    NvShaderNoperspectiveInterpolation,
+    /// `GL_NV_shader_storage_buffer_object`
+    NvShaderStorageBufferObject,
+    /// `GL_NV_shader_texture_footprint`
+    NvShaderTextureFootprint,
+    /// `GL_NV_shader_thread_group`
+    NvShaderThreadGroup,
+    /// `GL_NV_shader_thread_shuffle`
+    NvShaderThreadShuffle,
+    /// `GL_NV_shading_rate_image`
+    NvShadingRateImage,
+    /// `GL_NV_shadow_samplers_array`
+    NvShadowSamplersArray,
+    /// `GL_NV_shadow_samplers_cube`
+    NvShadowSamplersCube,
+    /// `GL_NV_stereo_view_rendering`
+    NvStereoViewRendering,
+    /// `GL_NV_tessellation_program5`
+    NvTessellationProgram5,
+    /// `GL_NV_texgen_emboss`
+    NvTexgenEmboss,
+    /// `GL_NV_texgen_reflection`
+    NvTexgenReflection,
+    /// `GL_NV_texture_barrier`
+    NvTextureBarrier,
+    /// `GL_NV_texture_border_clamp`
+    NvTextureBorderClamp,
+    /// `GL_NV_texture_compression_s3tc_update`
+    NvTextureCompressionS3tcUpdate,
+    /// `GL_NV_texture_compression_vtc`
+    NvTextureCompressionVtc,
+    /// `GL_NV_texture_env_combine4`
+    NvTextureEnvCombine4,
+    /// `GL_NV_texture_expand_normal`
+    NvTextureExpandNormal,
+    /// `GL_NV_texture_multisample`
+    NvTextureMultisample,
+    /// `GL_NV_texture_npot_2D_mipmap`
+    NvTextureNpot2dMipmap,
+    /// `GL_NV_texture_rectangle`
+    NvTextureRectangle,
+    /// `GL_NV_texture_rectangle_compressed`
+    NvTextureRectangleCompressed,
+    /// `GL_NV_texture_shader`
+    NvTextureShader,
+    /// `GL_NV_texture_shader2`
+    NvTextureShader2,
+    /// `GL_NV_texture_shader3`
+    NvTextureShader3,
+    /// `GL_NV_transform_feedback`
+    NvTransformFeedback,
+    /// `GL_NV_transform_feedback2`
+    NvTransformFeedback2,
+    /// `GL_NV_uniform_buffer_unified_memory`
+    NvUniformBufferUnifiedMemory,
+    /// `GL_NV_vdpau_interop`
+    NvVdpauInterop,
+    /// `GL_NV_vdpau_interop2`
+    NvVdpauInterop2,
+    /// `GL_NV_vertex_array_range`
+    NvVertexArrayRange,
+    /// `GL_NV_vertex_array_range2`
+    NvVertexArrayRange2,
+    /// `GL_NV_vertex_attrib_integer_64bit`
+    NvVertexAttribInteger64bit,
+    /// `GL_NV_vertex_buffer_unified_memory`
+    NvVertexBufferUnifiedMemory,
+    /// `GL_NV_vertex_program`
+    NvVertexProgram,
+    /// `GL_NV_vertex_program1_1`
+    NvVertexProgram11,
+    /// `GL_NV_vertex_program2`
+    NvVertexProgram2,
+    /// `GL_NV_vertex_program2_option`
+    NvVertexProgram2Option,
+    /// `GL_NV_vertex_program3`
+    NvVertexProgram3,
+    /// `GL_NV_vertex_program4`
+    NvVertexProgram4,
+    /// `GL_NV_video_capture`
+    NvVideoCapture,
+    /// `GL_NV_viewport_array`
+    NvViewportArray,
+    /// `GL_NV_viewport_array2`
+    NvViewportArray2,
+    /// `GL_NV_viewport_swizzle`
+    NvViewportSwizzle,
+    /// `GL_OES_EGL_image`
+    OesEglImage,
+    /// `GL_OES_EGL_image_external`
+    OesEglImageExternal,
+    /// `GL_OES_EGL_image_external_essl3`
+    OesEglImageExternalEssl3,
+    /// `GL_OES_blend_equation_separate`
+    OesBlendEquationSeparate,
+    /// `GL_OES_blend_func_separate`
+    OesBlendFuncSeparate,
+    /// `GL_OES_blend_subtract`
+    OesBlendSubtract,
+    /// `GL_OES_byte_coordinates`
+    OesByteCoordinates,
+    /// `GL_OES_compressed_ETC1_RGB8_sub_texture`
+    OesCompressedEtc1Rgb8SubTexture,
+    /// `GL_OES_compressed_ETC1_RGB8_texture`
+    OesCompressedEtc1Rgb8Texture,
+    /// `GL_OES_compressed_paletted_texture`
+    OesCompressedPalettedTexture,
+    /// `GL_OES_copy_image`
+    OesCopyImage,
+    /// `GL_OES_depth24`
+    OesDepth24,
+    /// `GL_OES_depth32`
+    OesDepth32,
+    /// `GL_OES_depth_texture`
+    OesDepthTexture,
+    /// `GL_OES_draw_buffers_indexed`
+    OesDrawBuffersIndexed,
+    /// `GL_OES_draw_elements_base_vertex`
+    OesDrawElementsBaseVertex,
+    /// `GL_OES_draw_texture`
+    OesDrawTexture,
+    /// `GL_OES_element_index_uint`
+    OesElementIndexUint,
+    /// `GL_OES_extended_matrix_palette`
+    OesExtendedMatrixPalette,
+    /// `GL_OES_fbo_render_mipmap`
+    OesFboRenderMipmap,
+    /// `GL_OES_fixed_point`
+    OesFixedPoint,
+    /// `GL_OES_fragment_precision_high`
+    OesFragmentPrecisionHigh,
+    /// `GL_OES_framebuffer_object`
+    OesFramebufferObject,
+    /// `GL_OES_geometry_point_size`
+    OesGeometryPointSize,
+    /// `GL_OES_geometry_shader`
+    OesGeometryShader,
+    /// `GL_OES_get_program_binary`
+    OesGetProgramBinary,
+    /// `GL_OES_gpu_shader5`
+    OesGpuShader5,
+    /// `GL_OES_mapbuffer`
+    OesMapbuffer,
+    /// `GL_OES_matrix_get`
+    OesMatrixGet,
+    /// `GL_OES_matrix_palette`
+    OesMatrixPalette,
+    /// `GL_OES_packed_depth_stencil`
+    OesPackedDepthStencil,
+    /// `GL_OES_point_size_array`
+    OesPointSizeArray,
+    /// `GL_OES_point_sprite`
+    OesPointSprite,
+    /// `GL_OES_primitive_bounding_box`
+    OesPrimitiveBoundingBox,
+    /// `GL_OES_query_matrix`
+    OesQueryMatrix,
+    /// `GL_OES_read_format`
+    OesReadFormat,
+    /// `GL_OES_required_internalformat`
+    OesRequiredInternalformat,
+    /// `GL_OES_rgb8_rgba8`
+    OesRgb8Rgba8,
+    /// `GL_OES_sample_shading`
+    OesSampleShading,
+    /// `GL_OES_sample_variables`
+    OesSampleVariables,
+    /// `GL_OES_shader_image_atomic`
+    OesShaderImageAtomic,
+    /// `GL_OES_shader_io_blocks`
+    OesShaderIoBlocks,
+    /// `GL_OES_shader_multisample_interpolation`
+    OesShaderMultisampleInterpolation,
+    /// `GL_OES_single_precision`
+    OesSinglePrecision,
+    /// `GL_OES_standard_derivatives`
+    OesStandardDerivatives,
+    /// `GL_OES_stencil1`
+    OesStencil1,
+    /// `GL_OES_stencil4`
+    OesStencil4,
+    /// `GL_OES_stencil8`
+    OesStencil8,
+    /// `GL_OES_stencil_wrap`
+    OesStencilWrap,
+    /// `GL_OES_surfaceless_context`
+    OesSurfacelessContext,
+    /// `GL_OES_tessellation_point_size`
+    OesTessellationPointSize,
+    /// `GL_OES_tessellation_shader`
+    OesTessellationShader,
+    /// `GL_OES_texture_3D`
+    OesTexture3d,
+    /// `GL_OES_texture_border_clamp`
+    OesTextureBorderClamp,
+    /// `GL_OES_texture_buffer`
+    OesTextureBuffer,
+    /// `GL_OES_texture_compression_astc`
+    OesTextureCompressionAstc,
+    /// `GL_OES_texture_cube_map`
+    OesTextureCubeMap,
+    /// `GL_OES_texture_cube_map_array`
+    OesTextureCubeMapArray,
+    /// `GL_OES_texture_env_crossbar`
+    OesTextureEnvCrossbar,
+    /// `GL_OES_texture_float`
+    OesTextureFloat,
+    /// `GL_OES_texture_float_linear`
+    OesTextureFloatLinear,
+    /// `GL_OES_texture_half_float`
+    OesTextureHalfFloat,
+    /// `GL_OES_texture_half_float_linear`
+    OesTextureHalfFloatLinear,
+    /// `GL_OES_texture_mirrored_repeat`
+    OesTextureMirroredRepeat,
+    /// `GL_OES_texture_npot`
+    OesTextureNpot,
+    /// `GL_OES_texture_stencil8`
+    OesTextureStencil8,
+    /// `GL_OES_texture_storage_multisample_2d_array`
+    OesTextureStorageMultisample2dArray,
+    /// `GL_OES_texture_view`
+    OesTextureView,
+    /// `GL_OES_vertex_array_object`
+    OesVertexArrayObject,
+    /// `GL_OES_vertex_half_float`
+    OesVertexHalfFloat,
+    /// `GL_OES_vertex_type_10_10_10_2`
+    OesVertexType1010102,
+    /// `GL_OES_viewport_array`
+    OesViewportArray,
+    /// `GL_OML_interlace`
+    OmlInterlace,
+    /// `GL_OML_resample`
+    OmlResample,
+    /// `GL_OML_subsample`
+    OmlSubsample,
+    /// `GL_OVR_multiview`
+    OvrMultiview,
+    /// `GL_OVR_multiview2`
+    OvrMultiview2,
+    /// `GL_OVR_multiview_multisampled_render_to_texture`
+    OvrMultiviewMultisampledRenderToTexture,
+    /// `GL_PGI_misc_hints`
+    PgiMiscHints,
+    /// `GL_PGI_vertex_hints`
+    PgiVertexHints,
+    /// `GL_QCOM_YUV_texture_gather`
+    QcomYuvTextureGather,
+    /// `GL_QCOM_alpha_test`
+    QcomAlphaTest,
+    /// `GL_QCOM_binning_control`
+    QcomBinningControl,
+    /// `GL_QCOM_driver_control`
+    QcomDriverControl,
+    /// `GL_QCOM_extended_get`
+    QcomExtendedGet,
+    /// `GL_QCOM_extended_get2`
+    QcomExtendedGet2,
+    /// `GL_QCOM_framebuffer_foveated`
+    QcomFramebufferFoveated,
+    /// `GL_QCOM_perfmon_global_mode`
+    QcomPerfmonGlobalMode,
+    /// `GL_QCOM_shader_framebuffer_fetch_noncoherent`
+    QcomShaderFramebufferFetchNoncoherent,
+    /// `GL_QCOM_shader_framebuffer_fetch_rate`
+    QcomShaderFramebufferFetchRate,
+    /// `GL_QCOM_texture_foveated`
+    QcomTextureFoveated,
+    /// `GL_QCOM_texture_foveated_subsampled_layout`
+    QcomTextureFoveatedSubsampledLayout,
+    /// `GL_QCOM_tiled_rendering`
+    QcomTiledRendering,
+    /// `GL_QCOM_writeonly_rendering`
+    QcomWriteonlyRendering,
+    /// `GL_REND_screen_coordinates`
+    RendScreenCoordinates,
+    /// `GL_S3_s3tc`
+    S3S3tc,
+    /// `GL_SGIS_detail_texture`
+    SgisDetailTexture,
+    /// `GL_SGIS_fog_function`
+    SgisFogFunction,
+    /// `GL_SGIS_generate_mipmap`
+    SgisGenerateMipmap,
+    /// `GL_SGIS_multisample`
+    SgisMultisample,
+    /// `GL_SGIS_pixel_texture`
+    SgisPixelTexture,
+    /// `GL_SGIS_point_line_texgen`
+    SgisPointLineTexgen,
+    /// `GL_SGIS_point_parameters`
+    SgisPointParameters,
+    /// `GL_SGIS_sharpen_texture`
+    SgisSharpenTexture,
+    /// `GL_SGIS_texture4D`
+    SgisTexture4d,
+    /// `GL_SGIS_texture_border_clamp`
+    SgisTextureBorderClamp,
+    /// `GL_SGIS_texture_color_mask`
+    SgisTextureColorMask,
+    /// `GL_SGIS_texture_edge_clamp`
+    SgisTextureEdgeClamp,
+    /// `GL_SGIS_texture_filter4`
+    SgisTextureFilter4,
+    /// `GL_SGIS_texture_lod`
+    SgisTextureLod,
+    /// `GL_SGIS_texture_select`
+    SgisTextureSelect,
+    /// `GL_SGIX_async`
+    SgixAsync,
+    /// `GL_SGIX_async_histogram`
+    SgixAsyncHistogram,
+    /// `GL_SGIX_async_pixel`
+    SgixAsyncPixel,
+    /// `GL_SGIX_blend_alpha_minmax`
+    SgixBlendAlphaMinmax,
+    /// `GL_SGIX_calligraphic_fragment`
+    SgixCalligraphicFragment,
+    /// `GL_SGIX_clipmap`
+    SgixClipmap,
+    /// `GL_SGIX_convolution_accuracy`
+    SgixConvolutionAccuracy,
+    /// `GL_SGIX_depth_pass_instrument`
+    SgixDepthPassInstrument,
+    /// `GL_SGIX_depth_texture`
+    SgixDepthTexture,
+    /// `GL_SGIX_flush_raster`
+    SgixFlushRaster,
+    /// `GL_SGIX_fog_offset`
+    SgixFogOffset,
+    /// `GL_SGIX_fragment_lighting`
+    SgixFragmentLighting,
+    /// `GL_SGIX_framezoom`
+    SgixFramezoom,
+    /// `GL_SGIX_igloo_interface`
+    SgixIglooInterface,
+    /// `GL_SGIX_instruments`
+    SgixInstruments,
+    /// `GL_SGIX_interlace`
+    SgixInterlace,
+    /// `GL_SGIX_ir_instrument1`
+    SgixIrInstrument1,
+    /// `GL_SGIX_list_priority`
+    SgixListPriority,
+    /// `GL_SGIX_pixel_texture`
+    SgixPixelTexture,
+    /// `GL_SGIX_pixel_tiles`
+    SgixPixelTiles,
+    /// `GL_SGIX_polynomial_ffd`
+    SgixPolynomialFfd,
+    /// `GL_SGIX_reference_plane`
+    SgixReferencePlane,
+    /// `GL_SGIX_resample`
+    SgixResample,
+    /// `GL_SGIX_scalebias_hint`
+    SgixScalebiasHint,
+    /// `GL_SGIX_shadow`
+    SgixShadow,
+    /// `GL_SGIX_shadow_ambient`
+    SgixShadowAmbient,
+    /// `GL_SGIX_sprite`
+    SgixSprite,
+    /// `GL_SGIX_subsample`
+    SgixSubsample,
+    /// `GL_SGIX_tag_sample_buffer`
+    SgixTagSampleBuffer,
+    /// `GL_SGIX_texture_add_env`
+    SgixTextureAddEnv,
+    /// `GL_SGIX_texture_coordinate_clamp`
+    SgixTextureCoordinateClamp,
+    /// `GL_SGIX_texture_lod_bias`
+    SgixTextureLodBias,
+    /// `GL_SGIX_texture_multi_buffer`
+    SgixTextureMultiBuffer,
+    /// `GL_SGIX_texture_scale_bias`
+    SgixTextureScaleBias,
+    /// `GL_SGIX_vertex_preclip`
+    SgixVertexPreclip,
+    /// `GL_SGIX_ycrcb`
+    SgixYcrcb,
+    /// `GL_SGIX_ycrcb_subsample`
+    SgixYcrcbSubsample,
+    /// `GL_SGIX_ycrcba`
+    SgixYcrcba,
+    /// `GL_SGI_color_matrix`
+    SgiColorMatrix,
+    /// `GL_SGI_color_table`
+    SgiColorTable,
+    /// `GL_SGI_texture_color_table`
+    SgiTextureColorTable,
+    /// `GL_SUNX_constant_data`
+    SunxConstantData,
+    /// `GL_SUN_convolution_border_modes`
+    SunConvolutionBorderModes,
+    /// `GL_SUN_global_alpha`
+    SunGlobalAlpha,
+    /// `GL_SUN_mesh_array`
+    SunMeshArray,
+    /// `GL_SUN_slice_accum`
+    SunSliceAccum,
+    /// `GL_SUN_triangle_list`
+    SunTriangleList,
+    /// `GL_SUN_vertex`
+    SunVertex,
+    /// `GL_VIV_shader_binary`
+    VivShaderBinary,
+    /// `GL_WIN_phong_shading`
+    WinPhongShading,
+    /// `GL_WIN_specular_fog`
+    WinSpecularFog,
+    /// `WEBGL_blend_equation_advanced_coherent`
+    WebglBlendEquationAdvancedCoherent,
+    /// `WEBGL_clip_cull_distance`
+    WebglClipCullDistance,
+    /// `WEBGL_color_buffer_float`
+    WebglColorBufferFloat,
+    /// `WEBGL_compressed_texture_astc`
+    WebglCompressedTextureAstc,
+    /// `WEBGL_compressed_texture_etc`
+    WebglCompressedTextureEtc,
+    /// `WEBGL_compressed_texture_etc1`
+    WebglCompressedTextureEtc1,
+    /// `WEBGL_compressed_texture_pvrtc`
+    WebglCompressedTexturePvrtc,
+    /// `WEBGL_compressed_texture_s3tc`
+    WebglCompressedTextureS3tc,
+    /// `WEBGL_compressed_texture_s3tc_srgb`
+    WebglCompressedTextureS3tcSrgb,
+    /// `WEBGL_debug_renderer_info`
+    WebglDebugRendererInfo,
+    /// `WEBGL_debug_shaders`
+    WebglDebugShaders,
+    /// `WEBGL_depth_texture`
+    WebglDepthTexture,
+    /// `WEBGL_draw_buffers`
+    WebglDrawBuffers,
+    /// `WEBGL_draw_instanced_base_vertex_base_instance`
+    WebglDrawInstancedBaseVertexBaseInstance,
+    /// `WEBGL_lose_context`
+    WebglLoseContext,
+    /// `WEBGL_multi_draw`
+    WebglMultiDraw,
+    /// `WEBGL_multi_draw_instanced_base_vertex_base_instance`
+    WebglMultiDrawInstancedBaseVertexBaseInstance,
+    /// `WEBGL_polygon_mode`
+    WebglPolygonMode,
+    /// `WEBGL_provoking_vertex`
+    WebglProvokingVertex,
+    /// `WEBGL_render_shared_exponent`
+    WebglRenderSharedExponent,
+    /// `WEBGL_stencil_texturing`
+    WebglStencilTexturing,
+}
+
+impl Extension {
+    /// All known extensions, sorted by name.
+    pub const ALL: [Extension; 842] = [
+        Extension::ExtDepthClamp,
+        Extension::ExtDisjointTimerQueryWebgl2,
+        Extension::ExtFragDepth,
+        Extension::Gl3dfxMultisample,
+        Extension::Gl3dfxTbuffer,
+        Extension::Gl3dfxTextureCompressionFxt1,
+        Extension::AmdBlendMinmaxFactor,
+        Extension::AmdCompressed3dcTexture,
+        Extension::AmdCompressedAtcTexture,
+        Extension::AmdConservativeDepth,
+        Extension::AmdDebugOutput,
+        Extension::AmdDepthClampSeparate,
+        Extension::AmdDrawBuffersBlend,
+        Extension::AmdFramebufferMultisampleAdvanced,
+        Extension::AmdFramebufferSamplePositions,
+        Extension::AmdGcnShader,
+        Extension::AmdGpuShaderHalfFloat,
+        Extension::AmdGpuShaderInt16,
+        Extension::AmdGpuShaderInt64,
+        Extension::AmdInterleavedElements,
+        Extension::AmdMultiDrawIndirect,
+        Extension::AmdNameGenDelete,
+        Extension::AmdOcclusionQueryEvent,
+        Extension::AmdPerformanceMonitor,
+        Extension::AmdPinnedMemory,
+        Extension::AmdProgramBinaryZ400,
+        Extension::AmdQueryBufferObject,
+        Extension::AmdSamplePositions,
+        Extension::AmdSeamlessCubemapPerTexture,
+        Extension::AmdShaderAtomicCounterOps,
+        Extension::AmdShaderBallot,
+        Extension::AmdShaderExplicitVertexParameter,
+        Extension::AmdShaderGpuShaderHalfFloatFetch,
+        Extension::AmdShaderImageLoadStoreLod,
+        Extension::AmdShaderStencilExport,
+        Extension::AmdShaderTrinaryMinmax,
+        Extension::AmdSparseTexture,
+        Extension::AmdStencilOperationExtended,
+        Extension::AmdTextureGatherBiasLod,
+        Extension::AmdTextureTexture4,
+        Extension::AmdTransformFeedback3LinesTriangles,
+        Extension::AmdTransformFeedback4,
+        Extension::AmdVertexShaderLayer,
+        Extension::AmdVertexShaderTessellator,
+        Extension::AmdVertexShaderViewportIndex,
+        Extension::AndroidExtensionPackEs31a,
+        Extension::AngleDepthTexture,
+        Extension::AngleFramebufferBlit,
+        Extension::AngleFramebufferMultisample,
+        Extension::AngleInstancedArrays,
+        Extension::AnglePackReverseRowOrder,
+        Extension::AngleProgramBinary,
+        Extension::AngleTextureCompressionDxt3,
+        Extension::AngleTextureCompressionDxt5,
+        Extension::AngleTextureUsage,
+        Extension::AngleTranslatedShaderSource,
+        Extension::AppleAuxDepthStencil,
+        Extension::AppleClientStorage,
+        Extension::AppleClipDistance,
+        Extension::AppleColorBufferPackedFloat,
+        Extension::AppleCopyTextureLevels,
+        Extension::AppleElementArray,
+        Extension::AppleFence,
+        Extension::AppleFloatPixels,
+        Extension::AppleFlushBufferRange,
+        Extension::AppleFramebufferMultisample,
+        Extension::AppleObjectPurgeable,
+        Extension::AppleRgb422,
+        Extension::AppleRowBytes,
+        Extension::AppleSpecularVector,
+        Extension::AppleSync,
+        Extension::AppleTexture2dLimitedNpot,
+        Extension::AppleTextureFormatBgra8888,
+        Extension::AppleTextureMaxLevel,
+        Extension::AppleTexturePackedFloat,
+        Extension::AppleTextureRange,
+        Extension::AppleTransformHint,
+        Extension::AppleVertexArrayObject,
+        Extension::AppleVertexArrayRange,
+        Extension::AppleVertexProgramEvaluators,
+        Extension::AppleYcbcr422,
+        Extension::ArbEs2Compatibility,
+        Extension::ArbEs31Compatibility,
+        Extension::ArbEs32Compatibility,
+        Extension::ArbEs3Compatibility,
+        Extension::ArbArraysOfArrays,
+        Extension::ArbBaseInstance,
+        Extension::ArbBindlessTexture,
+        Extension::ArbBlendFuncExtended,
+        Extension::ArbBufferStorage,
+        Extension::ArbClEvent,
+        Extension::ArbClearBufferObject,
+        Extension::ArbClearTexture,
+        Extension::ArbClipControl,
+        Extension::ArbColorBufferFloat,
+        Extension::ArbCompatibility,
+        Extension::ArbCompressedTexturePixelStorage,
+        Extension::ArbComputeShader,
+        Extension::ArbComputeVariableGroupSize,
+        Extension::ArbConditionalRenderInverted,
+        Extension::ArbConservativeDepth,
+        Extension::ArbCopyBuffer,
+        Extension::ArbCopyImage,
+        Extension::ArbCullDistance,
+        Extension::ArbDebugOutput,
+        Extension::ArbDepthBufferFloat,
+        Extension::ArbDepthClamp,
+        Extension::ArbDepthTexture,
+        Extension::ArbDerivativeControl,
+        Extension::ArbDirectStateAccess,
+        Extension::ArbDrawBuffers,
+        Extension::ArbDrawBuffersBlend,
+        Extension::ArbDrawElementsBaseVertex,
+        Extension::ArbDrawIndirect,
+        Extension::ArbDrawInstanced,
+        Extension::ArbEnhancedLayouts,
+        Extension::ArbExplicitAttribLocation,
+        Extension::ArbExplicitUniformLocation,
+        Extension::ArbFragmentCoordConventions,
+        Extension::ArbFragmentLayerViewport,
+        Extension::ArbFragmentProgram,
+        Extension::ArbFragmentProgramShadow,
+        Extension::ArbFragmentShader,
+        Extension::ArbFragmentShaderInterlock,
+        Extension::ArbFramebufferNoAttachments,
+        Extension::ArbFramebufferObject,
+        Extension::ArbFramebufferSrgb,
+        Extension::ArbGeometryShader4,
+        Extension::ArbGetProgramBinary,
+        Extension::ArbGetTextureSubImage,
+        Extension::ArbGlSpirv,
+        Extension::ArbGpuShader5,
+        Extension::ArbGpuShaderFp64,
+        Extension::ArbGpuShaderInt64,
+        Extension::ArbHalfFloatPixel,
+        Extension::ArbHalfFloatVertex,
+        Extension::ArbImaging,
+        Extension::ArbIndirectParameters,
+        Extension::ArbInstancedArrays,
+        Extension::ArbInternalformatQuery,
+        Extension::ArbInternalformatQuery2,
+        Extension::ArbInvalidateSubdata,
+        Extension::ArbMapBufferAlignment,
+        Extension::ArbMapBufferRange,
+        Extension::ArbMatrixPalette,
+        Extension::ArbMultiBind,
+        Extension::ArbMultiDrawIndirect,
+        Extension::ArbMultisample,
+        Extension::ArbMultitexture,
+        Extension::ArbOcclusionQuery,
+        Extension::ArbOcclusionQuery2,
+        Extension::ArbParallelShaderCompile,
+        Extension::ArbPipelineStatisticsQuery,
+        Extension::ArbPixelBufferObject,
+        Extension::ArbPointParameters,
+        Extension::ArbPointSprite,
+        Extension::ArbPolygonOffsetClamp,
+        Extension::ArbPostDepthCoverage,
+        Extension::ArbProgramInterfaceQuery,
+        Extension::ArbProvokingVertex,
+        Extension::ArbQueryBufferObject,
+        Extension::ArbRobustBufferAccessBehavior,
+        Extension::ArbRobustness,
+        Extension::ArbRobustnessIsolation,
+        Extension::ArbSampleLocations,
+        Extension::ArbSampleShading,
+        Extension::ArbSamplerObjects,
+        Extension::ArbSeamlessCubeMap,
+        Extension::ArbSeamlessCubemapPerTexture,
+        Extension::ArbSeparateShaderObjects,
+        Extension::ArbShaderAtomicCounterOps,
+        Extension::ArbShaderAtomicCounters,
+        Extension::ArbShaderBallot,
+        Extension::ArbShaderBitEncoding,
+        Extension::ArbShaderClock,
+        Extension::ArbShaderDrawParameters,
+        Extension::ArbShaderGroupVote,
+        Extension::ArbShaderImageLoadStore,
+        Extension::ArbShaderImageSize,
+        Extension::ArbShaderObjects,
+        Extension::ArbShaderPrecision,
+        Extension::ArbShaderStencilExport,
+        Extension::ArbShaderStorageBufferObject,
+        Extension::ArbShaderSubroutine,
+        Extension::ArbShaderTextureImageSamples,
+        Extension::ArbShaderTextureLod,
+        Extension::ArbShaderViewportLayerArray,
+        Extension::ArbShadingLanguage100,
+        Extension::ArbShadingLanguage420pack,
+        Extension::ArbShadingLanguageInclude,
+        Extension::ArbShadingLanguagePacking,
+        Extension::ArbShadow,
+        Extension::ArbShadowAmbient,
+        Extension::ArbSparseBuffer,
+        Extension::ArbSparseTexture,
+        Extension::ArbSparseTexture2,
+        Extension::ArbSparseTextureClamp,
+        Extension::ArbSpirvExtensions,
+        Extension::ArbStencilTexturing,
+        Extension::ArbSync,
+        Extension::ArbTessellationShader,
+        Extension::ArbTextureBarrier,
+        Extension::ArbTextureBorderClamp,
+        Extension::ArbTextureBufferObject,
+        Extension::ArbTextureBufferObjectRgb32,
+        Extension::ArbTextureBufferRange,
+        Extension::ArbTextureCompression,
+        Extension::ArbTextureCompressionBptc,
+        Extension::ArbTextureCompressionRgtc,
+        Extension::ArbTextureCubeMap,
+        Extension::ArbTextureCubeMapArray,
+        Extension::ArbTextureEnvAdd,
+        Extension::ArbTextureEnvCombine,
+        Extension::ArbTextureEnvCrossbar,
+        Extension::ArbTextureEnvDot3,
+        Extension::ArbTextureFilterAnisotropic,
+        Extension::ArbTextureFilterMinmax,
+        Extension::ArbTextureFloat,
+        Extension::ArbTextureGather,
+        Extension::ArbTextureMirrorClampToEdge,
+        Extension::ArbTextureMirroredRepeat,
+        Extension::ArbTextureMultisample,
+        Extension::ArbTextureNonPowerOfTwo,
+        Extension::ArbTextureQueryLevels,
+        Extension::ArbTextureQueryLod,
+        Extension::ArbTextureRectangle,
+        Extension::ArbTextureRg,
+        Extension::ArbTextureRgb10A2ui,
+        Extension::ArbTextureStencil8,
+        Extension::ArbTextureStorage,
+        Extension::ArbTextureStorageMultisample,
+        Extension::ArbTextureSwizzle,
+        Extension::ArbTextureView,
+        Extension::ArbTimerQuery,
+        Extension::ArbTransformFeedback2,
+        Extension::ArbTransformFeedback3,
+        Extension::ArbTransformFeedbackInstanced,
+        Extension::ArbTransformFeedbackOverflowQuery,
+        Extension::ArbTransposeMatrix,
+        Extension::ArbUniformBufferObject,
+        Extension::ArbVertexArrayBgra,
+        Extension::ArbVertexArrayObject,
+        Extension::ArbVertexAttrib64bit,
+        Extension::ArbVertexAttribBinding,
+        Extension::ArbVertexBlend,
+        Extension::ArbVertexBufferObject,
+        Extension::ArbVertexProgram,
+        Extension::ArbVertexShader,
+        Extension::ArbVertexType10f11f11fRev,
+        Extension::ArbVertexType2101010Rev,
+        Extension::ArbViewportArray,
+        Extension::ArbWindowPos,
+        Extension::ArmMaliProgramBinary,
+        Extension::ArmMaliShaderBinary,
+        Extension::ArmRgba8,
+        Extension::ArmShaderFramebufferFetch,
+        Extension::ArmShaderFramebufferFetchDepthStencil,
+        Extension::AtiDrawBuffers,
+        Extension::AtiElementArray,
+        Extension::AtiEnvmapBumpmap,
+        Extension::AtiFragmentShader,
+        Extension::AtiMapObjectBuffer,
+        Extension::AtiMeminfo,
+        Extension::AtiPixelFormatFloat,
+        Extension::AtiPnTriangles,
+        Extension::AtiSeparateStencil,
+        Extension::AtiTextFragmentShader,
+        Extension::AtiTextureEnvCombine3,
+        Extension::AtiTextureFloat,
+        Extension::AtiTextureMirrorOnce,
+        Extension::AtiVertexArrayObject,
+        Extension::AtiVertexAttribArrayObject,
+        Extension::AtiVertexStreams,
+        Extension::DmpProgramBinary,
+        Extension::DmpShaderBinary,
+        Extension::Ext422Pixels,
+        Extension::ExtEglImageArray,
+        Extension::ExtEglImageStorage,
+        Extension::ExtYuvTarget,
+        Extension::ExtAbgr,
+        Extension::ExtBaseInstance,
+        Extension::ExtBgra,
+        Extension::ExtBindableUniform,
+        Extension::ExtBlendColor,
+        Extension::ExtBlendEquationSeparate,
+        Extension::ExtBlendFuncExtended,
+        Extension::ExtBlendFuncSeparate,
+        Extension::ExtBlendLogicOp,
+        Extension::ExtBlendMinmax,
+        Extension::ExtBlendSubtract,
+        Extension::ExtBufferStorage,
+        Extension::ExtClearTexture,
+        Extension::ExtClipControl,
+        Extension::ExtClipCullDistance,
+        Extension::ExtClipVolumeHint,
+        Extension::ExtCmyka,
+        Extension::ExtColorBufferFloat,
+        Extension::ExtColorBufferHalfFloat,
+        Extension::ExtColorSubtable,
+        Extension::ExtCompiledVertexArray,
+        Extension::ExtConservativeDepth,
+        Extension::ExtConvolution,
+        Extension::ExtCoordinateFrame,
+        Extension::ExtCopyImage,
+        Extension::ExtCopyTexture,
+        Extension::ExtCullVertex,
+        Extension::ExtDebugLabel,
+        Extension::ExtDebugMarker,
+        Extension::ExtDepthBoundsTest,
+        Extension::ExtDirectStateAccess,
+        Extension::ExtDiscardFramebuffer,
+        Extension::ExtDisjointTimerQuery,
+        Extension::ExtDrawBuffers,
+        Extension::ExtDrawBuffers2,
+        Extension::ExtDrawBuffersIndexed,
+        Extension::ExtDrawElementsBaseVertex,
+        Extension::ExtDrawInstanced,
+        Extension::ExtDrawRangeElements,
+        Extension::ExtDrawTransformFeedback,
+        Extension::ExtExternalBuffer,
+        Extension::ExtFloatBlend,
+        Extension::ExtFogCoord,
+        Extension::ExtFramebufferBlit,
+        Extension::ExtFramebufferMultisample,
+        Extension::ExtFramebufferMultisampleBlitScaled,
+        Extension::ExtFramebufferObject,
+        Extension::ExtFramebufferSrgb,
+        Extension::ExtGeometryPointSize,
+        Extension::ExtGeometryShader,
+        Extension::ExtGeometryShader4,
+        Extension::ExtGpuProgramParameters,
+        Extension::ExtGpuShader4,
+        Extension::ExtGpuShader5,
+        Extension::ExtHistogram,
+        Extension::ExtIndexArrayFormats,
+        Extension::ExtIndexFunc,
+        Extension::ExtIndexMaterial,
+        Extension::ExtIndexTexture,
+        Extension::ExtInstancedArrays,
+        Extension::ExtLightTexture,
+        Extension::ExtMapBufferRange,
+        Extension::ExtMemoryObject,
+        Extension::ExtMemoryObjectFd,
+        Extension::ExtMemoryObjectWin32,
+        Extension::ExtMiscAttribute,
+        Extension::ExtMultiDrawArrays,
+        Extension::ExtMultiDrawIndirect,
+        Extension::ExtMultisample,
+        Extension::ExtMultisampledCompatibility,
+        Extension::ExtMultisampledRenderToTexture,
+        Extension::ExtMultiviewDrawBuffers,
+        Extension::ExtOcclusionQueryBoolean,
+        Extension::ExtPackedDepthStencil,
+        Extension::ExtPackedFloat,
+        Extension::ExtPackedPixels,
+        Extension::ExtPalettedTexture,
+        Extension::ExtPixelBufferObject,
+        Extension::ExtPixelTransform,
+        Extension::ExtPixelTransformColorTable,
+        Extension::ExtPointParameters,
+        Extension::ExtPolygonOffset,
+        Extension::ExtPolygonOffsetClamp,
+        Extension::ExtPostDepthCoverage,
+        Extension::ExtPrimitiveBoundingBox,
+        Extension::ExtProtectedTextures,
+        Extension::ExtProvokingVertex,
+        Extension::ExtPvrtcSrgb,
+        Extension::ExtRasterMultisample,
+        Extension::ExtReadFormatBgra,
+        Extension::ExtRenderSnorm,
+        Extension::ExtRescaleNormal,
+        Extension::ExtRobustness,
+        Extension::ExtSrgb,
+        Extension::ExtSrgbWriteControl,
+        Extension::ExtSecondaryColor,
+        Extension::ExtSemaphore,
+        Extension::ExtSemaphoreFd,
+        Extension::ExtSemaphoreWin32,
+        Extension::ExtSeparateShaderObjects,
+        Extension::ExtSeparateSpecularColor,
+        Extension::ExtShaderFramebufferFetch,
+        Extension::ExtShaderFramebufferFetchNonCoherent,
+        Extension::ExtShaderGroupVote,
+        Extension::ExtShaderImageLoadFormatted,
+        Extension::ExtShaderImageLoadStore,
+        Extension::ExtShaderImplicitConversions,
+        Extension::ExtShaderIntegerMix,
+        Extension::ExtShaderIoBlocks,
+        Extension::ExtShaderNonConstantGlobalInitializers,
+        Extension::ExtShaderPixelLocalStorage,
+        Extension::ExtShaderPixelLocalStorage2,
+        Extension::ExtShaderTextureLod,
+        Extension::ExtShadowFuncs,
+        Extension::ExtShadowSamplers,
+        Extension::ExtSharedTexturePalette,
+        Extension::ExtSparseTexture,
+        Extension::ExtSparseTexture2,
+        Extension::ExtStencilClearTag,
+        Extension::ExtStencilTwoSide,
+        Extension::ExtStencilWrap,
+        Extension::ExtSubtexture,
+        Extension::ExtTessellationPointSize,
+        Extension::ExtTessellationShader,
+        Extension::ExtTexture,
+        Extension::ExtTexture3d,
+        Extension::ExtTextureArray,
+        Extension::ExtTextureBorderClamp,
+        Extension::ExtTextureBuffer,
+        Extension::ExtTextureBufferObject,
+        Extension::ExtTextureCompressionAstcDecodeMode,
+        Extension::ExtTextureCompressionBptc,
+        Extension::ExtTextureCompressionDxt1,
+        Extension::ExtTextureCompressionLatc,
+        Extension::ExtTextureCompressionRgtc,
+        Extension::ExtTextureCompressionS3tc,
+        Extension::ExtTextureCompressionS3tcSrgb,
+        Extension::ExtTextureCubeMap,
+        Extension::ExtTextureCubeMapArray,
+        Extension::ExtTextureEnvAdd,
+        Extension::ExtTextureEnvCombine,
+        Extension::ExtTextureEnvDot3,
+        Extension::ExtTextureFilterAnisotropic,
+        Extension::ExtTextureFilterMinmax,
+        Extension::ExtTextureFormatBgra8888,
+        Extension::ExtTextureFormatSrgbOverride,
+        Extension::ExtTextureInteger,
+        Extension::ExtTextureLodBias,
+        Extension::ExtTextureMirrorClamp,
+        Extension::ExtTextureMirrorClampToEdge,
+        Extension::ExtTextureNorm16,
+        Extension::ExtTextureObject,
+        Extension::ExtTexturePerturbNormal,
+        Extension::ExtTextureRg,
+        Extension::ExtTextureSrgb,
+        Extension::ExtTextureSrgbR8,
+        Extension::ExtTextureSrgbRg8,
+        Extension::ExtTextureSrgbDecode,
+        Extension::ExtTextureSharedExponent,
+        Extension::ExtTextureSnorm,
+        Extension::ExtTextureStorage,
+        Extension::ExtTextureSwizzle,
+        Extension::ExtTextureType2101010Rev,
+        Extension::ExtTextureView,
+        Extension::ExtTimerQuery,
+        Extension::ExtTransformFeedback,
+        Extension::ExtUnpackSubimage,
+        Extension::ExtVertexArray,
+        Extension::ExtVertexArrayBgra,
+        Extension::ExtVertexAttrib64bit,
+        Extension::ExtVertexShader,
+        Extension::ExtVertexWeighting,
+        Extension::ExtWin32KeyedMutex,
+        Extension::ExtWindowRectangles,
+        Extension::ExtX11SyncObject,
+        Extension::FjShaderBinaryGccso,
+        Extension::GremedyFrameTerminator,
+        Extension::GremedyStringMarker,
+        Extension::HpConvolutionBorderModes,
+        Extension::HpImageTransform,
+        Extension::HpOcclusionTest,
+        Extension::HpTextureLighting,
+        Extension::IbmCullVertex,
+        Extension::IbmMultimodeDrawArrays,
+        Extension::IbmRasterposClip,
+        Extension::IbmStaticData,
+        Extension::IbmTextureMirroredRepeat,
+        Extension::IbmVertexArrayLists,
+        Extension::ImgBindlessTexture,
+        Extension::ImgFramebufferDownsample,
+        Extension::ImgMultisampledRenderToTexture,
+        Extension::ImgProgramBinary,
+        Extension::ImgReadFormat,
+        Extension::ImgShaderBinary,
+        Extension::ImgTextureCompressionPvrtc,
+        Extension::ImgTextureCompressionPvrtc2,
+        Extension::ImgTextureEnvEnhancedFixedFunction,
+        Extension::ImgTextureFilterCubic,
+        Extension::ImgUserClipPlane,
+        Extension::IngrBlendFuncSeparate,
+        Extension::IngrColorClamp,
+        Extension::IngrInterlaceRead,
+        Extension::IntelBlackholeRender,
+        Extension::IntelConservativeRasterization,
+        Extension::IntelFragmentShaderOrdering,
+        Extension::IntelFramebufferCmaa,
+        Extension::IntelMapTexture,
+        Extension::IntelParallelArrays,
+        Extension::IntelPerformanceQuery,
+        Extension::KhrBlendEquationAdvanced,
+        Extension::KhrBlendEquationAdvancedCoherent,
+        Extension::KhrContextFlushControl,
+        Extension::KhrDebug,
+        Extension::KhrNoError,
+        Extension::KhrParallelShaderCompile,
+        Extension::KhrRobustBufferAccessBehavior,
+        Extension::KhrRobustness,
+        Extension::KhrTextureCompressionAstcHdr,
+        Extension::KhrTextureCompressionAstcLdr,
+        Extension::KhrTextureCompressionAstcSliced3d,
+        Extension::MesaxTextureStack,
+        Extension::MesaFramebufferFlipY,
+        Extension::MesaPackInvert,
+        Extension::MesaProgramBinaryFormats,
+        Extension::MesaResizeBuffers,
+        Extension::MesaShaderIntegerFunctions,
+        Extension::MesaTileRasterOrder,
+        Extension::MesaWindowPos,
+        Extension::MesaYcbcrTexture,
+        Extension::NvxBlendEquationAdvancedMultiDrawBuffers,
+        Extension::NvxConditionalRender,
+        Extension::NvxCrossProcessInterop,
+        Extension::NvxGpuMemoryInfo,
+        Extension::NvxLinkedGpuMulticast,
+        Extension::NvAlphaToCoverageDitherControl,
+        Extension::NvBindlessMultiDrawIndirect,
+        Extension::NvBindlessMultiDrawIndirectCount,
+        Extension::NvBindlessTexture,
+        Extension::NvBlendEquationAdvanced,
+        Extension::NvBlendEquationAdvancedCoherent,
+        Extension::NvBlendMinmaxFactor,
+        Extension::NvBlendSquare,
+        Extension::NvClipSpaceWScaling,
+        Extension::NvCommandList,
+        Extension::NvComputeProgram5,
+        Extension::NvComputeShaderDerivatives,
+        Extension::NvConditionalRender,
+        Extension::NvConservativeRaster,
+        Extension::NvConservativeRasterDilate,
+        Extension::NvConservativeRasterPreSnap,
+        Extension::NvConservativeRasterPreSnapTriangles,
+        Extension::NvConservativeRasterUnderestimation,
+        Extension::NvCopyBuffer,
+        Extension::NvCopyDepthToColor,
+        Extension::NvCopyImage,
+        Extension::NvCoverageSample,
+        Extension::NvDeepTexture3d,
+        Extension::NvDepthBufferFloat,
+        Extension::NvDepthClamp,
+        Extension::NvDepthNonlinear,
+        Extension::NvDrawBuffers,
+        Extension::NvDrawInstanced,
+        Extension::NvDrawTexture,
+        Extension::NvDrawVulkanImage,
+        Extension::NvEvaluators,
+        Extension::NvExplicitAttribLocation,
+        Extension::NvExplicitMultisample,
+        Extension::NvFboColorAttachments,
+        Extension::NvFence,
+        Extension::NvFillRectangle,
+        Extension::NvFloatBuffer,
+        Extension::NvFogDistance,
+        Extension::NvFragmentCoverageToColor,
+        Extension::NvFragmentProgram,
+        Extension::NvFragmentProgram2,
+        Extension::NvFragmentProgram4,
+        Extension::NvFragmentProgramOption,
+        Extension::NvFragmentShaderBarycentric,
+        Extension::NvFragmentShaderInterlock,
+        Extension::NvFramebufferBlit,
+        Extension::NvFramebufferMixedSamples,
+        Extension::NvFramebufferMultisample,
+        Extension::NvFramebufferMultisampleCoverage,
+        Extension::NvGenerateMipmapSrgb,
+        Extension::NvGeometryProgram4,
+        Extension::NvGeometryShader4,
+        Extension::NvGeometryShaderPassthrough,
+        Extension::NvGpuMulticast,
+        Extension::NvGpuProgram4,
+        Extension::NvGpuProgram5,
+        Extension::NvGpuProgram5MemExtended,
+        Extension::NvGpuShader5,
+        Extension::NvHalfFloat,
+        Extension::NvImageFormats,
+        Extension::NvInstancedArrays,
+        Extension::NvInternalformatSampleQuery,
+        Extension::NvLightMaxExponent,
+        Extension::NvMemoryAttachment,
+        Extension::NvMeshShader,
+        Extension::NvMultisampleCoverage,
+        Extension::NvMultisampleFilterHint,
+        Extension::NvNonSquareMatrices,
+        Extension::NvOcclusionQuery,
+        Extension::NvPackedDepthStencil,
+        Extension::NvParameterBufferObject,
+        Extension::NvParameterBufferObject2,
+        Extension::NvPathRendering,
+        Extension::NvPathRenderingSharedEdge,
+        Extension::NvPixelBufferObject,
+        Extension::NvPixelDataRange,
+        Extension::NvPointSprite,
+        Extension::NvPolygonMode,
+        Extension::NvPresentVideo,
+        Extension::NvPrimitiveRestart,
+        Extension::NvQueryResource,
+        Extension::NvQueryResourceTag,
+        Extension::NvReadBuffer,
+        Extension::NvReadBufferFront,
+        Extension::NvReadDepth,
+        Extension::NvReadDepthStencil,
+        Extension::NvReadStencil,
+        Extension::NvRegisterCombiners,
+        Extension::NvRegisterCombiners2,
+        Extension::NvRepresentativeFragmentTest,
+        Extension::NvRobustnessVideoMemoryPurge,
+        Extension::NvSrgbFormats,
+        Extension::NvSampleLocations,
+        Extension::NvSampleMaskOverrideCoverage,
+        Extension::NvScissorExclusive,
+        Extension::NvShaderAtomicCounters,
+        Extension::NvShaderAtomicFloat,
+        Extension::NvShaderAtomicFloat64,
+        Extension::NvShaderAtomicFp16Vector,
+        Extension::NvShaderAtomicInt64,
+        Extension::NvShaderBufferLoad,
+        Extension::NvShaderBufferStore,
+        Extension::NvShaderNoperspectiveInterpolation,
+        Extension::NvShaderStorageBufferObject,
+        Extension::NvShaderTextureFootprint,
+        Extension::NvShaderThreadGroup,
+        Extension::NvShaderThreadShuffle,
+        Extension::NvShadingRateImage,
+        Extension::NvShadowSamplersArray,
+        Extension::NvShadowSamplersCube,
+        Extension::NvStereoViewRendering,
+        Extension::NvTessellationProgram5,
+        Extension::NvTexgenEmboss,
+        Extension::NvTexgenReflection,
+        Extension::NvTextureBarrier,
+        Extension::NvTextureBorderClamp,
+        Extension::NvTextureCompressionS3tcUpdate,
+        Extension::NvTextureCompressionVtc,
+        Extension::NvTextureEnvCombine4,
+        Extension::NvTextureExpandNormal,
+        Extension::NvTextureMultisample,
+        Extension::NvTextureNpot2dMipmap,
+        Extension::NvTextureRectangle,
+        Extension::NvTextureRectangleCompressed,
+        Extension::NvTextureShader,
+        Extension::NvTextureShader2,
+        Extension::NvTextureShader3,
+        Extension::NvTransformFeedback,
+        Extension::NvTransformFeedback2,
+        Extension::NvUniformBufferUnifiedMemory,
+        Extension::NvVdpauInterop,
+        Extension::NvVdpauInterop2,
+        Extension::NvVertexArrayRange,
+        Extension::NvVertexArrayRange2,
+        Extension::NvVertexAttribInteger64bit,
+        Extension::NvVertexBufferUnifiedMemory,
+        Extension::NvVertexProgram,
+        Extension::NvVertexProgram11,
+        Extension::NvVertexProgram2,
+        Extension::NvVertexProgram2Option,
+        Extension::NvVertexProgram3,
+        Extension::NvVertexProgram4,
+        Extension::NvVideoCapture,
+        Extension::NvViewportArray,
+        Extension::NvViewportArray2,
+        Extension::NvViewportSwizzle,
+        Extension::OesEglImage,
+        Extension::OesEglImageExternal,
+        Extension::OesEglImageExternalEssl3,
+        Extension::OesBlendEquationSeparate,
+        Extension::OesBlendFuncSeparate,
+        Extension::OesBlendSubtract,
+        Extension::OesByteCoordinates,
+        Extension::OesCompressedEtc1Rgb8SubTexture,
+        Extension::OesCompressedEtc1Rgb8Texture,
+        Extension::OesCompressedPalettedTexture,
+        Extension::OesCopyImage,
+        Extension::OesDepth24,
+        Extension::OesDepth32,
+        Extension::OesDepthTexture,
+        Extension::OesDrawBuffersIndexed,
+        Extension::OesDrawElementsBaseVertex,
+        Extension::OesDrawTexture,
+        Extension::OesElementIndexUint,
+        Extension::OesExtendedMatrixPalette,
+        Extension::OesFboRenderMipmap,
+        Extension::OesFixedPoint,
+        Extension::OesFragmentPrecisionHigh,
+        Extension::OesFramebufferObject,
+        Extension::OesGeometryPointSize,
+        Extension::OesGeometryShader,
+        Extension::OesGetProgramBinary,
+        Extension::OesGpuShader5,
+        Extension::OesMapbuffer,
+        Extension::OesMatrixGet,
+        Extension::OesMatrixPalette,
+        Extension::OesPackedDepthStencil,
+        Extension::OesPointSizeArray,
+        Extension::OesPointSprite,
+        Extension::OesPrimitiveBoundingBox,
+        Extension::OesQueryMatrix,
+        Extension::OesReadFormat,
+        Extension::OesRequiredInternalformat,
+        Extension::OesRgb8Rgba8,
+        Extension::OesSampleShading,
+        Extension::OesSampleVariables,
+        Extension::OesShaderImageAtomic,
+        Extension::OesShaderIoBlocks,
+        Extension::OesShaderMultisampleInterpolation,
+        Extension::OesSinglePrecision,
+        Extension::OesStandardDerivatives,
+        Extension::OesStencil1,
+        Extension::OesStencil4,
+        Extension::OesStencil8,
+        Extension::OesStencilWrap,
+        Extension::OesSurfacelessContext,
+        Extension::OesTessellationPointSize,
+        Extension::OesTessellationShader,
+        Extension::OesTexture3d,
+        Extension::OesTextureBorderClamp,
+        Extension::OesTextureBuffer,
+        Extension::OesTextureCompressionAstc,
+        Extension::OesTextureCubeMap,
+        Extension::OesTextureCubeMapArray,
+        Extension::OesTextureEnvCrossbar,
+        Extension::OesTextureFloat,
+        Extension::OesTextureFloatLinear,
+        Extension::OesTextureHalfFloat,
+        Extension::OesTextureHalfFloatLinear,
+        Extension::OesTextureMirroredRepeat,
+        Extension::OesTextureNpot,
+        Extension::OesTextureStencil8,
+        Extension::OesTextureStorageMultisample2dArray,
+        Extension::OesTextureView,
+        Extension::OesVertexArrayObject,
+        Extension::OesVertexHalfFloat,
+        Extension::OesVertexType1010102,
+        Extension::OesViewportArray,
+        Extension::OmlInterlace,
+        Extension::OmlResample,
+        Extension::OmlSubsample,
+        Extension::OvrMultiview,
+        Extension::OvrMultiview2,
+        Extension::OvrMultiviewMultisampledRenderToTexture,
+        Extension::PgiMiscHints,
+        Extension::PgiVertexHints,
+        Extension::QcomYuvTextureGather,
+        Extension::QcomAlphaTest,
+        Extension::QcomBinningControl,
+        Extension::QcomDriverControl,
+        Extension::QcomExtendedGet,
+        Extension::QcomExtendedGet2,
+        Extension::QcomFramebufferFoveated,
+        Extension::QcomPerfmonGlobalMode,
+        Extension::QcomShaderFramebufferFetchNoncoherent,
+        Extension::QcomShaderFramebufferFetchRate,
+        Extension::QcomTextureFoveated,
+        Extension::QcomTextureFoveatedSubsampledLayout,
+        Extension::QcomTiledRendering,
+        Extension::QcomWriteonlyRendering,
+        Extension::RendScreenCoordinates,
+        Extension::S3S3tc,
+        Extension::SgisDetailTexture,
+        Extension::SgisFogFunction,
+        Extension::SgisGenerateMipmap,
+        Extension::SgisMultisample,
+        Extension::SgisPixelTexture,
+        Extension::SgisPointLineTexgen,
+        Extension::SgisPointParameters,
+        Extension::SgisSharpenTexture,
+        Extension::SgisTexture4d,
+        Extension::SgisTextureBorderClamp,
+        Extension::SgisTextureColorMask,
+        Extension::SgisTextureEdgeClamp,
+        Extension::SgisTextureFilter4,
+        Extension::SgisTextureLod,
+        Extension::SgisTextureSelect,
+        Extension::SgixAsync,
+        Extension::SgixAsyncHistogram,
+        Extension::SgixAsyncPixel,
+        Extension::SgixBlendAlphaMinmax,
+        Extension::SgixCalligraphicFragment,
+        Extension::SgixClipmap,
+        Extension::SgixConvolutionAccuracy,
+        Extension::SgixDepthPassInstrument,
+        Extension::SgixDepthTexture,
+        Extension::SgixFlushRaster,
+        Extension::SgixFogOffset,
+        Extension::SgixFragmentLighting,
+        Extension::SgixFramezoom,
+        Extension::SgixIglooInterface,
+        Extension::SgixInstruments,
+        Extension::SgixInterlace,
+        Extension::SgixIrInstrument1,
+        Extension::SgixListPriority,
+        Extension::SgixPixelTexture,
+        Extension::SgixPixelTiles,
+        Extension::SgixPolynomialFfd,
+        Extension::SgixReferencePlane,
+        Extension::SgixResample,
+        Extension::SgixScalebiasHint,
+        Extension::SgixShadow,
+        Extension::SgixShadowAmbient,
+        Extension::SgixSprite,
+        Extension::SgixSubsample,
+        Extension::SgixTagSampleBuffer,
+        Extension::SgixTextureAddEnv,
+        Extension::SgixTextureCoordinateClamp,
+        Extension::SgixTextureLodBias,
+        Extension::SgixTextureMultiBuffer,
+        Extension::SgixTextureScaleBias,
+        Extension::SgixVertexPreclip,
+        Extension::SgixYcrcb,
+        Extension::SgixYcrcbSubsample,
+        Extension::SgixYcrcba,
+        Extension::SgiColorMatrix,
+        Extension::SgiColorTable,
+        Extension::SgiTextureColorTable,
+        Extension::SunxConstantData,
+        Extension::SunConvolutionBorderModes,
+        Extension::SunGlobalAlpha,
+        Extension::SunMeshArray,
+        Extension::SunSliceAccum,
+        Extension::SunTriangleList,
+        Extension::SunVertex,
+        Extension::VivShaderBinary,
+        Extension::WinPhongShading,
+        Extension::WinSpecularFog,
+        Extension::WebglBlendEquationAdvancedCoherent,
+        Extension::WebglClipCullDistance,
+        Extension::WebglColorBufferFloat,
+        Extension::WebglCompressedTextureAstc,
+        Extension::WebglCompressedTextureEtc,
+        Extension::WebglCompressedTextureEtc1,
+        Extension::WebglCompressedTexturePvrtc,
+        Extension::WebglCompressedTextureS3tc,
+        Extension::WebglCompressedTextureS3tcSrgb,
+        Extension::WebglDebugRendererInfo,
+        Extension::WebglDebugShaders,
+        Extension::WebglDepthTexture,
+        Extension::WebglDrawBuffers,
+        Extension::WebglDrawInstancedBaseVertexBaseInstance,
+        Extension::WebglLoseContext,
+        Extension::WebglMultiDraw,
+        Extension::WebglMultiDrawInstancedBaseVertexBaseInstance,
+        Extension::WebglPolygonMode,
+        Extension::WebglProvokingVertex,
+        Extension::WebglRenderSharedExponent,
+        Extension::WebglStencilTexturing,
+    ];
+}
+
+/// The name of each extension in [`Extension::ALL`], in the same order.
+pub(crate) static NAMES: [&str; 842] = [
+    "EXT_depth_clamp",
+    "EXT_disjoint_timer_query_webgl2",
+    "EXT_frag_depth",
+    "GL_3DFX_multisample",
+    "GL_3DFX_tbuffer",
+    "GL_3DFX_texture_compression_FXT1",
+    "GL_AMD_blend_minmax_factor",
+    "GL_AMD_compressed_3DC_texture",
+    "GL_AMD_compressed_ATC_texture",
+    "GL_AMD_conservative_depth",
+    "GL_AMD_debug_output",
+    "GL_AMD_depth_clamp_separate",
+    "GL_AMD_draw_buffers_blend",
+    "GL_AMD_framebuffer_multisample_advanced",
+    "GL_AMD_framebuffer_sample_positions",
+    "GL_AMD_gcn_shader",
+    "GL_AMD_gpu_shader_half_float",
+    "GL_AMD_gpu_shader_int16",
+    "GL_AMD_gpu_shader_int64",
+    "GL_AMD_interleaved_elements",
+    "GL_AMD_multi_draw_indirect",
+    "GL_AMD_name_gen_delete",
+    "GL_AMD_occlusion_query_event",
+    "GL_AMD_performance_monitor",
+    "GL_AMD_pinned_memory",
+    "GL_AMD_program_binary_Z400",
+    "GL_AMD_query_buffer_object",
+    "GL_AMD_sample_positions",
+    "GL_AMD_seamless_cubemap_per_texture",
+    "GL_AMD_shader_atomic_counter_ops",
+    "GL_AMD_shader_ballot",
+    "GL_AMD_shader_explicit_vertex_parameter",
+    "GL_AMD_shader_gpu_shader_half_float_fetch",
+    "GL_AMD_shader_image_load_store_lod",
+    "GL_AMD_shader_stencil_export",
+    "GL_AMD_shader_trinary_minmax",
+    "GL_AMD_sparse_texture",
+    "GL_AMD_stencil_operation_extended",
+    "GL_AMD_texture_gather_bias_lod",
+    "GL_AMD_texture_texture4",
+    "GL_AMD_transform_feedback3_lines_triangles",
+    "GL_AMD_transform_feedback4",
+    "GL_AMD_vertex_shader_layer",
+    "GL_AMD_vertex_shader_tessellator",
+    "GL_AMD_vertex_shader_viewport_index",
+    "GL_ANDROID_extension_pack_es31a",
+    "GL_ANGLE_depth_texture",
+    "GL_ANGLE_framebuffer_blit",
+    "GL_ANGLE_framebuffer_multisample",
+    "GL_ANGLE_instanced_arrays",
+    "GL_ANGLE_pack_reverse_row_order",
+    "GL_ANGLE_program_binary",
+    "GL_ANGLE_texture_compression_dxt3",
+    "GL_ANGLE_texture_compression_dxt5",
+    "GL_ANGLE_texture_usage",
+    "GL_ANGLE_translated_shader_source",
+    "GL_APPLE_aux_depth_stencil",
+    "GL_APPLE_client_storage",
+    "GL_APPLE_clip_distance",
+    "GL_APPLE_color_buffer_packed_float",
+    "GL_APPLE_copy_texture_levels",
+    "GL_APPLE_element_array",
+    "GL_APPLE_fence",
+    "GL_APPLE_float_pixels",
+    "GL_APPLE_flush_buffer_range",
+    "GL_APPLE_framebuffer_multisample",
+    "GL_APPLE_object_purgeable",
+    "GL_APPLE_rgb_422",
+    "GL_APPLE_row_bytes",
+    "GL_APPLE_specular_vector",
+    "GL_APPLE_sync",
+    "GL_APPLE_texture_2D_limited_npot",
+    "GL_APPLE_texture_format_BGRA8888",
+    "GL_APPLE_texture_max_level",
+    "GL_APPLE_texture_packed_float",
+    "GL_APPLE_texture_range",
+    "GL_APPLE_transform_hint",
+    "GL_APPLE_vertex_array_object",
+    "GL_APPLE_vertex_array_range",
+    "GL_APPLE_vertex_program_evaluators",
+    "GL_APPLE_ycbcr_422",
+    "GL_ARB_ES2_compatibility",
+    "GL_ARB_ES3_1_compatibility",
+    "GL_ARB_ES3_2_compatibility",
+    "GL_ARB_ES3_compatibility",
+    "GL_ARB_arrays_of_arrays",
+    "GL_ARB_base_instance",
+    "GL_ARB_bindless_texture",
+    "GL_ARB_blend_func_extended",
+    "GL_ARB_buffer_storage",
+    "GL_ARB_cl_event",
+    "GL_ARB_clear_buffer_object",
+    "GL_ARB_clear_texture",
+    "GL_ARB_clip_control",
+    "GL_ARB_color_buffer_float",
+    "GL_ARB_compatibility",
+    "GL_ARB_compressed_texture_pixel_storage",
+    "GL_ARB_compute_shader",
+    "GL_ARB_compute_variable_group_size",
+    "GL_ARB_conditional_render_inverted",
+    "GL_ARB_conservative_depth",
+    "GL_ARB_copy_buffer",
+    "GL_ARB_copy_image",
+    "GL_ARB_cull_distance",
+    "GL_ARB_debug_output",
+    "GL_ARB_depth_buffer_float",
+    "GL_ARB_depth_clamp",
+    "GL_ARB_depth_texture",
+    "GL_ARB_derivative_control",
+    "GL_ARB_direct_state_access",
+    "GL_ARB_draw_buffers",
+    "GL_ARB_draw_buffers_blend",
+    "GL_ARB_draw_elements_base_vertex",
+    "GL_ARB_draw_indirect",
+    "GL_ARB_draw_instanced",
+    "GL_ARB_enhanced_layouts",
+    "GL_ARB_explicit_attrib_location",
+    "GL_ARB_explicit_uniform_location",
+    "GL_ARB_fragment_coord_conventions",
+    "GL_ARB_fragment_layer_viewport",
+    "GL_ARB_fragment_program",
+    "GL_ARB_fragment_program_shadow",
+    "GL_ARB_fragment_shader",
+    "GL_ARB_fragment_shader_interlock",
+    "GL_ARB_framebuffer_no_attachments",
+    "GL_ARB_framebuffer_object",
+    "GL_ARB_framebuffer_sRGB",
+    "GL_ARB_geometry_shader4",
+    "GL_ARB_get_program_binary",
+    "GL_ARB_get_texture_sub_image",
+    "GL_ARB_gl_spirv",
+    "GL_ARB_gpu_shader5",
+    "GL_ARB_gpu_shader_fp64",
+    "GL_ARB_gpu_shader_int64",
+    "GL_ARB_half_float_pixel",
+    "GL_ARB_half_float_vertex",
+    "GL_ARB_imaging",
+    "GL_ARB_indirect_parameters",
+    "GL_ARB_instanced_arrays",
+    "GL_ARB_internalformat_query",
+    "GL_ARB_internalformat_query2",
+    "GL_ARB_invalidate_subdata",
+    "GL_ARB_map_buffer_alignment",
+    "GL_ARB_map_buffer_range",
+    "GL_ARB_matrix_palette",
+    "GL_ARB_multi_bind",
+    "GL_ARB_multi_draw_indirect",
+    "GL_ARB_multisample",
+    "GL_ARB_multitexture",
+    "GL_ARB_occlusion_query",
+    "GL_ARB_occlusion_query2",
+    "GL_ARB_parallel_shader_compile",
+    "GL_ARB_pipeline_statistics_query",
+    "GL_ARB_pixel_buffer_object",
+    "GL_ARB_point_parameters",
+    "GL_ARB_point_sprite",
+    "GL_ARB_polygon_offset_clamp",
+    "GL_ARB_post_depth_coverage",
+    "GL_ARB_program_interface_query",
+    "GL_ARB_provoking_vertex",
+    "GL_ARB_query_buffer_object",
+    "GL_ARB_robust_buffer_access_behavior",
+    "GL_ARB_robustness",
+    "GL_ARB_robustness_isolation",
+    "GL_ARB_sample_locations",
+    "GL_ARB_sample_shading",
+    "GL_ARB_sampler_objects",
+    "GL_ARB_seamless_cube_map",
+    "GL_ARB_seamless_cubemap_per_texture",
+    "GL_ARB_separate_shader_objects",
+    "GL_ARB_shader_atomic_counter_ops",
+    "GL_ARB_shader_atomic_counters",
+    "GL_ARB_shader_ballot",
+    "GL_ARB_shader_bit_encoding",
+    "GL_ARB_shader_clock",
+    "GL_ARB_shader_draw_parameters",
+    "GL_ARB_shader_group_vote",
+    "GL_ARB_shader_image_load_store",
+    "GL_ARB_shader_image_size",
+    "GL_ARB_shader_objects",
+    "GL_ARB_shader_precision",
+    "GL_ARB_shader_stencil_export",
+    "GL_ARB_shader_storage_buffer_object",
+    "GL_ARB_shader_subroutine",
+    "GL_ARB_shader_texture_image_samples",
+    "GL_ARB_shader_texture_lod",
+    "GL_ARB_shader_viewport_layer_array",
+    "GL_ARB_shading_language_100",
+    "GL_ARB_shading_language_420pack",
+    "GL_ARB_shading_language_include",
+    "GL_ARB_shading_language_packing",
+    "GL_ARB_shadow",
+    "GL_ARB_shadow_ambient",
+    "GL_ARB_sparse_buffer",
+    "GL_ARB_sparse_texture",
+    "GL_ARB_sparse_texture2",
+    "GL_ARB_sparse_texture_clamp",
+    "GL_ARB_spirv_extensions",
+    "GL_ARB_stencil_texturing",
+    "GL_ARB_sync",
+    "GL_ARB_tessellation_shader",
+    "GL_ARB_texture_barrier",
+    "GL_ARB_texture_border_clamp",
+    "GL_ARB_texture_buffer_object",
+    "GL_ARB_texture_buffer_object_rgb32",
+    "GL_ARB_texture_buffer_range",
+    "GL_ARB_texture_compression",
+    "GL_ARB_texture_compression_bptc",
+    "GL_ARB_texture_compression_rgtc",
+    "GL_ARB_texture_cube_map",
+    "GL_ARB_texture_cube_map_array",
+    "GL_ARB_texture_env_add",
+    "GL_ARB_texture_env_combine",
+    "GL_ARB_texture_env_crossbar",
+    "GL_ARB_texture_env_dot3",
+    "GL_ARB_texture_filter_anisotropic",
+    "GL_ARB_texture_filter_minmax",
+    "GL_ARB_texture_float",
+    "GL_ARB_texture_gather",
+    "GL_ARB_texture_mirror_clamp_to_edge",
+    "GL_ARB_texture_mirrored_repeat",
+    "GL_ARB_texture_multisample",
+    "GL_ARB_texture_non_power_of_two",
+    "GL_ARB_texture_query_levels",
+    "GL_ARB_texture_query_lod",
+    "GL_ARB_texture_rectangle",
+    "GL_ARB_texture_rg",
+    "GL_ARB_texture_rgb10_a2ui",
+    "GL_ARB_texture_stencil8",
+    "GL_ARB_texture_storage",
+    "GL_ARB_texture_storage_multisample",
+    "GL_ARB_texture_swizzle",
+    "GL_ARB_texture_view",
+    "GL_ARB_timer_query",
+    "GL_ARB_transform_feedback2",
+    "GL_ARB_transform_feedback3",
+    "GL_ARB_transform_feedback_instanced",
+    "GL_ARB_transform_feedback_overflow_query",
+    "GL_ARB_transpose_matrix",
+    "GL_ARB_uniform_buffer_object",
+    "GL_ARB_vertex_array_bgra",
+    "GL_ARB_vertex_array_object",
+    "GL_ARB_vertex_attrib_64bit",
+    "GL_ARB_vertex_attrib_binding",
+    "GL_ARB_vertex_blend",
+    "GL_ARB_vertex_buffer_object",
+    "GL_ARB_vertex_program",
+    "GL_ARB_vertex_shader",
+    "GL_ARB_vertex_type_10f_11f_11f_rev",
+    "GL_ARB_vertex_type_2_10_10_10_rev",
+    "GL_ARB_viewport_array",
+    "GL_ARB_window_pos",
+    "GL_ARM_mali_program_binary",
+    "GL_ARM_mali_shader_binary",
+    "GL_ARM_rgba8",
+    "GL_ARM_shader_framebuffer_fetch",
+    "GL_ARM_shader_framebuffer_fetch_depth_stencil",
+    "GL_ATI_draw_buffers",
+    "GL_ATI_element_array",
+    "GL_ATI_envmap_bumpmap",
+    "GL_ATI_fragment_shader",
+    "GL_ATI_map_object_buffer",
+    "GL_ATI_meminfo",
+    "GL_ATI_pixel_format_float",
+    "GL_ATI_pn_triangles",
+    "GL_ATI_separate_stencil",
+    "GL_ATI_text_fragment_shader",
+    "GL_ATI_texture_env_combine3",
+    "GL_ATI_texture_float",
+    "GL_ATI_texture_mirror_once",
+    "GL_ATI_vertex_array_object",
+    "GL_ATI_vertex_attrib_array_object",
+    "GL_ATI_vertex_streams",
+    "GL_DMP_program_binary",
+    "GL_DMP_shader_binary",
+    "GL_EXT_422_pixels",
+    "GL_EXT_EGL_image_array",
+    "GL_EXT_EGL_image_storage",
+    "GL_EXT_YUV_target",
+    "GL_EXT_abgr",
+    "GL_EXT_base_instance",
+    "GL_EXT_bgra",
+    "GL_EXT_bindable_uniform",
+    "GL_EXT_blend_color",
+    "GL_EXT_blend_equation_separate",
+    "GL_EXT_blend_func_extended",
+    "GL_EXT_blend_func_separate",
+    "GL_EXT_blend_logic_op",
+    "GL_EXT_blend_minmax",
+    "GL_EXT_blend_subtract",
+    "GL_EXT_buffer_storage",
+    "GL_EXT_clear_texture",
+    "GL_EXT_clip_control",
+    "GL_EXT_clip_cull_distance",
+    "GL_EXT_clip_volume_hint",
+    "GL_EXT_cmyka",
+    "GL_EXT_color_buffer_float",
+    "GL_EXT_color_buffer_half_float",
+    "GL_EXT_color_subtable",
+    "GL_EXT_compiled_vertex_array",
+    "GL_EXT_conservative_depth",
+    "GL_EXT_convolution",
+    "GL_EXT_coordinate_frame",
+    "GL_EXT_copy_image",
+    "GL_EXT_copy_texture",
+    "GL_EXT_cull_vertex",
+    "GL_EXT_debug_label",
+    "GL_EXT_debug_marker",
+    "GL_EXT_depth_bounds_test",
+    "GL_EXT_direct_state_access",
+    "GL_EXT_discard_framebuffer",
+    "GL_EXT_disjoint_timer_query",
+    "GL_EXT_draw_buffers",
+    "GL_EXT_draw_buffers2",
+    "GL_EXT_draw_buffers_indexed",
+    "GL_EXT_draw_elements_base_vertex",
+    "GL_EXT_draw_instanced",
+    "GL_EXT_draw_range_elements",
+    "GL_EXT_draw_transform_feedback",
+    "GL_EXT_external_buffer",
+    "GL_EXT_float_blend",
+    "GL_EXT_fog_coord",
+    "GL_EXT_framebuffer_blit",
+    "GL_EXT_framebuffer_multisample",
+    "GL_EXT_framebuffer_multisample_blit_scaled",
+    "GL_EXT_framebuffer_object",
+    "GL_EXT_framebuffer_sRGB",
+    "GL_EXT_geometry_point_size",
+    "GL_EXT_geometry_shader",
+    "GL_EXT_geometry_shader4",
+    "GL_EXT_gpu_program_parameters",
+    "GL_EXT_gpu_shader4",
+    "GL_EXT_gpu_shader5",
+    "GL_EXT_histogram",
+    "GL_EXT_index_array_formats",
+    "GL_EXT_index_func",
+    "GL_EXT_index_material",
+    "GL_EXT_index_texture",
+    "GL_EXT_instanced_arrays",
+    "GL_EXT_light_texture",
+    "GL_EXT_map_buffer_range",
+    "GL_EXT_memory_object",
+    "GL_EXT_memory_object_fd",
+    "GL_EXT_memory_object_win32",
+    "GL_EXT_misc_attribute",
+    "GL_EXT_multi_draw_arrays",
+    "GL_EXT_multi_draw_indirect",
+    "GL_EXT_multisample",
+    "GL_EXT_multisampled_compatibility",
+    "GL_EXT_multisampled_render_to_texture",
+    "GL_EXT_multiview_draw_buffers",
+    "GL_EXT_occlusion_query_boolean",
+    "GL_EXT_packed_depth_stencil",
+    "GL_EXT_packed_float",
+    "GL_EXT_packed_pixels",
+    "GL_EXT_paletted_texture",
+    "GL_EXT_pixel_buffer_object",
+    "GL_EXT_pixel_transform",
+    "GL_EXT_pixel_transform_color_table",
+    "GL_EXT_point_parameters",
+    "GL_EXT_polygon_offset",
+    "GL_EXT_polygon_offset_clamp",
+    "GL_EXT_post_depth_coverage",
+    "GL_EXT_primitive_bounding_box",
+    "GL_EXT_protected_textures",
+    "GL_EXT_provoking_vertex",
+    "GL_EXT_pvrtc_sRGB",
+    "GL_EXT_raster_multisample",
+    "GL_EXT_read_format_bgra",
+    "GL_EXT_render_snorm",
+    "GL_EXT_rescale_normal",
+    "GL_EXT_robustness",
+    "GL_EXT_sRGB",
+    "GL_EXT_sRGB_write_control",
+    "GL_EXT_secondary_color",
+    "GL_EXT_semaphore",
+    "GL_EXT_semaphore_fd",
+    "GL_EXT_semaphore_win32",
+    "GL_EXT_separate_shader_objects",
+    "GL_EXT_separate_specular_color",
+    "GL_EXT_shader_framebuffer_fetch",
+    "GL_EXT_shader_framebuffer_fetch_non_coherent",
+    "GL_EXT_shader_group_vote",
+    "GL_EXT_shader_image_load_formatted",
+    "GL_EXT_shader_image_load_store",
+    "GL_EXT_shader_implicit_conversions",
+    "GL_EXT_shader_integer_mix",
+    "GL_EXT_shader_io_blocks",
+    "GL_EXT_shader_non_constant_global_initializers",
+    "GL_EXT_shader_pixel_local_storage",
+    "GL_EXT_shader_pixel_local_storage2",
+    "GL_EXT_shader_texture_lod",
+    "GL_EXT_shadow_funcs",
+    "GL_EXT_shadow_samplers",
+    "GL_EXT_shared_texture_palette",
+    "GL_EXT_sparse_texture",
+    "GL_EXT_sparse_texture2",
+    "GL_EXT_stencil_clear_tag",
+    "GL_EXT_stencil_two_side",
+    "GL_EXT_stencil_wrap",
+    "GL_EXT_subtexture",
+    "GL_EXT_tessellation_point_size",
+    "GL_EXT_tessellation_shader",
+    "GL_EXT_texture",
+    "GL_EXT_texture3D",
+    "GL_EXT_texture_array",
+    "GL_EXT_texture_border_clamp",
+    "GL_EXT_texture_buffer",
+    "GL_EXT_texture_buffer_object",
+    "GL_EXT_texture_compression_astc_decode_mode",
+    "GL_EXT_texture_compression_bptc",
+    "GL_EXT_texture_compression_dxt1",
+    "GL_EXT_texture_compression_latc",
+    "GL_EXT_texture_compression_rgtc",
+    "GL_EXT_texture_compression_s3tc",
+    "GL_EXT_texture_compression_s3tc_srgb",
+    "GL_EXT_texture_cube_map",
+    "GL_EXT_texture_cube_map_array",
+    "GL_EXT_texture_env_add",
+    "GL_EXT_texture_env_combine",
+    "GL_EXT_texture_env_dot3",
+    "GL_EXT_texture_filter_anisotropic",
+    "GL_EXT_texture_filter_minmax",
+    "GL_EXT_texture_format_BGRA8888",
+    "GL_EXT_texture_format_sRGB_override",
+    "GL_EXT_texture_integer",
+    "GL_EXT_texture_lod_bias",
+    "GL_EXT_texture_mirror_clamp",
+    "GL_EXT_texture_mirror_clamp_to_edge",
+    "GL_EXT_texture_norm16",
+    "GL_EXT_texture_object",
+    "GL_EXT_texture_perturb_normal",
+    "GL_EXT_texture_rg",
+    "GL_EXT_texture_sRGB",
+    "GL_EXT_texture_sRGB_R8",
+    "GL_EXT_texture_sRGB_RG8",
+    "GL_EXT_texture_sRGB_decode",
+    "GL_EXT_texture_shared_exponent",
+    "GL_EXT_texture_snorm",
+    "GL_EXT_texture_storage",
+    "GL_EXT_texture_swizzle",
+    "GL_EXT_texture_type_2_10_10_10_REV",
+    "GL_EXT_texture_view",
+    "GL_EXT_timer_query",
+    "GL_EXT_transform_feedback",
+    "GL_EXT_unpack_subimage",
+    "GL_EXT_vertex_array",
+    "GL_EXT_vertex_array_bgra",
+    "GL_EXT_vertex_attrib_64bit",
+    "GL_EXT_vertex_shader",
+    "GL_EXT_vertex_weighting",
+    "GL_EXT_win32_keyed_mutex",
+    "GL_EXT_window_rectangles",
+    "GL_EXT_x11_sync_object",
+    "GL_FJ_shader_binary_GCCSO",
+    "GL_GREMEDY_frame_terminator",
+    "GL_GREMEDY_string_marker",
+    "GL_HP_convolution_border_modes",
+    "GL_HP_image_transform",
+    "GL_HP_occlusion_test",
+    "GL_HP_texture_lighting",
+    "GL_IBM_cull_vertex",
+    "GL_IBM_multimode_draw_arrays",
+    "GL_IBM_rasterpos_clip",
+    "GL_IBM_static_data",
+    "GL_IBM_texture_mirrored_repeat",
+    "GL_IBM_vertex_array_lists",
+    "GL_IMG_bindless_texture",
+    "GL_IMG_framebuffer_downsample",
+    "GL_IMG_multisampled_render_to_texture",
+    "GL_IMG_program_binary",
+    "GL_IMG_read_format",
+    "GL_IMG_shader_binary",
+    "GL_IMG_texture_compression_pvrtc",
+    "GL_IMG_texture_compression_pvrtc2",
+    "GL_IMG_texture_env_enhanced_fixed_function",
+    "GL_IMG_texture_filter_cubic",
+    "GL_IMG_user_clip_plane",
+    "GL_INGR_blend_func_separate",
+    "GL_INGR_color_clamp",
+    "GL_INGR_interlace_read",
+    "GL_INTEL_blackhole_render",
+    "GL_INTEL_conservative_rasterization",
+    "GL_INTEL_fragment_shader_ordering",
+    "GL_INTEL_framebuffer_CMAA",
+    "GL_INTEL_map_texture",
+    "GL_INTEL_parallel_arrays",
+    "GL_INTEL_performance_query",
+    "GL_KHR_blend_equation_advanced",
+    "GL_KHR_blend_equation_advanced_coherent",
+    "GL_KHR_context_flush_control",
+    "GL_KHR_debug",
+    "GL_KHR_no_error",
+    "GL_KHR_parallel_shader_compile",
+    "GL_KHR_robust_buffer_access_behavior",
+    "GL_KHR_robustness",
+    "GL_KHR_texture_compression_astc_hdr",
+    "GL_KHR_texture_compression_astc_ldr",
+    "GL_KHR_texture_compression_astc_sliced_3d",
+    "GL_MESAX_texture_stack",
+    "GL_MESA_framebuffer_flip_y",
+    "GL_MESA_pack_invert",
+    "GL_MESA_program_binary_formats",
+    "GL_MESA_resize_buffers",
+    "GL_MESA_shader_integer_functions",
+    "GL_MESA_tile_raster_order",
+    "GL_MESA_window_pos",
+    "GL_MESA_ycbcr_texture",
+    "GL_NVX_blend_equation_advanced_multi_draw_buffers",
+    "GL_NVX_conditional_render",
+    "GL_NVX_cross_process_interop",
+    "GL_NVX_gpu_memory_info",
+    "GL_NVX_linked_gpu_multicast",
+    "GL_NV_alpha_to_coverage_dither_control",
+    "GL_NV_bindless_multi_draw_indirect",
+    "GL_NV_bindless_multi_draw_indirect_count",
+    "GL_NV_bindless_texture",
+    "GL_NV_blend_equation_advanced",
+    "GL_NV_blend_equation_advanced_coherent",
+    "GL_NV_blend_minmax_factor",
+    "GL_NV_blend_square",
+    "GL_NV_clip_space_w_scaling",
+    "GL_NV_command_list",
+    "GL_NV_compute_program5",
+    "GL_NV_compute_shader_derivatives",
+    "GL_NV_conditional_render",
+    "GL_NV_conservative_raster",
+    "GL_NV_conservative_raster_dilate",
+    "GL_NV_conservative_raster_pre_snap",
+    "GL_NV_conservative_raster_pre_snap_triangles",
+    "GL_NV_conservative_raster_underestimation",
+    "GL_NV_copy_buffer",
+    "GL_NV_copy_depth_to_color",
+    "GL_NV_copy_image",
+    "GL_NV_coverage_sample",
+    "GL_NV_deep_texture3D",
+    "GL_NV_depth_buffer_float",
+    "GL_NV_depth_clamp",
+    "GL_NV_depth_nonlinear",
+    "GL_NV_draw_buffers",
+    "GL_NV_draw_instanced",
+    "GL_NV_draw_texture",
+    "GL_NV_draw_vulkan_image",
+    "GL_NV_evaluators",
+    "GL_NV_explicit_attrib_location",
+    "GL_NV_explicit_multisample",
+    "GL_NV_fbo_color_attachments",
+    "GL_NV_fence",
+    "GL_NV_fill_rectangle",
+    "GL_NV_float_buffer",
+    "GL_NV_fog_distance",
+    "GL_NV_fragment_coverage_to_color",
+    "GL_NV_fragment_program",
+    "GL_NV_fragment_program2",
+    "GL_NV_fragment_program4",
+    "GL_NV_fragment_program_option",
+    "GL_NV_fragment_shader_barycentric",
+    "GL_NV_fragment_shader_interlock",
+    "GL_NV_framebuffer_blit",
+    "GL_NV_framebuffer_mixed_samples",
+    "GL_NV_framebuffer_multisample",
+    "GL_NV_framebuffer_multisample_coverage",
+    "GL_NV_generate_mipmap_sRGB",
+    "GL_NV_geometry_program4",
+    "GL_NV_geometry_shader4",
+    "GL_NV_geometry_shader_passthrough",
+    "GL_NV_gpu_multicast",
+    "GL_NV_gpu_program4",
+    "GL_NV_gpu_program5",
+    "GL_NV_gpu_program5_mem_extended",
+    "GL_NV_gpu_shader5",
+    "GL_NV_half_float",
+    "GL_NV_image_formats",
+    "GL_NV_instanced_arrays",
+    "GL_NV_internalformat_sample_query",
+    "GL_NV_light_max_exponent",
+    "GL_NV_memory_attachment",
+    "GL_NV_mesh_shader",
+    "GL_NV_multisample_coverage",
+    "GL_NV_multisample_filter_hint",
+    "GL_NV_non_square_matrices",
+    "GL_NV_occlusion_query",
+    "GL_NV_packed_depth_stencil",
+    "GL_NV_parameter_buffer_object",
+    "GL_NV_parameter_buffer_object2",
+    "GL_NV_path_rendering",
+    "GL_NV_path_rendering_shared_edge",
+    "GL_NV_pixel_buffer_object",
+    "GL_NV_pixel_data_range",
+    "GL_NV_point_sprite",
+    "GL_NV_polygon_mode",
+    "GL_NV_present_video",
+    "GL_NV_primitive_restart",
+    "GL_NV_query_resource",
+    "GL_NV_query_resource_tag",
+    "GL_NV_read_buffer",
+    "GL_NV_read_buffer_front",
+    "GL_NV_read_depth",
+    "GL_NV_read_depth_stencil",
+    "GL_NV_read_stencil",
+    "GL_NV_register_combiners",
+    "GL_NV_register_combiners2",
+    "GL_NV_representative_fragment_test",
+    "GL_NV_robustness_video_memory_purge",
+    "GL_NV_sRGB_formats",
+    "GL_NV_sample_locations",
+    "GL_NV_sample_mask_override_coverage",
+    "GL_NV_scissor_exclusive",
+    "GL_NV_shader_atomic_counters",
+    "GL_NV_shader_atomic_float",
+    "GL_NV_shader_atomic_float64",
+    "GL_NV_shader_atomic_fp16_vector",
+    "GL_NV_shader_atomic_int64",
+    "GL_NV_shader_buffer_load",
+    "GL_NV_shader_buffer_store",
+    "GL_NV_shader_noperspective_interpolation",
+    "GL_NV_shader_storage_buffer_object",
+    "GL_NV_shader_texture_footprint",
+    "GL_NV_shader_thread_group",
+    "GL_NV_shader_thread_shuffle",
+    "GL_NV_shading_rate_image",
+    "GL_NV_shadow_samplers_array",
+    "GL_NV_shadow_samplers_cube",
+    "GL_NV_stereo_view_rendering",
+    "GL_NV_tessellation_program5",
+    "GL_NV_texgen_emboss",
+    "GL_NV_texgen_reflection",
+    "GL_NV_texture_barrier",
+    "GL_NV_texture_border_clamp",
+    "GL_NV_texture_compression_s3tc_update",
+    "GL_NV_texture_compression_vtc",
+    "GL_NV_texture_env_combine4",
+    "GL_NV_texture_expand_normal",
+    "GL_NV_texture_multisample",
+    "GL_NV_texture_npot_2D_mipmap",
+    "GL_NV_texture_rectangle",
+    "GL_NV_texture_rectangle_compressed",
+    "GL_NV_texture_shader",
+    "GL_NV_texture_shader2",
+    "GL_NV_texture_shader3",
+    "GL_NV_transform_feedback",
+    "GL_NV_transform_feedback2",
+    "GL_NV_uniform_buffer_unified_memory",
+    "GL_NV_vdpau_interop",
+    "GL_NV_vdpau_interop2",
+    "GL_NV_vertex_array_range",
+    "GL_NV_vertex_array_range2",
+    "GL_NV_vertex_attrib_integer_64bit",
+    "GL_NV_vertex_buffer_unified_memory",
+    "GL_NV_vertex_program",
+    "GL_NV_vertex_program1_1",
+    "GL_NV_vertex_program2",
+    "GL_NV_vertex_program2_option",
+    "GL_NV_vertex_program3",
+    "GL_NV_vertex_program4",
+    "GL_NV_video_capture",
+    "GL_NV_viewport_array",
+    "GL_NV_viewport_array2",
+    "GL_NV_viewport_swizzle",
+    "GL_OES_EGL_image",
+    "GL_OES_EGL_image_external",
+    "GL_OES_EGL_image_external_essl3",
+    "GL_OES_blend_equation_separate",
+    "GL_OES_blend_func_separate",
+    "GL_OES_blend_subtract",
+    "GL_OES_byte_coordinates",
+    "GL_OES_compressed_ETC1_RGB8_sub_texture",
+    "GL_OES_compressed_ETC1_RGB8_texture",
+    "GL_OES_compressed_paletted_texture",
+    "GL_OES_copy_image",
+    "GL_OES_depth24",
+    "GL_OES_depth32",
+    "GL_OES_depth_texture",
+    "GL_OES_draw_buffers_indexed",
+    "GL_OES_draw_elements_base_vertex",
+    "GL_OES_draw_texture",
+    "GL_OES_element_index_uint",
+    "GL_OES_extended_matrix_palette",
+    "GL_OES_fbo_render_mipmap",
+    "GL_OES_fixed_point",
+    "GL_OES_fragment_precision_high",
+    "GL_OES_framebuffer_object",
+    "GL_OES_geometry_point_size",
+    "GL_OES_geometry_shader",
+    "GL_OES_get_program_binary",
+    "GL_OES_gpu_shader5",
+    "GL_OES_mapbuffer",
+    "GL_OES_matrix_get",
+    "GL_OES_matrix_palette",
+    "GL_OES_packed_depth_stencil",
+    "GL_OES_point_size_array",
+    "GL_OES_point_sprite",
+    "GL_OES_primitive_bounding_box",
+    "GL_OES_query_matrix",
+    "GL_OES_read_format",
+    "GL_OES_required_internalformat",
+    "GL_OES_rgb8_rgba8",
+    "GL_OES_sample_shading",
+    "GL_OES_sample_variables",
+    "GL_OES_shader_image_atomic",
+    "GL_OES_shader_io_blocks",
+    "GL_OES_shader_multisample_interpolation",
+    "GL_OES_single_precision",
+    "GL_OES_standard_derivatives",
+    "GL_OES_stencil1",
+    "GL_OES_stencil4",
+    "GL_OES_stencil8",
+    "GL_OES_stencil_wrap",
+    "GL_OES_surfaceless_context",
+    "GL_OES_tessellation_point_size",
+    "GL_OES_tessellation_shader",
+    "GL_OES_texture_3D",
+    "GL_OES_texture_border_clamp",
+    "GL_OES_texture_buffer",
+    "GL_OES_texture_compression_astc",
+    "GL_OES_texture_cube_map",
+    "GL_OES_texture_cube_map_array",
+    "GL_OES_texture_env_crossbar",
+    "GL_OES_texture_float",
+    "GL_OES_texture_float_linear",
+    "GL_OES_texture_half_float",
+    "GL_OES_texture_half_float_linear",
+    "GL_OES_texture_mirrored_repeat",
+    "GL_OES_texture_npot",
+    "GL_OES_texture_stencil8",
+    "GL_OES_texture_storage_multisample_2d_array",
+    "GL_OES_texture_view",
+    "GL_OES_vertex_array_object",
+    "GL_OES_vertex_half_float",
+    "GL_OES_vertex_type_10_10_10_2",
+    "GL_OES_viewport_array",
+    "GL_OML_interlace",
+    "GL_OML_resample",
+    "GL_OML_subsample",
+    "GL_OVR_multiview",
+    "GL_OVR_multiview2",
+    "GL_OVR_multiview_multisampled_render_to_texture",
+    "GL_PGI_misc_hints",
+    "GL_PGI_vertex_hints",
+    "GL_QCOM_YUV_texture_gather",
+    "GL_QCOM_alpha_test",
+    "GL_QCOM_binning_control",
+    "GL_QCOM_driver_control",
+    "GL_QCOM_extended_get",
+    "GL_QCOM_extended_get2",
+    "GL_QCOM_framebuffer_foveated",
+    "GL_QCOM_perfmon_global_mode",
+    "GL_QCOM_shader_framebuffer_fetch_noncoherent",
+    "GL_QCOM_shader_framebuffer_fetch_rate",
+    "GL_QCOM_texture_foveated",
+    "GL_QCOM_texture_foveated_subsampled_layout",
+    "GL_QCOM_tiled_rendering",
+    "GL_QCOM_writeonly_rendering",
+    "GL_REND_screen_coordinates",
+    "GL_S3_s3tc",
+    "GL_SGIS_detail_texture",
+    "GL_SGIS_fog_function",
+    "GL_SGIS_generate_mipmap",
+    "GL_SGIS_multisample",
+    "GL_SGIS_pixel_texture",
+    "GL_SGIS_point_line_texgen",
+    "GL_SGIS_point_parameters",
+    "GL_SGIS_sharpen_texture",
+    "GL_SGIS_texture4D",
+    "GL_SGIS_texture_border_clamp",
+    "GL_SGIS_texture_color_mask",
+    "GL_SGIS_texture_edge_clamp",
+    "GL_SGIS_texture_filter4",
+    "GL_SGIS_texture_lod",
+    "GL_SGIS_texture_select",
+    "GL_SGIX_async",
+    "GL_SGIX_async_histogram",
+    "GL_SGIX_async_pixel",
+    "GL_SGIX_blend_alpha_minmax",
+    "GL_SGIX_calligraphic_fragment",
+    "GL_SGIX_clipmap",
+    "GL_SGIX_convolution_accuracy",
+    "GL_SGIX_depth_pass_instrument",
+    "GL_SGIX_depth_texture",
+    "GL_SGIX_flush_raster",
+    "GL_SGIX_fog_offset",
+    "GL_SGIX_fragment_lighting",
+    "GL_SGIX_framezoom",
+    "GL_SGIX_igloo_interface",
+    "GL_SGIX_instruments",
+    "GL_SGIX_interlace",
+    "GL_SGIX_ir_instrument1",
+    "GL_SGIX_list_priority",
+    "GL_SGIX_pixel_texture",
+    "GL_SGIX_pixel_tiles",
+    "GL_SGIX_polynomial_ffd",
+    "GL_SGIX_reference_plane",
+    "GL_SGIX_resample",
+    "GL_SGIX_scalebias_hint",
+    "GL_SGIX_shadow",
+    "GL_SGIX_shadow_ambient",
+    "GL_SGIX_sprite",
+    "GL_SGIX_subsample",
+    "GL_SGIX_tag_sample_buffer",
+    "GL_SGIX_texture_add_env",
+    "GL_SGIX_texture_coordinate_clamp",
+    "GL_SGIX_texture_lod_bias",
+    "GL_SGIX_texture_multi_buffer",
+    "GL_SGIX_texture_scale_bias",
+    "GL_SGIX_vertex_preclip",
+    "GL_SGIX_ycrcb",
+    "GL_SGIX_ycrcb_subsample",
+    "GL_SGIX_ycrcba",
+    "GL_SGI_color_matrix",
+    "GL_SGI_color_table",
+    "GL_SGI_texture_color_table",
+    "GL_SUNX_constant_data",
+    "GL_SUN_convolution_border_modes",
+    "GL_SUN_global_alpha",
+    "GL_SUN_mesh_array",
+    "GL_SUN_slice_accum",
+    "GL_SUN_triangle_list",
+    "GL_SUN_vertex",
+    "GL_VIV_shader_binary",
+    "GL_WIN_phong_shading",
+    "GL_WIN_specular_fog",
+    "WEBGL_blend_equation_advanced_coherent",
+    "WEBGL_clip_cull_distance",
+    "WEBGL_color_buffer_float",
+    "WEBGL_compressed_texture_astc",
+    "WEBGL_compressed_texture_etc",
+    "WEBGL_compressed_texture_etc1",
+    "WEBGL_compressed_texture_pvrtc",
+    "WEBGL_compressed_texture_s3tc",
+    "WEBGL_compressed_texture_s3tc_srgb",
+    "WEBGL_debug_renderer_info",
+    "WEBGL_debug_shaders",
+    "WEBGL_depth_texture",
+    "WEBGL_draw_buffers",
+    "WEBGL_draw_instanced_base_vertex_base_instance",
+    "WEBGL_lose_context",
+    "WEBGL_multi_draw",
+    "WEBGL_multi_draw_instanced_base_vertex_base_instance",
+    "WEBGL_polygon_mode",
+    "WEBGL_provoking_vertex",
+    "WEBGL_render_shared_exponent",
+    "WEBGL_stencil_texturing",
+];
@@ -0,0 +1,168 @@
+pub use crate::extension_names::Extension;
+use crate::extension_names::NAMES;
+use alloc::format;
+
+const WORDS: usize = Extension::ALL.len().div_ceil(64);
+
+impl Extension {
+    /// Returns the name of the extension, e.g. `GL_KHR_debug`.
+    ///
+    /// Extensions that are only defined by the WebGL registry don't have a `GL_` prefix.
+    pub fn name(self) -> &'static str {
+        NAMES[self as usize]
+    }
+
+    /// Looks up an extension by the name reported by the driver or browser.
+    ///
+    /// WebGL reports extensions without the `GL_` prefix, so these names are matched against the
+    /// OpenGL ES extensions too, and drivers that report WebGL-only extensions with the prefix
+    /// are matched against the WebGL extensions. Returns `None` for extensions that aren't in the
+    /// registries.
+    pub fn from_name(name: &str) -> Option<Self> {
+        fn find(name: &str) -> Option<Extension> {
+            NAMES
+                .binary_search(&name)
+                .ok()
+                .map(|index| Extension::ALL[index])
+        }
+
+        find(name).or_else(|| match name.strip_prefix("GL_") {
+            Some(unprefixed) => find(unprefixed),
+            None => find(&format!("GL_{}", name)),
+        })
+    }
+}
+
+impl core::fmt::Display for Extension {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A set of [`Extension`]s, stored as a bitset so that checking for an extension is cheap.
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct Extensions {
+    bits: [u64; WORDS],
+}
+
+impl Extensions {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Extensions { bits: [0; WORDS] }
+    }
+
+    /// Creates a set from extension names, ignoring any that aren't in the registries.
+    pub fn from_names<'a>(names: impl IntoIterator<Item = &'a str>) -> Self {
+        names.into_iter().filter_map(Extension::from_name).collect()
+    }
+
+    pub fn has(&self, extension: Extension) -> bool {
+        let index = extension as usize;
+        self.bits[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    pub fn insert(&mut self, extension: Extension) {
+        let index = extension as usize;
+        self.bits[index / 64] |= 1 << (index % 64);
+    }
+
+    pub fn remove(&mut self, extension: Extension) {
+        let index = extension as usize;
+        self.bits[index / 64] &= !(1 << (index % 64));
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&word| word == 0)
+    }
+
+    /// Iterates over the extensions in the set, sorted by name.
+    pub fn iter(&self) -> impl Iterator<Item = Extension> + '_ {
+        Extension::ALL
+            .iter()
+            .copied()
+            .filter(move |&extension| self.has(extension))
+    }
+}
+
+impl Default for Extensions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for Extensions {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl Extend<Extension> for Extensions {
+    fn extend<T: IntoIterator<Item = Extension>>(&mut self, iter: T) {
+        for extension in iter {
+            self.insert(extension);
+        }
+    }
+}
+
+impl FromIterator<Extension> for Extensions {
+    fn from_iter<T: IntoIterator<Item = Extension>>(iter: T) -> Self {
+        let mut extensions = Extensions::new();
+        extensions.extend(iter);
+        extensions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extension_names() {
+        assert!(NAMES.windows(2).all(|w| w[0] < w[1]));
+        assert_eq!(Extension::KhrDebug.name(), "GL_KHR_debug");
+        assert_eq!(
+            Extension::from_name("GL_KHR_debug"),
+            Some(Extension::KhrDebug)
+        );
+        assert_eq!(
+            Extension::from_name("OES_texture_float"),
+            Some(Extension::OesTextureFloat)
+        );
+        assert_eq!(
+            Extension::from_name("WEBGL_lose_context"),
+            Some(Extension::WebglLoseContext)
+        );
+        for name in ["EXT_frag_depth", "GL_EXT_frag_depth"] {
+            assert_eq!(Extension::from_name(name), Some(Extension::ExtFragDepth));
+        }
+        assert_eq!(
+            Extension::from_name("GL_EXT_depth_clamp"),
+            Some(Extension::ExtDepthClamp)
+        );
+        assert_eq!(Extension::from_name("GL_VENDOR_unknown"), None);
+    }
+
+    #[test]
+    fn test_extensions() {
+        let mut extensions =
+            Extensions::from_names(["GL_KHR_debug", "GL_VENDOR_unknown", "OES_texture_float"]);
+        assert_eq!(extensions.len(), 2);
+        assert!(extensions.has(Extension::KhrDebug));
+        assert!(extensions.has(Extension::OesTextureFloat));
+        assert!(!extensions.has(Extension::ArbComputeShader));
+        extensions.remove(Extension::KhrDebug);
+        assert_eq!(
+            extensions.iter().collect::<Vec<_>>(),
+            [Extension::OesTextureFloat]
+        );
+        extensions.remove(Extension::OesTextureFloat);
+        assert!(extensions.is_empty());
+    }
+}
@@ -0,0 +1,817 @@
+//! Descriptions of texture formats and pixel transfer formats.
+//!
+//! [`info`] describes an internal format, e.g. `RGBA8` or `COMPRESSED_RGBA_ASTC_4x4_KHR`, and
+//! [`pixel_size`] the size of a pixel for a `format` and `ty` pair as passed to `tex_image_2d`
+//! and `read_pixels`. [`PixelStore`] computes how many bytes a transfer reads or writes, and
+//! [`check_unpack`], [`check_pack`] and [`check_compressed`] check pixel data before a transfer.
+
+use crate::*;
+
+/// A description of an internal format.
+///
+/// `renderable` and `filterable` follow the OpenGL ES 3.0 core tables, so they're conservative
+/// on desktop OpenGL and when extensions like `EXT_color_buffer_float` are available.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct FormatInfo {
+    pub internal_format: u32,
+    /// The base format, e.g. `RGBA` for `RGBA8`. This is also the `format` used to upload it.
+    pub base_format: u32,
+    /// The size of a pixel, or of a block for compressed formats. 0 for unsized formats, whose
+    /// size depends on the pixel type.
+    pub bytes_per_block: u32,
+    /// The block width, 1 for uncompressed formats.
+    pub block_width: u32,
+    /// The block height, 1 for uncompressed formats.
+    pub block_height: u32,
+    pub sized: bool,
+    pub compressed: bool,
+    /// Whether the format is color, depth or stencil renderable.
+    pub renderable: bool,
+    /// Whether the format can be sampled with `LINEAR` filtering.
+    pub filterable: bool,
+}
+
+impl FormatInfo {
+    /// Returns the size of a `width` x `height` x `depth` image in this format, ignoring the
+    /// pixel store parameters. Returns `None` for unsized formats, or if the size overflows.
+    pub fn image_size(&self, width: u32, height: u32, depth: u32) -> Option<usize> {
+        if !self.sized {
+            return None;
+        }
+        let blocks_x = width.div_ceil(self.block_width) as usize;
+        let blocks_y = height.div_ceil(self.block_height) as usize;
+        blocks_x
+            .checked_mul(blocks_y)?
+            .checked_mul(depth as usize)?
+            .checked_mul(self.bytes_per_block as usize)
+    }
+}
+
+const fn unsized_format(internal_format: u32) -> FormatInfo {
+    FormatInfo {
+        internal_format,
+        base_format: internal_format,
+        bytes_per_block: 0,
+        block_width: 1,
+        block_height: 1,
+        sized: false,
+        compressed: false,
+        renderable: true,
+        filterable: true,
+    }
+}
+
+const fn uncompressed(
+    internal_format: u32,
+    base_format: u32,
+    bytes_per_pixel: u32,
+    renderable: bool,
+    filterable: bool,
+) -> FormatInfo {
+    FormatInfo {
+        internal_format,
+        base_format,
+        bytes_per_block: bytes_per_pixel,
+        block_width: 1,
+        block_height: 1,
+        sized: true,
+        compressed: false,
+        renderable,
+        filterable,
+    }
+}
+
+const fn compressed(
+    internal_format: u32,
+    base_format: u32,
+    bytes_per_block: u32,
+    block_width: u32,
+    block_height: u32,
+) -> FormatInfo {
+    FormatInfo {
+        internal_format,
+        base_format,
+        bytes_per_block,
+        block_width,
+        block_height,
+        sized: true,
+        compressed: true,
+        renderable: false,
+        filterable: true,
+    }
+}
+
+#[rustfmt::skip]
+static FORMATS: &[FormatInfo] = &[
+    unsized_format(ALPHA),
+    unsized_format(LUMINANCE),
+    unsized_format(LUMINANCE_ALPHA),
+    unsized_format(RGB),
+    unsized_format(RGBA),
+
+    uncompressed(R8, RED, 1, true, true),
+    uncompressed(R8_SNORM, RED, 1, false, true),
+    uncompressed(R16, RED, 2, true, true),
+    uncompressed(R16F, RED, 2, false, true),
+    uncompressed(R32F, RED, 4, false, false),
+    uncompressed(R8UI, RED_INTEGER, 1, true, false),
+    uncompressed(R8I, RED_INTEGER, 1, true, false),
+    uncompressed(R16UI, RED_INTEGER, 2, true, false),
+    uncompressed(R16I, RED_INTEGER, 2, true, false),
+    uncompressed(R32UI, RED_INTEGER, 4, true, false),
+    uncompressed(R32I, RED_INTEGER, 4, true, false),
+    uncompressed(RG8, RG, 2, true, true),
+    uncompressed(RG8_SNORM, RG, 2, false, true),
+    uncompressed(RG16, RG, 4, true, true),
+    uncompressed(RG16F, RG, 4, false, true),
+    uncompressed(RG32F, RG, 8, false, false),
+    uncompressed(RG8UI, RG_INTEGER, 2, true, false),
+    uncompressed(RG8I, RG_INTEGER, 2, true, false),
+    uncompressed(RG16UI, RG_INTEGER, 4, true, false),
+    uncompressed(RG16I, RG_INTEGER, 4, true, false),
+    uncompressed(RG32UI, RG_INTEGER, 8, true, false),
+    uncompressed(RG32I, RG_INTEGER, 8, true, false),
+    uncompressed(RGB8, RGB, 3, true, true),
+    uncompressed(SRGB8, RGB, 3, false, true),
+    uncompressed(RGB565, RGB, 2, true, true),
+    uncompressed(RGB8_SNORM, RGB, 3, false, true),
+    uncompressed(RGB16, RGB, 6, false, true),
+    uncompressed(R11F_G11F_B10F, RGB, 4, false, true),
+    uncompressed(RGB9_E5, RGB, 4, false, true),
+    uncompressed(RGB16F, RGB, 6, false, true),
+    uncompressed(RGB32F, RGB, 12, false, false),
+    uncompressed(RGB8UI, RGB_INTEGER, 3, false, false),
+    uncompressed(RGB8I, RGB_INTEGER, 3, false, false),
+    uncompressed(RGB16UI, RGB_INTEGER, 6, false, false),
+    uncompressed(RGB16I, RGB_INTEGER, 6, false, false),
+    uncompressed(RGB32UI, RGB_INTEGER, 12, false, false),
+    uncompressed(RGB32I, RGB_INTEGER, 12, false, false),
+    uncompressed(RGBA8, RGBA, 4, true, true),
+    uncompressed(SRGB8_ALPHA8, RGBA, 4, true, true),
+    uncompressed(RGBA8_SNORM, RGBA, 4, false, true),
+    uncompressed(RGB5_A1, RGBA, 2, true, true),
+    uncompressed(RGBA4, RGBA, 2, true, true),
+    uncompressed(RGB10_A2, RGBA, 4, true, true),
+    uncompressed(RGBA16, RGBA, 8, true, true),
+    uncompressed(RGBA16F, RGBA, 8, false, true),
+    uncompressed(RGBA32F, RGBA, 16, false, false),
+    uncompressed(RGB10_A2UI, RGBA_INTEGER, 4, true, false),
+    uncompressed(RGBA8UI, RGBA_INTEGER, 4, true, false),
+    uncompressed(RGBA8I, RGBA_INTEGER, 4, true, false),
+    uncompressed(RGBA16UI, RGBA_INTEGER, 8, true, false),
+    uncompressed(RGBA16I, RGBA_INTEGER, 8, true, false),
+    uncompressed(RGBA32UI, RGBA_INTEGER, 16, true, false),
+    uncompressed(RGBA32I, RGBA_INTEGER, 16, true, false),
+
+    uncompressed(DEPTH_COMPONENT16, DEPTH_COMPONENT, 2, true, false),
+    uncompressed(DEPTH_COMPONENT24, DEPTH_COMPONENT, 4, true, false),
+    uncompressed(DEPTH_COMPONENT32F, DEPTH_COMPONENT, 4, true, false),
+    uncompressed(DEPTH24_STENCIL8, DEPTH_STENCIL, 4, true, false),
+    uncompressed(DEPTH32F_STENCIL8, DEPTH_STENCIL, 8, true, false),
+    uncompressed(STENCIL_INDEX8, STENCIL_INDEX, 1, true, false),
+
+    compressed(COMPRESSED_R11_EAC, RED, 8, 4, 4),
+    compressed(COMPRESSED_SIGNED_R11_EAC, RED, 8, 4, 4),
+    compressed(COMPRESSED_RG11_EAC, RG, 16, 4, 4),
+    compressed(COMPRESSED_SIGNED_RG11_EAC, RG, 16, 4, 4),
+    compressed(COMPRESSED_RGB8_ETC2, RGB, 8, 4, 4),
+    compressed(COMPRESSED_SRGB8_ETC2, RGB, 8, 4, 4),
+    compressed(COMPRESSED_RGB8_PUNCHTHROUGH_ALPHA1_ETC2, RGBA, 8, 4, 4),
+    compressed(COMPRESSED_SRGB8_PUNCHTHROUGH_ALPHA1_ETC2, RGBA, 8, 4, 4),
+    compressed(COMPRESSED_RGBA8_ETC2_EAC, RGBA, 16, 4, 4),
+    compressed(COMPRESSED_SRGB8_ALPHA8_ETC2_EAC, RGBA, 16, 4, 4),
+
+    compressed(COMPRESSED_RGB_S3TC_DXT1_EXT, RGB, 8, 4, 4),
+    compressed(COMPRESSED_RGBA_S3TC_DXT1_EXT, RGBA, 8, 4, 4),
+    compressed(COMPRESSED_RGBA_S3TC_DXT3_EXT, RGBA, 16, 4, 4),
+    compressed(COMPRESSED_RGBA_S3TC_DXT5_EXT, RGBA, 16, 4, 4),
+    compressed(COMPRESSED_SRGB_S3TC_DXT1_EXT, RGB, 8, 4, 4),
+    compressed(COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT, RGBA, 8, 4, 4),
+    compressed(COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT, RGBA, 16, 4, 4),
+    compressed(COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT, RGBA, 16, 4, 4),
+
+    compressed(COMPRESSED_RED_RGTC1, RED, 8, 4, 4),
+    compressed(COMPRESSED_SIGNED_RED_RGTC1, RED, 8, 4, 4),
+    compressed(COMPRESSED_RG_RGTC2, RG, 16, 4, 4),
+    compressed(COMPRESSED_SIGNED_RG_RGTC2, RG, 16, 4, 4),
+
+    compressed(COMPRESSED_RGBA_BPTC_UNORM, RGBA, 16, 4, 4),
+    compressed(COMPRESSED_SRGB_ALPHA_BPTC_UNORM, RGBA, 16, 4, 4),
+    compressed(COMPRESSED_RGB_BPTC_SIGNED_FLOAT, RGB, 16, 4, 4),
+    compressed(COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT, RGB, 16, 4, 4),
+
+    compressed(COMPRESSED_RGBA_ASTC_4x4_KHR, RGBA, 16, 4, 4),
+    compressed(COMPRESSED_RGBA_ASTC_5x4_KHR, RGBA, 16, 5, 4),
+    compressed(COMPRESSED_RGBA_ASTC_5x5_KHR, RGBA, 16, 5, 5),
+    compressed(COMPRESSED_RGBA_ASTC_6x5_KHR, RGBA, 16, 6, 5),
+    compressed(COMPRESSED_RGBA_ASTC_6x6_KHR, RGBA, 16, 6, 6),
+    compressed(COMPRESSED_RGBA_ASTC_8x5_KHR, RGBA, 16, 8, 5),
+    compressed(COMPRESSED_RGBA_ASTC_8x6_KHR, RGBA, 16, 8, 6),
+    compressed(COMPRESSED_RGBA_ASTC_8x8_KHR, RGBA, 16, 8, 8),
+    compressed(COMPRESSED_RGBA_ASTC_10x5_KHR, RGBA, 16, 10, 5),
+    compressed(COMPRESSED_RGBA_ASTC_10x6_KHR, RGBA, 16, 10, 6),
+    compressed(COMPRESSED_RGBA_ASTC_10x8_KHR, RGBA, 16, 10, 8),
+    compressed(COMPRESSED_RGBA_ASTC_10x10_KHR, RGBA, 16, 10, 10),
+    compressed(COMPRESSED_RGBA_ASTC_12x10_KHR, RGBA, 16, 12, 10),
+    compressed(COMPRESSED_RGBA_ASTC_12x12_KHR, RGBA, 16, 12, 12),
+    compressed(COMPRESSED_SRGB8_ALPHA8_ASTC_4x4_KHR, RGBA, 16, 4, 4),
+    compressed(COMPRESSED_SRGB8_ALPHA8_ASTC_5x4_KHR, RGBA, 16, 5, 4),
+    compressed(COMPRESSED_SRGB8_ALPHA8_ASTC_5x5_KHR, RGBA, 16, 5, 5),
+    compressed(COMPRESSED_SRGB8_ALPHA8_ASTC_6x5_KHR, RGBA, 16, 6, 5),
+    compressed(COMPRESSED_SRGB8_ALPHA8_ASTC_6x6_KHR, RGBA, 16, 6, 6),
+    compressed(COMPRESSED_SRGB8_ALPHA8_ASTC_8x5_KHR, RGBA, 16, 8, 5),
+    compressed(COMPRESSED_SRGB8_ALPHA8_ASTC_8x6_KHR, RGBA, 16, 8, 6),
+    compressed(COMPRESSED_SRGB8_ALPHA8_ASTC_8x8_KHR, RGBA, 16, 8, 8),
+    compressed(COMPRESSED_SRGB8_ALPHA8_ASTC_10x5_KHR, RGBA, 16, 10, 5),
+    compressed(COMPRESSED_SRGB8_ALPHA8_ASTC_10x6_KHR, RGBA, 16, 10, 6),
+    compressed(COMPRESSED_SRGB8_ALPHA8_ASTC_10x8_KHR, RGBA, 16, 10, 8),
+    compressed(COMPRESSED_SRGB8_ALPHA8_ASTC_10x10_KHR, RGBA, 16, 10, 10),
+    compressed(COMPRESSED_SRGB8_ALPHA8_ASTC_12x10_KHR, RGBA, 16, 12, 10),
+    compressed(COMPRESSED_SRGB8_ALPHA8_ASTC_12x12_KHR, RGBA, 16, 12, 12),
+];
+
+/// Describes an internal format, or returns `None` if it isn't known.
+pub fn info(internal_format: u32) -> Option<&'static FormatInfo> {
+    FORMATS
+        .iter()
+        .find(|info| info.internal_format == internal_format)
+}
+
+/// Returns every known internal format.
+pub fn all() -> &'static [FormatInfo] {
+    FORMATS
+}
+
+/// Returns the size of a pixel in bytes for a pixel transfer `format` and `ty`, e.g. 4 for
+/// `RGBA` and `UNSIGNED_BYTE`, or `None` if the pair isn't valid.
+pub fn pixel_size(format: u32, ty: u32) -> Option<u32> {
+    let components = match format {
+        RED | RED_INTEGER | GREEN | BLUE | ALPHA | LUMINANCE | DEPTH_COMPONENT | STENCIL_INDEX => 1,
+        RG | RG_INTEGER | LUMINANCE_ALPHA => 2,
+        RGB | RGB_INTEGER | BGR | BGR_INTEGER | SRGB => 3,
+        RGBA | RGBA_INTEGER | BGRA | BGRA_INTEGER | SRGB_ALPHA => 4,
+        // Depth stencil transfers always use a packed type
+        DEPTH_STENCIL => {
+            return match ty {
+                UNSIGNED_INT_24_8 => Some(4),
+                FLOAT_32_UNSIGNED_INT_24_8_REV => Some(8),
+                _ => None,
+            }
+        }
+        _ => return None,
+    };
+    let is_rgb = matches!(format, RGB | RGB_INTEGER | BGR | BGR_INTEGER);
+    let is_rgba = matches!(format, RGBA | RGBA_INTEGER | BGRA | BGRA_INTEGER);
+    match ty {
+        UNSIGNED_BYTE | BYTE => Some(components),
+        UNSIGNED_SHORT | SHORT | HALF_FLOAT | HALF_FLOAT_OES => Some(components * 2),
+        UNSIGNED_INT | INT | FLOAT => Some(components * 4),
+        UNSIGNED_BYTE_3_3_2 | UNSIGNED_BYTE_2_3_3_REV if is_rgb => Some(1),
+        UNSIGNED_SHORT_5_6_5 | UNSIGNED_SHORT_5_6_5_REV if is_rgb => Some(2),
+        UNSIGNED_SHORT_4_4_4_4
+        | UNSIGNED_SHORT_4_4_4_4_REV
+        | UNSIGNED_SHORT_5_5_5_1
+        | UNSIGNED_SHORT_1_5_5_5_REV
+            if is_rgba =>
+        {
+            Some(2)
+        }
+        UNSIGNED_INT_8_8_8_8
+        | UNSIGNED_INT_8_8_8_8_REV
+        | UNSIGNED_INT_10_10_10_2
+        | UNSIGNED_INT_2_10_10_10_REV
+            if is_rgba =>
+        {
+            Some(4)
+        }
+        UNSIGNED_INT_10F_11F_11F_REV | UNSIGNED_INT_5_9_9_9_REV if is_rgb => Some(4),
+        _ => None,
+    }
+}
+
+/// Returns whether a context supports textures with `internal_format`.
+///
+/// Compressed formats are checked against the context version and extensions, e.g.
+/// `EXT_texture_compression_s3tc` or `WEBGL_compressed_texture_s3tc` for BC1-3. Uncompressed
+/// formats are assumed to be supported, and unknown formats are not.
+pub fn is_supported<C: HasContext>(gl: &C, internal_format: u32) -> bool {
+    let info = match info(internal_format) {
+        Some(info) => info,
+        None => return false,
+    };
+    if !info.compressed {
+        return true;
+    }
+    let version = gl.version();
+    let native = !gl.driver_info().is_webgl;
+    let gl_version = |major, minor| {
+        native && !version.is_embedded && (version.major, version.minor) >= (major, minor)
+    };
+    let es_version = |major, minor| {
+        native && version.is_embedded && (version.major, version.minor) >= (major, minor)
+    };
+    let ext = |extension| gl.extensions().has(extension);
+    let s3tc =
+        ext(Extension::ExtTextureCompressionS3tc) || ext(Extension::WebglCompressedTextureS3tc);
+    match internal_format {
+        COMPRESSED_RGB_S3TC_DXT1_EXT | COMPRESSED_RGBA_S3TC_DXT1_EXT => {
+            s3tc || ext(Extension::ExtTextureCompressionDxt1)
+        }
+        COMPRESSED_RGBA_S3TC_DXT3_EXT | COMPRESSED_RGBA_S3TC_DXT5_EXT => s3tc,
+        COMPRESSED_SRGB_S3TC_DXT1_EXT
+        | COMPRESSED_SRGB_ALPHA_S3TC_DXT1_EXT
+        | COMPRESSED_SRGB_ALPHA_S3TC_DXT3_EXT
+        | COMPRESSED_SRGB_ALPHA_S3TC_DXT5_EXT => {
+            ext(Extension::ExtTextureCompressionS3tcSrgb)
+                || ext(Extension::WebglCompressedTextureS3tcSrgb)
+                || (s3tc && (ext(Extension::ExtTextureSrgb) || ext(Extension::NvSrgbFormats)))
+        }
+        COMPRESSED_RED_RGTC1
+        | COMPRESSED_SIGNED_RED_RGTC1
+        | COMPRESSED_RG_RGTC2
+        | COMPRESSED_SIGNED_RG_RGTC2 => {
+            gl_version(3, 0)
+                || ext(Extension::ArbTextureCompressionRgtc)
+                || ext(Extension::ExtTextureCompressionRgtc)
+        }
+        COMPRESSED_RGBA_BPTC_UNORM
+        | COMPRESSED_SRGB_ALPHA_BPTC_UNORM
+        | COMPRESSED_RGB_BPTC_SIGNED_FLOAT
+        | COMPRESSED_RGB_BPTC_UNSIGNED_FLOAT => {
+            gl_version(4, 2)
+                || ext(Extension::ArbTextureCompressionBptc)
+                || ext(Extension::ExtTextureCompressionBptc)
+        }
+        COMPRESSED_R11_EAC..=COMPRESSED_SRGB8_ALPHA8_ETC2_EAC => {
+            gl_version(4, 3)
+                || es_version(3, 0)
+                || ext(Extension::ArbEs3Compatibility)
+                || ext(Extension::WebglCompressedTextureEtc)
+        }
+        COMPRESSED_RGBA_ASTC_4x4_KHR..=COMPRESSED_SRGB8_ALPHA8_ASTC_12x12_KHR => {
+            es_version(3, 2)
+                || ext(Extension::KhrTextureCompressionAstcLdr)
+                || ext(Extension::WebglCompressedTextureAstc)
+        }
+        _ => false,
+    }
+}
+
+/// The pixel store parameters that affect the layout of transferred pixels, as set with
+/// `pixel_store_i32`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct PixelStore {
+    pub alignment: u32,
+    pub row_length: u32,
+    pub image_height: u32,
+    pub skip_pixels: u32,
+    pub skip_rows: u32,
+    pub skip_images: u32,
+}
+
+impl Default for PixelStore {
+    fn default() -> Self {
+        PixelStore {
+            alignment: 4,
+            row_length: 0,
+            image_height: 0,
+            skip_pixels: 0,
+            skip_rows: 0,
+            skip_images: 0,
+        }
+    }
+}
+
+impl PixelStore {
+    /// Queries the current `UNPACK_*` parameters.
+    pub unsafe fn query_unpack<C: HasContext>(gl: &C) -> Self {
+        Self::query(
+            gl,
+            [
+                UNPACK_ALIGNMENT,
+                UNPACK_ROW_LENGTH,
+                UNPACK_IMAGE_HEIGHT,
+                UNPACK_SKIP_PIXELS,
+                UNPACK_SKIP_ROWS,
+                UNPACK_SKIP_IMAGES,
+            ],
+        )
+    }
+
+    /// Queries the current `PACK_*` parameters.
+    pub unsafe fn query_pack<C: HasContext>(gl: &C) -> Self {
+        Self::query(
+            gl,
+            [
+                PACK_ALIGNMENT,
+                PACK_ROW_LENGTH,
+                0,
+                PACK_SKIP_PIXELS,
+                PACK_SKIP_ROWS,
+                0,
+            ],
+        )
+    }
+
+    /// Sets the `UNPACK_*` parameters, e.g. to restore ones returned by
+    /// [`HasContext::unpack_parameters`].
+    pub unsafe fn apply_unpack<C: HasContext>(&self, gl: &C) {
+        gl.pixel_store_i32(UNPACK_ALIGNMENT, self.alignment as i32);
+        let version = gl.version();
+        if version.is_embedded && version.major < 3 {
+            return;
+        }
+        gl.pixel_store_i32(UNPACK_ROW_LENGTH, self.row_length as i32);
+        gl.pixel_store_i32(UNPACK_IMAGE_HEIGHT, self.image_height as i32);
+        gl.pixel_store_i32(UNPACK_SKIP_PIXELS, self.skip_pixels as i32);
+        gl.pixel_store_i32(UNPACK_SKIP_ROWS, self.skip_rows as i32);
+        gl.pixel_store_i32(UNPACK_SKIP_IMAGES, self.skip_images as i32);
+    }
+
+    /// Sets the `PACK_*` parameters, e.g. to restore ones returned by
+    /// [`HasContext::pack_parameters`].
+    pub unsafe fn apply_pack<C: HasContext>(&self, gl: &C) {
+        gl.pixel_store_i32(PACK_ALIGNMENT, self.alignment as i32);
+        let version = gl.version();
+        if version.is_embedded && version.major < 3 {
+            return;
+        }
+        gl.pixel_store_i32(PACK_ROW_LENGTH, self.row_length as i32);
+        gl.pixel_store_i32(PACK_SKIP_PIXELS, self.skip_pixels as i32);
+        gl.pixel_store_i32(PACK_SKIP_ROWS, self.skip_rows as i32);
+    }
+
+    unsafe fn query<C: HasContext>(gl: &C, parameters: [u32; 6]) -> Self {
+        let alignment = gl.get_parameter_i32(parameters[0]) as u32;
+        // Only the alignment exists in OpenGL ES 2.0 and WebGL 1
+        let version = gl.version();
+        if version.is_embedded && version.major < 3 {
+            return PixelStore {
+                alignment,
+                ..PixelStore::default()
+            };
+        }
+        let get = |parameter| {
+            if parameter == 0 {
+                0
+            } else {
+                gl.get_parameter_i32(parameter).max(0) as u32
+            }
+        };
+        PixelStore {
+            alignment,
+            row_length: get(parameters[1]),
+            image_height: get(parameters[2]),
+            skip_pixels: get(parameters[3]),
+            skip_rows: get(parameters[4]),
+            skip_images: get(parameters[5]),
+        }
+    }
+
+    /// Returns the number of bytes read or written by a transfer of `width` x `height` x `depth`
+    /// pixels of `pixel_size` bytes each, including skipped pixels and row padding. Returns `None`
+    /// if the size overflows.
+    pub fn transfer_size(
+        &self,
+        width: u32,
+        height: u32,
+        depth: u32,
+        pixel_size: u32,
+    ) -> Option<usize> {
+        if width == 0 || height == 0 || depth == 0 {
+            return Some(0);
+        }
+        let [width, height, depth, pixel_size] =
+            [width, height, depth, pixel_size].map(|value| value as usize);
+        let row_length = if self.row_length > 0 {
+            self.row_length as usize
+        } else {
+            width
+        };
+        let image_height = if self.image_height > 0 {
+            self.image_height as usize
+        } else {
+            height
+        };
+        let alignment = self.alignment.max(1) as usize;
+        let stride = row_length
+            .checked_mul(pixel_size)?
+            .div_ceil(alignment)
+            .checked_mul(alignment)?;
+        let rows = (self.skip_images as usize)
+            .checked_add(depth - 1)?
+            .checked_mul(image_height)?
+            .checked_add(self.skip_rows as usize)?
+            .checked_add(height - 1)?;
+        let last_row = (self.skip_pixels as usize)
+            .checked_add(width)?
+            .checked_mul(pixel_size)?;
+        rows.checked_mul(stride)?.checked_add(last_row)
+    }
+}
+
+/// The pack and unpack parameters set through a context, tracked so that transfers can be checked
+/// without querying them.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct PixelStoreState {
+    pub(crate) unpack: PixelStore,
+    pub(crate) pack: PixelStore,
+}
+
+impl PixelStoreState {
+    /// Queries the parameters when a context is created, since they may have been set before.
+    pub(crate) unsafe fn query<C: HasContext>(gl: &C) -> Self {
+        PixelStoreState {
+            unpack: PixelStore::query_unpack(gl),
+            pack: PixelStore::query_pack(gl),
+        }
+    }
+
+    /// Records a `pixel_store_i32` call. Invalid values are ignored, like the driver does.
+    pub(crate) fn set(&mut self, parameter: u32, value: i32) {
+        let alignment = matches!(parameter, UNPACK_ALIGNMENT | PACK_ALIGNMENT);
+        if value < 0 || (alignment && !matches!(value, 1 | 2 | 4 | 8)) {
+            return;
+        }
+        let value = value as u32;
+        match parameter {
+            UNPACK_ALIGNMENT => self.unpack.alignment = value,
+            UNPACK_ROW_LENGTH => self.unpack.row_length = value,
+            UNPACK_IMAGE_HEIGHT => self.unpack.image_height = value,
+            UNPACK_SKIP_PIXELS => self.unpack.skip_pixels = value,
+            UNPACK_SKIP_ROWS => self.unpack.skip_rows = value,
+            UNPACK_SKIP_IMAGES => self.unpack.skip_images = value,
+            PACK_ALIGNMENT => self.pack.alignment = value,
+            PACK_ROW_LENGTH => self.pack.row_length = value,
+            PACK_SKIP_PIXELS => self.pack.skip_pixels = value,
+            PACK_SKIP_ROWS => self.pack.skip_rows = value,
+            _ => {}
+        }
+    }
+}
+
+/// Checks that `len` bytes of pixel data are enough for an upload of `width` x `height` x
+/// `depth` pixels of `format` and `ty` with the unpack parameters of `gl`, and that `format` and
+/// `ty` are a valid pair.
+///
+/// Upload methods like `tex_image_2d` panic instead when given a slice that is too short, so
+/// this can be used to handle untrusted data.
+pub fn check_unpack<C: HasContext>(
+    gl: &C,
+    width: i32,
+    height: i32,
+    depth: i32,
+    format: u32,
+    ty: u32,
+    len: usize,
+) -> Result<(), String> {
+    check_transfer(
+        gl.unpack_parameters(),
+        width,
+        height,
+        depth,
+        format,
+        ty,
+        len,
+    )
+}
+
+/// Like [`check_unpack`], for `read_pixels` with the pack parameters of `gl`.
+pub fn check_pack<C: HasContext>(
+    gl: &C,
+    width: i32,
+    height: i32,
+    format: u32,
+    ty: u32,
+    len: usize,
+) -> Result<(), String> {
+    check_transfer(gl.pack_parameters(), width, height, 1, format, ty, len)
+}
+
+/// Panics if `pixels` is a slice that is too short for an upload with the unpack parameters of
+/// `gl`. Unknown `format` and `ty` pairs may come from extensions, so they're only rejected in
+/// validation mode, see `Context::set_validation`.
+#[track_caller]
+pub(crate) fn validate_unpack<C: HasContext>(
+    gl: &C,
+    validation: bool,
+    method: &str,
+    width: i32,
+    height: i32,
+    depth: i32,
+    format: u32,
+    ty: u32,
+    pixels: &PixelUnpackData,
+) {
+    if let PixelUnpackData::Slice(Some(data)) = pixels {
+        if validation || pixel_size(format, ty).is_some() {
+            let result = check_unpack(gl, width, height, depth, format, ty, data.len());
+            invalid_transfer(method, result);
+        }
+    }
+}
+
+/// Like [`validate_unpack`], for a readback with the pack parameters of `gl`.
+#[track_caller]
+pub(crate) fn validate_pack<C: HasContext>(
+    gl: &C,
+    validation: bool,
+    method: &str,
+    width: i32,
+    height: i32,
+    format: u32,
+    ty: u32,
+    pixels: &PixelPackData,
+) {
+    if let PixelPackData::Slice(Some(data)) = pixels {
+        if validation || pixel_size(format, ty).is_some() {
+            let result = check_pack(gl, width, height, format, ty, data.len());
+            invalid_transfer(method, result);
+        }
+    }
+}
+
+/// Like [`validate_unpack`], for compressed data. `image_size` is always checked against the
+/// length of `pixels`, and against the format in validation mode, see [`check_compressed`].
+#[track_caller]
+pub(crate) fn validate_compressed(
+    validation: bool,
+    method: &str,
+    internal_format: u32,
+    width: i32,
+    height: i32,
+    depth: i32,
+    image_size: i32,
+    pixels: &[u8],
+) {
+    let result = if validation {
+        check_compressed(
+            internal_format,
+            width,
+            height,
+            depth,
+            image_size,
+            pixels.len(),
+        )
+    } else {
+        check_image_size(image_size, pixels.len())
+    };
+    invalid_transfer(method, result);
+}
+
+#[track_caller]
+fn invalid_transfer(method: &str, result: Result<(), String>) {
+    if let Err(problem) = result {
+        panic!(
+            "glow: `{}` was called with invalid pixel data: {}",
+            method, problem
+        );
+    }
+}
+
+fn check_transfer(
+    store: PixelStore,
+    width: i32,
+    height: i32,
+    depth: i32,
+    format: u32,
+    ty: u32,
+    len: usize,
+) -> Result<(), String> {
+    let pixel_size = pixel_size(format, ty)
+        .ok_or_else(|| format!("Invalid pixel format {:#06x} and type {:#06x}", format, ty))?;
+    let [width, height, depth] = [width, height, depth].map(|value| value.max(0) as u32);
+    let size = store
+        .transfer_size(width, height, depth, pixel_size)
+        .ok_or_else(|| format!("{}x{}x{} pixels are too many", width, height, depth))?;
+    if len < size {
+        return Err(format!(
+            "Pixel data is {} bytes, but {}x{}x{} pixels of format {:#06x} and type {:#06x} need {}",
+            len, width, height, depth, format, ty, size
+        ));
+    }
+    Ok(())
+}
+
+/// Checks `image_size` and the length of compressed data for an upload of `internal_format`.
+///
+/// Unknown formats are only checked against `image_size`.
+pub fn check_compressed(
+    internal_format: u32,
+    width: i32,
+    height: i32,
+    depth: i32,
+    image_size: i32,
+    len: usize,
+) -> Result<(), String> {
+    check_image_size(image_size, len)?;
+    let [width, height, depth] = [width, height, depth].map(|value| value.max(0) as u32);
+    match info(internal_format).and_then(|info| info.image_size(width, height, depth)) {
+        Some(size) if size != image_size as usize => Err(format!(
+            "Compressed image size is {}, but {}x{}x{} pixels of format {:#06x} need {}",
+            image_size, width, height, depth, internal_format, size
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn check_image_size(image_size: i32, len: usize) -> Result<(), String> {
+    if image_size < 0 || len < image_size as usize {
+        return Err(format!(
+            "Compressed data is {} bytes, but the image size is {}",
+            len, image_size
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_info() {
+        assert!(FORMATS.iter().enumerate().all(|(i, a)| FORMATS[..i]
+            .iter()
+            .all(|b| a.internal_format != b.internal_format)));
+
+        let rgba8 = info(RGBA8).unwrap();
+        assert_eq!(rgba8.base_format, RGBA);
+        assert_eq!(rgba8.image_size(3, 3, 1), Some(36));
+        let astc = info(COMPRESSED_RGBA_ASTC_6x5_KHR).unwrap();
+        assert_eq!(astc.image_size(13, 10, 1), Some(3 * 2 * 16));
+        assert_eq!(info(RGBA).unwrap().image_size(1, 1, 1), None);
+        assert!(info(0).is_none());
+    }
+
+    #[test]
+    fn test_pixel_size() {
+        assert_eq!(pixel_size(RGBA, UNSIGNED_BYTE), Some(4));
+        assert_eq!(pixel_size(RGB, FLOAT), Some(12));
+        assert_eq!(pixel_size(RGB, UNSIGNED_SHORT_5_6_5), Some(2));
+        assert_eq!(pixel_size(RGBA, UNSIGNED_SHORT_5_6_5), None);
+        assert_eq!(pixel_size(DEPTH_STENCIL, UNSIGNED_INT_24_8), Some(4));
+        assert_eq!(pixel_size(DEPTH_STENCIL, UNSIGNED_BYTE), None);
+        assert_eq!(pixel_size(RGBA, 0), None);
+        assert_eq!(pixel_size(RGBA, HALF_FLOAT_OES), Some(8));
+        assert_eq!(pixel_size(SRGB_ALPHA, UNSIGNED_BYTE), Some(4));
+    }
+
+    #[test]
+    fn test_transfer_size() {
+        let store = PixelStore::default();
+        assert_eq!(store.transfer_size(4, 4, 1, 4), Some(64));
+        // 3 byte rows are padded to 4 bytes, except for the last one
+        assert_eq!(store.transfer_size(1, 3, 1, 3), Some(11));
+        assert_eq!(store.transfer_size(1, 3, 2, 3), Some(23));
+        assert_eq!(store.transfer_size(0, 3, 1, 3), Some(0));
+
+        let store = PixelStore {
+            alignment: 1,
+            row_length: 8,
+            skip_pixels: 1,
+            skip_rows: 2,
+            ..PixelStore::default()
+        };
+        assert_eq!(store.transfer_size(2, 2, 1, 4), Some(3 * 32 + 3 * 4));
+
+        assert!(check_transfer(PixelStore::default(), 2, 2, 1, RGBA, UNSIGNED_BYTE, 16).is_ok());
+        assert!(check_transfer(PixelStore::default(), 2, 2, 1, RGBA, UNSIGNED_BYTE, 15).is_err());
+        assert!(check_transfer(PixelStore::default(), 2, 2, 1, RGBA, 0, 16).is_err());
+    }
+
+    #[test]
+    fn test_pixel_store_state() {
+        let mut state = PixelStoreState::default();
+        state.set(UNPACK_ALIGNMENT, 1);
+        state.set(UNPACK_ROW_LENGTH, 16);
+        state.set(PACK_SKIP_ROWS, 2);
+        // Invalid values are ignored
+        state.set(PACK_ALIGNMENT, 3);
+        state.set(UNPACK_SKIP_PIXELS, -1);
+        assert_eq!(
+            state.unpack,
+            PixelStore {
+                alignment: 1,
+                row_length: 16,
+                ..PixelStore::default()
+            }
+        );
+        assert_eq!(
+            state.pack,
+            PixelStore {
+                skip_rows: 2,
+                ..PixelStore::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_check_compressed() {
+        let format = COMPRESSED_RGBA_S3TC_DXT5_EXT;
+        assert!(check_compressed(format, 8, 8, 1, 64, 64).is_ok());
+        assert!(check_compressed(format, 8, 8, 1, 64, 63).is_err());
+        assert!(check_compressed(format, 8, 8, 1, 48, 64).is_err());
+        assert!(check_compressed(0, 8, 8, 1, 48, 48).is_ok());
+    }
+}
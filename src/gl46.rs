@@ -117,16 +117,14 @@
 //! * Individual GL commands are generally safe to use once they've been properly loaded for the current context, but this crate doesn't attempt to sort out what is safe and what's not. All GL commands are blanket marked as being `unsafe`.
 //! It's up to you to try and manage this unsafety! Sorry, but this crate just does what you tell it to.
 
-#[cfg(any(
-    all(
-        not(feature = "log"),
-        any(feature = "debug_trace_calls", feature = "debug_automatic_glGetError")
-    ),
-    not(feature = "chlorine"),
+#[cfg(all(
+    not(feature = "log"),
+    any(feature = "debug_trace_calls", feature = "debug_automatic_glGetError")
 ))]
 extern crate std;
 
-use std::os::raw::*;
+use alloc::boxed::Box;
+use core::ffi::*;
 
 #[cfg(feature = "log")]
 #[allow(unused)]
@@ -4882,6 +4880,51 @@ pub mod struct_commands {
             out
         }
 
+        /// Constructs a new struct which loads each pointer with the `get_proc_address` given the
+        /// first time that it's used, instead of loading all of them up front.
+        ///
+        /// Lookups that fail are remembered, so missing functions are only looked up once.
+        pub unsafe fn load_lazily_with<F>(get_proc_address: F) -> Self
+        where
+            F: Fn(*const c_char) -> *mut c_void + Send + Sync + 'static,
+        {
+            // Safety: see `load_with`.
+            let out: Self = core::mem::zeroed();
+            let loader: Box<LazyLoader> = Box::new(Box::new(get_proc_address));
+            out.lazy_loader_p
+                .store(Box::into_raw(loader) as *mut c_void, RELAX);
+            out
+        }
+
+        /// Returns `ptr`, loading it first if this struct loads lazily and it isn't loaded yet.
+        ///
+        /// Returns a null pointer if the function is missing.
+        #[inline(always)]
+        fn lazy<'a>(&self, ptr: &'a APcv, fn_name: &[u8]) -> &'a APcv {
+            let p = ptr.load(RELAX);
+            if p as usize == LAZY_MISSING || (p.is_null() && !self.load_lazily(ptr, fn_name)) {
+                return &LAZY_NOT_LOADED;
+            }
+            ptr
+        }
+
+        /// Loads `ptr` and returns whether it was found, storing `LAZY_MISSING` if it wasn't.
+        #[cold]
+        #[inline(never)]
+        fn load_lazily(&self, ptr: &APcv, fn_name: &[u8]) -> bool {
+            let loader = self.lazy_loader_p.load(RELAX) as *const LazyLoader;
+            if loader.is_null() {
+                return false;
+            }
+            // Safety: the loader is owned by `self` and only freed when it's dropped.
+            let loader = unsafe { &*loader };
+            let loaded = load_dyn_name_atomic_ptr(&mut |name| loader(name), fn_name, ptr);
+            if !loaded {
+                ptr.store(LAZY_MISSING as *mut c_void, RELAX);
+            }
+            loaded
+        }
+
         #[cfg(feature = "debug_automatic_glGetError")]
         #[inline(never)]
         unsafe fn automatic_glGetError(&self, name: &str) {
@@ -5731,7 +5774,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glActiveShaderProgram",
-                &self.glActiveShaderProgram_p,
+                self.lazy(&self.glActiveShaderProgram_p, b"glActiveShaderProgram\0"),
                 pipeline,
                 program,
             );
@@ -5755,7 +5798,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ActiveShaderProgram_is_loaded(&self) -> bool {
-            !self.glActiveShaderProgram_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glActiveShaderProgram_p, b"glActiveShaderProgram\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glActiveTexture](http://docs.gl/gl4/glActiveTexture)(texture)
         /// * `texture` group: TextureUnit
@@ -5766,7 +5812,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.ActiveTexture({:#X});", texture);
             }
-            let out = call_atomic_ptr_1arg("glActiveTexture", &self.glActiveTexture_p, texture);
+            let out = call_atomic_ptr_1arg(
+                "glActiveTexture",
+                self.lazy(&self.glActiveTexture_p, b"glActiveTexture\0"),
+                texture,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glActiveTexture");
@@ -5787,7 +5837,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ActiveTexture_is_loaded(&self) -> bool {
-            !self.glActiveTexture_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glActiveTexture_p, b"glActiveTexture\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glAttachShader](http://docs.gl/gl4/glAttachShader)(program, shader)
         #[cfg_attr(feature = "inline", inline)]
@@ -5797,8 +5850,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.AttachShader({:?}, {:?});", program, shader);
             }
-            let out =
-                call_atomic_ptr_2arg("glAttachShader", &self.glAttachShader_p, program, shader);
+            let out = call_atomic_ptr_2arg(
+                "glAttachShader",
+                self.lazy(&self.glAttachShader_p, b"glAttachShader\0"),
+                program,
+                shader,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glAttachShader");
@@ -5819,7 +5876,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn AttachShader_is_loaded(&self) -> bool {
-            !self.glAttachShader_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glAttachShader_p, b"glAttachShader\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBeginConditionalRender](http://docs.gl/gl4/glBeginConditionalRender)(id, mode)
         /// * `mode` group: ConditionalRenderMode
@@ -5832,7 +5892,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glBeginConditionalRender",
-                &self.glBeginConditionalRender_p,
+                self.lazy(
+                    &self.glBeginConditionalRender_p,
+                    b"glBeginConditionalRender\0",
+                ),
                 id,
                 mode,
             );
@@ -5856,7 +5919,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BeginConditionalRender_is_loaded(&self) -> bool {
-            !self.glBeginConditionalRender_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glBeginConditionalRender_p,
+                    b"glBeginConditionalRender\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glBeginQuery](http://docs.gl/gl4/glBeginQuery)(target, id)
         /// * `target` group: QueryTarget
@@ -5867,7 +5936,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.BeginQuery({:#X}, {:?});", target, id);
             }
-            let out = call_atomic_ptr_2arg("glBeginQuery", &self.glBeginQuery_p, target, id);
+            let out = call_atomic_ptr_2arg(
+                "glBeginQuery",
+                self.lazy(&self.glBeginQuery_p, b"glBeginQuery\0"),
+                target,
+                id,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glBeginQuery");
@@ -5884,7 +5958,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BeginQuery_is_loaded(&self) -> bool {
-            !self.glBeginQuery_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBeginQuery_p, b"glBeginQuery\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBeginQueryEXT](http://docs.gl/gl4/glBeginQueryEXT)(target, id)
         /// * `target` group: QueryTarget
@@ -5895,7 +5972,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.BeginQueryEXT({:#X}, {:?});", target, id);
             }
-            let out = call_atomic_ptr_2arg("glBeginQueryEXT", &self.glBeginQueryEXT_p, target, id);
+            let out = call_atomic_ptr_2arg(
+                "glBeginQueryEXT",
+                self.lazy(&self.glBeginQueryEXT_p, b"glBeginQueryEXT\0"),
+                target,
+                id,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glBeginQueryEXT");
@@ -5916,7 +5998,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BeginQueryEXT_is_loaded(&self) -> bool {
-            !self.glBeginQueryEXT_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBeginQueryEXT_p, b"glBeginQueryEXT\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBeginQueryIndexed](http://docs.gl/gl4/glBeginQueryIndexed)(target, index, id)
         /// * `target` group: QueryTarget
@@ -5934,7 +6019,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glBeginQueryIndexed",
-                &self.glBeginQueryIndexed_p,
+                self.lazy(&self.glBeginQueryIndexed_p, b"glBeginQueryIndexed\0"),
                 target,
                 index,
                 id,
@@ -5959,7 +6044,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BeginQueryIndexed_is_loaded(&self) -> bool {
-            !self.glBeginQueryIndexed_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBeginQueryIndexed_p, b"glBeginQueryIndexed\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBeginTransformFeedback](http://docs.gl/gl4/glBeginTransformFeedback)(primitiveMode)
         /// * `primitiveMode` group: PrimitiveType
@@ -5972,7 +6060,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_1arg(
                 "glBeginTransformFeedback",
-                &self.glBeginTransformFeedback_p,
+                self.lazy(
+                    &self.glBeginTransformFeedback_p,
+                    b"glBeginTransformFeedback\0",
+                ),
                 primitiveMode,
             );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
@@ -5995,7 +6086,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BeginTransformFeedback_is_loaded(&self) -> bool {
-            !self.glBeginTransformFeedback_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glBeginTransformFeedback_p,
+                    b"glBeginTransformFeedback\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindAttribLocation](http://docs.gl/gl4/glBindAttribLocation)(program, index, name)
         #[cfg_attr(feature = "inline", inline)]
@@ -6017,7 +6114,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glBindAttribLocation",
-                &self.glBindAttribLocation_p,
+                self.lazy(&self.glBindAttribLocation_p, b"glBindAttribLocation\0"),
                 program,
                 index,
                 name,
@@ -6042,7 +6139,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindAttribLocation_is_loaded(&self) -> bool {
-            !self.glBindAttribLocation_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindAttribLocation_p, b"glBindAttribLocation\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindBuffer](http://docs.gl/gl4/glBindBuffer)(target, buffer)
         /// * `target` group: BufferTargetARB
@@ -6053,7 +6153,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.BindBuffer({:#X}, {:?});", target, buffer);
             }
-            let out = call_atomic_ptr_2arg("glBindBuffer", &self.glBindBuffer_p, target, buffer);
+            let out = call_atomic_ptr_2arg(
+                "glBindBuffer",
+                self.lazy(&self.glBindBuffer_p, b"glBindBuffer\0"),
+                target,
+                buffer,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glBindBuffer");
@@ -6070,7 +6175,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindBuffer_is_loaded(&self) -> bool {
-            !self.glBindBuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindBuffer_p, b"glBindBuffer\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindBufferBase](http://docs.gl/gl4/glBindBufferBase)(target, index, buffer)
         /// * `target` group: BufferTargetARB
@@ -6088,7 +6196,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glBindBufferBase",
-                &self.glBindBufferBase_p,
+                self.lazy(&self.glBindBufferBase_p, b"glBindBufferBase\0"),
                 target,
                 index,
                 buffer,
@@ -6113,7 +6221,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindBufferBase_is_loaded(&self) -> bool {
-            !self.glBindBufferBase_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindBufferBase_p, b"glBindBufferBase\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindBufferRange](http://docs.gl/gl4/glBindBufferRange)(target, index, buffer, offset, size)
         /// * `target` group: BufferTargetARB
@@ -6142,7 +6253,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glBindBufferRange",
-                &self.glBindBufferRange_p,
+                self.lazy(&self.glBindBufferRange_p, b"glBindBufferRange\0"),
                 target,
                 index,
                 buffer,
@@ -6169,7 +6280,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindBufferRange_is_loaded(&self) -> bool {
-            !self.glBindBufferRange_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindBufferRange_p, b"glBindBufferRange\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindBuffersBase](http://docs.gl/gl4/glBindBuffersBase)(target, first, count, buffers)
         /// * `target` group: BufferTargetARB
@@ -6195,7 +6309,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glBindBuffersBase",
-                &self.glBindBuffersBase_p,
+                self.lazy(&self.glBindBuffersBase_p, b"glBindBuffersBase\0"),
                 target,
                 first,
                 count,
@@ -6221,7 +6335,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindBuffersBase_is_loaded(&self) -> bool {
-            !self.glBindBuffersBase_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindBuffersBase_p, b"glBindBuffersBase\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindBuffersRange](http://docs.gl/gl4/glBindBuffersRange)(target, first, count, buffers, offsets, sizes)
         /// * `target` group: BufferTargetARB
@@ -6253,7 +6370,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glBindBuffersRange",
-                &self.glBindBuffersRange_p,
+                self.lazy(&self.glBindBuffersRange_p, b"glBindBuffersRange\0"),
                 target,
                 first,
                 count,
@@ -6281,7 +6398,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindBuffersRange_is_loaded(&self) -> bool {
-            !self.glBindBuffersRange_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindBuffersRange_p, b"glBindBuffersRange\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindFragDataLocation](http://docs.gl/gl4/glBindFragDataLocation)(program, color, name)
         /// * `name` len: COMPSIZE(name)
@@ -6304,7 +6424,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glBindFragDataLocation",
-                &self.glBindFragDataLocation_p,
+                self.lazy(&self.glBindFragDataLocation_p, b"glBindFragDataLocation\0"),
                 program,
                 color,
                 name,
@@ -6329,7 +6449,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindFragDataLocation_is_loaded(&self) -> bool {
-            !self.glBindFragDataLocation_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindFragDataLocation_p, b"glBindFragDataLocation\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindFragDataLocationIndexed](http://docs.gl/gl4/glBindFragDataLocationIndexed)(program, colorNumber, index, name)
         #[cfg_attr(feature = "inline", inline)]
@@ -6353,7 +6476,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glBindFragDataLocationIndexed",
-                &self.glBindFragDataLocationIndexed_p,
+                self.lazy(
+                    &self.glBindFragDataLocationIndexed_p,
+                    b"glBindFragDataLocationIndexed\0",
+                ),
                 program,
                 colorNumber,
                 index,
@@ -6379,7 +6505,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindFragDataLocationIndexed_is_loaded(&self) -> bool {
-            !self.glBindFragDataLocationIndexed_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glBindFragDataLocationIndexed_p,
+                    b"glBindFragDataLocationIndexed\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindFramebuffer](http://docs.gl/gl4/glBindFramebuffer)(target, framebuffer)
         /// * `target` group: FramebufferTarget
@@ -6396,7 +6528,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glBindFramebuffer",
-                &self.glBindFramebuffer_p,
+                self.lazy(&self.glBindFramebuffer_p, b"glBindFramebuffer\0"),
                 target,
                 framebuffer,
             );
@@ -6420,7 +6552,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindFramebuffer_is_loaded(&self) -> bool {
-            !self.glBindFramebuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindFramebuffer_p, b"glBindFramebuffer\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindImageTexture](http://docs.gl/gl4/glBindImageTexture)(unit, texture, level, layered, layer, access, format)
         /// * `access` group: BufferAccessARB
@@ -6452,7 +6587,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_7arg(
                 "glBindImageTexture",
-                &self.glBindImageTexture_p,
+                self.lazy(&self.glBindImageTexture_p, b"glBindImageTexture\0"),
                 unit,
                 texture,
                 level,
@@ -6481,7 +6616,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindImageTexture_is_loaded(&self) -> bool {
-            !self.glBindImageTexture_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindImageTexture_p, b"glBindImageTexture\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindImageTextures](http://docs.gl/gl4/glBindImageTextures)(first, count, textures)
         /// * `textures` len: count
@@ -6504,7 +6642,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glBindImageTextures",
-                &self.glBindImageTextures_p,
+                self.lazy(&self.glBindImageTextures_p, b"glBindImageTextures\0"),
                 first,
                 count,
                 textures,
@@ -6529,7 +6667,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindImageTextures_is_loaded(&self) -> bool {
-            !self.glBindImageTextures_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindImageTextures_p, b"glBindImageTextures\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindProgramPipeline](http://docs.gl/gl4/glBindProgramPipeline)(pipeline)
         #[cfg_attr(feature = "inline", inline)]
@@ -6541,7 +6682,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_1arg(
                 "glBindProgramPipeline",
-                &self.glBindProgramPipeline_p,
+                self.lazy(&self.glBindProgramPipeline_p, b"glBindProgramPipeline\0"),
                 pipeline,
             );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
@@ -6564,7 +6705,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindProgramPipeline_is_loaded(&self) -> bool {
-            !self.glBindProgramPipeline_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindProgramPipeline_p, b"glBindProgramPipeline\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindRenderbuffer](http://docs.gl/gl4/glBindRenderbuffer)(target, renderbuffer)
         /// * `target` group: RenderbufferTarget
@@ -6581,7 +6725,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glBindRenderbuffer",
-                &self.glBindRenderbuffer_p,
+                self.lazy(&self.glBindRenderbuffer_p, b"glBindRenderbuffer\0"),
                 target,
                 renderbuffer,
             );
@@ -6605,7 +6749,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindRenderbuffer_is_loaded(&self) -> bool {
-            !self.glBindRenderbuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindRenderbuffer_p, b"glBindRenderbuffer\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindSampler](http://docs.gl/gl4/glBindSampler)(unit, sampler)
         #[cfg_attr(feature = "inline", inline)]
@@ -6615,7 +6762,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.BindSampler({:?}, {:?});", unit, sampler);
             }
-            let out = call_atomic_ptr_2arg("glBindSampler", &self.glBindSampler_p, unit, sampler);
+            let out = call_atomic_ptr_2arg(
+                "glBindSampler",
+                self.lazy(&self.glBindSampler_p, b"glBindSampler\0"),
+                unit,
+                sampler,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glBindSampler");
@@ -6632,7 +6784,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindSampler_is_loaded(&self) -> bool {
-            !self.glBindSampler_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindSampler_p, b"glBindSampler\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindSamplers](http://docs.gl/gl4/glBindSamplers)(first, count, samplers)
         /// * `samplers` len: count
@@ -6650,7 +6805,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glBindSamplers",
-                &self.glBindSamplers_p,
+                self.lazy(&self.glBindSamplers_p, b"glBindSamplers\0"),
                 first,
                 count,
                 samplers,
@@ -6675,7 +6830,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindSamplers_is_loaded(&self) -> bool {
-            !self.glBindSamplers_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindSamplers_p, b"glBindSamplers\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindTexture](http://docs.gl/gl4/glBindTexture)(target, texture)
         /// * `target` group: TextureTarget
@@ -6687,7 +6845,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.BindTexture({:#X}, {:?});", target, texture);
             }
-            let out = call_atomic_ptr_2arg("glBindTexture", &self.glBindTexture_p, target, texture);
+            let out = call_atomic_ptr_2arg(
+                "glBindTexture",
+                self.lazy(&self.glBindTexture_p, b"glBindTexture\0"),
+                target,
+                texture,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glBindTexture");
@@ -6704,7 +6867,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindTexture_is_loaded(&self) -> bool {
-            !self.glBindTexture_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindTexture_p, b"glBindTexture\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindTextureUnit](http://docs.gl/gl4/glBindTextureUnit)(unit, texture)
         #[cfg_attr(feature = "inline", inline)]
@@ -6716,7 +6882,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glBindTextureUnit",
-                &self.glBindTextureUnit_p,
+                self.lazy(&self.glBindTextureUnit_p, b"glBindTextureUnit\0"),
                 unit,
                 texture,
             );
@@ -6740,7 +6906,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindTextureUnit_is_loaded(&self) -> bool {
-            !self.glBindTextureUnit_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindTextureUnit_p, b"glBindTextureUnit\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindTextures](http://docs.gl/gl4/glBindTextures)(first, count, textures)
         /// * `textures` len: count
@@ -6758,7 +6927,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glBindTextures",
-                &self.glBindTextures_p,
+                self.lazy(&self.glBindTextures_p, b"glBindTextures\0"),
                 first,
                 count,
                 textures,
@@ -6783,7 +6952,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindTextures_is_loaded(&self) -> bool {
-            !self.glBindTextures_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindTextures_p, b"glBindTextures\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindTransformFeedback](http://docs.gl/gl4/glBindTransformFeedback)(target, id)
         /// * `target` group: BindTransformFeedbackTarget
@@ -6796,7 +6968,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glBindTransformFeedback",
-                &self.glBindTransformFeedback_p,
+                self.lazy(
+                    &self.glBindTransformFeedback_p,
+                    b"glBindTransformFeedback\0",
+                ),
                 target,
                 id,
             );
@@ -6820,7 +6995,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindTransformFeedback_is_loaded(&self) -> bool {
-            !self.glBindTransformFeedback_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glBindTransformFeedback_p,
+                    b"glBindTransformFeedback\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindVertexArray](http://docs.gl/gl4/glBindVertexArray)(array)
         #[cfg_attr(feature = "inline", inline)]
@@ -6830,7 +7011,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.BindVertexArray({:?});", array);
             }
-            let out = call_atomic_ptr_1arg("glBindVertexArray", &self.glBindVertexArray_p, array);
+            let out = call_atomic_ptr_1arg(
+                "glBindVertexArray",
+                self.lazy(&self.glBindVertexArray_p, b"glBindVertexArray\0"),
+                array,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glBindVertexArray");
@@ -6851,7 +7036,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindVertexArray_is_loaded(&self) -> bool {
-            !self.glBindVertexArray_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindVertexArray_p, b"glBindVertexArray\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindVertexArrayAPPLE](http://docs.gl/gl4/glBindVertexArrayAPPLE)(array)
         #[cfg_attr(feature = "inline", inline)]
@@ -6863,7 +7051,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_1arg(
                 "glBindVertexArrayAPPLE",
-                &self.glBindVertexArrayAPPLE_p,
+                self.lazy(&self.glBindVertexArrayAPPLE_p, b"glBindVertexArrayAPPLE\0"),
                 array,
             );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
@@ -6886,7 +7074,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindVertexArrayAPPLE_is_loaded(&self) -> bool {
-            !self.glBindVertexArrayAPPLE_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindVertexArrayAPPLE_p, b"glBindVertexArrayAPPLE\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindVertexArrayOES](http://docs.gl/gl4/glBindVertexArrayOES)(array)
         /// * alias of: [`glBindVertexArray`]
@@ -6897,8 +7088,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.BindVertexArrayOES({:?});", array);
             }
-            let out =
-                call_atomic_ptr_1arg("glBindVertexArrayOES", &self.glBindVertexArrayOES_p, array);
+            let out = call_atomic_ptr_1arg(
+                "glBindVertexArrayOES",
+                self.lazy(&self.glBindVertexArrayOES_p, b"glBindVertexArrayOES\0"),
+                array,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glBindVertexArrayOES");
@@ -6919,7 +7113,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindVertexArrayOES_is_loaded(&self) -> bool {
-            !self.glBindVertexArrayOES_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindVertexArrayOES_p, b"glBindVertexArrayOES\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindVertexBuffer](http://docs.gl/gl4/glBindVertexBuffer)(bindingindex, buffer, offset, stride)
         /// * `offset` group: BufferOffset
@@ -6944,7 +7141,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glBindVertexBuffer",
-                &self.glBindVertexBuffer_p,
+                self.lazy(&self.glBindVertexBuffer_p, b"glBindVertexBuffer\0"),
                 bindingindex,
                 buffer,
                 offset,
@@ -6970,7 +7167,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindVertexBuffer_is_loaded(&self) -> bool {
-            !self.glBindVertexBuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindVertexBuffer_p, b"glBindVertexBuffer\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBindVertexBuffers](http://docs.gl/gl4/glBindVertexBuffers)(first, count, buffers, offsets, strides)
         /// * `buffers` len: count
@@ -6999,7 +7199,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glBindVertexBuffers",
-                &self.glBindVertexBuffers_p,
+                self.lazy(&self.glBindVertexBuffers_p, b"glBindVertexBuffers\0"),
                 first,
                 count,
                 buffers,
@@ -7026,7 +7226,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BindVertexBuffers_is_loaded(&self) -> bool {
-            !self.glBindVertexBuffers_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBindVertexBuffers_p, b"glBindVertexBuffers\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBlendBarrier](http://docs.gl/gl4/glBlendBarrier)()
         #[cfg_attr(feature = "inline", inline)]
@@ -7036,7 +7239,10 @@ pub mod struct_commands {
             {
                 trace!("calling gl.BlendBarrier();",);
             }
-            let out = call_atomic_ptr_0arg("glBlendBarrier", &self.glBlendBarrier_p);
+            let out = call_atomic_ptr_0arg(
+                "glBlendBarrier",
+                self.lazy(&self.glBlendBarrier_p, b"glBlendBarrier\0"),
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glBlendBarrier");
@@ -7057,7 +7263,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BlendBarrier_is_loaded(&self) -> bool {
-            !self.glBlendBarrier_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBlendBarrier_p, b"glBlendBarrier\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBlendColor](http://docs.gl/gl4/glBlendColor)(red, green, blue, alpha)
         /// * `red` group: ColorF
@@ -7085,7 +7294,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glBlendColor",
-                &self.glBlendColor_p,
+                self.lazy(&self.glBlendColor_p, b"glBlendColor\0"),
                 red,
                 green,
                 blue,
@@ -7107,7 +7316,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BlendColor_is_loaded(&self) -> bool {
-            !self.glBlendColor_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBlendColor_p, b"glBlendColor\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBlendEquation](http://docs.gl/gl4/glBlendEquation)(mode)
         /// * `mode` group: BlendEquationModeEXT
@@ -7118,7 +7330,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.BlendEquation({:#X});", mode);
             }
-            let out = call_atomic_ptr_1arg("glBlendEquation", &self.glBlendEquation_p, mode);
+            let out = call_atomic_ptr_1arg(
+                "glBlendEquation",
+                self.lazy(&self.glBlendEquation_p, b"glBlendEquation\0"),
+                mode,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glBlendEquation");
@@ -7139,7 +7355,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BlendEquation_is_loaded(&self) -> bool {
-            !self.glBlendEquation_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBlendEquation_p, b"glBlendEquation\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBlendEquationSeparate](http://docs.gl/gl4/glBlendEquationSeparate)(modeRGB, modeAlpha)
         /// * `modeRGB` group: BlendEquationModeEXT
@@ -7157,7 +7376,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glBlendEquationSeparate",
-                &self.glBlendEquationSeparate_p,
+                self.lazy(
+                    &self.glBlendEquationSeparate_p,
+                    b"glBlendEquationSeparate\0",
+                ),
                 modeRGB,
                 modeAlpha,
             );
@@ -7181,7 +7403,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BlendEquationSeparate_is_loaded(&self) -> bool {
-            !self.glBlendEquationSeparate_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glBlendEquationSeparate_p,
+                    b"glBlendEquationSeparate\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glBlendEquationSeparatei](http://docs.gl/gl4/glBlendEquationSeparate)(buf, modeRGB, modeAlpha)
         /// * `modeRGB` group: BlendEquationModeEXT
@@ -7205,7 +7433,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glBlendEquationSeparatei",
-                &self.glBlendEquationSeparatei_p,
+                self.lazy(
+                    &self.glBlendEquationSeparatei_p,
+                    b"glBlendEquationSeparatei\0",
+                ),
                 buf,
                 modeRGB,
                 modeAlpha,
@@ -7230,7 +7461,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BlendEquationSeparatei_is_loaded(&self) -> bool {
-            !self.glBlendEquationSeparatei_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glBlendEquationSeparatei_p,
+                    b"glBlendEquationSeparatei\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glBlendEquationi](http://docs.gl/gl4/glBlendEquation)(buf, mode)
         /// * `mode` group: BlendEquationModeEXT
@@ -7241,7 +7478,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.BlendEquationi({:?}, {:#X});", buf, mode);
             }
-            let out = call_atomic_ptr_2arg("glBlendEquationi", &self.glBlendEquationi_p, buf, mode);
+            let out = call_atomic_ptr_2arg(
+                "glBlendEquationi",
+                self.lazy(&self.glBlendEquationi_p, b"glBlendEquationi\0"),
+                buf,
+                mode,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glBlendEquationi");
@@ -7262,7 +7504,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BlendEquationi_is_loaded(&self) -> bool {
-            !self.glBlendEquationi_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBlendEquationi_p, b"glBlendEquationi\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBlendFunc](http://docs.gl/gl4/glBlendFunc)(sfactor, dfactor)
         /// * `sfactor` group: BlendingFactor
@@ -7274,7 +7519,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.BlendFunc({:#X}, {:#X});", sfactor, dfactor);
             }
-            let out = call_atomic_ptr_2arg("glBlendFunc", &self.glBlendFunc_p, sfactor, dfactor);
+            let out = call_atomic_ptr_2arg(
+                "glBlendFunc",
+                self.lazy(&self.glBlendFunc_p, b"glBlendFunc\0"),
+                sfactor,
+                dfactor,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glBlendFunc");
@@ -7291,7 +7541,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BlendFunc_is_loaded(&self) -> bool {
-            !self.glBlendFunc_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBlendFunc_p, b"glBlendFunc\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBlendFuncSeparate](http://docs.gl/gl4/glBlendFuncSeparate)(sfactorRGB, dfactorRGB, sfactorAlpha, dfactorAlpha)
         /// * `sfactorRGB` group: BlendingFactor
@@ -7319,7 +7572,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glBlendFuncSeparate",
-                &self.glBlendFuncSeparate_p,
+                self.lazy(&self.glBlendFuncSeparate_p, b"glBlendFuncSeparate\0"),
                 sfactorRGB,
                 dfactorRGB,
                 sfactorAlpha,
@@ -7345,7 +7598,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BlendFuncSeparate_is_loaded(&self) -> bool {
-            !self.glBlendFuncSeparate_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBlendFuncSeparate_p, b"glBlendFuncSeparate\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBlendFuncSeparatei](http://docs.gl/gl4/glBlendFuncSeparate)(buf, srcRGB, dstRGB, srcAlpha, dstAlpha)
         /// * `srcRGB` group: BlendingFactor
@@ -7375,7 +7631,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glBlendFuncSeparatei",
-                &self.glBlendFuncSeparatei_p,
+                self.lazy(&self.glBlendFuncSeparatei_p, b"glBlendFuncSeparatei\0"),
                 buf,
                 srcRGB,
                 dstRGB,
@@ -7402,7 +7658,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BlendFuncSeparatei_is_loaded(&self) -> bool {
-            !self.glBlendFuncSeparatei_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBlendFuncSeparatei_p, b"glBlendFuncSeparatei\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBlendFunci](http://docs.gl/gl4/glBlendFunc)(buf, src, dst)
         /// * `src` group: BlendingFactor
@@ -7414,7 +7673,13 @@ pub mod struct_commands {
             {
                 trace!("calling gl.BlendFunci({:?}, {:#X}, {:#X});", buf, src, dst);
             }
-            let out = call_atomic_ptr_3arg("glBlendFunci", &self.glBlendFunci_p, buf, src, dst);
+            let out = call_atomic_ptr_3arg(
+                "glBlendFunci",
+                self.lazy(&self.glBlendFunci_p, b"glBlendFunci\0"),
+                buf,
+                src,
+                dst,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glBlendFunci");
@@ -7431,7 +7696,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BlendFunci_is_loaded(&self) -> bool {
-            !self.glBlendFunci_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBlendFunci_p, b"glBlendFunci\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBlitFramebuffer](http://docs.gl/gl4/glBlitFramebuffer)(srcX0, srcY0, srcX1, srcY1, dstX0, dstY0, dstX1, dstY1, mask, filter)
         /// * `mask` group: ClearBufferMask
@@ -7457,7 +7725,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_10arg(
                 "glBlitFramebuffer",
-                &self.glBlitFramebuffer_p,
+                self.lazy(&self.glBlitFramebuffer_p, b"glBlitFramebuffer\0"),
                 srcX0,
                 srcY0,
                 srcX1,
@@ -7489,7 +7757,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BlitFramebuffer_is_loaded(&self) -> bool {
-            !self.glBlitFramebuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBlitFramebuffer_p, b"glBlitFramebuffer\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBlitNamedFramebuffer](http://docs.gl/gl4/glBlitNamedFramebuffer)(readFramebuffer, drawFramebuffer, srcX0, srcY0, srcX1, srcY1, dstX0, dstY0, dstX1, dstY1, mask, filter)
         /// * `mask` group: ClearBufferMask
@@ -7517,7 +7788,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_12arg(
                 "glBlitNamedFramebuffer",
-                &self.glBlitNamedFramebuffer_p,
+                self.lazy(&self.glBlitNamedFramebuffer_p, b"glBlitNamedFramebuffer\0"),
                 readFramebuffer,
                 drawFramebuffer,
                 srcX0,
@@ -7551,7 +7822,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BlitNamedFramebuffer_is_loaded(&self) -> bool {
-            !self.glBlitNamedFramebuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBlitNamedFramebuffer_p, b"glBlitNamedFramebuffer\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBufferData](http://docs.gl/gl4/glBufferData)(target, size, data, usage)
         /// * `target` group: BufferTargetARB
@@ -7579,7 +7853,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glBufferData",
-                &self.glBufferData_p,
+                self.lazy(&self.glBufferData_p, b"glBufferData\0"),
                 target,
                 size,
                 data,
@@ -7601,7 +7875,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BufferData_is_loaded(&self) -> bool {
-            !self.glBufferData_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBufferData_p, b"glBufferData\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBufferStorage](http://docs.gl/gl4/glBufferStorage)(target, size, data, flags)
         /// * `target` group: BufferStorageTarget
@@ -7628,7 +7905,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glBufferStorage",
-                &self.glBufferStorage_p,
+                self.lazy(&self.glBufferStorage_p, b"glBufferStorage\0"),
                 target,
                 size,
                 data,
@@ -7654,7 +7931,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BufferStorage_is_loaded(&self) -> bool {
-            !self.glBufferStorage_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBufferStorage_p, b"glBufferStorage\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBufferStorageEXT](http://docs.gl/gl4/glBufferStorageEXT)(target, size, data, flags)
         /// * `target` group: BufferStorageTarget
@@ -7682,7 +7962,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glBufferStorageEXT",
-                &self.glBufferStorageEXT_p,
+                self.lazy(&self.glBufferStorageEXT_p, b"glBufferStorageEXT\0"),
                 target,
                 size,
                 data,
@@ -7708,7 +7988,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BufferStorageEXT_is_loaded(&self) -> bool {
-            !self.glBufferStorageEXT_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBufferStorageEXT_p, b"glBufferStorageEXT\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glBufferSubData](http://docs.gl/gl4/glBufferSubData)(target, offset, size, data)
         /// * `target` group: BufferTargetARB
@@ -7736,7 +8019,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glBufferSubData",
-                &self.glBufferSubData_p,
+                self.lazy(&self.glBufferSubData_p, b"glBufferSubData\0"),
                 target,
                 offset,
                 size,
@@ -7762,7 +8045,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn BufferSubData_is_loaded(&self) -> bool {
-            !self.glBufferSubData_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glBufferSubData_p, b"glBufferSubData\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCheckFramebufferStatus](http://docs.gl/gl4/glCheckFramebufferStatus)(target)
         /// * `target` group: FramebufferTarget
@@ -7776,7 +8062,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_1arg(
                 "glCheckFramebufferStatus",
-                &self.glCheckFramebufferStatus_p,
+                self.lazy(
+                    &self.glCheckFramebufferStatus_p,
+                    b"glCheckFramebufferStatus\0",
+                ),
                 target,
             );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
@@ -7799,7 +8088,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CheckFramebufferStatus_is_loaded(&self) -> bool {
-            !self.glCheckFramebufferStatus_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glCheckFramebufferStatus_p,
+                    b"glCheckFramebufferStatus\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glCheckNamedFramebufferStatus](http://docs.gl/gl4/glCheckNamedFramebufferStatus)(framebuffer, target)
         /// * `target` group: FramebufferTarget
@@ -7821,7 +8116,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glCheckNamedFramebufferStatus",
-                &self.glCheckNamedFramebufferStatus_p,
+                self.lazy(
+                    &self.glCheckNamedFramebufferStatus_p,
+                    b"glCheckNamedFramebufferStatus\0",
+                ),
                 framebuffer,
                 target,
             );
@@ -7845,7 +8143,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CheckNamedFramebufferStatus_is_loaded(&self) -> bool {
-            !self.glCheckNamedFramebufferStatus_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glCheckNamedFramebufferStatus_p,
+                    b"glCheckNamedFramebufferStatus\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glClampColor](http://docs.gl/gl4/glClampColor)(target, clamp)
         /// * `target` group: ClampColorTargetARB
@@ -7857,7 +8161,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.ClampColor({:#X}, {:#X});", target, clamp);
             }
-            let out = call_atomic_ptr_2arg("glClampColor", &self.glClampColor_p, target, clamp);
+            let out = call_atomic_ptr_2arg(
+                "glClampColor",
+                self.lazy(&self.glClampColor_p, b"glClampColor\0"),
+                target,
+                clamp,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glClampColor");
@@ -7874,7 +8183,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ClampColor_is_loaded(&self) -> bool {
-            !self.glClampColor_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glClampColor_p, b"glClampColor\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glClear](http://docs.gl/gl4/glClear)(mask)
         /// * `mask` group: ClearBufferMask
@@ -7885,7 +8197,8 @@ pub mod struct_commands {
             {
                 trace!("calling gl.Clear({:?});", mask);
             }
-            let out = call_atomic_ptr_1arg("glClear", &self.glClear_p, mask);
+            let out =
+                call_atomic_ptr_1arg("glClear", self.lazy(&self.glClear_p, b"glClear\0"), mask);
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glClear");
@@ -7902,7 +8215,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Clear_is_loaded(&self) -> bool {
-            !self.glClear_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glClear_p, b"glClear\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glClearBufferData](http://docs.gl/gl4/glClearBufferData)(target, internalformat, format, type_, data)
         /// * `target` group: BufferStorageTarget
@@ -7933,7 +8249,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glClearBufferData",
-                &self.glClearBufferData_p,
+                self.lazy(&self.glClearBufferData_p, b"glClearBufferData\0"),
                 target,
                 internalformat,
                 format,
@@ -7960,7 +8276,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ClearBufferData_is_loaded(&self) -> bool {
-            !self.glClearBufferData_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glClearBufferData_p, b"glClearBufferData\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glClearBufferSubData](http://docs.gl/gl4/glClearBufferSubData)(target, internalformat, offset, size, format, type_, data)
         /// * `target` group: BufferTargetARB
@@ -7997,7 +8316,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_7arg(
                 "glClearBufferSubData",
-                &self.glClearBufferSubData_p,
+                self.lazy(&self.glClearBufferSubData_p, b"glClearBufferSubData\0"),
                 target,
                 internalformat,
                 offset,
@@ -8026,7 +8345,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ClearBufferSubData_is_loaded(&self) -> bool {
-            !self.glClearBufferSubData_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glClearBufferSubData_p, b"glClearBufferSubData\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glClearBufferfi](http://docs.gl/gl4/glClearBuffer)(buffer, drawbuffer, depth, stencil)
         /// * `buffer` group: Buffer
@@ -8052,7 +8374,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glClearBufferfi",
-                &self.glClearBufferfi_p,
+                self.lazy(&self.glClearBufferfi_p, b"glClearBufferfi\0"),
                 buffer,
                 drawbuffer,
                 depth,
@@ -8078,7 +8400,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ClearBufferfi_is_loaded(&self) -> bool {
-            !self.glClearBufferfi_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glClearBufferfi_p, b"glClearBufferfi\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glClearBufferfv](http://docs.gl/gl4/glClearBuffer)(buffer, drawbuffer, value)
         /// * `buffer` group: Buffer
@@ -8103,7 +8428,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glClearBufferfv",
-                &self.glClearBufferfv_p,
+                self.lazy(&self.glClearBufferfv_p, b"glClearBufferfv\0"),
                 buffer,
                 drawbuffer,
                 value,
@@ -8128,7 +8453,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ClearBufferfv_is_loaded(&self) -> bool {
-            !self.glClearBufferfv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glClearBufferfv_p, b"glClearBufferfv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glClearBufferiv](http://docs.gl/gl4/glClearBuffer)(buffer, drawbuffer, value)
         /// * `buffer` group: Buffer
@@ -8148,7 +8476,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glClearBufferiv",
-                &self.glClearBufferiv_p,
+                self.lazy(&self.glClearBufferiv_p, b"glClearBufferiv\0"),
                 buffer,
                 drawbuffer,
                 value,
@@ -8173,7 +8501,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ClearBufferiv_is_loaded(&self) -> bool {
-            !self.glClearBufferiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glClearBufferiv_p, b"glClearBufferiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glClearBufferuiv](http://docs.gl/gl4/glClearBuffer)(buffer, drawbuffer, value)
         /// * `buffer` group: Buffer
@@ -8198,7 +8529,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glClearBufferuiv",
-                &self.glClearBufferuiv_p,
+                self.lazy(&self.glClearBufferuiv_p, b"glClearBufferuiv\0"),
                 buffer,
                 drawbuffer,
                 value,
@@ -8223,7 +8554,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ClearBufferuiv_is_loaded(&self) -> bool {
-            !self.glClearBufferuiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glClearBufferuiv_p, b"glClearBufferuiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glClearColor](http://docs.gl/gl4/glClearColor)(red, green, blue, alpha)
         /// * `red` group: ColorF
@@ -8251,7 +8585,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glClearColor",
-                &self.glClearColor_p,
+                self.lazy(&self.glClearColor_p, b"glClearColor\0"),
                 red,
                 green,
                 blue,
@@ -8273,7 +8607,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ClearColor_is_loaded(&self) -> bool {
-            !self.glClearColor_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glClearColor_p, b"glClearColor\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glClearDepth](http://docs.gl/gl4/glClearDepth)(depth)
         #[cfg_attr(feature = "inline", inline)]
@@ -8283,7 +8620,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.ClearDepth({:?});", depth);
             }
-            let out = call_atomic_ptr_1arg("glClearDepth", &self.glClearDepth_p, depth);
+            let out = call_atomic_ptr_1arg(
+                "glClearDepth",
+                self.lazy(&self.glClearDepth_p, b"glClearDepth\0"),
+                depth,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glClearDepth");
@@ -8300,7 +8641,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ClearDepth_is_loaded(&self) -> bool {
-            !self.glClearDepth_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glClearDepth_p, b"glClearDepth\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glClearDepthf](http://docs.gl/gl4/glClearDepth)(d)
         #[cfg_attr(feature = "inline", inline)]
@@ -8310,7 +8654,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.ClearDepthf({:?});", d);
             }
-            let out = call_atomic_ptr_1arg("glClearDepthf", &self.glClearDepthf_p, d);
+            let out = call_atomic_ptr_1arg(
+                "glClearDepthf",
+                self.lazy(&self.glClearDepthf_p, b"glClearDepthf\0"),
+                d,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glClearDepthf");
@@ -8327,7 +8675,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ClearDepthf_is_loaded(&self) -> bool {
-            !self.glClearDepthf_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glClearDepthf_p, b"glClearDepthf\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glClearNamedBufferData](http://docs.gl/gl4/glClearNamedBufferData)(buffer, internalformat, format, type_, data)
         /// * `internalformat` group: InternalFormat
@@ -8356,7 +8707,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glClearNamedBufferData",
-                &self.glClearNamedBufferData_p,
+                self.lazy(&self.glClearNamedBufferData_p, b"glClearNamedBufferData\0"),
                 buffer,
                 internalformat,
                 format,
@@ -8383,7 +8734,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ClearNamedBufferData_is_loaded(&self) -> bool {
-            !self.glClearNamedBufferData_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glClearNamedBufferData_p, b"glClearNamedBufferData\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glClearNamedBufferSubData](http://docs.gl/gl4/glClearNamedBufferSubData)(buffer, internalformat, offset, size, format, type_, data)
         /// * `internalformat` group: InternalFormat
@@ -8408,7 +8762,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_7arg(
                 "glClearNamedBufferSubData",
-                &self.glClearNamedBufferSubData_p,
+                self.lazy(
+                    &self.glClearNamedBufferSubData_p,
+                    b"glClearNamedBufferSubData\0",
+                ),
                 buffer,
                 internalformat,
                 offset,
@@ -8437,7 +8794,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ClearNamedBufferSubData_is_loaded(&self) -> bool {
-            !self.glClearNamedBufferSubData_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glClearNamedBufferSubData_p,
+                    b"glClearNamedBufferSubData\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glClearNamedFramebufferfi](http://docs.gl/gl4/glClearNamedFramebuffer)(framebuffer, buffer, drawbuffer, depth, stencil)
         /// * `buffer` group: Buffer
@@ -8464,7 +8827,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glClearNamedFramebufferfi",
-                &self.glClearNamedFramebufferfi_p,
+                self.lazy(
+                    &self.glClearNamedFramebufferfi_p,
+                    b"glClearNamedFramebufferfi\0",
+                ),
                 framebuffer,
                 buffer,
                 drawbuffer,
@@ -8491,7 +8857,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ClearNamedFramebufferfi_is_loaded(&self) -> bool {
-            !self.glClearNamedFramebufferfi_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glClearNamedFramebufferfi_p,
+                    b"glClearNamedFramebufferfi\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glClearNamedFramebufferfv](http://docs.gl/gl4/glClearNamedFramebuffer)(framebuffer, buffer, drawbuffer, value)
         /// * `buffer` group: Buffer
@@ -8516,7 +8888,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glClearNamedFramebufferfv",
-                &self.glClearNamedFramebufferfv_p,
+                self.lazy(
+                    &self.glClearNamedFramebufferfv_p,
+                    b"glClearNamedFramebufferfv\0",
+                ),
                 framebuffer,
                 buffer,
                 drawbuffer,
@@ -8542,7 +8917,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ClearNamedFramebufferfv_is_loaded(&self) -> bool {
-            !self.glClearNamedFramebufferfv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glClearNamedFramebufferfv_p,
+                    b"glClearNamedFramebufferfv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glClearNamedFramebufferiv](http://docs.gl/gl4/glClearNamedFramebuffer)(framebuffer, buffer, drawbuffer, value)
         /// * `buffer` group: Buffer
@@ -8567,7 +8948,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glClearNamedFramebufferiv",
-                &self.glClearNamedFramebufferiv_p,
+                self.lazy(
+                    &self.glClearNamedFramebufferiv_p,
+                    b"glClearNamedFramebufferiv\0",
+                ),
                 framebuffer,
                 buffer,
                 drawbuffer,
@@ -8593,7 +8977,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ClearNamedFramebufferiv_is_loaded(&self) -> bool {
-            !self.glClearNamedFramebufferiv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glClearNamedFramebufferiv_p,
+                    b"glClearNamedFramebufferiv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glClearNamedFramebufferuiv](http://docs.gl/gl4/glClearNamedFramebuffer)(framebuffer, buffer, drawbuffer, value)
         /// * `buffer` group: Buffer
@@ -8618,7 +9008,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glClearNamedFramebufferuiv",
-                &self.glClearNamedFramebufferuiv_p,
+                self.lazy(
+                    &self.glClearNamedFramebufferuiv_p,
+                    b"glClearNamedFramebufferuiv\0",
+                ),
                 framebuffer,
                 buffer,
                 drawbuffer,
@@ -8644,7 +9037,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ClearNamedFramebufferuiv_is_loaded(&self) -> bool {
-            !self.glClearNamedFramebufferuiv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glClearNamedFramebufferuiv_p,
+                    b"glClearNamedFramebufferuiv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glClearStencil](http://docs.gl/gl4/glClearStencil)(s)
         /// * `s` group: StencilValue
@@ -8655,7 +9054,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.ClearStencil({:?});", s);
             }
-            let out = call_atomic_ptr_1arg("glClearStencil", &self.glClearStencil_p, s);
+            let out = call_atomic_ptr_1arg(
+                "glClearStencil",
+                self.lazy(&self.glClearStencil_p, b"glClearStencil\0"),
+                s,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glClearStencil");
@@ -8676,7 +9079,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ClearStencil_is_loaded(&self) -> bool {
-            !self.glClearStencil_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glClearStencil_p, b"glClearStencil\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glClearTexImage](http://docs.gl/gl4/glClearTexImage)(texture, level, format, type_, data)
         /// * `format` group: PixelFormat
@@ -8705,7 +9111,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glClearTexImage",
-                &self.glClearTexImage_p,
+                self.lazy(&self.glClearTexImage_p, b"glClearTexImage\0"),
                 texture,
                 level,
                 format,
@@ -8732,7 +9138,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ClearTexImage_is_loaded(&self) -> bool {
-            !self.glClearTexImage_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glClearTexImage_p, b"glClearTexImage\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glClearTexSubImage](http://docs.gl/gl4/glClearTexSubImage)(texture, level, xoffset, yoffset, zoffset, width, height, depth, format, type_, data)
         /// * `format` group: PixelFormat
@@ -8760,7 +9169,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_11arg(
                 "glClearTexSubImage",
-                &self.glClearTexSubImage_p,
+                self.lazy(&self.glClearTexSubImage_p, b"glClearTexSubImage\0"),
                 texture,
                 level,
                 xoffset,
@@ -8793,7 +9202,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ClearTexSubImage_is_loaded(&self) -> bool {
-            !self.glClearTexSubImage_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glClearTexSubImage_p, b"glClearTexSubImage\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glClientWaitSync](http://docs.gl/gl4/glClientWaitSync)(sync, flags, timeout)
         /// * `sync` group: sync
@@ -8818,7 +9230,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glClientWaitSync",
-                &self.glClientWaitSync_p,
+                self.lazy(&self.glClientWaitSync_p, b"glClientWaitSync\0"),
                 sync,
                 flags,
                 timeout,
@@ -8843,7 +9255,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ClientWaitSync_is_loaded(&self) -> bool {
-            !self.glClientWaitSync_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glClientWaitSync_p, b"glClientWaitSync\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glClipControl](http://docs.gl/gl4/glClipControl)(origin, depth)
         /// * `origin` group: ClipControlOrigin
@@ -8855,7 +9270,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.ClipControl({:#X}, {:#X});", origin, depth);
             }
-            let out = call_atomic_ptr_2arg("glClipControl", &self.glClipControl_p, origin, depth);
+            let out = call_atomic_ptr_2arg(
+                "glClipControl",
+                self.lazy(&self.glClipControl_p, b"glClipControl\0"),
+                origin,
+                depth,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glClipControl");
@@ -8872,7 +9292,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ClipControl_is_loaded(&self) -> bool {
-            !self.glClipControl_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glClipControl_p, b"glClipControl\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glColorMask](http://docs.gl/gl4/glColorMask)(red, green, blue, alpha)
         #[cfg_attr(feature = "inline", inline)]
@@ -8894,8 +9317,14 @@ pub mod struct_commands {
                     alpha
                 );
             }
-            let out =
-                call_atomic_ptr_4arg("glColorMask", &self.glColorMask_p, red, green, blue, alpha);
+            let out = call_atomic_ptr_4arg(
+                "glColorMask",
+                self.lazy(&self.glColorMask_p, b"glColorMask\0"),
+                red,
+                green,
+                blue,
+                alpha,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glColorMask");
@@ -8912,7 +9341,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ColorMask_is_loaded(&self) -> bool {
-            !self.glColorMask_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glColorMask_p, b"glColorMask\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glColorMaskIndexedEXT](http://docs.gl/gl4/glColorMaskIndexedEXT)(index, r, g, b, a)
         /// * alias of: [`glColorMaski`]
@@ -8939,7 +9371,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glColorMaskIndexedEXT",
-                &self.glColorMaskIndexedEXT_p,
+                self.lazy(&self.glColorMaskIndexedEXT_p, b"glColorMaskIndexedEXT\0"),
                 index,
                 r,
                 g,
@@ -8966,7 +9398,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ColorMaskIndexedEXT_is_loaded(&self) -> bool {
-            !self.glColorMaskIndexedEXT_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glColorMaskIndexedEXT_p, b"glColorMaskIndexedEXT\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glColorMaski](http://docs.gl/gl4/glColorMask)(index, r, g, b, a)
         #[cfg_attr(feature = "inline", inline)]
@@ -8990,7 +9425,15 @@ pub mod struct_commands {
                     a
                 );
             }
-            let out = call_atomic_ptr_5arg("glColorMaski", &self.glColorMaski_p, index, r, g, b, a);
+            let out = call_atomic_ptr_5arg(
+                "glColorMaski",
+                self.lazy(&self.glColorMaski_p, b"glColorMaski\0"),
+                index,
+                r,
+                g,
+                b,
+                a,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glColorMaski");
@@ -9007,7 +9450,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ColorMaski_is_loaded(&self) -> bool {
-            !self.glColorMaski_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glColorMaski_p, b"glColorMaski\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCompileShader](http://docs.gl/gl4/glCompileShader)(shader)
         #[cfg_attr(feature = "inline", inline)]
@@ -9017,7 +9463,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.CompileShader({:?});", shader);
             }
-            let out = call_atomic_ptr_1arg("glCompileShader", &self.glCompileShader_p, shader);
+            let out = call_atomic_ptr_1arg(
+                "glCompileShader",
+                self.lazy(&self.glCompileShader_p, b"glCompileShader\0"),
+                shader,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glCompileShader");
@@ -9038,7 +9488,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CompileShader_is_loaded(&self) -> bool {
-            !self.glCompileShader_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCompileShader_p, b"glCompileShader\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCompressedTexImage1D](http://docs.gl/gl4/glCompressedTexImage1D)(target, level, internalformat, width, border, imageSize, data)
         /// * `target` group: TextureTarget
@@ -9074,7 +9527,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_7arg(
                 "glCompressedTexImage1D",
-                &self.glCompressedTexImage1D_p,
+                self.lazy(&self.glCompressedTexImage1D_p, b"glCompressedTexImage1D\0"),
                 target,
                 level,
                 internalformat,
@@ -9103,7 +9556,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CompressedTexImage1D_is_loaded(&self) -> bool {
-            !self.glCompressedTexImage1D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCompressedTexImage1D_p, b"glCompressedTexImage1D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCompressedTexImage2D](http://docs.gl/gl4/glCompressedTexImage2D)(target, level, internalformat, width, height, border, imageSize, data)
         /// * `target` group: TextureTarget
@@ -9131,7 +9587,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_8arg(
                 "glCompressedTexImage2D",
-                &self.glCompressedTexImage2D_p,
+                self.lazy(&self.glCompressedTexImage2D_p, b"glCompressedTexImage2D\0"),
                 target,
                 level,
                 internalformat,
@@ -9161,7 +9617,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CompressedTexImage2D_is_loaded(&self) -> bool {
-            !self.glCompressedTexImage2D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCompressedTexImage2D_p, b"glCompressedTexImage2D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCompressedTexImage3D](http://docs.gl/gl4/glCompressedTexImage3D)(target, level, internalformat, width, height, depth, border, imageSize, data)
         /// * `target` group: TextureTarget
@@ -9190,7 +9649,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_9arg(
                 "glCompressedTexImage3D",
-                &self.glCompressedTexImage3D_p,
+                self.lazy(&self.glCompressedTexImage3D_p, b"glCompressedTexImage3D\0"),
                 target,
                 level,
                 internalformat,
@@ -9221,7 +9680,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CompressedTexImage3D_is_loaded(&self) -> bool {
-            !self.glCompressedTexImage3D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCompressedTexImage3D_p, b"glCompressedTexImage3D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCompressedTexSubImage1D](http://docs.gl/gl4/glCompressedTexSubImage1D)(target, level, xoffset, width, format, imageSize, data)
         /// * `target` group: TextureTarget
@@ -9248,7 +9710,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_7arg(
                 "glCompressedTexSubImage1D",
-                &self.glCompressedTexSubImage1D_p,
+                self.lazy(
+                    &self.glCompressedTexSubImage1D_p,
+                    b"glCompressedTexSubImage1D\0",
+                ),
                 target,
                 level,
                 xoffset,
@@ -9277,7 +9742,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CompressedTexSubImage1D_is_loaded(&self) -> bool {
-            !self.glCompressedTexSubImage1D_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glCompressedTexSubImage1D_p,
+                    b"glCompressedTexSubImage1D\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glCompressedTexSubImage2D](http://docs.gl/gl4/glCompressedTexSubImage2D)(target, level, xoffset, yoffset, width, height, format, imageSize, data)
         /// * `target` group: TextureTarget
@@ -9307,7 +9778,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_9arg(
                 "glCompressedTexSubImage2D",
-                &self.glCompressedTexSubImage2D_p,
+                self.lazy(
+                    &self.glCompressedTexSubImage2D_p,
+                    b"glCompressedTexSubImage2D\0",
+                ),
                 target,
                 level,
                 xoffset,
@@ -9338,7 +9812,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CompressedTexSubImage2D_is_loaded(&self) -> bool {
-            !self.glCompressedTexSubImage2D_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glCompressedTexSubImage2D_p,
+                    b"glCompressedTexSubImage2D\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glCompressedTexSubImage3D](http://docs.gl/gl4/glCompressedTexSubImage3D)(target, level, xoffset, yoffset, zoffset, width, height, depth, format, imageSize, data)
         /// * `target` group: TextureTarget
@@ -9371,7 +9851,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_11arg(
                 "glCompressedTexSubImage3D",
-                &self.glCompressedTexSubImage3D_p,
+                self.lazy(
+                    &self.glCompressedTexSubImage3D_p,
+                    b"glCompressedTexSubImage3D\0",
+                ),
                 target,
                 level,
                 xoffset,
@@ -9404,7 +9887,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CompressedTexSubImage3D_is_loaded(&self) -> bool {
-            !self.glCompressedTexSubImage3D_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glCompressedTexSubImage3D_p,
+                    b"glCompressedTexSubImage3D\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glCompressedTextureSubImage1D](http://docs.gl/gl4/glCompressedTextureSubImage1D)(texture, level, xoffset, width, format, imageSize, data)
         /// * `format` group: PixelFormat
@@ -9426,7 +9915,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_7arg(
                 "glCompressedTextureSubImage1D",
-                &self.glCompressedTextureSubImage1D_p,
+                self.lazy(
+                    &self.glCompressedTextureSubImage1D_p,
+                    b"glCompressedTextureSubImage1D\0",
+                ),
                 texture,
                 level,
                 xoffset,
@@ -9455,7 +9947,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CompressedTextureSubImage1D_is_loaded(&self) -> bool {
-            !self.glCompressedTextureSubImage1D_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glCompressedTextureSubImage1D_p,
+                    b"glCompressedTextureSubImage1D\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glCompressedTextureSubImage2D](http://docs.gl/gl4/glCompressedTextureSubImage2D)(texture, level, xoffset, yoffset, width, height, format, imageSize, data)
         /// * `format` group: PixelFormat
@@ -9479,7 +9977,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_9arg(
                 "glCompressedTextureSubImage2D",
-                &self.glCompressedTextureSubImage2D_p,
+                self.lazy(
+                    &self.glCompressedTextureSubImage2D_p,
+                    b"glCompressedTextureSubImage2D\0",
+                ),
                 texture,
                 level,
                 xoffset,
@@ -9510,7 +10011,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CompressedTextureSubImage2D_is_loaded(&self) -> bool {
-            !self.glCompressedTextureSubImage2D_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glCompressedTextureSubImage2D_p,
+                    b"glCompressedTextureSubImage2D\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glCompressedTextureSubImage3D](http://docs.gl/gl4/glCompressedTextureSubImage3D)(texture, level, xoffset, yoffset, zoffset, width, height, depth, format, imageSize, data)
         /// * `format` group: PixelFormat
@@ -9536,7 +10043,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_11arg(
                 "glCompressedTextureSubImage3D",
-                &self.glCompressedTextureSubImage3D_p,
+                self.lazy(
+                    &self.glCompressedTextureSubImage3D_p,
+                    b"glCompressedTextureSubImage3D\0",
+                ),
                 texture,
                 level,
                 xoffset,
@@ -9569,7 +10079,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CompressedTextureSubImage3D_is_loaded(&self) -> bool {
-            !self.glCompressedTextureSubImage3D_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glCompressedTextureSubImage3D_p,
+                    b"glCompressedTextureSubImage3D\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glCopyBufferSubData](http://docs.gl/gl4/glCopyBufferSubData)(readTarget, writeTarget, readOffset, writeOffset, size)
         /// * `readTarget` group: CopyBufferSubDataTarget
@@ -9600,7 +10116,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glCopyBufferSubData",
-                &self.glCopyBufferSubData_p,
+                self.lazy(&self.glCopyBufferSubData_p, b"glCopyBufferSubData\0"),
                 readTarget,
                 writeTarget,
                 readOffset,
@@ -9627,7 +10143,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CopyBufferSubData_is_loaded(&self) -> bool {
-            !self.glCopyBufferSubData_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCopyBufferSubData_p, b"glCopyBufferSubData\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCopyBufferSubDataNV](http://docs.gl/gl4/glCopyBufferSubDataNV)(readTarget, writeTarget, readOffset, writeOffset, size)
         /// * `readTarget` group: CopyBufferSubDataTarget
@@ -9659,7 +10178,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glCopyBufferSubDataNV",
-                &self.glCopyBufferSubDataNV_p,
+                self.lazy(&self.glCopyBufferSubDataNV_p, b"glCopyBufferSubDataNV\0"),
                 readTarget,
                 writeTarget,
                 readOffset,
@@ -9686,7 +10205,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CopyBufferSubDataNV_is_loaded(&self) -> bool {
-            !self.glCopyBufferSubDataNV_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCopyBufferSubDataNV_p, b"glCopyBufferSubDataNV\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCopyImageSubData](http://docs.gl/gl4/glCopyImageSubData)(srcName, srcTarget, srcLevel, srcX, srcY, srcZ, dstName, dstTarget, dstLevel, dstX, dstY, dstZ, srcWidth, srcHeight, srcDepth)
         /// * `srcTarget` group: CopyImageSubDataTarget
@@ -9717,7 +10239,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_15arg(
                 "glCopyImageSubData",
-                &self.glCopyImageSubData_p,
+                self.lazy(&self.glCopyImageSubData_p, b"glCopyImageSubData\0"),
                 srcName,
                 srcTarget,
                 srcLevel,
@@ -9754,7 +10276,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CopyImageSubData_is_loaded(&self) -> bool {
-            !self.glCopyImageSubData_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCopyImageSubData_p, b"glCopyImageSubData\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCopyNamedBufferSubData](http://docs.gl/gl4/glCopyNamedBufferSubData)(readBuffer, writeBuffer, readOffset, writeOffset, size)
         /// * `size` group: BufferSize
@@ -9781,7 +10306,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glCopyNamedBufferSubData",
-                &self.glCopyNamedBufferSubData_p,
+                self.lazy(
+                    &self.glCopyNamedBufferSubData_p,
+                    b"glCopyNamedBufferSubData\0",
+                ),
                 readBuffer,
                 writeBuffer,
                 readOffset,
@@ -9808,7 +10336,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CopyNamedBufferSubData_is_loaded(&self) -> bool {
-            !self.glCopyNamedBufferSubData_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glCopyNamedBufferSubData_p,
+                    b"glCopyNamedBufferSubData\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glCopyTexImage1D](http://docs.gl/gl4/glCopyTexImage1D)(target, level, internalformat, x, y, width, border)
         /// * `target` group: TextureTarget
@@ -9844,7 +10378,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_7arg(
                 "glCopyTexImage1D",
-                &self.glCopyTexImage1D_p,
+                self.lazy(&self.glCopyTexImage1D_p, b"glCopyTexImage1D\0"),
                 target,
                 level,
                 internalformat,
@@ -9873,7 +10407,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CopyTexImage1D_is_loaded(&self) -> bool {
-            !self.glCopyTexImage1D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCopyTexImage1D_p, b"glCopyTexImage1D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCopyTexImage2D](http://docs.gl/gl4/glCopyTexImage2D)(target, level, internalformat, x, y, width, height, border)
         /// * `target` group: TextureTarget
@@ -9911,7 +10448,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_8arg(
                 "glCopyTexImage2D",
-                &self.glCopyTexImage2D_p,
+                self.lazy(&self.glCopyTexImage2D_p, b"glCopyTexImage2D\0"),
                 target,
                 level,
                 internalformat,
@@ -9941,7 +10478,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CopyTexImage2D_is_loaded(&self) -> bool {
-            !self.glCopyTexImage2D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCopyTexImage2D_p, b"glCopyTexImage2D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCopyTexSubImage1D](http://docs.gl/gl4/glCopyTexSubImage1D)(target, level, xoffset, x, y, width)
         /// * `target` group: TextureTarget
@@ -9974,7 +10514,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glCopyTexSubImage1D",
-                &self.glCopyTexSubImage1D_p,
+                self.lazy(&self.glCopyTexSubImage1D_p, b"glCopyTexSubImage1D\0"),
                 target,
                 level,
                 xoffset,
@@ -10002,7 +10542,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CopyTexSubImage1D_is_loaded(&self) -> bool {
-            !self.glCopyTexSubImage1D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCopyTexSubImage1D_p, b"glCopyTexSubImage1D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCopyTexSubImage2D](http://docs.gl/gl4/glCopyTexSubImage2D)(target, level, xoffset, yoffset, x, y, width, height)
         /// * `target` group: TextureTarget
@@ -10030,7 +10573,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_8arg(
                 "glCopyTexSubImage2D",
-                &self.glCopyTexSubImage2D_p,
+                self.lazy(&self.glCopyTexSubImage2D_p, b"glCopyTexSubImage2D\0"),
                 target,
                 level,
                 xoffset,
@@ -10060,7 +10603,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CopyTexSubImage2D_is_loaded(&self) -> bool {
-            !self.glCopyTexSubImage2D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCopyTexSubImage2D_p, b"glCopyTexSubImage2D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCopyTexSubImage3D](http://docs.gl/gl4/glCopyTexSubImage3D)(target, level, xoffset, yoffset, zoffset, x, y, width, height)
         /// * `target` group: TextureTarget
@@ -10090,7 +10636,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_9arg(
                 "glCopyTexSubImage3D",
-                &self.glCopyTexSubImage3D_p,
+                self.lazy(&self.glCopyTexSubImage3D_p, b"glCopyTexSubImage3D\0"),
                 target,
                 level,
                 xoffset,
@@ -10121,7 +10667,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CopyTexSubImage3D_is_loaded(&self) -> bool {
-            !self.glCopyTexSubImage3D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCopyTexSubImage3D_p, b"glCopyTexSubImage3D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCopyTextureSubImage1D](http://docs.gl/gl4/glCopyTextureSubImage1D)(texture, level, xoffset, x, y, width)
         #[cfg_attr(feature = "inline", inline)]
@@ -10149,7 +10698,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glCopyTextureSubImage1D",
-                &self.glCopyTextureSubImage1D_p,
+                self.lazy(
+                    &self.glCopyTextureSubImage1D_p,
+                    b"glCopyTextureSubImage1D\0",
+                ),
                 texture,
                 level,
                 xoffset,
@@ -10177,7 +10729,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CopyTextureSubImage1D_is_loaded(&self) -> bool {
-            !self.glCopyTextureSubImage1D_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glCopyTextureSubImage1D_p,
+                    b"glCopyTextureSubImage1D\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glCopyTextureSubImage2D](http://docs.gl/gl4/glCopyTextureSubImage2D)(texture, level, xoffset, yoffset, x, y, width, height)
         #[cfg_attr(feature = "inline", inline)]
@@ -10199,7 +10757,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_8arg(
                 "glCopyTextureSubImage2D",
-                &self.glCopyTextureSubImage2D_p,
+                self.lazy(
+                    &self.glCopyTextureSubImage2D_p,
+                    b"glCopyTextureSubImage2D\0",
+                ),
                 texture,
                 level,
                 xoffset,
@@ -10229,7 +10790,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CopyTextureSubImage2D_is_loaded(&self) -> bool {
-            !self.glCopyTextureSubImage2D_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glCopyTextureSubImage2D_p,
+                    b"glCopyTextureSubImage2D\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glCopyTextureSubImage3D](http://docs.gl/gl4/glCopyTextureSubImage3D)(texture, level, xoffset, yoffset, zoffset, x, y, width, height)
         #[cfg_attr(feature = "inline", inline)]
@@ -10252,7 +10819,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_9arg(
                 "glCopyTextureSubImage3D",
-                &self.glCopyTextureSubImage3D_p,
+                self.lazy(
+                    &self.glCopyTextureSubImage3D_p,
+                    b"glCopyTextureSubImage3D\0",
+                ),
                 texture,
                 level,
                 xoffset,
@@ -10283,7 +10853,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CopyTextureSubImage3D_is_loaded(&self) -> bool {
-            !self.glCopyTextureSubImage3D_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glCopyTextureSubImage3D_p,
+                    b"glCopyTextureSubImage3D\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glCreateBuffers](http://docs.gl/gl4/glCreateBuffers)(n, buffers)
         /// * `buffers` len: n
@@ -10294,7 +10870,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.CreateBuffers({:?}, {:p});", n, buffers);
             }
-            let out = call_atomic_ptr_2arg("glCreateBuffers", &self.glCreateBuffers_p, n, buffers);
+            let out = call_atomic_ptr_2arg(
+                "glCreateBuffers",
+                self.lazy(&self.glCreateBuffers_p, b"glCreateBuffers\0"),
+                n,
+                buffers,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glCreateBuffers");
@@ -10315,7 +10896,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CreateBuffers_is_loaded(&self) -> bool {
-            !self.glCreateBuffers_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCreateBuffers_p, b"glCreateBuffers\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCreateFramebuffers](http://docs.gl/gl4/glCreateFramebuffers)(n, framebuffers)
         /// * `framebuffers` len: n
@@ -10332,7 +10916,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glCreateFramebuffers",
-                &self.glCreateFramebuffers_p,
+                self.lazy(&self.glCreateFramebuffers_p, b"glCreateFramebuffers\0"),
                 n,
                 framebuffers,
             );
@@ -10356,7 +10940,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CreateFramebuffers_is_loaded(&self) -> bool {
-            !self.glCreateFramebuffers_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCreateFramebuffers_p, b"glCreateFramebuffers\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCreateProgram](http://docs.gl/gl4/glCreateProgram)()
         #[cfg_attr(feature = "inline", inline)]
@@ -10366,7 +10953,10 @@ pub mod struct_commands {
             {
                 trace!("calling gl.CreateProgram();",);
             }
-            let out = call_atomic_ptr_0arg("glCreateProgram", &self.glCreateProgram_p);
+            let out = call_atomic_ptr_0arg(
+                "glCreateProgram",
+                self.lazy(&self.glCreateProgram_p, b"glCreateProgram\0"),
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glCreateProgram");
@@ -10387,7 +10977,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CreateProgram_is_loaded(&self) -> bool {
-            !self.glCreateProgram_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCreateProgram_p, b"glCreateProgram\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCreateProgramPipelines](http://docs.gl/gl4/glCreateProgramPipelines)(n, pipelines)
         /// * `pipelines` len: n
@@ -10404,7 +10997,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glCreateProgramPipelines",
-                &self.glCreateProgramPipelines_p,
+                self.lazy(
+                    &self.glCreateProgramPipelines_p,
+                    b"glCreateProgramPipelines\0",
+                ),
                 n,
                 pipelines,
             );
@@ -10428,7 +11024,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CreateProgramPipelines_is_loaded(&self) -> bool {
-            !self.glCreateProgramPipelines_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glCreateProgramPipelines_p,
+                    b"glCreateProgramPipelines\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glCreateQueries](http://docs.gl/gl4/glCreateQueries)(target, n, ids)
         /// * `target` group: QueryTarget
@@ -10445,8 +11047,13 @@ pub mod struct_commands {
                     ids
                 );
             }
-            let out =
-                call_atomic_ptr_3arg("glCreateQueries", &self.glCreateQueries_p, target, n, ids);
+            let out = call_atomic_ptr_3arg(
+                "glCreateQueries",
+                self.lazy(&self.glCreateQueries_p, b"glCreateQueries\0"),
+                target,
+                n,
+                ids,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glCreateQueries");
@@ -10467,7 +11074,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CreateQueries_is_loaded(&self) -> bool {
-            !self.glCreateQueries_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCreateQueries_p, b"glCreateQueries\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCreateRenderbuffers](http://docs.gl/gl4/glCreateRenderbuffers)(n, renderbuffers)
         /// * `renderbuffers` len: n
@@ -10484,7 +11094,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glCreateRenderbuffers",
-                &self.glCreateRenderbuffers_p,
+                self.lazy(&self.glCreateRenderbuffers_p, b"glCreateRenderbuffers\0"),
                 n,
                 renderbuffers,
             );
@@ -10508,7 +11118,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CreateRenderbuffers_is_loaded(&self) -> bool {
-            !self.glCreateRenderbuffers_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCreateRenderbuffers_p, b"glCreateRenderbuffers\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCreateSamplers](http://docs.gl/gl4/glCreateSamplers)(n, samplers)
         /// * `samplers` len: n
@@ -10519,8 +11132,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.CreateSamplers({:?}, {:p});", n, samplers);
             }
-            let out =
-                call_atomic_ptr_2arg("glCreateSamplers", &self.glCreateSamplers_p, n, samplers);
+            let out = call_atomic_ptr_2arg(
+                "glCreateSamplers",
+                self.lazy(&self.glCreateSamplers_p, b"glCreateSamplers\0"),
+                n,
+                samplers,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glCreateSamplers");
@@ -10541,7 +11158,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CreateSamplers_is_loaded(&self) -> bool {
-            !self.glCreateSamplers_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCreateSamplers_p, b"glCreateSamplers\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCreateShader](http://docs.gl/gl4/glCreateShader)(type_)
         /// * `type_` group: ShaderType
@@ -10552,7 +11172,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.CreateShader({:#X});", type_);
             }
-            let out = call_atomic_ptr_1arg("glCreateShader", &self.glCreateShader_p, type_);
+            let out = call_atomic_ptr_1arg(
+                "glCreateShader",
+                self.lazy(&self.glCreateShader_p, b"glCreateShader\0"),
+                type_,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glCreateShader");
@@ -10573,7 +11197,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CreateShader_is_loaded(&self) -> bool {
-            !self.glCreateShader_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCreateShader_p, b"glCreateShader\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCreateShaderProgramv](http://docs.gl/gl4/glCreateShaderProgramv)(type_, count, strings)
         /// * `type_` group: ShaderType
@@ -10597,7 +11224,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glCreateShaderProgramv",
-                &self.glCreateShaderProgramv_p,
+                self.lazy(&self.glCreateShaderProgramv_p, b"glCreateShaderProgramv\0"),
                 type_,
                 count,
                 strings,
@@ -10622,7 +11249,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CreateShaderProgramv_is_loaded(&self) -> bool {
-            !self.glCreateShaderProgramv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCreateShaderProgramv_p, b"glCreateShaderProgramv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCreateTextures](http://docs.gl/gl4/glCreateTextures)(target, n, textures)
         /// * `target` group: TextureTarget
@@ -10641,7 +11271,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glCreateTextures",
-                &self.glCreateTextures_p,
+                self.lazy(&self.glCreateTextures_p, b"glCreateTextures\0"),
                 target,
                 n,
                 textures,
@@ -10666,7 +11296,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CreateTextures_is_loaded(&self) -> bool {
-            !self.glCreateTextures_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCreateTextures_p, b"glCreateTextures\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCreateTransformFeedbacks](http://docs.gl/gl4/glCreateTransformFeedbacks)(n, ids)
         /// * `ids` len: n
@@ -10679,7 +11312,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glCreateTransformFeedbacks",
-                &self.glCreateTransformFeedbacks_p,
+                self.lazy(
+                    &self.glCreateTransformFeedbacks_p,
+                    b"glCreateTransformFeedbacks\0",
+                ),
                 n,
                 ids,
             );
@@ -10703,7 +11339,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CreateTransformFeedbacks_is_loaded(&self) -> bool {
-            !self.glCreateTransformFeedbacks_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glCreateTransformFeedbacks_p,
+                    b"glCreateTransformFeedbacks\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glCreateVertexArrays](http://docs.gl/gl4/glCreateVertexArrays)(n, arrays)
         /// * `arrays` len: n
@@ -10716,7 +11358,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glCreateVertexArrays",
-                &self.glCreateVertexArrays_p,
+                self.lazy(&self.glCreateVertexArrays_p, b"glCreateVertexArrays\0"),
                 n,
                 arrays,
             );
@@ -10740,7 +11382,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CreateVertexArrays_is_loaded(&self) -> bool {
-            !self.glCreateVertexArrays_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCreateVertexArrays_p, b"glCreateVertexArrays\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glCullFace](http://docs.gl/gl4/glCullFace)(mode)
         /// * `mode` group: CullFaceMode
@@ -10751,7 +11396,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.CullFace({:#X});", mode);
             }
-            let out = call_atomic_ptr_1arg("glCullFace", &self.glCullFace_p, mode);
+            let out = call_atomic_ptr_1arg(
+                "glCullFace",
+                self.lazy(&self.glCullFace_p, b"glCullFace\0"),
+                mode,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glCullFace");
@@ -10768,7 +11417,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn CullFace_is_loaded(&self) -> bool {
-            !self.glCullFace_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glCullFace_p, b"glCullFace\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDebugMessageCallback](http://docs.gl/gl4/glDebugMessageCallback)(callback, userParam)
         #[cfg_attr(feature = "inline", inline)]
@@ -10784,7 +11436,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glDebugMessageCallback",
-                &self.glDebugMessageCallback_p,
+                self.lazy(&self.glDebugMessageCallback_p, b"glDebugMessageCallback\0"),
                 callback,
                 userParam,
             );
@@ -10808,7 +11460,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DebugMessageCallback_is_loaded(&self) -> bool {
-            !self.glDebugMessageCallback_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDebugMessageCallback_p, b"glDebugMessageCallback\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDebugMessageCallbackARB](http://docs.gl/gl4/glDebugMessageCallbackARB)(callback, userParam)
         /// * `userParam` len: COMPSIZE(callback)
@@ -10830,7 +11485,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glDebugMessageCallbackARB",
-                &self.glDebugMessageCallbackARB_p,
+                self.lazy(
+                    &self.glDebugMessageCallbackARB_p,
+                    b"glDebugMessageCallbackARB\0",
+                ),
                 callback,
                 userParam,
             );
@@ -10854,7 +11512,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DebugMessageCallbackARB_is_loaded(&self) -> bool {
-            !self.glDebugMessageCallbackARB_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glDebugMessageCallbackARB_p,
+                    b"glDebugMessageCallbackARB\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glDebugMessageCallbackKHR](http://docs.gl/gl4/glDebugMessageCallbackKHR)(callback, userParam)
         /// * alias of: [`glDebugMessageCallback`]
@@ -10875,7 +11539,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glDebugMessageCallbackKHR",
-                &self.glDebugMessageCallbackKHR_p,
+                self.lazy(
+                    &self.glDebugMessageCallbackKHR_p,
+                    b"glDebugMessageCallbackKHR\0",
+                ),
                 callback,
                 userParam,
             );
@@ -10899,7 +11566,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DebugMessageCallbackKHR_is_loaded(&self) -> bool {
-            !self.glDebugMessageCallbackKHR_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glDebugMessageCallbackKHR_p,
+                    b"glDebugMessageCallbackKHR\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glDebugMessageControl](http://docs.gl/gl4/glDebugMessageControl)(source, type_, severity, count, ids, enabled)
         /// * `source` group: DebugSource
@@ -10931,7 +11604,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glDebugMessageControl",
-                &self.glDebugMessageControl_p,
+                self.lazy(&self.glDebugMessageControl_p, b"glDebugMessageControl\0"),
                 source,
                 type_,
                 severity,
@@ -10959,7 +11632,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DebugMessageControl_is_loaded(&self) -> bool {
-            !self.glDebugMessageControl_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDebugMessageControl_p, b"glDebugMessageControl\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDebugMessageControlARB](http://docs.gl/gl4/glDebugMessageControlARB)(source, type_, severity, count, ids, enabled)
         /// * `source` group: DebugSource
@@ -10992,7 +11668,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glDebugMessageControlARB",
-                &self.glDebugMessageControlARB_p,
+                self.lazy(
+                    &self.glDebugMessageControlARB_p,
+                    b"glDebugMessageControlARB\0",
+                ),
                 source,
                 type_,
                 severity,
@@ -11020,7 +11699,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DebugMessageControlARB_is_loaded(&self) -> bool {
-            !self.glDebugMessageControlARB_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glDebugMessageControlARB_p,
+                    b"glDebugMessageControlARB\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glDebugMessageControlKHR](http://docs.gl/gl4/glDebugMessageControlKHR)(source, type_, severity, count, ids, enabled)
         /// * `source` group: DebugSource
@@ -11052,7 +11737,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glDebugMessageControlKHR",
-                &self.glDebugMessageControlKHR_p,
+                self.lazy(
+                    &self.glDebugMessageControlKHR_p,
+                    b"glDebugMessageControlKHR\0",
+                ),
                 source,
                 type_,
                 severity,
@@ -11080,7 +11768,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DebugMessageControlKHR_is_loaded(&self) -> bool {
-            !self.glDebugMessageControlKHR_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glDebugMessageControlKHR_p,
+                    b"glDebugMessageControlKHR\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glDebugMessageInsert](http://docs.gl/gl4/glDebugMessageInsert)(source, type_, id, severity, length, buf)
         /// * `source` group: DebugSource
@@ -11112,7 +11806,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glDebugMessageInsert",
-                &self.glDebugMessageInsert_p,
+                self.lazy(&self.glDebugMessageInsert_p, b"glDebugMessageInsert\0"),
                 source,
                 type_,
                 id,
@@ -11140,7 +11834,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DebugMessageInsert_is_loaded(&self) -> bool {
-            !self.glDebugMessageInsert_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDebugMessageInsert_p, b"glDebugMessageInsert\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDebugMessageInsertARB](http://docs.gl/gl4/glDebugMessageInsertARB)(source, type_, id, severity, length, buf)
         /// * `source` group: DebugSource
@@ -11173,7 +11870,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glDebugMessageInsertARB",
-                &self.glDebugMessageInsertARB_p,
+                self.lazy(
+                    &self.glDebugMessageInsertARB_p,
+                    b"glDebugMessageInsertARB\0",
+                ),
                 source,
                 type_,
                 id,
@@ -11201,7 +11901,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DebugMessageInsertARB_is_loaded(&self) -> bool {
-            !self.glDebugMessageInsertARB_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glDebugMessageInsertARB_p,
+                    b"glDebugMessageInsertARB\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glDebugMessageInsertKHR](http://docs.gl/gl4/glDebugMessageInsertKHR)(source, type_, id, severity, length, buf)
         /// * `source` group: DebugSource
@@ -11233,7 +11939,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glDebugMessageInsertKHR",
-                &self.glDebugMessageInsertKHR_p,
+                self.lazy(
+                    &self.glDebugMessageInsertKHR_p,
+                    b"glDebugMessageInsertKHR\0",
+                ),
                 source,
                 type_,
                 id,
@@ -11261,7 +11970,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DebugMessageInsertKHR_is_loaded(&self) -> bool {
-            !self.glDebugMessageInsertKHR_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glDebugMessageInsertKHR_p,
+                    b"glDebugMessageInsertKHR\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glDeleteBuffers](http://docs.gl/gl4/glDeleteBuffers)(n, buffers)
         /// * `buffers` len: n
@@ -11272,7 +11987,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.DeleteBuffers({:?}, {:p});", n, buffers);
             }
-            let out = call_atomic_ptr_2arg("glDeleteBuffers", &self.glDeleteBuffers_p, n, buffers);
+            let out = call_atomic_ptr_2arg(
+                "glDeleteBuffers",
+                self.lazy(&self.glDeleteBuffers_p, b"glDeleteBuffers\0"),
+                n,
+                buffers,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glDeleteBuffers");
@@ -11293,7 +12013,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DeleteBuffers_is_loaded(&self) -> bool {
-            !self.glDeleteBuffers_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDeleteBuffers_p, b"glDeleteBuffers\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDeleteFramebuffers](http://docs.gl/gl4/glDeleteFramebuffers)(n, framebuffers)
         /// * `framebuffers` len: n
@@ -11310,7 +12033,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glDeleteFramebuffers",
-                &self.glDeleteFramebuffers_p,
+                self.lazy(&self.glDeleteFramebuffers_p, b"glDeleteFramebuffers\0"),
                 n,
                 framebuffers,
             );
@@ -11334,7 +12057,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DeleteFramebuffers_is_loaded(&self) -> bool {
-            !self.glDeleteFramebuffers_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDeleteFramebuffers_p, b"glDeleteFramebuffers\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDeleteProgram](http://docs.gl/gl4/glDeleteProgram)(program)
         #[cfg_attr(feature = "inline", inline)]
@@ -11344,7 +12070,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.DeleteProgram({:?});", program);
             }
-            let out = call_atomic_ptr_1arg("glDeleteProgram", &self.glDeleteProgram_p, program);
+            let out = call_atomic_ptr_1arg(
+                "glDeleteProgram",
+                self.lazy(&self.glDeleteProgram_p, b"glDeleteProgram\0"),
+                program,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glDeleteProgram");
@@ -11365,7 +12095,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DeleteProgram_is_loaded(&self) -> bool {
-            !self.glDeleteProgram_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDeleteProgram_p, b"glDeleteProgram\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDeleteProgramPipelines](http://docs.gl/gl4/glDeleteProgramPipelines)(n, pipelines)
         /// * `pipelines` len: n
@@ -11382,7 +12115,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glDeleteProgramPipelines",
-                &self.glDeleteProgramPipelines_p,
+                self.lazy(
+                    &self.glDeleteProgramPipelines_p,
+                    b"glDeleteProgramPipelines\0",
+                ),
                 n,
                 pipelines,
             );
@@ -11406,7 +12142,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DeleteProgramPipelines_is_loaded(&self) -> bool {
-            !self.glDeleteProgramPipelines_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glDeleteProgramPipelines_p,
+                    b"glDeleteProgramPipelines\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glDeleteQueries](http://docs.gl/gl4/glDeleteQueries)(n, ids)
         /// * `ids` len: n
@@ -11417,7 +12159,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.DeleteQueries({:?}, {:p});", n, ids);
             }
-            let out = call_atomic_ptr_2arg("glDeleteQueries", &self.glDeleteQueries_p, n, ids);
+            let out = call_atomic_ptr_2arg(
+                "glDeleteQueries",
+                self.lazy(&self.glDeleteQueries_p, b"glDeleteQueries\0"),
+                n,
+                ids,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glDeleteQueries");
@@ -11438,7 +12185,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DeleteQueries_is_loaded(&self) -> bool {
-            !self.glDeleteQueries_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDeleteQueries_p, b"glDeleteQueries\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDeleteQueriesEXT](http://docs.gl/gl4/glDeleteQueriesEXT)(n, ids)
         /// * `ids` len: n
@@ -11449,8 +12199,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.DeleteQueriesEXT({:?}, {:p});", n, ids);
             }
-            let out =
-                call_atomic_ptr_2arg("glDeleteQueriesEXT", &self.glDeleteQueriesEXT_p, n, ids);
+            let out = call_atomic_ptr_2arg(
+                "glDeleteQueriesEXT",
+                self.lazy(&self.glDeleteQueriesEXT_p, b"glDeleteQueriesEXT\0"),
+                n,
+                ids,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glDeleteQueriesEXT");
@@ -11471,7 +12225,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DeleteQueriesEXT_is_loaded(&self) -> bool {
-            !self.glDeleteQueriesEXT_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDeleteQueriesEXT_p, b"glDeleteQueriesEXT\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDeleteRenderbuffers](http://docs.gl/gl4/glDeleteRenderbuffers)(n, renderbuffers)
         /// * `renderbuffers` len: n
@@ -11488,7 +12245,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glDeleteRenderbuffers",
-                &self.glDeleteRenderbuffers_p,
+                self.lazy(&self.glDeleteRenderbuffers_p, b"glDeleteRenderbuffers\0"),
                 n,
                 renderbuffers,
             );
@@ -11512,7 +12269,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DeleteRenderbuffers_is_loaded(&self) -> bool {
-            !self.glDeleteRenderbuffers_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDeleteRenderbuffers_p, b"glDeleteRenderbuffers\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDeleteSamplers](http://docs.gl/gl4/glDeleteSamplers)(count, samplers)
         /// * `samplers` len: count
@@ -11525,7 +12285,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glDeleteSamplers",
-                &self.glDeleteSamplers_p,
+                self.lazy(&self.glDeleteSamplers_p, b"glDeleteSamplers\0"),
                 count,
                 samplers,
             );
@@ -11549,7 +12309,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DeleteSamplers_is_loaded(&self) -> bool {
-            !self.glDeleteSamplers_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDeleteSamplers_p, b"glDeleteSamplers\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDeleteShader](http://docs.gl/gl4/glDeleteShader)(shader)
         #[cfg_attr(feature = "inline", inline)]
@@ -11559,7 +12322,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.DeleteShader({:?});", shader);
             }
-            let out = call_atomic_ptr_1arg("glDeleteShader", &self.glDeleteShader_p, shader);
+            let out = call_atomic_ptr_1arg(
+                "glDeleteShader",
+                self.lazy(&self.glDeleteShader_p, b"glDeleteShader\0"),
+                shader,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glDeleteShader");
@@ -11580,7 +12347,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DeleteShader_is_loaded(&self) -> bool {
-            !self.glDeleteShader_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDeleteShader_p, b"glDeleteShader\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDeleteSync](http://docs.gl/gl4/glDeleteSync)(sync)
         /// * `sync` group: sync
@@ -11591,7 +12361,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.DeleteSync({:p});", sync);
             }
-            let out = call_atomic_ptr_1arg("glDeleteSync", &self.glDeleteSync_p, sync);
+            let out = call_atomic_ptr_1arg(
+                "glDeleteSync",
+                self.lazy(&self.glDeleteSync_p, b"glDeleteSync\0"),
+                sync,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glDeleteSync");
@@ -11608,7 +12382,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DeleteSync_is_loaded(&self) -> bool {
-            !self.glDeleteSync_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDeleteSync_p, b"glDeleteSync\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDeleteTextures](http://docs.gl/gl4/glDeleteTextures)(n, textures)
         /// * `textures` group: Texture
@@ -11620,8 +12397,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.DeleteTextures({:?}, {:p});", n, textures);
             }
-            let out =
-                call_atomic_ptr_2arg("glDeleteTextures", &self.glDeleteTextures_p, n, textures);
+            let out = call_atomic_ptr_2arg(
+                "glDeleteTextures",
+                self.lazy(&self.glDeleteTextures_p, b"glDeleteTextures\0"),
+                n,
+                textures,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glDeleteTextures");
@@ -11642,7 +12423,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DeleteTextures_is_loaded(&self) -> bool {
-            !self.glDeleteTextures_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDeleteTextures_p, b"glDeleteTextures\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDeleteTransformFeedbacks](http://docs.gl/gl4/glDeleteTransformFeedbacks)(n, ids)
         /// * `ids` len: n
@@ -11655,7 +12439,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glDeleteTransformFeedbacks",
-                &self.glDeleteTransformFeedbacks_p,
+                self.lazy(
+                    &self.glDeleteTransformFeedbacks_p,
+                    b"glDeleteTransformFeedbacks\0",
+                ),
                 n,
                 ids,
             );
@@ -11679,7 +12466,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DeleteTransformFeedbacks_is_loaded(&self) -> bool {
-            !self.glDeleteTransformFeedbacks_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glDeleteTransformFeedbacks_p,
+                    b"glDeleteTransformFeedbacks\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glDeleteVertexArrays](http://docs.gl/gl4/glDeleteVertexArrays)(n, arrays)
         /// * `arrays` len: n
@@ -11692,7 +12485,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glDeleteVertexArrays",
-                &self.glDeleteVertexArrays_p,
+                self.lazy(&self.glDeleteVertexArrays_p, b"glDeleteVertexArrays\0"),
                 n,
                 arrays,
             );
@@ -11716,7 +12509,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DeleteVertexArrays_is_loaded(&self) -> bool {
-            !self.glDeleteVertexArrays_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDeleteVertexArrays_p, b"glDeleteVertexArrays\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDeleteVertexArraysAPPLE](http://docs.gl/gl4/glDeleteVertexArraysAPPLE)(n, arrays)
         /// * `arrays` len: n
@@ -11730,7 +12526,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glDeleteVertexArraysAPPLE",
-                &self.glDeleteVertexArraysAPPLE_p,
+                self.lazy(
+                    &self.glDeleteVertexArraysAPPLE_p,
+                    b"glDeleteVertexArraysAPPLE\0",
+                ),
                 n,
                 arrays,
             );
@@ -11754,7 +12553,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DeleteVertexArraysAPPLE_is_loaded(&self) -> bool {
-            !self.glDeleteVertexArraysAPPLE_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glDeleteVertexArraysAPPLE_p,
+                    b"glDeleteVertexArraysAPPLE\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glDeleteVertexArraysOES](http://docs.gl/gl4/glDeleteVertexArraysOES)(n, arrays)
         /// * `arrays` len: n
@@ -11768,7 +12573,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glDeleteVertexArraysOES",
-                &self.glDeleteVertexArraysOES_p,
+                self.lazy(
+                    &self.glDeleteVertexArraysOES_p,
+                    b"glDeleteVertexArraysOES\0",
+                ),
                 n,
                 arrays,
             );
@@ -11792,7 +12600,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DeleteVertexArraysOES_is_loaded(&self) -> bool {
-            !self.glDeleteVertexArraysOES_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glDeleteVertexArraysOES_p,
+                    b"glDeleteVertexArraysOES\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glDepthFunc](http://docs.gl/gl4/glDepthFunc)(func)
         /// * `func` group: DepthFunction
@@ -11803,7 +12617,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.DepthFunc({:#X});", func);
             }
-            let out = call_atomic_ptr_1arg("glDepthFunc", &self.glDepthFunc_p, func);
+            let out = call_atomic_ptr_1arg(
+                "glDepthFunc",
+                self.lazy(&self.glDepthFunc_p, b"glDepthFunc\0"),
+                func,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glDepthFunc");
@@ -11820,7 +12638,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DepthFunc_is_loaded(&self) -> bool {
-            !self.glDepthFunc_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDepthFunc_p, b"glDepthFunc\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDepthMask](http://docs.gl/gl4/glDepthMask)(flag)
         #[cfg_attr(feature = "inline", inline)]
@@ -11830,7 +12651,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.DepthMask({:?});", flag);
             }
-            let out = call_atomic_ptr_1arg("glDepthMask", &self.glDepthMask_p, flag);
+            let out = call_atomic_ptr_1arg(
+                "glDepthMask",
+                self.lazy(&self.glDepthMask_p, b"glDepthMask\0"),
+                flag,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glDepthMask");
@@ -11847,7 +12672,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DepthMask_is_loaded(&self) -> bool {
-            !self.glDepthMask_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDepthMask_p, b"glDepthMask\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDepthRange](http://docs.gl/gl4/glDepthRange)(n, f)
         #[cfg_attr(feature = "inline", inline)]
@@ -11857,7 +12685,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.DepthRange({:?}, {:?});", n, f);
             }
-            let out = call_atomic_ptr_2arg("glDepthRange", &self.glDepthRange_p, n, f);
+            let out = call_atomic_ptr_2arg(
+                "glDepthRange",
+                self.lazy(&self.glDepthRange_p, b"glDepthRange\0"),
+                n,
+                f,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glDepthRange");
@@ -11874,7 +12707,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DepthRange_is_loaded(&self) -> bool {
-            !self.glDepthRange_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDepthRange_p, b"glDepthRange\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDepthRangeArrayv](http://docs.gl/gl4/glDepthRangeArrayv)(first, count, v)
         /// * `v` len: COMPSIZE(count)
@@ -11892,7 +12728,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glDepthRangeArrayv",
-                &self.glDepthRangeArrayv_p,
+                self.lazy(&self.glDepthRangeArrayv_p, b"glDepthRangeArrayv\0"),
                 first,
                 count,
                 v,
@@ -11917,7 +12753,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DepthRangeArrayv_is_loaded(&self) -> bool {
-            !self.glDepthRangeArrayv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDepthRangeArrayv_p, b"glDepthRangeArrayv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDepthRangeIndexed](http://docs.gl/gl4/glDepthRangeIndexed)(index, n, f)
         #[cfg_attr(feature = "inline", inline)]
@@ -11934,7 +12773,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glDepthRangeIndexed",
-                &self.glDepthRangeIndexed_p,
+                self.lazy(&self.glDepthRangeIndexed_p, b"glDepthRangeIndexed\0"),
                 index,
                 n,
                 f,
@@ -11959,7 +12798,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DepthRangeIndexed_is_loaded(&self) -> bool {
-            !self.glDepthRangeIndexed_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDepthRangeIndexed_p, b"glDepthRangeIndexed\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDepthRangef](http://docs.gl/gl4/glDepthRange)(n, f)
         #[cfg_attr(feature = "inline", inline)]
@@ -11969,7 +12811,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.DepthRangef({:?}, {:?});", n, f);
             }
-            let out = call_atomic_ptr_2arg("glDepthRangef", &self.glDepthRangef_p, n, f);
+            let out = call_atomic_ptr_2arg(
+                "glDepthRangef",
+                self.lazy(&self.glDepthRangef_p, b"glDepthRangef\0"),
+                n,
+                f,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glDepthRangef");
@@ -11986,7 +12833,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DepthRangef_is_loaded(&self) -> bool {
-            !self.glDepthRangef_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDepthRangef_p, b"glDepthRangef\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDetachShader](http://docs.gl/gl4/glDetachShader)(program, shader)
         #[cfg_attr(feature = "inline", inline)]
@@ -11996,8 +12846,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.DetachShader({:?}, {:?});", program, shader);
             }
-            let out =
-                call_atomic_ptr_2arg("glDetachShader", &self.glDetachShader_p, program, shader);
+            let out = call_atomic_ptr_2arg(
+                "glDetachShader",
+                self.lazy(&self.glDetachShader_p, b"glDetachShader\0"),
+                program,
+                shader,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glDetachShader");
@@ -12018,7 +12872,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DetachShader_is_loaded(&self) -> bool {
-            !self.glDetachShader_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDetachShader_p, b"glDetachShader\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDisable](http://docs.gl/gl4/glDisable)(cap)
         /// * `cap` group: EnableCap
@@ -12029,7 +12886,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.Disable({:#X});", cap);
             }
-            let out = call_atomic_ptr_1arg("glDisable", &self.glDisable_p, cap);
+            let out = call_atomic_ptr_1arg(
+                "glDisable",
+                self.lazy(&self.glDisable_p, b"glDisable\0"),
+                cap,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glDisable");
@@ -12046,7 +12907,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Disable_is_loaded(&self) -> bool {
-            !self.glDisable_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDisable_p, b"glDisable\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDisableIndexedEXT](http://docs.gl/gl4/glDisableIndexedEXT)(target, index)
         /// * `target` group: EnableCap
@@ -12060,7 +12924,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glDisableIndexedEXT",
-                &self.glDisableIndexedEXT_p,
+                self.lazy(&self.glDisableIndexedEXT_p, b"glDisableIndexedEXT\0"),
                 target,
                 index,
             );
@@ -12084,7 +12948,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DisableIndexedEXT_is_loaded(&self) -> bool {
-            !self.glDisableIndexedEXT_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDisableIndexedEXT_p, b"glDisableIndexedEXT\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDisableVertexArrayAttrib](http://docs.gl/gl4/glDisableVertexArrayAttrib)(vaobj, index)
         #[cfg_attr(feature = "inline", inline)]
@@ -12100,7 +12967,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glDisableVertexArrayAttrib",
-                &self.glDisableVertexArrayAttrib_p,
+                self.lazy(
+                    &self.glDisableVertexArrayAttrib_p,
+                    b"glDisableVertexArrayAttrib\0",
+                ),
                 vaobj,
                 index,
             );
@@ -12124,7 +12994,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DisableVertexArrayAttrib_is_loaded(&self) -> bool {
-            !self.glDisableVertexArrayAttrib_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glDisableVertexArrayAttrib_p,
+                    b"glDisableVertexArrayAttrib\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glDisableVertexAttribArray](http://docs.gl/gl4/glDisableVertexAttribArray)(index)
         #[cfg_attr(feature = "inline", inline)]
@@ -12136,7 +13012,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_1arg(
                 "glDisableVertexAttribArray",
-                &self.glDisableVertexAttribArray_p,
+                self.lazy(
+                    &self.glDisableVertexAttribArray_p,
+                    b"glDisableVertexAttribArray\0",
+                ),
                 index,
             );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
@@ -12159,7 +13038,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DisableVertexAttribArray_is_loaded(&self) -> bool {
-            !self.glDisableVertexAttribArray_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glDisableVertexAttribArray_p,
+                    b"glDisableVertexAttribArray\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glDisablei](http://docs.gl/gl4/glDisable)(target, index)
         /// * `target` group: EnableCap
@@ -12170,7 +13055,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.Disablei({:#X}, {:?});", target, index);
             }
-            let out = call_atomic_ptr_2arg("glDisablei", &self.glDisablei_p, target, index);
+            let out = call_atomic_ptr_2arg(
+                "glDisablei",
+                self.lazy(&self.glDisablei_p, b"glDisablei\0"),
+                target,
+                index,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glDisablei");
@@ -12187,7 +13077,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Disablei_is_loaded(&self) -> bool {
-            !self.glDisablei_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDisablei_p, b"glDisablei\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDispatchCompute](http://docs.gl/gl4/glDispatchCompute)(num_groups_x, num_groups_y, num_groups_z)
         #[cfg_attr(feature = "inline", inline)]
@@ -12209,7 +13102,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glDispatchCompute",
-                &self.glDispatchCompute_p,
+                self.lazy(&self.glDispatchCompute_p, b"glDispatchCompute\0"),
                 num_groups_x,
                 num_groups_y,
                 num_groups_z,
@@ -12234,7 +13127,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DispatchCompute_is_loaded(&self) -> bool {
-            !self.glDispatchCompute_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDispatchCompute_p, b"glDispatchCompute\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDispatchComputeIndirect](http://docs.gl/gl4/glDispatchComputeIndirect)(indirect)
         /// * `indirect` group: BufferOffset
@@ -12247,7 +13143,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_1arg(
                 "glDispatchComputeIndirect",
-                &self.glDispatchComputeIndirect_p,
+                self.lazy(
+                    &self.glDispatchComputeIndirect_p,
+                    b"glDispatchComputeIndirect\0",
+                ),
                 indirect,
             );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
@@ -12270,7 +13169,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DispatchComputeIndirect_is_loaded(&self) -> bool {
-            !self.glDispatchComputeIndirect_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glDispatchComputeIndirect_p,
+                    b"glDispatchComputeIndirect\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glDrawArrays](http://docs.gl/gl4/glDrawArrays)(mode, first, count)
         /// * `mode` group: PrimitiveType
@@ -12286,8 +13191,13 @@ pub mod struct_commands {
                     count
                 );
             }
-            let out =
-                call_atomic_ptr_3arg("glDrawArrays", &self.glDrawArrays_p, mode, first, count);
+            let out = call_atomic_ptr_3arg(
+                "glDrawArrays",
+                self.lazy(&self.glDrawArrays_p, b"glDrawArrays\0"),
+                mode,
+                first,
+                count,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glDrawArrays");
@@ -12304,7 +13214,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DrawArrays_is_loaded(&self) -> bool {
-            !self.glDrawArrays_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDrawArrays_p, b"glDrawArrays\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDrawArraysIndirect](http://docs.gl/gl4/glDrawArraysIndirect)(mode, indirect)
         /// * `mode` group: PrimitiveType
@@ -12321,7 +13234,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glDrawArraysIndirect",
-                &self.glDrawArraysIndirect_p,
+                self.lazy(&self.glDrawArraysIndirect_p, b"glDrawArraysIndirect\0"),
                 mode,
                 indirect,
             );
@@ -12345,7 +13258,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DrawArraysIndirect_is_loaded(&self) -> bool {
-            !self.glDrawArraysIndirect_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDrawArraysIndirect_p, b"glDrawArraysIndirect\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDrawArraysInstanced](http://docs.gl/gl4/glDrawArraysInstanced)(mode, first, count, instancecount)
         /// * `mode` group: PrimitiveType
@@ -12370,7 +13286,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glDrawArraysInstanced",
-                &self.glDrawArraysInstanced_p,
+                self.lazy(&self.glDrawArraysInstanced_p, b"glDrawArraysInstanced\0"),
                 mode,
                 first,
                 count,
@@ -12396,7 +13312,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DrawArraysInstanced_is_loaded(&self) -> bool {
-            !self.glDrawArraysInstanced_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDrawArraysInstanced_p, b"glDrawArraysInstanced\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDrawArraysInstancedARB](http://docs.gl/gl4/glDrawArraysInstancedARB)(mode, first, count, primcount)
         /// * `mode` group: PrimitiveType
@@ -12422,7 +13341,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glDrawArraysInstancedARB",
-                &self.glDrawArraysInstancedARB_p,
+                self.lazy(
+                    &self.glDrawArraysInstancedARB_p,
+                    b"glDrawArraysInstancedARB\0",
+                ),
                 mode,
                 first,
                 count,
@@ -12448,7 +13370,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DrawArraysInstancedARB_is_loaded(&self) -> bool {
-            !self.glDrawArraysInstancedARB_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glDrawArraysInstancedARB_p,
+                    b"glDrawArraysInstancedARB\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glDrawArraysInstancedBaseInstance](http://docs.gl/gl4/glDrawArraysInstancedBaseInstance)(mode, first, count, instancecount, baseinstance)
         /// * `mode` group: PrimitiveType
@@ -12475,7 +13403,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glDrawArraysInstancedBaseInstance",
-                &self.glDrawArraysInstancedBaseInstance_p,
+                self.lazy(
+                    &self.glDrawArraysInstancedBaseInstance_p,
+                    b"glDrawArraysInstancedBaseInstance\0",
+                ),
                 mode,
                 first,
                 count,
@@ -12503,7 +13434,10 @@ pub mod struct_commands {
         #[doc(hidden)]
         pub fn DrawArraysInstancedBaseInstance_is_loaded(&self) -> bool {
             !self
-                .glDrawArraysInstancedBaseInstance_p
+                .lazy(
+                    &self.glDrawArraysInstancedBaseInstance_p,
+                    b"glDrawArraysInstancedBaseInstance\0",
+                )
                 .load(RELAX)
                 .is_null()
         }
@@ -12516,7 +13450,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.DrawBuffer({:#X});", buf);
             }
-            let out = call_atomic_ptr_1arg("glDrawBuffer", &self.glDrawBuffer_p, buf);
+            let out = call_atomic_ptr_1arg(
+                "glDrawBuffer",
+                self.lazy(&self.glDrawBuffer_p, b"glDrawBuffer\0"),
+                buf,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glDrawBuffer");
@@ -12533,7 +13471,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DrawBuffer_is_loaded(&self) -> bool {
-            !self.glDrawBuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDrawBuffer_p, b"glDrawBuffer\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDrawBuffers](http://docs.gl/gl4/glDrawBuffers)(n, bufs)
         /// * `bufs` group: DrawBufferMode
@@ -12545,7 +13486,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.DrawBuffers({:?}, {:p});", n, bufs);
             }
-            let out = call_atomic_ptr_2arg("glDrawBuffers", &self.glDrawBuffers_p, n, bufs);
+            let out = call_atomic_ptr_2arg(
+                "glDrawBuffers",
+                self.lazy(&self.glDrawBuffers_p, b"glDrawBuffers\0"),
+                n,
+                bufs,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glDrawBuffers");
@@ -12562,7 +13508,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DrawBuffers_is_loaded(&self) -> bool {
-            !self.glDrawBuffers_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDrawBuffers_p, b"glDrawBuffers\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDrawElements](http://docs.gl/gl4/glDrawElements)(mode, count, type_, indices)
         /// * `mode` group: PrimitiveType
@@ -12589,7 +13538,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glDrawElements",
-                &self.glDrawElements_p,
+                self.lazy(&self.glDrawElements_p, b"glDrawElements\0"),
                 mode,
                 count,
                 type_,
@@ -12615,7 +13564,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DrawElements_is_loaded(&self) -> bool {
-            !self.glDrawElements_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDrawElements_p, b"glDrawElements\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDrawElementsBaseVertex](http://docs.gl/gl4/glDrawElementsBaseVertex)(mode, count, type_, indices, basevertex)
         /// * `mode` group: PrimitiveType
@@ -12644,7 +13596,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glDrawElementsBaseVertex",
-                &self.glDrawElementsBaseVertex_p,
+                self.lazy(
+                    &self.glDrawElementsBaseVertex_p,
+                    b"glDrawElementsBaseVertex\0",
+                ),
                 mode,
                 count,
                 type_,
@@ -12671,7 +13626,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DrawElementsBaseVertex_is_loaded(&self) -> bool {
-            !self.glDrawElementsBaseVertex_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glDrawElementsBaseVertex_p,
+                    b"glDrawElementsBaseVertex\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glDrawElementsIndirect](http://docs.gl/gl4/glDrawElementsIndirect)(mode, type_, indirect)
         /// * `mode` group: PrimitiveType
@@ -12695,7 +13656,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glDrawElementsIndirect",
-                &self.glDrawElementsIndirect_p,
+                self.lazy(&self.glDrawElementsIndirect_p, b"glDrawElementsIndirect\0"),
                 mode,
                 type_,
                 indirect,
@@ -12720,7 +13681,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DrawElementsIndirect_is_loaded(&self) -> bool {
-            !self.glDrawElementsIndirect_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDrawElementsIndirect_p, b"glDrawElementsIndirect\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDrawElementsInstanced](http://docs.gl/gl4/glDrawElementsInstanced)(mode, count, type_, indices, instancecount)
         /// * `mode` group: PrimitiveType
@@ -12749,7 +13713,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glDrawElementsInstanced",
-                &self.glDrawElementsInstanced_p,
+                self.lazy(
+                    &self.glDrawElementsInstanced_p,
+                    b"glDrawElementsInstanced\0",
+                ),
                 mode,
                 count,
                 type_,
@@ -12776,7 +13743,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DrawElementsInstanced_is_loaded(&self) -> bool {
-            !self.glDrawElementsInstanced_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glDrawElementsInstanced_p,
+                    b"glDrawElementsInstanced\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glDrawElementsInstancedARB](http://docs.gl/gl4/glDrawElementsInstancedARB)(mode, count, type_, indices, primcount)
         /// * `mode` group: PrimitiveType
@@ -12806,7 +13779,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glDrawElementsInstancedARB",
-                &self.glDrawElementsInstancedARB_p,
+                self.lazy(
+                    &self.glDrawElementsInstancedARB_p,
+                    b"glDrawElementsInstancedARB\0",
+                ),
                 mode,
                 count,
                 type_,
@@ -12833,7 +13809,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DrawElementsInstancedARB_is_loaded(&self) -> bool {
-            !self.glDrawElementsInstancedARB_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glDrawElementsInstancedARB_p,
+                    b"glDrawElementsInstancedARB\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glDrawElementsInstancedBaseInstance](http://docs.gl/gl4/glDrawElementsInstancedBaseInstance)(mode, count, type_, indices, instancecount, baseinstance)
         /// * `mode` group: PrimitiveType
@@ -12856,7 +13838,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glDrawElementsInstancedBaseInstance",
-                &self.glDrawElementsInstancedBaseInstance_p,
+                self.lazy(
+                    &self.glDrawElementsInstancedBaseInstance_p,
+                    b"glDrawElementsInstancedBaseInstance\0",
+                ),
                 mode,
                 count,
                 type_,
@@ -12885,7 +13870,10 @@ pub mod struct_commands {
         #[doc(hidden)]
         pub fn DrawElementsInstancedBaseInstance_is_loaded(&self) -> bool {
             !self
-                .glDrawElementsInstancedBaseInstance_p
+                .lazy(
+                    &self.glDrawElementsInstancedBaseInstance_p,
+                    b"glDrawElementsInstancedBaseInstance\0",
+                )
                 .load(RELAX)
                 .is_null()
         }
@@ -12910,7 +13898,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glDrawElementsInstancedBaseVertex",
-                &self.glDrawElementsInstancedBaseVertex_p,
+                self.lazy(
+                    &self.glDrawElementsInstancedBaseVertex_p,
+                    b"glDrawElementsInstancedBaseVertex\0",
+                ),
                 mode,
                 count,
                 type_,
@@ -12939,7 +13930,10 @@ pub mod struct_commands {
         #[doc(hidden)]
         pub fn DrawElementsInstancedBaseVertex_is_loaded(&self) -> bool {
             !self
-                .glDrawElementsInstancedBaseVertex_p
+                .lazy(
+                    &self.glDrawElementsInstancedBaseVertex_p,
+                    b"glDrawElementsInstancedBaseVertex\0",
+                )
                 .load(RELAX)
                 .is_null()
         }
@@ -12965,7 +13959,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_7arg(
                 "glDrawElementsInstancedBaseVertexBaseInstance",
-                &self.glDrawElementsInstancedBaseVertexBaseInstance_p,
+                self.lazy(
+                    &self.glDrawElementsInstancedBaseVertexBaseInstance_p,
+                    b"glDrawElementsInstancedBaseVertexBaseInstance\0",
+                ),
                 mode,
                 count,
                 type_,
@@ -12995,7 +13992,10 @@ pub mod struct_commands {
         #[doc(hidden)]
         pub fn DrawElementsInstancedBaseVertexBaseInstance_is_loaded(&self) -> bool {
             !self
-                .glDrawElementsInstancedBaseVertexBaseInstance_p
+                .lazy(
+                    &self.glDrawElementsInstancedBaseVertexBaseInstance_p,
+                    b"glDrawElementsInstancedBaseVertexBaseInstance\0",
+                )
                 .load(RELAX)
                 .is_null()
         }
@@ -13028,7 +14028,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glDrawRangeElements",
-                &self.glDrawRangeElements_p,
+                self.lazy(&self.glDrawRangeElements_p, b"glDrawRangeElements\0"),
                 mode,
                 start,
                 end,
@@ -13056,7 +14056,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DrawRangeElements_is_loaded(&self) -> bool {
-            !self.glDrawRangeElements_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glDrawRangeElements_p, b"glDrawRangeElements\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glDrawRangeElementsBaseVertex](http://docs.gl/gl4/glDrawRangeElementsBaseVertex)(mode, start, end, count, type_, indices, basevertex)
         /// * `mode` group: PrimitiveType
@@ -13080,7 +14083,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_7arg(
                 "glDrawRangeElementsBaseVertex",
-                &self.glDrawRangeElementsBaseVertex_p,
+                self.lazy(
+                    &self.glDrawRangeElementsBaseVertex_p,
+                    b"glDrawRangeElementsBaseVertex\0",
+                ),
                 mode,
                 start,
                 end,
@@ -13109,7 +14115,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DrawRangeElementsBaseVertex_is_loaded(&self) -> bool {
-            !self.glDrawRangeElementsBaseVertex_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glDrawRangeElementsBaseVertex_p,
+                    b"glDrawRangeElementsBaseVertex\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glDrawTransformFeedback](http://docs.gl/gl4/glDrawTransformFeedback)(mode, id)
         /// * `mode` group: PrimitiveType
@@ -13122,7 +14134,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glDrawTransformFeedback",
-                &self.glDrawTransformFeedback_p,
+                self.lazy(
+                    &self.glDrawTransformFeedback_p,
+                    b"glDrawTransformFeedback\0",
+                ),
                 mode,
                 id,
             );
@@ -13146,7 +14161,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DrawTransformFeedback_is_loaded(&self) -> bool {
-            !self.glDrawTransformFeedback_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glDrawTransformFeedback_p,
+                    b"glDrawTransformFeedback\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glDrawTransformFeedbackInstanced](http://docs.gl/gl4/glDrawTransformFeedbackInstanced)(mode, id, instancecount)
         /// * `mode` group: PrimitiveType
@@ -13169,7 +14190,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glDrawTransformFeedbackInstanced",
-                &self.glDrawTransformFeedbackInstanced_p,
+                self.lazy(
+                    &self.glDrawTransformFeedbackInstanced_p,
+                    b"glDrawTransformFeedbackInstanced\0",
+                ),
                 mode,
                 id,
                 instancecount,
@@ -13195,7 +14219,10 @@ pub mod struct_commands {
         #[doc(hidden)]
         pub fn DrawTransformFeedbackInstanced_is_loaded(&self) -> bool {
             !self
-                .glDrawTransformFeedbackInstanced_p
+                .lazy(
+                    &self.glDrawTransformFeedbackInstanced_p,
+                    b"glDrawTransformFeedbackInstanced\0",
+                )
                 .load(RELAX)
                 .is_null()
         }
@@ -13215,7 +14242,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glDrawTransformFeedbackStream",
-                &self.glDrawTransformFeedbackStream_p,
+                self.lazy(
+                    &self.glDrawTransformFeedbackStream_p,
+                    b"glDrawTransformFeedbackStream\0",
+                ),
                 mode,
                 id,
                 stream,
@@ -13240,7 +14270,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn DrawTransformFeedbackStream_is_loaded(&self) -> bool {
-            !self.glDrawTransformFeedbackStream_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glDrawTransformFeedbackStream_p,
+                    b"glDrawTransformFeedbackStream\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glDrawTransformFeedbackStreamInstanced](http://docs.gl/gl4/glDrawTransformFeedbackStreamInstanced)(mode, id, stream, instancecount)
         /// * `mode` group: PrimitiveType
@@ -13265,7 +14301,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glDrawTransformFeedbackStreamInstanced",
-                &self.glDrawTransformFeedbackStreamInstanced_p,
+                self.lazy(
+                    &self.glDrawTransformFeedbackStreamInstanced_p,
+                    b"glDrawTransformFeedbackStreamInstanced\0",
+                ),
                 mode,
                 id,
                 stream,
@@ -13292,7 +14331,10 @@ pub mod struct_commands {
         #[doc(hidden)]
         pub fn DrawTransformFeedbackStreamInstanced_is_loaded(&self) -> bool {
             !self
-                .glDrawTransformFeedbackStreamInstanced_p
+                .lazy(
+                    &self.glDrawTransformFeedbackStreamInstanced_p,
+                    b"glDrawTransformFeedbackStreamInstanced\0",
+                )
                 .load(RELAX)
                 .is_null()
         }
@@ -13305,7 +14347,8 @@ pub mod struct_commands {
             {
                 trace!("calling gl.Enable({:#X});", cap);
             }
-            let out = call_atomic_ptr_1arg("glEnable", &self.glEnable_p, cap);
+            let out =
+                call_atomic_ptr_1arg("glEnable", self.lazy(&self.glEnable_p, b"glEnable\0"), cap);
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glEnable");
@@ -13322,7 +14365,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Enable_is_loaded(&self) -> bool {
-            !self.glEnable_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glEnable_p, b"glEnable\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glEnableIndexedEXT](http://docs.gl/gl4/glEnableIndexedEXT)(target, index)
         /// * `target` group: EnableCap
@@ -13336,7 +14382,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glEnableIndexedEXT",
-                &self.glEnableIndexedEXT_p,
+                self.lazy(&self.glEnableIndexedEXT_p, b"glEnableIndexedEXT\0"),
                 target,
                 index,
             );
@@ -13360,7 +14406,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn EnableIndexedEXT_is_loaded(&self) -> bool {
-            !self.glEnableIndexedEXT_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glEnableIndexedEXT_p, b"glEnableIndexedEXT\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glEnableVertexArrayAttrib](http://docs.gl/gl4/glEnableVertexArrayAttrib)(vaobj, index)
         #[cfg_attr(feature = "inline", inline)]
@@ -13376,7 +14425,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glEnableVertexArrayAttrib",
-                &self.glEnableVertexArrayAttrib_p,
+                self.lazy(
+                    &self.glEnableVertexArrayAttrib_p,
+                    b"glEnableVertexArrayAttrib\0",
+                ),
                 vaobj,
                 index,
             );
@@ -13400,7 +14452,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn EnableVertexArrayAttrib_is_loaded(&self) -> bool {
-            !self.glEnableVertexArrayAttrib_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glEnableVertexArrayAttrib_p,
+                    b"glEnableVertexArrayAttrib\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glEnableVertexAttribArray](http://docs.gl/gl4/glEnableVertexAttribArray)(index)
         #[cfg_attr(feature = "inline", inline)]
@@ -13412,7 +14470,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_1arg(
                 "glEnableVertexAttribArray",
-                &self.glEnableVertexAttribArray_p,
+                self.lazy(
+                    &self.glEnableVertexAttribArray_p,
+                    b"glEnableVertexAttribArray\0",
+                ),
                 index,
             );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
@@ -13435,7 +14496,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn EnableVertexAttribArray_is_loaded(&self) -> bool {
-            !self.glEnableVertexAttribArray_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glEnableVertexAttribArray_p,
+                    b"glEnableVertexAttribArray\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glEnablei](http://docs.gl/gl4/glEnable)(target, index)
         /// * `target` group: EnableCap
@@ -13446,7 +14513,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.Enablei({:#X}, {:?});", target, index);
             }
-            let out = call_atomic_ptr_2arg("glEnablei", &self.glEnablei_p, target, index);
+            let out = call_atomic_ptr_2arg(
+                "glEnablei",
+                self.lazy(&self.glEnablei_p, b"glEnablei\0"),
+                target,
+                index,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glEnablei");
@@ -13463,7 +14535,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Enablei_is_loaded(&self) -> bool {
-            !self.glEnablei_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glEnablei_p, b"glEnablei\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glEndConditionalRender](http://docs.gl/gl4/glEndConditionalRender)()
         #[cfg_attr(feature = "inline", inline)]
@@ -13473,8 +14548,10 @@ pub mod struct_commands {
             {
                 trace!("calling gl.EndConditionalRender();",);
             }
-            let out =
-                call_atomic_ptr_0arg("glEndConditionalRender", &self.glEndConditionalRender_p);
+            let out = call_atomic_ptr_0arg(
+                "glEndConditionalRender",
+                self.lazy(&self.glEndConditionalRender_p, b"glEndConditionalRender\0"),
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glEndConditionalRender");
@@ -13495,7 +14572,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn EndConditionalRender_is_loaded(&self) -> bool {
-            !self.glEndConditionalRender_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glEndConditionalRender_p, b"glEndConditionalRender\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glEndQuery](http://docs.gl/gl4/glEndQuery)(target)
         /// * `target` group: QueryTarget
@@ -13506,7 +14586,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.EndQuery({:#X});", target);
             }
-            let out = call_atomic_ptr_1arg("glEndQuery", &self.glEndQuery_p, target);
+            let out = call_atomic_ptr_1arg(
+                "glEndQuery",
+                self.lazy(&self.glEndQuery_p, b"glEndQuery\0"),
+                target,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glEndQuery");
@@ -13523,7 +14607,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn EndQuery_is_loaded(&self) -> bool {
-            !self.glEndQuery_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glEndQuery_p, b"glEndQuery\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glEndQueryEXT](http://docs.gl/gl4/glEndQueryEXT)(target)
         /// * `target` group: QueryTarget
@@ -13534,7 +14621,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.EndQueryEXT({:#X});", target);
             }
-            let out = call_atomic_ptr_1arg("glEndQueryEXT", &self.glEndQueryEXT_p, target);
+            let out = call_atomic_ptr_1arg(
+                "glEndQueryEXT",
+                self.lazy(&self.glEndQueryEXT_p, b"glEndQueryEXT\0"),
+                target,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glEndQueryEXT");
@@ -13551,7 +14642,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn EndQueryEXT_is_loaded(&self) -> bool {
-            !self.glEndQueryEXT_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glEndQueryEXT_p, b"glEndQueryEXT\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glEndQueryIndexed](http://docs.gl/gl4/glEndQueryIndexed)(target, index)
         /// * `target` group: QueryTarget
@@ -13564,7 +14658,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glEndQueryIndexed",
-                &self.glEndQueryIndexed_p,
+                self.lazy(&self.glEndQueryIndexed_p, b"glEndQueryIndexed\0"),
                 target,
                 index,
             );
@@ -13588,7 +14682,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn EndQueryIndexed_is_loaded(&self) -> bool {
-            !self.glEndQueryIndexed_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glEndQueryIndexed_p, b"glEndQueryIndexed\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glEndTransformFeedback](http://docs.gl/gl4/glEndTransformFeedback)()
         #[cfg_attr(feature = "inline", inline)]
@@ -13598,8 +14695,10 @@ pub mod struct_commands {
             {
                 trace!("calling gl.EndTransformFeedback();",);
             }
-            let out =
-                call_atomic_ptr_0arg("glEndTransformFeedback", &self.glEndTransformFeedback_p);
+            let out = call_atomic_ptr_0arg(
+                "glEndTransformFeedback",
+                self.lazy(&self.glEndTransformFeedback_p, b"glEndTransformFeedback\0"),
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glEndTransformFeedback");
@@ -13620,7 +14719,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn EndTransformFeedback_is_loaded(&self) -> bool {
-            !self.glEndTransformFeedback_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glEndTransformFeedback_p, b"glEndTransformFeedback\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glFenceSync](http://docs.gl/gl4/glFenceSync)(condition, flags)
         /// * `condition` group: SyncCondition
@@ -13633,7 +14735,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.FenceSync({:#X}, {:?});", condition, flags);
             }
-            let out = call_atomic_ptr_2arg("glFenceSync", &self.glFenceSync_p, condition, flags);
+            let out = call_atomic_ptr_2arg(
+                "glFenceSync",
+                self.lazy(&self.glFenceSync_p, b"glFenceSync\0"),
+                condition,
+                flags,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glFenceSync");
@@ -13650,7 +14757,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn FenceSync_is_loaded(&self) -> bool {
-            !self.glFenceSync_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glFenceSync_p, b"glFenceSync\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glFinish](http://docs.gl/gl4/glFinish)()
         #[cfg_attr(feature = "inline", inline)]
@@ -13660,7 +14770,7 @@ pub mod struct_commands {
             {
                 trace!("calling gl.Finish();",);
             }
-            let out = call_atomic_ptr_0arg("glFinish", &self.glFinish_p);
+            let out = call_atomic_ptr_0arg("glFinish", self.lazy(&self.glFinish_p, b"glFinish\0"));
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glFinish");
@@ -13677,7 +14787,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Finish_is_loaded(&self) -> bool {
-            !self.glFinish_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glFinish_p, b"glFinish\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glFlush](http://docs.gl/gl4/glFlush)()
         #[cfg_attr(feature = "inline", inline)]
@@ -13687,7 +14800,7 @@ pub mod struct_commands {
             {
                 trace!("calling gl.Flush();",);
             }
-            let out = call_atomic_ptr_0arg("glFlush", &self.glFlush_p);
+            let out = call_atomic_ptr_0arg("glFlush", self.lazy(&self.glFlush_p, b"glFlush\0"));
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glFlush");
@@ -13704,7 +14817,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Flush_is_loaded(&self) -> bool {
-            !self.glFlush_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glFlush_p, b"glFlush\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glFlushMappedBufferRange](http://docs.gl/gl4/glFlushMappedBufferRange)(target, offset, length)
         /// * `target` group: BufferTargetARB
@@ -13729,7 +14845,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glFlushMappedBufferRange",
-                &self.glFlushMappedBufferRange_p,
+                self.lazy(
+                    &self.glFlushMappedBufferRange_p,
+                    b"glFlushMappedBufferRange\0",
+                ),
                 target,
                 offset,
                 length,
@@ -13754,7 +14873,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn FlushMappedBufferRange_is_loaded(&self) -> bool {
-            !self.glFlushMappedBufferRange_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glFlushMappedBufferRange_p,
+                    b"glFlushMappedBufferRange\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glFlushMappedNamedBufferRange](http://docs.gl/gl4/glFlushMappedNamedBufferRange)(buffer, offset, length)
         /// * `length` group: BufferSize
@@ -13777,7 +14902,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glFlushMappedNamedBufferRange",
-                &self.glFlushMappedNamedBufferRange_p,
+                self.lazy(
+                    &self.glFlushMappedNamedBufferRange_p,
+                    b"glFlushMappedNamedBufferRange\0",
+                ),
                 buffer,
                 offset,
                 length,
@@ -13802,7 +14930,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn FlushMappedNamedBufferRange_is_loaded(&self) -> bool {
-            !self.glFlushMappedNamedBufferRange_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glFlushMappedNamedBufferRange_p,
+                    b"glFlushMappedNamedBufferRange\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glFramebufferParameteri](http://docs.gl/gl4/glFramebufferParameter)(target, pname, param)
         /// * `target` group: FramebufferTarget
@@ -13821,7 +14955,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glFramebufferParameteri",
-                &self.glFramebufferParameteri_p,
+                self.lazy(
+                    &self.glFramebufferParameteri_p,
+                    b"glFramebufferParameteri\0",
+                ),
                 target,
                 pname,
                 param,
@@ -13846,7 +14983,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn FramebufferParameteri_is_loaded(&self) -> bool {
-            !self.glFramebufferParameteri_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glFramebufferParameteri_p,
+                    b"glFramebufferParameteri\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glFramebufferRenderbuffer](http://docs.gl/gl4/glFramebufferRenderbuffer)(target, attachment, renderbuffertarget, renderbuffer)
         /// * `target` group: FramebufferTarget
@@ -13873,7 +15016,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glFramebufferRenderbuffer",
-                &self.glFramebufferRenderbuffer_p,
+                self.lazy(
+                    &self.glFramebufferRenderbuffer_p,
+                    b"glFramebufferRenderbuffer\0",
+                ),
                 target,
                 attachment,
                 renderbuffertarget,
@@ -13899,7 +15045,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn FramebufferRenderbuffer_is_loaded(&self) -> bool {
-            !self.glFramebufferRenderbuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glFramebufferRenderbuffer_p,
+                    b"glFramebufferRenderbuffer\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glFramebufferTexture](http://docs.gl/gl4/glFramebufferTexture)(target, attachment, texture, level)
         /// * `target` group: FramebufferTarget
@@ -13925,7 +15077,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glFramebufferTexture",
-                &self.glFramebufferTexture_p,
+                self.lazy(&self.glFramebufferTexture_p, b"glFramebufferTexture\0"),
                 target,
                 attachment,
                 texture,
@@ -13951,7 +15103,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn FramebufferTexture_is_loaded(&self) -> bool {
-            !self.glFramebufferTexture_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glFramebufferTexture_p, b"glFramebufferTexture\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glFramebufferTexture1D](http://docs.gl/gl4/glFramebufferTexture1D)(target, attachment, textarget, texture, level)
         /// * `target` group: FramebufferTarget
@@ -13980,7 +15135,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glFramebufferTexture1D",
-                &self.glFramebufferTexture1D_p,
+                self.lazy(&self.glFramebufferTexture1D_p, b"glFramebufferTexture1D\0"),
                 target,
                 attachment,
                 textarget,
@@ -14007,7 +15162,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn FramebufferTexture1D_is_loaded(&self) -> bool {
-            !self.glFramebufferTexture1D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glFramebufferTexture1D_p, b"glFramebufferTexture1D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glFramebufferTexture2D](http://docs.gl/gl4/glFramebufferTexture2D)(target, attachment, textarget, texture, level)
         /// * `target` group: FramebufferTarget
@@ -14036,7 +15194,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glFramebufferTexture2D",
-                &self.glFramebufferTexture2D_p,
+                self.lazy(&self.glFramebufferTexture2D_p, b"glFramebufferTexture2D\0"),
                 target,
                 attachment,
                 textarget,
@@ -14063,7 +15221,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn FramebufferTexture2D_is_loaded(&self) -> bool {
-            !self.glFramebufferTexture2D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glFramebufferTexture2D_p, b"glFramebufferTexture2D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glFramebufferTexture2DMultisampleEXT](http://docs.gl/gl4/glFramebufferTexture2DMultisampleEXT)(target, attachment, textarget, texture, level, samples)
         /// * `target` group: FramebufferTarget
@@ -14090,7 +15251,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glFramebufferTexture2DMultisampleEXT",
-                &self.glFramebufferTexture2DMultisampleEXT_p,
+                self.lazy(
+                    &self.glFramebufferTexture2DMultisampleEXT_p,
+                    b"glFramebufferTexture2DMultisampleEXT\0",
+                ),
                 target,
                 attachment,
                 textarget,
@@ -14127,7 +15291,10 @@ pub mod struct_commands {
         )]
         pub fn FramebufferTexture2DMultisampleEXT_is_loaded(&self) -> bool {
             !self
-                .glFramebufferTexture2DMultisampleEXT_p
+                .lazy(
+                    &self.glFramebufferTexture2DMultisampleEXT_p,
+                    b"glFramebufferTexture2DMultisampleEXT\0",
+                )
                 .load(RELAX)
                 .is_null()
         }
@@ -14160,7 +15327,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glFramebufferTexture3D",
-                &self.glFramebufferTexture3D_p,
+                self.lazy(&self.glFramebufferTexture3D_p, b"glFramebufferTexture3D\0"),
                 target,
                 attachment,
                 textarget,
@@ -14188,7 +15355,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn FramebufferTexture3D_is_loaded(&self) -> bool {
-            !self.glFramebufferTexture3D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glFramebufferTexture3D_p, b"glFramebufferTexture3D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glFramebufferTextureLayer](http://docs.gl/gl4/glFramebufferTextureLayer)(target, attachment, texture, level, layer)
         /// * `target` group: FramebufferTarget
@@ -14219,7 +15389,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glFramebufferTextureLayer",
-                &self.glFramebufferTextureLayer_p,
+                self.lazy(
+                    &self.glFramebufferTextureLayer_p,
+                    b"glFramebufferTextureLayer\0",
+                ),
                 target,
                 attachment,
                 texture,
@@ -14246,7 +15419,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn FramebufferTextureLayer_is_loaded(&self) -> bool {
-            !self.glFramebufferTextureLayer_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glFramebufferTextureLayer_p,
+                    b"glFramebufferTextureLayer\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glFrontFace](http://docs.gl/gl4/glFrontFace)(mode)
         /// * `mode` group: FrontFaceDirection
@@ -14257,7 +15436,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.FrontFace({:#X});", mode);
             }
-            let out = call_atomic_ptr_1arg("glFrontFace", &self.glFrontFace_p, mode);
+            let out = call_atomic_ptr_1arg(
+                "glFrontFace",
+                self.lazy(&self.glFrontFace_p, b"glFrontFace\0"),
+                mode,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glFrontFace");
@@ -14274,7 +15457,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn FrontFace_is_loaded(&self) -> bool {
-            !self.glFrontFace_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glFrontFace_p, b"glFrontFace\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGenBuffers](http://docs.gl/gl4/glGenBuffers)(n, buffers)
         /// * `buffers` len: n
@@ -14285,7 +15471,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.GenBuffers({:?}, {:p});", n, buffers);
             }
-            let out = call_atomic_ptr_2arg("glGenBuffers", &self.glGenBuffers_p, n, buffers);
+            let out = call_atomic_ptr_2arg(
+                "glGenBuffers",
+                self.lazy(&self.glGenBuffers_p, b"glGenBuffers\0"),
+                n,
+                buffers,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glGenBuffers");
@@ -14302,7 +15493,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GenBuffers_is_loaded(&self) -> bool {
-            !self.glGenBuffers_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGenBuffers_p, b"glGenBuffers\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGenFramebuffers](http://docs.gl/gl4/glGenFramebuffers)(n, framebuffers)
         /// * `framebuffers` len: n
@@ -14315,7 +15509,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glGenFramebuffers",
-                &self.glGenFramebuffers_p,
+                self.lazy(&self.glGenFramebuffers_p, b"glGenFramebuffers\0"),
                 n,
                 framebuffers,
             );
@@ -14339,7 +15533,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GenFramebuffers_is_loaded(&self) -> bool {
-            !self.glGenFramebuffers_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGenFramebuffers_p, b"glGenFramebuffers\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGenProgramPipelines](http://docs.gl/gl4/glGenProgramPipelines)(n, pipelines)
         /// * `pipelines` len: n
@@ -14352,7 +15549,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glGenProgramPipelines",
-                &self.glGenProgramPipelines_p,
+                self.lazy(&self.glGenProgramPipelines_p, b"glGenProgramPipelines\0"),
                 n,
                 pipelines,
             );
@@ -14376,7 +15573,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GenProgramPipelines_is_loaded(&self) -> bool {
-            !self.glGenProgramPipelines_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGenProgramPipelines_p, b"glGenProgramPipelines\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGenQueries](http://docs.gl/gl4/glGenQueries)(n, ids)
         /// * `ids` len: n
@@ -14387,7 +15587,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.GenQueries({:?}, {:p});", n, ids);
             }
-            let out = call_atomic_ptr_2arg("glGenQueries", &self.glGenQueries_p, n, ids);
+            let out = call_atomic_ptr_2arg(
+                "glGenQueries",
+                self.lazy(&self.glGenQueries_p, b"glGenQueries\0"),
+                n,
+                ids,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glGenQueries");
@@ -14404,7 +15609,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GenQueries_is_loaded(&self) -> bool {
-            !self.glGenQueries_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGenQueries_p, b"glGenQueries\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGenQueriesEXT](http://docs.gl/gl4/glGenQueriesEXT)(n, ids)
         /// * `ids` len: n
@@ -14415,7 +15623,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.GenQueriesEXT({:?}, {:p});", n, ids);
             }
-            let out = call_atomic_ptr_2arg("glGenQueriesEXT", &self.glGenQueriesEXT_p, n, ids);
+            let out = call_atomic_ptr_2arg(
+                "glGenQueriesEXT",
+                self.lazy(&self.glGenQueriesEXT_p, b"glGenQueriesEXT\0"),
+                n,
+                ids,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glGenQueriesEXT");
@@ -14436,7 +15649,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GenQueriesEXT_is_loaded(&self) -> bool {
-            !self.glGenQueriesEXT_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGenQueriesEXT_p, b"glGenQueriesEXT\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGenRenderbuffers](http://docs.gl/gl4/glGenRenderbuffers)(n, renderbuffers)
         /// * `renderbuffers` len: n
@@ -14449,7 +15665,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glGenRenderbuffers",
-                &self.glGenRenderbuffers_p,
+                self.lazy(&self.glGenRenderbuffers_p, b"glGenRenderbuffers\0"),
                 n,
                 renderbuffers,
             );
@@ -14473,7 +15689,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GenRenderbuffers_is_loaded(&self) -> bool {
-            !self.glGenRenderbuffers_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGenRenderbuffers_p, b"glGenRenderbuffers\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGenSamplers](http://docs.gl/gl4/glGenSamplers)(count, samplers)
         /// * `samplers` len: count
@@ -14484,7 +15703,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.GenSamplers({:?}, {:p});", count, samplers);
             }
-            let out = call_atomic_ptr_2arg("glGenSamplers", &self.glGenSamplers_p, count, samplers);
+            let out = call_atomic_ptr_2arg(
+                "glGenSamplers",
+                self.lazy(&self.glGenSamplers_p, b"glGenSamplers\0"),
+                count,
+                samplers,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glGenSamplers");
@@ -14501,7 +15725,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GenSamplers_is_loaded(&self) -> bool {
-            !self.glGenSamplers_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGenSamplers_p, b"glGenSamplers\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGenTextures](http://docs.gl/gl4/glGenTextures)(n, textures)
         /// * `textures` group: Texture
@@ -14513,7 +15740,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.GenTextures({:?}, {:p});", n, textures);
             }
-            let out = call_atomic_ptr_2arg("glGenTextures", &self.glGenTextures_p, n, textures);
+            let out = call_atomic_ptr_2arg(
+                "glGenTextures",
+                self.lazy(&self.glGenTextures_p, b"glGenTextures\0"),
+                n,
+                textures,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glGenTextures");
@@ -14530,7 +15762,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GenTextures_is_loaded(&self) -> bool {
-            !self.glGenTextures_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGenTextures_p, b"glGenTextures\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGenTransformFeedbacks](http://docs.gl/gl4/glGenTransformFeedbacks)(n, ids)
         /// * `ids` len: n
@@ -14543,7 +15778,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glGenTransformFeedbacks",
-                &self.glGenTransformFeedbacks_p,
+                self.lazy(
+                    &self.glGenTransformFeedbacks_p,
+                    b"glGenTransformFeedbacks\0",
+                ),
                 n,
                 ids,
             );
@@ -14567,7 +15805,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GenTransformFeedbacks_is_loaded(&self) -> bool {
-            !self.glGenTransformFeedbacks_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGenTransformFeedbacks_p,
+                    b"glGenTransformFeedbacks\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGenVertexArrays](http://docs.gl/gl4/glGenVertexArrays)(n, arrays)
         /// * `arrays` len: n
@@ -14578,8 +15822,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.GenVertexArrays({:?}, {:p});", n, arrays);
             }
-            let out =
-                call_atomic_ptr_2arg("glGenVertexArrays", &self.glGenVertexArrays_p, n, arrays);
+            let out = call_atomic_ptr_2arg(
+                "glGenVertexArrays",
+                self.lazy(&self.glGenVertexArrays_p, b"glGenVertexArrays\0"),
+                n,
+                arrays,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glGenVertexArrays");
@@ -14600,7 +15848,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GenVertexArrays_is_loaded(&self) -> bool {
-            !self.glGenVertexArrays_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGenVertexArrays_p, b"glGenVertexArrays\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGenVertexArraysAPPLE](http://docs.gl/gl4/glGenVertexArraysAPPLE)(n, arrays)
         /// * `arrays` len: n
@@ -14614,7 +15865,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glGenVertexArraysAPPLE",
-                &self.glGenVertexArraysAPPLE_p,
+                self.lazy(&self.glGenVertexArraysAPPLE_p, b"glGenVertexArraysAPPLE\0"),
                 n,
                 arrays,
             );
@@ -14638,7 +15889,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GenVertexArraysAPPLE_is_loaded(&self) -> bool {
-            !self.glGenVertexArraysAPPLE_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGenVertexArraysAPPLE_p, b"glGenVertexArraysAPPLE\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGenVertexArraysOES](http://docs.gl/gl4/glGenVertexArraysOES)(n, arrays)
         /// * `arrays` len: n
@@ -14652,7 +15906,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glGenVertexArraysOES",
-                &self.glGenVertexArraysOES_p,
+                self.lazy(&self.glGenVertexArraysOES_p, b"glGenVertexArraysOES\0"),
                 n,
                 arrays,
             );
@@ -14676,7 +15930,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GenVertexArraysOES_is_loaded(&self) -> bool {
-            !self.glGenVertexArraysOES_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGenVertexArraysOES_p, b"glGenVertexArraysOES\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGenerateMipmap](http://docs.gl/gl4/glGenerateMipmap)(target)
         /// * `target` group: TextureTarget
@@ -14687,7 +15944,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.GenerateMipmap({:#X});", target);
             }
-            let out = call_atomic_ptr_1arg("glGenerateMipmap", &self.glGenerateMipmap_p, target);
+            let out = call_atomic_ptr_1arg(
+                "glGenerateMipmap",
+                self.lazy(&self.glGenerateMipmap_p, b"glGenerateMipmap\0"),
+                target,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glGenerateMipmap");
@@ -14708,7 +15969,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GenerateMipmap_is_loaded(&self) -> bool {
-            !self.glGenerateMipmap_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGenerateMipmap_p, b"glGenerateMipmap\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGenerateTextureMipmap](http://docs.gl/gl4/glGenerateTextureMipmap)(texture)
         #[cfg_attr(feature = "inline", inline)]
@@ -14720,7 +15984,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_1arg(
                 "glGenerateTextureMipmap",
-                &self.glGenerateTextureMipmap_p,
+                self.lazy(
+                    &self.glGenerateTextureMipmap_p,
+                    b"glGenerateTextureMipmap\0",
+                ),
                 texture,
             );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
@@ -14743,7 +16010,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GenerateTextureMipmap_is_loaded(&self) -> bool {
-            !self.glGenerateTextureMipmap_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGenerateTextureMipmap_p,
+                    b"glGenerateTextureMipmap\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetActiveAtomicCounterBufferiv](http://docs.gl/gl4/glGetActiveAtomicCounterBuffer)(program, bufferIndex, pname, params)
         /// * `pname` group: AtomicCounterBufferPName
@@ -14769,7 +16042,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetActiveAtomicCounterBufferiv",
-                &self.glGetActiveAtomicCounterBufferiv_p,
+                self.lazy(
+                    &self.glGetActiveAtomicCounterBufferiv_p,
+                    b"glGetActiveAtomicCounterBufferiv\0",
+                ),
                 program,
                 bufferIndex,
                 pname,
@@ -14796,7 +16072,10 @@ pub mod struct_commands {
         #[doc(hidden)]
         pub fn GetActiveAtomicCounterBufferiv_is_loaded(&self) -> bool {
             !self
-                .glGetActiveAtomicCounterBufferiv_p
+                .lazy(
+                    &self.glGetActiveAtomicCounterBufferiv_p,
+                    b"glGetActiveAtomicCounterBufferiv\0",
+                )
                 .load(RELAX)
                 .is_null()
         }
@@ -14833,7 +16112,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_7arg(
                 "glGetActiveAttrib",
-                &self.glGetActiveAttrib_p,
+                self.lazy(&self.glGetActiveAttrib_p, b"glGetActiveAttrib\0"),
                 program,
                 index,
                 bufSize,
@@ -14862,7 +16141,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetActiveAttrib_is_loaded(&self) -> bool {
-            !self.glGetActiveAttrib_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetActiveAttrib_p, b"glGetActiveAttrib\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetActiveSubroutineName](http://docs.gl/gl4/glGetActiveSubroutineName)(program, shadertype, index, bufSize, length, name)
         /// * `shadertype` group: ShaderType
@@ -14893,7 +16175,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glGetActiveSubroutineName",
-                &self.glGetActiveSubroutineName_p,
+                self.lazy(
+                    &self.glGetActiveSubroutineName_p,
+                    b"glGetActiveSubroutineName\0",
+                ),
                 program,
                 shadertype,
                 index,
@@ -14921,7 +16206,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetActiveSubroutineName_is_loaded(&self) -> bool {
-            !self.glGetActiveSubroutineName_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetActiveSubroutineName_p,
+                    b"glGetActiveSubroutineName\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetActiveSubroutineUniformName](http://docs.gl/gl4/glGetActiveSubroutineUniformName)(program, shadertype, index, bufSize, length, name)
         /// * `shadertype` group: ShaderType
@@ -14944,7 +16235,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glGetActiveSubroutineUniformName",
-                &self.glGetActiveSubroutineUniformName_p,
+                self.lazy(
+                    &self.glGetActiveSubroutineUniformName_p,
+                    b"glGetActiveSubroutineUniformName\0",
+                ),
                 program,
                 shadertype,
                 index,
@@ -14973,7 +16267,10 @@ pub mod struct_commands {
         #[doc(hidden)]
         pub fn GetActiveSubroutineUniformName_is_loaded(&self) -> bool {
             !self
-                .glGetActiveSubroutineUniformName_p
+                .lazy(
+                    &self.glGetActiveSubroutineUniformName_p,
+                    b"glGetActiveSubroutineUniformName\0",
+                )
                 .load(RELAX)
                 .is_null()
         }
@@ -15004,7 +16301,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glGetActiveSubroutineUniformiv",
-                &self.glGetActiveSubroutineUniformiv_p,
+                self.lazy(
+                    &self.glGetActiveSubroutineUniformiv_p,
+                    b"glGetActiveSubroutineUniformiv\0",
+                ),
                 program,
                 shadertype,
                 index,
@@ -15031,7 +16331,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetActiveSubroutineUniformiv_is_loaded(&self) -> bool {
-            !self.glGetActiveSubroutineUniformiv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetActiveSubroutineUniformiv_p,
+                    b"glGetActiveSubroutineUniformiv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetActiveUniform](http://docs.gl/gl4/glGetActiveUniform)(program, index, bufSize, length, size, type_, name)
         /// * `length` len: 1
@@ -15066,7 +16372,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_7arg(
                 "glGetActiveUniform",
-                &self.glGetActiveUniform_p,
+                self.lazy(&self.glGetActiveUniform_p, b"glGetActiveUniform\0"),
                 program,
                 index,
                 bufSize,
@@ -15095,7 +16401,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetActiveUniform_is_loaded(&self) -> bool {
-            !self.glGetActiveUniform_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetActiveUniform_p, b"glGetActiveUniform\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetActiveUniformBlockName](http://docs.gl/gl4/glGetActiveUniformBlockName)(program, uniformBlockIndex, bufSize, length, uniformBlockName)
         /// * `length` len: 1
@@ -15123,7 +16432,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glGetActiveUniformBlockName",
-                &self.glGetActiveUniformBlockName_p,
+                self.lazy(
+                    &self.glGetActiveUniformBlockName_p,
+                    b"glGetActiveUniformBlockName\0",
+                ),
                 program,
                 uniformBlockIndex,
                 bufSize,
@@ -15150,7 +16462,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetActiveUniformBlockName_is_loaded(&self) -> bool {
-            !self.glGetActiveUniformBlockName_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetActiveUniformBlockName_p,
+                    b"glGetActiveUniformBlockName\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetActiveUniformBlockiv](http://docs.gl/gl4/glGetActiveUniformBlockiv)(program, uniformBlockIndex, pname, params)
         /// * `pname` group: UniformBlockPName
@@ -15176,7 +16494,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetActiveUniformBlockiv",
-                &self.glGetActiveUniformBlockiv_p,
+                self.lazy(
+                    &self.glGetActiveUniformBlockiv_p,
+                    b"glGetActiveUniformBlockiv\0",
+                ),
                 program,
                 uniformBlockIndex,
                 pname,
@@ -15202,7 +16523,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetActiveUniformBlockiv_is_loaded(&self) -> bool {
-            !self.glGetActiveUniformBlockiv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetActiveUniformBlockiv_p,
+                    b"glGetActiveUniformBlockiv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetActiveUniformName](http://docs.gl/gl4/glGetActiveUniformName)(program, uniformIndex, bufSize, length, uniformName)
         /// * `length` len: 1
@@ -15230,7 +16557,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glGetActiveUniformName",
-                &self.glGetActiveUniformName_p,
+                self.lazy(&self.glGetActiveUniformName_p, b"glGetActiveUniformName\0"),
                 program,
                 uniformIndex,
                 bufSize,
@@ -15257,7 +16584,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetActiveUniformName_is_loaded(&self) -> bool {
-            !self.glGetActiveUniformName_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetActiveUniformName_p, b"glGetActiveUniformName\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetActiveUniformsiv](http://docs.gl/gl4/glGetActiveUniformsiv)(program, uniformCount, uniformIndices, pname, params)
         /// * `uniformIndices` len: uniformCount
@@ -15286,7 +16616,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glGetActiveUniformsiv",
-                &self.glGetActiveUniformsiv_p,
+                self.lazy(&self.glGetActiveUniformsiv_p, b"glGetActiveUniformsiv\0"),
                 program,
                 uniformCount,
                 uniformIndices,
@@ -15313,7 +16643,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetActiveUniformsiv_is_loaded(&self) -> bool {
-            !self.glGetActiveUniformsiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetActiveUniformsiv_p, b"glGetActiveUniformsiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetAttachedShaders](http://docs.gl/gl4/glGetAttachedShaders)(program, maxCount, count, shaders)
         /// * `count` len: 1
@@ -15339,7 +16672,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetAttachedShaders",
-                &self.glGetAttachedShaders_p,
+                self.lazy(&self.glGetAttachedShaders_p, b"glGetAttachedShaders\0"),
                 program,
                 maxCount,
                 count,
@@ -15365,7 +16698,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetAttachedShaders_is_loaded(&self) -> bool {
-            !self.glGetAttachedShaders_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetAttachedShaders_p, b"glGetAttachedShaders\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetAttribLocation](http://docs.gl/gl4/glGetAttribLocation)(program, name)
         #[cfg_attr(feature = "inline", inline)]
@@ -15377,7 +16713,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glGetAttribLocation",
-                &self.glGetAttribLocation_p,
+                self.lazy(&self.glGetAttribLocation_p, b"glGetAttribLocation\0"),
                 program,
                 name,
             );
@@ -15401,7 +16737,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetAttribLocation_is_loaded(&self) -> bool {
-            !self.glGetAttribLocation_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetAttribLocation_p, b"glGetAttribLocation\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetBooleanIndexedvEXT](http://docs.gl/gl4/glGetBooleanIndexedvEXT)(target, index, data)
         /// * `target` group: BufferTargetARB
@@ -15426,7 +16765,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetBooleanIndexedvEXT",
-                &self.glGetBooleanIndexedvEXT_p,
+                self.lazy(
+                    &self.glGetBooleanIndexedvEXT_p,
+                    b"glGetBooleanIndexedvEXT\0",
+                ),
                 target,
                 index,
                 data,
@@ -15451,7 +16793,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetBooleanIndexedvEXT_is_loaded(&self) -> bool {
-            !self.glGetBooleanIndexedvEXT_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetBooleanIndexedvEXT_p,
+                    b"glGetBooleanIndexedvEXT\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetBooleani_v](http://docs.gl/gl4/glGet)(target, index, data)
         /// * `target` group: BufferTargetARB
@@ -15470,7 +16818,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetBooleani_v",
-                &self.glGetBooleani_v_p,
+                self.lazy(&self.glGetBooleani_v_p, b"glGetBooleani_v\0"),
                 target,
                 index,
                 data,
@@ -15495,7 +16843,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetBooleani_v_is_loaded(&self) -> bool {
-            !self.glGetBooleani_v_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetBooleani_v_p, b"glGetBooleani_v\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetBooleanv](http://docs.gl/gl4/glGet)(pname, data)
         /// * `pname` group: GetPName
@@ -15507,7 +16858,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.GetBooleanv({:#X}, {:p});", pname, data);
             }
-            let out = call_atomic_ptr_2arg("glGetBooleanv", &self.glGetBooleanv_p, pname, data);
+            let out = call_atomic_ptr_2arg(
+                "glGetBooleanv",
+                self.lazy(&self.glGetBooleanv_p, b"glGetBooleanv\0"),
+                pname,
+                data,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glGetBooleanv");
@@ -15524,7 +16880,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetBooleanv_is_loaded(&self) -> bool {
-            !self.glGetBooleanv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetBooleanv_p, b"glGetBooleanv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetBufferParameteri64v](http://docs.gl/gl4/glGetBufferParameter)(target, pname, params)
         /// * `target` group: BufferTargetARB
@@ -15549,7 +16908,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetBufferParameteri64v",
-                &self.glGetBufferParameteri64v_p,
+                self.lazy(
+                    &self.glGetBufferParameteri64v_p,
+                    b"glGetBufferParameteri64v\0",
+                ),
                 target,
                 pname,
                 params,
@@ -15574,7 +16936,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetBufferParameteri64v_is_loaded(&self) -> bool {
-            !self.glGetBufferParameteri64v_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetBufferParameteri64v_p,
+                    b"glGetBufferParameteri64v\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetBufferParameteriv](http://docs.gl/gl4/glGetBufferParameter)(target, pname, params)
         /// * `target` group: BufferTargetARB
@@ -15599,7 +16967,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetBufferParameteriv",
-                &self.glGetBufferParameteriv_p,
+                self.lazy(&self.glGetBufferParameteriv_p, b"glGetBufferParameteriv\0"),
                 target,
                 pname,
                 params,
@@ -15624,7 +16992,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetBufferParameteriv_is_loaded(&self) -> bool {
-            !self.glGetBufferParameteriv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetBufferParameteriv_p, b"glGetBufferParameteriv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetBufferPointerv](http://docs.gl/gl4/glGetBufferPointerv)(target, pname, params)
         /// * `target` group: BufferTargetARB
@@ -15649,7 +17020,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetBufferPointerv",
-                &self.glGetBufferPointerv_p,
+                self.lazy(&self.glGetBufferPointerv_p, b"glGetBufferPointerv\0"),
                 target,
                 pname,
                 params,
@@ -15674,7 +17045,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetBufferPointerv_is_loaded(&self) -> bool {
-            !self.glGetBufferPointerv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetBufferPointerv_p, b"glGetBufferPointerv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetBufferSubData](http://docs.gl/gl4/glGetBufferSubData)(target, offset, size, data)
         /// * `target` group: BufferTargetARB
@@ -15702,7 +17076,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetBufferSubData",
-                &self.glGetBufferSubData_p,
+                self.lazy(&self.glGetBufferSubData_p, b"glGetBufferSubData\0"),
                 target,
                 offset,
                 size,
@@ -15728,7 +17102,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetBufferSubData_is_loaded(&self) -> bool {
-            !self.glGetBufferSubData_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetBufferSubData_p, b"glGetBufferSubData\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetCompressedTexImage](http://docs.gl/gl4/glGetCompressedTexImage)(target, level, img)
         /// * `target` group: TextureTarget
@@ -15749,7 +17126,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetCompressedTexImage",
-                &self.glGetCompressedTexImage_p,
+                self.lazy(
+                    &self.glGetCompressedTexImage_p,
+                    b"glGetCompressedTexImage\0",
+                ),
                 target,
                 level,
                 img,
@@ -15774,7 +17154,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetCompressedTexImage_is_loaded(&self) -> bool {
-            !self.glGetCompressedTexImage_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetCompressedTexImage_p,
+                    b"glGetCompressedTexImage\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetCompressedTextureImage](http://docs.gl/gl4/glGetCompressedTextureImage)(texture, level, bufSize, pixels)
         #[cfg_attr(feature = "inline", inline)]
@@ -15798,7 +17184,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetCompressedTextureImage",
-                &self.glGetCompressedTextureImage_p,
+                self.lazy(
+                    &self.glGetCompressedTextureImage_p,
+                    b"glGetCompressedTextureImage\0",
+                ),
                 texture,
                 level,
                 bufSize,
@@ -15824,7 +17213,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetCompressedTextureImage_is_loaded(&self) -> bool {
-            !self.glGetCompressedTextureImage_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetCompressedTextureImage_p,
+                    b"glGetCompressedTextureImage\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetCompressedTextureSubImage](http://docs.gl/gl4/glGetCompressedTextureSubImage)(texture, level, xoffset, yoffset, zoffset, width, height, depth, bufSize, pixels)
         #[cfg_attr(feature = "inline", inline)]
@@ -15848,7 +17243,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_10arg(
                 "glGetCompressedTextureSubImage",
-                &self.glGetCompressedTextureSubImage_p,
+                self.lazy(
+                    &self.glGetCompressedTextureSubImage_p,
+                    b"glGetCompressedTextureSubImage\0",
+                ),
                 texture,
                 level,
                 xoffset,
@@ -15880,7 +17278,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetCompressedTextureSubImage_is_loaded(&self) -> bool {
-            !self.glGetCompressedTextureSubImage_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetCompressedTextureSubImage_p,
+                    b"glGetCompressedTextureSubImage\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetDebugMessageLog](http://docs.gl/gl4/glGetDebugMessageLog)(count, bufSize, sources, types, ids, severities, lengths, messageLog)
         /// * `sources` group: DebugSource
@@ -15911,7 +17315,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_8arg(
                 "glGetDebugMessageLog",
-                &self.glGetDebugMessageLog_p,
+                self.lazy(&self.glGetDebugMessageLog_p, b"glGetDebugMessageLog\0"),
                 count,
                 bufSize,
                 sources,
@@ -15941,7 +17345,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetDebugMessageLog_is_loaded(&self) -> bool {
-            !self.glGetDebugMessageLog_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetDebugMessageLog_p, b"glGetDebugMessageLog\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetDebugMessageLogARB](http://docs.gl/gl4/glGetDebugMessageLogARB)(count, bufSize, sources, types, ids, severities, lengths, messageLog)
         /// * `sources` group: DebugSource
@@ -15973,7 +17380,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_8arg(
                 "glGetDebugMessageLogARB",
-                &self.glGetDebugMessageLogARB_p,
+                self.lazy(
+                    &self.glGetDebugMessageLogARB_p,
+                    b"glGetDebugMessageLogARB\0",
+                ),
                 count,
                 bufSize,
                 sources,
@@ -16003,7 +17413,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetDebugMessageLogARB_is_loaded(&self) -> bool {
-            !self.glGetDebugMessageLogARB_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetDebugMessageLogARB_p,
+                    b"glGetDebugMessageLogARB\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetDebugMessageLogKHR](http://docs.gl/gl4/glGetDebugMessageLogKHR)(count, bufSize, sources, types, ids, severities, lengths, messageLog)
         /// * `sources` group: DebugSource
@@ -16035,7 +17451,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_8arg(
                 "glGetDebugMessageLogKHR",
-                &self.glGetDebugMessageLogKHR_p,
+                self.lazy(
+                    &self.glGetDebugMessageLogKHR_p,
+                    b"glGetDebugMessageLogKHR\0",
+                ),
                 count,
                 bufSize,
                 sources,
@@ -16065,7 +17484,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetDebugMessageLogKHR_is_loaded(&self) -> bool {
-            !self.glGetDebugMessageLogKHR_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetDebugMessageLogKHR_p,
+                    b"glGetDebugMessageLogKHR\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetDoublei_v](http://docs.gl/gl4/glGetDoublei_v)(target, index, data)
         /// * `target` group: GetPName
@@ -16084,7 +17509,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetDoublei_v",
-                &self.glGetDoublei_v_p,
+                self.lazy(&self.glGetDoublei_v_p, b"glGetDoublei_v\0"),
                 target,
                 index,
                 data,
@@ -16109,7 +17534,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetDoublei_v_is_loaded(&self) -> bool {
-            !self.glGetDoublei_v_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetDoublei_v_p, b"glGetDoublei_v\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetDoublev](http://docs.gl/gl4/glGetDoublev)(pname, data)
         /// * `pname` group: GetPName
@@ -16121,7 +17549,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.GetDoublev({:#X}, {:p});", pname, data);
             }
-            let out = call_atomic_ptr_2arg("glGetDoublev", &self.glGetDoublev_p, pname, data);
+            let out = call_atomic_ptr_2arg(
+                "glGetDoublev",
+                self.lazy(&self.glGetDoublev_p, b"glGetDoublev\0"),
+                pname,
+                data,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glGetDoublev");
@@ -16138,7 +17571,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetDoublev_is_loaded(&self) -> bool {
-            !self.glGetDoublev_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetDoublev_p, b"glGetDoublev\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetError](http://docs.gl/gl4/glGetError)()
         /// * return value group: ErrorCode
@@ -16149,7 +17585,8 @@ pub mod struct_commands {
             {
                 trace!("calling gl.GetError();",);
             }
-            let out = call_atomic_ptr_0arg("glGetError", &self.glGetError_p);
+            let out =
+                call_atomic_ptr_0arg("glGetError", self.lazy(&self.glGetError_p, b"glGetError\0"));
 
             out
         }
@@ -16163,7 +17600,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetError_is_loaded(&self) -> bool {
-            !self.glGetError_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetError_p, b"glGetError\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetFloati_v](http://docs.gl/gl4/glGetFloati_v)(target, index, data)
         /// * `target` group: GetPName
@@ -16180,8 +17620,13 @@ pub mod struct_commands {
                     data
                 );
             }
-            let out =
-                call_atomic_ptr_3arg("glGetFloati_v", &self.glGetFloati_v_p, target, index, data);
+            let out = call_atomic_ptr_3arg(
+                "glGetFloati_v",
+                self.lazy(&self.glGetFloati_v_p, b"glGetFloati_v\0"),
+                target,
+                index,
+                data,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glGetFloati_v");
@@ -16198,7 +17643,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetFloati_v_is_loaded(&self) -> bool {
-            !self.glGetFloati_v_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetFloati_v_p, b"glGetFloati_v\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetFloatv](http://docs.gl/gl4/glGet)(pname, data)
         /// * `pname` group: GetPName
@@ -16210,7 +17658,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.GetFloatv({:#X}, {:p});", pname, data);
             }
-            let out = call_atomic_ptr_2arg("glGetFloatv", &self.glGetFloatv_p, pname, data);
+            let out = call_atomic_ptr_2arg(
+                "glGetFloatv",
+                self.lazy(&self.glGetFloatv_p, b"glGetFloatv\0"),
+                pname,
+                data,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glGetFloatv");
@@ -16227,7 +17680,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetFloatv_is_loaded(&self) -> bool {
-            !self.glGetFloatv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetFloatv_p, b"glGetFloatv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetFragDataIndex](http://docs.gl/gl4/glGetFragDataIndex)(program, name)
         #[cfg_attr(feature = "inline", inline)]
@@ -16239,7 +17695,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glGetFragDataIndex",
-                &self.glGetFragDataIndex_p,
+                self.lazy(&self.glGetFragDataIndex_p, b"glGetFragDataIndex\0"),
                 program,
                 name,
             );
@@ -16263,7 +17719,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetFragDataIndex_is_loaded(&self) -> bool {
-            !self.glGetFragDataIndex_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetFragDataIndex_p, b"glGetFragDataIndex\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetFragDataLocation](http://docs.gl/gl4/glGetFragDataLocation)(program, name)
         /// * `name` len: COMPSIZE(name)
@@ -16276,7 +17735,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glGetFragDataLocation",
-                &self.glGetFragDataLocation_p,
+                self.lazy(&self.glGetFragDataLocation_p, b"glGetFragDataLocation\0"),
                 program,
                 name,
             );
@@ -16300,7 +17759,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetFragDataLocation_is_loaded(&self) -> bool {
-            !self.glGetFragDataLocation_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetFragDataLocation_p, b"glGetFragDataLocation\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetFramebufferAttachmentParameteriv](http://docs.gl/gl4/glGetFramebufferAttachmentParameter)(target, attachment, pname, params)
         /// * `target` group: FramebufferTarget
@@ -16328,7 +17790,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetFramebufferAttachmentParameteriv",
-                &self.glGetFramebufferAttachmentParameteriv_p,
+                self.lazy(
+                    &self.glGetFramebufferAttachmentParameteriv_p,
+                    b"glGetFramebufferAttachmentParameteriv\0",
+                ),
                 target,
                 attachment,
                 pname,
@@ -16355,7 +17820,10 @@ pub mod struct_commands {
         #[doc(hidden)]
         pub fn GetFramebufferAttachmentParameteriv_is_loaded(&self) -> bool {
             !self
-                .glGetFramebufferAttachmentParameteriv_p
+                .lazy(
+                    &self.glGetFramebufferAttachmentParameteriv_p,
+                    b"glGetFramebufferAttachmentParameteriv\0",
+                )
                 .load(RELAX)
                 .is_null()
         }
@@ -16382,7 +17850,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetFramebufferParameteriv",
-                &self.glGetFramebufferParameteriv_p,
+                self.lazy(
+                    &self.glGetFramebufferParameteriv_p,
+                    b"glGetFramebufferParameteriv\0",
+                ),
                 target,
                 pname,
                 params,
@@ -16407,7 +17878,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetFramebufferParameteriv_is_loaded(&self) -> bool {
-            !self.glGetFramebufferParameteriv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetFramebufferParameteriv_p,
+                    b"glGetFramebufferParameteriv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetGraphicsResetStatus](http://docs.gl/gl4/glGetGraphicsResetStatus)()
         /// * return value group: GraphicsResetStatus
@@ -16418,8 +17895,13 @@ pub mod struct_commands {
             {
                 trace!("calling gl.GetGraphicsResetStatus();",);
             }
-            let out =
-                call_atomic_ptr_0arg("glGetGraphicsResetStatus", &self.glGetGraphicsResetStatus_p);
+            let out = call_atomic_ptr_0arg(
+                "glGetGraphicsResetStatus",
+                self.lazy(
+                    &self.glGetGraphicsResetStatus_p,
+                    b"glGetGraphicsResetStatus\0",
+                ),
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glGetGraphicsResetStatus");
@@ -16440,7 +17922,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetGraphicsResetStatus_is_loaded(&self) -> bool {
-            !self.glGetGraphicsResetStatus_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetGraphicsResetStatus_p,
+                    b"glGetGraphicsResetStatus\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetInteger64i_v](http://docs.gl/gl4/glGet)(target, index, data)
         /// * `target` group: GetPName
@@ -16459,7 +17947,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetInteger64i_v",
-                &self.glGetInteger64i_v_p,
+                self.lazy(&self.glGetInteger64i_v_p, b"glGetInteger64i_v\0"),
                 target,
                 index,
                 data,
@@ -16484,7 +17972,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetInteger64i_v_is_loaded(&self) -> bool {
-            !self.glGetInteger64i_v_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetInteger64i_v_p, b"glGetInteger64i_v\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetInteger64v](http://docs.gl/gl4/glGet)(pname, data)
         /// * `pname` group: GetPName
@@ -16496,7 +17987,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.GetInteger64v({:#X}, {:p});", pname, data);
             }
-            let out = call_atomic_ptr_2arg("glGetInteger64v", &self.glGetInteger64v_p, pname, data);
+            let out = call_atomic_ptr_2arg(
+                "glGetInteger64v",
+                self.lazy(&self.glGetInteger64v_p, b"glGetInteger64v\0"),
+                pname,
+                data,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glGetInteger64v");
@@ -16517,7 +18013,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetInteger64v_is_loaded(&self) -> bool {
-            !self.glGetInteger64v_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetInteger64v_p, b"glGetInteger64v\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetInteger64vEXT](http://docs.gl/gl4/glGetInteger64vEXT)(pname, data)
         /// * `pname` group: GetPName
@@ -16532,7 +18031,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glGetInteger64vEXT",
-                &self.glGetInteger64vEXT_p,
+                self.lazy(&self.glGetInteger64vEXT_p, b"glGetInteger64vEXT\0"),
                 pname,
                 data,
             );
@@ -16556,7 +18055,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetInteger64vEXT_is_loaded(&self) -> bool {
-            !self.glGetInteger64vEXT_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetInteger64vEXT_p, b"glGetInteger64vEXT\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetIntegerIndexedvEXT](http://docs.gl/gl4/glGetIntegerIndexedvEXT)(target, index, data)
         /// * `target` group: GetPName
@@ -16581,7 +18083,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetIntegerIndexedvEXT",
-                &self.glGetIntegerIndexedvEXT_p,
+                self.lazy(
+                    &self.glGetIntegerIndexedvEXT_p,
+                    b"glGetIntegerIndexedvEXT\0",
+                ),
                 target,
                 index,
                 data,
@@ -16606,7 +18111,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetIntegerIndexedvEXT_is_loaded(&self) -> bool {
-            !self.glGetIntegerIndexedvEXT_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetIntegerIndexedvEXT_p,
+                    b"glGetIntegerIndexedvEXT\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetIntegeri_v](http://docs.gl/gl4/glGet)(target, index, data)
         /// * `target` group: GetPName
@@ -16625,7 +18136,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetIntegeri_v",
-                &self.glGetIntegeri_v_p,
+                self.lazy(&self.glGetIntegeri_v_p, b"glGetIntegeri_v\0"),
                 target,
                 index,
                 data,
@@ -16650,7 +18161,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetIntegeri_v_is_loaded(&self) -> bool {
-            !self.glGetIntegeri_v_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetIntegeri_v_p, b"glGetIntegeri_v\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetIntegerv](http://docs.gl/gl4/glGet)(pname, data)
         /// * `pname` group: GetPName
@@ -16662,7 +18176,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.GetIntegerv({:#X}, {:p});", pname, data);
             }
-            let out = call_atomic_ptr_2arg("glGetIntegerv", &self.glGetIntegerv_p, pname, data);
+            let out = call_atomic_ptr_2arg(
+                "glGetIntegerv",
+                self.lazy(&self.glGetIntegerv_p, b"glGetIntegerv\0"),
+                pname,
+                data,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glGetIntegerv");
@@ -16679,7 +18198,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetIntegerv_is_loaded(&self) -> bool {
-            !self.glGetIntegerv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetIntegerv_p, b"glGetIntegerv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetInternalformati64v](http://docs.gl/gl4/glGetInternalformat)(target, internalformat, pname, count, params)
         /// * `target` group: TextureTarget
@@ -16709,7 +18231,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glGetInternalformati64v",
-                &self.glGetInternalformati64v_p,
+                self.lazy(
+                    &self.glGetInternalformati64v_p,
+                    b"glGetInternalformati64v\0",
+                ),
                 target,
                 internalformat,
                 pname,
@@ -16736,7 +18261,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetInternalformati64v_is_loaded(&self) -> bool {
-            !self.glGetInternalformati64v_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetInternalformati64v_p,
+                    b"glGetInternalformati64v\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetInternalformativ](http://docs.gl/gl4/glGetInternalformativ)(target, internalformat, pname, count, params)
         /// * `target` group: TextureTarget
@@ -16766,7 +18297,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glGetInternalformativ",
-                &self.glGetInternalformativ_p,
+                self.lazy(&self.glGetInternalformativ_p, b"glGetInternalformativ\0"),
                 target,
                 internalformat,
                 pname,
@@ -16793,7 +18324,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetInternalformativ_is_loaded(&self) -> bool {
-            !self.glGetInternalformativ_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetInternalformativ_p, b"glGetInternalformativ\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetMultisamplefv](http://docs.gl/gl4/glGetMultisample)(pname, index, val)
         /// * `pname` group: GetMultisamplePNameNV
@@ -16812,7 +18346,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetMultisamplefv",
-                &self.glGetMultisamplefv_p,
+                self.lazy(&self.glGetMultisamplefv_p, b"glGetMultisamplefv\0"),
                 pname,
                 index,
                 val,
@@ -16837,7 +18371,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetMultisamplefv_is_loaded(&self) -> bool {
-            !self.glGetMultisamplefv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetMultisamplefv_p, b"glGetMultisamplefv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetNamedBufferParameteri64v](http://docs.gl/gl4/glGetNamedBufferParameter)(buffer, pname, params)
         /// * `pname` group: BufferPNameARB
@@ -16860,7 +18397,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetNamedBufferParameteri64v",
-                &self.glGetNamedBufferParameteri64v_p,
+                self.lazy(
+                    &self.glGetNamedBufferParameteri64v_p,
+                    b"glGetNamedBufferParameteri64v\0",
+                ),
                 buffer,
                 pname,
                 params,
@@ -16885,7 +18425,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetNamedBufferParameteri64v_is_loaded(&self) -> bool {
-            !self.glGetNamedBufferParameteri64v_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetNamedBufferParameteri64v_p,
+                    b"glGetNamedBufferParameteri64v\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetNamedBufferParameteriv](http://docs.gl/gl4/glGetNamedBufferParameter)(buffer, pname, params)
         /// * `pname` group: BufferPNameARB
@@ -16908,7 +18454,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetNamedBufferParameteriv",
-                &self.glGetNamedBufferParameteriv_p,
+                self.lazy(
+                    &self.glGetNamedBufferParameteriv_p,
+                    b"glGetNamedBufferParameteriv\0",
+                ),
                 buffer,
                 pname,
                 params,
@@ -16933,7 +18482,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetNamedBufferParameteriv_is_loaded(&self) -> bool {
-            !self.glGetNamedBufferParameteriv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetNamedBufferParameteriv_p,
+                    b"glGetNamedBufferParameteriv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetNamedBufferPointerv](http://docs.gl/gl4/glGetNamedBufferPointerv)(buffer, pname, params)
         /// * `pname` group: BufferPointerNameARB
@@ -16956,7 +18511,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetNamedBufferPointerv",
-                &self.glGetNamedBufferPointerv_p,
+                self.lazy(
+                    &self.glGetNamedBufferPointerv_p,
+                    b"glGetNamedBufferPointerv\0",
+                ),
                 buffer,
                 pname,
                 params,
@@ -16981,7 +18539,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetNamedBufferPointerv_is_loaded(&self) -> bool {
-            !self.glGetNamedBufferPointerv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetNamedBufferPointerv_p,
+                    b"glGetNamedBufferPointerv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetNamedBufferSubData](http://docs.gl/gl4/glGetNamedBufferSubData)(buffer, offset, size, data)
         /// * `size` group: BufferSize
@@ -17006,7 +18570,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetNamedBufferSubData",
-                &self.glGetNamedBufferSubData_p,
+                self.lazy(
+                    &self.glGetNamedBufferSubData_p,
+                    b"glGetNamedBufferSubData\0",
+                ),
                 buffer,
                 offset,
                 size,
@@ -17032,7 +18599,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetNamedBufferSubData_is_loaded(&self) -> bool {
-            !self.glGetNamedBufferSubData_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetNamedBufferSubData_p,
+                    b"glGetNamedBufferSubData\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetNamedFramebufferAttachmentParameteriv](http://docs.gl/gl4/glGetNamedFramebufferAttachmentParameter)(framebuffer, attachment, pname, params)
         /// * `attachment` group: FramebufferAttachment
@@ -17052,7 +18625,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetNamedFramebufferAttachmentParameteriv",
-                &self.glGetNamedFramebufferAttachmentParameteriv_p,
+                self.lazy(
+                    &self.glGetNamedFramebufferAttachmentParameteriv_p,
+                    b"glGetNamedFramebufferAttachmentParameteriv\0",
+                ),
                 framebuffer,
                 attachment,
                 pname,
@@ -17079,7 +18655,10 @@ pub mod struct_commands {
         #[doc(hidden)]
         pub fn GetNamedFramebufferAttachmentParameteriv_is_loaded(&self) -> bool {
             !self
-                .glGetNamedFramebufferAttachmentParameteriv_p
+                .lazy(
+                    &self.glGetNamedFramebufferAttachmentParameteriv_p,
+                    b"glGetNamedFramebufferAttachmentParameteriv\0",
+                )
                 .load(RELAX)
                 .is_null()
         }
@@ -17104,7 +18683,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetNamedFramebufferParameteriv",
-                &self.glGetNamedFramebufferParameteriv_p,
+                self.lazy(
+                    &self.glGetNamedFramebufferParameteriv_p,
+                    b"glGetNamedFramebufferParameteriv\0",
+                ),
                 framebuffer,
                 pname,
                 param,
@@ -17130,7 +18712,10 @@ pub mod struct_commands {
         #[doc(hidden)]
         pub fn GetNamedFramebufferParameteriv_is_loaded(&self) -> bool {
             !self
-                .glGetNamedFramebufferParameteriv_p
+                .lazy(
+                    &self.glGetNamedFramebufferParameteriv_p,
+                    b"glGetNamedFramebufferParameteriv\0",
+                )
                 .load(RELAX)
                 .is_null()
         }
@@ -17155,7 +18740,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetNamedRenderbufferParameteriv",
-                &self.glGetNamedRenderbufferParameteriv_p,
+                self.lazy(
+                    &self.glGetNamedRenderbufferParameteriv_p,
+                    b"glGetNamedRenderbufferParameteriv\0",
+                ),
                 renderbuffer,
                 pname,
                 params,
@@ -17181,7 +18769,10 @@ pub mod struct_commands {
         #[doc(hidden)]
         pub fn GetNamedRenderbufferParameteriv_is_loaded(&self) -> bool {
             !self
-                .glGetNamedRenderbufferParameteriv_p
+                .lazy(
+                    &self.glGetNamedRenderbufferParameteriv_p,
+                    b"glGetNamedRenderbufferParameteriv\0",
+                )
                 .load(RELAX)
                 .is_null()
         }
@@ -17212,7 +18803,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glGetObjectLabel",
-                &self.glGetObjectLabel_p,
+                self.lazy(&self.glGetObjectLabel_p, b"glGetObjectLabel\0"),
                 identifier,
                 name,
                 bufSize,
@@ -17239,7 +18830,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetObjectLabel_is_loaded(&self) -> bool {
-            !self.glGetObjectLabel_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetObjectLabel_p, b"glGetObjectLabel\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetObjectLabelKHR](http://docs.gl/gl4/glGetObjectLabelKHR)(identifier, name, bufSize, length, label)
         /// * `label` len: bufSize
@@ -17267,7 +18861,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glGetObjectLabelKHR",
-                &self.glGetObjectLabelKHR_p,
+                self.lazy(&self.glGetObjectLabelKHR_p, b"glGetObjectLabelKHR\0"),
                 identifier,
                 name,
                 bufSize,
@@ -17294,7 +18888,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetObjectLabelKHR_is_loaded(&self) -> bool {
-            !self.glGetObjectLabelKHR_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetObjectLabelKHR_p, b"glGetObjectLabelKHR\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetObjectPtrLabel](http://docs.gl/gl4/glGetObjectPtrLabel)(ptr, bufSize, length, label)
         /// * `length` len: 1
@@ -17320,7 +18917,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetObjectPtrLabel",
-                &self.glGetObjectPtrLabel_p,
+                self.lazy(&self.glGetObjectPtrLabel_p, b"glGetObjectPtrLabel\0"),
                 ptr,
                 bufSize,
                 length,
@@ -17346,7 +18943,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetObjectPtrLabel_is_loaded(&self) -> bool {
-            !self.glGetObjectPtrLabel_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetObjectPtrLabel_p, b"glGetObjectPtrLabel\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetObjectPtrLabelKHR](http://docs.gl/gl4/glGetObjectPtrLabelKHR)(ptr, bufSize, length, label)
         /// * `length` len: 1
@@ -17373,7 +18973,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetObjectPtrLabelKHR",
-                &self.glGetObjectPtrLabelKHR_p,
+                self.lazy(&self.glGetObjectPtrLabelKHR_p, b"glGetObjectPtrLabelKHR\0"),
                 ptr,
                 bufSize,
                 length,
@@ -17399,7 +18999,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetObjectPtrLabelKHR_is_loaded(&self) -> bool {
-            !self.glGetObjectPtrLabelKHR_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetObjectPtrLabelKHR_p, b"glGetObjectPtrLabelKHR\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetPointerv](http://docs.gl/gl4/glGetPointerv)(pname, params)
         /// * `pname` group: GetPointervPName
@@ -17411,7 +19014,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.GetPointerv({:#X}, {:p});", pname, params);
             }
-            let out = call_atomic_ptr_2arg("glGetPointerv", &self.glGetPointerv_p, pname, params);
+            let out = call_atomic_ptr_2arg(
+                "glGetPointerv",
+                self.lazy(&self.glGetPointerv_p, b"glGetPointerv\0"),
+                pname,
+                params,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glGetPointerv");
@@ -17428,7 +19036,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetPointerv_is_loaded(&self) -> bool {
-            !self.glGetPointerv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetPointerv_p, b"glGetPointerv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetPointervKHR](http://docs.gl/gl4/glGetPointervKHR)(pname, params)
         /// * alias of: [`glGetPointerv`]
@@ -17439,8 +19050,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.GetPointervKHR({:#X}, {:p});", pname, params);
             }
-            let out =
-                call_atomic_ptr_2arg("glGetPointervKHR", &self.glGetPointervKHR_p, pname, params);
+            let out = call_atomic_ptr_2arg(
+                "glGetPointervKHR",
+                self.lazy(&self.glGetPointervKHR_p, b"glGetPointervKHR\0"),
+                pname,
+                params,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glGetPointervKHR");
@@ -17461,7 +19076,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetPointervKHR_is_loaded(&self) -> bool {
-            !self.glGetPointervKHR_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetPointervKHR_p, b"glGetPointervKHR\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetProgramBinary](http://docs.gl/gl4/glGetProgramBinary)(program, bufSize, length, binaryFormat, binary)
         /// * `length` len: 1
@@ -17490,7 +19108,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glGetProgramBinary",
-                &self.glGetProgramBinary_p,
+                self.lazy(&self.glGetProgramBinary_p, b"glGetProgramBinary\0"),
                 program,
                 bufSize,
                 length,
@@ -17517,7 +19135,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetProgramBinary_is_loaded(&self) -> bool {
-            !self.glGetProgramBinary_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetProgramBinary_p, b"glGetProgramBinary\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetProgramInfoLog](http://docs.gl/gl4/glGetProgramInfoLog)(program, bufSize, length, infoLog)
         /// * `length` len: 1
@@ -17543,7 +19164,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetProgramInfoLog",
-                &self.glGetProgramInfoLog_p,
+                self.lazy(&self.glGetProgramInfoLog_p, b"glGetProgramInfoLog\0"),
                 program,
                 bufSize,
                 length,
@@ -17569,7 +19190,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetProgramInfoLog_is_loaded(&self) -> bool {
-            !self.glGetProgramInfoLog_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetProgramInfoLog_p, b"glGetProgramInfoLog\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetProgramInterfaceiv](http://docs.gl/gl4/glGetProgramInterface)(program, programInterface, pname, params)
         /// * `programInterface` group: ProgramInterface
@@ -17596,7 +19220,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetProgramInterfaceiv",
-                &self.glGetProgramInterfaceiv_p,
+                self.lazy(
+                    &self.glGetProgramInterfaceiv_p,
+                    b"glGetProgramInterfaceiv\0",
+                ),
                 program,
                 programInterface,
                 pname,
@@ -17622,7 +19249,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetProgramInterfaceiv_is_loaded(&self) -> bool {
-            !self.glGetProgramInterfaceiv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetProgramInterfaceiv_p,
+                    b"glGetProgramInterfaceiv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetProgramPipelineInfoLog](http://docs.gl/gl4/glGetProgramPipelineInfoLog)(pipeline, bufSize, length, infoLog)
         /// * `length` len: 1
@@ -17648,7 +19281,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetProgramPipelineInfoLog",
-                &self.glGetProgramPipelineInfoLog_p,
+                self.lazy(
+                    &self.glGetProgramPipelineInfoLog_p,
+                    b"glGetProgramPipelineInfoLog\0",
+                ),
                 pipeline,
                 bufSize,
                 length,
@@ -17674,7 +19310,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetProgramPipelineInfoLog_is_loaded(&self) -> bool {
-            !self.glGetProgramPipelineInfoLog_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetProgramPipelineInfoLog_p,
+                    b"glGetProgramPipelineInfoLog\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetProgramPipelineiv](http://docs.gl/gl4/glGetProgramPipeline)(pipeline, pname, params)
         /// * `pname` group: PipelineParameterName
@@ -17698,7 +19340,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetProgramPipelineiv",
-                &self.glGetProgramPipelineiv_p,
+                self.lazy(&self.glGetProgramPipelineiv_p, b"glGetProgramPipelineiv\0"),
                 pipeline,
                 pname,
                 params,
@@ -17723,7 +19365,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetProgramPipelineiv_is_loaded(&self) -> bool {
-            !self.glGetProgramPipelineiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetProgramPipelineiv_p, b"glGetProgramPipelineiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetProgramResourceIndex](http://docs.gl/gl4/glGetProgramResourceIndex)(program, programInterface, name)
         /// * `programInterface` group: ProgramInterface
@@ -17747,7 +19392,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetProgramResourceIndex",
-                &self.glGetProgramResourceIndex_p,
+                self.lazy(
+                    &self.glGetProgramResourceIndex_p,
+                    b"glGetProgramResourceIndex\0",
+                ),
                 program,
                 programInterface,
                 name,
@@ -17772,7 +19420,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetProgramResourceIndex_is_loaded(&self) -> bool {
-            !self.glGetProgramResourceIndex_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetProgramResourceIndex_p,
+                    b"glGetProgramResourceIndex\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetProgramResourceLocation](http://docs.gl/gl4/glGetProgramResourceLocation)(program, programInterface, name)
         /// * `programInterface` group: ProgramInterface
@@ -17796,7 +19450,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetProgramResourceLocation",
-                &self.glGetProgramResourceLocation_p,
+                self.lazy(
+                    &self.glGetProgramResourceLocation_p,
+                    b"glGetProgramResourceLocation\0",
+                ),
                 program,
                 programInterface,
                 name,
@@ -17821,7 +19478,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetProgramResourceLocation_is_loaded(&self) -> bool {
-            !self.glGetProgramResourceLocation_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetProgramResourceLocation_p,
+                    b"glGetProgramResourceLocation\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetProgramResourceLocationIndex](http://docs.gl/gl4/glGetProgramResourceLocationIndex)(program, programInterface, name)
         /// * `programInterface` group: ProgramInterface
@@ -17845,7 +19508,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetProgramResourceLocationIndex",
-                &self.glGetProgramResourceLocationIndex_p,
+                self.lazy(
+                    &self.glGetProgramResourceLocationIndex_p,
+                    b"glGetProgramResourceLocationIndex\0",
+                ),
                 program,
                 programInterface,
                 name,
@@ -17871,7 +19537,10 @@ pub mod struct_commands {
         #[doc(hidden)]
         pub fn GetProgramResourceLocationIndex_is_loaded(&self) -> bool {
             !self
-                .glGetProgramResourceLocationIndex_p
+                .lazy(
+                    &self.glGetProgramResourceLocationIndex_p,
+                    b"glGetProgramResourceLocationIndex\0",
+                )
                 .load(RELAX)
                 .is_null()
         }
@@ -17904,7 +19573,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glGetProgramResourceName",
-                &self.glGetProgramResourceName_p,
+                self.lazy(
+                    &self.glGetProgramResourceName_p,
+                    b"glGetProgramResourceName\0",
+                ),
                 program,
                 programInterface,
                 index,
@@ -17932,7 +19604,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetProgramResourceName_is_loaded(&self) -> bool {
-            !self.glGetProgramResourceName_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetProgramResourceName_p,
+                    b"glGetProgramResourceName\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetProgramResourceiv](http://docs.gl/gl4/glGetProgramResource)(program, programInterface, index, propCount, props, count, length, params)
         /// * `programInterface` group: ProgramInterface
@@ -17959,7 +19637,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_8arg(
                 "glGetProgramResourceiv",
-                &self.glGetProgramResourceiv_p,
+                self.lazy(&self.glGetProgramResourceiv_p, b"glGetProgramResourceiv\0"),
                 program,
                 programInterface,
                 index,
@@ -17989,7 +19667,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetProgramResourceiv_is_loaded(&self) -> bool {
-            !self.glGetProgramResourceiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetProgramResourceiv_p, b"glGetProgramResourceiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetProgramStageiv](http://docs.gl/gl4/glGetProgramStage)(program, shadertype, pname, values)
         /// * `shadertype` group: ShaderType
@@ -18016,7 +19697,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetProgramStageiv",
-                &self.glGetProgramStageiv_p,
+                self.lazy(&self.glGetProgramStageiv_p, b"glGetProgramStageiv\0"),
                 program,
                 shadertype,
                 pname,
@@ -18042,7 +19723,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetProgramStageiv_is_loaded(&self) -> bool {
-            !self.glGetProgramStageiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetProgramStageiv_p, b"glGetProgramStageiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetProgramiv](http://docs.gl/gl4/glGetProgram)(program, pname, params)
         /// * `pname` group: ProgramPropertyARB
@@ -18061,7 +19745,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetProgramiv",
-                &self.glGetProgramiv_p,
+                self.lazy(&self.glGetProgramiv_p, b"glGetProgramiv\0"),
                 program,
                 pname,
                 params,
@@ -18086,7 +19770,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetProgramiv_is_loaded(&self) -> bool {
-            !self.glGetProgramiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetProgramiv_p, b"glGetProgramiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetQueryBufferObjecti64v](http://docs.gl/gl4/glGetQueryBufferObject)(id, buffer, pname, offset)
         /// * `pname` group: QueryObjectParameterName
@@ -18111,7 +19798,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetQueryBufferObjecti64v",
-                &self.glGetQueryBufferObjecti64v_p,
+                self.lazy(
+                    &self.glGetQueryBufferObjecti64v_p,
+                    b"glGetQueryBufferObjecti64v\0",
+                ),
                 id,
                 buffer,
                 pname,
@@ -18137,7 +19827,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetQueryBufferObjecti64v_is_loaded(&self) -> bool {
-            !self.glGetQueryBufferObjecti64v_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetQueryBufferObjecti64v_p,
+                    b"glGetQueryBufferObjecti64v\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetQueryBufferObjectiv](http://docs.gl/gl4/glGetQueryBufferObject)(id, buffer, pname, offset)
         /// * `pname` group: QueryObjectParameterName
@@ -18162,7 +19858,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetQueryBufferObjectiv",
-                &self.glGetQueryBufferObjectiv_p,
+                self.lazy(
+                    &self.glGetQueryBufferObjectiv_p,
+                    b"glGetQueryBufferObjectiv\0",
+                ),
                 id,
                 buffer,
                 pname,
@@ -18188,7 +19887,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetQueryBufferObjectiv_is_loaded(&self) -> bool {
-            !self.glGetQueryBufferObjectiv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetQueryBufferObjectiv_p,
+                    b"glGetQueryBufferObjectiv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetQueryBufferObjectui64v](http://docs.gl/gl4/glGetQueryBufferObjectu)(id, buffer, pname, offset)
         /// * `pname` group: QueryObjectParameterName
@@ -18213,7 +19918,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetQueryBufferObjectui64v",
-                &self.glGetQueryBufferObjectui64v_p,
+                self.lazy(
+                    &self.glGetQueryBufferObjectui64v_p,
+                    b"glGetQueryBufferObjectui64v\0",
+                ),
                 id,
                 buffer,
                 pname,
@@ -18239,7 +19947,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetQueryBufferObjectui64v_is_loaded(&self) -> bool {
-            !self.glGetQueryBufferObjectui64v_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetQueryBufferObjectui64v_p,
+                    b"glGetQueryBufferObjectui64v\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetQueryBufferObjectuiv](http://docs.gl/gl4/glGetQueryBufferObject)(id, buffer, pname, offset)
         /// * `pname` group: QueryObjectParameterName
@@ -18264,7 +19978,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetQueryBufferObjectuiv",
-                &self.glGetQueryBufferObjectuiv_p,
+                self.lazy(
+                    &self.glGetQueryBufferObjectuiv_p,
+                    b"glGetQueryBufferObjectuiv\0",
+                ),
                 id,
                 buffer,
                 pname,
@@ -18290,7 +20007,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetQueryBufferObjectuiv_is_loaded(&self) -> bool {
-            !self.glGetQueryBufferObjectuiv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetQueryBufferObjectuiv_p,
+                    b"glGetQueryBufferObjectuiv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetQueryIndexediv](http://docs.gl/gl4/glGetQueryIndexed)(target, index, pname, params)
         /// * `target` group: QueryTarget
@@ -18317,7 +20040,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetQueryIndexediv",
-                &self.glGetQueryIndexediv_p,
+                self.lazy(&self.glGetQueryIndexediv_p, b"glGetQueryIndexediv\0"),
                 target,
                 index,
                 pname,
@@ -18343,7 +20066,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetQueryIndexediv_is_loaded(&self) -> bool {
-            !self.glGetQueryIndexediv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetQueryIndexediv_p, b"glGetQueryIndexediv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetQueryObjecti64v](http://docs.gl/gl4/glGetQueryObject)(id, pname, params)
         /// * `pname` group: QueryObjectParameterName
@@ -18362,7 +20088,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetQueryObjecti64v",
-                &self.glGetQueryObjecti64v_p,
+                self.lazy(&self.glGetQueryObjecti64v_p, b"glGetQueryObjecti64v\0"),
                 id,
                 pname,
                 params,
@@ -18387,7 +20113,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetQueryObjecti64v_is_loaded(&self) -> bool {
-            !self.glGetQueryObjecti64v_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetQueryObjecti64v_p, b"glGetQueryObjecti64v\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetQueryObjecti64vEXT](http://docs.gl/gl4/glGetQueryObjecti64vEXT)(id, pname, params)
         /// * `pname` group: QueryObjectParameterName
@@ -18412,7 +20141,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetQueryObjecti64vEXT",
-                &self.glGetQueryObjecti64vEXT_p,
+                self.lazy(
+                    &self.glGetQueryObjecti64vEXT_p,
+                    b"glGetQueryObjecti64vEXT\0",
+                ),
                 id,
                 pname,
                 params,
@@ -18437,7 +20169,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetQueryObjecti64vEXT_is_loaded(&self) -> bool {
-            !self.glGetQueryObjecti64vEXT_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetQueryObjecti64vEXT_p,
+                    b"glGetQueryObjecti64vEXT\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetQueryObjectiv](http://docs.gl/gl4/glGetQueryObject)(id, pname, params)
         /// * `pname` group: QueryObjectParameterName
@@ -18456,7 +20194,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetQueryObjectiv",
-                &self.glGetQueryObjectiv_p,
+                self.lazy(&self.glGetQueryObjectiv_p, b"glGetQueryObjectiv\0"),
                 id,
                 pname,
                 params,
@@ -18481,7 +20219,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetQueryObjectiv_is_loaded(&self) -> bool {
-            !self.glGetQueryObjectiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetQueryObjectiv_p, b"glGetQueryObjectiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetQueryObjectivEXT](http://docs.gl/gl4/glGetQueryObjectivEXT)(id, pname, params)
         /// * `pname` group: QueryObjectParameterName
@@ -18501,7 +20242,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetQueryObjectivEXT",
-                &self.glGetQueryObjectivEXT_p,
+                self.lazy(&self.glGetQueryObjectivEXT_p, b"glGetQueryObjectivEXT\0"),
                 id,
                 pname,
                 params,
@@ -18526,7 +20267,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetQueryObjectivEXT_is_loaded(&self) -> bool {
-            !self.glGetQueryObjectivEXT_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetQueryObjectivEXT_p, b"glGetQueryObjectivEXT\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetQueryObjectui64v](http://docs.gl/gl4/glGetQueryObjectu)(id, pname, params)
         /// * `pname` group: QueryObjectParameterName
@@ -18545,7 +20289,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetQueryObjectui64v",
-                &self.glGetQueryObjectui64v_p,
+                self.lazy(&self.glGetQueryObjectui64v_p, b"glGetQueryObjectui64v\0"),
                 id,
                 pname,
                 params,
@@ -18570,7 +20314,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetQueryObjectui64v_is_loaded(&self) -> bool {
-            !self.glGetQueryObjectui64v_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetQueryObjectui64v_p, b"glGetQueryObjectui64v\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetQueryObjectui64vEXT](http://docs.gl/gl4/glGetQueryObjectui64vEXT)(id, pname, params)
         /// * `pname` group: QueryObjectParameterName
@@ -18595,7 +20342,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetQueryObjectui64vEXT",
-                &self.glGetQueryObjectui64vEXT_p,
+                self.lazy(
+                    &self.glGetQueryObjectui64vEXT_p,
+                    b"glGetQueryObjectui64vEXT\0",
+                ),
                 id,
                 pname,
                 params,
@@ -18620,7 +20370,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetQueryObjectui64vEXT_is_loaded(&self) -> bool {
-            !self.glGetQueryObjectui64vEXT_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetQueryObjectui64vEXT_p,
+                    b"glGetQueryObjectui64vEXT\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetQueryObjectuiv](http://docs.gl/gl4/glGetQueryObject)(id, pname, params)
         /// * `pname` group: QueryObjectParameterName
@@ -18639,7 +20395,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetQueryObjectuiv",
-                &self.glGetQueryObjectuiv_p,
+                self.lazy(&self.glGetQueryObjectuiv_p, b"glGetQueryObjectuiv\0"),
                 id,
                 pname,
                 params,
@@ -18664,7 +20420,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetQueryObjectuiv_is_loaded(&self) -> bool {
-            !self.glGetQueryObjectuiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetQueryObjectuiv_p, b"glGetQueryObjectuiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetQueryObjectuivEXT](http://docs.gl/gl4/glGetQueryObjectuivEXT)(id, pname, params)
         /// * `pname` group: QueryObjectParameterName
@@ -18683,7 +20442,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetQueryObjectuivEXT",
-                &self.glGetQueryObjectuivEXT_p,
+                self.lazy(&self.glGetQueryObjectuivEXT_p, b"glGetQueryObjectuivEXT\0"),
                 id,
                 pname,
                 params,
@@ -18708,7 +20467,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetQueryObjectuivEXT_is_loaded(&self) -> bool {
-            !self.glGetQueryObjectuivEXT_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetQueryObjectuivEXT_p, b"glGetQueryObjectuivEXT\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetQueryiv](http://docs.gl/gl4/glGetQuery)(target, pname, params)
         /// * `target` group: QueryTarget
@@ -18726,8 +20488,13 @@ pub mod struct_commands {
                     params
                 );
             }
-            let out =
-                call_atomic_ptr_3arg("glGetQueryiv", &self.glGetQueryiv_p, target, pname, params);
+            let out = call_atomic_ptr_3arg(
+                "glGetQueryiv",
+                self.lazy(&self.glGetQueryiv_p, b"glGetQueryiv\0"),
+                target,
+                pname,
+                params,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glGetQueryiv");
@@ -18744,7 +20511,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetQueryiv_is_loaded(&self) -> bool {
-            !self.glGetQueryiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetQueryiv_p, b"glGetQueryiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetQueryivEXT](http://docs.gl/gl4/glGetQueryivEXT)(target, pname, params)
         /// * `target` group: QueryTarget
@@ -18764,7 +20534,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetQueryivEXT",
-                &self.glGetQueryivEXT_p,
+                self.lazy(&self.glGetQueryivEXT_p, b"glGetQueryivEXT\0"),
                 target,
                 pname,
                 params,
@@ -18789,7 +20559,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetQueryivEXT_is_loaded(&self) -> bool {
-            !self.glGetQueryivEXT_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetQueryivEXT_p, b"glGetQueryivEXT\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetRenderbufferParameteriv](http://docs.gl/gl4/glGetRenderbufferParameter)(target, pname, params)
         /// * `target` group: RenderbufferTarget
@@ -18814,7 +20587,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetRenderbufferParameteriv",
-                &self.glGetRenderbufferParameteriv_p,
+                self.lazy(
+                    &self.glGetRenderbufferParameteriv_p,
+                    b"glGetRenderbufferParameteriv\0",
+                ),
                 target,
                 pname,
                 params,
@@ -18839,7 +20615,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetRenderbufferParameteriv_is_loaded(&self) -> bool {
-            !self.glGetRenderbufferParameteriv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetRenderbufferParameteriv_p,
+                    b"glGetRenderbufferParameteriv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetSamplerParameterIiv](http://docs.gl/gl4/glGetSamplerParameter)(sampler, pname, params)
         /// * `pname` group: SamplerParameterI
@@ -18863,7 +20645,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetSamplerParameterIiv",
-                &self.glGetSamplerParameterIiv_p,
+                self.lazy(
+                    &self.glGetSamplerParameterIiv_p,
+                    b"glGetSamplerParameterIiv\0",
+                ),
                 sampler,
                 pname,
                 params,
@@ -18888,7 +20673,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetSamplerParameterIiv_is_loaded(&self) -> bool {
-            !self.glGetSamplerParameterIiv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetSamplerParameterIiv_p,
+                    b"glGetSamplerParameterIiv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetSamplerParameterIuiv](http://docs.gl/gl4/glGetSamplerParameter)(sampler, pname, params)
         /// * `pname` group: SamplerParameterI
@@ -18912,7 +20703,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetSamplerParameterIuiv",
-                &self.glGetSamplerParameterIuiv_p,
+                self.lazy(
+                    &self.glGetSamplerParameterIuiv_p,
+                    b"glGetSamplerParameterIuiv\0",
+                ),
                 sampler,
                 pname,
                 params,
@@ -18937,7 +20731,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetSamplerParameterIuiv_is_loaded(&self) -> bool {
-            !self.glGetSamplerParameterIuiv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetSamplerParameterIuiv_p,
+                    b"glGetSamplerParameterIuiv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetSamplerParameterfv](http://docs.gl/gl4/glGetSamplerParameter)(sampler, pname, params)
         /// * `pname` group: SamplerParameterF
@@ -18961,7 +20761,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetSamplerParameterfv",
-                &self.glGetSamplerParameterfv_p,
+                self.lazy(
+                    &self.glGetSamplerParameterfv_p,
+                    b"glGetSamplerParameterfv\0",
+                ),
                 sampler,
                 pname,
                 params,
@@ -18986,7 +20789,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetSamplerParameterfv_is_loaded(&self) -> bool {
-            !self.glGetSamplerParameterfv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetSamplerParameterfv_p,
+                    b"glGetSamplerParameterfv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetSamplerParameteriv](http://docs.gl/gl4/glGetSamplerParameter)(sampler, pname, params)
         /// * `pname` group: SamplerParameterI
@@ -19010,7 +20819,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetSamplerParameteriv",
-                &self.glGetSamplerParameteriv_p,
+                self.lazy(
+                    &self.glGetSamplerParameteriv_p,
+                    b"glGetSamplerParameteriv\0",
+                ),
                 sampler,
                 pname,
                 params,
@@ -19035,7 +20847,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetSamplerParameteriv_is_loaded(&self) -> bool {
-            !self.glGetSamplerParameteriv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetSamplerParameteriv_p,
+                    b"glGetSamplerParameteriv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetShaderInfoLog](http://docs.gl/gl4/glGetShaderInfoLog)(shader, bufSize, length, infoLog)
         /// * `length` len: 1
@@ -19061,7 +20879,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetShaderInfoLog",
-                &self.glGetShaderInfoLog_p,
+                self.lazy(&self.glGetShaderInfoLog_p, b"glGetShaderInfoLog\0"),
                 shader,
                 bufSize,
                 length,
@@ -19087,7 +20905,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetShaderInfoLog_is_loaded(&self) -> bool {
-            !self.glGetShaderInfoLog_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetShaderInfoLog_p, b"glGetShaderInfoLog\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetShaderPrecisionFormat](http://docs.gl/gl4/glGetShaderPrecisionFormat)(shadertype, precisiontype, range, precision)
         /// * `shadertype` group: ShaderType
@@ -19115,7 +20936,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetShaderPrecisionFormat",
-                &self.glGetShaderPrecisionFormat_p,
+                self.lazy(
+                    &self.glGetShaderPrecisionFormat_p,
+                    b"glGetShaderPrecisionFormat\0",
+                ),
                 shadertype,
                 precisiontype,
                 range,
@@ -19141,7 +20965,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetShaderPrecisionFormat_is_loaded(&self) -> bool {
-            !self.glGetShaderPrecisionFormat_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetShaderPrecisionFormat_p,
+                    b"glGetShaderPrecisionFormat\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetShaderSource](http://docs.gl/gl4/glGetShaderSource)(shader, bufSize, length, source)
         /// * `length` len: 1
@@ -19167,7 +20997,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetShaderSource",
-                &self.glGetShaderSource_p,
+                self.lazy(&self.glGetShaderSource_p, b"glGetShaderSource\0"),
                 shader,
                 bufSize,
                 length,
@@ -19193,7 +21023,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetShaderSource_is_loaded(&self) -> bool {
-            !self.glGetShaderSource_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetShaderSource_p, b"glGetShaderSource\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetShaderiv](http://docs.gl/gl4/glGetShaderiv)(shader, pname, params)
         /// * `pname` group: ShaderParameterName
@@ -19212,7 +21045,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetShaderiv",
-                &self.glGetShaderiv_p,
+                self.lazy(&self.glGetShaderiv_p, b"glGetShaderiv\0"),
                 shader,
                 pname,
                 params,
@@ -19233,7 +21066,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetShaderiv_is_loaded(&self) -> bool {
-            !self.glGetShaderiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetShaderiv_p, b"glGetShaderiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetString](http://docs.gl/gl4/glGetString)(name)
         /// * `name` group: StringName
@@ -19245,7 +21081,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.GetString({:#X});", name);
             }
-            let out = call_atomic_ptr_1arg("glGetString", &self.glGetString_p, name);
+            let out = call_atomic_ptr_1arg(
+                "glGetString",
+                self.lazy(&self.glGetString_p, b"glGetString\0"),
+                name,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glGetString");
@@ -19262,7 +21102,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetString_is_loaded(&self) -> bool {
-            !self.glGetString_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetString_p, b"glGetString\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetStringi](http://docs.gl/gl4/glGetString)(name, index)
         /// * `name` group: StringName
@@ -19274,7 +21117,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.GetStringi({:#X}, {:?});", name, index);
             }
-            let out = call_atomic_ptr_2arg("glGetStringi", &self.glGetStringi_p, name, index);
+            let out = call_atomic_ptr_2arg(
+                "glGetStringi",
+                self.lazy(&self.glGetStringi_p, b"glGetStringi\0"),
+                name,
+                index,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glGetStringi");
@@ -19291,7 +21139,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetStringi_is_loaded(&self) -> bool {
-            !self.glGetStringi_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetStringi_p, b"glGetStringi\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetSubroutineIndex](http://docs.gl/gl4/glGetSubroutineIndex)(program, shadertype, name)
         /// * `shadertype` group: ShaderType
@@ -19314,7 +21165,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetSubroutineIndex",
-                &self.glGetSubroutineIndex_p,
+                self.lazy(&self.glGetSubroutineIndex_p, b"glGetSubroutineIndex\0"),
                 program,
                 shadertype,
                 name,
@@ -19339,7 +21190,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetSubroutineIndex_is_loaded(&self) -> bool {
-            !self.glGetSubroutineIndex_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetSubroutineIndex_p, b"glGetSubroutineIndex\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetSubroutineUniformLocation](http://docs.gl/gl4/glGetSubroutineUniformLocation)(program, shadertype, name)
         /// * `shadertype` group: ShaderType
@@ -19362,7 +21216,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetSubroutineUniformLocation",
-                &self.glGetSubroutineUniformLocation_p,
+                self.lazy(
+                    &self.glGetSubroutineUniformLocation_p,
+                    b"glGetSubroutineUniformLocation\0",
+                ),
                 program,
                 shadertype,
                 name,
@@ -19387,7 +21244,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetSubroutineUniformLocation_is_loaded(&self) -> bool {
-            !self.glGetSubroutineUniformLocation_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetSubroutineUniformLocation_p,
+                    b"glGetSubroutineUniformLocation\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetSynciv](http://docs.gl/gl4/glGetSync)(sync, pname, count, length, values)
         /// * `sync` group: sync
@@ -19417,7 +21280,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glGetSynciv",
-                &self.glGetSynciv_p,
+                self.lazy(&self.glGetSynciv_p, b"glGetSynciv\0"),
                 sync,
                 pname,
                 count,
@@ -19440,7 +21303,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetSynciv_is_loaded(&self) -> bool {
-            !self.glGetSynciv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetSynciv_p, b"glGetSynciv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetTexImage](http://docs.gl/gl4/glGetTexImage)(target, level, format, type_, pixels)
         /// * `target` group: TextureTarget
@@ -19471,7 +21337,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glGetTexImage",
-                &self.glGetTexImage_p,
+                self.lazy(&self.glGetTexImage_p, b"glGetTexImage\0"),
                 target,
                 level,
                 format,
@@ -19494,7 +21360,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetTexImage_is_loaded(&self) -> bool {
-            !self.glGetTexImage_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetTexImage_p, b"glGetTexImage\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetTexLevelParameterfv](http://docs.gl/gl4/glGetTexLevelParameter)(target, level, pname, params)
         /// * `target` group: TextureTarget
@@ -19522,7 +21391,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetTexLevelParameterfv",
-                &self.glGetTexLevelParameterfv_p,
+                self.lazy(
+                    &self.glGetTexLevelParameterfv_p,
+                    b"glGetTexLevelParameterfv\0",
+                ),
                 target,
                 level,
                 pname,
@@ -19548,7 +21420,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetTexLevelParameterfv_is_loaded(&self) -> bool {
-            !self.glGetTexLevelParameterfv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetTexLevelParameterfv_p,
+                    b"glGetTexLevelParameterfv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetTexLevelParameteriv](http://docs.gl/gl4/glGetTexLevelParameter)(target, level, pname, params)
         /// * `target` group: TextureTarget
@@ -19576,7 +21454,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetTexLevelParameteriv",
-                &self.glGetTexLevelParameteriv_p,
+                self.lazy(
+                    &self.glGetTexLevelParameteriv_p,
+                    b"glGetTexLevelParameteriv\0",
+                ),
                 target,
                 level,
                 pname,
@@ -19602,7 +21483,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetTexLevelParameteriv_is_loaded(&self) -> bool {
-            !self.glGetTexLevelParameteriv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetTexLevelParameteriv_p,
+                    b"glGetTexLevelParameteriv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetTexParameterIiv](http://docs.gl/gl4/glGetTexParameter)(target, pname, params)
         /// * `target` group: TextureTarget
@@ -19622,7 +21509,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetTexParameterIiv",
-                &self.glGetTexParameterIiv_p,
+                self.lazy(&self.glGetTexParameterIiv_p, b"glGetTexParameterIiv\0"),
                 target,
                 pname,
                 params,
@@ -19647,7 +21534,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetTexParameterIiv_is_loaded(&self) -> bool {
-            !self.glGetTexParameterIiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetTexParameterIiv_p, b"glGetTexParameterIiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetTexParameterIuiv](http://docs.gl/gl4/glGetTexParameter)(target, pname, params)
         /// * `target` group: TextureTarget
@@ -19672,7 +21562,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetTexParameterIuiv",
-                &self.glGetTexParameterIuiv_p,
+                self.lazy(&self.glGetTexParameterIuiv_p, b"glGetTexParameterIuiv\0"),
                 target,
                 pname,
                 params,
@@ -19697,7 +21587,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetTexParameterIuiv_is_loaded(&self) -> bool {
-            !self.glGetTexParameterIuiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetTexParameterIuiv_p, b"glGetTexParameterIuiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetTexParameterfv](http://docs.gl/gl4/glGetTexParameter)(target, pname, params)
         /// * `target` group: TextureTarget
@@ -19722,7 +21615,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetTexParameterfv",
-                &self.glGetTexParameterfv_p,
+                self.lazy(&self.glGetTexParameterfv_p, b"glGetTexParameterfv\0"),
                 target,
                 pname,
                 params,
@@ -19747,7 +21640,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetTexParameterfv_is_loaded(&self) -> bool {
-            !self.glGetTexParameterfv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetTexParameterfv_p, b"glGetTexParameterfv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetTexParameteriv](http://docs.gl/gl4/glGetTexParameter)(target, pname, params)
         /// * `target` group: TextureTarget
@@ -19767,7 +21663,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetTexParameteriv",
-                &self.glGetTexParameteriv_p,
+                self.lazy(&self.glGetTexParameteriv_p, b"glGetTexParameteriv\0"),
                 target,
                 pname,
                 params,
@@ -19792,7 +21688,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetTexParameteriv_is_loaded(&self) -> bool {
-            !self.glGetTexParameteriv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetTexParameteriv_p, b"glGetTexParameteriv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetTextureImage](http://docs.gl/gl4/glGetTextureImage)(texture, level, format, type_, bufSize, pixels)
         /// * `format` group: PixelFormat
@@ -19822,7 +21721,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glGetTextureImage",
-                &self.glGetTextureImage_p,
+                self.lazy(&self.glGetTextureImage_p, b"glGetTextureImage\0"),
                 texture,
                 level,
                 format,
@@ -19850,7 +21749,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetTextureImage_is_loaded(&self) -> bool {
-            !self.glGetTextureImage_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetTextureImage_p, b"glGetTextureImage\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetTextureLevelParameterfv](http://docs.gl/gl4/glGetTextureLevelParameter)(texture, level, pname, params)
         /// * `pname` group: GetTextureParameter
@@ -19875,7 +21777,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetTextureLevelParameterfv",
-                &self.glGetTextureLevelParameterfv_p,
+                self.lazy(
+                    &self.glGetTextureLevelParameterfv_p,
+                    b"glGetTextureLevelParameterfv\0",
+                ),
                 texture,
                 level,
                 pname,
@@ -19901,7 +21806,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetTextureLevelParameterfv_is_loaded(&self) -> bool {
-            !self.glGetTextureLevelParameterfv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetTextureLevelParameterfv_p,
+                    b"glGetTextureLevelParameterfv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetTextureLevelParameteriv](http://docs.gl/gl4/glGetTextureLevelParameter)(texture, level, pname, params)
         /// * `pname` group: GetTextureParameter
@@ -19926,7 +21837,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetTextureLevelParameteriv",
-                &self.glGetTextureLevelParameteriv_p,
+                self.lazy(
+                    &self.glGetTextureLevelParameteriv_p,
+                    b"glGetTextureLevelParameteriv\0",
+                ),
                 texture,
                 level,
                 pname,
@@ -19952,7 +21866,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetTextureLevelParameteriv_is_loaded(&self) -> bool {
-            !self.glGetTextureLevelParameteriv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetTextureLevelParameteriv_p,
+                    b"glGetTextureLevelParameteriv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetTextureParameterIiv](http://docs.gl/gl4/glGetTextureParameter)(texture, pname, params)
         /// * `pname` group: GetTextureParameter
@@ -19975,7 +21895,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetTextureParameterIiv",
-                &self.glGetTextureParameterIiv_p,
+                self.lazy(
+                    &self.glGetTextureParameterIiv_p,
+                    b"glGetTextureParameterIiv\0",
+                ),
                 texture,
                 pname,
                 params,
@@ -20000,7 +21923,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetTextureParameterIiv_is_loaded(&self) -> bool {
-            !self.glGetTextureParameterIiv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetTextureParameterIiv_p,
+                    b"glGetTextureParameterIiv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetTextureParameterIuiv](http://docs.gl/gl4/glGetTextureParameter)(texture, pname, params)
         /// * `pname` group: GetTextureParameter
@@ -20023,7 +21952,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetTextureParameterIuiv",
-                &self.glGetTextureParameterIuiv_p,
+                self.lazy(
+                    &self.glGetTextureParameterIuiv_p,
+                    b"glGetTextureParameterIuiv\0",
+                ),
                 texture,
                 pname,
                 params,
@@ -20048,7 +21980,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetTextureParameterIuiv_is_loaded(&self) -> bool {
-            !self.glGetTextureParameterIuiv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetTextureParameterIuiv_p,
+                    b"glGetTextureParameterIuiv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetTextureParameterfv](http://docs.gl/gl4/glGetTextureParameter)(texture, pname, params)
         /// * `pname` group: GetTextureParameter
@@ -20071,7 +22009,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetTextureParameterfv",
-                &self.glGetTextureParameterfv_p,
+                self.lazy(
+                    &self.glGetTextureParameterfv_p,
+                    b"glGetTextureParameterfv\0",
+                ),
                 texture,
                 pname,
                 params,
@@ -20096,7 +22037,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetTextureParameterfv_is_loaded(&self) -> bool {
-            !self.glGetTextureParameterfv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetTextureParameterfv_p,
+                    b"glGetTextureParameterfv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetTextureParameteriv](http://docs.gl/gl4/glGetTextureParameter)(texture, pname, params)
         /// * `pname` group: GetTextureParameter
@@ -20119,7 +22066,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetTextureParameteriv",
-                &self.glGetTextureParameteriv_p,
+                self.lazy(
+                    &self.glGetTextureParameteriv_p,
+                    b"glGetTextureParameteriv\0",
+                ),
                 texture,
                 pname,
                 params,
@@ -20144,7 +22094,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetTextureParameteriv_is_loaded(&self) -> bool {
-            !self.glGetTextureParameteriv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetTextureParameteriv_p,
+                    b"glGetTextureParameteriv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetTextureSubImage](http://docs.gl/gl4/glGetTextureSubImage)(texture, level, xoffset, yoffset, zoffset, width, height, depth, format, type_, bufSize, pixels)
         /// * `format` group: PixelFormat
@@ -20172,7 +22128,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_12arg(
                 "glGetTextureSubImage",
-                &self.glGetTextureSubImage_p,
+                self.lazy(&self.glGetTextureSubImage_p, b"glGetTextureSubImage\0"),
                 texture,
                 level,
                 xoffset,
@@ -20206,7 +22162,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetTextureSubImage_is_loaded(&self) -> bool {
-            !self.glGetTextureSubImage_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetTextureSubImage_p, b"glGetTextureSubImage\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetTransformFeedbackVarying](http://docs.gl/gl4/glGetTransformFeedbackVarying)(program, index, bufSize, length, size, type_, name)
         /// * `length` len: 1
@@ -20232,7 +22191,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_7arg(
                 "glGetTransformFeedbackVarying",
-                &self.glGetTransformFeedbackVarying_p,
+                self.lazy(
+                    &self.glGetTransformFeedbackVarying_p,
+                    b"glGetTransformFeedbackVarying\0",
+                ),
                 program,
                 index,
                 bufSize,
@@ -20261,7 +22223,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetTransformFeedbackVarying_is_loaded(&self) -> bool {
-            !self.glGetTransformFeedbackVarying_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetTransformFeedbackVarying_p,
+                    b"glGetTransformFeedbackVarying\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetTransformFeedbacki64_v](http://docs.gl/gl4/glGetTransformFeedbacki64_v)(xfb, pname, index, param)
         /// * `pname` group: TransformFeedbackPName
@@ -20286,7 +22254,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetTransformFeedbacki64_v",
-                &self.glGetTransformFeedbacki64_v_p,
+                self.lazy(
+                    &self.glGetTransformFeedbacki64_v_p,
+                    b"glGetTransformFeedbacki64_v\0",
+                ),
                 xfb,
                 pname,
                 index,
@@ -20312,7 +22283,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetTransformFeedbacki64_v_is_loaded(&self) -> bool {
-            !self.glGetTransformFeedbacki64_v_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetTransformFeedbacki64_v_p,
+                    b"glGetTransformFeedbacki64_v\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetTransformFeedbacki_v](http://docs.gl/gl4/glGetTransformFeedbacki_v)(xfb, pname, index, param)
         /// * `pname` group: TransformFeedbackPName
@@ -20337,7 +22314,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetTransformFeedbacki_v",
-                &self.glGetTransformFeedbacki_v_p,
+                self.lazy(
+                    &self.glGetTransformFeedbacki_v_p,
+                    b"glGetTransformFeedbacki_v\0",
+                ),
                 xfb,
                 pname,
                 index,
@@ -20363,7 +22343,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetTransformFeedbacki_v_is_loaded(&self) -> bool {
-            !self.glGetTransformFeedbacki_v_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetTransformFeedbacki_v_p,
+                    b"glGetTransformFeedbacki_v\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetTransformFeedbackiv](http://docs.gl/gl4/glGetTransformFeedback)(xfb, pname, param)
         /// * `pname` group: TransformFeedbackPName
@@ -20381,7 +22367,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetTransformFeedbackiv",
-                &self.glGetTransformFeedbackiv_p,
+                self.lazy(
+                    &self.glGetTransformFeedbackiv_p,
+                    b"glGetTransformFeedbackiv\0",
+                ),
                 xfb,
                 pname,
                 param,
@@ -20406,7 +22395,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetTransformFeedbackiv_is_loaded(&self) -> bool {
-            !self.glGetTransformFeedbackiv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetTransformFeedbackiv_p,
+                    b"glGetTransformFeedbackiv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetUniformBlockIndex](http://docs.gl/gl4/glGetUniformBlockIndex)(program, uniformBlockName)
         /// * `uniformBlockName` len: COMPSIZE()
@@ -20427,7 +22422,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glGetUniformBlockIndex",
-                &self.glGetUniformBlockIndex_p,
+                self.lazy(&self.glGetUniformBlockIndex_p, b"glGetUniformBlockIndex\0"),
                 program,
                 uniformBlockName,
             );
@@ -20451,7 +22446,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetUniformBlockIndex_is_loaded(&self) -> bool {
-            !self.glGetUniformBlockIndex_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetUniformBlockIndex_p, b"glGetUniformBlockIndex\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetUniformIndices](http://docs.gl/gl4/glGetUniformIndices)(program, uniformCount, uniformNames, uniformIndices)
         /// * `uniformNames` len: COMPSIZE(uniformCount)
@@ -20477,7 +22475,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetUniformIndices",
-                &self.glGetUniformIndices_p,
+                self.lazy(&self.glGetUniformIndices_p, b"glGetUniformIndices\0"),
                 program,
                 uniformCount,
                 uniformNames,
@@ -20503,7 +22501,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetUniformIndices_is_loaded(&self) -> bool {
-            !self.glGetUniformIndices_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetUniformIndices_p, b"glGetUniformIndices\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetUniformLocation](http://docs.gl/gl4/glGetUniformLocation)(program, name)
         #[cfg_attr(feature = "inline", inline)]
@@ -20515,7 +22516,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glGetUniformLocation",
-                &self.glGetUniformLocation_p,
+                self.lazy(&self.glGetUniformLocation_p, b"glGetUniformLocation\0"),
                 program,
                 name,
             );
@@ -20539,7 +22540,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetUniformLocation_is_loaded(&self) -> bool {
-            !self.glGetUniformLocation_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetUniformLocation_p, b"glGetUniformLocation\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetUniformSubroutineuiv](http://docs.gl/gl4/glGetUniformSubroutine)(shadertype, location, params)
         /// * `shadertype` group: ShaderType
@@ -20563,7 +22567,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetUniformSubroutineuiv",
-                &self.glGetUniformSubroutineuiv_p,
+                self.lazy(
+                    &self.glGetUniformSubroutineuiv_p,
+                    b"glGetUniformSubroutineuiv\0",
+                ),
                 shadertype,
                 location,
                 params,
@@ -20588,7 +22595,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetUniformSubroutineuiv_is_loaded(&self) -> bool {
-            !self.glGetUniformSubroutineuiv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetUniformSubroutineuiv_p,
+                    b"glGetUniformSubroutineuiv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetUniformdv](http://docs.gl/gl4/glGetUniformdv)(program, location, params)
         /// * `params` len: COMPSIZE(program,location)
@@ -20606,7 +22619,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetUniformdv",
-                &self.glGetUniformdv_p,
+                self.lazy(&self.glGetUniformdv_p, b"glGetUniformdv\0"),
                 program,
                 location,
                 params,
@@ -20631,7 +22644,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetUniformdv_is_loaded(&self) -> bool {
-            !self.glGetUniformdv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetUniformdv_p, b"glGetUniformdv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetUniformfv](http://docs.gl/gl4/glGetUniform)(program, location, params)
         /// * `params` len: COMPSIZE(program,location)
@@ -20649,7 +22665,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetUniformfv",
-                &self.glGetUniformfv_p,
+                self.lazy(&self.glGetUniformfv_p, b"glGetUniformfv\0"),
                 program,
                 location,
                 params,
@@ -20674,7 +22690,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetUniformfv_is_loaded(&self) -> bool {
-            !self.glGetUniformfv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetUniformfv_p, b"glGetUniformfv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetUniformiv](http://docs.gl/gl4/glGetUniform)(program, location, params)
         /// * `params` len: COMPSIZE(program,location)
@@ -20692,7 +22711,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetUniformiv",
-                &self.glGetUniformiv_p,
+                self.lazy(&self.glGetUniformiv_p, b"glGetUniformiv\0"),
                 program,
                 location,
                 params,
@@ -20717,7 +22736,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetUniformiv_is_loaded(&self) -> bool {
-            !self.glGetUniformiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetUniformiv_p, b"glGetUniformiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetUniformuiv](http://docs.gl/gl4/glGetUniform)(program, location, params)
         /// * `params` len: COMPSIZE(program,location)
@@ -20735,7 +22757,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetUniformuiv",
-                &self.glGetUniformuiv_p,
+                self.lazy(&self.glGetUniformuiv_p, b"glGetUniformuiv\0"),
                 program,
                 location,
                 params,
@@ -20760,7 +22782,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetUniformuiv_is_loaded(&self) -> bool {
-            !self.glGetUniformuiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetUniformuiv_p, b"glGetUniformuiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetVertexArrayIndexed64iv](http://docs.gl/gl4/glGetVertexArrayIndexed6)(vaobj, index, pname, param)
         /// * `pname` group: VertexArrayPName
@@ -20785,7 +22810,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetVertexArrayIndexed64iv",
-                &self.glGetVertexArrayIndexed64iv_p,
+                self.lazy(
+                    &self.glGetVertexArrayIndexed64iv_p,
+                    b"glGetVertexArrayIndexed64iv\0",
+                ),
                 vaobj,
                 index,
                 pname,
@@ -20811,7 +22839,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetVertexArrayIndexed64iv_is_loaded(&self) -> bool {
-            !self.glGetVertexArrayIndexed64iv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetVertexArrayIndexed64iv_p,
+                    b"glGetVertexArrayIndexed64iv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetVertexArrayIndexediv](http://docs.gl/gl4/glGetVertexArrayIndexed)(vaobj, index, pname, param)
         /// * `pname` group: VertexArrayPName
@@ -20836,7 +22870,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetVertexArrayIndexediv",
-                &self.glGetVertexArrayIndexediv_p,
+                self.lazy(
+                    &self.glGetVertexArrayIndexediv_p,
+                    b"glGetVertexArrayIndexediv\0",
+                ),
                 vaobj,
                 index,
                 pname,
@@ -20862,7 +22899,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetVertexArrayIndexediv_is_loaded(&self) -> bool {
-            !self.glGetVertexArrayIndexediv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetVertexArrayIndexediv_p,
+                    b"glGetVertexArrayIndexediv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetVertexArrayiv](http://docs.gl/gl4/glGetVertexArray)(vaobj, pname, param)
         /// * `pname` group: VertexArrayPName
@@ -20880,7 +22923,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetVertexArrayiv",
-                &self.glGetVertexArrayiv_p,
+                self.lazy(&self.glGetVertexArrayiv_p, b"glGetVertexArrayiv\0"),
                 vaobj,
                 pname,
                 param,
@@ -20905,7 +22948,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetVertexArrayiv_is_loaded(&self) -> bool {
-            !self.glGetVertexArrayiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetVertexArrayiv_p, b"glGetVertexArrayiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetVertexAttribIiv](http://docs.gl/gl4/glGetVertexAttrib)(index, pname, params)
         /// * `pname` group: VertexAttribEnum
@@ -20924,7 +22970,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetVertexAttribIiv",
-                &self.glGetVertexAttribIiv_p,
+                self.lazy(&self.glGetVertexAttribIiv_p, b"glGetVertexAttribIiv\0"),
                 index,
                 pname,
                 params,
@@ -20949,7 +22995,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetVertexAttribIiv_is_loaded(&self) -> bool {
-            !self.glGetVertexAttribIiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetVertexAttribIiv_p, b"glGetVertexAttribIiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetVertexAttribIuiv](http://docs.gl/gl4/glGetVertexAttrib)(index, pname, params)
         /// * `pname` group: VertexAttribEnum
@@ -20973,7 +23022,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetVertexAttribIuiv",
-                &self.glGetVertexAttribIuiv_p,
+                self.lazy(&self.glGetVertexAttribIuiv_p, b"glGetVertexAttribIuiv\0"),
                 index,
                 pname,
                 params,
@@ -20998,7 +23047,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetVertexAttribIuiv_is_loaded(&self) -> bool {
-            !self.glGetVertexAttribIuiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetVertexAttribIuiv_p, b"glGetVertexAttribIuiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetVertexAttribLdv](http://docs.gl/gl4/glGetVertexAttribLdv)(index, pname, params)
         /// * `pname` group: VertexAttribEnum
@@ -21022,7 +23074,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetVertexAttribLdv",
-                &self.glGetVertexAttribLdv_p,
+                self.lazy(&self.glGetVertexAttribLdv_p, b"glGetVertexAttribLdv\0"),
                 index,
                 pname,
                 params,
@@ -21047,7 +23099,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetVertexAttribLdv_is_loaded(&self) -> bool {
-            !self.glGetVertexAttribLdv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetVertexAttribLdv_p, b"glGetVertexAttribLdv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetVertexAttribPointerv](http://docs.gl/gl4/glGetVertexAttribPointerv)(index, pname, pointer)
         /// * `pname` group: VertexAttribPointerPropertyARB
@@ -21071,7 +23126,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetVertexAttribPointerv",
-                &self.glGetVertexAttribPointerv_p,
+                self.lazy(
+                    &self.glGetVertexAttribPointerv_p,
+                    b"glGetVertexAttribPointerv\0",
+                ),
                 index,
                 pname,
                 pointer,
@@ -21096,7 +23154,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetVertexAttribPointerv_is_loaded(&self) -> bool {
-            !self.glGetVertexAttribPointerv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetVertexAttribPointerv_p,
+                    b"glGetVertexAttribPointerv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetVertexAttribdv](http://docs.gl/gl4/glGetVertexAttribdv)(index, pname, params)
         /// * `pname` group: VertexAttribPropertyARB
@@ -21120,7 +23184,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetVertexAttribdv",
-                &self.glGetVertexAttribdv_p,
+                self.lazy(&self.glGetVertexAttribdv_p, b"glGetVertexAttribdv\0"),
                 index,
                 pname,
                 params,
@@ -21145,7 +23209,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetVertexAttribdv_is_loaded(&self) -> bool {
-            !self.glGetVertexAttribdv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetVertexAttribdv_p, b"glGetVertexAttribdv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetVertexAttribfv](http://docs.gl/gl4/glGetVertexAttrib)(index, pname, params)
         /// * `pname` group: VertexAttribPropertyARB
@@ -21164,7 +23231,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetVertexAttribfv",
-                &self.glGetVertexAttribfv_p,
+                self.lazy(&self.glGetVertexAttribfv_p, b"glGetVertexAttribfv\0"),
                 index,
                 pname,
                 params,
@@ -21189,7 +23256,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetVertexAttribfv_is_loaded(&self) -> bool {
-            !self.glGetVertexAttribfv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetVertexAttribfv_p, b"glGetVertexAttribfv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetVertexAttribiv](http://docs.gl/gl4/glGetVertexAttrib)(index, pname, params)
         /// * `pname` group: VertexAttribPropertyARB
@@ -21208,7 +23278,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glGetVertexAttribiv",
-                &self.glGetVertexAttribiv_p,
+                self.lazy(&self.glGetVertexAttribiv_p, b"glGetVertexAttribiv\0"),
                 index,
                 pname,
                 params,
@@ -21233,7 +23303,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetVertexAttribiv_is_loaded(&self) -> bool {
-            !self.glGetVertexAttribiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetVertexAttribiv_p, b"glGetVertexAttribiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetnCompressedTexImage](http://docs.gl/gl4/glGetnCompressedTexImage)(target, lod, bufSize, pixels)
         /// * `target` group: TextureTarget
@@ -21258,7 +23331,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetnCompressedTexImage",
-                &self.glGetnCompressedTexImage_p,
+                self.lazy(
+                    &self.glGetnCompressedTexImage_p,
+                    b"glGetnCompressedTexImage\0",
+                ),
                 target,
                 lod,
                 bufSize,
@@ -21284,7 +23360,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetnCompressedTexImage_is_loaded(&self) -> bool {
-            !self.glGetnCompressedTexImage_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glGetnCompressedTexImage_p,
+                    b"glGetnCompressedTexImage\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetnTexImage](http://docs.gl/gl4/glGetnTexImage)(target, level, format, type_, bufSize, pixels)
         /// * `target` group: TextureTarget
@@ -21316,7 +23398,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glGetnTexImage",
-                &self.glGetnTexImage_p,
+                self.lazy(&self.glGetnTexImage_p, b"glGetnTexImage\0"),
                 target,
                 level,
                 format,
@@ -21344,7 +23426,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetnTexImage_is_loaded(&self) -> bool {
-            !self.glGetnTexImage_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetnTexImage_p, b"glGetnTexImage\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetnUniformdv](http://docs.gl/gl4/glGetnUniformdv)(program, location, bufSize, params)
         /// * `params` len: bufSize
@@ -21369,7 +23454,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetnUniformdv",
-                &self.glGetnUniformdv_p,
+                self.lazy(&self.glGetnUniformdv_p, b"glGetnUniformdv\0"),
                 program,
                 location,
                 bufSize,
@@ -21395,7 +23480,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetnUniformdv_is_loaded(&self) -> bool {
-            !self.glGetnUniformdv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetnUniformdv_p, b"glGetnUniformdv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetnUniformfv](http://docs.gl/gl4/glGetnUniform)(program, location, bufSize, params)
         /// * `params` len: bufSize
@@ -21420,7 +23508,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetnUniformfv",
-                &self.glGetnUniformfv_p,
+                self.lazy(&self.glGetnUniformfv_p, b"glGetnUniformfv\0"),
                 program,
                 location,
                 bufSize,
@@ -21446,7 +23534,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetnUniformfv_is_loaded(&self) -> bool {
-            !self.glGetnUniformfv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetnUniformfv_p, b"glGetnUniformfv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetnUniformiv](http://docs.gl/gl4/glGetnUniform)(program, location, bufSize, params)
         /// * `params` len: bufSize
@@ -21471,7 +23562,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetnUniformiv",
-                &self.glGetnUniformiv_p,
+                self.lazy(&self.glGetnUniformiv_p, b"glGetnUniformiv\0"),
                 program,
                 location,
                 bufSize,
@@ -21497,7 +23588,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetnUniformiv_is_loaded(&self) -> bool {
-            !self.glGetnUniformiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetnUniformiv_p, b"glGetnUniformiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glGetnUniformuiv](http://docs.gl/gl4/glGetnUniform)(program, location, bufSize, params)
         /// * `params` len: bufSize
@@ -21522,7 +23616,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glGetnUniformuiv",
-                &self.glGetnUniformuiv_p,
+                self.lazy(&self.glGetnUniformuiv_p, b"glGetnUniformuiv\0"),
                 program,
                 location,
                 bufSize,
@@ -21548,7 +23642,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn GetnUniformuiv_is_loaded(&self) -> bool {
-            !self.glGetnUniformuiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glGetnUniformuiv_p, b"glGetnUniformuiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glHint](http://docs.gl/gl4/glHint)(target, mode)
         /// * `target` group: HintTarget
@@ -21560,7 +23657,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.Hint({:#X}, {:#X});", target, mode);
             }
-            let out = call_atomic_ptr_2arg("glHint", &self.glHint_p, target, mode);
+            let out = call_atomic_ptr_2arg(
+                "glHint",
+                self.lazy(&self.glHint_p, b"glHint\0"),
+                target,
+                mode,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glHint");
@@ -21577,7 +23679,7 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Hint_is_loaded(&self) -> bool {
-            !self.glHint_p.load(RELAX).is_null()
+            !self.lazy(&self.glHint_p, b"glHint\0").load(RELAX).is_null()
         }
         /// [glInvalidateBufferData](http://docs.gl/gl4/glInvalidateBufferData)(buffer)
         #[cfg_attr(feature = "inline", inline)]
@@ -21589,7 +23691,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_1arg(
                 "glInvalidateBufferData",
-                &self.glInvalidateBufferData_p,
+                self.lazy(&self.glInvalidateBufferData_p, b"glInvalidateBufferData\0"),
                 buffer,
             );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
@@ -21612,7 +23714,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn InvalidateBufferData_is_loaded(&self) -> bool {
-            !self.glInvalidateBufferData_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glInvalidateBufferData_p, b"glInvalidateBufferData\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glInvalidateBufferSubData](http://docs.gl/gl4/glInvalidateBufferSubData)(buffer, offset, length)
         /// * `offset` group: BufferOffset
@@ -21636,7 +23741,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glInvalidateBufferSubData",
-                &self.glInvalidateBufferSubData_p,
+                self.lazy(
+                    &self.glInvalidateBufferSubData_p,
+                    b"glInvalidateBufferSubData\0",
+                ),
                 buffer,
                 offset,
                 length,
@@ -21661,7 +23769,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn InvalidateBufferSubData_is_loaded(&self) -> bool {
-            !self.glInvalidateBufferSubData_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glInvalidateBufferSubData_p,
+                    b"glInvalidateBufferSubData\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glInvalidateFramebuffer](http://docs.gl/gl4/glInvalidateFramebuffer)(target, numAttachments, attachments)
         /// * `target` group: FramebufferTarget
@@ -21686,7 +23800,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glInvalidateFramebuffer",
-                &self.glInvalidateFramebuffer_p,
+                self.lazy(
+                    &self.glInvalidateFramebuffer_p,
+                    b"glInvalidateFramebuffer\0",
+                ),
                 target,
                 numAttachments,
                 attachments,
@@ -21711,7 +23828,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn InvalidateFramebuffer_is_loaded(&self) -> bool {
-            !self.glInvalidateFramebuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glInvalidateFramebuffer_p,
+                    b"glInvalidateFramebuffer\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glInvalidateNamedFramebufferData](http://docs.gl/gl4/glInvalidateNamedFramebufferData)(framebuffer, numAttachments, attachments)
         /// * `attachments` group: FramebufferAttachment
@@ -21734,7 +23857,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glInvalidateNamedFramebufferData",
-                &self.glInvalidateNamedFramebufferData_p,
+                self.lazy(
+                    &self.glInvalidateNamedFramebufferData_p,
+                    b"glInvalidateNamedFramebufferData\0",
+                ),
                 framebuffer,
                 numAttachments,
                 attachments,
@@ -21760,7 +23886,10 @@ pub mod struct_commands {
         #[doc(hidden)]
         pub fn InvalidateNamedFramebufferData_is_loaded(&self) -> bool {
             !self
-                .glInvalidateNamedFramebufferData_p
+                .lazy(
+                    &self.glInvalidateNamedFramebufferData_p,
+                    b"glInvalidateNamedFramebufferData\0",
+                )
                 .load(RELAX)
                 .is_null()
         }
@@ -21784,7 +23913,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_7arg(
                 "glInvalidateNamedFramebufferSubData",
-                &self.glInvalidateNamedFramebufferSubData_p,
+                self.lazy(
+                    &self.glInvalidateNamedFramebufferSubData_p,
+                    b"glInvalidateNamedFramebufferSubData\0",
+                ),
                 framebuffer,
                 numAttachments,
                 attachments,
@@ -21814,7 +23946,10 @@ pub mod struct_commands {
         #[doc(hidden)]
         pub fn InvalidateNamedFramebufferSubData_is_loaded(&self) -> bool {
             !self
-                .glInvalidateNamedFramebufferSubData_p
+                .lazy(
+                    &self.glInvalidateNamedFramebufferSubData_p,
+                    b"glInvalidateNamedFramebufferSubData\0",
+                )
                 .load(RELAX)
                 .is_null()
         }
@@ -21840,7 +23975,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_7arg(
                 "glInvalidateSubFramebuffer",
-                &self.glInvalidateSubFramebuffer_p,
+                self.lazy(
+                    &self.glInvalidateSubFramebuffer_p,
+                    b"glInvalidateSubFramebuffer\0",
+                ),
                 target,
                 numAttachments,
                 attachments,
@@ -21869,7 +24007,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn InvalidateSubFramebuffer_is_loaded(&self) -> bool {
-            !self.glInvalidateSubFramebuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glInvalidateSubFramebuffer_p,
+                    b"glInvalidateSubFramebuffer\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glInvalidateTexImage](http://docs.gl/gl4/glInvalidateTexImage)(texture, level)
         #[cfg_attr(feature = "inline", inline)]
@@ -21881,7 +24025,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glInvalidateTexImage",
-                &self.glInvalidateTexImage_p,
+                self.lazy(&self.glInvalidateTexImage_p, b"glInvalidateTexImage\0"),
                 texture,
                 level,
             );
@@ -21905,7 +24049,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn InvalidateTexImage_is_loaded(&self) -> bool {
-            !self.glInvalidateTexImage_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glInvalidateTexImage_p, b"glInvalidateTexImage\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glInvalidateTexSubImage](http://docs.gl/gl4/glInvalidateTexSubImage)(texture, level, xoffset, yoffset, zoffset, width, height, depth)
         #[cfg_attr(feature = "inline", inline)]
@@ -21927,7 +24074,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_8arg(
                 "glInvalidateTexSubImage",
-                &self.glInvalidateTexSubImage_p,
+                self.lazy(
+                    &self.glInvalidateTexSubImage_p,
+                    b"glInvalidateTexSubImage\0",
+                ),
                 texture,
                 level,
                 xoffset,
@@ -21957,7 +24107,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn InvalidateTexSubImage_is_loaded(&self) -> bool {
-            !self.glInvalidateTexSubImage_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glInvalidateTexSubImage_p,
+                    b"glInvalidateTexSubImage\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glIsBuffer](http://docs.gl/gl4/glIsBuffer)(buffer)
         #[cfg_attr(feature = "inline", inline)]
@@ -21967,7 +24123,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.IsBuffer({:?});", buffer);
             }
-            let out = call_atomic_ptr_1arg("glIsBuffer", &self.glIsBuffer_p, buffer);
+            let out = call_atomic_ptr_1arg(
+                "glIsBuffer",
+                self.lazy(&self.glIsBuffer_p, b"glIsBuffer\0"),
+                buffer,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glIsBuffer");
@@ -21984,7 +24144,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn IsBuffer_is_loaded(&self) -> bool {
-            !self.glIsBuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glIsBuffer_p, b"glIsBuffer\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glIsEnabled](http://docs.gl/gl4/glIsEnabled)(cap)
         /// * `cap` group: EnableCap
@@ -21995,7 +24158,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.IsEnabled({:#X});", cap);
             }
-            let out = call_atomic_ptr_1arg("glIsEnabled", &self.glIsEnabled_p, cap);
+            let out = call_atomic_ptr_1arg(
+                "glIsEnabled",
+                self.lazy(&self.glIsEnabled_p, b"glIsEnabled\0"),
+                cap,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glIsEnabled");
@@ -22012,7 +24179,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn IsEnabled_is_loaded(&self) -> bool {
-            !self.glIsEnabled_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glIsEnabled_p, b"glIsEnabled\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glIsEnabledIndexedEXT](http://docs.gl/gl4/glIsEnabledIndexedEXT)(target, index)
         /// * `target` group: EnableCap
@@ -22030,7 +24200,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glIsEnabledIndexedEXT",
-                &self.glIsEnabledIndexedEXT_p,
+                self.lazy(&self.glIsEnabledIndexedEXT_p, b"glIsEnabledIndexedEXT\0"),
                 target,
                 index,
             );
@@ -22054,7 +24224,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn IsEnabledIndexedEXT_is_loaded(&self) -> bool {
-            !self.glIsEnabledIndexedEXT_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glIsEnabledIndexedEXT_p, b"glIsEnabledIndexedEXT\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glIsEnabledi](http://docs.gl/gl4/glIsEnabled)(target, index)
         /// * `target` group: EnableCap
@@ -22065,7 +24238,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.IsEnabledi({:#X}, {:?});", target, index);
             }
-            let out = call_atomic_ptr_2arg("glIsEnabledi", &self.glIsEnabledi_p, target, index);
+            let out = call_atomic_ptr_2arg(
+                "glIsEnabledi",
+                self.lazy(&self.glIsEnabledi_p, b"glIsEnabledi\0"),
+                target,
+                index,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glIsEnabledi");
@@ -22082,7 +24260,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn IsEnabledi_is_loaded(&self) -> bool {
-            !self.glIsEnabledi_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glIsEnabledi_p, b"glIsEnabledi\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glIsFramebuffer](http://docs.gl/gl4/glIsFramebuffer)(framebuffer)
         #[cfg_attr(feature = "inline", inline)]
@@ -22092,7 +24273,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.IsFramebuffer({:?});", framebuffer);
             }
-            let out = call_atomic_ptr_1arg("glIsFramebuffer", &self.glIsFramebuffer_p, framebuffer);
+            let out = call_atomic_ptr_1arg(
+                "glIsFramebuffer",
+                self.lazy(&self.glIsFramebuffer_p, b"glIsFramebuffer\0"),
+                framebuffer,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glIsFramebuffer");
@@ -22113,7 +24298,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn IsFramebuffer_is_loaded(&self) -> bool {
-            !self.glIsFramebuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glIsFramebuffer_p, b"glIsFramebuffer\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glIsProgram](http://docs.gl/gl4/glIsProgram)(program)
         #[cfg_attr(feature = "inline", inline)]
@@ -22123,7 +24311,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.IsProgram({:?});", program);
             }
-            let out = call_atomic_ptr_1arg("glIsProgram", &self.glIsProgram_p, program);
+            let out = call_atomic_ptr_1arg(
+                "glIsProgram",
+                self.lazy(&self.glIsProgram_p, b"glIsProgram\0"),
+                program,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glIsProgram");
@@ -22140,7 +24332,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn IsProgram_is_loaded(&self) -> bool {
-            !self.glIsProgram_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glIsProgram_p, b"glIsProgram\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glIsProgramPipeline](http://docs.gl/gl4/glIsProgramPipeline)(pipeline)
         #[cfg_attr(feature = "inline", inline)]
@@ -22150,8 +24345,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.IsProgramPipeline({:?});", pipeline);
             }
-            let out =
-                call_atomic_ptr_1arg("glIsProgramPipeline", &self.glIsProgramPipeline_p, pipeline);
+            let out = call_atomic_ptr_1arg(
+                "glIsProgramPipeline",
+                self.lazy(&self.glIsProgramPipeline_p, b"glIsProgramPipeline\0"),
+                pipeline,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glIsProgramPipeline");
@@ -22172,7 +24370,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn IsProgramPipeline_is_loaded(&self) -> bool {
-            !self.glIsProgramPipeline_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glIsProgramPipeline_p, b"glIsProgramPipeline\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glIsQuery](http://docs.gl/gl4/glIsQuery)(id)
         #[cfg_attr(feature = "inline", inline)]
@@ -22182,7 +24383,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.IsQuery({:?});", id);
             }
-            let out = call_atomic_ptr_1arg("glIsQuery", &self.glIsQuery_p, id);
+            let out = call_atomic_ptr_1arg(
+                "glIsQuery",
+                self.lazy(&self.glIsQuery_p, b"glIsQuery\0"),
+                id,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glIsQuery");
@@ -22199,7 +24404,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn IsQuery_is_loaded(&self) -> bool {
-            !self.glIsQuery_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glIsQuery_p, b"glIsQuery\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glIsQueryEXT](http://docs.gl/gl4/glIsQueryEXT)(id)
         #[cfg_attr(feature = "inline", inline)]
@@ -22209,7 +24417,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.IsQueryEXT({:?});", id);
             }
-            let out = call_atomic_ptr_1arg("glIsQueryEXT", &self.glIsQueryEXT_p, id);
+            let out = call_atomic_ptr_1arg(
+                "glIsQueryEXT",
+                self.lazy(&self.glIsQueryEXT_p, b"glIsQueryEXT\0"),
+                id,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glIsQueryEXT");
@@ -22226,7 +24438,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn IsQueryEXT_is_loaded(&self) -> bool {
-            !self.glIsQueryEXT_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glIsQueryEXT_p, b"glIsQueryEXT\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glIsRenderbuffer](http://docs.gl/gl4/glIsRenderbuffer)(renderbuffer)
         #[cfg_attr(feature = "inline", inline)]
@@ -22236,8 +24451,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.IsRenderbuffer({:?});", renderbuffer);
             }
-            let out =
-                call_atomic_ptr_1arg("glIsRenderbuffer", &self.glIsRenderbuffer_p, renderbuffer);
+            let out = call_atomic_ptr_1arg(
+                "glIsRenderbuffer",
+                self.lazy(&self.glIsRenderbuffer_p, b"glIsRenderbuffer\0"),
+                renderbuffer,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glIsRenderbuffer");
@@ -22258,7 +24476,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn IsRenderbuffer_is_loaded(&self) -> bool {
-            !self.glIsRenderbuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glIsRenderbuffer_p, b"glIsRenderbuffer\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glIsSampler](http://docs.gl/gl4/glIsSampler)(sampler)
         #[cfg_attr(feature = "inline", inline)]
@@ -22268,7 +24489,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.IsSampler({:?});", sampler);
             }
-            let out = call_atomic_ptr_1arg("glIsSampler", &self.glIsSampler_p, sampler);
+            let out = call_atomic_ptr_1arg(
+                "glIsSampler",
+                self.lazy(&self.glIsSampler_p, b"glIsSampler\0"),
+                sampler,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glIsSampler");
@@ -22285,7 +24510,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn IsSampler_is_loaded(&self) -> bool {
-            !self.glIsSampler_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glIsSampler_p, b"glIsSampler\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glIsShader](http://docs.gl/gl4/glIsShader)(shader)
         #[cfg_attr(feature = "inline", inline)]
@@ -22295,7 +24523,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.IsShader({:?});", shader);
             }
-            let out = call_atomic_ptr_1arg("glIsShader", &self.glIsShader_p, shader);
+            let out = call_atomic_ptr_1arg(
+                "glIsShader",
+                self.lazy(&self.glIsShader_p, b"glIsShader\0"),
+                shader,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glIsShader");
@@ -22312,7 +24544,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn IsShader_is_loaded(&self) -> bool {
-            !self.glIsShader_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glIsShader_p, b"glIsShader\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glIsSync](http://docs.gl/gl4/glIsSync)(sync)
         /// * `sync` group: sync
@@ -22323,7 +24558,8 @@ pub mod struct_commands {
             {
                 trace!("calling gl.IsSync({:p});", sync);
             }
-            let out = call_atomic_ptr_1arg("glIsSync", &self.glIsSync_p, sync);
+            let out =
+                call_atomic_ptr_1arg("glIsSync", self.lazy(&self.glIsSync_p, b"glIsSync\0"), sync);
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glIsSync");
@@ -22340,7 +24576,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn IsSync_is_loaded(&self) -> bool {
-            !self.glIsSync_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glIsSync_p, b"glIsSync\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glIsTexture](http://docs.gl/gl4/glIsTexture)(texture)
         /// * `texture` group: Texture
@@ -22351,7 +24590,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.IsTexture({:?});", texture);
             }
-            let out = call_atomic_ptr_1arg("glIsTexture", &self.glIsTexture_p, texture);
+            let out = call_atomic_ptr_1arg(
+                "glIsTexture",
+                self.lazy(&self.glIsTexture_p, b"glIsTexture\0"),
+                texture,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glIsTexture");
@@ -22368,7 +24611,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn IsTexture_is_loaded(&self) -> bool {
-            !self.glIsTexture_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glIsTexture_p, b"glIsTexture\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glIsTransformFeedback](http://docs.gl/gl4/glIsTransformFeedback)(id)
         #[cfg_attr(feature = "inline", inline)]
@@ -22378,8 +24624,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.IsTransformFeedback({:?});", id);
             }
-            let out =
-                call_atomic_ptr_1arg("glIsTransformFeedback", &self.glIsTransformFeedback_p, id);
+            let out = call_atomic_ptr_1arg(
+                "glIsTransformFeedback",
+                self.lazy(&self.glIsTransformFeedback_p, b"glIsTransformFeedback\0"),
+                id,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glIsTransformFeedback");
@@ -22400,7 +24649,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn IsTransformFeedback_is_loaded(&self) -> bool {
-            !self.glIsTransformFeedback_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glIsTransformFeedback_p, b"glIsTransformFeedback\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glIsVertexArray](http://docs.gl/gl4/glIsVertexArray)(array)
         #[cfg_attr(feature = "inline", inline)]
@@ -22410,7 +24662,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.IsVertexArray({:?});", array);
             }
-            let out = call_atomic_ptr_1arg("glIsVertexArray", &self.glIsVertexArray_p, array);
+            let out = call_atomic_ptr_1arg(
+                "glIsVertexArray",
+                self.lazy(&self.glIsVertexArray_p, b"glIsVertexArray\0"),
+                array,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glIsVertexArray");
@@ -22431,7 +24687,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn IsVertexArray_is_loaded(&self) -> bool {
-            !self.glIsVertexArray_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glIsVertexArray_p, b"glIsVertexArray\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glIsVertexArrayAPPLE](http://docs.gl/gl4/glIsVertexArrayAPPLE)(array)
         /// * alias of: [`glIsVertexArray`]
@@ -22442,8 +24701,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.IsVertexArrayAPPLE({:?});", array);
             }
-            let out =
-                call_atomic_ptr_1arg("glIsVertexArrayAPPLE", &self.glIsVertexArrayAPPLE_p, array);
+            let out = call_atomic_ptr_1arg(
+                "glIsVertexArrayAPPLE",
+                self.lazy(&self.glIsVertexArrayAPPLE_p, b"glIsVertexArrayAPPLE\0"),
+                array,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glIsVertexArrayAPPLE");
@@ -22464,7 +24726,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn IsVertexArrayAPPLE_is_loaded(&self) -> bool {
-            !self.glIsVertexArrayAPPLE_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glIsVertexArrayAPPLE_p, b"glIsVertexArrayAPPLE\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glIsVertexArrayOES](http://docs.gl/gl4/glIsVertexArrayOES)(array)
         /// * alias of: [`glIsVertexArray`]
@@ -22475,7 +24740,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.IsVertexArrayOES({:?});", array);
             }
-            let out = call_atomic_ptr_1arg("glIsVertexArrayOES", &self.glIsVertexArrayOES_p, array);
+            let out = call_atomic_ptr_1arg(
+                "glIsVertexArrayOES",
+                self.lazy(&self.glIsVertexArrayOES_p, b"glIsVertexArrayOES\0"),
+                array,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glIsVertexArrayOES");
@@ -22496,7 +24765,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn IsVertexArrayOES_is_loaded(&self) -> bool {
-            !self.glIsVertexArrayOES_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glIsVertexArrayOES_p, b"glIsVertexArrayOES\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glLineWidth](http://docs.gl/gl4/glLineWidth)(width)
         /// * `width` group: CheckedFloat32
@@ -22507,7 +24779,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.LineWidth({:?});", width);
             }
-            let out = call_atomic_ptr_1arg("glLineWidth", &self.glLineWidth_p, width);
+            let out = call_atomic_ptr_1arg(
+                "glLineWidth",
+                self.lazy(&self.glLineWidth_p, b"glLineWidth\0"),
+                width,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glLineWidth");
@@ -22524,7 +24800,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn LineWidth_is_loaded(&self) -> bool {
-            !self.glLineWidth_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glLineWidth_p, b"glLineWidth\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glLinkProgram](http://docs.gl/gl4/glLinkProgram)(program)
         #[cfg_attr(feature = "inline", inline)]
@@ -22534,7 +24813,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.LinkProgram({:?});", program);
             }
-            let out = call_atomic_ptr_1arg("glLinkProgram", &self.glLinkProgram_p, program);
+            let out = call_atomic_ptr_1arg(
+                "glLinkProgram",
+                self.lazy(&self.glLinkProgram_p, b"glLinkProgram\0"),
+                program,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glLinkProgram");
@@ -22551,7 +24834,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn LinkProgram_is_loaded(&self) -> bool {
-            !self.glLinkProgram_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glLinkProgram_p, b"glLinkProgram\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glLogicOp](http://docs.gl/gl4/glLogicOp)(opcode)
         /// * `opcode` group: LogicOp
@@ -22562,7 +24848,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.LogicOp({:#X});", opcode);
             }
-            let out = call_atomic_ptr_1arg("glLogicOp", &self.glLogicOp_p, opcode);
+            let out = call_atomic_ptr_1arg(
+                "glLogicOp",
+                self.lazy(&self.glLogicOp_p, b"glLogicOp\0"),
+                opcode,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glLogicOp");
@@ -22579,7 +24869,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn LogicOp_is_loaded(&self) -> bool {
-            !self.glLogicOp_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glLogicOp_p, b"glLogicOp\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glMapBuffer](http://docs.gl/gl4/glMapBuffer)(target, access)
         /// * `target` group: BufferTargetARB
@@ -22591,7 +24884,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.MapBuffer({:#X}, {:#X});", target, access);
             }
-            let out = call_atomic_ptr_2arg("glMapBuffer", &self.glMapBuffer_p, target, access);
+            let out = call_atomic_ptr_2arg(
+                "glMapBuffer",
+                self.lazy(&self.glMapBuffer_p, b"glMapBuffer\0"),
+                target,
+                access,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glMapBuffer");
@@ -22608,7 +24906,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn MapBuffer_is_loaded(&self) -> bool {
-            !self.glMapBuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glMapBuffer_p, b"glMapBuffer\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glMapBufferRange](http://docs.gl/gl4/glMapBufferRange)(target, offset, length, access)
         /// * `target` group: BufferTargetARB
@@ -22636,7 +24937,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glMapBufferRange",
-                &self.glMapBufferRange_p,
+                self.lazy(&self.glMapBufferRange_p, b"glMapBufferRange\0"),
                 target,
                 offset,
                 length,
@@ -22662,7 +24963,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn MapBufferRange_is_loaded(&self) -> bool {
-            !self.glMapBufferRange_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glMapBufferRange_p, b"glMapBufferRange\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glMapNamedBuffer](http://docs.gl/gl4/glMapNamedBuffer)(buffer, access)
         /// * `access` group: BufferAccessARB
@@ -22673,8 +24977,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.MapNamedBuffer({:?}, {:#X});", buffer, access);
             }
-            let out =
-                call_atomic_ptr_2arg("glMapNamedBuffer", &self.glMapNamedBuffer_p, buffer, access);
+            let out = call_atomic_ptr_2arg(
+                "glMapNamedBuffer",
+                self.lazy(&self.glMapNamedBuffer_p, b"glMapNamedBuffer\0"),
+                buffer,
+                access,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glMapNamedBuffer");
@@ -22695,7 +25003,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn MapNamedBuffer_is_loaded(&self) -> bool {
-            !self.glMapNamedBuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glMapNamedBuffer_p, b"glMapNamedBuffer\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glMapNamedBufferRange](http://docs.gl/gl4/glMapNamedBufferRange)(buffer, offset, length, access)
         /// * `length` group: BufferSize
@@ -22721,7 +25032,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glMapNamedBufferRange",
-                &self.glMapNamedBufferRange_p,
+                self.lazy(&self.glMapNamedBufferRange_p, b"glMapNamedBufferRange\0"),
                 buffer,
                 offset,
                 length,
@@ -22747,7 +25058,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn MapNamedBufferRange_is_loaded(&self) -> bool {
-            !self.glMapNamedBufferRange_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glMapNamedBufferRange_p, b"glMapNamedBufferRange\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glMaxShaderCompilerThreadsARB](http://docs.gl/gl4/glMaxShaderCompilerThreadsARB)(count)
         /// * alias of: [`glMaxShaderCompilerThreadsKHR`]
@@ -22760,7 +25074,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_1arg(
                 "glMaxShaderCompilerThreadsARB",
-                &self.glMaxShaderCompilerThreadsARB_p,
+                self.lazy(
+                    &self.glMaxShaderCompilerThreadsARB_p,
+                    b"glMaxShaderCompilerThreadsARB\0",
+                ),
                 count,
             );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
@@ -22783,7 +25100,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn MaxShaderCompilerThreadsARB_is_loaded(&self) -> bool {
-            !self.glMaxShaderCompilerThreadsARB_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glMaxShaderCompilerThreadsARB_p,
+                    b"glMaxShaderCompilerThreadsARB\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glMaxShaderCompilerThreadsKHR](http://docs.gl/gl4/glMaxShaderCompilerThreadsKHR)(count)
         #[cfg_attr(feature = "inline", inline)]
@@ -22795,7 +25118,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_1arg(
                 "glMaxShaderCompilerThreadsKHR",
-                &self.glMaxShaderCompilerThreadsKHR_p,
+                self.lazy(
+                    &self.glMaxShaderCompilerThreadsKHR_p,
+                    b"glMaxShaderCompilerThreadsKHR\0",
+                ),
                 count,
             );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
@@ -22818,7 +25144,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn MaxShaderCompilerThreadsKHR_is_loaded(&self) -> bool {
-            !self.glMaxShaderCompilerThreadsKHR_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glMaxShaderCompilerThreadsKHR_p,
+                    b"glMaxShaderCompilerThreadsKHR\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glMemoryBarrier](http://docs.gl/gl4/glMemoryBarrier)(barriers)
         /// * `barriers` group: MemoryBarrierMask
@@ -22829,7 +25161,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.MemoryBarrier({:?});", barriers);
             }
-            let out = call_atomic_ptr_1arg("glMemoryBarrier", &self.glMemoryBarrier_p, barriers);
+            let out = call_atomic_ptr_1arg(
+                "glMemoryBarrier",
+                self.lazy(&self.glMemoryBarrier_p, b"glMemoryBarrier\0"),
+                barriers,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glMemoryBarrier");
@@ -22850,7 +25186,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn MemoryBarrier_is_loaded(&self) -> bool {
-            !self.glMemoryBarrier_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glMemoryBarrier_p, b"glMemoryBarrier\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glMemoryBarrierByRegion](http://docs.gl/gl4/glMemoryBarrierByRegion)(barriers)
         /// * `barriers` group: MemoryBarrierMask
@@ -22863,7 +25202,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_1arg(
                 "glMemoryBarrierByRegion",
-                &self.glMemoryBarrierByRegion_p,
+                self.lazy(
+                    &self.glMemoryBarrierByRegion_p,
+                    b"glMemoryBarrierByRegion\0",
+                ),
                 barriers,
             );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
@@ -22886,7 +25228,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn MemoryBarrierByRegion_is_loaded(&self) -> bool {
-            !self.glMemoryBarrierByRegion_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glMemoryBarrierByRegion_p,
+                    b"glMemoryBarrierByRegion\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glMinSampleShading](http://docs.gl/gl4/glMinSampleShading)(value)
         /// * `value` group: ColorF
@@ -22897,7 +25245,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.MinSampleShading({:?});", value);
             }
-            let out = call_atomic_ptr_1arg("glMinSampleShading", &self.glMinSampleShading_p, value);
+            let out = call_atomic_ptr_1arg(
+                "glMinSampleShading",
+                self.lazy(&self.glMinSampleShading_p, b"glMinSampleShading\0"),
+                value,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glMinSampleShading");
@@ -22918,7 +25270,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn MinSampleShading_is_loaded(&self) -> bool {
-            !self.glMinSampleShading_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glMinSampleShading_p, b"glMinSampleShading\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glMultiDrawArrays](http://docs.gl/gl4/glMultiDrawArrays)(mode, first, count, drawcount)
         /// * `mode` group: PrimitiveType
@@ -22945,7 +25300,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glMultiDrawArrays",
-                &self.glMultiDrawArrays_p,
+                self.lazy(&self.glMultiDrawArrays_p, b"glMultiDrawArrays\0"),
                 mode,
                 first,
                 count,
@@ -22971,7 +25326,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn MultiDrawArrays_is_loaded(&self) -> bool {
-            !self.glMultiDrawArrays_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glMultiDrawArrays_p, b"glMultiDrawArrays\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glMultiDrawArraysIndirect](http://docs.gl/gl4/glMultiDrawArraysIndirect)(mode, indirect, drawcount, stride)
         /// * `mode` group: PrimitiveType
@@ -22997,7 +25355,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glMultiDrawArraysIndirect",
-                &self.glMultiDrawArraysIndirect_p,
+                self.lazy(
+                    &self.glMultiDrawArraysIndirect_p,
+                    b"glMultiDrawArraysIndirect\0",
+                ),
                 mode,
                 indirect,
                 drawcount,
@@ -23023,7 +25384,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn MultiDrawArraysIndirect_is_loaded(&self) -> bool {
-            !self.glMultiDrawArraysIndirect_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glMultiDrawArraysIndirect_p,
+                    b"glMultiDrawArraysIndirect\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glMultiDrawArraysIndirectCount](http://docs.gl/gl4/glMultiDrawArraysIndirectCount)(mode, indirect, drawcount, maxdrawcount, stride)
         /// * `mode` group: PrimitiveType
@@ -23050,7 +25417,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glMultiDrawArraysIndirectCount",
-                &self.glMultiDrawArraysIndirectCount_p,
+                self.lazy(
+                    &self.glMultiDrawArraysIndirectCount_p,
+                    b"glMultiDrawArraysIndirectCount\0",
+                ),
                 mode,
                 indirect,
                 drawcount,
@@ -23077,7 +25447,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn MultiDrawArraysIndirectCount_is_loaded(&self) -> bool {
-            !self.glMultiDrawArraysIndirectCount_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glMultiDrawArraysIndirectCount_p,
+                    b"glMultiDrawArraysIndirectCount\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glMultiDrawElements](http://docs.gl/gl4/glMultiDrawElements)(mode, count, type_, indices, drawcount)
         /// * `mode` group: PrimitiveType
@@ -23107,7 +25483,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glMultiDrawElements",
-                &self.glMultiDrawElements_p,
+                self.lazy(&self.glMultiDrawElements_p, b"glMultiDrawElements\0"),
                 mode,
                 count,
                 type_,
@@ -23134,7 +25510,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn MultiDrawElements_is_loaded(&self) -> bool {
-            !self.glMultiDrawElements_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glMultiDrawElements_p, b"glMultiDrawElements\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glMultiDrawElementsBaseVertex](http://docs.gl/gl4/glMultiDrawElementsBaseVertex)(mode, count, type_, indices, drawcount, basevertex)
         /// * `mode` group: PrimitiveType
@@ -23167,7 +25546,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glMultiDrawElementsBaseVertex",
-                &self.glMultiDrawElementsBaseVertex_p,
+                self.lazy(
+                    &self.glMultiDrawElementsBaseVertex_p,
+                    b"glMultiDrawElementsBaseVertex\0",
+                ),
                 mode,
                 count,
                 type_,
@@ -23195,7 +25577,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn MultiDrawElementsBaseVertex_is_loaded(&self) -> bool {
-            !self.glMultiDrawElementsBaseVertex_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glMultiDrawElementsBaseVertex_p,
+                    b"glMultiDrawElementsBaseVertex\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glMultiDrawElementsIndirect](http://docs.gl/gl4/glMultiDrawElementsIndirect)(mode, type_, indirect, drawcount, stride)
         /// * `mode` group: PrimitiveType
@@ -23224,7 +25612,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glMultiDrawElementsIndirect",
-                &self.glMultiDrawElementsIndirect_p,
+                self.lazy(
+                    &self.glMultiDrawElementsIndirect_p,
+                    b"glMultiDrawElementsIndirect\0",
+                ),
                 mode,
                 type_,
                 indirect,
@@ -23251,7 +25642,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn MultiDrawElementsIndirect_is_loaded(&self) -> bool {
-            !self.glMultiDrawElementsIndirect_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glMultiDrawElementsIndirect_p,
+                    b"glMultiDrawElementsIndirect\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glMultiDrawElementsIndirectCount](http://docs.gl/gl4/glMultiDrawElementsIndirectCount)(mode, type_, indirect, drawcount, maxdrawcount, stride)
         /// * `mode` group: PrimitiveType
@@ -23273,7 +25670,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glMultiDrawElementsIndirectCount",
-                &self.glMultiDrawElementsIndirectCount_p,
+                self.lazy(
+                    &self.glMultiDrawElementsIndirectCount_p,
+                    b"glMultiDrawElementsIndirectCount\0",
+                ),
                 mode,
                 type_,
                 indirect,
@@ -23302,7 +25702,10 @@ pub mod struct_commands {
         #[doc(hidden)]
         pub fn MultiDrawElementsIndirectCount_is_loaded(&self) -> bool {
             !self
-                .glMultiDrawElementsIndirectCount_p
+                .lazy(
+                    &self.glMultiDrawElementsIndirectCount_p,
+                    b"glMultiDrawElementsIndirectCount\0",
+                )
                 .load(RELAX)
                 .is_null()
         }
@@ -23330,7 +25733,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glNamedBufferData",
-                &self.glNamedBufferData_p,
+                self.lazy(&self.glNamedBufferData_p, b"glNamedBufferData\0"),
                 buffer,
                 size,
                 data,
@@ -23356,7 +25759,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn NamedBufferData_is_loaded(&self) -> bool {
-            !self.glNamedBufferData_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glNamedBufferData_p, b"glNamedBufferData\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glNamedBufferStorage](http://docs.gl/gl4/glNamedBufferStorage)(buffer, size, data, flags)
         /// * `size` group: BufferSize
@@ -23383,7 +25789,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glNamedBufferStorage",
-                &self.glNamedBufferStorage_p,
+                self.lazy(&self.glNamedBufferStorage_p, b"glNamedBufferStorage\0"),
                 buffer,
                 size,
                 data,
@@ -23409,7 +25815,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn NamedBufferStorage_is_loaded(&self) -> bool {
-            !self.glNamedBufferStorage_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glNamedBufferStorage_p, b"glNamedBufferStorage\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glNamedBufferSubData](http://docs.gl/gl4/glNamedBufferSubData)(buffer, offset, size, data)
         /// * `size` group: BufferSize
@@ -23435,7 +25844,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glNamedBufferSubData",
-                &self.glNamedBufferSubData_p,
+                self.lazy(&self.glNamedBufferSubData_p, b"glNamedBufferSubData\0"),
                 buffer,
                 offset,
                 size,
@@ -23461,7 +25870,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn NamedBufferSubData_is_loaded(&self) -> bool {
-            !self.glNamedBufferSubData_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glNamedBufferSubData_p, b"glNamedBufferSubData\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glNamedFramebufferDrawBuffer](http://docs.gl/gl4/glNamedFramebufferDrawBuffer)(framebuffer, buf)
         /// * `buf` group: ColorBuffer
@@ -23478,7 +25890,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glNamedFramebufferDrawBuffer",
-                &self.glNamedFramebufferDrawBuffer_p,
+                self.lazy(
+                    &self.glNamedFramebufferDrawBuffer_p,
+                    b"glNamedFramebufferDrawBuffer\0",
+                ),
                 framebuffer,
                 buf,
             );
@@ -23502,7 +25917,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn NamedFramebufferDrawBuffer_is_loaded(&self) -> bool {
-            !self.glNamedFramebufferDrawBuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glNamedFramebufferDrawBuffer_p,
+                    b"glNamedFramebufferDrawBuffer\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glNamedFramebufferDrawBuffers](http://docs.gl/gl4/glNamedFramebufferDrawBuffers)(framebuffer, n, bufs)
         /// * `bufs` group: ColorBuffer
@@ -23525,7 +25946,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glNamedFramebufferDrawBuffers",
-                &self.glNamedFramebufferDrawBuffers_p,
+                self.lazy(
+                    &self.glNamedFramebufferDrawBuffers_p,
+                    b"glNamedFramebufferDrawBuffers\0",
+                ),
                 framebuffer,
                 n,
                 bufs,
@@ -23550,7 +25974,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn NamedFramebufferDrawBuffers_is_loaded(&self) -> bool {
-            !self.glNamedFramebufferDrawBuffers_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glNamedFramebufferDrawBuffers_p,
+                    b"glNamedFramebufferDrawBuffers\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glNamedFramebufferParameteri](http://docs.gl/gl4/glNamedFramebufferParameter)(framebuffer, pname, param)
         /// * `pname` group: FramebufferParameterName
@@ -23573,7 +26003,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glNamedFramebufferParameteri",
-                &self.glNamedFramebufferParameteri_p,
+                self.lazy(
+                    &self.glNamedFramebufferParameteri_p,
+                    b"glNamedFramebufferParameteri\0",
+                ),
                 framebuffer,
                 pname,
                 param,
@@ -23598,7 +26031,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn NamedFramebufferParameteri_is_loaded(&self) -> bool {
-            !self.glNamedFramebufferParameteri_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glNamedFramebufferParameteri_p,
+                    b"glNamedFramebufferParameteri\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glNamedFramebufferReadBuffer](http://docs.gl/gl4/glNamedFramebufferReadBuffer)(framebuffer, src)
         /// * `src` group: ColorBuffer
@@ -23615,7 +26054,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glNamedFramebufferReadBuffer",
-                &self.glNamedFramebufferReadBuffer_p,
+                self.lazy(
+                    &self.glNamedFramebufferReadBuffer_p,
+                    b"glNamedFramebufferReadBuffer\0",
+                ),
                 framebuffer,
                 src,
             );
@@ -23639,7 +26081,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn NamedFramebufferReadBuffer_is_loaded(&self) -> bool {
-            !self.glNamedFramebufferReadBuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glNamedFramebufferReadBuffer_p,
+                    b"glNamedFramebufferReadBuffer\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glNamedFramebufferRenderbuffer](http://docs.gl/gl4/glNamedFramebufferRenderbuffer)(framebuffer, attachment, renderbuffertarget, renderbuffer)
         /// * `attachment` group: FramebufferAttachment
@@ -23665,7 +26113,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glNamedFramebufferRenderbuffer",
-                &self.glNamedFramebufferRenderbuffer_p,
+                self.lazy(
+                    &self.glNamedFramebufferRenderbuffer_p,
+                    b"glNamedFramebufferRenderbuffer\0",
+                ),
                 framebuffer,
                 attachment,
                 renderbuffertarget,
@@ -23691,7 +26142,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn NamedFramebufferRenderbuffer_is_loaded(&self) -> bool {
-            !self.glNamedFramebufferRenderbuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glNamedFramebufferRenderbuffer_p,
+                    b"glNamedFramebufferRenderbuffer\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glNamedFramebufferTexture](http://docs.gl/gl4/glNamedFramebufferTexture)(framebuffer, attachment, texture, level)
         /// * `attachment` group: FramebufferAttachment
@@ -23716,7 +26173,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glNamedFramebufferTexture",
-                &self.glNamedFramebufferTexture_p,
+                self.lazy(
+                    &self.glNamedFramebufferTexture_p,
+                    b"glNamedFramebufferTexture\0",
+                ),
                 framebuffer,
                 attachment,
                 texture,
@@ -23742,7 +26202,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn NamedFramebufferTexture_is_loaded(&self) -> bool {
-            !self.glNamedFramebufferTexture_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glNamedFramebufferTexture_p,
+                    b"glNamedFramebufferTexture\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glNamedFramebufferTextureLayer](http://docs.gl/gl4/glNamedFramebufferTextureLayer)(framebuffer, attachment, texture, level, layer)
         /// * `attachment` group: FramebufferAttachment
@@ -23769,7 +26235,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glNamedFramebufferTextureLayer",
-                &self.glNamedFramebufferTextureLayer_p,
+                self.lazy(
+                    &self.glNamedFramebufferTextureLayer_p,
+                    b"glNamedFramebufferTextureLayer\0",
+                ),
                 framebuffer,
                 attachment,
                 texture,
@@ -23796,7 +26265,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn NamedFramebufferTextureLayer_is_loaded(&self) -> bool {
-            !self.glNamedFramebufferTextureLayer_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glNamedFramebufferTextureLayer_p,
+                    b"glNamedFramebufferTextureLayer\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glNamedRenderbufferStorage](http://docs.gl/gl4/glNamedRenderbufferStorage)(renderbuffer, internalformat, width, height)
         /// * `internalformat` group: InternalFormat
@@ -23821,7 +26296,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glNamedRenderbufferStorage",
-                &self.glNamedRenderbufferStorage_p,
+                self.lazy(
+                    &self.glNamedRenderbufferStorage_p,
+                    b"glNamedRenderbufferStorage\0",
+                ),
                 renderbuffer,
                 internalformat,
                 width,
@@ -23847,7 +26325,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn NamedRenderbufferStorage_is_loaded(&self) -> bool {
-            !self.glNamedRenderbufferStorage_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glNamedRenderbufferStorage_p,
+                    b"glNamedRenderbufferStorage\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glNamedRenderbufferStorageMultisample](http://docs.gl/gl4/glNamedRenderbufferStorageMultisample)(renderbuffer, samples, internalformat, width, height)
         /// * `internalformat` group: InternalFormat
@@ -23867,7 +26351,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glNamedRenderbufferStorageMultisample",
-                &self.glNamedRenderbufferStorageMultisample_p,
+                self.lazy(
+                    &self.glNamedRenderbufferStorageMultisample_p,
+                    b"glNamedRenderbufferStorageMultisample\0",
+                ),
                 renderbuffer,
                 samples,
                 internalformat,
@@ -23895,7 +26382,10 @@ pub mod struct_commands {
         #[doc(hidden)]
         pub fn NamedRenderbufferStorageMultisample_is_loaded(&self) -> bool {
             !self
-                .glNamedRenderbufferStorageMultisample_p
+                .lazy(
+                    &self.glNamedRenderbufferStorageMultisample_p,
+                    b"glNamedRenderbufferStorageMultisample\0",
+                )
                 .load(RELAX)
                 .is_null()
         }
@@ -23923,7 +26413,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glObjectLabel",
-                &self.glObjectLabel_p,
+                self.lazy(&self.glObjectLabel_p, b"glObjectLabel\0"),
                 identifier,
                 name,
                 length,
@@ -23945,7 +26435,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ObjectLabel_is_loaded(&self) -> bool {
-            !self.glObjectLabel_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glObjectLabel_p, b"glObjectLabel\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glObjectLabelKHR](http://docs.gl/gl4/glObjectLabelKHR)(identifier, name, length, label)
         /// * `identifier` group: ObjectIdentifier
@@ -23971,7 +26464,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glObjectLabelKHR",
-                &self.glObjectLabelKHR_p,
+                self.lazy(&self.glObjectLabelKHR_p, b"glObjectLabelKHR\0"),
                 identifier,
                 name,
                 length,
@@ -23997,7 +26490,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ObjectLabelKHR_is_loaded(&self) -> bool {
-            !self.glObjectLabelKHR_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glObjectLabelKHR_p, b"glObjectLabelKHR\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glObjectPtrLabel](http://docs.gl/gl4/glObjectPtrLabel)(ptr, length, label)
         /// * `label` len: COMPSIZE(label,length)
@@ -24020,7 +26516,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glObjectPtrLabel",
-                &self.glObjectPtrLabel_p,
+                self.lazy(&self.glObjectPtrLabel_p, b"glObjectPtrLabel\0"),
                 ptr,
                 length,
                 label,
@@ -24045,7 +26541,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ObjectPtrLabel_is_loaded(&self) -> bool {
-            !self.glObjectPtrLabel_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glObjectPtrLabel_p, b"glObjectPtrLabel\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glObjectPtrLabelKHR](http://docs.gl/gl4/glObjectPtrLabelKHR)(ptr, length, label)
         /// * alias of: [`glObjectPtrLabel`]
@@ -24068,7 +26567,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glObjectPtrLabelKHR",
-                &self.glObjectPtrLabelKHR_p,
+                self.lazy(&self.glObjectPtrLabelKHR_p, b"glObjectPtrLabelKHR\0"),
                 ptr,
                 length,
                 label,
@@ -24093,7 +26592,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ObjectPtrLabelKHR_is_loaded(&self) -> bool {
-            !self.glObjectPtrLabelKHR_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glObjectPtrLabelKHR_p, b"glObjectPtrLabelKHR\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glPatchParameterfv](http://docs.gl/gl4/glPatchParameter)(pname, values)
         /// * `pname` group: PatchParameterName
@@ -24107,7 +26609,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glPatchParameterfv",
-                &self.glPatchParameterfv_p,
+                self.lazy(&self.glPatchParameterfv_p, b"glPatchParameterfv\0"),
                 pname,
                 values,
             );
@@ -24131,7 +26633,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn PatchParameterfv_is_loaded(&self) -> bool {
-            !self.glPatchParameterfv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glPatchParameterfv_p, b"glPatchParameterfv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glPatchParameteri](http://docs.gl/gl4/glPatchParameter)(pname, value)
         /// * `pname` group: PatchParameterName
@@ -24142,8 +26647,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.PatchParameteri({:#X}, {:?});", pname, value);
             }
-            let out =
-                call_atomic_ptr_2arg("glPatchParameteri", &self.glPatchParameteri_p, pname, value);
+            let out = call_atomic_ptr_2arg(
+                "glPatchParameteri",
+                self.lazy(&self.glPatchParameteri_p, b"glPatchParameteri\0"),
+                pname,
+                value,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glPatchParameteri");
@@ -24164,7 +26673,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn PatchParameteri_is_loaded(&self) -> bool {
-            !self.glPatchParameteri_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glPatchParameteri_p, b"glPatchParameteri\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glPauseTransformFeedback](http://docs.gl/gl4/glPauseTransformFeedback)()
         #[cfg_attr(feature = "inline", inline)]
@@ -24174,8 +26686,13 @@ pub mod struct_commands {
             {
                 trace!("calling gl.PauseTransformFeedback();",);
             }
-            let out =
-                call_atomic_ptr_0arg("glPauseTransformFeedback", &self.glPauseTransformFeedback_p);
+            let out = call_atomic_ptr_0arg(
+                "glPauseTransformFeedback",
+                self.lazy(
+                    &self.glPauseTransformFeedback_p,
+                    b"glPauseTransformFeedback\0",
+                ),
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glPauseTransformFeedback");
@@ -24196,7 +26713,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn PauseTransformFeedback_is_loaded(&self) -> bool {
-            !self.glPauseTransformFeedback_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glPauseTransformFeedback_p,
+                    b"glPauseTransformFeedback\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glPixelStoref](http://docs.gl/gl4/glPixelStore)(pname, param)
         /// * `pname` group: PixelStoreParameter
@@ -24208,7 +26731,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.PixelStoref({:#X}, {:?});", pname, param);
             }
-            let out = call_atomic_ptr_2arg("glPixelStoref", &self.glPixelStoref_p, pname, param);
+            let out = call_atomic_ptr_2arg(
+                "glPixelStoref",
+                self.lazy(&self.glPixelStoref_p, b"glPixelStoref\0"),
+                pname,
+                param,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glPixelStoref");
@@ -24225,7 +26753,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn PixelStoref_is_loaded(&self) -> bool {
-            !self.glPixelStoref_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glPixelStoref_p, b"glPixelStoref\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glPixelStorei](http://docs.gl/gl4/glPixelStore)(pname, param)
         /// * `pname` group: PixelStoreParameter
@@ -24237,7 +26768,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.PixelStorei({:#X}, {:?});", pname, param);
             }
-            let out = call_atomic_ptr_2arg("glPixelStorei", &self.glPixelStorei_p, pname, param);
+            let out = call_atomic_ptr_2arg(
+                "glPixelStorei",
+                self.lazy(&self.glPixelStorei_p, b"glPixelStorei\0"),
+                pname,
+                param,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glPixelStorei");
@@ -24254,7 +26790,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn PixelStorei_is_loaded(&self) -> bool {
-            !self.glPixelStorei_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glPixelStorei_p, b"glPixelStorei\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glPointParameterf](http://docs.gl/gl4/glPointParameter)(pname, param)
         /// * `pname` group: PointParameterNameARB
@@ -24266,8 +26805,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.PointParameterf({:#X}, {:?});", pname, param);
             }
-            let out =
-                call_atomic_ptr_2arg("glPointParameterf", &self.glPointParameterf_p, pname, param);
+            let out = call_atomic_ptr_2arg(
+                "glPointParameterf",
+                self.lazy(&self.glPointParameterf_p, b"glPointParameterf\0"),
+                pname,
+                param,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glPointParameterf");
@@ -24288,7 +26831,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn PointParameterf_is_loaded(&self) -> bool {
-            !self.glPointParameterf_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glPointParameterf_p, b"glPointParameterf\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glPointParameterfv](http://docs.gl/gl4/glPointParameter)(pname, params)
         /// * `pname` group: PointParameterNameARB
@@ -24303,7 +26849,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glPointParameterfv",
-                &self.glPointParameterfv_p,
+                self.lazy(&self.glPointParameterfv_p, b"glPointParameterfv\0"),
                 pname,
                 params,
             );
@@ -24327,7 +26873,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn PointParameterfv_is_loaded(&self) -> bool {
-            !self.glPointParameterfv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glPointParameterfv_p, b"glPointParameterfv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glPointParameteri](http://docs.gl/gl4/glPointParameter)(pname, param)
         /// * `pname` group: PointParameterNameARB
@@ -24338,8 +26887,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.PointParameteri({:#X}, {:?});", pname, param);
             }
-            let out =
-                call_atomic_ptr_2arg("glPointParameteri", &self.glPointParameteri_p, pname, param);
+            let out = call_atomic_ptr_2arg(
+                "glPointParameteri",
+                self.lazy(&self.glPointParameteri_p, b"glPointParameteri\0"),
+                pname,
+                param,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glPointParameteri");
@@ -24360,7 +26913,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn PointParameteri_is_loaded(&self) -> bool {
-            !self.glPointParameteri_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glPointParameteri_p, b"glPointParameteri\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glPointParameteriv](http://docs.gl/gl4/glPointParameter)(pname, params)
         /// * `pname` group: PointParameterNameARB
@@ -24374,7 +26930,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glPointParameteriv",
-                &self.glPointParameteriv_p,
+                self.lazy(&self.glPointParameteriv_p, b"glPointParameteriv\0"),
                 pname,
                 params,
             );
@@ -24398,7 +26954,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn PointParameteriv_is_loaded(&self) -> bool {
-            !self.glPointParameteriv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glPointParameteriv_p, b"glPointParameteriv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glPointSize](http://docs.gl/gl4/glPointSize)(size)
         /// * `size` group: CheckedFloat32
@@ -24409,7 +26968,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.PointSize({:?});", size);
             }
-            let out = call_atomic_ptr_1arg("glPointSize", &self.glPointSize_p, size);
+            let out = call_atomic_ptr_1arg(
+                "glPointSize",
+                self.lazy(&self.glPointSize_p, b"glPointSize\0"),
+                size,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glPointSize");
@@ -24426,7 +26989,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn PointSize_is_loaded(&self) -> bool {
-            !self.glPointSize_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glPointSize_p, b"glPointSize\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glPolygonMode](http://docs.gl/gl4/glPolygonMode)(face, mode)
         /// * `face` group: MaterialFace
@@ -24438,7 +27004,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.PolygonMode({:#X}, {:#X});", face, mode);
             }
-            let out = call_atomic_ptr_2arg("glPolygonMode", &self.glPolygonMode_p, face, mode);
+            let out = call_atomic_ptr_2arg(
+                "glPolygonMode",
+                self.lazy(&self.glPolygonMode_p, b"glPolygonMode\0"),
+                face,
+                mode,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glPolygonMode");
@@ -24455,7 +27026,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn PolygonMode_is_loaded(&self) -> bool {
-            !self.glPolygonMode_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glPolygonMode_p, b"glPolygonMode\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glPolygonOffset](http://docs.gl/gl4/glPolygonOffset)(factor, units)
         #[cfg_attr(feature = "inline", inline)]
@@ -24465,8 +27039,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.PolygonOffset({:?}, {:?});", factor, units);
             }
-            let out =
-                call_atomic_ptr_2arg("glPolygonOffset", &self.glPolygonOffset_p, factor, units);
+            let out = call_atomic_ptr_2arg(
+                "glPolygonOffset",
+                self.lazy(&self.glPolygonOffset_p, b"glPolygonOffset\0"),
+                factor,
+                units,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glPolygonOffset");
@@ -24487,7 +27065,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn PolygonOffset_is_loaded(&self) -> bool {
-            !self.glPolygonOffset_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glPolygonOffset_p, b"glPolygonOffset\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glPolygonOffsetClamp](http://docs.gl/gl4/glPolygonOffsetClamp)(factor, units, clamp)
         #[cfg_attr(feature = "inline", inline)]
@@ -24504,7 +27085,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glPolygonOffsetClamp",
-                &self.glPolygonOffsetClamp_p,
+                self.lazy(&self.glPolygonOffsetClamp_p, b"glPolygonOffsetClamp\0"),
                 factor,
                 units,
                 clamp,
@@ -24529,7 +27110,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn PolygonOffsetClamp_is_loaded(&self) -> bool {
-            !self.glPolygonOffsetClamp_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glPolygonOffsetClamp_p, b"glPolygonOffsetClamp\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glPopDebugGroup](http://docs.gl/gl4/glPopDebugGroup)()
         #[cfg_attr(feature = "inline", inline)]
@@ -24539,7 +27123,10 @@ pub mod struct_commands {
             {
                 trace!("calling gl.PopDebugGroup();",);
             }
-            let out = call_atomic_ptr_0arg("glPopDebugGroup", &self.glPopDebugGroup_p);
+            let out = call_atomic_ptr_0arg(
+                "glPopDebugGroup",
+                self.lazy(&self.glPopDebugGroup_p, b"glPopDebugGroup\0"),
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glPopDebugGroup");
@@ -24560,7 +27147,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn PopDebugGroup_is_loaded(&self) -> bool {
-            !self.glPopDebugGroup_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glPopDebugGroup_p, b"glPopDebugGroup\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glPopDebugGroupKHR](http://docs.gl/gl4/glPopDebugGroupKHR)()
         /// * alias of: [`glPopDebugGroup`]
@@ -24571,7 +27161,10 @@ pub mod struct_commands {
             {
                 trace!("calling gl.PopDebugGroupKHR();",);
             }
-            let out = call_atomic_ptr_0arg("glPopDebugGroupKHR", &self.glPopDebugGroupKHR_p);
+            let out = call_atomic_ptr_0arg(
+                "glPopDebugGroupKHR",
+                self.lazy(&self.glPopDebugGroupKHR_p, b"glPopDebugGroupKHR\0"),
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glPopDebugGroupKHR");
@@ -24592,7 +27185,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn PopDebugGroupKHR_is_loaded(&self) -> bool {
-            !self.glPopDebugGroupKHR_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glPopDebugGroupKHR_p, b"glPopDebugGroupKHR\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glPrimitiveBoundingBox](http://docs.gl/gl4/glPrimitiveBoundingBox)(minX, minY, minZ, minW, maxX, maxY, maxZ, maxW)
         #[cfg_attr(feature = "inline", inline)]
@@ -24614,7 +27210,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_8arg(
                 "glPrimitiveBoundingBox",
-                &self.glPrimitiveBoundingBox_p,
+                self.lazy(&self.glPrimitiveBoundingBox_p, b"glPrimitiveBoundingBox\0"),
                 minX,
                 minY,
                 minZ,
@@ -24644,7 +27240,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn PrimitiveBoundingBox_is_loaded(&self) -> bool {
-            !self.glPrimitiveBoundingBox_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glPrimitiveBoundingBox_p, b"glPrimitiveBoundingBox\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glPrimitiveRestartIndex](http://docs.gl/gl4/glPrimitiveRestartIndex)(index)
         #[cfg_attr(feature = "inline", inline)]
@@ -24656,7 +27255,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_1arg(
                 "glPrimitiveRestartIndex",
-                &self.glPrimitiveRestartIndex_p,
+                self.lazy(
+                    &self.glPrimitiveRestartIndex_p,
+                    b"glPrimitiveRestartIndex\0",
+                ),
                 index,
             );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
@@ -24679,7 +27281,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn PrimitiveRestartIndex_is_loaded(&self) -> bool {
-            !self.glPrimitiveRestartIndex_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glPrimitiveRestartIndex_p,
+                    b"glPrimitiveRestartIndex\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramBinary](http://docs.gl/gl4/glProgramBinary)(program, binaryFormat, binary, length)
         /// * `binary` len: length
@@ -24704,7 +27312,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glProgramBinary",
-                &self.glProgramBinary_p,
+                self.lazy(&self.glProgramBinary_p, b"glProgramBinary\0"),
                 program,
                 binaryFormat,
                 binary,
@@ -24730,7 +27338,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramBinary_is_loaded(&self) -> bool {
-            !self.glProgramBinary_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramBinary_p, b"glProgramBinary\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramParameteri](http://docs.gl/gl4/glProgramParameteri)(program, pname, value)
         /// * `pname` group: ProgramParameterPName
@@ -24748,7 +27359,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glProgramParameteri",
-                &self.glProgramParameteri_p,
+                self.lazy(&self.glProgramParameteri_p, b"glProgramParameteri\0"),
                 program,
                 pname,
                 value,
@@ -24773,7 +27384,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramParameteri_is_loaded(&self) -> bool {
-            !self.glProgramParameteri_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramParameteri_p, b"glProgramParameteri\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform1d](http://docs.gl/gl4/glProgramUniform1d)(program, location, v0)
         #[cfg_attr(feature = "inline", inline)]
@@ -24790,7 +27404,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glProgramUniform1d",
-                &self.glProgramUniform1d_p,
+                self.lazy(&self.glProgramUniform1d_p, b"glProgramUniform1d\0"),
                 program,
                 location,
                 v0,
@@ -24815,7 +27429,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform1d_is_loaded(&self) -> bool {
-            !self.glProgramUniform1d_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform1d_p, b"glProgramUniform1d\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform1dv](http://docs.gl/gl4/glProgramUniform1dv)(program, location, count, value)
         /// * `value` len: count
@@ -24840,7 +27457,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glProgramUniform1dv",
-                &self.glProgramUniform1dv_p,
+                self.lazy(&self.glProgramUniform1dv_p, b"glProgramUniform1dv\0"),
                 program,
                 location,
                 count,
@@ -24866,7 +27483,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform1dv_is_loaded(&self) -> bool {
-            !self.glProgramUniform1dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform1dv_p, b"glProgramUniform1dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform1f](http://docs.gl/gl4/glProgramUniform)(program, location, v0)
         #[cfg_attr(feature = "inline", inline)]
@@ -24883,7 +27503,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glProgramUniform1f",
-                &self.glProgramUniform1f_p,
+                self.lazy(&self.glProgramUniform1f_p, b"glProgramUniform1f\0"),
                 program,
                 location,
                 v0,
@@ -24908,7 +27528,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform1f_is_loaded(&self) -> bool {
-            !self.glProgramUniform1f_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform1f_p, b"glProgramUniform1f\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform1fv](http://docs.gl/gl4/glProgramUniform)(program, location, count, value)
         /// * `value` len: count
@@ -24933,7 +27556,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glProgramUniform1fv",
-                &self.glProgramUniform1fv_p,
+                self.lazy(&self.glProgramUniform1fv_p, b"glProgramUniform1fv\0"),
                 program,
                 location,
                 count,
@@ -24959,7 +27582,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform1fv_is_loaded(&self) -> bool {
-            !self.glProgramUniform1fv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform1fv_p, b"glProgramUniform1fv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform1i](http://docs.gl/gl4/glProgramUniform)(program, location, v0)
         #[cfg_attr(feature = "inline", inline)]
@@ -24976,7 +27602,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glProgramUniform1i",
-                &self.glProgramUniform1i_p,
+                self.lazy(&self.glProgramUniform1i_p, b"glProgramUniform1i\0"),
                 program,
                 location,
                 v0,
@@ -25001,7 +27627,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform1i_is_loaded(&self) -> bool {
-            !self.glProgramUniform1i_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform1i_p, b"glProgramUniform1i\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform1iv](http://docs.gl/gl4/glProgramUniform)(program, location, count, value)
         /// * `value` len: count
@@ -25026,7 +27655,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glProgramUniform1iv",
-                &self.glProgramUniform1iv_p,
+                self.lazy(&self.glProgramUniform1iv_p, b"glProgramUniform1iv\0"),
                 program,
                 location,
                 count,
@@ -25052,7 +27681,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform1iv_is_loaded(&self) -> bool {
-            !self.glProgramUniform1iv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform1iv_p, b"glProgramUniform1iv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform1ui](http://docs.gl/gl4/glProgramUniform)(program, location, v0)
         #[cfg_attr(feature = "inline", inline)]
@@ -25069,7 +27701,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glProgramUniform1ui",
-                &self.glProgramUniform1ui_p,
+                self.lazy(&self.glProgramUniform1ui_p, b"glProgramUniform1ui\0"),
                 program,
                 location,
                 v0,
@@ -25094,7 +27726,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform1ui_is_loaded(&self) -> bool {
-            !self.glProgramUniform1ui_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform1ui_p, b"glProgramUniform1ui\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform1uiv](http://docs.gl/gl4/glProgramUniform)(program, location, count, value)
         /// * `value` len: count
@@ -25119,7 +27754,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glProgramUniform1uiv",
-                &self.glProgramUniform1uiv_p,
+                self.lazy(&self.glProgramUniform1uiv_p, b"glProgramUniform1uiv\0"),
                 program,
                 location,
                 count,
@@ -25145,7 +27780,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform1uiv_is_loaded(&self) -> bool {
-            !self.glProgramUniform1uiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform1uiv_p, b"glProgramUniform1uiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform2d](http://docs.gl/gl4/glProgramUniform2d)(program, location, v0, v1)
         #[cfg_attr(feature = "inline", inline)]
@@ -25169,7 +27807,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glProgramUniform2d",
-                &self.glProgramUniform2d_p,
+                self.lazy(&self.glProgramUniform2d_p, b"glProgramUniform2d\0"),
                 program,
                 location,
                 v0,
@@ -25195,7 +27833,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform2d_is_loaded(&self) -> bool {
-            !self.glProgramUniform2d_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform2d_p, b"glProgramUniform2d\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform2dv](http://docs.gl/gl4/glProgramUniform2dv)(program, location, count, value)
         /// * `value` len: count*2
@@ -25220,7 +27861,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glProgramUniform2dv",
-                &self.glProgramUniform2dv_p,
+                self.lazy(&self.glProgramUniform2dv_p, b"glProgramUniform2dv\0"),
                 program,
                 location,
                 count,
@@ -25246,7 +27887,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform2dv_is_loaded(&self) -> bool {
-            !self.glProgramUniform2dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform2dv_p, b"glProgramUniform2dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform2f](http://docs.gl/gl4/glProgramUniform)(program, location, v0, v1)
         #[cfg_attr(feature = "inline", inline)]
@@ -25270,7 +27914,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glProgramUniform2f",
-                &self.glProgramUniform2f_p,
+                self.lazy(&self.glProgramUniform2f_p, b"glProgramUniform2f\0"),
                 program,
                 location,
                 v0,
@@ -25296,7 +27940,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform2f_is_loaded(&self) -> bool {
-            !self.glProgramUniform2f_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform2f_p, b"glProgramUniform2f\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform2fv](http://docs.gl/gl4/glProgramUniform)(program, location, count, value)
         /// * `value` len: count*2
@@ -25321,7 +27968,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glProgramUniform2fv",
-                &self.glProgramUniform2fv_p,
+                self.lazy(&self.glProgramUniform2fv_p, b"glProgramUniform2fv\0"),
                 program,
                 location,
                 count,
@@ -25347,7 +27994,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform2fv_is_loaded(&self) -> bool {
-            !self.glProgramUniform2fv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform2fv_p, b"glProgramUniform2fv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform2i](http://docs.gl/gl4/glProgramUniform)(program, location, v0, v1)
         #[cfg_attr(feature = "inline", inline)]
@@ -25371,7 +28021,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glProgramUniform2i",
-                &self.glProgramUniform2i_p,
+                self.lazy(&self.glProgramUniform2i_p, b"glProgramUniform2i\0"),
                 program,
                 location,
                 v0,
@@ -25397,7 +28047,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform2i_is_loaded(&self) -> bool {
-            !self.glProgramUniform2i_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform2i_p, b"glProgramUniform2i\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform2iv](http://docs.gl/gl4/glProgramUniform)(program, location, count, value)
         /// * `value` len: count*2
@@ -25422,7 +28075,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glProgramUniform2iv",
-                &self.glProgramUniform2iv_p,
+                self.lazy(&self.glProgramUniform2iv_p, b"glProgramUniform2iv\0"),
                 program,
                 location,
                 count,
@@ -25448,7 +28101,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform2iv_is_loaded(&self) -> bool {
-            !self.glProgramUniform2iv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform2iv_p, b"glProgramUniform2iv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform2ui](http://docs.gl/gl4/glProgramUniform)(program, location, v0, v1)
         #[cfg_attr(feature = "inline", inline)]
@@ -25472,7 +28128,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glProgramUniform2ui",
-                &self.glProgramUniform2ui_p,
+                self.lazy(&self.glProgramUniform2ui_p, b"glProgramUniform2ui\0"),
                 program,
                 location,
                 v0,
@@ -25498,7 +28154,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform2ui_is_loaded(&self) -> bool {
-            !self.glProgramUniform2ui_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform2ui_p, b"glProgramUniform2ui\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform2uiv](http://docs.gl/gl4/glProgramUniform)(program, location, count, value)
         /// * `value` len: count*2
@@ -25523,7 +28182,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glProgramUniform2uiv",
-                &self.glProgramUniform2uiv_p,
+                self.lazy(&self.glProgramUniform2uiv_p, b"glProgramUniform2uiv\0"),
                 program,
                 location,
                 count,
@@ -25549,7 +28208,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform2uiv_is_loaded(&self) -> bool {
-            !self.glProgramUniform2uiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform2uiv_p, b"glProgramUniform2uiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform3d](http://docs.gl/gl4/glProgramUniform3d)(program, location, v0, v1, v2)
         #[cfg_attr(feature = "inline", inline)]
@@ -25575,7 +28237,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniform3d",
-                &self.glProgramUniform3d_p,
+                self.lazy(&self.glProgramUniform3d_p, b"glProgramUniform3d\0"),
                 program,
                 location,
                 v0,
@@ -25602,7 +28264,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform3d_is_loaded(&self) -> bool {
-            !self.glProgramUniform3d_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform3d_p, b"glProgramUniform3d\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform3dv](http://docs.gl/gl4/glProgramUniform3dv)(program, location, count, value)
         /// * `value` len: count*3
@@ -25627,7 +28292,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glProgramUniform3dv",
-                &self.glProgramUniform3dv_p,
+                self.lazy(&self.glProgramUniform3dv_p, b"glProgramUniform3dv\0"),
                 program,
                 location,
                 count,
@@ -25653,7 +28318,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform3dv_is_loaded(&self) -> bool {
-            !self.glProgramUniform3dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform3dv_p, b"glProgramUniform3dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform3f](http://docs.gl/gl4/glProgramUniform)(program, location, v0, v1, v2)
         #[cfg_attr(feature = "inline", inline)]
@@ -25679,7 +28347,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniform3f",
-                &self.glProgramUniform3f_p,
+                self.lazy(&self.glProgramUniform3f_p, b"glProgramUniform3f\0"),
                 program,
                 location,
                 v0,
@@ -25706,7 +28374,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform3f_is_loaded(&self) -> bool {
-            !self.glProgramUniform3f_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform3f_p, b"glProgramUniform3f\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform3fv](http://docs.gl/gl4/glProgramUniform)(program, location, count, value)
         /// * `value` len: count*3
@@ -25731,7 +28402,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glProgramUniform3fv",
-                &self.glProgramUniform3fv_p,
+                self.lazy(&self.glProgramUniform3fv_p, b"glProgramUniform3fv\0"),
                 program,
                 location,
                 count,
@@ -25757,7 +28428,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform3fv_is_loaded(&self) -> bool {
-            !self.glProgramUniform3fv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform3fv_p, b"glProgramUniform3fv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform3i](http://docs.gl/gl4/glProgramUniform)(program, location, v0, v1, v2)
         #[cfg_attr(feature = "inline", inline)]
@@ -25783,7 +28457,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniform3i",
-                &self.glProgramUniform3i_p,
+                self.lazy(&self.glProgramUniform3i_p, b"glProgramUniform3i\0"),
                 program,
                 location,
                 v0,
@@ -25810,7 +28484,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform3i_is_loaded(&self) -> bool {
-            !self.glProgramUniform3i_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform3i_p, b"glProgramUniform3i\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform3iv](http://docs.gl/gl4/glProgramUniform)(program, location, count, value)
         /// * `value` len: count*3
@@ -25835,7 +28512,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glProgramUniform3iv",
-                &self.glProgramUniform3iv_p,
+                self.lazy(&self.glProgramUniform3iv_p, b"glProgramUniform3iv\0"),
                 program,
                 location,
                 count,
@@ -25861,7 +28538,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform3iv_is_loaded(&self) -> bool {
-            !self.glProgramUniform3iv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform3iv_p, b"glProgramUniform3iv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform3ui](http://docs.gl/gl4/glProgramUniform)(program, location, v0, v1, v2)
         #[cfg_attr(feature = "inline", inline)]
@@ -25887,7 +28567,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniform3ui",
-                &self.glProgramUniform3ui_p,
+                self.lazy(&self.glProgramUniform3ui_p, b"glProgramUniform3ui\0"),
                 program,
                 location,
                 v0,
@@ -25914,7 +28594,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform3ui_is_loaded(&self) -> bool {
-            !self.glProgramUniform3ui_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform3ui_p, b"glProgramUniform3ui\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform3uiv](http://docs.gl/gl4/glProgramUniform)(program, location, count, value)
         /// * `value` len: count*3
@@ -25939,7 +28622,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glProgramUniform3uiv",
-                &self.glProgramUniform3uiv_p,
+                self.lazy(&self.glProgramUniform3uiv_p, b"glProgramUniform3uiv\0"),
                 program,
                 location,
                 count,
@@ -25965,7 +28648,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform3uiv_is_loaded(&self) -> bool {
-            !self.glProgramUniform3uiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform3uiv_p, b"glProgramUniform3uiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform4d](http://docs.gl/gl4/glProgramUniform4d)(program, location, v0, v1, v2, v3)
         #[cfg_attr(feature = "inline", inline)]
@@ -25993,7 +28679,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glProgramUniform4d",
-                &self.glProgramUniform4d_p,
+                self.lazy(&self.glProgramUniform4d_p, b"glProgramUniform4d\0"),
                 program,
                 location,
                 v0,
@@ -26021,7 +28707,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform4d_is_loaded(&self) -> bool {
-            !self.glProgramUniform4d_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform4d_p, b"glProgramUniform4d\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform4dv](http://docs.gl/gl4/glProgramUniform4dv)(program, location, count, value)
         /// * `value` len: count*4
@@ -26046,7 +28735,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glProgramUniform4dv",
-                &self.glProgramUniform4dv_p,
+                self.lazy(&self.glProgramUniform4dv_p, b"glProgramUniform4dv\0"),
                 program,
                 location,
                 count,
@@ -26072,7 +28761,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform4dv_is_loaded(&self) -> bool {
-            !self.glProgramUniform4dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform4dv_p, b"glProgramUniform4dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform4f](http://docs.gl/gl4/glProgramUniform)(program, location, v0, v1, v2, v3)
         #[cfg_attr(feature = "inline", inline)]
@@ -26100,7 +28792,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glProgramUniform4f",
-                &self.glProgramUniform4f_p,
+                self.lazy(&self.glProgramUniform4f_p, b"glProgramUniform4f\0"),
                 program,
                 location,
                 v0,
@@ -26128,7 +28820,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform4f_is_loaded(&self) -> bool {
-            !self.glProgramUniform4f_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform4f_p, b"glProgramUniform4f\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform4fv](http://docs.gl/gl4/glProgramUniform)(program, location, count, value)
         /// * `value` len: count*4
@@ -26153,7 +28848,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glProgramUniform4fv",
-                &self.glProgramUniform4fv_p,
+                self.lazy(&self.glProgramUniform4fv_p, b"glProgramUniform4fv\0"),
                 program,
                 location,
                 count,
@@ -26179,7 +28874,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform4fv_is_loaded(&self) -> bool {
-            !self.glProgramUniform4fv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform4fv_p, b"glProgramUniform4fv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform4i](http://docs.gl/gl4/glProgramUniform)(program, location, v0, v1, v2, v3)
         #[cfg_attr(feature = "inline", inline)]
@@ -26207,7 +28905,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glProgramUniform4i",
-                &self.glProgramUniform4i_p,
+                self.lazy(&self.glProgramUniform4i_p, b"glProgramUniform4i\0"),
                 program,
                 location,
                 v0,
@@ -26235,7 +28933,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform4i_is_loaded(&self) -> bool {
-            !self.glProgramUniform4i_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform4i_p, b"glProgramUniform4i\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform4iv](http://docs.gl/gl4/glProgramUniform)(program, location, count, value)
         /// * `value` len: count*4
@@ -26260,7 +28961,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glProgramUniform4iv",
-                &self.glProgramUniform4iv_p,
+                self.lazy(&self.glProgramUniform4iv_p, b"glProgramUniform4iv\0"),
                 program,
                 location,
                 count,
@@ -26286,7 +28987,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform4iv_is_loaded(&self) -> bool {
-            !self.glProgramUniform4iv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform4iv_p, b"glProgramUniform4iv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform4ui](http://docs.gl/gl4/glProgramUniform)(program, location, v0, v1, v2, v3)
         #[cfg_attr(feature = "inline", inline)]
@@ -26314,7 +29018,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glProgramUniform4ui",
-                &self.glProgramUniform4ui_p,
+                self.lazy(&self.glProgramUniform4ui_p, b"glProgramUniform4ui\0"),
                 program,
                 location,
                 v0,
@@ -26342,7 +29046,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform4ui_is_loaded(&self) -> bool {
-            !self.glProgramUniform4ui_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform4ui_p, b"glProgramUniform4ui\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniform4uiv](http://docs.gl/gl4/glProgramUniform)(program, location, count, value)
         /// * `value` len: count*4
@@ -26367,7 +29074,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glProgramUniform4uiv",
-                &self.glProgramUniform4uiv_p,
+                self.lazy(&self.glProgramUniform4uiv_p, b"glProgramUniform4uiv\0"),
                 program,
                 location,
                 count,
@@ -26393,7 +29100,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniform4uiv_is_loaded(&self) -> bool {
-            !self.glProgramUniform4uiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProgramUniform4uiv_p, b"glProgramUniform4uiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniformMatrix2dv](http://docs.gl/gl4/glProgramUniformMatrix2dv)(program, location, count, transpose, value)
         /// * `value` len: count*4
@@ -26420,7 +29130,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniformMatrix2dv",
-                &self.glProgramUniformMatrix2dv_p,
+                self.lazy(
+                    &self.glProgramUniformMatrix2dv_p,
+                    b"glProgramUniformMatrix2dv\0",
+                ),
                 program,
                 location,
                 count,
@@ -26447,7 +29160,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniformMatrix2dv_is_loaded(&self) -> bool {
-            !self.glProgramUniformMatrix2dv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glProgramUniformMatrix2dv_p,
+                    b"glProgramUniformMatrix2dv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniformMatrix2fv](http://docs.gl/gl4/glProgramUniform)(program, location, count, transpose, value)
         /// * `value` len: count*4
@@ -26474,7 +29193,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniformMatrix2fv",
-                &self.glProgramUniformMatrix2fv_p,
+                self.lazy(
+                    &self.glProgramUniformMatrix2fv_p,
+                    b"glProgramUniformMatrix2fv\0",
+                ),
                 program,
                 location,
                 count,
@@ -26501,7 +29223,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniformMatrix2fv_is_loaded(&self) -> bool {
-            !self.glProgramUniformMatrix2fv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glProgramUniformMatrix2fv_p,
+                    b"glProgramUniformMatrix2fv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniformMatrix2x3dv](http://docs.gl/gl4/glProgramUniformMatrix2x3dv)(program, location, count, transpose, value)
         /// * `value` len: count*6
@@ -26528,7 +29256,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniformMatrix2x3dv",
-                &self.glProgramUniformMatrix2x3dv_p,
+                self.lazy(
+                    &self.glProgramUniformMatrix2x3dv_p,
+                    b"glProgramUniformMatrix2x3dv\0",
+                ),
                 program,
                 location,
                 count,
@@ -26555,7 +29286,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniformMatrix2x3dv_is_loaded(&self) -> bool {
-            !self.glProgramUniformMatrix2x3dv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glProgramUniformMatrix2x3dv_p,
+                    b"glProgramUniformMatrix2x3dv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniformMatrix2x3fv](http://docs.gl/gl4/glProgramUniform)(program, location, count, transpose, value)
         /// * `value` len: count*6
@@ -26582,7 +29319,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniformMatrix2x3fv",
-                &self.glProgramUniformMatrix2x3fv_p,
+                self.lazy(
+                    &self.glProgramUniformMatrix2x3fv_p,
+                    b"glProgramUniformMatrix2x3fv\0",
+                ),
                 program,
                 location,
                 count,
@@ -26609,7 +29349,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniformMatrix2x3fv_is_loaded(&self) -> bool {
-            !self.glProgramUniformMatrix2x3fv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glProgramUniformMatrix2x3fv_p,
+                    b"glProgramUniformMatrix2x3fv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniformMatrix2x4dv](http://docs.gl/gl4/glProgramUniformMatrix2x4dv)(program, location, count, transpose, value)
         /// * `value` len: count*8
@@ -26636,7 +29382,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniformMatrix2x4dv",
-                &self.glProgramUniformMatrix2x4dv_p,
+                self.lazy(
+                    &self.glProgramUniformMatrix2x4dv_p,
+                    b"glProgramUniformMatrix2x4dv\0",
+                ),
                 program,
                 location,
                 count,
@@ -26663,7 +29412,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniformMatrix2x4dv_is_loaded(&self) -> bool {
-            !self.glProgramUniformMatrix2x4dv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glProgramUniformMatrix2x4dv_p,
+                    b"glProgramUniformMatrix2x4dv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniformMatrix2x4fv](http://docs.gl/gl4/glProgramUniform)(program, location, count, transpose, value)
         /// * `value` len: count*8
@@ -26690,7 +29445,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniformMatrix2x4fv",
-                &self.glProgramUniformMatrix2x4fv_p,
+                self.lazy(
+                    &self.glProgramUniformMatrix2x4fv_p,
+                    b"glProgramUniformMatrix2x4fv\0",
+                ),
                 program,
                 location,
                 count,
@@ -26717,7 +29475,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniformMatrix2x4fv_is_loaded(&self) -> bool {
-            !self.glProgramUniformMatrix2x4fv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glProgramUniformMatrix2x4fv_p,
+                    b"glProgramUniformMatrix2x4fv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniformMatrix3dv](http://docs.gl/gl4/glProgramUniformMatrix3dv)(program, location, count, transpose, value)
         /// * `value` len: count*9
@@ -26744,7 +29508,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniformMatrix3dv",
-                &self.glProgramUniformMatrix3dv_p,
+                self.lazy(
+                    &self.glProgramUniformMatrix3dv_p,
+                    b"glProgramUniformMatrix3dv\0",
+                ),
                 program,
                 location,
                 count,
@@ -26771,7 +29538,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniformMatrix3dv_is_loaded(&self) -> bool {
-            !self.glProgramUniformMatrix3dv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glProgramUniformMatrix3dv_p,
+                    b"glProgramUniformMatrix3dv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniformMatrix3fv](http://docs.gl/gl4/glProgramUniform)(program, location, count, transpose, value)
         /// * `value` len: count*9
@@ -26798,7 +29571,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniformMatrix3fv",
-                &self.glProgramUniformMatrix3fv_p,
+                self.lazy(
+                    &self.glProgramUniformMatrix3fv_p,
+                    b"glProgramUniformMatrix3fv\0",
+                ),
                 program,
                 location,
                 count,
@@ -26825,7 +29601,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniformMatrix3fv_is_loaded(&self) -> bool {
-            !self.glProgramUniformMatrix3fv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glProgramUniformMatrix3fv_p,
+                    b"glProgramUniformMatrix3fv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniformMatrix3x2dv](http://docs.gl/gl4/glProgramUniformMatrix3x2dv)(program, location, count, transpose, value)
         /// * `value` len: count*6
@@ -26852,7 +29634,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniformMatrix3x2dv",
-                &self.glProgramUniformMatrix3x2dv_p,
+                self.lazy(
+                    &self.glProgramUniformMatrix3x2dv_p,
+                    b"glProgramUniformMatrix3x2dv\0",
+                ),
                 program,
                 location,
                 count,
@@ -26879,7 +29664,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniformMatrix3x2dv_is_loaded(&self) -> bool {
-            !self.glProgramUniformMatrix3x2dv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glProgramUniformMatrix3x2dv_p,
+                    b"glProgramUniformMatrix3x2dv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniformMatrix3x2fv](http://docs.gl/gl4/glProgramUniform)(program, location, count, transpose, value)
         /// * `value` len: count*6
@@ -26906,7 +29697,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniformMatrix3x2fv",
-                &self.glProgramUniformMatrix3x2fv_p,
+                self.lazy(
+                    &self.glProgramUniformMatrix3x2fv_p,
+                    b"glProgramUniformMatrix3x2fv\0",
+                ),
                 program,
                 location,
                 count,
@@ -26933,7 +29727,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniformMatrix3x2fv_is_loaded(&self) -> bool {
-            !self.glProgramUniformMatrix3x2fv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glProgramUniformMatrix3x2fv_p,
+                    b"glProgramUniformMatrix3x2fv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniformMatrix3x4dv](http://docs.gl/gl4/glProgramUniformMatrix3x4dv)(program, location, count, transpose, value)
         /// * `value` len: count*12
@@ -26960,7 +29760,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniformMatrix3x4dv",
-                &self.glProgramUniformMatrix3x4dv_p,
+                self.lazy(
+                    &self.glProgramUniformMatrix3x4dv_p,
+                    b"glProgramUniformMatrix3x4dv\0",
+                ),
                 program,
                 location,
                 count,
@@ -26987,7 +29790,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniformMatrix3x4dv_is_loaded(&self) -> bool {
-            !self.glProgramUniformMatrix3x4dv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glProgramUniformMatrix3x4dv_p,
+                    b"glProgramUniformMatrix3x4dv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniformMatrix3x4fv](http://docs.gl/gl4/glProgramUniform)(program, location, count, transpose, value)
         /// * `value` len: count*12
@@ -27014,7 +29823,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniformMatrix3x4fv",
-                &self.glProgramUniformMatrix3x4fv_p,
+                self.lazy(
+                    &self.glProgramUniformMatrix3x4fv_p,
+                    b"glProgramUniformMatrix3x4fv\0",
+                ),
                 program,
                 location,
                 count,
@@ -27041,7 +29853,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniformMatrix3x4fv_is_loaded(&self) -> bool {
-            !self.glProgramUniformMatrix3x4fv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glProgramUniformMatrix3x4fv_p,
+                    b"glProgramUniformMatrix3x4fv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniformMatrix4dv](http://docs.gl/gl4/glProgramUniformMatrix4dv)(program, location, count, transpose, value)
         /// * `value` len: count*16
@@ -27068,7 +29886,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniformMatrix4dv",
-                &self.glProgramUniformMatrix4dv_p,
+                self.lazy(
+                    &self.glProgramUniformMatrix4dv_p,
+                    b"glProgramUniformMatrix4dv\0",
+                ),
                 program,
                 location,
                 count,
@@ -27095,7 +29916,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniformMatrix4dv_is_loaded(&self) -> bool {
-            !self.glProgramUniformMatrix4dv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glProgramUniformMatrix4dv_p,
+                    b"glProgramUniformMatrix4dv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniformMatrix4fv](http://docs.gl/gl4/glProgramUniform)(program, location, count, transpose, value)
         /// * `value` len: count*16
@@ -27122,7 +29949,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniformMatrix4fv",
-                &self.glProgramUniformMatrix4fv_p,
+                self.lazy(
+                    &self.glProgramUniformMatrix4fv_p,
+                    b"glProgramUniformMatrix4fv\0",
+                ),
                 program,
                 location,
                 count,
@@ -27149,7 +29979,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniformMatrix4fv_is_loaded(&self) -> bool {
-            !self.glProgramUniformMatrix4fv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glProgramUniformMatrix4fv_p,
+                    b"glProgramUniformMatrix4fv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniformMatrix4x2dv](http://docs.gl/gl4/glProgramUniformMatrix4x2dv)(program, location, count, transpose, value)
         /// * `value` len: count*8
@@ -27176,7 +30012,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniformMatrix4x2dv",
-                &self.glProgramUniformMatrix4x2dv_p,
+                self.lazy(
+                    &self.glProgramUniformMatrix4x2dv_p,
+                    b"glProgramUniformMatrix4x2dv\0",
+                ),
                 program,
                 location,
                 count,
@@ -27203,7 +30042,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniformMatrix4x2dv_is_loaded(&self) -> bool {
-            !self.glProgramUniformMatrix4x2dv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glProgramUniformMatrix4x2dv_p,
+                    b"glProgramUniformMatrix4x2dv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniformMatrix4x2fv](http://docs.gl/gl4/glProgramUniform)(program, location, count, transpose, value)
         /// * `value` len: count*8
@@ -27230,7 +30075,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniformMatrix4x2fv",
-                &self.glProgramUniformMatrix4x2fv_p,
+                self.lazy(
+                    &self.glProgramUniformMatrix4x2fv_p,
+                    b"glProgramUniformMatrix4x2fv\0",
+                ),
                 program,
                 location,
                 count,
@@ -27257,7 +30105,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniformMatrix4x2fv_is_loaded(&self) -> bool {
-            !self.glProgramUniformMatrix4x2fv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glProgramUniformMatrix4x2fv_p,
+                    b"glProgramUniformMatrix4x2fv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniformMatrix4x3dv](http://docs.gl/gl4/glProgramUniformMatrix4x3dv)(program, location, count, transpose, value)
         /// * `value` len: count*12
@@ -27284,7 +30138,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniformMatrix4x3dv",
-                &self.glProgramUniformMatrix4x3dv_p,
+                self.lazy(
+                    &self.glProgramUniformMatrix4x3dv_p,
+                    b"glProgramUniformMatrix4x3dv\0",
+                ),
                 program,
                 location,
                 count,
@@ -27311,7 +30168,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniformMatrix4x3dv_is_loaded(&self) -> bool {
-            !self.glProgramUniformMatrix4x3dv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glProgramUniformMatrix4x3dv_p,
+                    b"glProgramUniformMatrix4x3dv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glProgramUniformMatrix4x3fv](http://docs.gl/gl4/glProgramUniform)(program, location, count, transpose, value)
         /// * `value` len: count*12
@@ -27338,7 +30201,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glProgramUniformMatrix4x3fv",
-                &self.glProgramUniformMatrix4x3fv_p,
+                self.lazy(
+                    &self.glProgramUniformMatrix4x3fv_p,
+                    b"glProgramUniformMatrix4x3fv\0",
+                ),
                 program,
                 location,
                 count,
@@ -27365,7 +30231,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProgramUniformMatrix4x3fv_is_loaded(&self) -> bool {
-            !self.glProgramUniformMatrix4x3fv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glProgramUniformMatrix4x3fv_p,
+                    b"glProgramUniformMatrix4x3fv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glProvokingVertex](http://docs.gl/gl4/glProvokingVertex)(mode)
         /// * `mode` group: VertexProvokingMode
@@ -27376,7 +30248,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.ProvokingVertex({:#X});", mode);
             }
-            let out = call_atomic_ptr_1arg("glProvokingVertex", &self.glProvokingVertex_p, mode);
+            let out = call_atomic_ptr_1arg(
+                "glProvokingVertex",
+                self.lazy(&self.glProvokingVertex_p, b"glProvokingVertex\0"),
+                mode,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glProvokingVertex");
@@ -27397,7 +30273,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ProvokingVertex_is_loaded(&self) -> bool {
-            !self.glProvokingVertex_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glProvokingVertex_p, b"glProvokingVertex\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glPushDebugGroup](http://docs.gl/gl4/glPushDebugGroup)(source, id, length, message)
         /// * `source` group: DebugSource
@@ -27423,7 +30302,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glPushDebugGroup",
-                &self.glPushDebugGroup_p,
+                self.lazy(&self.glPushDebugGroup_p, b"glPushDebugGroup\0"),
                 source,
                 id,
                 length,
@@ -27449,7 +30328,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn PushDebugGroup_is_loaded(&self) -> bool {
-            !self.glPushDebugGroup_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glPushDebugGroup_p, b"glPushDebugGroup\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glPushDebugGroupKHR](http://docs.gl/gl4/glPushDebugGroupKHR)(source, id, length, message)
         /// * `source` group: DebugSource
@@ -27475,7 +30357,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glPushDebugGroupKHR",
-                &self.glPushDebugGroupKHR_p,
+                self.lazy(&self.glPushDebugGroupKHR_p, b"glPushDebugGroupKHR\0"),
                 source,
                 id,
                 length,
@@ -27501,7 +30383,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn PushDebugGroupKHR_is_loaded(&self) -> bool {
-            !self.glPushDebugGroupKHR_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glPushDebugGroupKHR_p, b"glPushDebugGroupKHR\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glQueryCounter](http://docs.gl/gl4/glQueryCounter)(id, target)
         /// * `target` group: QueryCounterTarget
@@ -27512,7 +30397,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.QueryCounter({:?}, {:#X});", id, target);
             }
-            let out = call_atomic_ptr_2arg("glQueryCounter", &self.glQueryCounter_p, id, target);
+            let out = call_atomic_ptr_2arg(
+                "glQueryCounter",
+                self.lazy(&self.glQueryCounter_p, b"glQueryCounter\0"),
+                id,
+                target,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glQueryCounter");
@@ -27533,7 +30423,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn QueryCounter_is_loaded(&self) -> bool {
-            !self.glQueryCounter_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glQueryCounter_p, b"glQueryCounter\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glQueryCounterEXT](http://docs.gl/gl4/glQueryCounterEXT)(id, target)
         /// * `target` group: QueryCounterTarget
@@ -27545,8 +30438,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.QueryCounterEXT({:?}, {:#X});", id, target);
             }
-            let out =
-                call_atomic_ptr_2arg("glQueryCounterEXT", &self.glQueryCounterEXT_p, id, target);
+            let out = call_atomic_ptr_2arg(
+                "glQueryCounterEXT",
+                self.lazy(&self.glQueryCounterEXT_p, b"glQueryCounterEXT\0"),
+                id,
+                target,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glQueryCounterEXT");
@@ -27567,7 +30464,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn QueryCounterEXT_is_loaded(&self) -> bool {
-            !self.glQueryCounterEXT_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glQueryCounterEXT_p, b"glQueryCounterEXT\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glReadBuffer](http://docs.gl/gl4/glReadBuffer)(src)
         /// * `src` group: ReadBufferMode
@@ -27578,7 +30478,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.ReadBuffer({:#X});", src);
             }
-            let out = call_atomic_ptr_1arg("glReadBuffer", &self.glReadBuffer_p, src);
+            let out = call_atomic_ptr_1arg(
+                "glReadBuffer",
+                self.lazy(&self.glReadBuffer_p, b"glReadBuffer\0"),
+                src,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glReadBuffer");
@@ -27595,7 +30499,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ReadBuffer_is_loaded(&self) -> bool {
-            !self.glReadBuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glReadBuffer_p, b"glReadBuffer\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glReadPixels](http://docs.gl/gl4/glReadPixels)(x, y, width, height, format, type_, pixels)
         /// * `x` group: WinCoord
@@ -27630,7 +30537,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_7arg(
                 "glReadPixels",
-                &self.glReadPixels_p,
+                self.lazy(&self.glReadPixels_p, b"glReadPixels\0"),
                 x,
                 y,
                 width,
@@ -27655,7 +30562,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ReadPixels_is_loaded(&self) -> bool {
-            !self.glReadPixels_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glReadPixels_p, b"glReadPixels\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glReadnPixels](http://docs.gl/gl4/glReadnPixels)(x, y, width, height, format, type_, bufSize, data)
         /// * `format` group: PixelFormat
@@ -27690,7 +30600,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_8arg(
                 "glReadnPixels",
-                &self.glReadnPixels_p,
+                self.lazy(&self.glReadnPixels_p, b"glReadnPixels\0"),
                 x,
                 y,
                 width,
@@ -27716,7 +30626,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ReadnPixels_is_loaded(&self) -> bool {
-            !self.glReadnPixels_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glReadnPixels_p, b"glReadnPixels\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glReleaseShaderCompiler](http://docs.gl/gl4/glReleaseShaderCompiler)()
         #[cfg_attr(feature = "inline", inline)]
@@ -27726,8 +30639,13 @@ pub mod struct_commands {
             {
                 trace!("calling gl.ReleaseShaderCompiler();",);
             }
-            let out =
-                call_atomic_ptr_0arg("glReleaseShaderCompiler", &self.glReleaseShaderCompiler_p);
+            let out = call_atomic_ptr_0arg(
+                "glReleaseShaderCompiler",
+                self.lazy(
+                    &self.glReleaseShaderCompiler_p,
+                    b"glReleaseShaderCompiler\0",
+                ),
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glReleaseShaderCompiler");
@@ -27748,7 +30666,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ReleaseShaderCompiler_is_loaded(&self) -> bool {
-            !self.glReleaseShaderCompiler_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glReleaseShaderCompiler_p,
+                    b"glReleaseShaderCompiler\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glRenderbufferStorage](http://docs.gl/gl4/glRenderbufferStorage)(target, internalformat, width, height)
         /// * `target` group: RenderbufferTarget
@@ -27774,7 +30698,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glRenderbufferStorage",
-                &self.glRenderbufferStorage_p,
+                self.lazy(&self.glRenderbufferStorage_p, b"glRenderbufferStorage\0"),
                 target,
                 internalformat,
                 width,
@@ -27800,7 +30724,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn RenderbufferStorage_is_loaded(&self) -> bool {
-            !self.glRenderbufferStorage_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glRenderbufferStorage_p, b"glRenderbufferStorage\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glRenderbufferStorageMultisample](http://docs.gl/gl4/glRenderbufferStorageMultisample)(target, samples, internalformat, width, height)
         /// * `target` group: RenderbufferTarget
@@ -27828,7 +30755,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glRenderbufferStorageMultisample",
-                &self.glRenderbufferStorageMultisample_p,
+                self.lazy(
+                    &self.glRenderbufferStorageMultisample_p,
+                    b"glRenderbufferStorageMultisample\0",
+                ),
                 target,
                 samples,
                 internalformat,
@@ -27856,7 +30786,10 @@ pub mod struct_commands {
         #[doc(hidden)]
         pub fn RenderbufferStorageMultisample_is_loaded(&self) -> bool {
             !self
-                .glRenderbufferStorageMultisample_p
+                .lazy(
+                    &self.glRenderbufferStorageMultisample_p,
+                    b"glRenderbufferStorageMultisample\0",
+                )
                 .load(RELAX)
                 .is_null()
         }
@@ -27891,7 +30824,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glRenderbufferStorageMultisampleEXT",
-                &self.glRenderbufferStorageMultisampleEXT_p,
+                self.lazy(
+                    &self.glRenderbufferStorageMultisampleEXT_p,
+                    b"glRenderbufferStorageMultisampleEXT\0",
+                ),
                 target,
                 samples,
                 internalformat,
@@ -27927,7 +30863,10 @@ pub mod struct_commands {
         )]
         pub fn RenderbufferStorageMultisampleEXT_is_loaded(&self) -> bool {
             !self
-                .glRenderbufferStorageMultisampleEXT_p
+                .lazy(
+                    &self.glRenderbufferStorageMultisampleEXT_p,
+                    b"glRenderbufferStorageMultisampleEXT\0",
+                )
                 .load(RELAX)
                 .is_null()
         }
@@ -27941,7 +30880,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_0arg(
                 "glResumeTransformFeedback",
-                &self.glResumeTransformFeedback_p,
+                self.lazy(
+                    &self.glResumeTransformFeedback_p,
+                    b"glResumeTransformFeedback\0",
+                ),
             );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
@@ -27963,7 +30905,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ResumeTransformFeedback_is_loaded(&self) -> bool {
-            !self.glResumeTransformFeedback_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glResumeTransformFeedback_p,
+                    b"glResumeTransformFeedback\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glSampleCoverage](http://docs.gl/gl4/glSampleCoverage)(value, invert)
         #[cfg_attr(feature = "inline", inline)]
@@ -27973,8 +30921,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.SampleCoverage({:?}, {:?});", value, invert);
             }
-            let out =
-                call_atomic_ptr_2arg("glSampleCoverage", &self.glSampleCoverage_p, value, invert);
+            let out = call_atomic_ptr_2arg(
+                "glSampleCoverage",
+                self.lazy(&self.glSampleCoverage_p, b"glSampleCoverage\0"),
+                value,
+                invert,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glSampleCoverage");
@@ -27995,7 +30947,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn SampleCoverage_is_loaded(&self) -> bool {
-            !self.glSampleCoverage_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glSampleCoverage_p, b"glSampleCoverage\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glSampleMaski](http://docs.gl/gl4/glSampleMask)(maskNumber, mask)
         #[cfg_attr(feature = "inline", inline)]
@@ -28005,8 +30960,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.SampleMaski({:?}, {:?});", maskNumber, mask);
             }
-            let out =
-                call_atomic_ptr_2arg("glSampleMaski", &self.glSampleMaski_p, maskNumber, mask);
+            let out = call_atomic_ptr_2arg(
+                "glSampleMaski",
+                self.lazy(&self.glSampleMaski_p, b"glSampleMaski\0"),
+                maskNumber,
+                mask,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glSampleMaski");
@@ -28023,7 +30982,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn SampleMaski_is_loaded(&self) -> bool {
-            !self.glSampleMaski_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glSampleMaski_p, b"glSampleMaski\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glSamplerParameterIiv](http://docs.gl/gl4/glSamplerParameter)(sampler, pname, param)
         /// * `pname` group: SamplerParameterI
@@ -28047,7 +31009,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glSamplerParameterIiv",
-                &self.glSamplerParameterIiv_p,
+                self.lazy(&self.glSamplerParameterIiv_p, b"glSamplerParameterIiv\0"),
                 sampler,
                 pname,
                 param,
@@ -28072,7 +31034,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn SamplerParameterIiv_is_loaded(&self) -> bool {
-            !self.glSamplerParameterIiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glSamplerParameterIiv_p, b"glSamplerParameterIiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glSamplerParameterIuiv](http://docs.gl/gl4/glSamplerParameter)(sampler, pname, param)
         /// * `pname` group: SamplerParameterI
@@ -28096,7 +31061,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glSamplerParameterIuiv",
-                &self.glSamplerParameterIuiv_p,
+                self.lazy(&self.glSamplerParameterIuiv_p, b"glSamplerParameterIuiv\0"),
                 sampler,
                 pname,
                 param,
@@ -28121,7 +31086,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn SamplerParameterIuiv_is_loaded(&self) -> bool {
-            !self.glSamplerParameterIuiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glSamplerParameterIuiv_p, b"glSamplerParameterIuiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glSamplerParameterf](http://docs.gl/gl4/glSamplerParameter)(sampler, pname, param)
         /// * `pname` group: SamplerParameterF
@@ -28139,7 +31107,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glSamplerParameterf",
-                &self.glSamplerParameterf_p,
+                self.lazy(&self.glSamplerParameterf_p, b"glSamplerParameterf\0"),
                 sampler,
                 pname,
                 param,
@@ -28164,7 +31132,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn SamplerParameterf_is_loaded(&self) -> bool {
-            !self.glSamplerParameterf_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glSamplerParameterf_p, b"glSamplerParameterf\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glSamplerParameterfv](http://docs.gl/gl4/glSamplerParameter)(sampler, pname, param)
         /// * `pname` group: SamplerParameterF
@@ -28188,7 +31159,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glSamplerParameterfv",
-                &self.glSamplerParameterfv_p,
+                self.lazy(&self.glSamplerParameterfv_p, b"glSamplerParameterfv\0"),
                 sampler,
                 pname,
                 param,
@@ -28213,7 +31184,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn SamplerParameterfv_is_loaded(&self) -> bool {
-            !self.glSamplerParameterfv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glSamplerParameterfv_p, b"glSamplerParameterfv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glSamplerParameteri](http://docs.gl/gl4/glSamplerParameter)(sampler, pname, param)
         /// * `pname` group: SamplerParameterI
@@ -28231,7 +31205,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glSamplerParameteri",
-                &self.glSamplerParameteri_p,
+                self.lazy(&self.glSamplerParameteri_p, b"glSamplerParameteri\0"),
                 sampler,
                 pname,
                 param,
@@ -28256,7 +31230,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn SamplerParameteri_is_loaded(&self) -> bool {
-            !self.glSamplerParameteri_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glSamplerParameteri_p, b"glSamplerParameteri\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glSamplerParameteriv](http://docs.gl/gl4/glSamplerParameter)(sampler, pname, param)
         /// * `pname` group: SamplerParameterI
@@ -28280,7 +31257,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glSamplerParameteriv",
-                &self.glSamplerParameteriv_p,
+                self.lazy(&self.glSamplerParameteriv_p, b"glSamplerParameteriv\0"),
                 sampler,
                 pname,
                 param,
@@ -28305,7 +31282,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn SamplerParameteriv_is_loaded(&self) -> bool {
-            !self.glSamplerParameteriv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glSamplerParameteriv_p, b"glSamplerParameteriv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glScissor](http://docs.gl/gl4/glScissor)(x, y, width, height)
         /// * `x` group: WinCoord
@@ -28323,7 +31303,14 @@ pub mod struct_commands {
                     height
                 );
             }
-            let out = call_atomic_ptr_4arg("glScissor", &self.glScissor_p, x, y, width, height);
+            let out = call_atomic_ptr_4arg(
+                "glScissor",
+                self.lazy(&self.glScissor_p, b"glScissor\0"),
+                x,
+                y,
+                width,
+                height,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glScissor");
@@ -28340,7 +31327,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Scissor_is_loaded(&self) -> bool {
-            !self.glScissor_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glScissor_p, b"glScissor\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glScissorArrayv](http://docs.gl/gl4/glScissorArrayv)(first, count, v)
         /// * `v` len: COMPSIZE(count)
@@ -28356,8 +31346,13 @@ pub mod struct_commands {
                     v
                 );
             }
-            let out =
-                call_atomic_ptr_3arg("glScissorArrayv", &self.glScissorArrayv_p, first, count, v);
+            let out = call_atomic_ptr_3arg(
+                "glScissorArrayv",
+                self.lazy(&self.glScissorArrayv_p, b"glScissorArrayv\0"),
+                first,
+                count,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glScissorArrayv");
@@ -28378,7 +31373,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ScissorArrayv_is_loaded(&self) -> bool {
-            !self.glScissorArrayv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glScissorArrayv_p, b"glScissorArrayv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glScissorIndexed](http://docs.gl/gl4/glScissorIndexed)(index, left, bottom, width, height)
         #[cfg_attr(feature = "inline", inline)]
@@ -28404,7 +31402,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glScissorIndexed",
-                &self.glScissorIndexed_p,
+                self.lazy(&self.glScissorIndexed_p, b"glScissorIndexed\0"),
                 index,
                 left,
                 bottom,
@@ -28431,7 +31429,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ScissorIndexed_is_loaded(&self) -> bool {
-            !self.glScissorIndexed_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glScissorIndexed_p, b"glScissorIndexed\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glScissorIndexedv](http://docs.gl/gl4/glScissorIndexedv)(index, v)
         /// * `v` len: 4
@@ -28442,8 +31443,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.ScissorIndexedv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glScissorIndexedv", &self.glScissorIndexedv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glScissorIndexedv",
+                self.lazy(&self.glScissorIndexedv_p, b"glScissorIndexedv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glScissorIndexedv");
@@ -28464,7 +31469,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ScissorIndexedv_is_loaded(&self) -> bool {
-            !self.glScissorIndexedv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glScissorIndexedv_p, b"glScissorIndexedv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glShaderBinary](http://docs.gl/gl4/glShaderBinary)(count, shaders, binaryFormat, binary, length)
         /// * `shaders` len: count
@@ -28493,7 +31501,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glShaderBinary",
-                &self.glShaderBinary_p,
+                self.lazy(&self.glShaderBinary_p, b"glShaderBinary\0"),
                 count,
                 shaders,
                 binaryFormat,
@@ -28520,7 +31528,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ShaderBinary_is_loaded(&self) -> bool {
-            !self.glShaderBinary_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glShaderBinary_p, b"glShaderBinary\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glShaderSource](http://docs.gl/gl4/glShaderSource)(shader, count, string, length)
         /// * `string` len: count
@@ -28546,7 +31557,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glShaderSource",
-                &self.glShaderSource_p,
+                self.lazy(&self.glShaderSource_p, b"glShaderSource\0"),
                 shader,
                 count,
                 string,
@@ -28572,7 +31583,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ShaderSource_is_loaded(&self) -> bool {
-            !self.glShaderSource_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glShaderSource_p, b"glShaderSource\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glShaderStorageBlockBinding](http://docs.gl/gl4/glShaderStorageBlockBinding)(program, storageBlockIndex, storageBlockBinding)
         #[cfg_attr(feature = "inline", inline)]
@@ -28594,7 +31608,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glShaderStorageBlockBinding",
-                &self.glShaderStorageBlockBinding_p,
+                self.lazy(
+                    &self.glShaderStorageBlockBinding_p,
+                    b"glShaderStorageBlockBinding\0",
+                ),
                 program,
                 storageBlockIndex,
                 storageBlockBinding,
@@ -28619,7 +31636,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ShaderStorageBlockBinding_is_loaded(&self) -> bool {
-            !self.glShaderStorageBlockBinding_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glShaderStorageBlockBinding_p,
+                    b"glShaderStorageBlockBinding\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glSpecializeShader](http://docs.gl/gl4/glSpecializeShader)(shader, pEntryPoint, numSpecializationConstants, pConstantIndex, pConstantValue)
         #[cfg_attr(feature = "inline", inline)]
@@ -28645,7 +31668,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glSpecializeShader",
-                &self.glSpecializeShader_p,
+                self.lazy(&self.glSpecializeShader_p, b"glSpecializeShader\0"),
                 shader,
                 pEntryPoint,
                 numSpecializationConstants,
@@ -28672,7 +31695,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn SpecializeShader_is_loaded(&self) -> bool {
-            !self.glSpecializeShader_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glSpecializeShader_p, b"glSpecializeShader\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glStencilFunc](http://docs.gl/gl4/glStencilFunc)(func, ref_, mask)
         /// * `func` group: StencilFunction
@@ -28690,8 +31716,13 @@ pub mod struct_commands {
                     mask
                 );
             }
-            let out =
-                call_atomic_ptr_3arg("glStencilFunc", &self.glStencilFunc_p, func, ref_, mask);
+            let out = call_atomic_ptr_3arg(
+                "glStencilFunc",
+                self.lazy(&self.glStencilFunc_p, b"glStencilFunc\0"),
+                func,
+                ref_,
+                mask,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glStencilFunc");
@@ -28708,7 +31739,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn StencilFunc_is_loaded(&self) -> bool {
-            !self.glStencilFunc_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glStencilFunc_p, b"glStencilFunc\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glStencilFuncSeparate](http://docs.gl/gl4/glStencilFuncSeparate)(face, func, ref_, mask)
         /// * `face` group: StencilFaceDirection
@@ -28736,7 +31770,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glStencilFuncSeparate",
-                &self.glStencilFuncSeparate_p,
+                self.lazy(&self.glStencilFuncSeparate_p, b"glStencilFuncSeparate\0"),
                 face,
                 func,
                 ref_,
@@ -28762,7 +31796,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn StencilFuncSeparate_is_loaded(&self) -> bool {
-            !self.glStencilFuncSeparate_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glStencilFuncSeparate_p, b"glStencilFuncSeparate\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glStencilMask](http://docs.gl/gl4/glStencilMask)(mask)
         /// * `mask` group: MaskedStencilValue
@@ -28773,7 +31810,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.StencilMask({:?});", mask);
             }
-            let out = call_atomic_ptr_1arg("glStencilMask", &self.glStencilMask_p, mask);
+            let out = call_atomic_ptr_1arg(
+                "glStencilMask",
+                self.lazy(&self.glStencilMask_p, b"glStencilMask\0"),
+                mask,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glStencilMask");
@@ -28790,7 +31831,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn StencilMask_is_loaded(&self) -> bool {
-            !self.glStencilMask_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glStencilMask_p, b"glStencilMask\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glStencilMaskSeparate](http://docs.gl/gl4/glStencilMaskSeparate)(face, mask)
         /// * `face` group: StencilFaceDirection
@@ -28804,7 +31848,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glStencilMaskSeparate",
-                &self.glStencilMaskSeparate_p,
+                self.lazy(&self.glStencilMaskSeparate_p, b"glStencilMaskSeparate\0"),
                 face,
                 mask,
             );
@@ -28828,7 +31872,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn StencilMaskSeparate_is_loaded(&self) -> bool {
-            !self.glStencilMaskSeparate_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glStencilMaskSeparate_p, b"glStencilMaskSeparate\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glStencilOp](http://docs.gl/gl4/glStencilOp)(fail, zfail, zpass)
         /// * `fail` group: StencilOp
@@ -28846,7 +31893,13 @@ pub mod struct_commands {
                     zpass
                 );
             }
-            let out = call_atomic_ptr_3arg("glStencilOp", &self.glStencilOp_p, fail, zfail, zpass);
+            let out = call_atomic_ptr_3arg(
+                "glStencilOp",
+                self.lazy(&self.glStencilOp_p, b"glStencilOp\0"),
+                fail,
+                zfail,
+                zpass,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glStencilOp");
@@ -28863,7 +31916,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn StencilOp_is_loaded(&self) -> bool {
-            !self.glStencilOp_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glStencilOp_p, b"glStencilOp\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glStencilOpSeparate](http://docs.gl/gl4/glStencilOpSeparate)(face, sfail, dpfail, dppass)
         /// * `face` group: StencilFaceDirection
@@ -28891,7 +31947,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glStencilOpSeparate",
-                &self.glStencilOpSeparate_p,
+                self.lazy(&self.glStencilOpSeparate_p, b"glStencilOpSeparate\0"),
                 face,
                 sfail,
                 dpfail,
@@ -28917,7 +31973,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn StencilOpSeparate_is_loaded(&self) -> bool {
-            !self.glStencilOpSeparate_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glStencilOpSeparate_p, b"glStencilOpSeparate\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTexBuffer](http://docs.gl/gl4/glTexBuffer)(target, internalformat, buffer)
         /// * `target` group: TextureTarget
@@ -28936,7 +31995,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glTexBuffer",
-                &self.glTexBuffer_p,
+                self.lazy(&self.glTexBuffer_p, b"glTexBuffer\0"),
                 target,
                 internalformat,
                 buffer,
@@ -28957,7 +32016,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TexBuffer_is_loaded(&self) -> bool {
-            !self.glTexBuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTexBuffer_p, b"glTexBuffer\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTexBufferRange](http://docs.gl/gl4/glTexBufferRange)(target, internalformat, buffer, offset, size)
         /// * `target` group: TextureTarget
@@ -28987,7 +32049,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glTexBufferRange",
-                &self.glTexBufferRange_p,
+                self.lazy(&self.glTexBufferRange_p, b"glTexBufferRange\0"),
                 target,
                 internalformat,
                 buffer,
@@ -29014,7 +32076,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TexBufferRange_is_loaded(&self) -> bool {
-            !self.glTexBufferRange_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTexBufferRange_p, b"glTexBufferRange\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTexImage1D](http://docs.gl/gl4/glTexImage1D)(target, level, internalformat, width, border, format, type_, pixels)
         /// * `target` group: TextureTarget
@@ -29053,7 +32118,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_8arg(
                 "glTexImage1D",
-                &self.glTexImage1D_p,
+                self.lazy(&self.glTexImage1D_p, b"glTexImage1D\0"),
                 target,
                 level,
                 internalformat,
@@ -29079,7 +32144,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TexImage1D_is_loaded(&self) -> bool {
-            !self.glTexImage1D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTexImage1D_p, b"glTexImage1D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTexImage2D](http://docs.gl/gl4/glTexImage2D)(target, level, internalformat, width, height, border, format, type_, pixels)
         /// * `target` group: TextureTarget
@@ -29109,7 +32177,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_9arg(
                 "glTexImage2D",
-                &self.glTexImage2D_p,
+                self.lazy(&self.glTexImage2D_p, b"glTexImage2D\0"),
                 target,
                 level,
                 internalformat,
@@ -29136,7 +32204,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TexImage2D_is_loaded(&self) -> bool {
-            !self.glTexImage2D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTexImage2D_p, b"glTexImage2D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTexImage2DMultisample](http://docs.gl/gl4/glTexImage2DMultisample)(target, samples, internalformat, width, height, fixedsamplelocations)
         /// * `target` group: TextureTarget
@@ -29166,7 +32237,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glTexImage2DMultisample",
-                &self.glTexImage2DMultisample_p,
+                self.lazy(
+                    &self.glTexImage2DMultisample_p,
+                    b"glTexImage2DMultisample\0",
+                ),
                 target,
                 samples,
                 internalformat,
@@ -29194,7 +32268,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TexImage2DMultisample_is_loaded(&self) -> bool {
-            !self.glTexImage2DMultisample_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glTexImage2DMultisample_p,
+                    b"glTexImage2DMultisample\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glTexImage3D](http://docs.gl/gl4/glTexImage3D)(target, level, internalformat, width, height, depth, border, format, type_, pixels)
         /// * `target` group: TextureTarget
@@ -29225,7 +32305,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_10arg(
                 "glTexImage3D",
-                &self.glTexImage3D_p,
+                self.lazy(&self.glTexImage3D_p, b"glTexImage3D\0"),
                 target,
                 level,
                 internalformat,
@@ -29253,7 +32333,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TexImage3D_is_loaded(&self) -> bool {
-            !self.glTexImage3D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTexImage3D_p, b"glTexImage3D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTexImage3DMultisample](http://docs.gl/gl4/glTexImage3DMultisample)(target, samples, internalformat, width, height, depth, fixedsamplelocations)
         /// * `target` group: TextureTarget
@@ -29285,7 +32368,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_7arg(
                 "glTexImage3DMultisample",
-                &self.glTexImage3DMultisample_p,
+                self.lazy(
+                    &self.glTexImage3DMultisample_p,
+                    b"glTexImage3DMultisample\0",
+                ),
                 target,
                 samples,
                 internalformat,
@@ -29314,7 +32400,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TexImage3DMultisample_is_loaded(&self) -> bool {
-            !self.glTexImage3DMultisample_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glTexImage3DMultisample_p,
+                    b"glTexImage3DMultisample\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glTexParameterIiv](http://docs.gl/gl4/glTexParameter)(target, pname, params)
         /// * `target` group: TextureTarget
@@ -29334,7 +32426,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glTexParameterIiv",
-                &self.glTexParameterIiv_p,
+                self.lazy(&self.glTexParameterIiv_p, b"glTexParameterIiv\0"),
                 target,
                 pname,
                 params,
@@ -29359,7 +32451,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TexParameterIiv_is_loaded(&self) -> bool {
-            !self.glTexParameterIiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTexParameterIiv_p, b"glTexParameterIiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTexParameterIuiv](http://docs.gl/gl4/glTexParameter)(target, pname, params)
         /// * `target` group: TextureTarget
@@ -29384,7 +32479,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glTexParameterIuiv",
-                &self.glTexParameterIuiv_p,
+                self.lazy(&self.glTexParameterIuiv_p, b"glTexParameterIuiv\0"),
                 target,
                 pname,
                 params,
@@ -29409,7 +32504,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TexParameterIuiv_is_loaded(&self) -> bool {
-            !self.glTexParameterIuiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTexParameterIuiv_p, b"glTexParameterIuiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTexParameterf](http://docs.gl/gl4/glTexParameter)(target, pname, param)
         /// * `target` group: TextureTarget
@@ -29429,7 +32527,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glTexParameterf",
-                &self.glTexParameterf_p,
+                self.lazy(&self.glTexParameterf_p, b"glTexParameterf\0"),
                 target,
                 pname,
                 param,
@@ -29454,7 +32552,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TexParameterf_is_loaded(&self) -> bool {
-            !self.glTexParameterf_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTexParameterf_p, b"glTexParameterf\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTexParameterfv](http://docs.gl/gl4/glTexParameter)(target, pname, params)
         /// * `target` group: TextureTarget
@@ -29475,7 +32576,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glTexParameterfv",
-                &self.glTexParameterfv_p,
+                self.lazy(&self.glTexParameterfv_p, b"glTexParameterfv\0"),
                 target,
                 pname,
                 params,
@@ -29500,7 +32601,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TexParameterfv_is_loaded(&self) -> bool {
-            !self.glTexParameterfv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTexParameterfv_p, b"glTexParameterfv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTexParameteri](http://docs.gl/gl4/glTexParameter)(target, pname, param)
         /// * `target` group: TextureTarget
@@ -29520,7 +32624,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glTexParameteri",
-                &self.glTexParameteri_p,
+                self.lazy(&self.glTexParameteri_p, b"glTexParameteri\0"),
                 target,
                 pname,
                 param,
@@ -29545,7 +32649,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TexParameteri_is_loaded(&self) -> bool {
-            !self.glTexParameteri_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTexParameteri_p, b"glTexParameteri\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTexParameteriv](http://docs.gl/gl4/glTexParameter)(target, pname, params)
         /// * `target` group: TextureTarget
@@ -29566,7 +32673,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glTexParameteriv",
-                &self.glTexParameteriv_p,
+                self.lazy(&self.glTexParameteriv_p, b"glTexParameteriv\0"),
                 target,
                 pname,
                 params,
@@ -29591,7 +32698,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TexParameteriv_is_loaded(&self) -> bool {
-            !self.glTexParameteriv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTexParameteriv_p, b"glTexParameteriv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTexStorage1D](http://docs.gl/gl4/glTexStorage1D)(target, levels, internalformat, width)
         /// * `target` group: TextureTarget
@@ -29617,7 +32727,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glTexStorage1D",
-                &self.glTexStorage1D_p,
+                self.lazy(&self.glTexStorage1D_p, b"glTexStorage1D\0"),
                 target,
                 levels,
                 internalformat,
@@ -29643,7 +32753,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TexStorage1D_is_loaded(&self) -> bool {
-            !self.glTexStorage1D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTexStorage1D_p, b"glTexStorage1D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTexStorage2D](http://docs.gl/gl4/glTexStorage2D)(target, levels, internalformat, width, height)
         /// * `target` group: TextureTarget
@@ -29671,7 +32784,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glTexStorage2D",
-                &self.glTexStorage2D_p,
+                self.lazy(&self.glTexStorage2D_p, b"glTexStorage2D\0"),
                 target,
                 levels,
                 internalformat,
@@ -29698,7 +32811,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TexStorage2D_is_loaded(&self) -> bool {
-            !self.glTexStorage2D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTexStorage2D_p, b"glTexStorage2D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTexStorage2DMultisample](http://docs.gl/gl4/glTexStorage2DMultisample)(target, samples, internalformat, width, height, fixedsamplelocations)
         /// * `target` group: TextureTarget
@@ -29728,7 +32844,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glTexStorage2DMultisample",
-                &self.glTexStorage2DMultisample_p,
+                self.lazy(
+                    &self.glTexStorage2DMultisample_p,
+                    b"glTexStorage2DMultisample\0",
+                ),
                 target,
                 samples,
                 internalformat,
@@ -29756,7 +32875,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TexStorage2DMultisample_is_loaded(&self) -> bool {
-            !self.glTexStorage2DMultisample_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glTexStorage2DMultisample_p,
+                    b"glTexStorage2DMultisample\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glTexStorage3D](http://docs.gl/gl4/glTexStorage3D)(target, levels, internalformat, width, height, depth)
         /// * `target` group: TextureTarget
@@ -29786,7 +32911,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glTexStorage3D",
-                &self.glTexStorage3D_p,
+                self.lazy(&self.glTexStorage3D_p, b"glTexStorage3D\0"),
                 target,
                 levels,
                 internalformat,
@@ -29814,7 +32939,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TexStorage3D_is_loaded(&self) -> bool {
-            !self.glTexStorage3D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTexStorage3D_p, b"glTexStorage3D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTexStorage3DMultisample](http://docs.gl/gl4/glTexStorage3DMultisample)(target, samples, internalformat, width, height, depth, fixedsamplelocations)
         /// * `target` group: TextureTarget
@@ -29837,7 +32965,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_7arg(
                 "glTexStorage3DMultisample",
-                &self.glTexStorage3DMultisample_p,
+                self.lazy(
+                    &self.glTexStorage3DMultisample_p,
+                    b"glTexStorage3DMultisample\0",
+                ),
                 target,
                 samples,
                 internalformat,
@@ -29866,7 +32997,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TexStorage3DMultisample_is_loaded(&self) -> bool {
-            !self.glTexStorage3DMultisample_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glTexStorage3DMultisample_p,
+                    b"glTexStorage3DMultisample\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glTexSubImage1D](http://docs.gl/gl4/glTexSubImage1D)(target, level, xoffset, width, format, type_, pixels)
         /// * `target` group: TextureTarget
@@ -29902,7 +33039,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_7arg(
                 "glTexSubImage1D",
-                &self.glTexSubImage1D_p,
+                self.lazy(&self.glTexSubImage1D_p, b"glTexSubImage1D\0"),
                 target,
                 level,
                 xoffset,
@@ -29931,7 +33068,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TexSubImage1D_is_loaded(&self) -> bool {
-            !self.glTexSubImage1D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTexSubImage1D_p, b"glTexSubImage1D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTexSubImage2D](http://docs.gl/gl4/glTexSubImage2D)(target, level, xoffset, yoffset, width, height, format, type_, pixels)
         /// * `target` group: TextureTarget
@@ -29961,7 +33101,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_9arg(
                 "glTexSubImage2D",
-                &self.glTexSubImage2D_p,
+                self.lazy(&self.glTexSubImage2D_p, b"glTexSubImage2D\0"),
                 target,
                 level,
                 xoffset,
@@ -29992,7 +33132,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TexSubImage2D_is_loaded(&self) -> bool {
-            !self.glTexSubImage2D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTexSubImage2D_p, b"glTexSubImage2D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTexSubImage3D](http://docs.gl/gl4/glTexSubImage3D)(target, level, xoffset, yoffset, zoffset, width, height, depth, format, type_, pixels)
         /// * `target` group: TextureTarget
@@ -30025,7 +33168,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_11arg(
                 "glTexSubImage3D",
-                &self.glTexSubImage3D_p,
+                self.lazy(&self.glTexSubImage3D_p, b"glTexSubImage3D\0"),
                 target,
                 level,
                 xoffset,
@@ -30058,7 +33201,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TexSubImage3D_is_loaded(&self) -> bool {
-            !self.glTexSubImage3D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTexSubImage3D_p, b"glTexSubImage3D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTextureBarrier](http://docs.gl/gl4/glTextureBarrier)()
         #[cfg_attr(feature = "inline", inline)]
@@ -30068,7 +33214,10 @@ pub mod struct_commands {
             {
                 trace!("calling gl.TextureBarrier();",);
             }
-            let out = call_atomic_ptr_0arg("glTextureBarrier", &self.glTextureBarrier_p);
+            let out = call_atomic_ptr_0arg(
+                "glTextureBarrier",
+                self.lazy(&self.glTextureBarrier_p, b"glTextureBarrier\0"),
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glTextureBarrier");
@@ -30089,7 +33238,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TextureBarrier_is_loaded(&self) -> bool {
-            !self.glTextureBarrier_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTextureBarrier_p, b"glTextureBarrier\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTextureBuffer](http://docs.gl/gl4/glTextureBuffer)(texture, internalformat, buffer)
         /// * `internalformat` group: InternalFormat
@@ -30112,7 +33264,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glTextureBuffer",
-                &self.glTextureBuffer_p,
+                self.lazy(&self.glTextureBuffer_p, b"glTextureBuffer\0"),
                 texture,
                 internalformat,
                 buffer,
@@ -30137,7 +33289,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TextureBuffer_is_loaded(&self) -> bool {
-            !self.glTextureBuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTextureBuffer_p, b"glTextureBuffer\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTextureBufferRange](http://docs.gl/gl4/glTextureBufferRange)(texture, internalformat, buffer, offset, size)
         /// * `internalformat` group: InternalFormat
@@ -30165,7 +33320,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glTextureBufferRange",
-                &self.glTextureBufferRange_p,
+                self.lazy(&self.glTextureBufferRange_p, b"glTextureBufferRange\0"),
                 texture,
                 internalformat,
                 buffer,
@@ -30192,7 +33347,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TextureBufferRange_is_loaded(&self) -> bool {
-            !self.glTextureBufferRange_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTextureBufferRange_p, b"glTextureBufferRange\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTextureParameterIiv](http://docs.gl/gl4/glTextureParameter)(texture, pname, params)
         /// * `pname` group: TextureParameterName
@@ -30215,7 +33373,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glTextureParameterIiv",
-                &self.glTextureParameterIiv_p,
+                self.lazy(&self.glTextureParameterIiv_p, b"glTextureParameterIiv\0"),
                 texture,
                 pname,
                 params,
@@ -30240,7 +33398,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TextureParameterIiv_is_loaded(&self) -> bool {
-            !self.glTextureParameterIiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTextureParameterIiv_p, b"glTextureParameterIiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTextureParameterIuiv](http://docs.gl/gl4/glTextureParameter)(texture, pname, params)
         /// * `pname` group: TextureParameterName
@@ -30263,7 +33424,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glTextureParameterIuiv",
-                &self.glTextureParameterIuiv_p,
+                self.lazy(&self.glTextureParameterIuiv_p, b"glTextureParameterIuiv\0"),
                 texture,
                 pname,
                 params,
@@ -30288,7 +33449,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TextureParameterIuiv_is_loaded(&self) -> bool {
-            !self.glTextureParameterIuiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTextureParameterIuiv_p, b"glTextureParameterIuiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTextureParameterf](http://docs.gl/gl4/glTextureParameter)(texture, pname, param)
         /// * `pname` group: TextureParameterName
@@ -30306,7 +33470,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glTextureParameterf",
-                &self.glTextureParameterf_p,
+                self.lazy(&self.glTextureParameterf_p, b"glTextureParameterf\0"),
                 texture,
                 pname,
                 param,
@@ -30331,7 +33495,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TextureParameterf_is_loaded(&self) -> bool {
-            !self.glTextureParameterf_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTextureParameterf_p, b"glTextureParameterf\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTextureParameterfv](http://docs.gl/gl4/glTextureParameter)(texture, pname, param)
         /// * `pname` group: TextureParameterName
@@ -30354,7 +33521,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glTextureParameterfv",
-                &self.glTextureParameterfv_p,
+                self.lazy(&self.glTextureParameterfv_p, b"glTextureParameterfv\0"),
                 texture,
                 pname,
                 param,
@@ -30379,7 +33546,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TextureParameterfv_is_loaded(&self) -> bool {
-            !self.glTextureParameterfv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTextureParameterfv_p, b"glTextureParameterfv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTextureParameteri](http://docs.gl/gl4/glTextureParameter)(texture, pname, param)
         /// * `pname` group: TextureParameterName
@@ -30397,7 +33567,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glTextureParameteri",
-                &self.glTextureParameteri_p,
+                self.lazy(&self.glTextureParameteri_p, b"glTextureParameteri\0"),
                 texture,
                 pname,
                 param,
@@ -30422,7 +33592,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TextureParameteri_is_loaded(&self) -> bool {
-            !self.glTextureParameteri_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTextureParameteri_p, b"glTextureParameteri\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTextureParameteriv](http://docs.gl/gl4/glTextureParameter)(texture, pname, param)
         /// * `pname` group: TextureParameterName
@@ -30445,7 +33618,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glTextureParameteriv",
-                &self.glTextureParameteriv_p,
+                self.lazy(&self.glTextureParameteriv_p, b"glTextureParameteriv\0"),
                 texture,
                 pname,
                 param,
@@ -30470,7 +33643,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TextureParameteriv_is_loaded(&self) -> bool {
-            !self.glTextureParameteriv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTextureParameteriv_p, b"glTextureParameteriv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTextureStorage1D](http://docs.gl/gl4/glTextureStorage1D)(texture, levels, internalformat, width)
         /// * `internalformat` group: InternalFormat
@@ -30495,7 +33671,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glTextureStorage1D",
-                &self.glTextureStorage1D_p,
+                self.lazy(&self.glTextureStorage1D_p, b"glTextureStorage1D\0"),
                 texture,
                 levels,
                 internalformat,
@@ -30521,7 +33697,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TextureStorage1D_is_loaded(&self) -> bool {
-            !self.glTextureStorage1D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTextureStorage1D_p, b"glTextureStorage1D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTextureStorage2D](http://docs.gl/gl4/glTextureStorage2D)(texture, levels, internalformat, width, height)
         /// * `internalformat` group: InternalFormat
@@ -30548,7 +33727,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glTextureStorage2D",
-                &self.glTextureStorage2D_p,
+                self.lazy(&self.glTextureStorage2D_p, b"glTextureStorage2D\0"),
                 texture,
                 levels,
                 internalformat,
@@ -30575,7 +33754,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TextureStorage2D_is_loaded(&self) -> bool {
-            !self.glTextureStorage2D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTextureStorage2D_p, b"glTextureStorage2D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTextureStorage2DMultisample](http://docs.gl/gl4/glTextureStorage2DMultisample)(texture, samples, internalformat, width, height, fixedsamplelocations)
         /// * `internalformat` group: InternalFormat
@@ -30604,7 +33786,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glTextureStorage2DMultisample",
-                &self.glTextureStorage2DMultisample_p,
+                self.lazy(
+                    &self.glTextureStorage2DMultisample_p,
+                    b"glTextureStorage2DMultisample\0",
+                ),
                 texture,
                 samples,
                 internalformat,
@@ -30632,7 +33817,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TextureStorage2DMultisample_is_loaded(&self) -> bool {
-            !self.glTextureStorage2DMultisample_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glTextureStorage2DMultisample_p,
+                    b"glTextureStorage2DMultisample\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glTextureStorage3D](http://docs.gl/gl4/glTextureStorage3D)(texture, levels, internalformat, width, height, depth)
         /// * `internalformat` group: InternalFormat
@@ -30661,7 +33852,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glTextureStorage3D",
-                &self.glTextureStorage3D_p,
+                self.lazy(&self.glTextureStorage3D_p, b"glTextureStorage3D\0"),
                 texture,
                 levels,
                 internalformat,
@@ -30689,7 +33880,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TextureStorage3D_is_loaded(&self) -> bool {
-            !self.glTextureStorage3D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTextureStorage3D_p, b"glTextureStorage3D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTextureStorage3DMultisample](http://docs.gl/gl4/glTextureStorage3DMultisample)(texture, samples, internalformat, width, height, depth, fixedsamplelocations)
         /// * `internalformat` group: InternalFormat
@@ -30711,7 +33905,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_7arg(
                 "glTextureStorage3DMultisample",
-                &self.glTextureStorage3DMultisample_p,
+                self.lazy(
+                    &self.glTextureStorage3DMultisample_p,
+                    b"glTextureStorage3DMultisample\0",
+                ),
                 texture,
                 samples,
                 internalformat,
@@ -30740,7 +33937,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TextureStorage3DMultisample_is_loaded(&self) -> bool {
-            !self.glTextureStorage3DMultisample_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glTextureStorage3DMultisample_p,
+                    b"glTextureStorage3DMultisample\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glTextureSubImage1D](http://docs.gl/gl4/glTextureSubImage1D)(texture, level, xoffset, width, format, type_, pixels)
         /// * `format` group: PixelFormat
@@ -30772,7 +33975,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_7arg(
                 "glTextureSubImage1D",
-                &self.glTextureSubImage1D_p,
+                self.lazy(&self.glTextureSubImage1D_p, b"glTextureSubImage1D\0"),
                 texture,
                 level,
                 xoffset,
@@ -30801,7 +34004,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TextureSubImage1D_is_loaded(&self) -> bool {
-            !self.glTextureSubImage1D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTextureSubImage1D_p, b"glTextureSubImage1D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTextureSubImage2D](http://docs.gl/gl4/glTextureSubImage2D)(texture, level, xoffset, yoffset, width, height, format, type_, pixels)
         /// * `format` group: PixelFormat
@@ -30826,7 +34032,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_9arg(
                 "glTextureSubImage2D",
-                &self.glTextureSubImage2D_p,
+                self.lazy(&self.glTextureSubImage2D_p, b"glTextureSubImage2D\0"),
                 texture,
                 level,
                 xoffset,
@@ -30857,7 +34063,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TextureSubImage2D_is_loaded(&self) -> bool {
-            !self.glTextureSubImage2D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTextureSubImage2D_p, b"glTextureSubImage2D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTextureSubImage3D](http://docs.gl/gl4/glTextureSubImage3D)(texture, level, xoffset, yoffset, zoffset, width, height, depth, format, type_, pixels)
         /// * `format` group: PixelFormat
@@ -30884,7 +34093,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_11arg(
                 "glTextureSubImage3D",
-                &self.glTextureSubImage3D_p,
+                self.lazy(&self.glTextureSubImage3D_p, b"glTextureSubImage3D\0"),
                 texture,
                 level,
                 xoffset,
@@ -30917,7 +34126,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TextureSubImage3D_is_loaded(&self) -> bool {
-            !self.glTextureSubImage3D_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTextureSubImage3D_p, b"glTextureSubImage3D\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTextureView](http://docs.gl/gl4/glTextureView)(texture, target, origtexture, internalformat, minlevel, numlevels, minlayer, numlayers)
         /// * `target` group: TextureTarget
@@ -30951,7 +34163,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_8arg(
                 "glTextureView",
-                &self.glTextureView_p,
+                self.lazy(&self.glTextureView_p, b"glTextureView\0"),
                 texture,
                 target,
                 origtexture,
@@ -30977,7 +34189,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TextureView_is_loaded(&self) -> bool {
-            !self.glTextureView_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glTextureView_p, b"glTextureView\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glTransformFeedbackBufferBase](http://docs.gl/gl4/glTransformFeedbackBufferBase)(xfb, index, buffer)
         #[cfg_attr(feature = "inline", inline)]
@@ -30999,7 +34214,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glTransformFeedbackBufferBase",
-                &self.glTransformFeedbackBufferBase_p,
+                self.lazy(
+                    &self.glTransformFeedbackBufferBase_p,
+                    b"glTransformFeedbackBufferBase\0",
+                ),
                 xfb,
                 index,
                 buffer,
@@ -31024,7 +34242,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TransformFeedbackBufferBase_is_loaded(&self) -> bool {
-            !self.glTransformFeedbackBufferBase_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glTransformFeedbackBufferBase_p,
+                    b"glTransformFeedbackBufferBase\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glTransformFeedbackBufferRange](http://docs.gl/gl4/glTransformFeedbackBufferRange)(xfb, index, buffer, offset, size)
         /// * `size` group: BufferSize
@@ -31051,7 +34275,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glTransformFeedbackBufferRange",
-                &self.glTransformFeedbackBufferRange_p,
+                self.lazy(
+                    &self.glTransformFeedbackBufferRange_p,
+                    b"glTransformFeedbackBufferRange\0",
+                ),
                 xfb,
                 index,
                 buffer,
@@ -31078,7 +34305,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TransformFeedbackBufferRange_is_loaded(&self) -> bool {
-            !self.glTransformFeedbackBufferRange_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glTransformFeedbackBufferRange_p,
+                    b"glTransformFeedbackBufferRange\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glTransformFeedbackVaryings](http://docs.gl/gl4/glTransformFeedbackVaryings)(program, count, varyings, bufferMode)
         /// * `varyings` len: count
@@ -31104,7 +34337,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glTransformFeedbackVaryings",
-                &self.glTransformFeedbackVaryings_p,
+                self.lazy(
+                    &self.glTransformFeedbackVaryings_p,
+                    b"glTransformFeedbackVaryings\0",
+                ),
                 program,
                 count,
                 varyings,
@@ -31130,7 +34366,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn TransformFeedbackVaryings_is_loaded(&self) -> bool {
-            !self.glTransformFeedbackVaryings_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glTransformFeedbackVaryings_p,
+                    b"glTransformFeedbackVaryings\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform1d](http://docs.gl/gl4/glUniform1d)(location, x)
         #[cfg_attr(feature = "inline", inline)]
@@ -31140,7 +34382,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.Uniform1d({:?}, {:?});", location, x);
             }
-            let out = call_atomic_ptr_2arg("glUniform1d", &self.glUniform1d_p, location, x);
+            let out = call_atomic_ptr_2arg(
+                "glUniform1d",
+                self.lazy(&self.glUniform1d_p, b"glUniform1d\0"),
+                location,
+                x,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform1d");
@@ -31157,7 +34404,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform1d_is_loaded(&self) -> bool {
-            !self.glUniform1d_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform1d_p, b"glUniform1d\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform1dv](http://docs.gl/gl4/glUniform1dv)(location, count, value)
         /// * `value` len: count*1
@@ -31173,8 +34423,13 @@ pub mod struct_commands {
                     value
                 );
             }
-            let out =
-                call_atomic_ptr_3arg("glUniform1dv", &self.glUniform1dv_p, location, count, value);
+            let out = call_atomic_ptr_3arg(
+                "glUniform1dv",
+                self.lazy(&self.glUniform1dv_p, b"glUniform1dv\0"),
+                location,
+                count,
+                value,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform1dv");
@@ -31191,7 +34446,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform1dv_is_loaded(&self) -> bool {
-            !self.glUniform1dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform1dv_p, b"glUniform1dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform1f](http://docs.gl/gl4/glUniform)(location, v0)
         #[cfg_attr(feature = "inline", inline)]
@@ -31201,7 +34459,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.Uniform1f({:?}, {:?});", location, v0);
             }
-            let out = call_atomic_ptr_2arg("glUniform1f", &self.glUniform1f_p, location, v0);
+            let out = call_atomic_ptr_2arg(
+                "glUniform1f",
+                self.lazy(&self.glUniform1f_p, b"glUniform1f\0"),
+                location,
+                v0,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform1f");
@@ -31218,7 +34481,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform1f_is_loaded(&self) -> bool {
-            !self.glUniform1f_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform1f_p, b"glUniform1f\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform1fv](http://docs.gl/gl4/glUniform)(location, count, value)
         /// * `value` len: count*1
@@ -31234,8 +34500,13 @@ pub mod struct_commands {
                     value
                 );
             }
-            let out =
-                call_atomic_ptr_3arg("glUniform1fv", &self.glUniform1fv_p, location, count, value);
+            let out = call_atomic_ptr_3arg(
+                "glUniform1fv",
+                self.lazy(&self.glUniform1fv_p, b"glUniform1fv\0"),
+                location,
+                count,
+                value,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform1fv");
@@ -31252,7 +34523,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform1fv_is_loaded(&self) -> bool {
-            !self.glUniform1fv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform1fv_p, b"glUniform1fv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform1i](http://docs.gl/gl4/glUniform)(location, v0)
         #[cfg_attr(feature = "inline", inline)]
@@ -31262,7 +34536,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.Uniform1i({:?}, {:?});", location, v0);
             }
-            let out = call_atomic_ptr_2arg("glUniform1i", &self.glUniform1i_p, location, v0);
+            let out = call_atomic_ptr_2arg(
+                "glUniform1i",
+                self.lazy(&self.glUniform1i_p, b"glUniform1i\0"),
+                location,
+                v0,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform1i");
@@ -31279,7 +34558,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform1i_is_loaded(&self) -> bool {
-            !self.glUniform1i_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform1i_p, b"glUniform1i\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform1iv](http://docs.gl/gl4/glUniform)(location, count, value)
         /// * `value` len: count*1
@@ -31295,8 +34577,13 @@ pub mod struct_commands {
                     value
                 );
             }
-            let out =
-                call_atomic_ptr_3arg("glUniform1iv", &self.glUniform1iv_p, location, count, value);
+            let out = call_atomic_ptr_3arg(
+                "glUniform1iv",
+                self.lazy(&self.glUniform1iv_p, b"glUniform1iv\0"),
+                location,
+                count,
+                value,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform1iv");
@@ -31313,7 +34600,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform1iv_is_loaded(&self) -> bool {
-            !self.glUniform1iv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform1iv_p, b"glUniform1iv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform1ui](http://docs.gl/gl4/glUniform)(location, v0)
         #[cfg_attr(feature = "inline", inline)]
@@ -31323,7 +34613,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.Uniform1ui({:?}, {:?});", location, v0);
             }
-            let out = call_atomic_ptr_2arg("glUniform1ui", &self.glUniform1ui_p, location, v0);
+            let out = call_atomic_ptr_2arg(
+                "glUniform1ui",
+                self.lazy(&self.glUniform1ui_p, b"glUniform1ui\0"),
+                location,
+                v0,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform1ui");
@@ -31340,7 +34635,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform1ui_is_loaded(&self) -> bool {
-            !self.glUniform1ui_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform1ui_p, b"glUniform1ui\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform1uiv](http://docs.gl/gl4/glUniform)(location, count, value)
         /// * `value` len: count*1
@@ -31358,7 +34656,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glUniform1uiv",
-                &self.glUniform1uiv_p,
+                self.lazy(&self.glUniform1uiv_p, b"glUniform1uiv\0"),
                 location,
                 count,
                 value,
@@ -31379,7 +34677,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform1uiv_is_loaded(&self) -> bool {
-            !self.glUniform1uiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform1uiv_p, b"glUniform1uiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform2d](http://docs.gl/gl4/glUniform2d)(location, x, y)
         #[cfg_attr(feature = "inline", inline)]
@@ -31389,7 +34690,13 @@ pub mod struct_commands {
             {
                 trace!("calling gl.Uniform2d({:?}, {:?}, {:?});", location, x, y);
             }
-            let out = call_atomic_ptr_3arg("glUniform2d", &self.glUniform2d_p, location, x, y);
+            let out = call_atomic_ptr_3arg(
+                "glUniform2d",
+                self.lazy(&self.glUniform2d_p, b"glUniform2d\0"),
+                location,
+                x,
+                y,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform2d");
@@ -31406,7 +34713,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform2d_is_loaded(&self) -> bool {
-            !self.glUniform2d_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform2d_p, b"glUniform2d\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform2dv](http://docs.gl/gl4/glUniform2dv)(location, count, value)
         /// * `value` len: count*2
@@ -31422,8 +34732,13 @@ pub mod struct_commands {
                     value
                 );
             }
-            let out =
-                call_atomic_ptr_3arg("glUniform2dv", &self.glUniform2dv_p, location, count, value);
+            let out = call_atomic_ptr_3arg(
+                "glUniform2dv",
+                self.lazy(&self.glUniform2dv_p, b"glUniform2dv\0"),
+                location,
+                count,
+                value,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform2dv");
@@ -31440,7 +34755,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform2dv_is_loaded(&self) -> bool {
-            !self.glUniform2dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform2dv_p, b"glUniform2dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform2f](http://docs.gl/gl4/glUniform)(location, v0, v1)
         #[cfg_attr(feature = "inline", inline)]
@@ -31450,7 +34768,13 @@ pub mod struct_commands {
             {
                 trace!("calling gl.Uniform2f({:?}, {:?}, {:?});", location, v0, v1);
             }
-            let out = call_atomic_ptr_3arg("glUniform2f", &self.glUniform2f_p, location, v0, v1);
+            let out = call_atomic_ptr_3arg(
+                "glUniform2f",
+                self.lazy(&self.glUniform2f_p, b"glUniform2f\0"),
+                location,
+                v0,
+                v1,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform2f");
@@ -31467,7 +34791,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform2f_is_loaded(&self) -> bool {
-            !self.glUniform2f_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform2f_p, b"glUniform2f\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform2fv](http://docs.gl/gl4/glUniform)(location, count, value)
         /// * `value` len: count*2
@@ -31483,8 +34810,13 @@ pub mod struct_commands {
                     value
                 );
             }
-            let out =
-                call_atomic_ptr_3arg("glUniform2fv", &self.glUniform2fv_p, location, count, value);
+            let out = call_atomic_ptr_3arg(
+                "glUniform2fv",
+                self.lazy(&self.glUniform2fv_p, b"glUniform2fv\0"),
+                location,
+                count,
+                value,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform2fv");
@@ -31501,7 +34833,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform2fv_is_loaded(&self) -> bool {
-            !self.glUniform2fv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform2fv_p, b"glUniform2fv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform2i](http://docs.gl/gl4/glUniform)(location, v0, v1)
         #[cfg_attr(feature = "inline", inline)]
@@ -31511,7 +34846,13 @@ pub mod struct_commands {
             {
                 trace!("calling gl.Uniform2i({:?}, {:?}, {:?});", location, v0, v1);
             }
-            let out = call_atomic_ptr_3arg("glUniform2i", &self.glUniform2i_p, location, v0, v1);
+            let out = call_atomic_ptr_3arg(
+                "glUniform2i",
+                self.lazy(&self.glUniform2i_p, b"glUniform2i\0"),
+                location,
+                v0,
+                v1,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform2i");
@@ -31528,7 +34869,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform2i_is_loaded(&self) -> bool {
-            !self.glUniform2i_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform2i_p, b"glUniform2i\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform2iv](http://docs.gl/gl4/glUniform)(location, count, value)
         /// * `value` len: count*2
@@ -31544,8 +34888,13 @@ pub mod struct_commands {
                     value
                 );
             }
-            let out =
-                call_atomic_ptr_3arg("glUniform2iv", &self.glUniform2iv_p, location, count, value);
+            let out = call_atomic_ptr_3arg(
+                "glUniform2iv",
+                self.lazy(&self.glUniform2iv_p, b"glUniform2iv\0"),
+                location,
+                count,
+                value,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform2iv");
@@ -31562,7 +34911,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform2iv_is_loaded(&self) -> bool {
-            !self.glUniform2iv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform2iv_p, b"glUniform2iv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform2ui](http://docs.gl/gl4/glUniform)(location, v0, v1)
         #[cfg_attr(feature = "inline", inline)]
@@ -31572,7 +34924,13 @@ pub mod struct_commands {
             {
                 trace!("calling gl.Uniform2ui({:?}, {:?}, {:?});", location, v0, v1);
             }
-            let out = call_atomic_ptr_3arg("glUniform2ui", &self.glUniform2ui_p, location, v0, v1);
+            let out = call_atomic_ptr_3arg(
+                "glUniform2ui",
+                self.lazy(&self.glUniform2ui_p, b"glUniform2ui\0"),
+                location,
+                v0,
+                v1,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform2ui");
@@ -31589,7 +34947,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform2ui_is_loaded(&self) -> bool {
-            !self.glUniform2ui_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform2ui_p, b"glUniform2ui\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform2uiv](http://docs.gl/gl4/glUniform)(location, count, value)
         /// * `value` len: count*2
@@ -31607,7 +34968,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glUniform2uiv",
-                &self.glUniform2uiv_p,
+                self.lazy(&self.glUniform2uiv_p, b"glUniform2uiv\0"),
                 location,
                 count,
                 value,
@@ -31628,7 +34989,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform2uiv_is_loaded(&self) -> bool {
-            !self.glUniform2uiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform2uiv_p, b"glUniform2uiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform3d](http://docs.gl/gl4/glUniform3d)(location, x, y, z)
         #[cfg_attr(feature = "inline", inline)]
@@ -31644,7 +35008,14 @@ pub mod struct_commands {
                     z
                 );
             }
-            let out = call_atomic_ptr_4arg("glUniform3d", &self.glUniform3d_p, location, x, y, z);
+            let out = call_atomic_ptr_4arg(
+                "glUniform3d",
+                self.lazy(&self.glUniform3d_p, b"glUniform3d\0"),
+                location,
+                x,
+                y,
+                z,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform3d");
@@ -31661,7 +35032,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform3d_is_loaded(&self) -> bool {
-            !self.glUniform3d_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform3d_p, b"glUniform3d\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform3dv](http://docs.gl/gl4/glUniform3dv)(location, count, value)
         /// * `value` len: count*3
@@ -31677,8 +35051,13 @@ pub mod struct_commands {
                     value
                 );
             }
-            let out =
-                call_atomic_ptr_3arg("glUniform3dv", &self.glUniform3dv_p, location, count, value);
+            let out = call_atomic_ptr_3arg(
+                "glUniform3dv",
+                self.lazy(&self.glUniform3dv_p, b"glUniform3dv\0"),
+                location,
+                count,
+                value,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform3dv");
@@ -31695,7 +35074,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform3dv_is_loaded(&self) -> bool {
-            !self.glUniform3dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform3dv_p, b"glUniform3dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform3f](http://docs.gl/gl4/glUniform)(location, v0, v1, v2)
         #[cfg_attr(feature = "inline", inline)]
@@ -31711,8 +35093,14 @@ pub mod struct_commands {
                     v2
                 );
             }
-            let out =
-                call_atomic_ptr_4arg("glUniform3f", &self.glUniform3f_p, location, v0, v1, v2);
+            let out = call_atomic_ptr_4arg(
+                "glUniform3f",
+                self.lazy(&self.glUniform3f_p, b"glUniform3f\0"),
+                location,
+                v0,
+                v1,
+                v2,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform3f");
@@ -31729,7 +35117,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform3f_is_loaded(&self) -> bool {
-            !self.glUniform3f_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform3f_p, b"glUniform3f\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform3fv](http://docs.gl/gl4/glUniform)(location, count, value)
         /// * `value` len: count*3
@@ -31745,8 +35136,13 @@ pub mod struct_commands {
                     value
                 );
             }
-            let out =
-                call_atomic_ptr_3arg("glUniform3fv", &self.glUniform3fv_p, location, count, value);
+            let out = call_atomic_ptr_3arg(
+                "glUniform3fv",
+                self.lazy(&self.glUniform3fv_p, b"glUniform3fv\0"),
+                location,
+                count,
+                value,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform3fv");
@@ -31763,7 +35159,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform3fv_is_loaded(&self) -> bool {
-            !self.glUniform3fv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform3fv_p, b"glUniform3fv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform3i](http://docs.gl/gl4/glUniform)(location, v0, v1, v2)
         #[cfg_attr(feature = "inline", inline)]
@@ -31779,8 +35178,14 @@ pub mod struct_commands {
                     v2
                 );
             }
-            let out =
-                call_atomic_ptr_4arg("glUniform3i", &self.glUniform3i_p, location, v0, v1, v2);
+            let out = call_atomic_ptr_4arg(
+                "glUniform3i",
+                self.lazy(&self.glUniform3i_p, b"glUniform3i\0"),
+                location,
+                v0,
+                v1,
+                v2,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform3i");
@@ -31797,7 +35202,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform3i_is_loaded(&self) -> bool {
-            !self.glUniform3i_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform3i_p, b"glUniform3i\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform3iv](http://docs.gl/gl4/glUniform)(location, count, value)
         /// * `value` len: count*3
@@ -31813,8 +35221,13 @@ pub mod struct_commands {
                     value
                 );
             }
-            let out =
-                call_atomic_ptr_3arg("glUniform3iv", &self.glUniform3iv_p, location, count, value);
+            let out = call_atomic_ptr_3arg(
+                "glUniform3iv",
+                self.lazy(&self.glUniform3iv_p, b"glUniform3iv\0"),
+                location,
+                count,
+                value,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform3iv");
@@ -31831,7 +35244,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform3iv_is_loaded(&self) -> bool {
-            !self.glUniform3iv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform3iv_p, b"glUniform3iv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform3ui](http://docs.gl/gl4/glUniform)(location, v0, v1, v2)
         #[cfg_attr(feature = "inline", inline)]
@@ -31847,8 +35263,14 @@ pub mod struct_commands {
                     v2
                 );
             }
-            let out =
-                call_atomic_ptr_4arg("glUniform3ui", &self.glUniform3ui_p, location, v0, v1, v2);
+            let out = call_atomic_ptr_4arg(
+                "glUniform3ui",
+                self.lazy(&self.glUniform3ui_p, b"glUniform3ui\0"),
+                location,
+                v0,
+                v1,
+                v2,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform3ui");
@@ -31865,7 +35287,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform3ui_is_loaded(&self) -> bool {
-            !self.glUniform3ui_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform3ui_p, b"glUniform3ui\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform3uiv](http://docs.gl/gl4/glUniform)(location, count, value)
         /// * `value` len: count*3
@@ -31883,7 +35308,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glUniform3uiv",
-                &self.glUniform3uiv_p,
+                self.lazy(&self.glUniform3uiv_p, b"glUniform3uiv\0"),
                 location,
                 count,
                 value,
@@ -31904,7 +35329,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform3uiv_is_loaded(&self) -> bool {
-            !self.glUniform3uiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform3uiv_p, b"glUniform3uiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform4d](http://docs.gl/gl4/glUniform4d)(location, x, y, z, w)
         #[cfg_attr(feature = "inline", inline)]
@@ -31928,8 +35356,15 @@ pub mod struct_commands {
                     w
                 );
             }
-            let out =
-                call_atomic_ptr_5arg("glUniform4d", &self.glUniform4d_p, location, x, y, z, w);
+            let out = call_atomic_ptr_5arg(
+                "glUniform4d",
+                self.lazy(&self.glUniform4d_p, b"glUniform4d\0"),
+                location,
+                x,
+                y,
+                z,
+                w,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform4d");
@@ -31946,7 +35381,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform4d_is_loaded(&self) -> bool {
-            !self.glUniform4d_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform4d_p, b"glUniform4d\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform4dv](http://docs.gl/gl4/glUniform4dv)(location, count, value)
         /// * `value` len: count*4
@@ -31962,8 +35400,13 @@ pub mod struct_commands {
                     value
                 );
             }
-            let out =
-                call_atomic_ptr_3arg("glUniform4dv", &self.glUniform4dv_p, location, count, value);
+            let out = call_atomic_ptr_3arg(
+                "glUniform4dv",
+                self.lazy(&self.glUniform4dv_p, b"glUniform4dv\0"),
+                location,
+                count,
+                value,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform4dv");
@@ -31980,7 +35423,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform4dv_is_loaded(&self) -> bool {
-            !self.glUniform4dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform4dv_p, b"glUniform4dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform4f](http://docs.gl/gl4/glUniform)(location, v0, v1, v2, v3)
         #[cfg_attr(feature = "inline", inline)]
@@ -32004,8 +35450,15 @@ pub mod struct_commands {
                     v3
                 );
             }
-            let out =
-                call_atomic_ptr_5arg("glUniform4f", &self.glUniform4f_p, location, v0, v1, v2, v3);
+            let out = call_atomic_ptr_5arg(
+                "glUniform4f",
+                self.lazy(&self.glUniform4f_p, b"glUniform4f\0"),
+                location,
+                v0,
+                v1,
+                v2,
+                v3,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform4f");
@@ -32022,7 +35475,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform4f_is_loaded(&self) -> bool {
-            !self.glUniform4f_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform4f_p, b"glUniform4f\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform4fv](http://docs.gl/gl4/glUniform)(location, count, value)
         /// * `value` len: count*4
@@ -32038,8 +35494,13 @@ pub mod struct_commands {
                     value
                 );
             }
-            let out =
-                call_atomic_ptr_3arg("glUniform4fv", &self.glUniform4fv_p, location, count, value);
+            let out = call_atomic_ptr_3arg(
+                "glUniform4fv",
+                self.lazy(&self.glUniform4fv_p, b"glUniform4fv\0"),
+                location,
+                count,
+                value,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform4fv");
@@ -32056,7 +35517,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform4fv_is_loaded(&self) -> bool {
-            !self.glUniform4fv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform4fv_p, b"glUniform4fv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform4i](http://docs.gl/gl4/glUniform)(location, v0, v1, v2, v3)
         #[cfg_attr(feature = "inline", inline)]
@@ -32080,8 +35544,15 @@ pub mod struct_commands {
                     v3
                 );
             }
-            let out =
-                call_atomic_ptr_5arg("glUniform4i", &self.glUniform4i_p, location, v0, v1, v2, v3);
+            let out = call_atomic_ptr_5arg(
+                "glUniform4i",
+                self.lazy(&self.glUniform4i_p, b"glUniform4i\0"),
+                location,
+                v0,
+                v1,
+                v2,
+                v3,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform4i");
@@ -32098,7 +35569,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform4i_is_loaded(&self) -> bool {
-            !self.glUniform4i_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform4i_p, b"glUniform4i\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform4iv](http://docs.gl/gl4/glUniform)(location, count, value)
         /// * `value` len: count*4
@@ -32114,8 +35588,13 @@ pub mod struct_commands {
                     value
                 );
             }
-            let out =
-                call_atomic_ptr_3arg("glUniform4iv", &self.glUniform4iv_p, location, count, value);
+            let out = call_atomic_ptr_3arg(
+                "glUniform4iv",
+                self.lazy(&self.glUniform4iv_p, b"glUniform4iv\0"),
+                location,
+                count,
+                value,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUniform4iv");
@@ -32132,7 +35611,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform4iv_is_loaded(&self) -> bool {
-            !self.glUniform4iv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform4iv_p, b"glUniform4iv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform4ui](http://docs.gl/gl4/glUniform)(location, v0, v1, v2, v3)
         #[cfg_attr(feature = "inline", inline)]
@@ -32158,7 +35640,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glUniform4ui",
-                &self.glUniform4ui_p,
+                self.lazy(&self.glUniform4ui_p, b"glUniform4ui\0"),
                 location,
                 v0,
                 v1,
@@ -32181,7 +35663,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform4ui_is_loaded(&self) -> bool {
-            !self.glUniform4ui_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform4ui_p, b"glUniform4ui\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniform4uiv](http://docs.gl/gl4/glUniform)(location, count, value)
         /// * `value` len: count*4
@@ -32199,7 +35684,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glUniform4uiv",
-                &self.glUniform4uiv_p,
+                self.lazy(&self.glUniform4uiv_p, b"glUniform4uiv\0"),
                 location,
                 count,
                 value,
@@ -32220,7 +35705,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Uniform4uiv_is_loaded(&self) -> bool {
-            !self.glUniform4uiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniform4uiv_p, b"glUniform4uiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniformBlockBinding](http://docs.gl/gl4/glUniformBlockBinding)(program, uniformBlockIndex, uniformBlockBinding)
         #[cfg_attr(feature = "inline", inline)]
@@ -32242,7 +35730,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glUniformBlockBinding",
-                &self.glUniformBlockBinding_p,
+                self.lazy(&self.glUniformBlockBinding_p, b"glUniformBlockBinding\0"),
                 program,
                 uniformBlockIndex,
                 uniformBlockBinding,
@@ -32267,7 +35755,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UniformBlockBinding_is_loaded(&self) -> bool {
-            !self.glUniformBlockBinding_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniformBlockBinding_p, b"glUniformBlockBinding\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniformMatrix2dv](http://docs.gl/gl4/glUniformMatrix2dv)(location, count, transpose, value)
         /// * `value` len: count*4
@@ -32292,7 +35783,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glUniformMatrix2dv",
-                &self.glUniformMatrix2dv_p,
+                self.lazy(&self.glUniformMatrix2dv_p, b"glUniformMatrix2dv\0"),
                 location,
                 count,
                 transpose,
@@ -32318,7 +35809,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UniformMatrix2dv_is_loaded(&self) -> bool {
-            !self.glUniformMatrix2dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniformMatrix2dv_p, b"glUniformMatrix2dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniformMatrix2fv](http://docs.gl/gl4/glUniform)(location, count, transpose, value)
         /// * `value` len: count*4
@@ -32343,7 +35837,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glUniformMatrix2fv",
-                &self.glUniformMatrix2fv_p,
+                self.lazy(&self.glUniformMatrix2fv_p, b"glUniformMatrix2fv\0"),
                 location,
                 count,
                 transpose,
@@ -32369,7 +35863,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UniformMatrix2fv_is_loaded(&self) -> bool {
-            !self.glUniformMatrix2fv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniformMatrix2fv_p, b"glUniformMatrix2fv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniformMatrix2x3dv](http://docs.gl/gl4/glUniformMatrix2x3dv)(location, count, transpose, value)
         /// * `value` len: count*6
@@ -32394,7 +35891,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glUniformMatrix2x3dv",
-                &self.glUniformMatrix2x3dv_p,
+                self.lazy(&self.glUniformMatrix2x3dv_p, b"glUniformMatrix2x3dv\0"),
                 location,
                 count,
                 transpose,
@@ -32420,7 +35917,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UniformMatrix2x3dv_is_loaded(&self) -> bool {
-            !self.glUniformMatrix2x3dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniformMatrix2x3dv_p, b"glUniformMatrix2x3dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniformMatrix2x3fv](http://docs.gl/gl4/glUniform)(location, count, transpose, value)
         /// * `value` len: count*6
@@ -32445,7 +35945,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glUniformMatrix2x3fv",
-                &self.glUniformMatrix2x3fv_p,
+                self.lazy(&self.glUniformMatrix2x3fv_p, b"glUniformMatrix2x3fv\0"),
                 location,
                 count,
                 transpose,
@@ -32471,7 +35971,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UniformMatrix2x3fv_is_loaded(&self) -> bool {
-            !self.glUniformMatrix2x3fv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniformMatrix2x3fv_p, b"glUniformMatrix2x3fv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniformMatrix2x4dv](http://docs.gl/gl4/glUniformMatrix2x4dv)(location, count, transpose, value)
         /// * `value` len: count*8
@@ -32496,7 +35999,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glUniformMatrix2x4dv",
-                &self.glUniformMatrix2x4dv_p,
+                self.lazy(&self.glUniformMatrix2x4dv_p, b"glUniformMatrix2x4dv\0"),
                 location,
                 count,
                 transpose,
@@ -32522,7 +36025,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UniformMatrix2x4dv_is_loaded(&self) -> bool {
-            !self.glUniformMatrix2x4dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniformMatrix2x4dv_p, b"glUniformMatrix2x4dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniformMatrix2x4fv](http://docs.gl/gl4/glUniform)(location, count, transpose, value)
         /// * `value` len: count*8
@@ -32547,7 +36053,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glUniformMatrix2x4fv",
-                &self.glUniformMatrix2x4fv_p,
+                self.lazy(&self.glUniformMatrix2x4fv_p, b"glUniformMatrix2x4fv\0"),
                 location,
                 count,
                 transpose,
@@ -32573,7 +36079,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UniformMatrix2x4fv_is_loaded(&self) -> bool {
-            !self.glUniformMatrix2x4fv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniformMatrix2x4fv_p, b"glUniformMatrix2x4fv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniformMatrix3dv](http://docs.gl/gl4/glUniformMatrix3dv)(location, count, transpose, value)
         /// * `value` len: count*9
@@ -32598,7 +36107,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glUniformMatrix3dv",
-                &self.glUniformMatrix3dv_p,
+                self.lazy(&self.glUniformMatrix3dv_p, b"glUniformMatrix3dv\0"),
                 location,
                 count,
                 transpose,
@@ -32624,7 +36133,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UniformMatrix3dv_is_loaded(&self) -> bool {
-            !self.glUniformMatrix3dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniformMatrix3dv_p, b"glUniformMatrix3dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniformMatrix3fv](http://docs.gl/gl4/glUniform)(location, count, transpose, value)
         /// * `value` len: count*9
@@ -32649,7 +36161,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glUniformMatrix3fv",
-                &self.glUniformMatrix3fv_p,
+                self.lazy(&self.glUniformMatrix3fv_p, b"glUniformMatrix3fv\0"),
                 location,
                 count,
                 transpose,
@@ -32675,7 +36187,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UniformMatrix3fv_is_loaded(&self) -> bool {
-            !self.glUniformMatrix3fv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniformMatrix3fv_p, b"glUniformMatrix3fv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniformMatrix3x2dv](http://docs.gl/gl4/glUniformMatrix3x2dv)(location, count, transpose, value)
         /// * `value` len: count*6
@@ -32700,7 +36215,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glUniformMatrix3x2dv",
-                &self.glUniformMatrix3x2dv_p,
+                self.lazy(&self.glUniformMatrix3x2dv_p, b"glUniformMatrix3x2dv\0"),
                 location,
                 count,
                 transpose,
@@ -32726,7 +36241,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UniformMatrix3x2dv_is_loaded(&self) -> bool {
-            !self.glUniformMatrix3x2dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniformMatrix3x2dv_p, b"glUniformMatrix3x2dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniformMatrix3x2fv](http://docs.gl/gl4/glUniform)(location, count, transpose, value)
         /// * `value` len: count*6
@@ -32751,7 +36269,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glUniformMatrix3x2fv",
-                &self.glUniformMatrix3x2fv_p,
+                self.lazy(&self.glUniformMatrix3x2fv_p, b"glUniformMatrix3x2fv\0"),
                 location,
                 count,
                 transpose,
@@ -32777,7 +36295,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UniformMatrix3x2fv_is_loaded(&self) -> bool {
-            !self.glUniformMatrix3x2fv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniformMatrix3x2fv_p, b"glUniformMatrix3x2fv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniformMatrix3x4dv](http://docs.gl/gl4/glUniformMatrix3x4dv)(location, count, transpose, value)
         /// * `value` len: count*12
@@ -32802,7 +36323,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glUniformMatrix3x4dv",
-                &self.glUniformMatrix3x4dv_p,
+                self.lazy(&self.glUniformMatrix3x4dv_p, b"glUniformMatrix3x4dv\0"),
                 location,
                 count,
                 transpose,
@@ -32828,7 +36349,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UniformMatrix3x4dv_is_loaded(&self) -> bool {
-            !self.glUniformMatrix3x4dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniformMatrix3x4dv_p, b"glUniformMatrix3x4dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniformMatrix3x4fv](http://docs.gl/gl4/glUniform)(location, count, transpose, value)
         /// * `value` len: count*12
@@ -32853,7 +36377,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glUniformMatrix3x4fv",
-                &self.glUniformMatrix3x4fv_p,
+                self.lazy(&self.glUniformMatrix3x4fv_p, b"glUniformMatrix3x4fv\0"),
                 location,
                 count,
                 transpose,
@@ -32879,7 +36403,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UniformMatrix3x4fv_is_loaded(&self) -> bool {
-            !self.glUniformMatrix3x4fv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniformMatrix3x4fv_p, b"glUniformMatrix3x4fv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniformMatrix4dv](http://docs.gl/gl4/glUniformMatrix4dv)(location, count, transpose, value)
         /// * `value` len: count*16
@@ -32904,7 +36431,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glUniformMatrix4dv",
-                &self.glUniformMatrix4dv_p,
+                self.lazy(&self.glUniformMatrix4dv_p, b"glUniformMatrix4dv\0"),
                 location,
                 count,
                 transpose,
@@ -32930,7 +36457,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UniformMatrix4dv_is_loaded(&self) -> bool {
-            !self.glUniformMatrix4dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniformMatrix4dv_p, b"glUniformMatrix4dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniformMatrix4fv](http://docs.gl/gl4/glUniform)(location, count, transpose, value)
         /// * `value` len: count*16
@@ -32955,7 +36485,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glUniformMatrix4fv",
-                &self.glUniformMatrix4fv_p,
+                self.lazy(&self.glUniformMatrix4fv_p, b"glUniformMatrix4fv\0"),
                 location,
                 count,
                 transpose,
@@ -32981,7 +36511,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UniformMatrix4fv_is_loaded(&self) -> bool {
-            !self.glUniformMatrix4fv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniformMatrix4fv_p, b"glUniformMatrix4fv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniformMatrix4x2dv](http://docs.gl/gl4/glUniformMatrix4x2dv)(location, count, transpose, value)
         /// * `value` len: count*8
@@ -33006,7 +36539,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glUniformMatrix4x2dv",
-                &self.glUniformMatrix4x2dv_p,
+                self.lazy(&self.glUniformMatrix4x2dv_p, b"glUniformMatrix4x2dv\0"),
                 location,
                 count,
                 transpose,
@@ -33032,7 +36565,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UniformMatrix4x2dv_is_loaded(&self) -> bool {
-            !self.glUniformMatrix4x2dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniformMatrix4x2dv_p, b"glUniformMatrix4x2dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniformMatrix4x2fv](http://docs.gl/gl4/glUniform)(location, count, transpose, value)
         /// * `value` len: count*8
@@ -33057,7 +36593,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glUniformMatrix4x2fv",
-                &self.glUniformMatrix4x2fv_p,
+                self.lazy(&self.glUniformMatrix4x2fv_p, b"glUniformMatrix4x2fv\0"),
                 location,
                 count,
                 transpose,
@@ -33083,7 +36619,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UniformMatrix4x2fv_is_loaded(&self) -> bool {
-            !self.glUniformMatrix4x2fv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniformMatrix4x2fv_p, b"glUniformMatrix4x2fv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniformMatrix4x3dv](http://docs.gl/gl4/glUniformMatrix4x3dv)(location, count, transpose, value)
         /// * `value` len: count*12
@@ -33108,7 +36647,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glUniformMatrix4x3dv",
-                &self.glUniformMatrix4x3dv_p,
+                self.lazy(&self.glUniformMatrix4x3dv_p, b"glUniformMatrix4x3dv\0"),
                 location,
                 count,
                 transpose,
@@ -33134,7 +36673,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UniformMatrix4x3dv_is_loaded(&self) -> bool {
-            !self.glUniformMatrix4x3dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniformMatrix4x3dv_p, b"glUniformMatrix4x3dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniformMatrix4x3fv](http://docs.gl/gl4/glUniform)(location, count, transpose, value)
         /// * `value` len: count*12
@@ -33159,7 +36701,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glUniformMatrix4x3fv",
-                &self.glUniformMatrix4x3fv_p,
+                self.lazy(&self.glUniformMatrix4x3fv_p, b"glUniformMatrix4x3fv\0"),
                 location,
                 count,
                 transpose,
@@ -33185,7 +36727,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UniformMatrix4x3fv_is_loaded(&self) -> bool {
-            !self.glUniformMatrix4x3fv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUniformMatrix4x3fv_p, b"glUniformMatrix4x3fv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUniformSubroutinesuiv](http://docs.gl/gl4/glUniformSubroutines)(shadertype, count, indices)
         /// * `shadertype` group: ShaderType
@@ -33209,7 +36754,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glUniformSubroutinesuiv",
-                &self.glUniformSubroutinesuiv_p,
+                self.lazy(
+                    &self.glUniformSubroutinesuiv_p,
+                    b"glUniformSubroutinesuiv\0",
+                ),
                 shadertype,
                 count,
                 indices,
@@ -33234,7 +36782,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UniformSubroutinesuiv_is_loaded(&self) -> bool {
-            !self.glUniformSubroutinesuiv_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glUniformSubroutinesuiv_p,
+                    b"glUniformSubroutinesuiv\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glUnmapBuffer](http://docs.gl/gl4/glUnmapBuffer)(target)
         /// * `target` group: BufferTargetARB
@@ -33245,7 +36799,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.UnmapBuffer({:#X});", target);
             }
-            let out = call_atomic_ptr_1arg("glUnmapBuffer", &self.glUnmapBuffer_p, target);
+            let out = call_atomic_ptr_1arg(
+                "glUnmapBuffer",
+                self.lazy(&self.glUnmapBuffer_p, b"glUnmapBuffer\0"),
+                target,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUnmapBuffer");
@@ -33262,7 +36820,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UnmapBuffer_is_loaded(&self) -> bool {
-            !self.glUnmapBuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUnmapBuffer_p, b"glUnmapBuffer\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUnmapNamedBuffer](http://docs.gl/gl4/glUnmapNamedBuffer)(buffer)
         #[cfg_attr(feature = "inline", inline)]
@@ -33272,8 +36833,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.UnmapNamedBuffer({:?});", buffer);
             }
-            let out =
-                call_atomic_ptr_1arg("glUnmapNamedBuffer", &self.glUnmapNamedBuffer_p, buffer);
+            let out = call_atomic_ptr_1arg(
+                "glUnmapNamedBuffer",
+                self.lazy(&self.glUnmapNamedBuffer_p, b"glUnmapNamedBuffer\0"),
+                buffer,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUnmapNamedBuffer");
@@ -33294,7 +36858,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UnmapNamedBuffer_is_loaded(&self) -> bool {
-            !self.glUnmapNamedBuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUnmapNamedBuffer_p, b"glUnmapNamedBuffer\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUseProgram](http://docs.gl/gl4/glUseProgram)(program)
         #[cfg_attr(feature = "inline", inline)]
@@ -33304,7 +36871,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.UseProgram({:?});", program);
             }
-            let out = call_atomic_ptr_1arg("glUseProgram", &self.glUseProgram_p, program);
+            let out = call_atomic_ptr_1arg(
+                "glUseProgram",
+                self.lazy(&self.glUseProgram_p, b"glUseProgram\0"),
+                program,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glUseProgram");
@@ -33321,7 +36892,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UseProgram_is_loaded(&self) -> bool {
-            !self.glUseProgram_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUseProgram_p, b"glUseProgram\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glUseProgramStages](http://docs.gl/gl4/glUseProgramStages)(pipeline, stages, program)
         /// * `stages` group: UseProgramStageMask
@@ -33344,7 +36918,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glUseProgramStages",
-                &self.glUseProgramStages_p,
+                self.lazy(&self.glUseProgramStages_p, b"glUseProgramStages\0"),
                 pipeline,
                 stages,
                 program,
@@ -33369,7 +36943,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn UseProgramStages_is_loaded(&self) -> bool {
-            !self.glUseProgramStages_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glUseProgramStages_p, b"glUseProgramStages\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glValidateProgram](http://docs.gl/gl4/glValidateProgram)(program)
         #[cfg_attr(feature = "inline", inline)]
@@ -33379,7 +36956,11 @@ pub mod struct_commands {
             {
                 trace!("calling gl.ValidateProgram({:?});", program);
             }
-            let out = call_atomic_ptr_1arg("glValidateProgram", &self.glValidateProgram_p, program);
+            let out = call_atomic_ptr_1arg(
+                "glValidateProgram",
+                self.lazy(&self.glValidateProgram_p, b"glValidateProgram\0"),
+                program,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glValidateProgram");
@@ -33400,7 +36981,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ValidateProgram_is_loaded(&self) -> bool {
-            !self.glValidateProgram_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glValidateProgram_p, b"glValidateProgram\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glValidateProgramPipeline](http://docs.gl/gl4/glValidateProgramPipeline)(pipeline)
         #[cfg_attr(feature = "inline", inline)]
@@ -33412,7 +36996,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_1arg(
                 "glValidateProgramPipeline",
-                &self.glValidateProgramPipeline_p,
+                self.lazy(
+                    &self.glValidateProgramPipeline_p,
+                    b"glValidateProgramPipeline\0",
+                ),
                 pipeline,
             );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
@@ -33435,7 +37022,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ValidateProgramPipeline_is_loaded(&self) -> bool {
-            !self.glValidateProgramPipeline_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glValidateProgramPipeline_p,
+                    b"glValidateProgramPipeline\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexArrayAttribBinding](http://docs.gl/gl4/glVertexArrayAttribBinding)(vaobj, attribindex, bindingindex)
         #[cfg_attr(feature = "inline", inline)]
@@ -33457,7 +37050,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glVertexArrayAttribBinding",
-                &self.glVertexArrayAttribBinding_p,
+                self.lazy(
+                    &self.glVertexArrayAttribBinding_p,
+                    b"glVertexArrayAttribBinding\0",
+                ),
                 vaobj,
                 attribindex,
                 bindingindex,
@@ -33482,7 +37078,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexArrayAttribBinding_is_loaded(&self) -> bool {
-            !self.glVertexArrayAttribBinding_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glVertexArrayAttribBinding_p,
+                    b"glVertexArrayAttribBinding\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexArrayAttribFormat](http://docs.gl/gl4/glVertexArrayAttribFormat)(vaobj, attribindex, size, type_, normalized, relativeoffset)
         /// * `type_` group: VertexAttribType
@@ -33511,7 +37113,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glVertexArrayAttribFormat",
-                &self.glVertexArrayAttribFormat_p,
+                self.lazy(
+                    &self.glVertexArrayAttribFormat_p,
+                    b"glVertexArrayAttribFormat\0",
+                ),
                 vaobj,
                 attribindex,
                 size,
@@ -33539,7 +37144,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexArrayAttribFormat_is_loaded(&self) -> bool {
-            !self.glVertexArrayAttribFormat_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glVertexArrayAttribFormat_p,
+                    b"glVertexArrayAttribFormat\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexArrayAttribIFormat](http://docs.gl/gl4/glVertexArrayAttribIFormat)(vaobj, attribindex, size, type_, relativeoffset)
         /// * `type_` group: VertexAttribIType
@@ -33566,7 +37177,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glVertexArrayAttribIFormat",
-                &self.glVertexArrayAttribIFormat_p,
+                self.lazy(
+                    &self.glVertexArrayAttribIFormat_p,
+                    b"glVertexArrayAttribIFormat\0",
+                ),
                 vaobj,
                 attribindex,
                 size,
@@ -33593,7 +37207,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexArrayAttribIFormat_is_loaded(&self) -> bool {
-            !self.glVertexArrayAttribIFormat_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glVertexArrayAttribIFormat_p,
+                    b"glVertexArrayAttribIFormat\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexArrayAttribLFormat](http://docs.gl/gl4/glVertexArrayAttribLFormat)(vaobj, attribindex, size, type_, relativeoffset)
         /// * `type_` group: VertexAttribLType
@@ -33620,7 +37240,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glVertexArrayAttribLFormat",
-                &self.glVertexArrayAttribLFormat_p,
+                self.lazy(
+                    &self.glVertexArrayAttribLFormat_p,
+                    b"glVertexArrayAttribLFormat\0",
+                ),
                 vaobj,
                 attribindex,
                 size,
@@ -33647,7 +37270,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexArrayAttribLFormat_is_loaded(&self) -> bool {
-            !self.glVertexArrayAttribLFormat_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glVertexArrayAttribLFormat_p,
+                    b"glVertexArrayAttribLFormat\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexArrayBindingDivisor](http://docs.gl/gl4/glVertexArrayBindingDivisor)(vaobj, bindingindex, divisor)
         #[cfg_attr(feature = "inline", inline)]
@@ -33669,7 +37298,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glVertexArrayBindingDivisor",
-                &self.glVertexArrayBindingDivisor_p,
+                self.lazy(
+                    &self.glVertexArrayBindingDivisor_p,
+                    b"glVertexArrayBindingDivisor\0",
+                ),
                 vaobj,
                 bindingindex,
                 divisor,
@@ -33694,7 +37326,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexArrayBindingDivisor_is_loaded(&self) -> bool {
-            !self.glVertexArrayBindingDivisor_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glVertexArrayBindingDivisor_p,
+                    b"glVertexArrayBindingDivisor\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexArrayElementBuffer](http://docs.gl/gl4/glVertexArrayElementBuffer)(vaobj, buffer)
         #[cfg_attr(feature = "inline", inline)]
@@ -33710,7 +37348,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glVertexArrayElementBuffer",
-                &self.glVertexArrayElementBuffer_p,
+                self.lazy(
+                    &self.glVertexArrayElementBuffer_p,
+                    b"glVertexArrayElementBuffer\0",
+                ),
                 vaobj,
                 buffer,
             );
@@ -33734,7 +37375,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexArrayElementBuffer_is_loaded(&self) -> bool {
-            !self.glVertexArrayElementBuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glVertexArrayElementBuffer_p,
+                    b"glVertexArrayElementBuffer\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexArrayVertexBuffer](http://docs.gl/gl4/glVertexArrayVertexBuffer)(vaobj, bindingindex, buffer, offset, stride)
         #[cfg_attr(feature = "inline", inline)]
@@ -33760,7 +37407,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glVertexArrayVertexBuffer",
-                &self.glVertexArrayVertexBuffer_p,
+                self.lazy(
+                    &self.glVertexArrayVertexBuffer_p,
+                    b"glVertexArrayVertexBuffer\0",
+                ),
                 vaobj,
                 bindingindex,
                 buffer,
@@ -33787,7 +37437,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexArrayVertexBuffer_is_loaded(&self) -> bool {
-            !self.glVertexArrayVertexBuffer_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glVertexArrayVertexBuffer_p,
+                    b"glVertexArrayVertexBuffer\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexArrayVertexBuffers](http://docs.gl/gl4/glVertexArrayVertexBuffers)(vaobj, first, count, buffers, offsets, strides)
         #[cfg_attr(feature = "inline", inline)]
@@ -33815,7 +37471,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glVertexArrayVertexBuffers",
-                &self.glVertexArrayVertexBuffers_p,
+                self.lazy(
+                    &self.glVertexArrayVertexBuffers_p,
+                    b"glVertexArrayVertexBuffers\0",
+                ),
                 vaobj,
                 first,
                 count,
@@ -33843,7 +37502,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexArrayVertexBuffers_is_loaded(&self) -> bool {
-            !self.glVertexArrayVertexBuffers_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glVertexArrayVertexBuffers_p,
+                    b"glVertexArrayVertexBuffers\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib1d](http://docs.gl/gl4/glVertexAttrib1d)(index, x)
         /// * vector equivalent: [`glVertexAttrib1dv`]
@@ -33854,7 +37519,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib1d({:?}, {:?});", index, x);
             }
-            let out = call_atomic_ptr_2arg("glVertexAttrib1d", &self.glVertexAttrib1d_p, index, x);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib1d",
+                self.lazy(&self.glVertexAttrib1d_p, b"glVertexAttrib1d\0"),
+                index,
+                x,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib1d");
@@ -33875,7 +37545,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib1d_is_loaded(&self) -> bool {
-            !self.glVertexAttrib1d_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib1d_p, b"glVertexAttrib1d\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib1dv](http://docs.gl/gl4/glVertexAttrib1dv)(index, v)
         /// * `v` len: 1
@@ -33886,8 +37559,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib1dv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib1dv", &self.glVertexAttrib1dv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib1dv",
+                self.lazy(&self.glVertexAttrib1dv_p, b"glVertexAttrib1dv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib1dv");
@@ -33908,7 +37585,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib1dv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib1dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib1dv_p, b"glVertexAttrib1dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib1f](http://docs.gl/gl4/glVertexAttrib)(index, x)
         /// * vector equivalent: [`glVertexAttrib1fv`]
@@ -33919,7 +37599,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib1f({:?}, {:?});", index, x);
             }
-            let out = call_atomic_ptr_2arg("glVertexAttrib1f", &self.glVertexAttrib1f_p, index, x);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib1f",
+                self.lazy(&self.glVertexAttrib1f_p, b"glVertexAttrib1f\0"),
+                index,
+                x,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib1f");
@@ -33940,7 +37625,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib1f_is_loaded(&self) -> bool {
-            !self.glVertexAttrib1f_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib1f_p, b"glVertexAttrib1f\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib1fv](http://docs.gl/gl4/glVertexAttrib)(index, v)
         /// * `v` len: 1
@@ -33951,8 +37639,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib1fv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib1fv", &self.glVertexAttrib1fv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib1fv",
+                self.lazy(&self.glVertexAttrib1fv_p, b"glVertexAttrib1fv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib1fv");
@@ -33973,7 +37665,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib1fv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib1fv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib1fv_p, b"glVertexAttrib1fv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib1s](http://docs.gl/gl4/glVertexAttrib1s)(index, x)
         /// * vector equivalent: [`glVertexAttrib1sv`]
@@ -33984,7 +37679,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib1s({:?}, {:?});", index, x);
             }
-            let out = call_atomic_ptr_2arg("glVertexAttrib1s", &self.glVertexAttrib1s_p, index, x);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib1s",
+                self.lazy(&self.glVertexAttrib1s_p, b"glVertexAttrib1s\0"),
+                index,
+                x,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib1s");
@@ -34005,7 +37705,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib1s_is_loaded(&self) -> bool {
-            !self.glVertexAttrib1s_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib1s_p, b"glVertexAttrib1s\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib1sv](http://docs.gl/gl4/glVertexAttrib1sv)(index, v)
         /// * `v` len: 1
@@ -34016,8 +37719,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib1sv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib1sv", &self.glVertexAttrib1sv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib1sv",
+                self.lazy(&self.glVertexAttrib1sv_p, b"glVertexAttrib1sv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib1sv");
@@ -34038,7 +37745,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib1sv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib1sv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib1sv_p, b"glVertexAttrib1sv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib2d](http://docs.gl/gl4/glVertexAttrib2d)(index, x, y)
         /// * vector equivalent: [`glVertexAttrib2dv`]
@@ -34049,8 +37759,13 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib2d({:?}, {:?}, {:?});", index, x, y);
             }
-            let out =
-                call_atomic_ptr_3arg("glVertexAttrib2d", &self.glVertexAttrib2d_p, index, x, y);
+            let out = call_atomic_ptr_3arg(
+                "glVertexAttrib2d",
+                self.lazy(&self.glVertexAttrib2d_p, b"glVertexAttrib2d\0"),
+                index,
+                x,
+                y,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib2d");
@@ -34071,7 +37786,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib2d_is_loaded(&self) -> bool {
-            !self.glVertexAttrib2d_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib2d_p, b"glVertexAttrib2d\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib2dv](http://docs.gl/gl4/glVertexAttrib2dv)(index, v)
         /// * `v` len: 2
@@ -34082,8 +37800,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib2dv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib2dv", &self.glVertexAttrib2dv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib2dv",
+                self.lazy(&self.glVertexAttrib2dv_p, b"glVertexAttrib2dv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib2dv");
@@ -34104,7 +37826,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib2dv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib2dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib2dv_p, b"glVertexAttrib2dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib2f](http://docs.gl/gl4/glVertexAttrib)(index, x, y)
         /// * vector equivalent: [`glVertexAttrib2fv`]
@@ -34115,8 +37840,13 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib2f({:?}, {:?}, {:?});", index, x, y);
             }
-            let out =
-                call_atomic_ptr_3arg("glVertexAttrib2f", &self.glVertexAttrib2f_p, index, x, y);
+            let out = call_atomic_ptr_3arg(
+                "glVertexAttrib2f",
+                self.lazy(&self.glVertexAttrib2f_p, b"glVertexAttrib2f\0"),
+                index,
+                x,
+                y,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib2f");
@@ -34137,7 +37867,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib2f_is_loaded(&self) -> bool {
-            !self.glVertexAttrib2f_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib2f_p, b"glVertexAttrib2f\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib2fv](http://docs.gl/gl4/glVertexAttrib)(index, v)
         /// * `v` len: 2
@@ -34148,8 +37881,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib2fv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib2fv", &self.glVertexAttrib2fv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib2fv",
+                self.lazy(&self.glVertexAttrib2fv_p, b"glVertexAttrib2fv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib2fv");
@@ -34170,7 +37907,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib2fv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib2fv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib2fv_p, b"glVertexAttrib2fv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib2s](http://docs.gl/gl4/glVertexAttrib2s)(index, x, y)
         /// * vector equivalent: [`glVertexAttrib2sv`]
@@ -34181,8 +37921,13 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib2s({:?}, {:?}, {:?});", index, x, y);
             }
-            let out =
-                call_atomic_ptr_3arg("glVertexAttrib2s", &self.glVertexAttrib2s_p, index, x, y);
+            let out = call_atomic_ptr_3arg(
+                "glVertexAttrib2s",
+                self.lazy(&self.glVertexAttrib2s_p, b"glVertexAttrib2s\0"),
+                index,
+                x,
+                y,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib2s");
@@ -34203,7 +37948,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib2s_is_loaded(&self) -> bool {
-            !self.glVertexAttrib2s_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib2s_p, b"glVertexAttrib2s\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib2sv](http://docs.gl/gl4/glVertexAttrib2sv)(index, v)
         /// * `v` len: 2
@@ -34214,8 +37962,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib2sv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib2sv", &self.glVertexAttrib2sv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib2sv",
+                self.lazy(&self.glVertexAttrib2sv_p, b"glVertexAttrib2sv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib2sv");
@@ -34236,7 +37988,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib2sv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib2sv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib2sv_p, b"glVertexAttrib2sv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib3d](http://docs.gl/gl4/glVertexAttrib3d)(index, x, y, z)
         /// * vector equivalent: [`glVertexAttrib3dv`]
@@ -34253,8 +38008,14 @@ pub mod struct_commands {
                     z
                 );
             }
-            let out =
-                call_atomic_ptr_4arg("glVertexAttrib3d", &self.glVertexAttrib3d_p, index, x, y, z);
+            let out = call_atomic_ptr_4arg(
+                "glVertexAttrib3d",
+                self.lazy(&self.glVertexAttrib3d_p, b"glVertexAttrib3d\0"),
+                index,
+                x,
+                y,
+                z,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib3d");
@@ -34275,7 +38036,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib3d_is_loaded(&self) -> bool {
-            !self.glVertexAttrib3d_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib3d_p, b"glVertexAttrib3d\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib3dv](http://docs.gl/gl4/glVertexAttrib3dv)(index, v)
         /// * `v` len: 3
@@ -34286,8 +38050,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib3dv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib3dv", &self.glVertexAttrib3dv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib3dv",
+                self.lazy(&self.glVertexAttrib3dv_p, b"glVertexAttrib3dv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib3dv");
@@ -34308,7 +38076,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib3dv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib3dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib3dv_p, b"glVertexAttrib3dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib3f](http://docs.gl/gl4/glVertexAttrib)(index, x, y, z)
         /// * vector equivalent: [`glVertexAttrib3fv`]
@@ -34325,8 +38096,14 @@ pub mod struct_commands {
                     z
                 );
             }
-            let out =
-                call_atomic_ptr_4arg("glVertexAttrib3f", &self.glVertexAttrib3f_p, index, x, y, z);
+            let out = call_atomic_ptr_4arg(
+                "glVertexAttrib3f",
+                self.lazy(&self.glVertexAttrib3f_p, b"glVertexAttrib3f\0"),
+                index,
+                x,
+                y,
+                z,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib3f");
@@ -34347,7 +38124,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib3f_is_loaded(&self) -> bool {
-            !self.glVertexAttrib3f_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib3f_p, b"glVertexAttrib3f\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib3fv](http://docs.gl/gl4/glVertexAttrib)(index, v)
         /// * `v` len: 3
@@ -34358,8 +38138,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib3fv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib3fv", &self.glVertexAttrib3fv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib3fv",
+                self.lazy(&self.glVertexAttrib3fv_p, b"glVertexAttrib3fv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib3fv");
@@ -34380,7 +38164,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib3fv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib3fv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib3fv_p, b"glVertexAttrib3fv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib3s](http://docs.gl/gl4/glVertexAttrib3s)(index, x, y, z)
         /// * vector equivalent: [`glVertexAttrib3sv`]
@@ -34397,8 +38184,14 @@ pub mod struct_commands {
                     z
                 );
             }
-            let out =
-                call_atomic_ptr_4arg("glVertexAttrib3s", &self.glVertexAttrib3s_p, index, x, y, z);
+            let out = call_atomic_ptr_4arg(
+                "glVertexAttrib3s",
+                self.lazy(&self.glVertexAttrib3s_p, b"glVertexAttrib3s\0"),
+                index,
+                x,
+                y,
+                z,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib3s");
@@ -34419,7 +38212,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib3s_is_loaded(&self) -> bool {
-            !self.glVertexAttrib3s_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib3s_p, b"glVertexAttrib3s\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib3sv](http://docs.gl/gl4/glVertexAttrib3sv)(index, v)
         /// * `v` len: 3
@@ -34430,8 +38226,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib3sv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib3sv", &self.glVertexAttrib3sv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib3sv",
+                self.lazy(&self.glVertexAttrib3sv_p, b"glVertexAttrib3sv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib3sv");
@@ -34452,7 +38252,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib3sv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib3sv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib3sv_p, b"glVertexAttrib3sv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib4Nbv](http://docs.gl/gl4/glVertexAttrib4Nbv)(index, v)
         /// * `v` len: 4
@@ -34463,8 +38266,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib4Nbv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib4Nbv", &self.glVertexAttrib4Nbv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib4Nbv",
+                self.lazy(&self.glVertexAttrib4Nbv_p, b"glVertexAttrib4Nbv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib4Nbv");
@@ -34485,7 +38292,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib4Nbv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib4Nbv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib4Nbv_p, b"glVertexAttrib4Nbv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib4Niv](http://docs.gl/gl4/glVertexAttrib4N)(index, v)
         /// * `v` len: 4
@@ -34496,8 +38306,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib4Niv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib4Niv", &self.glVertexAttrib4Niv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib4Niv",
+                self.lazy(&self.glVertexAttrib4Niv_p, b"glVertexAttrib4Niv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib4Niv");
@@ -34518,7 +38332,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib4Niv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib4Niv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib4Niv_p, b"glVertexAttrib4Niv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib4Nsv](http://docs.gl/gl4/glVertexAttrib4Nsv)(index, v)
         /// * `v` len: 4
@@ -34529,8 +38346,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib4Nsv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib4Nsv", &self.glVertexAttrib4Nsv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib4Nsv",
+                self.lazy(&self.glVertexAttrib4Nsv_p, b"glVertexAttrib4Nsv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib4Nsv");
@@ -34551,7 +38372,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib4Nsv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib4Nsv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib4Nsv_p, b"glVertexAttrib4Nsv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib4Nub](http://docs.gl/gl4/glVertexAttrib4Nub)(index, x, y, z, w)
         #[cfg_attr(feature = "inline", inline)]
@@ -34577,7 +38401,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glVertexAttrib4Nub",
-                &self.glVertexAttrib4Nub_p,
+                self.lazy(&self.glVertexAttrib4Nub_p, b"glVertexAttrib4Nub\0"),
                 index,
                 x,
                 y,
@@ -34604,7 +38428,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib4Nub_is_loaded(&self) -> bool {
-            !self.glVertexAttrib4Nub_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib4Nub_p, b"glVertexAttrib4Nub\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib4Nubv](http://docs.gl/gl4/glVertexAttrib4Nubv)(index, v)
         /// * `v` len: 4
@@ -34615,8 +38442,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib4Nubv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib4Nubv", &self.glVertexAttrib4Nubv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib4Nubv",
+                self.lazy(&self.glVertexAttrib4Nubv_p, b"glVertexAttrib4Nubv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib4Nubv");
@@ -34637,7 +38468,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib4Nubv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib4Nubv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib4Nubv_p, b"glVertexAttrib4Nubv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib4Nuiv](http://docs.gl/gl4/glVertexAttrib4N)(index, v)
         /// * `v` len: 4
@@ -34648,8 +38482,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib4Nuiv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib4Nuiv", &self.glVertexAttrib4Nuiv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib4Nuiv",
+                self.lazy(&self.glVertexAttrib4Nuiv_p, b"glVertexAttrib4Nuiv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib4Nuiv");
@@ -34670,7 +38508,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib4Nuiv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib4Nuiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib4Nuiv_p, b"glVertexAttrib4Nuiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib4Nusv](http://docs.gl/gl4/glVertexAttrib4Nusv)(index, v)
         /// * `v` len: 4
@@ -34681,8 +38522,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib4Nusv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib4Nusv", &self.glVertexAttrib4Nusv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib4Nusv",
+                self.lazy(&self.glVertexAttrib4Nusv_p, b"glVertexAttrib4Nusv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib4Nusv");
@@ -34703,7 +38548,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib4Nusv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib4Nusv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib4Nusv_p, b"glVertexAttrib4Nusv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib4bv](http://docs.gl/gl4/glVertexAttrib4bv)(index, v)
         /// * `v` len: 4
@@ -34714,8 +38562,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib4bv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib4bv", &self.glVertexAttrib4bv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib4bv",
+                self.lazy(&self.glVertexAttrib4bv_p, b"glVertexAttrib4bv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib4bv");
@@ -34736,7 +38588,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib4bv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib4bv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib4bv_p, b"glVertexAttrib4bv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib4d](http://docs.gl/gl4/glVertexAttrib4d)(index, x, y, z, w)
         /// * vector equivalent: [`glVertexAttrib4dv`]
@@ -34763,7 +38618,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glVertexAttrib4d",
-                &self.glVertexAttrib4d_p,
+                self.lazy(&self.glVertexAttrib4d_p, b"glVertexAttrib4d\0"),
                 index,
                 x,
                 y,
@@ -34790,7 +38645,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib4d_is_loaded(&self) -> bool {
-            !self.glVertexAttrib4d_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib4d_p, b"glVertexAttrib4d\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib4dv](http://docs.gl/gl4/glVertexAttrib4dv)(index, v)
         /// * `v` len: 4
@@ -34801,8 +38659,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib4dv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib4dv", &self.glVertexAttrib4dv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib4dv",
+                self.lazy(&self.glVertexAttrib4dv_p, b"glVertexAttrib4dv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib4dv");
@@ -34823,7 +38685,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib4dv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib4dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib4dv_p, b"glVertexAttrib4dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib4f](http://docs.gl/gl4/glVertexAttrib)(index, x, y, z, w)
         /// * vector equivalent: [`glVertexAttrib4fv`]
@@ -34850,7 +38715,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glVertexAttrib4f",
-                &self.glVertexAttrib4f_p,
+                self.lazy(&self.glVertexAttrib4f_p, b"glVertexAttrib4f\0"),
                 index,
                 x,
                 y,
@@ -34877,7 +38742,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib4f_is_loaded(&self) -> bool {
-            !self.glVertexAttrib4f_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib4f_p, b"glVertexAttrib4f\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib4fv](http://docs.gl/gl4/glVertexAttrib)(index, v)
         /// * `v` len: 4
@@ -34888,8 +38756,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib4fv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib4fv", &self.glVertexAttrib4fv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib4fv",
+                self.lazy(&self.glVertexAttrib4fv_p, b"glVertexAttrib4fv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib4fv");
@@ -34910,7 +38782,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib4fv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib4fv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib4fv_p, b"glVertexAttrib4fv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib4iv](http://docs.gl/gl4/glVertexAttrib)(index, v)
         /// * `v` len: 4
@@ -34921,8 +38796,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib4iv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib4iv", &self.glVertexAttrib4iv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib4iv",
+                self.lazy(&self.glVertexAttrib4iv_p, b"glVertexAttrib4iv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib4iv");
@@ -34943,7 +38822,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib4iv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib4iv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib4iv_p, b"glVertexAttrib4iv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib4s](http://docs.gl/gl4/glVertexAttrib4s)(index, x, y, z, w)
         /// * vector equivalent: [`glVertexAttrib4sv`]
@@ -34970,7 +38852,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glVertexAttrib4s",
-                &self.glVertexAttrib4s_p,
+                self.lazy(&self.glVertexAttrib4s_p, b"glVertexAttrib4s\0"),
                 index,
                 x,
                 y,
@@ -34997,7 +38879,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib4s_is_loaded(&self) -> bool {
-            !self.glVertexAttrib4s_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib4s_p, b"glVertexAttrib4s\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib4sv](http://docs.gl/gl4/glVertexAttrib4sv)(index, v)
         /// * `v` len: 4
@@ -35008,8 +38893,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib4sv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib4sv", &self.glVertexAttrib4sv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib4sv",
+                self.lazy(&self.glVertexAttrib4sv_p, b"glVertexAttrib4sv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib4sv");
@@ -35030,7 +38919,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib4sv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib4sv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib4sv_p, b"glVertexAttrib4sv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib4ubv](http://docs.gl/gl4/glVertexAttrib4ubv)(index, v)
         /// * `v` len: 4
@@ -35041,8 +38933,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib4ubv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib4ubv", &self.glVertexAttrib4ubv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib4ubv",
+                self.lazy(&self.glVertexAttrib4ubv_p, b"glVertexAttrib4ubv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib4ubv");
@@ -35063,7 +38959,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib4ubv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib4ubv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib4ubv_p, b"glVertexAttrib4ubv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib4uiv](http://docs.gl/gl4/glVertexAttrib)(index, v)
         /// * `v` len: 4
@@ -35074,8 +38973,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib4uiv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib4uiv", &self.glVertexAttrib4uiv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib4uiv",
+                self.lazy(&self.glVertexAttrib4uiv_p, b"glVertexAttrib4uiv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib4uiv");
@@ -35096,7 +38999,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib4uiv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib4uiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib4uiv_p, b"glVertexAttrib4uiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttrib4usv](http://docs.gl/gl4/glVertexAttrib4usv)(index, v)
         /// * `v` len: 4
@@ -35107,8 +39013,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttrib4usv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttrib4usv", &self.glVertexAttrib4usv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttrib4usv",
+                self.lazy(&self.glVertexAttrib4usv_p, b"glVertexAttrib4usv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttrib4usv");
@@ -35129,7 +39039,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttrib4usv_is_loaded(&self) -> bool {
-            !self.glVertexAttrib4usv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttrib4usv_p, b"glVertexAttrib4usv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribBinding](http://docs.gl/gl4/glVertexAttribBinding)(attribindex, bindingindex)
         #[cfg_attr(feature = "inline", inline)]
@@ -35145,7 +39058,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glVertexAttribBinding",
-                &self.glVertexAttribBinding_p,
+                self.lazy(&self.glVertexAttribBinding_p, b"glVertexAttribBinding\0"),
                 attribindex,
                 bindingindex,
             );
@@ -35169,7 +39082,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribBinding_is_loaded(&self) -> bool {
-            !self.glVertexAttribBinding_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribBinding_p, b"glVertexAttribBinding\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribDivisor](http://docs.gl/gl4/glVertexAttribDivisor)(index, divisor)
         #[cfg_attr(feature = "inline", inline)]
@@ -35185,7 +39101,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glVertexAttribDivisor",
-                &self.glVertexAttribDivisor_p,
+                self.lazy(&self.glVertexAttribDivisor_p, b"glVertexAttribDivisor\0"),
                 index,
                 divisor,
             );
@@ -35209,7 +39125,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribDivisor_is_loaded(&self) -> bool {
-            !self.glVertexAttribDivisor_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribDivisor_p, b"glVertexAttribDivisor\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribDivisorARB](http://docs.gl/gl4/glVertexAttribDivisorARB)(index, divisor)
         /// * alias of: [`glVertexAttribDivisor`]
@@ -35226,7 +39145,10 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glVertexAttribDivisorARB",
-                &self.glVertexAttribDivisorARB_p,
+                self.lazy(
+                    &self.glVertexAttribDivisorARB_p,
+                    b"glVertexAttribDivisorARB\0",
+                ),
                 index,
                 divisor,
             );
@@ -35250,7 +39172,13 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribDivisorARB_is_loaded(&self) -> bool {
-            !self.glVertexAttribDivisorARB_p.load(RELAX).is_null()
+            !self
+                .lazy(
+                    &self.glVertexAttribDivisorARB_p,
+                    b"glVertexAttribDivisorARB\0",
+                )
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribFormat](http://docs.gl/gl4/glVertexAttribFormat)(attribindex, size, type_, normalized, relativeoffset)
         /// * `type_` group: VertexAttribType
@@ -35277,7 +39205,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glVertexAttribFormat",
-                &self.glVertexAttribFormat_p,
+                self.lazy(&self.glVertexAttribFormat_p, b"glVertexAttribFormat\0"),
                 attribindex,
                 size,
                 type_,
@@ -35304,7 +39232,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribFormat_is_loaded(&self) -> bool {
-            !self.glVertexAttribFormat_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribFormat_p, b"glVertexAttribFormat\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribI1i](http://docs.gl/gl4/glVertexAttribI)(index, x)
         /// * vector equivalent: [`glVertexAttribI1iv`]
@@ -35315,8 +39246,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttribI1i({:?}, {:?});", index, x);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttribI1i", &self.glVertexAttribI1i_p, index, x);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttribI1i",
+                self.lazy(&self.glVertexAttribI1i_p, b"glVertexAttribI1i\0"),
+                index,
+                x,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttribI1i");
@@ -35337,7 +39272,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribI1i_is_loaded(&self) -> bool {
-            !self.glVertexAttribI1i_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribI1i_p, b"glVertexAttribI1i\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribI1iv](http://docs.gl/gl4/glVertexAttribI)(index, v)
         /// * `v` len: 1
@@ -35348,8 +39286,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttribI1iv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttribI1iv", &self.glVertexAttribI1iv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttribI1iv",
+                self.lazy(&self.glVertexAttribI1iv_p, b"glVertexAttribI1iv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttribI1iv");
@@ -35370,7 +39312,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribI1iv_is_loaded(&self) -> bool {
-            !self.glVertexAttribI1iv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribI1iv_p, b"glVertexAttribI1iv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribI1ui](http://docs.gl/gl4/glVertexAttribI)(index, x)
         /// * vector equivalent: [`glVertexAttribI1uiv`]
@@ -35381,8 +39326,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttribI1ui({:?}, {:?});", index, x);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttribI1ui", &self.glVertexAttribI1ui_p, index, x);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttribI1ui",
+                self.lazy(&self.glVertexAttribI1ui_p, b"glVertexAttribI1ui\0"),
+                index,
+                x,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttribI1ui");
@@ -35403,7 +39352,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribI1ui_is_loaded(&self) -> bool {
-            !self.glVertexAttribI1ui_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribI1ui_p, b"glVertexAttribI1ui\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribI1uiv](http://docs.gl/gl4/glVertexAttribI)(index, v)
         /// * `v` len: 1
@@ -35414,8 +39366,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttribI1uiv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttribI1uiv", &self.glVertexAttribI1uiv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttribI1uiv",
+                self.lazy(&self.glVertexAttribI1uiv_p, b"glVertexAttribI1uiv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttribI1uiv");
@@ -35436,7 +39392,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribI1uiv_is_loaded(&self) -> bool {
-            !self.glVertexAttribI1uiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribI1uiv_p, b"glVertexAttribI1uiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribI2i](http://docs.gl/gl4/glVertexAttribI)(index, x, y)
         /// * vector equivalent: [`glVertexAttribI2iv`]
@@ -35447,8 +39406,13 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttribI2i({:?}, {:?}, {:?});", index, x, y);
             }
-            let out =
-                call_atomic_ptr_3arg("glVertexAttribI2i", &self.glVertexAttribI2i_p, index, x, y);
+            let out = call_atomic_ptr_3arg(
+                "glVertexAttribI2i",
+                self.lazy(&self.glVertexAttribI2i_p, b"glVertexAttribI2i\0"),
+                index,
+                x,
+                y,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttribI2i");
@@ -35469,7 +39433,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribI2i_is_loaded(&self) -> bool {
-            !self.glVertexAttribI2i_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribI2i_p, b"glVertexAttribI2i\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribI2iv](http://docs.gl/gl4/glVertexAttribI)(index, v)
         /// * `v` len: 2
@@ -35480,8 +39447,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttribI2iv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttribI2iv", &self.glVertexAttribI2iv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttribI2iv",
+                self.lazy(&self.glVertexAttribI2iv_p, b"glVertexAttribI2iv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttribI2iv");
@@ -35502,7 +39473,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribI2iv_is_loaded(&self) -> bool {
-            !self.glVertexAttribI2iv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribI2iv_p, b"glVertexAttribI2iv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribI2ui](http://docs.gl/gl4/glVertexAttribI)(index, x, y)
         /// * vector equivalent: [`glVertexAttribI2uiv`]
@@ -35520,7 +39494,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glVertexAttribI2ui",
-                &self.glVertexAttribI2ui_p,
+                self.lazy(&self.glVertexAttribI2ui_p, b"glVertexAttribI2ui\0"),
                 index,
                 x,
                 y,
@@ -35545,7 +39519,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribI2ui_is_loaded(&self) -> bool {
-            !self.glVertexAttribI2ui_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribI2ui_p, b"glVertexAttribI2ui\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribI2uiv](http://docs.gl/gl4/glVertexAttribI)(index, v)
         /// * `v` len: 2
@@ -35556,8 +39533,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttribI2uiv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttribI2uiv", &self.glVertexAttribI2uiv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttribI2uiv",
+                self.lazy(&self.glVertexAttribI2uiv_p, b"glVertexAttribI2uiv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttribI2uiv");
@@ -35578,7 +39559,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribI2uiv_is_loaded(&self) -> bool {
-            !self.glVertexAttribI2uiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribI2uiv_p, b"glVertexAttribI2uiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribI3i](http://docs.gl/gl4/glVertexAttribI)(index, x, y, z)
         /// * vector equivalent: [`glVertexAttribI3iv`]
@@ -35597,7 +39581,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glVertexAttribI3i",
-                &self.glVertexAttribI3i_p,
+                self.lazy(&self.glVertexAttribI3i_p, b"glVertexAttribI3i\0"),
                 index,
                 x,
                 y,
@@ -35623,7 +39607,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribI3i_is_loaded(&self) -> bool {
-            !self.glVertexAttribI3i_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribI3i_p, b"glVertexAttribI3i\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribI3iv](http://docs.gl/gl4/glVertexAttribI)(index, v)
         /// * `v` len: 3
@@ -35634,8 +39621,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttribI3iv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttribI3iv", &self.glVertexAttribI3iv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttribI3iv",
+                self.lazy(&self.glVertexAttribI3iv_p, b"glVertexAttribI3iv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttribI3iv");
@@ -35656,7 +39647,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribI3iv_is_loaded(&self) -> bool {
-            !self.glVertexAttribI3iv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribI3iv_p, b"glVertexAttribI3iv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribI3ui](http://docs.gl/gl4/glVertexAttribI)(index, x, y, z)
         /// * vector equivalent: [`glVertexAttribI3uiv`]
@@ -35675,7 +39669,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glVertexAttribI3ui",
-                &self.glVertexAttribI3ui_p,
+                self.lazy(&self.glVertexAttribI3ui_p, b"glVertexAttribI3ui\0"),
                 index,
                 x,
                 y,
@@ -35701,7 +39695,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribI3ui_is_loaded(&self) -> bool {
-            !self.glVertexAttribI3ui_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribI3ui_p, b"glVertexAttribI3ui\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribI3uiv](http://docs.gl/gl4/glVertexAttribI)(index, v)
         /// * `v` len: 3
@@ -35712,8 +39709,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttribI3uiv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttribI3uiv", &self.glVertexAttribI3uiv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttribI3uiv",
+                self.lazy(&self.glVertexAttribI3uiv_p, b"glVertexAttribI3uiv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttribI3uiv");
@@ -35734,7 +39735,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribI3uiv_is_loaded(&self) -> bool {
-            !self.glVertexAttribI3uiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribI3uiv_p, b"glVertexAttribI3uiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribI4bv](http://docs.gl/gl4/glVertexAttribI4bv)(index, v)
         /// * `v` len: 4
@@ -35745,8 +39749,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttribI4bv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttribI4bv", &self.glVertexAttribI4bv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttribI4bv",
+                self.lazy(&self.glVertexAttribI4bv_p, b"glVertexAttribI4bv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttribI4bv");
@@ -35767,7 +39775,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribI4bv_is_loaded(&self) -> bool {
-            !self.glVertexAttribI4bv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribI4bv_p, b"glVertexAttribI4bv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribI4i](http://docs.gl/gl4/glVertexAttribI)(index, x, y, z, w)
         /// * vector equivalent: [`glVertexAttribI4iv`]
@@ -35794,7 +39805,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glVertexAttribI4i",
-                &self.glVertexAttribI4i_p,
+                self.lazy(&self.glVertexAttribI4i_p, b"glVertexAttribI4i\0"),
                 index,
                 x,
                 y,
@@ -35821,7 +39832,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribI4i_is_loaded(&self) -> bool {
-            !self.glVertexAttribI4i_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribI4i_p, b"glVertexAttribI4i\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribI4iv](http://docs.gl/gl4/glVertexAttrib)(index, v)
         /// * `v` len: 4
@@ -35832,8 +39846,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttribI4iv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttribI4iv", &self.glVertexAttribI4iv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttribI4iv",
+                self.lazy(&self.glVertexAttribI4iv_p, b"glVertexAttribI4iv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttribI4iv");
@@ -35854,7 +39872,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribI4iv_is_loaded(&self) -> bool {
-            !self.glVertexAttribI4iv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribI4iv_p, b"glVertexAttribI4iv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribI4sv](http://docs.gl/gl4/glVertexAttribI4sv)(index, v)
         /// * `v` len: 4
@@ -35865,8 +39886,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttribI4sv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttribI4sv", &self.glVertexAttribI4sv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttribI4sv",
+                self.lazy(&self.glVertexAttribI4sv_p, b"glVertexAttribI4sv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttribI4sv");
@@ -35887,7 +39912,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribI4sv_is_loaded(&self) -> bool {
-            !self.glVertexAttribI4sv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribI4sv_p, b"glVertexAttribI4sv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribI4ubv](http://docs.gl/gl4/glVertexAttribI4ubv)(index, v)
         /// * `v` len: 4
@@ -35898,8 +39926,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttribI4ubv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttribI4ubv", &self.glVertexAttribI4ubv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttribI4ubv",
+                self.lazy(&self.glVertexAttribI4ubv_p, b"glVertexAttribI4ubv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttribI4ubv");
@@ -35920,7 +39952,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribI4ubv_is_loaded(&self) -> bool {
-            !self.glVertexAttribI4ubv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribI4ubv_p, b"glVertexAttribI4ubv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribI4ui](http://docs.gl/gl4/glVertexAttrib)(index, x, y, z, w)
         /// * vector equivalent: [`glVertexAttribI4uiv`]
@@ -35947,7 +39982,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glVertexAttribI4ui",
-                &self.glVertexAttribI4ui_p,
+                self.lazy(&self.glVertexAttribI4ui_p, b"glVertexAttribI4ui\0"),
                 index,
                 x,
                 y,
@@ -35974,7 +40009,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribI4ui_is_loaded(&self) -> bool {
-            !self.glVertexAttribI4ui_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribI4ui_p, b"glVertexAttribI4ui\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribI4uiv](http://docs.gl/gl4/glVertexAttrib)(index, v)
         /// * `v` len: 4
@@ -35985,8 +40023,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttribI4uiv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttribI4uiv", &self.glVertexAttribI4uiv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttribI4uiv",
+                self.lazy(&self.glVertexAttribI4uiv_p, b"glVertexAttribI4uiv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttribI4uiv");
@@ -36007,7 +40049,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribI4uiv_is_loaded(&self) -> bool {
-            !self.glVertexAttribI4uiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribI4uiv_p, b"glVertexAttribI4uiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribI4usv](http://docs.gl/gl4/glVertexAttribI4usv)(index, v)
         /// * `v` len: 4
@@ -36018,8 +40063,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttribI4usv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttribI4usv", &self.glVertexAttribI4usv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttribI4usv",
+                self.lazy(&self.glVertexAttribI4usv_p, b"glVertexAttribI4usv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttribI4usv");
@@ -36040,7 +40089,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribI4usv_is_loaded(&self) -> bool {
-            !self.glVertexAttribI4usv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribI4usv_p, b"glVertexAttribI4usv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribIFormat](http://docs.gl/gl4/glVertexAttribIFormat)(attribindex, size, type_, relativeoffset)
         /// * `type_` group: VertexAttribIType
@@ -36065,7 +40117,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glVertexAttribIFormat",
-                &self.glVertexAttribIFormat_p,
+                self.lazy(&self.glVertexAttribIFormat_p, b"glVertexAttribIFormat\0"),
                 attribindex,
                 size,
                 type_,
@@ -36091,7 +40143,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribIFormat_is_loaded(&self) -> bool {
-            !self.glVertexAttribIFormat_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribIFormat_p, b"glVertexAttribIFormat\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribIPointer](http://docs.gl/gl4/glVertexAttribPointer)(index, size, type_, stride, pointer)
         /// * `type_` group: VertexAttribIType
@@ -36119,7 +40174,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glVertexAttribIPointer",
-                &self.glVertexAttribIPointer_p,
+                self.lazy(&self.glVertexAttribIPointer_p, b"glVertexAttribIPointer\0"),
                 index,
                 size,
                 type_,
@@ -36146,7 +40201,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribIPointer_is_loaded(&self) -> bool {
-            !self.glVertexAttribIPointer_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribIPointer_p, b"glVertexAttribIPointer\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribL1d](http://docs.gl/gl4/glVertexAttribL1d)(index, x)
         #[cfg_attr(feature = "inline", inline)]
@@ -36156,8 +40214,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttribL1d({:?}, {:?});", index, x);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttribL1d", &self.glVertexAttribL1d_p, index, x);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttribL1d",
+                self.lazy(&self.glVertexAttribL1d_p, b"glVertexAttribL1d\0"),
+                index,
+                x,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttribL1d");
@@ -36178,7 +40240,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribL1d_is_loaded(&self) -> bool {
-            !self.glVertexAttribL1d_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribL1d_p, b"glVertexAttribL1d\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribL1dv](http://docs.gl/gl4/glVertexAttribL1dv)(index, v)
         /// * `v` len: 1
@@ -36189,8 +40254,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttribL1dv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttribL1dv", &self.glVertexAttribL1dv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttribL1dv",
+                self.lazy(&self.glVertexAttribL1dv_p, b"glVertexAttribL1dv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttribL1dv");
@@ -36211,7 +40280,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribL1dv_is_loaded(&self) -> bool {
-            !self.glVertexAttribL1dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribL1dv_p, b"glVertexAttribL1dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribL2d](http://docs.gl/gl4/glVertexAttribL2d)(index, x, y)
         #[cfg_attr(feature = "inline", inline)]
@@ -36221,8 +40293,13 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttribL2d({:?}, {:?}, {:?});", index, x, y);
             }
-            let out =
-                call_atomic_ptr_3arg("glVertexAttribL2d", &self.glVertexAttribL2d_p, index, x, y);
+            let out = call_atomic_ptr_3arg(
+                "glVertexAttribL2d",
+                self.lazy(&self.glVertexAttribL2d_p, b"glVertexAttribL2d\0"),
+                index,
+                x,
+                y,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttribL2d");
@@ -36243,7 +40320,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribL2d_is_loaded(&self) -> bool {
-            !self.glVertexAttribL2d_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribL2d_p, b"glVertexAttribL2d\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribL2dv](http://docs.gl/gl4/glVertexAttribL2dv)(index, v)
         /// * `v` len: 2
@@ -36254,8 +40334,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttribL2dv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttribL2dv", &self.glVertexAttribL2dv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttribL2dv",
+                self.lazy(&self.glVertexAttribL2dv_p, b"glVertexAttribL2dv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttribL2dv");
@@ -36276,7 +40360,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribL2dv_is_loaded(&self) -> bool {
-            !self.glVertexAttribL2dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribL2dv_p, b"glVertexAttribL2dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribL3d](http://docs.gl/gl4/glVertexAttribL3d)(index, x, y, z)
         #[cfg_attr(feature = "inline", inline)]
@@ -36294,7 +40381,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glVertexAttribL3d",
-                &self.glVertexAttribL3d_p,
+                self.lazy(&self.glVertexAttribL3d_p, b"glVertexAttribL3d\0"),
                 index,
                 x,
                 y,
@@ -36320,7 +40407,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribL3d_is_loaded(&self) -> bool {
-            !self.glVertexAttribL3d_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribL3d_p, b"glVertexAttribL3d\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribL3dv](http://docs.gl/gl4/glVertexAttribL3dv)(index, v)
         /// * `v` len: 3
@@ -36331,8 +40421,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttribL3dv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttribL3dv", &self.glVertexAttribL3dv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttribL3dv",
+                self.lazy(&self.glVertexAttribL3dv_p, b"glVertexAttribL3dv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttribL3dv");
@@ -36353,7 +40447,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribL3dv_is_loaded(&self) -> bool {
-            !self.glVertexAttribL3dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribL3dv_p, b"glVertexAttribL3dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribL4d](http://docs.gl/gl4/glVertexAttribL4d)(index, x, y, z, w)
         #[cfg_attr(feature = "inline", inline)]
@@ -36379,7 +40476,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glVertexAttribL4d",
-                &self.glVertexAttribL4d_p,
+                self.lazy(&self.glVertexAttribL4d_p, b"glVertexAttribL4d\0"),
                 index,
                 x,
                 y,
@@ -36406,7 +40503,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribL4d_is_loaded(&self) -> bool {
-            !self.glVertexAttribL4d_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribL4d_p, b"glVertexAttribL4d\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribL4dv](http://docs.gl/gl4/glVertexAttribL4dv)(index, v)
         /// * `v` len: 4
@@ -36417,8 +40517,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.VertexAttribL4dv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glVertexAttribL4dv", &self.glVertexAttribL4dv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glVertexAttribL4dv",
+                self.lazy(&self.glVertexAttribL4dv_p, b"glVertexAttribL4dv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glVertexAttribL4dv");
@@ -36439,7 +40543,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribL4dv_is_loaded(&self) -> bool {
-            !self.glVertexAttribL4dv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribL4dv_p, b"glVertexAttribL4dv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribLFormat](http://docs.gl/gl4/glVertexAttribLFormat)(attribindex, size, type_, relativeoffset)
         /// * `type_` group: VertexAttribLType
@@ -36464,7 +40571,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glVertexAttribLFormat",
-                &self.glVertexAttribLFormat_p,
+                self.lazy(&self.glVertexAttribLFormat_p, b"glVertexAttribLFormat\0"),
                 attribindex,
                 size,
                 type_,
@@ -36490,7 +40597,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribLFormat_is_loaded(&self) -> bool {
-            !self.glVertexAttribLFormat_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribLFormat_p, b"glVertexAttribLFormat\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribLPointer](http://docs.gl/gl4/glVertexAttribLPointer)(index, size, type_, stride, pointer)
         /// * `type_` group: VertexAttribLType
@@ -36518,7 +40628,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glVertexAttribLPointer",
-                &self.glVertexAttribLPointer_p,
+                self.lazy(&self.glVertexAttribLPointer_p, b"glVertexAttribLPointer\0"),
                 index,
                 size,
                 type_,
@@ -36545,7 +40655,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribLPointer_is_loaded(&self) -> bool {
-            !self.glVertexAttribLPointer_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribLPointer_p, b"glVertexAttribLPointer\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribP1ui](http://docs.gl/gl4/glVertexAttribP)(index, type_, normalized, value)
         /// * `type_` group: VertexAttribPointerType
@@ -36570,7 +40683,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glVertexAttribP1ui",
-                &self.glVertexAttribP1ui_p,
+                self.lazy(&self.glVertexAttribP1ui_p, b"glVertexAttribP1ui\0"),
                 index,
                 type_,
                 normalized,
@@ -36596,7 +40709,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribP1ui_is_loaded(&self) -> bool {
-            !self.glVertexAttribP1ui_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribP1ui_p, b"glVertexAttribP1ui\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribP1uiv](http://docs.gl/gl4/glVertexAttribP)(index, type_, normalized, value)
         /// * `type_` group: VertexAttribPointerType
@@ -36622,7 +40738,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glVertexAttribP1uiv",
-                &self.glVertexAttribP1uiv_p,
+                self.lazy(&self.glVertexAttribP1uiv_p, b"glVertexAttribP1uiv\0"),
                 index,
                 type_,
                 normalized,
@@ -36648,7 +40764,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribP1uiv_is_loaded(&self) -> bool {
-            !self.glVertexAttribP1uiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribP1uiv_p, b"glVertexAttribP1uiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribP2ui](http://docs.gl/gl4/glVertexAttribP)(index, type_, normalized, value)
         /// * `type_` group: VertexAttribPointerType
@@ -36673,7 +40792,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glVertexAttribP2ui",
-                &self.glVertexAttribP2ui_p,
+                self.lazy(&self.glVertexAttribP2ui_p, b"glVertexAttribP2ui\0"),
                 index,
                 type_,
                 normalized,
@@ -36699,7 +40818,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribP2ui_is_loaded(&self) -> bool {
-            !self.glVertexAttribP2ui_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribP2ui_p, b"glVertexAttribP2ui\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribP2uiv](http://docs.gl/gl4/glVertexAttribP)(index, type_, normalized, value)
         /// * `type_` group: VertexAttribPointerType
@@ -36725,7 +40847,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glVertexAttribP2uiv",
-                &self.glVertexAttribP2uiv_p,
+                self.lazy(&self.glVertexAttribP2uiv_p, b"glVertexAttribP2uiv\0"),
                 index,
                 type_,
                 normalized,
@@ -36751,7 +40873,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribP2uiv_is_loaded(&self) -> bool {
-            !self.glVertexAttribP2uiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribP2uiv_p, b"glVertexAttribP2uiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribP3ui](http://docs.gl/gl4/glVertexAttribP)(index, type_, normalized, value)
         /// * `type_` group: VertexAttribPointerType
@@ -36776,7 +40901,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glVertexAttribP3ui",
-                &self.glVertexAttribP3ui_p,
+                self.lazy(&self.glVertexAttribP3ui_p, b"glVertexAttribP3ui\0"),
                 index,
                 type_,
                 normalized,
@@ -36802,7 +40927,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribP3ui_is_loaded(&self) -> bool {
-            !self.glVertexAttribP3ui_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribP3ui_p, b"glVertexAttribP3ui\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribP3uiv](http://docs.gl/gl4/glVertexAttribP)(index, type_, normalized, value)
         /// * `type_` group: VertexAttribPointerType
@@ -36828,7 +40956,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glVertexAttribP3uiv",
-                &self.glVertexAttribP3uiv_p,
+                self.lazy(&self.glVertexAttribP3uiv_p, b"glVertexAttribP3uiv\0"),
                 index,
                 type_,
                 normalized,
@@ -36854,7 +40982,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribP3uiv_is_loaded(&self) -> bool {
-            !self.glVertexAttribP3uiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribP3uiv_p, b"glVertexAttribP3uiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribP4ui](http://docs.gl/gl4/glVertexAttribP)(index, type_, normalized, value)
         /// * `type_` group: VertexAttribPointerType
@@ -36879,7 +41010,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glVertexAttribP4ui",
-                &self.glVertexAttribP4ui_p,
+                self.lazy(&self.glVertexAttribP4ui_p, b"glVertexAttribP4ui\0"),
                 index,
                 type_,
                 normalized,
@@ -36905,7 +41036,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribP4ui_is_loaded(&self) -> bool {
-            !self.glVertexAttribP4ui_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribP4ui_p, b"glVertexAttribP4ui\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribP4uiv](http://docs.gl/gl4/glVertexAttribP)(index, type_, normalized, value)
         /// * `type_` group: VertexAttribPointerType
@@ -36931,7 +41065,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_4arg(
                 "glVertexAttribP4uiv",
-                &self.glVertexAttribP4uiv_p,
+                self.lazy(&self.glVertexAttribP4uiv_p, b"glVertexAttribP4uiv\0"),
                 index,
                 type_,
                 normalized,
@@ -36957,7 +41091,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribP4uiv_is_loaded(&self) -> bool {
-            !self.glVertexAttribP4uiv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribP4uiv_p, b"glVertexAttribP4uiv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexAttribPointer](http://docs.gl/gl4/glVertexAttribPointer)(index, size, type_, normalized, stride, pointer)
         /// * `type_` group: VertexAttribPointerType
@@ -36987,7 +41124,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_6arg(
                 "glVertexAttribPointer",
-                &self.glVertexAttribPointer_p,
+                self.lazy(&self.glVertexAttribPointer_p, b"glVertexAttribPointer\0"),
                 index,
                 size,
                 type_,
@@ -37015,7 +41152,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexAttribPointer_is_loaded(&self) -> bool {
-            !self.glVertexAttribPointer_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexAttribPointer_p, b"glVertexAttribPointer\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glVertexBindingDivisor](http://docs.gl/gl4/glVertexBindingDivisor)(bindingindex, divisor)
         #[cfg_attr(feature = "inline", inline)]
@@ -37031,7 +41171,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_2arg(
                 "glVertexBindingDivisor",
-                &self.glVertexBindingDivisor_p,
+                self.lazy(&self.glVertexBindingDivisor_p, b"glVertexBindingDivisor\0"),
                 bindingindex,
                 divisor,
             );
@@ -37055,7 +41195,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn VertexBindingDivisor_is_loaded(&self) -> bool {
-            !self.glVertexBindingDivisor_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glVertexBindingDivisor_p, b"glVertexBindingDivisor\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glViewport](http://docs.gl/gl4/glViewport)(x, y, width, height)
         /// * `x` group: WinCoord
@@ -37073,7 +41216,14 @@ pub mod struct_commands {
                     height
                 );
             }
-            let out = call_atomic_ptr_4arg("glViewport", &self.glViewport_p, x, y, width, height);
+            let out = call_atomic_ptr_4arg(
+                "glViewport",
+                self.lazy(&self.glViewport_p, b"glViewport\0"),
+                x,
+                y,
+                width,
+                height,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glViewport");
@@ -37090,7 +41240,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn Viewport_is_loaded(&self) -> bool {
-            !self.glViewport_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glViewport_p, b"glViewport\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glViewportArrayv](http://docs.gl/gl4/glViewportArrayv)(first, count, v)
         /// * `v` len: COMPSIZE(count)
@@ -37108,7 +41261,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_3arg(
                 "glViewportArrayv",
-                &self.glViewportArrayv_p,
+                self.lazy(&self.glViewportArrayv_p, b"glViewportArrayv\0"),
                 first,
                 count,
                 v,
@@ -37133,7 +41286,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ViewportArrayv_is_loaded(&self) -> bool {
-            !self.glViewportArrayv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glViewportArrayv_p, b"glViewportArrayv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glViewportIndexedf](http://docs.gl/gl4/glViewportIndexed)(index, x, y, w, h)
         #[cfg_attr(feature = "inline", inline)]
@@ -37159,7 +41315,7 @@ pub mod struct_commands {
             }
             let out = call_atomic_ptr_5arg(
                 "glViewportIndexedf",
-                &self.glViewportIndexedf_p,
+                self.lazy(&self.glViewportIndexedf_p, b"glViewportIndexedf\0"),
                 index,
                 x,
                 y,
@@ -37186,7 +41342,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ViewportIndexedf_is_loaded(&self) -> bool {
-            !self.glViewportIndexedf_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glViewportIndexedf_p, b"glViewportIndexedf\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glViewportIndexedfv](http://docs.gl/gl4/glViewportIndexed)(index, v)
         /// * `v` len: 4
@@ -37197,8 +41356,12 @@ pub mod struct_commands {
             {
                 trace!("calling gl.ViewportIndexedfv({:?}, {:p});", index, v);
             }
-            let out =
-                call_atomic_ptr_2arg("glViewportIndexedfv", &self.glViewportIndexedfv_p, index, v);
+            let out = call_atomic_ptr_2arg(
+                "glViewportIndexedfv",
+                self.lazy(&self.glViewportIndexedfv_p, b"glViewportIndexedfv\0"),
+                index,
+                v,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glViewportIndexedfv");
@@ -37219,7 +41382,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn ViewportIndexedfv_is_loaded(&self) -> bool {
-            !self.glViewportIndexedfv_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glViewportIndexedfv_p, b"glViewportIndexedfv\0")
+                .load(RELAX)
+                .is_null()
         }
         /// [glWaitSync](http://docs.gl/gl4/glWaitSync)(sync, flags, timeout)
         /// * `sync` group: sync
@@ -37236,7 +41402,13 @@ pub mod struct_commands {
                     timeout
                 );
             }
-            let out = call_atomic_ptr_3arg("glWaitSync", &self.glWaitSync_p, sync, flags, timeout);
+            let out = call_atomic_ptr_3arg(
+                "glWaitSync",
+                self.lazy(&self.glWaitSync_p, b"glWaitSync\0"),
+                sync,
+                flags,
+                timeout,
+            );
             #[cfg(all(debug_assertions, feature = "debug_automatic_glGetError"))]
             {
                 self.automatic_glGetError("glWaitSync");
@@ -37253,7 +41425,10 @@ pub mod struct_commands {
         #[inline]
         #[doc(hidden)]
         pub fn WaitSync_is_loaded(&self) -> bool {
-            !self.glWaitSync_p.load(RELAX).is_null()
+            !self
+                .lazy(&self.glWaitSync_p, b"glWaitSync\0")
+                .load(RELAX)
+                .is_null()
         }
     }
     /// This holds the many, many function pointers for GL.
@@ -37261,6 +41436,8 @@ pub mod struct_commands {
     /// It's typically quite large (hundreds of pointers), depending on what API level and extensions you selected during the generation.
     #[repr(C)]
     pub struct GlFns {
+        /// A `Box<LazyLoader>` if the pointers are loaded on first use, otherwise null.
+        lazy_loader_p: APcv,
         glActiveShaderProgram_p: APcv,
         glActiveTexture_p: APcv,
         glAttachShader_p: APcv,
@@ -37986,5 +42163,18 @@ pub mod struct_commands {
             write!(f, "GlFns")
         }
     }
+    type LazyLoader = Box<dyn Fn(*const c_char) -> *mut c_void + Send + Sync>;
+    /// Stored by the lazy loader in place of a function pointer that couldn't be loaded.
+    const LAZY_MISSING: usize = 1;
+    static LAZY_NOT_LOADED: APcv = ap_null();
+    impl Drop for GlFns {
+        fn drop(&mut self) {
+            let loader = self.lazy_loader_p.load(RELAX) as *mut LazyLoader;
+            if !loader.is_null() {
+                // Safety: this was created by `Box::into_raw` in `load_lazily_with`.
+                drop(unsafe { Box::from_raw(loader) });
+            }
+        }
+    }
 }
 // end of module
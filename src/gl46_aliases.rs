@@ -0,0 +1,775 @@
+//! Promoted-extension aliases for the core commands loaded by `gl46`.
+//!
+//! Generated from `gl.xml` by `generate-native.sh`, do not edit by hand.
+
+/// Core command names, sorted, together with their suffixed aliases in the order they are tried.
+pub(crate) static ALIASES: &[(&[u8], &[&[u8]])] = &[
+    (b"glActiveTexture", &[b"glActiveTextureARB\0"]),
+    (
+        b"glBeginConditionalRender",
+        &[b"glBeginConditionalRenderNV\0"],
+    ),
+    (
+        b"glBeginQuery",
+        &[b"glBeginQueryARB\0", b"glBeginQueryEXT\0"],
+    ),
+    (
+        b"glBeginTransformFeedback",
+        &[
+            b"glBeginTransformFeedbackEXT\0",
+            b"glBeginTransformFeedbackNV\0",
+        ],
+    ),
+    (b"glBindAttribLocation", &[b"glBindAttribLocationARB\0"]),
+    (b"glBindBuffer", &[b"glBindBufferARB\0"]),
+    (
+        b"glBindBufferBase",
+        &[b"glBindBufferBaseEXT\0", b"glBindBufferBaseNV\0"],
+    ),
+    (
+        b"glBindBufferRange",
+        &[b"glBindBufferRangeEXT\0", b"glBindBufferRangeNV\0"],
+    ),
+    (b"glBindFragDataLocation", &[b"glBindFragDataLocationEXT\0"]),
+    (
+        b"glBindFragDataLocationIndexed",
+        &[b"glBindFragDataLocationIndexedEXT\0"],
+    ),
+    (b"glBindTexture", &[b"glBindTextureEXT\0"]),
+    (
+        b"glBindVertexArray",
+        &[b"glBindVertexArrayOES\0", b"glBindVertexArrayAPPLE\0"],
+    ),
+    (
+        b"glBlendBarrier",
+        &[b"glBlendBarrierKHR\0", b"glBlendBarrierNV\0"],
+    ),
+    (b"glBlendColor", &[b"glBlendColorEXT\0"]),
+    (b"glBlendEquation", &[b"glBlendEquationEXT\0"]),
+    (
+        b"glBlendEquationSeparate",
+        &[b"glBlendEquationSeparateEXT\0"],
+    ),
+    (
+        b"glBlendEquationSeparatei",
+        &[
+            b"glBlendEquationSeparateiARB\0",
+            b"glBlendEquationSeparateiEXT\0",
+            b"glBlendEquationSeparateiOES\0",
+        ],
+    ),
+    (
+        b"glBlendEquationi",
+        &[
+            b"glBlendEquationiARB\0",
+            b"glBlendEquationiEXT\0",
+            b"glBlendEquationiOES\0",
+        ],
+    ),
+    (b"glBlendFuncSeparate", &[b"glBlendFuncSeparateEXT\0"]),
+    (
+        b"glBlendFuncSeparatei",
+        &[
+            b"glBlendFuncSeparateiARB\0",
+            b"glBlendFuncSeparateiEXT\0",
+            b"glBlendFuncSeparateiOES\0",
+        ],
+    ),
+    (
+        b"glBlendFunci",
+        &[
+            b"glBlendFunciARB\0",
+            b"glBlendFunciEXT\0",
+            b"glBlendFunciOES\0",
+        ],
+    ),
+    (
+        b"glBlitFramebuffer",
+        &[b"glBlitFramebufferEXT\0", b"glBlitFramebufferNV\0"],
+    ),
+    (b"glBufferData", &[b"glBufferDataARB\0"]),
+    (b"glBufferStorage", &[b"glBufferStorageEXT\0"]),
+    (b"glBufferSubData", &[b"glBufferSubDataARB\0"]),
+    (
+        b"glCheckFramebufferStatus",
+        &[b"glCheckFramebufferStatusEXT\0"],
+    ),
+    (b"glClampColor", &[b"glClampColorARB\0"]),
+    (b"glClearDepthf", &[b"glClearDepthfOES\0"]),
+    (b"glClearTexImage", &[b"glClearTexImageEXT\0"]),
+    (b"glClearTexSubImage", &[b"glClearTexSubImageEXT\0"]),
+    (b"glClientWaitSync", &[b"glClientWaitSyncAPPLE\0"]),
+    (b"glClipControl", &[b"glClipControlEXT\0"]),
+    (
+        b"glColorMaski",
+        &[b"glColorMaskiEXT\0", b"glColorMaskiOES\0"],
+    ),
+    (b"glCompileShader", &[b"glCompileShaderARB\0"]),
+    (b"glCompressedTexImage1D", &[b"glCompressedTexImage1DARB\0"]),
+    (b"glCompressedTexImage2D", &[b"glCompressedTexImage2DARB\0"]),
+    (b"glCompressedTexImage3D", &[b"glCompressedTexImage3DARB\0"]),
+    (
+        b"glCompressedTexSubImage1D",
+        &[b"glCompressedTexSubImage1DARB\0"],
+    ),
+    (
+        b"glCompressedTexSubImage2D",
+        &[b"glCompressedTexSubImage2DARB\0"],
+    ),
+    (
+        b"glCompressedTexSubImage3D",
+        &[b"glCompressedTexSubImage3DARB\0"],
+    ),
+    (b"glCopyBufferSubData", &[b"glCopyBufferSubDataNV\0"]),
+    (
+        b"glCopyImageSubData",
+        &[b"glCopyImageSubDataEXT\0", b"glCopyImageSubDataOES\0"],
+    ),
+    (b"glCopyTexImage1D", &[b"glCopyTexImage1DEXT\0"]),
+    (b"glCopyTexImage2D", &[b"glCopyTexImage2DEXT\0"]),
+    (b"glCopyTexSubImage1D", &[b"glCopyTexSubImage1DEXT\0"]),
+    (b"glCopyTexSubImage2D", &[b"glCopyTexSubImage2DEXT\0"]),
+    (b"glCopyTexSubImage3D", &[b"glCopyTexSubImage3DEXT\0"]),
+    (
+        b"glDebugMessageCallback",
+        &[
+            b"glDebugMessageCallbackARB\0",
+            b"glDebugMessageCallbackKHR\0",
+        ],
+    ),
+    (
+        b"glDebugMessageControl",
+        &[b"glDebugMessageControlARB\0", b"glDebugMessageControlKHR\0"],
+    ),
+    (
+        b"glDebugMessageInsert",
+        &[b"glDebugMessageInsertARB\0", b"glDebugMessageInsertKHR\0"],
+    ),
+    (b"glDeleteBuffers", &[b"glDeleteBuffersARB\0"]),
+    (b"glDeleteFramebuffers", &[b"glDeleteFramebuffersEXT\0"]),
+    (
+        b"glDeleteQueries",
+        &[b"glDeleteQueriesARB\0", b"glDeleteQueriesEXT\0"],
+    ),
+    (b"glDeleteRenderbuffers", &[b"glDeleteRenderbuffersEXT\0"]),
+    (b"glDeleteSync", &[b"glDeleteSyncAPPLE\0"]),
+    (
+        b"glDeleteTransformFeedbacks",
+        &[b"glDeleteTransformFeedbacksNV\0"],
+    ),
+    (
+        b"glDeleteVertexArrays",
+        &[b"glDeleteVertexArraysOES\0", b"glDeleteVertexArraysAPPLE\0"],
+    ),
+    (b"glDepthRangef", &[b"glDepthRangefOES\0"]),
+    (
+        b"glDisableVertexAttribArray",
+        &[b"glDisableVertexAttribArrayARB\0"],
+    ),
+    (
+        b"glDisablei",
+        &[b"glDisableiEXT\0", b"glDisableiOES\0", b"glDisableiNV\0"],
+    ),
+    (b"glDrawArrays", &[b"glDrawArraysEXT\0"]),
+    (
+        b"glDrawArraysInstanced",
+        &[
+            b"glDrawArraysInstancedARB\0",
+            b"glDrawArraysInstancedEXT\0",
+            b"glDrawArraysInstancedANGLE\0",
+            b"glDrawArraysInstancedNV\0",
+        ],
+    ),
+    (
+        b"glDrawArraysInstancedBaseInstance",
+        &[b"glDrawArraysInstancedBaseInstanceEXT\0"],
+    ),
+    (
+        b"glDrawBuffers",
+        &[b"glDrawBuffersARB\0", b"glDrawBuffersEXT\0"],
+    ),
+    (
+        b"glDrawElementsBaseVertex",
+        &[
+            b"glDrawElementsBaseVertexEXT\0",
+            b"glDrawElementsBaseVertexOES\0",
+        ],
+    ),
+    (
+        b"glDrawElementsInstanced",
+        &[
+            b"glDrawElementsInstancedARB\0",
+            b"glDrawElementsInstancedEXT\0",
+            b"glDrawElementsInstancedANGLE\0",
+            b"glDrawElementsInstancedNV\0",
+        ],
+    ),
+    (
+        b"glDrawElementsInstancedBaseInstance",
+        &[b"glDrawElementsInstancedBaseInstanceEXT\0"],
+    ),
+    (
+        b"glDrawElementsInstancedBaseVertex",
+        &[
+            b"glDrawElementsInstancedBaseVertexEXT\0",
+            b"glDrawElementsInstancedBaseVertexOES\0",
+        ],
+    ),
+    (
+        b"glDrawElementsInstancedBaseVertexBaseInstance",
+        &[b"glDrawElementsInstancedBaseVertexBaseInstanceEXT\0"],
+    ),
+    (b"glDrawRangeElements", &[b"glDrawRangeElementsEXT\0"]),
+    (
+        b"glDrawRangeElementsBaseVertex",
+        &[
+            b"glDrawRangeElementsBaseVertexEXT\0",
+            b"glDrawRangeElementsBaseVertexOES\0",
+        ],
+    ),
+    (
+        b"glDrawTransformFeedback",
+        &[
+            b"glDrawTransformFeedbackEXT\0",
+            b"glDrawTransformFeedbackNV\0",
+        ],
+    ),
+    (
+        b"glDrawTransformFeedbackInstanced",
+        &[b"glDrawTransformFeedbackInstancedEXT\0"],
+    ),
+    (
+        b"glEnableVertexAttribArray",
+        &[b"glEnableVertexAttribArrayARB\0"],
+    ),
+    (
+        b"glEnablei",
+        &[b"glEnableiEXT\0", b"glEnableiOES\0", b"glEnableiNV\0"],
+    ),
+    (b"glEndConditionalRender", &[b"glEndConditionalRenderNV\0"]),
+    (b"glEndQuery", &[b"glEndQueryARB\0", b"glEndQueryEXT\0"]),
+    (
+        b"glEndTransformFeedback",
+        &[
+            b"glEndTransformFeedbackEXT\0",
+            b"glEndTransformFeedbackNV\0",
+        ],
+    ),
+    (b"glFenceSync", &[b"glFenceSyncAPPLE\0"]),
+    (
+        b"glFlushMappedBufferRange",
+        &[
+            b"glFlushMappedBufferRangeEXT\0",
+            b"glFlushMappedBufferRangeAPPLE\0",
+        ],
+    ),
+    (
+        b"glFramebufferRenderbuffer",
+        &[b"glFramebufferRenderbufferEXT\0"],
+    ),
+    (
+        b"glFramebufferTexture",
+        &[
+            b"glFramebufferTextureARB\0",
+            b"glFramebufferTextureEXT\0",
+            b"glFramebufferTextureOES\0",
+        ],
+    ),
+    (b"glFramebufferTexture1D", &[b"glFramebufferTexture1DEXT\0"]),
+    (b"glFramebufferTexture2D", &[b"glFramebufferTexture2DEXT\0"]),
+    (b"glFramebufferTexture3D", &[b"glFramebufferTexture3DEXT\0"]),
+    (
+        b"glFramebufferTextureLayer",
+        &[
+            b"glFramebufferTextureLayerARB\0",
+            b"glFramebufferTextureLayerEXT\0",
+        ],
+    ),
+    (b"glGenBuffers", &[b"glGenBuffersARB\0"]),
+    (b"glGenFramebuffers", &[b"glGenFramebuffersEXT\0"]),
+    (
+        b"glGenQueries",
+        &[b"glGenQueriesARB\0", b"glGenQueriesEXT\0"],
+    ),
+    (b"glGenRenderbuffers", &[b"glGenRenderbuffersEXT\0"]),
+    (
+        b"glGenTransformFeedbacks",
+        &[b"glGenTransformFeedbacksNV\0"],
+    ),
+    (
+        b"glGenVertexArrays",
+        &[b"glGenVertexArraysOES\0", b"glGenVertexArraysAPPLE\0"],
+    ),
+    (b"glGenerateMipmap", &[b"glGenerateMipmapEXT\0"]),
+    (b"glGetActiveAttrib", &[b"glGetActiveAttribARB\0"]),
+    (b"glGetActiveUniform", &[b"glGetActiveUniformARB\0"]),
+    (b"glGetAttribLocation", &[b"glGetAttribLocationARB\0"]),
+    (b"glGetBufferParameteriv", &[b"glGetBufferParameterivARB\0"]),
+    (
+        b"glGetBufferPointerv",
+        &[b"glGetBufferPointervARB\0", b"glGetBufferPointervOES\0"],
+    ),
+    (b"glGetBufferSubData", &[b"glGetBufferSubDataARB\0"]),
+    (
+        b"glGetCompressedTexImage",
+        &[b"glGetCompressedTexImageARB\0"],
+    ),
+    (
+        b"glGetDebugMessageLog",
+        &[b"glGetDebugMessageLogARB\0", b"glGetDebugMessageLogKHR\0"],
+    ),
+    (b"glGetDoublei_v", &[b"glGetDoublei_vEXT\0"]),
+    (
+        b"glGetFloati_v",
+        &[
+            b"glGetFloati_vEXT\0",
+            b"glGetFloati_vOES\0",
+            b"glGetFloati_vNV\0",
+        ],
+    ),
+    (b"glGetFragDataIndex", &[b"glGetFragDataIndexEXT\0"]),
+    (b"glGetFragDataLocation", &[b"glGetFragDataLocationEXT\0"]),
+    (
+        b"glGetFramebufferAttachmentParameteriv",
+        &[b"glGetFramebufferAttachmentParameterivEXT\0"],
+    ),
+    (
+        b"glGetGraphicsResetStatus",
+        &[
+            b"glGetGraphicsResetStatusEXT\0",
+            b"glGetGraphicsResetStatusKHR\0",
+        ],
+    ),
+    (b"glGetInteger64v", &[b"glGetInteger64vAPPLE\0"]),
+    (b"glGetMultisamplefv", &[b"glGetMultisamplefvNV\0"]),
+    (b"glGetObjectLabel", &[b"glGetObjectLabelKHR\0"]),
+    (b"glGetObjectPtrLabel", &[b"glGetObjectPtrLabelKHR\0"]),
+    (
+        b"glGetPointerv",
+        &[b"glGetPointervEXT\0", b"glGetPointervKHR\0"],
+    ),
+    (b"glGetProgramBinary", &[b"glGetProgramBinaryOES\0"]),
+    (b"glGetQueryObjecti64v", &[b"glGetQueryObjecti64vEXT\0"]),
+    (
+        b"glGetQueryObjectiv",
+        &[b"glGetQueryObjectivARB\0", b"glGetQueryObjectivEXT\0"],
+    ),
+    (b"glGetQueryObjectui64v", &[b"glGetQueryObjectui64vEXT\0"]),
+    (
+        b"glGetQueryObjectuiv",
+        &[b"glGetQueryObjectuivARB\0", b"glGetQueryObjectuivEXT\0"],
+    ),
+    (b"glGetQueryiv", &[b"glGetQueryivARB\0"]),
+    (
+        b"glGetRenderbufferParameteriv",
+        &[b"glGetRenderbufferParameterivEXT\0"],
+    ),
+    (
+        b"glGetSamplerParameterIiv",
+        &[
+            b"glGetSamplerParameterIivEXT\0",
+            b"glGetSamplerParameterIivOES\0",
+        ],
+    ),
+    (
+        b"glGetSamplerParameterIuiv",
+        &[
+            b"glGetSamplerParameterIuivEXT\0",
+            b"glGetSamplerParameterIuivOES\0",
+        ],
+    ),
+    (b"glGetShaderSource", &[b"glGetShaderSourceARB\0"]),
+    (b"glGetSynciv", &[b"glGetSyncivAPPLE\0"]),
+    (
+        b"glGetTexParameterIiv",
+        &[b"glGetTexParameterIivEXT\0", b"glGetTexParameterIivOES\0"],
+    ),
+    (
+        b"glGetTexParameterIuiv",
+        &[b"glGetTexParameterIuivEXT\0", b"glGetTexParameterIuivOES\0"],
+    ),
+    (
+        b"glGetTransformFeedbackVarying",
+        &[b"glGetTransformFeedbackVaryingEXT\0"],
+    ),
+    (b"glGetUniformLocation", &[b"glGetUniformLocationARB\0"]),
+    (b"glGetUniformfv", &[b"glGetUniformfvARB\0"]),
+    (b"glGetUniformiv", &[b"glGetUniformivARB\0"]),
+    (b"glGetUniformuiv", &[b"glGetUniformuivEXT\0"]),
+    (b"glGetVertexAttribIiv", &[b"glGetVertexAttribIivEXT\0"]),
+    (b"glGetVertexAttribIuiv", &[b"glGetVertexAttribIuivEXT\0"]),
+    (b"glGetVertexAttribLdv", &[b"glGetVertexAttribLdvEXT\0"]),
+    (
+        b"glGetVertexAttribPointerv",
+        &[b"glGetVertexAttribPointervARB\0"],
+    ),
+    (b"glGetVertexAttribdv", &[b"glGetVertexAttribdvARB\0"]),
+    (b"glGetVertexAttribfv", &[b"glGetVertexAttribfvARB\0"]),
+    (b"glGetVertexAttribiv", &[b"glGetVertexAttribivARB\0"]),
+    (
+        b"glGetnUniformfv",
+        &[b"glGetnUniformfvEXT\0", b"glGetnUniformfvKHR\0"],
+    ),
+    (
+        b"glGetnUniformiv",
+        &[b"glGetnUniformivEXT\0", b"glGetnUniformivKHR\0"],
+    ),
+    (b"glGetnUniformuiv", &[b"glGetnUniformuivKHR\0"]),
+    (b"glIsBuffer", &[b"glIsBufferARB\0"]),
+    (
+        b"glIsEnabledi",
+        &[
+            b"glIsEnablediEXT\0",
+            b"glIsEnablediOES\0",
+            b"glIsEnablediNV\0",
+        ],
+    ),
+    (b"glIsFramebuffer", &[b"glIsFramebufferEXT\0"]),
+    (b"glIsQuery", &[b"glIsQueryARB\0", b"glIsQueryEXT\0"]),
+    (b"glIsRenderbuffer", &[b"glIsRenderbufferEXT\0"]),
+    (b"glIsSync", &[b"glIsSyncAPPLE\0"]),
+    (b"glIsTransformFeedback", &[b"glIsTransformFeedbackNV\0"]),
+    (
+        b"glIsVertexArray",
+        &[b"glIsVertexArrayOES\0", b"glIsVertexArrayAPPLE\0"],
+    ),
+    (b"glLinkProgram", &[b"glLinkProgramARB\0"]),
+    (b"glMapBuffer", &[b"glMapBufferARB\0", b"glMapBufferOES\0"]),
+    (b"glMapBufferRange", &[b"glMapBufferRangeEXT\0"]),
+    (b"glMemoryBarrier", &[b"glMemoryBarrierEXT\0"]),
+    (
+        b"glMinSampleShading",
+        &[b"glMinSampleShadingARB\0", b"glMinSampleShadingOES\0"],
+    ),
+    (b"glMultiDrawArrays", &[b"glMultiDrawArraysEXT\0"]),
+    (
+        b"glMultiDrawArraysIndirect",
+        &[b"glMultiDrawArraysIndirectEXT\0"],
+    ),
+    (
+        b"glMultiDrawArraysIndirectCount",
+        &[b"glMultiDrawArraysIndirectCountARB\0"],
+    ),
+    (b"glMultiDrawElements", &[b"glMultiDrawElementsEXT\0"]),
+    (
+        b"glMultiDrawElementsBaseVertex",
+        &[b"glMultiDrawElementsBaseVertexEXT\0"],
+    ),
+    (
+        b"glMultiDrawElementsIndirect",
+        &[b"glMultiDrawElementsIndirectEXT\0"],
+    ),
+    (
+        b"glMultiDrawElementsIndirectCount",
+        &[b"glMultiDrawElementsIndirectCountARB\0"],
+    ),
+    (b"glNamedBufferStorage", &[b"glNamedBufferStorageEXT\0"]),
+    (b"glNamedBufferSubData", &[b"glNamedBufferSubDataEXT\0"]),
+    (b"glObjectLabel", &[b"glObjectLabelKHR\0"]),
+    (b"glObjectPtrLabel", &[b"glObjectPtrLabelKHR\0"]),
+    (
+        b"glPatchParameteri",
+        &[b"glPatchParameteriEXT\0", b"glPatchParameteriOES\0"],
+    ),
+    (
+        b"glPauseTransformFeedback",
+        &[b"glPauseTransformFeedbackNV\0"],
+    ),
+    (
+        b"glPointParameterf",
+        &[b"glPointParameterfARB\0", b"glPointParameterfEXT\0"],
+    ),
+    (
+        b"glPointParameterfv",
+        &[b"glPointParameterfvARB\0", b"glPointParameterfvEXT\0"],
+    ),
+    (b"glPointParameteri", &[b"glPointParameteriNV\0"]),
+    (b"glPointParameteriv", &[b"glPointParameterivNV\0"]),
+    (b"glPolygonMode", &[b"glPolygonModeNV\0"]),
+    (b"glPolygonOffsetClamp", &[b"glPolygonOffsetClampEXT\0"]),
+    (b"glPopDebugGroup", &[b"glPopDebugGroupKHR\0"]),
+    (
+        b"glPrimitiveBoundingBox",
+        &[
+            b"glPrimitiveBoundingBoxARB\0",
+            b"glPrimitiveBoundingBoxEXT\0",
+            b"glPrimitiveBoundingBoxOES\0",
+        ],
+    ),
+    (b"glProgramBinary", &[b"glProgramBinaryOES\0"]),
+    (b"glProgramUniform1f", &[b"glProgramUniform1fEXT\0"]),
+    (b"glProgramUniform1fv", &[b"glProgramUniform1fvEXT\0"]),
+    (b"glProgramUniform1i", &[b"glProgramUniform1iEXT\0"]),
+    (b"glProgramUniform1iv", &[b"glProgramUniform1ivEXT\0"]),
+    (b"glProgramUniform1ui", &[b"glProgramUniform1uiEXT\0"]),
+    (b"glProgramUniform1uiv", &[b"glProgramUniform1uivEXT\0"]),
+    (b"glProgramUniform2f", &[b"glProgramUniform2fEXT\0"]),
+    (b"glProgramUniform2fv", &[b"glProgramUniform2fvEXT\0"]),
+    (b"glProgramUniform2i", &[b"glProgramUniform2iEXT\0"]),
+    (b"glProgramUniform2iv", &[b"glProgramUniform2ivEXT\0"]),
+    (b"glProgramUniform2ui", &[b"glProgramUniform2uiEXT\0"]),
+    (b"glProgramUniform2uiv", &[b"glProgramUniform2uivEXT\0"]),
+    (b"glProgramUniform3f", &[b"glProgramUniform3fEXT\0"]),
+    (b"glProgramUniform3fv", &[b"glProgramUniform3fvEXT\0"]),
+    (b"glProgramUniform3i", &[b"glProgramUniform3iEXT\0"]),
+    (b"glProgramUniform3iv", &[b"glProgramUniform3ivEXT\0"]),
+    (b"glProgramUniform3ui", &[b"glProgramUniform3uiEXT\0"]),
+    (b"glProgramUniform3uiv", &[b"glProgramUniform3uivEXT\0"]),
+    (b"glProgramUniform4f", &[b"glProgramUniform4fEXT\0"]),
+    (b"glProgramUniform4fv", &[b"glProgramUniform4fvEXT\0"]),
+    (b"glProgramUniform4i", &[b"glProgramUniform4iEXT\0"]),
+    (b"glProgramUniform4iv", &[b"glProgramUniform4ivEXT\0"]),
+    (b"glProgramUniform4ui", &[b"glProgramUniform4uiEXT\0"]),
+    (b"glProgramUniform4uiv", &[b"glProgramUniform4uivEXT\0"]),
+    (
+        b"glProgramUniformMatrix2fv",
+        &[b"glProgramUniformMatrix2fvEXT\0"],
+    ),
+    (
+        b"glProgramUniformMatrix2x3fv",
+        &[b"glProgramUniformMatrix2x3fvEXT\0"],
+    ),
+    (
+        b"glProgramUniformMatrix2x4fv",
+        &[b"glProgramUniformMatrix2x4fvEXT\0"],
+    ),
+    (
+        b"glProgramUniformMatrix3fv",
+        &[b"glProgramUniformMatrix3fvEXT\0"],
+    ),
+    (
+        b"glProgramUniformMatrix3x2fv",
+        &[b"glProgramUniformMatrix3x2fvEXT\0"],
+    ),
+    (
+        b"glProgramUniformMatrix3x4fv",
+        &[b"glProgramUniformMatrix3x4fvEXT\0"],
+    ),
+    (
+        b"glProgramUniformMatrix4fv",
+        &[b"glProgramUniformMatrix4fvEXT\0"],
+    ),
+    (
+        b"glProgramUniformMatrix4x2fv",
+        &[b"glProgramUniformMatrix4x2fvEXT\0"],
+    ),
+    (
+        b"glProgramUniformMatrix4x3fv",
+        &[b"glProgramUniformMatrix4x3fvEXT\0"],
+    ),
+    (b"glProvokingVertex", &[b"glProvokingVertexEXT\0"]),
+    (b"glPushDebugGroup", &[b"glPushDebugGroupKHR\0"]),
+    (b"glQueryCounter", &[b"glQueryCounterEXT\0"]),
+    (
+        b"glReadnPixels",
+        &[
+            b"glReadnPixelsARB\0",
+            b"glReadnPixelsEXT\0",
+            b"glReadnPixelsKHR\0",
+        ],
+    ),
+    (b"glRenderbufferStorage", &[b"glRenderbufferStorageEXT\0"]),
+    (
+        b"glRenderbufferStorageMultisample",
+        &[
+            b"glRenderbufferStorageMultisampleEXT\0",
+            b"glRenderbufferStorageMultisampleNV\0",
+        ],
+    ),
+    (
+        b"glResumeTransformFeedback",
+        &[b"glResumeTransformFeedbackNV\0"],
+    ),
+    (b"glSampleCoverage", &[b"glSampleCoverageARB\0"]),
+    (
+        b"glSamplerParameterIiv",
+        &[b"glSamplerParameterIivEXT\0", b"glSamplerParameterIivOES\0"],
+    ),
+    (
+        b"glSamplerParameterIuiv",
+        &[
+            b"glSamplerParameterIuivEXT\0",
+            b"glSamplerParameterIuivOES\0",
+        ],
+    ),
+    (
+        b"glScissorArrayv",
+        &[b"glScissorArrayvOES\0", b"glScissorArrayvNV\0"],
+    ),
+    (
+        b"glScissorIndexed",
+        &[b"glScissorIndexedOES\0", b"glScissorIndexedNV\0"],
+    ),
+    (
+        b"glScissorIndexedv",
+        &[b"glScissorIndexedvOES\0", b"glScissorIndexedvNV\0"],
+    ),
+    (b"glShaderSource", &[b"glShaderSourceARB\0"]),
+    (b"glSpecializeShader", &[b"glSpecializeShaderARB\0"]),
+    (
+        b"glTexBuffer",
+        &[
+            b"glTexBufferARB\0",
+            b"glTexBufferEXT\0",
+            b"glTexBufferOES\0",
+        ],
+    ),
+    (
+        b"glTexBufferRange",
+        &[b"glTexBufferRangeEXT\0", b"glTexBufferRangeOES\0"],
+    ),
+    (b"glTexImage3D", &[b"glTexImage3DEXT\0"]),
+    (
+        b"glTexParameterIiv",
+        &[b"glTexParameterIivEXT\0", b"glTexParameterIivOES\0"],
+    ),
+    (
+        b"glTexParameterIuiv",
+        &[b"glTexParameterIuivEXT\0", b"glTexParameterIuivOES\0"],
+    ),
+    (b"glTexStorage1D", &[b"glTexStorage1DEXT\0"]),
+    (b"glTexStorage2D", &[b"glTexStorage2DEXT\0"]),
+    (b"glTexStorage3D", &[b"glTexStorage3DEXT\0"]),
+    (
+        b"glTexStorage3DMultisample",
+        &[b"glTexStorage3DMultisampleOES\0"],
+    ),
+    (b"glTexSubImage1D", &[b"glTexSubImage1DEXT\0"]),
+    (b"glTexSubImage2D", &[b"glTexSubImage2DEXT\0"]),
+    (b"glTexSubImage3D", &[b"glTexSubImage3DEXT\0"]),
+    (
+        b"glTextureView",
+        &[b"glTextureViewEXT\0", b"glTextureViewOES\0"],
+    ),
+    (
+        b"glTransformFeedbackVaryings",
+        &[b"glTransformFeedbackVaryingsEXT\0"],
+    ),
+    (b"glUniform1f", &[b"glUniform1fARB\0"]),
+    (b"glUniform1fv", &[b"glUniform1fvARB\0"]),
+    (b"glUniform1i", &[b"glUniform1iARB\0"]),
+    (b"glUniform1iv", &[b"glUniform1ivARB\0"]),
+    (b"glUniform1ui", &[b"glUniform1uiEXT\0"]),
+    (b"glUniform1uiv", &[b"glUniform1uivEXT\0"]),
+    (b"glUniform2f", &[b"glUniform2fARB\0"]),
+    (b"glUniform2fv", &[b"glUniform2fvARB\0"]),
+    (b"glUniform2i", &[b"glUniform2iARB\0"]),
+    (b"glUniform2iv", &[b"glUniform2ivARB\0"]),
+    (b"glUniform2ui", &[b"glUniform2uiEXT\0"]),
+    (b"glUniform2uiv", &[b"glUniform2uivEXT\0"]),
+    (b"glUniform3f", &[b"glUniform3fARB\0"]),
+    (b"glUniform3fv", &[b"glUniform3fvARB\0"]),
+    (b"glUniform3i", &[b"glUniform3iARB\0"]),
+    (b"glUniform3iv", &[b"glUniform3ivARB\0"]),
+    (b"glUniform3ui", &[b"glUniform3uiEXT\0"]),
+    (b"glUniform3uiv", &[b"glUniform3uivEXT\0"]),
+    (b"glUniform4f", &[b"glUniform4fARB\0"]),
+    (b"glUniform4fv", &[b"glUniform4fvARB\0"]),
+    (b"glUniform4i", &[b"glUniform4iARB\0"]),
+    (b"glUniform4iv", &[b"glUniform4ivARB\0"]),
+    (b"glUniform4ui", &[b"glUniform4uiEXT\0"]),
+    (b"glUniform4uiv", &[b"glUniform4uivEXT\0"]),
+    (b"glUniformMatrix2fv", &[b"glUniformMatrix2fvARB\0"]),
+    (b"glUniformMatrix2x3fv", &[b"glUniformMatrix2x3fvNV\0"]),
+    (b"glUniformMatrix2x4fv", &[b"glUniformMatrix2x4fvNV\0"]),
+    (b"glUniformMatrix3fv", &[b"glUniformMatrix3fvARB\0"]),
+    (b"glUniformMatrix3x2fv", &[b"glUniformMatrix3x2fvNV\0"]),
+    (b"glUniformMatrix3x4fv", &[b"glUniformMatrix3x4fvNV\0"]),
+    (b"glUniformMatrix4fv", &[b"glUniformMatrix4fvARB\0"]),
+    (b"glUniformMatrix4x2fv", &[b"glUniformMatrix4x2fvNV\0"]),
+    (b"glUniformMatrix4x3fv", &[b"glUniformMatrix4x3fvNV\0"]),
+    (
+        b"glUnmapBuffer",
+        &[b"glUnmapBufferARB\0", b"glUnmapBufferOES\0"],
+    ),
+    (b"glValidateProgram", &[b"glValidateProgramARB\0"]),
+    (b"glVertexAttrib1d", &[b"glVertexAttrib1dARB\0"]),
+    (b"glVertexAttrib1dv", &[b"glVertexAttrib1dvARB\0"]),
+    (b"glVertexAttrib1f", &[b"glVertexAttrib1fARB\0"]),
+    (b"glVertexAttrib1fv", &[b"glVertexAttrib1fvARB\0"]),
+    (b"glVertexAttrib1s", &[b"glVertexAttrib1sARB\0"]),
+    (b"glVertexAttrib1sv", &[b"glVertexAttrib1svARB\0"]),
+    (b"glVertexAttrib2d", &[b"glVertexAttrib2dARB\0"]),
+    (b"glVertexAttrib2dv", &[b"glVertexAttrib2dvARB\0"]),
+    (b"glVertexAttrib2f", &[b"glVertexAttrib2fARB\0"]),
+    (b"glVertexAttrib2fv", &[b"glVertexAttrib2fvARB\0"]),
+    (b"glVertexAttrib2s", &[b"glVertexAttrib2sARB\0"]),
+    (b"glVertexAttrib2sv", &[b"glVertexAttrib2svARB\0"]),
+    (b"glVertexAttrib3d", &[b"glVertexAttrib3dARB\0"]),
+    (b"glVertexAttrib3dv", &[b"glVertexAttrib3dvARB\0"]),
+    (b"glVertexAttrib3f", &[b"glVertexAttrib3fARB\0"]),
+    (b"glVertexAttrib3fv", &[b"glVertexAttrib3fvARB\0"]),
+    (b"glVertexAttrib3s", &[b"glVertexAttrib3sARB\0"]),
+    (b"glVertexAttrib3sv", &[b"glVertexAttrib3svARB\0"]),
+    (b"glVertexAttrib4Nbv", &[b"glVertexAttrib4NbvARB\0"]),
+    (b"glVertexAttrib4Niv", &[b"glVertexAttrib4NivARB\0"]),
+    (b"glVertexAttrib4Nsv", &[b"glVertexAttrib4NsvARB\0"]),
+    (b"glVertexAttrib4Nub", &[b"glVertexAttrib4NubARB\0"]),
+    (b"glVertexAttrib4Nubv", &[b"glVertexAttrib4NubvARB\0"]),
+    (b"glVertexAttrib4Nuiv", &[b"glVertexAttrib4NuivARB\0"]),
+    (b"glVertexAttrib4Nusv", &[b"glVertexAttrib4NusvARB\0"]),
+    (b"glVertexAttrib4bv", &[b"glVertexAttrib4bvARB\0"]),
+    (b"glVertexAttrib4d", &[b"glVertexAttrib4dARB\0"]),
+    (b"glVertexAttrib4dv", &[b"glVertexAttrib4dvARB\0"]),
+    (b"glVertexAttrib4f", &[b"glVertexAttrib4fARB\0"]),
+    (b"glVertexAttrib4fv", &[b"glVertexAttrib4fvARB\0"]),
+    (b"glVertexAttrib4iv", &[b"glVertexAttrib4ivARB\0"]),
+    (b"glVertexAttrib4s", &[b"glVertexAttrib4sARB\0"]),
+    (b"glVertexAttrib4sv", &[b"glVertexAttrib4svARB\0"]),
+    (b"glVertexAttrib4ubv", &[b"glVertexAttrib4ubvARB\0"]),
+    (b"glVertexAttrib4uiv", &[b"glVertexAttrib4uivARB\0"]),
+    (b"glVertexAttrib4usv", &[b"glVertexAttrib4usvARB\0"]),
+    (
+        b"glVertexAttribDivisor",
+        &[
+            b"glVertexAttribDivisorARB\0",
+            b"glVertexAttribDivisorEXT\0",
+            b"glVertexAttribDivisorANGLE\0",
+            b"glVertexAttribDivisorNV\0",
+        ],
+    ),
+    (b"glVertexAttribI1i", &[b"glVertexAttribI1iEXT\0"]),
+    (b"glVertexAttribI1iv", &[b"glVertexAttribI1ivEXT\0"]),
+    (b"glVertexAttribI1ui", &[b"glVertexAttribI1uiEXT\0"]),
+    (b"glVertexAttribI1uiv", &[b"glVertexAttribI1uivEXT\0"]),
+    (b"glVertexAttribI2i", &[b"glVertexAttribI2iEXT\0"]),
+    (b"glVertexAttribI2iv", &[b"glVertexAttribI2ivEXT\0"]),
+    (b"glVertexAttribI2ui", &[b"glVertexAttribI2uiEXT\0"]),
+    (b"glVertexAttribI2uiv", &[b"glVertexAttribI2uivEXT\0"]),
+    (b"glVertexAttribI3i", &[b"glVertexAttribI3iEXT\0"]),
+    (b"glVertexAttribI3iv", &[b"glVertexAttribI3ivEXT\0"]),
+    (b"glVertexAttribI3ui", &[b"glVertexAttribI3uiEXT\0"]),
+    (b"glVertexAttribI3uiv", &[b"glVertexAttribI3uivEXT\0"]),
+    (b"glVertexAttribI4bv", &[b"glVertexAttribI4bvEXT\0"]),
+    (b"glVertexAttribI4i", &[b"glVertexAttribI4iEXT\0"]),
+    (b"glVertexAttribI4iv", &[b"glVertexAttribI4ivEXT\0"]),
+    (b"glVertexAttribI4sv", &[b"glVertexAttribI4svEXT\0"]),
+    (b"glVertexAttribI4ubv", &[b"glVertexAttribI4ubvEXT\0"]),
+    (b"glVertexAttribI4ui", &[b"glVertexAttribI4uiEXT\0"]),
+    (b"glVertexAttribI4uiv", &[b"glVertexAttribI4uivEXT\0"]),
+    (b"glVertexAttribI4usv", &[b"glVertexAttribI4usvEXT\0"]),
+    (b"glVertexAttribIPointer", &[b"glVertexAttribIPointerEXT\0"]),
+    (b"glVertexAttribL1d", &[b"glVertexAttribL1dEXT\0"]),
+    (b"glVertexAttribL1dv", &[b"glVertexAttribL1dvEXT\0"]),
+    (b"glVertexAttribL2d", &[b"glVertexAttribL2dEXT\0"]),
+    (b"glVertexAttribL2dv", &[b"glVertexAttribL2dvEXT\0"]),
+    (b"glVertexAttribL3d", &[b"glVertexAttribL3dEXT\0"]),
+    (b"glVertexAttribL3dv", &[b"glVertexAttribL3dvEXT\0"]),
+    (b"glVertexAttribL4d", &[b"glVertexAttribL4dEXT\0"]),
+    (b"glVertexAttribL4dv", &[b"glVertexAttribL4dvEXT\0"]),
+    (b"glVertexAttribLPointer", &[b"glVertexAttribLPointerEXT\0"]),
+    (b"glVertexAttribPointer", &[b"glVertexAttribPointerARB\0"]),
+    (
+        b"glViewportArrayv",
+        &[b"glViewportArrayvOES\0", b"glViewportArrayvNV\0"],
+    ),
+    (
+        b"glViewportIndexedf",
+        &[b"glViewportIndexedfOES\0", b"glViewportIndexedfNV\0"],
+    ),
+    (
+        b"glViewportIndexedfv",
+        &[b"glViewportIndexedfvOES\0", b"glViewportIndexedfvNV\0"],
+    ),
+    (b"glWaitSync", &[b"glWaitSyncAPPLE\0"]),
+];
@@ -0,0 +1,246 @@
+use crate::*;
+
+/// Shader functionality that may need an `#extension` directive.
+///
+/// `Context::glsl_preamble` returns the `#version` line, `#extension` directives and default
+/// precision for a shader using a list of features. The `#version` line is the shading language
+/// version reported by the context, e.g. `#version 300 es` on WebGL 2 or `#version 330 core` on
+/// OpenGL 3.3, and extensions are only enabled if they're supported by the context.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ShaderFeature {
+    /// `dFdx`, `dFdy` and `fwidth`.
+    StandardDerivatives,
+    /// Writing `gl_FragDepth`.
+    FragDepth,
+    /// Writing multiple fragment outputs.
+    DrawBuffers,
+    /// `texture2DLod` and friends in fragment shaders.
+    ShaderTextureLod,
+    /// `layout(location = N)` on vertex inputs and fragment outputs.
+    ExplicitAttribLocation,
+    /// `gl_ViewID_OVR` and `layout(num_views = N)`.
+    Multiview,
+    /// `samplerExternalOES`.
+    ExternalTexture,
+    /// Reading the current framebuffer value through `inout` fragment outputs.
+    FramebufferFetch,
+}
+
+impl ShaderFeature {
+    /// Whether the feature is part of the shading language for `version`.
+    fn is_core(self, version: &Version) -> bool {
+        let at_least = |major, minor| (version.major, version.minor) >= (major, minor);
+        match self {
+            ShaderFeature::StandardDerivatives
+            | ShaderFeature::FragDepth
+            | ShaderFeature::DrawBuffers
+            | ShaderFeature::ShaderTextureLod => !version.is_embedded || at_least(3, 0),
+            ShaderFeature::ExplicitAttribLocation => {
+                if version.is_embedded {
+                    at_least(3, 0)
+                } else {
+                    at_least(3, 3)
+                }
+            }
+            ShaderFeature::Multiview
+            | ShaderFeature::ExternalTexture
+            | ShaderFeature::FramebufferFetch => false,
+        }
+    }
+
+    /// The extensions that provide the feature, and the name used in the `#extension` directive.
+    fn extensions(self, version: &Version) -> &'static [(Extension, &'static str)] {
+        match self {
+            ShaderFeature::StandardDerivatives => &[(
+                Extension::OesStandardDerivatives,
+                "GL_OES_standard_derivatives",
+            )],
+            ShaderFeature::FragDepth => &[(Extension::ExtFragDepth, "GL_EXT_frag_depth")],
+            ShaderFeature::DrawBuffers => &[
+                (Extension::ExtDrawBuffers, "GL_EXT_draw_buffers"),
+                (Extension::WebglDrawBuffers, "GL_EXT_draw_buffers"),
+            ],
+            ShaderFeature::ShaderTextureLod => {
+                &[(Extension::ExtShaderTextureLod, "GL_EXT_shader_texture_lod")]
+            }
+            ShaderFeature::ExplicitAttribLocation => &[(
+                Extension::ArbExplicitAttribLocation,
+                "GL_ARB_explicit_attrib_location",
+            )],
+            ShaderFeature::Multiview => &[(Extension::OvrMultiview2, "GL_OVR_multiview2")],
+            ShaderFeature::ExternalTexture if version.is_embedded && version.major >= 3 => &[(
+                Extension::OesEglImageExternalEssl3,
+                "GL_OES_EGL_image_external_essl3",
+            )],
+            ShaderFeature::ExternalTexture => {
+                &[(Extension::OesEglImageExternal, "GL_OES_EGL_image_external")]
+            }
+            ShaderFeature::FramebufferFetch => &[(
+                Extension::ExtShaderFramebufferFetch,
+                "GL_EXT_shader_framebuffer_fetch",
+            )],
+        }
+    }
+}
+
+/// Returns the `#version` directive for the shading language version reported by the driver,
+/// falling back to the one that matches the GL version if it couldn't be parsed.
+fn version_directive(info: &DriverInfo) -> String {
+    match &info.glsl {
+        Some(glsl) => {
+            let number = glsl.number();
+            let profile = match glsl.is_embedded {
+                true if number >= 300 => " es",
+                false if number >= 330 => " core",
+                _ => "",
+            };
+            format!("#version {}{}", number, profile)
+        }
+        None => gl_version_directive(&info.version).to_string(),
+    }
+}
+
+/// Returns the `#version` directive for the shading language that matches `version`.
+fn gl_version_directive(version: &Version) -> &'static str {
+    match (version.is_embedded, version.major, version.minor) {
+        (true, 0..=2, _) => "#version 100",
+        (true, 3, 0) => "#version 300 es",
+        (true, 3, 1) => "#version 310 es",
+        (true, _, _) => "#version 320 es",
+        (false, 0..=1, _) | (false, 2, 0) => "#version 110",
+        (false, 2, _) => "#version 120",
+        (false, 3, 0) => "#version 130",
+        (false, 3, 1) => "#version 140",
+        (false, 3, 2) => "#version 150",
+        (false, 3, _) => "#version 330 core",
+        (false, 4, 0) => "#version 400 core",
+        (false, 4, 1) => "#version 410 core",
+        (false, 4, 2) => "#version 420 core",
+        (false, 4, 3) => "#version 430 core",
+        (false, 4, 4) => "#version 440 core",
+        (false, 4, 5) => "#version 450 core",
+        (false, _, _) => "#version 460 core",
+    }
+}
+
+/// Builds the header for a shader that uses `features`.
+///
+/// Extensions are only enabled if they're in `extensions`, so features that aren't available are
+/// left for the shader to detect, e.g. with `#ifdef GL_EXT_frag_depth`.
+pub(crate) fn preamble(
+    info: &DriverInfo,
+    extensions: &Extensions,
+    features: &[ShaderFeature],
+) -> String {
+    let version = &info.version;
+    let mut preamble = version_directive(info);
+    preamble.push('\n');
+
+    let mut enabled = Vec::new();
+    for &feature in features {
+        if feature.is_core(version) {
+            continue;
+        }
+        let directive = feature
+            .extensions(version)
+            .iter()
+            .find(|&&(extension, _)| extensions.has(extension))
+            .map(|&(_, directive)| directive);
+        if let Some(directive) = directive {
+            if !enabled.contains(&directive) {
+                enabled.push(directive);
+                preamble.push_str(&format!("#extension {} : enable\n", directive));
+            }
+        }
+    }
+
+    if version.is_embedded {
+        if version.major < 3 {
+            // `highp` is optional in ES 2.0 fragment shaders
+            preamble.push_str(
+                "#ifdef GL_FRAGMENT_PRECISION_HIGH\n\
+                 precision highp float;\n\
+                 #else\n\
+                 precision mediump float;\n\
+                 #endif\n",
+            );
+        } else {
+            preamble.push_str("precision highp float;\nprecision highp int;\n");
+        }
+    }
+
+    preamble
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn driver_info(version: &str, glsl: &str) -> DriverInfo {
+        DriverInfo {
+            vendor: String::new(),
+            renderer: String::new(),
+            version: version.parse().unwrap(),
+            glsl: glsl.parse().ok(),
+            is_webgl: false,
+        }
+    }
+
+    #[test]
+    fn test_version_directive() {
+        let directive = |version: &str, glsl: &str| version_directive(&driver_info(version, glsl));
+        assert_eq!(directive("WebGL 1.0", ""), "#version 100");
+        assert_eq!(directive("WebGL 2.0", ""), "#version 300 es");
+        assert_eq!(
+            directive("OpenGL ES 3.2 Mesa 23.0.4", ""),
+            "#version 320 es"
+        );
+        assert_eq!(directive("2.1 Mesa 23.0.4", ""), "#version 120");
+        assert_eq!(directive("3.3.0 NVIDIA 535.54.03", ""), "#version 330 core");
+        assert_eq!(directive("4.6.0 NVIDIA 535.54.03", ""), "#version 460 core");
+
+        assert_eq!(
+            directive(
+                "WebGL 2.0",
+                "WebGL GLSL ES 3.00 (OpenGL ES GLSL ES 3.0 Chromium)"
+            ),
+            "#version 300 es"
+        );
+        assert_eq!(
+            directive("OpenGL ES 2.0 Mesa 23.0.4", "OpenGL ES GLSL ES 1.0.16"),
+            "#version 100"
+        );
+        // Compatibility profiles can report an older shading language than the GL version
+        assert_eq!(directive("3.1 Mesa 23.0.4", "1.40"), "#version 140");
+        assert_eq!(
+            directive("4.6.0 NVIDIA 535.54.03", "4.60 NVIDIA"),
+            "#version 460 core"
+        );
+    }
+
+    #[test]
+    fn test_preamble() {
+        let extensions = Extensions::from_names(["OES_standard_derivatives", "WEBGL_draw_buffers"]);
+        let features = [
+            ShaderFeature::StandardDerivatives,
+            ShaderFeature::DrawBuffers,
+            ShaderFeature::FragDepth,
+        ];
+
+        let webgl1 = preamble(&driver_info("WebGL 1.0", ""), &extensions, &features);
+        assert!(webgl1.starts_with("#version 100\n"));
+        assert!(webgl1.contains("#extension GL_OES_standard_derivatives : enable\n"));
+        assert!(webgl1.contains("#extension GL_EXT_draw_buffers : enable\n"));
+        assert!(!webgl1.contains("GL_EXT_frag_depth"));
+        assert!(webgl1.contains("precision mediump float;\n"));
+
+        let webgl2 = preamble(&driver_info("WebGL 2.0", ""), &extensions, &features);
+        assert_eq!(
+            webgl2,
+            "#version 300 es\nprecision highp float;\nprecision highp int;\n"
+        );
+
+        let desktop = preamble(&driver_info("3.3.0", "3.30"), &extensions, &features);
+        assert_eq!(desktop, "#version 330 core\n");
+    }
+}
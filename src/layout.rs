@@ -0,0 +1,563 @@
+use crate::reflection::{self, array_base_name};
+use crate::*;
+
+/// The memory layout rules of an interface block.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum LayoutRule {
+    /// `layout(std140)`, for uniform and shader storage blocks. Arrays and matrix columns are
+    /// aligned to 16 bytes.
+    Std140,
+    /// `layout(std430)`, only for shader storage blocks. Like `Std140`, but arrays and matrix
+    /// columns of scalars and 2-component vectors are packed tighter.
+    Std430,
+}
+
+/// A member of a [`BlockLayout`] and where it's placed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LayoutField {
+    /// The name as reported by the driver, without the `[0]` of arrays.
+    pub name: String,
+    /// The GLSL type, e.g. `FLOAT_VEC3` or `FLOAT_MAT4`.
+    pub utype: u32,
+    /// The number of array elements, or 0 for non-arrays.
+    pub array_len: u32,
+    pub offset: u32,
+    /// The distance between array elements, or 0 for non-arrays.
+    pub array_stride: u32,
+    /// The distance between matrix columns, or 0 for non-matrices.
+    pub matrix_stride: u32,
+}
+
+/// Computes std140 and std430 offsets for the members of a uniform or shader storage block.
+///
+/// Fields are added in declaration order with [`BlockLayout::field`] and
+/// [`BlockLayout::array`], using their GLSL types like `FLOAT_VEC4`. Matrices are column-major.
+/// Structs and unsized arrays aren't supported.
+///
+/// The layout can be checked against the driver with [`BlockLayout::verify_uniform_block`],
+/// [`BlockLayout::verify_storage_block`] or [`BlockLayout::verify`], which return a
+/// [`BlockWriter`] that packs values into a buffer with the verified layout.
+///
+/// ```
+/// # use glow::*;
+/// let mut layout = BlockLayout::new(LayoutRule::Std140);
+/// layout.field("time", FLOAT).field("offset", FLOAT_VEC3).array("weights", FLOAT, 4);
+/// assert_eq!(layout.get("offset").unwrap().offset, 16);
+/// assert_eq!(layout.get("weights").unwrap().array_stride, 16);
+/// assert_eq!(layout.size(), 96);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlockLayout {
+    rule: LayoutRule,
+    fields: Vec<LayoutField>,
+    /// The end of the last field.
+    end: u32,
+    /// The largest base alignment of the fields.
+    alignment: u32,
+}
+
+impl BlockLayout {
+    pub fn new(rule: LayoutRule) -> Self {
+        BlockLayout {
+            rule,
+            fields: Vec::new(),
+            end: 0,
+            alignment: match rule {
+                LayoutRule::Std140 => 16,
+                LayoutRule::Std430 => 1,
+            },
+        }
+    }
+
+    pub fn rule(&self) -> LayoutRule {
+        self.rule
+    }
+
+    /// Appends a non-array field of type `utype`, e.g. `FLOAT_VEC4`.
+    ///
+    /// Panics if `utype` isn't a scalar, vector or matrix type.
+    pub fn field(&mut self, name: &str, utype: u32) -> &mut Self {
+        self.push(name, utype, 0)
+    }
+
+    /// Appends an array of `len` elements of type `utype`.
+    ///
+    /// Panics if `utype` isn't a scalar, vector or matrix type, or if `len` is 0.
+    pub fn array(&mut self, name: &str, utype: u32, len: u32) -> &mut Self {
+        assert!(
+            len > 0,
+            "glow: array `{}` must have at least one element",
+            name
+        );
+        self.push(name, utype, len)
+    }
+
+    pub fn fields(&self) -> &[LayoutField] {
+        &self.fields
+    }
+
+    /// Returns a field by name, which may include the `[0]` of arrays.
+    pub fn get(&self, name: &str) -> Option<&LayoutField> {
+        let name = array_base_name(name);
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// The size of the block in bytes, rounded up to its alignment.
+    pub fn size(&self) -> u32 {
+        self.end.next_multiple_of(self.alignment)
+    }
+
+    /// Returns a writer for this layout without checking it against a program.
+    pub fn writer(&self) -> BlockWriter {
+        BlockWriter {
+            layout: self.clone(),
+            bytes: vec![0; self.size() as usize],
+        }
+    }
+
+    /// Checks the layout against the members of a block reported by the driver, see
+    /// `Context::reflect_program`.
+    ///
+    /// Every member of the block must be described by a field of the same type and array size,
+    /// with the same offset and strides. All mismatches are listed in the error.
+    pub fn verify(&self, block: &ReflectedBlock) -> Result<BlockWriter, String> {
+        let mut errors = Vec::new();
+        for member in &block.members {
+            let name = array_base_name(&member.name);
+            let field = match self.fields.iter().find(|field| field.name == name) {
+                Some(field) => field,
+                None => {
+                    errors.push(format!("`{}` is missing from the layout", name));
+                    continue;
+                }
+            };
+            if field.utype != member.utype {
+                errors.push(format!(
+                    "`{}` has type {:#06x}, but the driver reports {:#06x}",
+                    name, field.utype, member.utype
+                ));
+                continue;
+            }
+            let mut check = |property, expected: u32, actual: u32| {
+                if expected != actual {
+                    errors.push(format!(
+                        "`{}` has {} {}, but the driver reports {}",
+                        name, property, expected, actual
+                    ));
+                }
+            };
+            check(
+                "array size",
+                field.array_len.max(1),
+                member.size.max(0) as u32,
+            );
+            check("offset", field.offset, member.offset);
+            if field.array_len > 0 {
+                check("array stride", field.array_stride, member.array_stride);
+            }
+            if field.matrix_stride > 0 {
+                check("matrix stride", field.matrix_stride, member.matrix_stride);
+            }
+            if member.row_major {
+                errors.push(format!("`{}` is row-major", name));
+            }
+        }
+        for field in &self.fields {
+            let active = block
+                .members
+                .iter()
+                .any(|member| array_base_name(&member.name) == field.name);
+            if !active {
+                errors.push(format!("`{}` isn't a member of the block", field.name));
+            }
+        }
+        if block.data_size < self.size() {
+            errors.push(format!(
+                "The layout is {} bytes, but the driver reports {}",
+                self.size(),
+                block.data_size
+            ));
+        }
+
+        if errors.is_empty() {
+            Ok(self.writer())
+        } else {
+            Err(format!(
+                "Layout of block `{}` doesn't match the program: {}",
+                block.name,
+                errors.join(", ")
+            ))
+        }
+    }
+
+    /// Checks the layout against uniform block `index` of `program`, using
+    /// `get_active_uniform_block_parameter_i32` and `get_active_uniforms_parameter`.
+    pub unsafe fn verify_uniform_block<C: HasContext>(
+        &self,
+        gl: &C,
+        program: C::Program,
+        index: u32,
+    ) -> Result<BlockWriter, String> {
+        self.verify(&reflection::reflect_uniform_block(gl, program, index))
+    }
+
+    /// Checks the layout against shader storage block `index` of `program`, using
+    /// `get_program_resource_i32`.
+    pub unsafe fn verify_storage_block<C: HasContext>(
+        &self,
+        gl: &C,
+        program: C::Program,
+        index: u32,
+    ) -> Result<BlockWriter, String> {
+        if !gl.features().program_interface_query {
+            return Err("Program interface queries are not supported".to_string());
+        }
+        let blocks = reflection::reflect_storage_blocks(gl, program);
+        let block = blocks
+            .get(index as usize)
+            .ok_or_else(|| format!("Program has no shader storage block {}", index))?;
+        self.verify(block)
+    }
+
+    fn push(&mut self, name: &str, utype: u32, array_len: u32) -> &mut Self {
+        let shape = Shape::of(utype).unwrap_or_else(|| {
+            panic!(
+                "glow: `{}` has type {:#06x}, which isn't a scalar, vector or matrix",
+                name, utype
+            )
+        });
+        let scalar_size = shape.scalar.size();
+        // The base alignment of a column vector
+        let vector_alignment = scalar_size
+            * if shape.rows == 1 {
+                1
+            } else {
+                shape.rows.next_power_of_two()
+            };
+        let is_matrix = shape.columns > 1;
+        // Arrays and matrices are laid out like arrays of their elements or columns, which std140
+        // rounds up to the alignment of a vec4
+        let array_alignment = match self.rule {
+            LayoutRule::Std140 => vector_alignment.next_multiple_of(16),
+            LayoutRule::Std430 => vector_alignment,
+        };
+        let matrix_stride = if is_matrix { array_alignment } else { 0 };
+        let (alignment, element_size) = if is_matrix {
+            (array_alignment, shape.columns * matrix_stride)
+        } else if array_len > 0 {
+            (
+                array_alignment,
+                (shape.rows * scalar_size).next_multiple_of(array_alignment),
+            )
+        } else {
+            (vector_alignment, shape.rows * scalar_size)
+        };
+        let offset = self.end.next_multiple_of(alignment);
+        self.end = offset + element_size * array_len.max(1);
+        self.alignment = self.alignment.max(alignment);
+        self.fields.push(LayoutField {
+            name: array_base_name(name).to_string(),
+            utype,
+            array_len,
+            offset,
+            array_stride: if array_len > 0 { element_size } else { 0 },
+            matrix_stride,
+        });
+        self
+    }
+}
+
+/// Packs values into a buffer with the layout of a [`BlockLayout`], e.g. for
+/// `buffer_sub_data_u8_slice`.
+///
+/// Values are passed as flat slices: the components of a vector, the columns of a matrix one
+/// after another, and the elements of an array one after another. Padding is left unchanged.
+#[derive(Clone, Debug)]
+pub struct BlockWriter {
+    layout: BlockLayout,
+    bytes: Vec<u8>,
+}
+
+impl BlockWriter {
+    pub fn layout(&self) -> &BlockLayout {
+        &self.layout
+    }
+
+    /// The packed block, [`BlockLayout::size`] bytes long.
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Writes a `float` field, or a `vec*` or `mat*` field.
+    pub fn set_f32(&mut self, name: &str, values: &[f32]) -> Result<(), String> {
+        self.set(name, Scalar::Float, values.iter().map(|v| v.to_ne_bytes()))
+    }
+
+    /// Writes an `int` or `ivec*` field.
+    pub fn set_i32(&mut self, name: &str, values: &[i32]) -> Result<(), String> {
+        self.set(name, Scalar::Int, values.iter().map(|v| v.to_ne_bytes()))
+    }
+
+    /// Writes a `uint` or `uvec*` field.
+    pub fn set_u32(&mut self, name: &str, values: &[u32]) -> Result<(), String> {
+        self.set(name, Scalar::Uint, values.iter().map(|v| v.to_ne_bytes()))
+    }
+
+    /// Writes a `bool` or `bvec*` field, which are stored as 32-bit integers.
+    pub fn set_bool(&mut self, name: &str, values: &[bool]) -> Result<(), String> {
+        self.set(
+            name,
+            Scalar::Bool,
+            values.iter().map(|&v| (v as u32).to_ne_bytes()),
+        )
+    }
+
+    /// Writes a `double`, `dvec*` or `dmat*` field.
+    pub fn set_f64(&mut self, name: &str, values: &[f64]) -> Result<(), String> {
+        self.set(name, Scalar::Double, values.iter().map(|v| v.to_ne_bytes()))
+    }
+
+    fn set<const N: usize>(
+        &mut self,
+        name: &str,
+        scalar: Scalar,
+        values: impl ExactSizeIterator<Item = [u8; N]>,
+    ) -> Result<(), String> {
+        let field = self
+            .layout
+            .get(name)
+            .ok_or_else(|| format!("Block has no field `{}`", name))?;
+        let shape = Shape::of(field.utype).unwrap();
+        if shape.scalar != scalar {
+            return Err(format!(
+                "Field `{}` has type {:#06x}, which can't be written from {:?} values",
+                name, field.utype, scalar
+            ));
+        }
+        let element_len = (shape.columns * shape.rows) as usize;
+        let capacity = element_len * field.array_len.max(1) as usize;
+        if values.len() % element_len != 0 || values.len() > capacity {
+            return Err(format!(
+                "Field `{}` takes a multiple of {} values up to {}, but {} were given",
+                name,
+                element_len,
+                capacity,
+                values.len()
+            ));
+        }
+        let (offset, array_stride, matrix_stride) =
+            (field.offset, field.array_stride, field.matrix_stride);
+        for (i, value) in values.enumerate() {
+            let element = (i / element_len) as u32;
+            let column = (i % element_len) as u32 / shape.rows;
+            let row = (i % element_len) as u32 % shape.rows;
+            let start = (offset
+                + element * array_stride
+                + column * matrix_stride
+                + row * shape.scalar.size()) as usize;
+            self.bytes[start..start + N].copy_from_slice(&value);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Scalar {
+    Float,
+    Int,
+    Uint,
+    Bool,
+    Double,
+}
+
+impl Scalar {
+    fn size(self) -> u32 {
+        match self {
+            Scalar::Double => 8,
+            _ => 4,
+        }
+    }
+}
+
+/// The scalar type and dimensions of a GLSL type, vectors have a single column.
+#[derive(Clone, Copy, Debug)]
+struct Shape {
+    scalar: Scalar,
+    columns: u32,
+    rows: u32,
+}
+
+impl Shape {
+    fn of(utype: u32) -> Option<Self> {
+        let (scalar, columns, rows) = match utype {
+            FLOAT => (Scalar::Float, 1, 1),
+            FLOAT_VEC2 => (Scalar::Float, 1, 2),
+            FLOAT_VEC3 => (Scalar::Float, 1, 3),
+            FLOAT_VEC4 => (Scalar::Float, 1, 4),
+            INT => (Scalar::Int, 1, 1),
+            INT_VEC2 => (Scalar::Int, 1, 2),
+            INT_VEC3 => (Scalar::Int, 1, 3),
+            INT_VEC4 => (Scalar::Int, 1, 4),
+            UNSIGNED_INT => (Scalar::Uint, 1, 1),
+            UNSIGNED_INT_VEC2 => (Scalar::Uint, 1, 2),
+            UNSIGNED_INT_VEC3 => (Scalar::Uint, 1, 3),
+            UNSIGNED_INT_VEC4 => (Scalar::Uint, 1, 4),
+            BOOL => (Scalar::Bool, 1, 1),
+            BOOL_VEC2 => (Scalar::Bool, 1, 2),
+            BOOL_VEC3 => (Scalar::Bool, 1, 3),
+            BOOL_VEC4 => (Scalar::Bool, 1, 4),
+            DOUBLE => (Scalar::Double, 1, 1),
+            DOUBLE_VEC2 => (Scalar::Double, 1, 2),
+            DOUBLE_VEC3 => (Scalar::Double, 1, 3),
+            DOUBLE_VEC4 => (Scalar::Double, 1, 4),
+            FLOAT_MAT2 => (Scalar::Float, 2, 2),
+            FLOAT_MAT2x3 => (Scalar::Float, 2, 3),
+            FLOAT_MAT2x4 => (Scalar::Float, 2, 4),
+            FLOAT_MAT3x2 => (Scalar::Float, 3, 2),
+            FLOAT_MAT3 => (Scalar::Float, 3, 3),
+            FLOAT_MAT3x4 => (Scalar::Float, 3, 4),
+            FLOAT_MAT4x2 => (Scalar::Float, 4, 2),
+            FLOAT_MAT4x3 => (Scalar::Float, 4, 3),
+            FLOAT_MAT4 => (Scalar::Float, 4, 4),
+            DOUBLE_MAT2 => (Scalar::Double, 2, 2),
+            DOUBLE_MAT2x3 => (Scalar::Double, 2, 3),
+            DOUBLE_MAT2x4 => (Scalar::Double, 2, 4),
+            DOUBLE_MAT3x2 => (Scalar::Double, 3, 2),
+            DOUBLE_MAT3 => (Scalar::Double, 3, 3),
+            DOUBLE_MAT3x4 => (Scalar::Double, 3, 4),
+            DOUBLE_MAT4x2 => (Scalar::Double, 4, 2),
+            DOUBLE_MAT4x3 => (Scalar::Double, 4, 3),
+            DOUBLE_MAT4 => (Scalar::Double, 4, 4),
+            _ => return None,
+        };
+        Some(Shape {
+            scalar,
+            columns,
+            rows,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example(rule: LayoutRule) -> BlockLayout {
+        let mut layout = BlockLayout::new(rule);
+        layout
+            .field("a", FLOAT)
+            .field("b", FLOAT_VEC3)
+            .field("c", FLOAT)
+            .array("d", FLOAT_VEC2, 2)
+            .field("m", FLOAT_MAT3)
+            .array("e", FLOAT, 3);
+        layout
+    }
+
+    fn placement(layout: &BlockLayout) -> Vec<(u32, u32, u32)> {
+        layout
+            .fields()
+            .iter()
+            .map(|field| (field.offset, field.array_stride, field.matrix_stride))
+            .collect()
+    }
+
+    #[test]
+    fn test_layout() {
+        let std140 = example(LayoutRule::Std140);
+        assert_eq!(
+            placement(&std140),
+            [
+                (0, 0, 0),
+                (16, 0, 0),
+                (28, 0, 0),
+                (32, 16, 0),
+                (64, 0, 16),
+                (112, 16, 0),
+            ]
+        );
+        assert_eq!(std140.size(), 160);
+
+        let std430 = example(LayoutRule::Std430);
+        assert_eq!(
+            placement(&std430),
+            [
+                (0, 0, 0),
+                (16, 0, 0),
+                (28, 0, 0),
+                (32, 8, 0),
+                (48, 0, 16),
+                (96, 4, 0),
+            ]
+        );
+        assert_eq!(std430.size(), 112);
+
+        let mut doubles = BlockLayout::new(LayoutRule::Std430);
+        doubles.field("x", FLOAT).field("v", DOUBLE_VEC3);
+        assert_eq!(doubles.get("v").unwrap().offset, 32);
+        assert_eq!(doubles.size(), 64);
+    }
+
+    #[test]
+    fn test_verify_and_write() {
+        let layout = example(LayoutRule::Std140);
+        let member = |name: &str, utype, size, offset, array_stride, matrix_stride| BlockMember {
+            name: name.to_string(),
+            utype,
+            size,
+            offset,
+            array_stride,
+            matrix_stride,
+            row_major: false,
+            top_level_array_stride: 0,
+        };
+        let mut block = ReflectedBlock {
+            name: "Params".to_string(),
+            index: 0,
+            binding: 0,
+            data_size: 160,
+            members: vec![
+                member("a", FLOAT, 1, 0, 0, 0),
+                member("b", FLOAT_VEC3, 1, 16, 0, 0),
+                member("c", FLOAT, 1, 28, 0, 0),
+                member("d[0]", FLOAT_VEC2, 2, 32, 16, 0),
+                member("m", FLOAT_MAT3, 1, 64, 0, 16),
+                member("e[0]", FLOAT, 3, 112, 16, 0),
+            ],
+        };
+
+        let mut writer = layout.verify(&block).unwrap();
+        writer.set_f32("b", &[1.0, 2.0, 3.0]).unwrap();
+        writer.set_f32("d", &[4.0, 5.0, 6.0, 7.0]).unwrap();
+        writer
+            .set_f32("m", &[1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0])
+            .unwrap();
+        let read = |offset: usize| {
+            f32::from_ne_bytes(writer.bytes()[offset..offset + 4].try_into().unwrap())
+        };
+        assert_eq!([read(16), read(20), read(24)], [1.0, 2.0, 3.0]);
+        assert_eq!(
+            [read(32), read(36), read(48), read(52)],
+            [4.0, 5.0, 6.0, 7.0]
+        );
+        assert_eq!(
+            [read(64), read(84), read(104), read(68)],
+            [1.0, 1.0, 1.0, 0.0]
+        );
+        assert!(writer.set_i32("a", &[1]).is_err());
+        assert!(writer.set_f32("e", &[1.0; 4]).is_err());
+        assert!(writer.set_f32("missing", &[1.0]).is_err());
+
+        block.members[3].array_stride = 8;
+        block.members.pop();
+        assert_eq!(
+            layout.verify(&block).unwrap_err(),
+            "Layout of block `Params` doesn't match the program: `d` has array stride 16, but \
+             the driver reports 8, `e` isn't a member of the block"
+        );
+    }
+}
@@ -4,13 +4,87 @@
 #![allow(clippy::unreadable_literal)]
 #![allow(clippy::missing_safety_doc)]
 #![allow(clippy::pedantic)] // For anyone using pedantic and a source dep, this is needed
-
+// The web backend always has `std`
+#![cfg_attr(
+    not(any(
+        feature = "std",
+        test,
+        all(target_arch = "wasm32", not(target_os = "emscripten"))
+    )),
+    no_std
+)]
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeSet;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 use core::fmt::Debug;
 use core::hash::Hash;
-use std::collections::HashSet;
 
 mod version;
-pub use version::Version;
+pub use version::{DriverInfo, ShadingLanguageVersion, Version, VersionParseError};
+
+mod extension_names;
+mod extensions;
+pub use extensions::{Extension, Extensions};
+
+mod capabilities;
+pub use capabilities::{Features, Limits};
+
+mod workarounds;
+pub use workarounds::Workarounds;
+
+mod glsl;
+pub use glsl::ShaderFeature;
+
+mod diagnostics;
+pub use diagnostics::{DiagnosticSeverity, ShaderDiagnostic};
+
+mod program;
+pub use program::{PendingProgram, ProgramError};
+
+#[cfg(feature = "std")]
+mod program_cache;
+#[cfg(feature = "std")]
+pub use program_cache::ProgramCache;
+
+mod reflection;
+pub use reflection::{
+    BlockMember, ProgramReflection, ReflectedAttribute, ReflectedBlock, ReflectedSampler,
+    ReflectedUniform, ReflectedVarying,
+};
+
+mod layout;
+pub use layout::{BlockLayout, BlockWriter, LayoutField, LayoutRule};
+
+mod uniform;
+pub use uniform::UniformValue;
+
+mod vertex_layout;
+pub use vertex_layout::{
+    AttributeConversion, VertexAttribute, VertexBufferLayout, VertexLayout, VertexStep,
+};
+
+pub mod format;
+
+pub mod container;
+
+#[cfg(feature = "testing")]
+pub mod testing;
+
+mod readback;
+pub use readback::{AsyncReadback, PendingReadback};
+
+mod tracking;
+pub use tracking::{LiveObject, ObjectCensus, ObjectKind};
+
+mod sync;
+
+mod memory;
+pub use memory::{DriverMemoryInfo, MemoryUsage, ObjectMemory};
 
 #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
 mod native;
@@ -18,6 +92,40 @@ mod native;
 pub use native::*;
 #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
 mod gl46;
+#[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+mod gl46_aliases;
+
+#[cfg(all(
+    any(feature = "egl", feature = "osmesa"),
+    unix,
+    not(any(target_arch = "wasm32", target_os = "macos", target_os = "ios"))
+))]
+mod dl;
+#[cfg(all(
+    feature = "egl",
+    unix,
+    not(any(target_arch = "wasm32", target_os = "macos", target_os = "ios"))
+))]
+mod egl;
+#[cfg(all(
+    feature = "egl",
+    unix,
+    not(any(target_arch = "wasm32", target_os = "macos", target_os = "ios"))
+))]
+pub use egl::{ContextApi, ContextRequest, HeadlessEgl};
+
+#[cfg(all(
+    feature = "osmesa",
+    unix,
+    not(any(target_arch = "wasm32", target_os = "macos", target_os = "ios"))
+))]
+mod osmesa;
+#[cfg(all(
+    feature = "osmesa",
+    unix,
+    not(any(target_arch = "wasm32", target_os = "macos", target_os = "ios"))
+))]
+pub use osmesa::OsMesa;
 
 #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
 #[path = "web_sys.rs"]
@@ -153,12 +261,32 @@ pub trait HasContext: __private::Sealed {
     type TransformFeedback: Copy + Clone + Debug + Eq + Hash + Ord + PartialEq + PartialOrd;
     type UniformLocation: Clone + Debug;
 
-    fn supported_extensions(&self) -> &HashSet<String>;
+    fn supported_extensions(&self) -> &BTreeSet<String>;
+
+    /// The known extensions in [`HasContext::supported_extensions`].
+    fn extensions(&self) -> &Extensions;
+
+    fn features(&self) -> &Features;
+
+    fn limits(&self) -> &Limits;
 
     fn supports_debug(&self) -> bool;
 
     fn version(&self) -> &Version;
 
+    fn driver_info(&self) -> &DriverInfo;
+
+    /// The driver bugs that this context works around, see [`Workarounds`].
+    fn workarounds(&self) -> &Workarounds;
+
+    /// The `UNPACK_*` parameters set with `pixel_store_i32`, which the context tracks so that
+    /// uploads can be checked without querying them. Changes made by other code sharing the GL
+    /// context aren't seen.
+    fn unpack_parameters(&self) -> format::PixelStore;
+
+    /// Like [`HasContext::unpack_parameters`], for the `PACK_*` parameters.
+    fn pack_parameters(&self) -> format::PixelStore;
+
     unsafe fn create_framebuffer(&self) -> Result<Self::Framebuffer, String>;
 
     unsafe fn create_named_framebuffer(&self) -> Result<Self::Framebuffer, String>;
@@ -195,6 +323,10 @@ pub trait HasContext: __private::Sealed {
 
     unsafe fn get_shader_info_log(&self, shader: Self::Shader) -> String;
 
+    /// Parses [`HasContext::get_shader_info_log`] into diagnostics, understanding the common
+    /// vendor formats so that messages can be mapped back to source lines.
+    unsafe fn get_shader_diagnostics(&self, shader: Self::Shader) -> Vec<ShaderDiagnostic>;
+
     unsafe fn get_shader_precision_format(
         &self,
         shader_type: u32,
@@ -234,6 +366,9 @@ pub trait HasContext: __private::Sealed {
 
     unsafe fn get_program_info_log(&self, program: Self::Program) -> String;
 
+    /// Parses [`HasContext::get_program_info_log`] like [`HasContext::get_shader_diagnostics`].
+    unsafe fn get_program_diagnostics(&self, program: Self::Program) -> Vec<ShaderDiagnostic>;
+
     unsafe fn get_program_resource_i32(
         &self,
         program: Self::Program,
@@ -242,6 +377,21 @@ pub trait HasContext: __private::Sealed {
         properties: &[u32],
     ) -> Vec<i32>;
 
+    #[doc(alias = "GetProgramInterfaceiv")]
+    unsafe fn get_program_interface_i32(
+        &self,
+        program: Self::Program,
+        interface: u32,
+        parameter: u32,
+    ) -> i32;
+
+    unsafe fn get_program_resource_name(
+        &self,
+        program: Self::Program,
+        interface: u32,
+        index: u32,
+    ) -> String;
+
     unsafe fn program_uniform_1_i32(
         &self,
         program: Self::Program,
@@ -546,7 +696,7 @@ pub trait HasContext: __private::Sealed {
     unsafe fn bind_vertex_buffer(
         &self,
         binding_index: u32,
-        buffer: Option<Buffer>,
+        buffer: Option<Self::Buffer>,
         offset: i32,
         stride: i32,
     );
@@ -2894,6 +3044,16 @@ pub const GET_TEXTURE_IMAGE_FORMAT: u32 = 0x8291;
 
 pub const GET_TEXTURE_IMAGE_TYPE: u32 = 0x8292;
 
+pub const GPU_MEMORY_INFO_CURRENT_AVAILABLE_VIDMEM_NVX: u32 = 0x9049;
+
+pub const GPU_MEMORY_INFO_DEDICATED_VIDMEM_NVX: u32 = 0x9047;
+
+pub const GPU_MEMORY_INFO_EVICTED_MEMORY_NVX: u32 = 0x904B;
+
+pub const GPU_MEMORY_INFO_EVICTION_COUNT_NVX: u32 = 0x904A;
+
+pub const GPU_MEMORY_INFO_TOTAL_AVAILABLE_MEMORY_NVX: u32 = 0x9048;
+
 pub const GREATER: u32 = 0x0204;
 
 pub const GREEN: u32 = 0x1904;
@@ -3826,6 +3986,8 @@ pub const RENDERBUFFER_BLUE_SIZE: u32 = 0x8D52;
 
 pub const RENDERBUFFER_DEPTH_SIZE: u32 = 0x8D54;
 
+pub const RENDERBUFFER_FREE_MEMORY_ATI: u32 = 0x87FD;
+
 pub const RENDERBUFFER_GREEN_SIZE: u32 = 0x8D51;
 
 pub const RENDERBUFFER_HEIGHT: u32 = 0x8D43;
@@ -4404,6 +4566,8 @@ pub const TEXTURE_FETCH_BARRIER_BIT: u32 = 0x00000008;
 
 pub const TEXTURE_FIXED_SAMPLE_LOCATIONS: u32 = 0x9107;
 
+pub const TEXTURE_FREE_MEMORY_ATI: u32 = 0x87FC;
+
 pub const TEXTURE_GATHER: u32 = 0x82A2;
 
 pub const TEXTURE_GATHER_SHADOW: u32 = 0x82A3;
@@ -4732,6 +4896,8 @@ pub const UPPER_LEFT: u32 = 0x8CA2;
 
 pub const VALIDATE_STATUS: u32 = 0x8B83;
 
+pub const VBO_FREE_MEMORY_ATI: u32 = 0x87FB;
+
 pub const VENDOR: u32 = 0x1F00;
 
 pub const VERSION: u32 = 0x1F02;
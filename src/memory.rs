@@ -0,0 +1,490 @@
+use crate::sync::Mutex;
+use crate::*;
+use alloc::collections::BTreeMap;
+
+/// The estimated size of an object's storage.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ObjectMemory<H> {
+    pub handle: H,
+    pub bytes: u64,
+}
+
+/// Memory totals reported by the driver, in KiB.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DriverMemoryInfo {
+    /// From `NVX_gpu_memory_info`.
+    Nvidia {
+        dedicated_kib: u64,
+        total_available_kib: u64,
+        current_available_kib: u64,
+        evicted_kib: u64,
+        eviction_count: u64,
+    },
+    /// The free memory in the buffer, texture and renderbuffer pools, from `ATI_meminfo`.
+    Amd {
+        vbo_free_kib: u64,
+        texture_free_kib: u64,
+        renderbuffer_free_kib: u64,
+    },
+}
+
+/// Estimated memory usage by object, returned by `Context::memory_usage`.
+///
+/// Sizes are estimated from the sizes, formats, mip levels and sample counts passed when
+/// allocating storage, so they don't include driver overhead, padding or compression.
+#[derive(Clone, Debug)]
+pub struct MemoryUsage<C: HasContext> {
+    pub buffers: Vec<ObjectMemory<C::Buffer>>,
+    pub textures: Vec<ObjectMemory<C::Texture>>,
+    pub renderbuffers: Vec<ObjectMemory<C::Renderbuffer>>,
+    /// Totals reported by the driver, if it supports `NVX_gpu_memory_info` or `ATI_meminfo`.
+    pub driver: Option<DriverMemoryInfo>,
+}
+
+impl<C: HasContext> MemoryUsage<C> {
+    pub fn buffer_bytes(&self) -> u64 {
+        self.buffers.iter().map(|object| object.bytes).sum()
+    }
+
+    pub fn texture_bytes(&self) -> u64 {
+        self.textures.iter().map(|object| object.bytes).sum()
+    }
+
+    pub fn renderbuffer_bytes(&self) -> u64 {
+        self.renderbuffers.iter().map(|object| object.bytes).sum()
+    }
+
+    pub fn total_bytes(&self) -> u64 {
+        self.buffer_bytes() + self.texture_bytes() + self.renderbuffer_bytes()
+    }
+}
+
+impl<C: HasContext> Default for MemoryUsage<C> {
+    fn default() -> Self {
+        MemoryUsage {
+            buffers: Vec::new(),
+            textures: Vec::new(),
+            renderbuffers: Vec::new(),
+            driver: None,
+        }
+    }
+}
+
+/// Returns the size of a `width` x `height` x `depth` image uploaded with `format` and `ty`,
+/// which are only used if `internal_format` is unsized.
+pub(crate) fn image_bytes(
+    internal_format: u32,
+    format: u32,
+    ty: u32,
+    width: i32,
+    height: i32,
+    depth: i32,
+) -> u64 {
+    let (width, height, depth) = (
+        width.max(0) as u32,
+        height.max(0) as u32,
+        depth.max(0) as u32,
+    );
+    match format::info(internal_format).and_then(|info| info.image_size(width, height, depth)) {
+        Some(size) => size as u64,
+        None => {
+            let pixel_size = format::pixel_size(format, ty).unwrap_or(0);
+            pixel_size as u64 * width as u64 * height as u64 * depth as u64
+        }
+    }
+}
+
+/// Returns the dimensions of mip `level`, where array layers don't shrink.
+fn mip_dimensions(target: u32, (width, height, depth): Dimensions, level: u32) -> Dimensions {
+    let shrink = |size: u32| (size >> level.min(31)).max(1);
+    match target {
+        TEXTURE_1D_ARRAY => (shrink(width), height, depth),
+        TEXTURE_3D => (shrink(width), shrink(height), shrink(depth)),
+        _ => (shrink(width), shrink(height), depth),
+    }
+}
+
+type Dimensions = (u32, u32, u32);
+
+fn dimensions(width: i32, height: i32, depth: i32) -> Dimensions {
+    (
+        width.max(1) as u32,
+        height.max(1) as u32,
+        depth.max(1) as u32,
+    )
+}
+
+fn buffer_binding(target: u32) -> Option<u32> {
+    Some(match target {
+        ARRAY_BUFFER => ARRAY_BUFFER_BINDING,
+        ATOMIC_COUNTER_BUFFER => ATOMIC_COUNTER_BUFFER_BINDING,
+        COPY_READ_BUFFER => COPY_READ_BUFFER_BINDING,
+        COPY_WRITE_BUFFER => COPY_WRITE_BUFFER_BINDING,
+        DISPATCH_INDIRECT_BUFFER => DISPATCH_INDIRECT_BUFFER_BINDING,
+        DRAW_INDIRECT_BUFFER => DRAW_INDIRECT_BUFFER_BINDING,
+        ELEMENT_ARRAY_BUFFER => ELEMENT_ARRAY_BUFFER_BINDING,
+        PIXEL_PACK_BUFFER => PIXEL_PACK_BUFFER_BINDING,
+        PIXEL_UNPACK_BUFFER => PIXEL_UNPACK_BUFFER_BINDING,
+        QUERY_BUFFER => QUERY_BUFFER_BINDING,
+        SHADER_STORAGE_BUFFER => SHADER_STORAGE_BUFFER_BINDING,
+        TEXTURE_BUFFER => TEXTURE_BUFFER_BINDING,
+        TRANSFORM_FEEDBACK_BUFFER => TRANSFORM_FEEDBACK_BUFFER_BINDING,
+        UNIFORM_BUFFER => UNIFORM_BUFFER_BINDING,
+        _ => return None,
+    })
+}
+
+fn texture_binding(target: u32) -> Option<u32> {
+    Some(match target {
+        TEXTURE_1D => TEXTURE_BINDING_1D,
+        TEXTURE_1D_ARRAY => TEXTURE_BINDING_1D_ARRAY,
+        TEXTURE_2D => TEXTURE_BINDING_2D,
+        TEXTURE_2D_ARRAY => TEXTURE_BINDING_2D_ARRAY,
+        TEXTURE_2D_MULTISAMPLE => TEXTURE_BINDING_2D_MULTISAMPLE,
+        TEXTURE_2D_MULTISAMPLE_ARRAY => TEXTURE_BINDING_2D_MULTISAMPLE_ARRAY,
+        TEXTURE_3D => TEXTURE_BINDING_3D,
+        TEXTURE_CUBE_MAP
+        | TEXTURE_CUBE_MAP_POSITIVE_X
+        | TEXTURE_CUBE_MAP_NEGATIVE_X
+        | TEXTURE_CUBE_MAP_POSITIVE_Y
+        | TEXTURE_CUBE_MAP_NEGATIVE_Y
+        | TEXTURE_CUBE_MAP_POSITIVE_Z
+        | TEXTURE_CUBE_MAP_NEGATIVE_Z => TEXTURE_BINDING_CUBE_MAP,
+        TEXTURE_CUBE_MAP_ARRAY => TEXTURE_BINDING_CUBE_MAP_ARRAY,
+        TEXTURE_RECTANGLE => TEXTURE_BINDING_RECTANGLE,
+        _ => return None,
+    })
+}
+
+/// Queries the totals reported by `NVX_gpu_memory_info` or `ATI_meminfo`.
+#[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+pub(crate) unsafe fn driver_memory_info<C: HasContext>(gl: &C) -> Option<DriverMemoryInfo> {
+    let extensions = gl.extensions();
+    let query = |parameter| gl.get_parameter_i32(parameter).max(0) as u64;
+    if extensions.has(Extension::NvxGpuMemoryInfo) {
+        Some(DriverMemoryInfo::Nvidia {
+            dedicated_kib: query(GPU_MEMORY_INFO_DEDICATED_VIDMEM_NVX),
+            total_available_kib: query(GPU_MEMORY_INFO_TOTAL_AVAILABLE_MEMORY_NVX),
+            current_available_kib: query(GPU_MEMORY_INFO_CURRENT_AVAILABLE_VIDMEM_NVX),
+            evicted_kib: query(GPU_MEMORY_INFO_EVICTED_MEMORY_NVX),
+            eviction_count: query(GPU_MEMORY_INFO_EVICTION_COUNT_NVX),
+        })
+    } else if extensions.has(Extension::AtiMeminfo) {
+        // Each query returns the total and largest free block, in main and auxiliary memory
+        let query = |parameter| {
+            let mut values = [0; 4];
+            gl.get_parameter_i32_slice(parameter, &mut values);
+            values[0].max(0) as u64
+        };
+        Some(DriverMemoryInfo::Amd {
+            vbo_free_kib: query(VBO_FREE_MEMORY_ATI),
+            texture_free_kib: query(TEXTURE_FREE_MEMORY_ATI),
+            renderbuffer_free_kib: query(RENDERBUFFER_FREE_MEMORY_ATI),
+        })
+    } else {
+        None
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct TextureLevel {
+    bytes: u64,
+    dimensions: Dimensions,
+}
+
+/// The estimated sizes of the objects in a context, see `Context::set_memory_tracking`.
+#[derive(Debug)]
+pub(crate) struct MemoryTracker<C: HasContext> {
+    state: Mutex<MemoryState<C>>,
+}
+
+#[derive(Debug)]
+struct MemoryState<C: HasContext> {
+    buffers: BTreeMap<C::Buffer, u64>,
+    /// The images of each texture by target (to tell cube map faces apart) and level.
+    textures: BTreeMap<C::Texture, BTreeMap<(u32, u32), TextureLevel>>,
+    /// Textures allocated with `tex_storage_*`, which already have every level.
+    immutable_textures: BTreeSet<C::Texture>,
+    renderbuffers: BTreeMap<C::Renderbuffer, u64>,
+}
+
+impl<C: HasContext> MemoryTracker<C> {
+    pub(crate) fn new() -> Self {
+        MemoryTracker {
+            state: Mutex::new(MemoryState {
+                buffers: BTreeMap::new(),
+                textures: BTreeMap::new(),
+                immutable_textures: BTreeSet::new(),
+                renderbuffers: BTreeMap::new(),
+            }),
+        }
+    }
+
+    pub(crate) fn buffer(&self, buffer: C::Buffer, bytes: i32) {
+        let mut state = self.state.lock();
+        state.buffers.insert(buffer, bytes.max(0) as u64);
+    }
+
+    /// Records the storage of the buffer bound to `target`.
+    pub(crate) unsafe fn bound_buffer(&self, gl: &C, target: u32, bytes: i32) {
+        if let Some(buffer) =
+            buffer_binding(target).and_then(|binding| gl.get_parameter_buffer(binding))
+        {
+            self.buffer(buffer, bytes);
+        }
+    }
+
+    /// Records a single level of a texture, as allocated by `tex_image_*`.
+    pub(crate) fn texture_level(
+        &self,
+        texture: C::Texture,
+        target: u32,
+        level: i32,
+        bytes: u64,
+        (width, height, depth): (i32, i32, i32),
+    ) {
+        let image = TextureLevel {
+            bytes,
+            dimensions: dimensions(width, height, depth),
+        };
+        let mut state = self.state.lock();
+        let levels = state.textures.entry(texture).or_default();
+        levels.insert((target, level.max(0) as u32), image);
+    }
+
+    pub(crate) unsafe fn bound_texture_level(
+        &self,
+        gl: &C,
+        target: u32,
+        level: i32,
+        bytes: u64,
+        dimensions: (i32, i32, i32),
+    ) {
+        if let Some(texture) =
+            texture_binding(target).and_then(|binding| gl.get_parameter_texture(binding))
+        {
+            self.texture_level(texture, target, level, bytes, dimensions);
+        }
+    }
+
+    /// Records the immutable storage of a texture, as allocated by `tex_storage_*`.
+    pub(crate) fn texture_storage(
+        &self,
+        texture: C::Texture,
+        target: u32,
+        levels: i32,
+        samples: i32,
+        internal_format: u32,
+        (width, height, depth): (i32, i32, i32),
+    ) {
+        let faces = if target == TEXTURE_CUBE_MAP { 6 } else { 1 };
+        let samples = samples.max(1) as u64;
+        let base = dimensions(width, height, depth);
+        let storage = (0..levels.max(1) as u32)
+            .map(|level| {
+                let dimensions = mip_dimensions(target, base, level);
+                let (width, height, depth) = dimensions;
+                let bytes = image_bytes(
+                    internal_format,
+                    0,
+                    0,
+                    width as i32,
+                    height as i32,
+                    depth as i32,
+                );
+                let image = TextureLevel {
+                    bytes: bytes * faces * samples,
+                    dimensions,
+                };
+                ((target, level), image)
+            })
+            .collect();
+        let mut state = self.state.lock();
+        state.textures.insert(texture, storage);
+        state.immutable_textures.insert(texture);
+    }
+
+    pub(crate) unsafe fn bound_texture_storage(
+        &self,
+        gl: &C,
+        target: u32,
+        levels: i32,
+        samples: i32,
+        internal_format: u32,
+        dimensions: (i32, i32, i32),
+    ) {
+        if let Some(texture) =
+            texture_binding(target).and_then(|binding| gl.get_parameter_texture(binding))
+        {
+            self.texture_storage(
+                texture,
+                target,
+                levels,
+                samples,
+                internal_format,
+                dimensions,
+            );
+        }
+    }
+
+    /// Records the mip chain below level 0 of every image of a texture, as allocated by
+    /// `generate_mipmap`.
+    pub(crate) fn generate_mipmap(&self, texture: C::Texture) {
+        let mut state = self.state.lock();
+        if state.immutable_textures.contains(&texture) {
+            return;
+        }
+        let levels = match state.textures.get_mut(&texture) {
+            Some(levels) => levels,
+            None => return,
+        };
+        let bases: Vec<_> = levels
+            .iter()
+            .filter(|&(&(_, level), _)| level == 0)
+            .map(|(&(target, _), &base)| (target, base))
+            .collect();
+        for (target, base) in bases {
+            let (width, height, depth) = base.dimensions;
+            let texel_bytes = base.bytes / (width as u64 * height as u64 * depth as u64);
+            let level_count = 32
+                - width
+                    .max(height)
+                    .max(if target == TEXTURE_3D { depth } else { 1 })
+                    .leading_zeros();
+            for level in 1..level_count {
+                let dimensions = mip_dimensions(target, base.dimensions, level);
+                let (width, height, depth) = dimensions;
+                let bytes = texel_bytes * width as u64 * height as u64 * depth as u64;
+                levels.insert((target, level), TextureLevel { bytes, dimensions });
+            }
+        }
+    }
+
+    pub(crate) unsafe fn bound_generate_mipmap(&self, gl: &C, target: u32) {
+        if let Some(texture) =
+            texture_binding(target).and_then(|binding| gl.get_parameter_texture(binding))
+        {
+            self.generate_mipmap(texture);
+        }
+    }
+
+    /// Records the storage of the bound renderbuffer.
+    pub(crate) unsafe fn bound_renderbuffer(
+        &self,
+        gl: &C,
+        samples: i32,
+        internal_format: u32,
+        width: i32,
+        height: i32,
+    ) {
+        if let Some(renderbuffer) = gl.get_parameter_renderbuffer(RENDERBUFFER_BINDING) {
+            let bytes =
+                image_bytes(internal_format, 0, 0, width, height, 1) * samples.max(1) as u64;
+            let mut state = self.state.lock();
+            state.renderbuffers.insert(renderbuffer, bytes);
+        }
+    }
+
+    pub(crate) fn delete_buffer(&self, buffer: C::Buffer) {
+        self.state.lock().buffers.remove(&buffer);
+    }
+
+    pub(crate) fn delete_texture(&self, texture: C::Texture) {
+        let mut state = self.state.lock();
+        state.textures.remove(&texture);
+        state.immutable_textures.remove(&texture);
+    }
+
+    pub(crate) fn delete_renderbuffer(&self, renderbuffer: C::Renderbuffer) {
+        self.state.lock().renderbuffers.remove(&renderbuffer);
+    }
+
+    /// Returns the estimated sizes, sorted by handle, without the driver totals.
+    pub(crate) fn usage(&self) -> MemoryUsage<C> {
+        fn sorted<H: Ord + Copy>(objects: impl Iterator<Item = (H, u64)>) -> Vec<ObjectMemory<H>> {
+            let mut objects: Vec<_> = objects
+                .map(|(handle, bytes)| ObjectMemory { handle, bytes })
+                .collect();
+            objects.sort_unstable_by_key(|object| object.handle);
+            objects
+        }
+
+        let state = self.state.lock();
+        MemoryUsage {
+            buffers: sorted(
+                state
+                    .buffers
+                    .iter()
+                    .map(|(&buffer, &bytes)| (buffer, bytes)),
+            ),
+            textures: sorted(state.textures.iter().map(|(&texture, levels)| {
+                (texture, levels.values().map(|level| level.bytes).sum())
+            })),
+            renderbuffers: sorted(
+                state
+                    .renderbuffers
+                    .iter()
+                    .map(|(&renderbuffer, &bytes)| (renderbuffer, bytes)),
+            ),
+            driver: None,
+        }
+    }
+}
+
+#[cfg(all(test, any(not(target_arch = "wasm32"), target_os = "emscripten")))]
+mod tests {
+    use super::*;
+    use core::num::NonZeroU32;
+
+    #[test]
+    fn test_image_bytes() {
+        assert_eq!(image_bytes(RGBA8, RGBA, UNSIGNED_BYTE, 4, 4, 1), 64);
+        // Unsized formats use the pixel transfer format and type
+        assert_eq!(image_bytes(RGBA, RGBA, UNSIGNED_SHORT_4_4_4_4, 4, 4, 1), 32);
+        assert_eq!(
+            image_bytes(COMPRESSED_RGBA_S3TC_DXT5_EXT, 0, 0, 5, 5, 1),
+            64
+        );
+        assert_eq!(image_bytes(0xFFFF, 0, 0, 4, 4, 1), 0);
+    }
+
+    #[test]
+    fn test_memory_tracker() {
+        let texture = NativeTexture(NonZeroU32::new(1).unwrap());
+        let cube = NativeTexture(NonZeroU32::new(2).unwrap());
+        let buffer = NativeBuffer(NonZeroU32::new(3).unwrap());
+        let memory = MemoryTracker::<Context>::new();
+
+        // 4x4 + 2x2 + 1x1 RGBA8
+        memory.texture_storage(texture, TEXTURE_2D, 3, 1, RGBA8, (4, 4, 1));
+        memory.texture_storage(cube, TEXTURE_CUBE_MAP, 1, 4, R8, (2, 2, 1));
+        memory.buffer(buffer, 100);
+        let usage = memory.usage();
+        assert_eq!(usage.texture_bytes(), 84 + 6 * 4 * 4);
+        assert_eq!(
+            usage.buffers,
+            vec![ObjectMemory {
+                handle: buffer,
+                bytes: 100
+            }]
+        );
+        assert_eq!(usage.total_bytes(), 84 + 96 + 100);
+
+        // Mipmaps of immutable textures are already counted
+        memory.generate_mipmap(texture);
+        assert_eq!(memory.usage().texture_bytes(), 84 + 96);
+
+        // 8x4 + 4x2 + 2x1 + 1x1 RGBA8
+        let mutable = NativeTexture(NonZeroU32::new(4).unwrap());
+        memory.texture_level(mutable, TEXTURE_2D, 0, 128, (8, 4, 1));
+        memory.generate_mipmap(mutable);
+        assert_eq!(memory.usage().texture_bytes(), 84 + 96 + 172);
+
+        memory.delete_texture(texture);
+        memory.delete_texture(mutable);
+        memory.delete_buffer(buffer);
+        let usage = memory.usage();
+        assert_eq!(usage.textures.len(), 1);
+        assert_eq!(usage.buffer_bytes(), 0);
+    }
+}
@@ -1,13 +1,15 @@
 use super::*;
-use crate::{gl46 as native_gl, version::Version};
-use std::ffi::CStr;
-use std::ptr;
-use std::{collections::HashSet, ffi::CString, num::NonZeroU32};
-
-#[derive(Default)]
-struct Constants {
-    max_label_length: i32,
-}
+use crate::format::{PixelStore, PixelStoreState};
+use crate::memory::{self, MemoryTracker};
+use crate::sync::Mutex;
+use crate::tracking::{self, ObjectTracker};
+use crate::{gl46 as native_gl, gl46_aliases, version::Version};
+use alloc::borrow::ToOwned;
+use alloc::ffi::CString;
+use alloc::sync::Arc;
+use core::ffi::CStr;
+use core::num::NonZeroU32;
+use core::ptr;
 
 /// Store a boxed callback (i.e., `Box<Box<dyn FnMut(...)>>`) as a raw pointer, so that it can be
 /// referenced by the C API and later converted back into a `Box` and dropped.
@@ -16,7 +18,7 @@ struct Constants {
 /// guarantee that it's not undefined behavior to keep a `Box` here while it's used as a raw
 /// pointer in the C API.
 struct DebugCallbackRawPtr {
-    callback: *mut std::os::raw::c_void,
+    callback: *mut core::ffi::c_void,
 }
 
 unsafe impl Send for DebugCallbackRawPtr {}
@@ -33,45 +35,199 @@ impl Drop for DebugCallbackRawPtr {
     }
 }
 
+/// Loaded GL function pointers which can be shared by several [`Context`]s.
+///
+/// Contexts created by the same driver usually share the same function pointers, so this avoids
+/// looking up every entry point again when creating another context.
+#[derive(Clone, Debug)]
+pub struct SharedFunctions(Arc<native_gl::GlFns>);
+
 pub struct Context {
-    raw: native_gl::GlFns,
-    extensions: HashSet<String>,
-    constants: Constants,
+    raw: Arc<native_gl::GlFns>,
+    extensions: BTreeSet<String>,
+    extension_set: Extensions,
+    features: Features,
+    limits: Limits,
     version: Version,
+    driver_info: DriverInfo,
+    workarounds: Workarounds,
     debug_callback: Option<DebugCallbackRawPtr>,
+    tracker: Option<Arc<ObjectTracker>>,
+    validation: bool,
+    #[cfg(feature = "log")]
+    leak_warning: bool,
+    memory: Option<MemoryTracker<Context>>,
+    pixel_store: Mutex<PixelStoreState>,
+    #[cfg(debug_assertions)]
+    uniforms: uniform::UniformCache<Context>,
+}
+
+/// An error returned when a [`Context`] can't be created.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ContextCreationError {
+    /// `GL_VERSION` couldn't be read, usually because there is no GL context currently active.
+    MissingVersion,
+    /// `GL_VERSION` isn't valid UTF-8.
+    InvalidVersionEncoding(Vec<u8>),
+    /// `GL_VERSION` couldn't be parsed as a [`Version`].
+    ///
+    /// A version can be passed to the `try_from_*` constructors explicitly to work around this.
+    UnparseableVersion(String),
+    /// The windowing system or platform library couldn't create a context, e.g. when EGL isn't
+    /// available for `Context::new_headless_egl`.
+    Platform(String),
+}
+
+impl core::fmt::Display for ContextCreationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            ContextCreationError::MissingVersion => write!(
+                f,
+                "Reading GL_VERSION failed. Make sure there is a valid GL context currently active."
+            ),
+            ContextCreationError::InvalidVersionEncoding(bytes) => write!(
+                f,
+                "GL_VERSION is not valid UTF-8: {}",
+                String::from_utf8_lossy(bytes)
+            ),
+            ContextCreationError::UnparseableVersion(version) => {
+                write!(f, "GL_VERSION could not be parsed: {:?}", version)
+            }
+            ContextCreationError::Platform(message) => {
+                write!(f, "Creating the platform context failed: {}", message)
+            }
+        }
+    }
 }
 
+#[cfg(feature = "std")]
+impl std::error::Error for ContextCreationError {}
+
 impl Context {
-    pub unsafe fn from_loader_function_cstr<F>(mut loader_function: F) -> Self
+    pub unsafe fn from_loader_function_cstr<F>(loader_function: F) -> Self
+    where
+        F: FnMut(&CStr) -> *const core::ffi::c_void,
+    {
+        Self::try_from_loader_function_cstr(loader_function, None)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`Context::from_loader_function_cstr`], but returns an error instead of panicking if
+    /// `GL_VERSION` can't be read or parsed.
+    ///
+    /// If `version` is provided it's used instead of parsing `GL_VERSION`, which is useful for
+    /// drivers that report versions in an unexpected format.
+    pub unsafe fn try_from_loader_function_cstr<F>(
+        mut loader_function: F,
+        version: Option<Version>,
+    ) -> Result<Self, ContextCreationError>
+    where
+        F: FnMut(&CStr) -> *const core::ffi::c_void,
+    {
+        let raw: native_gl::GlFns = native_gl::GlFns::load_with(|p: *const core::ffi::c_char| {
+            let c_str = core::ffi::CStr::from_ptr(p);
+            load_with_aliases(&mut loader_function, c_str) as *mut core::ffi::c_void
+        });
+        Self::try_from_shared_functions(SharedFunctions(Arc::new(raw)), version)
+    }
+
+    /// Like [`Context::from_loader_function_cstr`], but each function pointer is only looked up
+    /// the first time that it's called.
+    ///
+    /// This makes creating a context much cheaper when only a few GL functions are used, at the
+    /// cost of keeping the loader function alive for as long as the context.
+    pub unsafe fn from_loader_function_cstr_lazy<F>(loader_function: F) -> Self
+    where
+        F: Fn(&CStr) -> *const core::ffi::c_void + Send + Sync + 'static,
+    {
+        Self::try_from_loader_function_cstr_lazy(loader_function, None)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like [`Context::from_loader_function_cstr_lazy`], but returns an error instead of
+    /// panicking, see [`Context::try_from_loader_function_cstr`].
+    pub unsafe fn try_from_loader_function_cstr_lazy<F>(
+        loader_function: F,
+        version: Option<Version>,
+    ) -> Result<Self, ContextCreationError>
     where
-        F: FnMut(&CStr) -> *const std::os::raw::c_void,
+        F: Fn(&CStr) -> *const core::ffi::c_void + Send + Sync + 'static,
     {
         let raw: native_gl::GlFns =
-            native_gl::GlFns::load_with(|p: *const std::os::raw::c_char| {
-                let c_str = std::ffi::CStr::from_ptr(p);
-                loader_function(c_str) as *mut std::os::raw::c_void
+            native_gl::GlFns::load_lazily_with(move |p: *const core::ffi::c_char| {
+                let c_str = core::ffi::CStr::from_ptr(p);
+                load_with_aliases(&mut |name| loader_function(name), c_str)
+                    as *mut core::ffi::c_void
             });
+        Self::try_from_shared_functions(SharedFunctions(Arc::new(raw)), version)
+    }
 
-        // Retrieve and parse `GL_VERSION`
-        let raw_string = raw.GetString(VERSION);
+    /// Creates a context which reuses the function pointers already loaded by another context.
+    ///
+    /// The GL context that the functions were loaded from must be compatible with the one that is
+    /// currently active, e.g. created by the same driver.
+    pub unsafe fn from_shared_functions(functions: SharedFunctions) -> Self {
+        Self::try_from_shared_functions(functions, None).unwrap_or_else(|err| panic!("{}", err))
+    }
 
-        if raw_string.is_null() {
-            panic!("Reading GL_VERSION failed. Make sure there is a valid GL context currently active.")
-        }
+    /// Like [`Context::from_shared_functions`], but returns an error instead of panicking if
+    /// `GL_VERSION` can't be read or parsed.
+    ///
+    /// If `version` is provided it's used instead of parsing `GL_VERSION`.
+    pub unsafe fn try_from_shared_functions(
+        functions: SharedFunctions,
+        version: Option<Version>,
+    ) -> Result<Self, ContextCreationError> {
+        let raw = functions.0;
+
+        let version = match version {
+            Some(version) => version,
+            None => {
+                // Retrieve and parse `GL_VERSION`
+                if !raw.GetString_is_loaded() {
+                    return Err(ContextCreationError::MissingVersion);
+                }
+                let raw_string = raw.GetString(VERSION);
+                if raw_string.is_null() {
+                    return Err(ContextCreationError::MissingVersion);
+                }
 
-        let raw_version = std::ffi::CStr::from_ptr(raw_string as *const native_gl::GLchar)
-            .to_str()
-            .unwrap()
-            .to_owned();
-        let version = Version::parse(&raw_version).unwrap();
+                let raw_version = core::ffi::CStr::from_ptr(raw_string as *const native_gl::GLchar);
+                let raw_version = raw_version.to_str().map_err(|_| {
+                    ContextCreationError::InvalidVersionEncoding(raw_version.to_bytes().to_vec())
+                })?;
+                Version::parse(raw_version)
+                    .map_err(|_| ContextCreationError::UnparseableVersion(raw_version.to_owned()))?
+            }
+        };
+
+        let driver_info = DriverInfo {
+            vendor: get_string_lossy(&raw, VENDOR).unwrap_or_default(),
+            renderer: get_string_lossy(&raw, RENDERER).unwrap_or_default(),
+            version: version.clone(),
+            glsl: get_string_lossy(&raw, SHADING_LANGUAGE_VERSION).and_then(|s| s.parse().ok()),
+            is_webgl: false,
+        };
 
         // Setup extensions and constants after the context has been built
         let mut context = Self {
             raw,
-            extensions: HashSet::new(),
-            constants: Constants::default(),
+            extensions: BTreeSet::new(),
+            extension_set: Extensions::new(),
+            features: Features::default(),
+            limits: Limits::default(),
             version,
+            workarounds: Workarounds::new(&driver_info),
+            driver_info,
             debug_callback: None,
+            tracker: None,
+            validation: false,
+            #[cfg(feature = "log")]
+            leak_warning: false,
+            memory: None,
+            pixel_store: Mutex::default(),
+            #[cfg(debug_assertions)]
+            uniforms: uniform::UniformCache::new(),
         };
 
         // Use core-only functions to populate extension list
@@ -92,25 +248,264 @@ impl Context {
                     .map(|s| s.to_string()),
             );
         };
+        context.extension_set =
+            Extensions::from_names(context.extensions.iter().map(String::as_str));
 
-        // After the extensions are known, we can populate constants (including
-        // constants that depend on extensions being enabled)
-        context.constants.max_label_length = if context.supports_debug() {
-            context.get_parameter_i32(MAX_LABEL_LENGTH)
-        } else {
-            0
-        };
+        // After the extensions are known, we can populate features and limits (including
+        // limits that depend on extensions being enabled)
+        context.features = Features::new(&context.version, &context.extension_set, false);
+        context.limits = Limits::query(&context, &context.features);
+        context.pixel_store = Mutex::new(PixelStoreState::query(&context));
 
-        context
+        Ok(context)
     }
 
     pub unsafe fn from_loader_function<F>(mut loader_function: F) -> Self
     where
-        F: FnMut(&str) -> *const std::os::raw::c_void,
+        F: FnMut(&str) -> *const core::ffi::c_void,
     {
         Self::from_loader_function_cstr(move |name| loader_function(name.to_str().unwrap()))
     }
 
+    /// Like [`Context::from_loader_function`], but returns an error instead of panicking if
+    /// `GL_VERSION` can't be read or parsed.
+    ///
+    /// See [`Context::try_from_loader_function_cstr`] for details.
+    pub unsafe fn try_from_loader_function<F>(
+        mut loader_function: F,
+        version: Option<Version>,
+    ) -> Result<Self, ContextCreationError>
+    where
+        F: FnMut(&str) -> *const core::ffi::c_void,
+    {
+        Self::try_from_loader_function_cstr(
+            move |name| loader_function(name.to_str().unwrap()),
+            version,
+        )
+    }
+
+    /// Returns the function pointers used by this context, so that they can be shared with
+    /// [`Context::from_shared_functions`].
+    pub fn shared_functions(&self) -> SharedFunctions {
+        SharedFunctions(self.raw.clone())
+    }
+
+    /// Replaces the driver workarounds detected when the context was created.
+    pub fn set_workarounds(&mut self, workarounds: Workarounds) {
+        self.workarounds = workarounds;
+    }
+
+    /// Enables or disables validation of object handles and pixel data, which is meant for debug
+    /// builds.
+    ///
+    /// While enabled, the context tracks the objects it creates and deletes, and methods panic
+    /// with their name when passed a handle that has been deleted, was never created, or is live
+    /// in another context with validation enabled. GL names are reused, so a handle from another
+    /// context is only recognized if its name isn't also live in this one. Objects that existed
+    /// before validation was enabled are adopted the first time they're used.
+    ///
+    /// Pixel transfer methods always panic when given a slice that is too short for the
+    /// dimensions, format and type, taking the pixel store parameters into account. While
+    /// validation is enabled, they also panic on unknown format and type pairs and on compressed
+    /// image sizes that don't match the format. See [`format::check_unpack`] to get an error
+    /// instead.
+    pub fn set_validation(&mut self, enabled: bool) {
+        self.validation = enabled;
+        self.update_tracker();
+    }
+
+    /// Returns whether validation of object handles is enabled, see [`Context::set_validation`].
+    pub fn validation(&self) -> bool {
+        self.validation
+    }
+
+    /// Enables or disables a warning through `log` when the context is dropped while objects
+    /// haven't been deleted, listing them like [`Context::live_objects`].
+    ///
+    /// Only objects created after enabling the warning (or validation) are tracked.
+    #[cfg(feature = "log")]
+    pub fn set_leak_warning(&mut self, enabled: bool) {
+        self.leak_warning = enabled;
+        self.update_tracker();
+    }
+
+    /// Returns the objects that haven't been deleted, with their labels if `KHR_debug` is
+    /// supported.
+    ///
+    /// Native contexts only track objects while validation (see [`Context::set_validation`]) or
+    /// the leak warning is enabled, and return an empty census otherwise.
+    pub unsafe fn live_objects(&self) -> ObjectCensus<Self> {
+        let mut census = ObjectCensus::default();
+        let tracker = match &self.tracker {
+            Some(tracker) => tracker,
+            None => return census,
+        };
+        for (kind, name) in tracker.live() {
+            let label = |identifier| self.tracked_object_label(identifier, name);
+            match kind {
+                ObjectKind::Shader => census.shaders.push(LiveObject {
+                    handle: NativeShader(non_zero_gl_name(name as u32)),
+                    label: label(SHADER),
+                }),
+                ObjectKind::Program => census.programs.push(LiveObject {
+                    handle: NativeProgram(non_zero_gl_name(name as u32)),
+                    label: label(PROGRAM),
+                }),
+                ObjectKind::Buffer => census.buffers.push(LiveObject {
+                    handle: NativeBuffer(non_zero_gl_name(name as u32)),
+                    label: label(BUFFER),
+                }),
+                ObjectKind::VertexArray => census.vertex_arrays.push(LiveObject {
+                    handle: NativeVertexArray(non_zero_gl_name(name as u32)),
+                    label: label(VERTEX_ARRAY),
+                }),
+                ObjectKind::Texture => census.textures.push(LiveObject {
+                    handle: NativeTexture(non_zero_gl_name(name as u32)),
+                    label: label(TEXTURE),
+                }),
+                ObjectKind::Sampler => census.samplers.push(LiveObject {
+                    handle: NativeSampler(non_zero_gl_name(name as u32)),
+                    label: label(SAMPLER),
+                }),
+                ObjectKind::Fence => {
+                    let fence = NativeFence(name as usize as native_gl::GLsync);
+                    census.fences.push(LiveObject {
+                        handle: fence,
+                        label: if self.features.debug {
+                            Some(self.get_object_ptr_label(fence)).filter(|l| !l.is_empty())
+                        } else {
+                            None
+                        },
+                    })
+                }
+                ObjectKind::Framebuffer => census.framebuffers.push(LiveObject {
+                    handle: NativeFramebuffer(non_zero_gl_name(name as u32)),
+                    label: label(FRAMEBUFFER),
+                }),
+                ObjectKind::Renderbuffer => census.renderbuffers.push(LiveObject {
+                    handle: NativeRenderbuffer(non_zero_gl_name(name as u32)),
+                    label: label(RENDERBUFFER),
+                }),
+                ObjectKind::Query => census.queries.push(LiveObject {
+                    handle: NativeQuery(non_zero_gl_name(name as u32)),
+                    label: label(QUERY),
+                }),
+                ObjectKind::TransformFeedback => census.transform_feedbacks.push(LiveObject {
+                    handle: NativeTransformFeedback(non_zero_gl_name(name as u32)),
+                    label: label(TRANSFORM_FEEDBACK),
+                }),
+            }
+        }
+        census
+    }
+
+    /// Enables or disables estimating the memory used by buffers, textures and renderbuffers, see
+    /// [`Context::memory_usage`].
+    ///
+    /// Only storage allocated while tracking is enabled is counted. Allocations through a target
+    /// are attributed to the object bound to it, which is queried on every allocation.
+    pub fn set_memory_tracking(&mut self, enabled: bool) {
+        if !enabled {
+            self.memory = None;
+        } else if self.memory.is_none() {
+            self.memory = Some(MemoryTracker::new());
+        }
+    }
+
+    /// Returns the estimated size of every buffer, texture and renderbuffer allocated while
+    /// memory tracking was enabled, along with the totals reported by `NVX_gpu_memory_info` or
+    /// `ATI_meminfo` if they're supported.
+    pub unsafe fn memory_usage(&self) -> MemoryUsage<Self> {
+        let mut usage = match &self.memory {
+            Some(memory) => memory.usage(),
+            None => MemoryUsage::default(),
+        };
+        usage.driver = memory::driver_memory_info(self);
+        usage
+    }
+
+    /// Returns the header for a shader using `features`, see [`ShaderFeature`].
+    pub fn glsl_preamble(&self, features: &[ShaderFeature]) -> String {
+        glsl::preamble(&self.driver_info, &self.extension_set, features)
+    }
+
+    /// Starts compiling and linking a program from `(shader_type, source)` pairs without waiting
+    /// for the driver, see [`PendingProgram`].
+    ///
+    /// Without `KHR_parallel_shader_compile` this falls back to blocking compilation when the
+    /// `PendingProgram` is first polled.
+    ///
+    /// # Safety
+    ///
+    /// The context must be current whenever the returned `PendingProgram` is polled or dropped.
+    pub unsafe fn compile_program_async(
+        &self,
+        sources: &[(u32, &str)],
+    ) -> PendingProgram<'_, Self> {
+        PendingProgram::new(self, sources)
+    }
+
+    /// Returns the attributes, uniforms, samplers, uniform and storage blocks and transform
+    /// feedback varyings of a linked program, see [`ProgramReflection`].
+    ///
+    /// Parts that the context doesn't support, e.g. storage blocks on WebGL 2, are left empty.
+    pub unsafe fn reflect_program(&self, program: NativeProgram) -> ProgramReflection<Self> {
+        reflection::reflect_program(self, program)
+    }
+
+    /// Sets the uniform at `location` in the current program to `value`, using the
+    /// `uniform_*` method for its type.
+    ///
+    /// Debug builds check that `value` matches the type and array size that `get_active_uniform`
+    /// reports for the uniform, and panic otherwise. The active uniforms are queried once per
+    /// linked program.
+    ///
+    /// Panics if `value` is an array with nested arrays or elements of different types.
+    pub unsafe fn set_uniform(
+        &self,
+        location: Option<&NativeUniformLocation>,
+        value: &UniformValue,
+    ) {
+        #[cfg(debug_assertions)]
+        if let Some(location) = location {
+            self.uniforms
+                .check(self, "set_uniform", None, location, value);
+        }
+        uniform::set_uniform(self, None, location, value)
+    }
+
+    /// Like [`Context::set_uniform`], but sets a uniform of `program` with the
+    /// `program_uniform_*` methods.
+    pub unsafe fn set_program_uniform(
+        &self,
+        program: NativeProgram,
+        location: Option<&NativeUniformLocation>,
+        value: &UniformValue,
+    ) {
+        #[cfg(debug_assertions)]
+        if let Some(location) = location {
+            self.uniforms
+                .check(self, "set_program_uniform", Some(program), location, value);
+        }
+        uniform::set_uniform(self, Some(program), location, value)
+    }
+
+    /// Sets up the buffers and attributes of `vao` as described by `layout`.
+    ///
+    /// Uses separate attribute formats and vertex buffer bindings when available, otherwise
+    /// `vertex_attrib_pointer_*`. The current vertex array and `ARRAY_BUFFER` bindings are left
+    /// unchanged.
+    ///
+    /// Returns an error without touching `vao` if the layout is invalid or needs features the
+    /// context doesn't support.
+    pub unsafe fn apply_vertex_layout(
+        &self,
+        vao: NativeVertexArray,
+        layout: &VertexLayout<Self>,
+    ) -> Result<(), String> {
+        vertex_layout::apply_vertex_layout(self, vao, layout)
+    }
+
     /// Creates a texture from an external GL name.
     ///
     /// This can be useful when a texture is created outside of glow (e.g. OpenXR surface) but glow
@@ -129,6 +524,87 @@ impl Context {
         NativeFramebuffer(non_zero_gl_name(gl_name))
     }
 
+    /// Objects are tracked while validation or the leak warning is enabled.
+    fn update_tracker(&mut self) {
+        #[cfg(feature = "log")]
+        let enabled = self.validation || self.leak_warning;
+        #[cfg(not(feature = "log"))]
+        let enabled = self.validation;
+        if !enabled {
+            self.tracker = None;
+        } else if self.tracker.is_none() {
+            let tracker = Arc::new(ObjectTracker::default());
+            ObjectTracker::register(&tracker);
+            self.tracker = Some(tracker);
+        }
+    }
+
+    unsafe fn tracked_object_label(&self, identifier: u32, name: u64) -> Option<String> {
+        if !self.features.debug {
+            return None;
+        }
+        Some(self.get_object_label(identifier, name as u32)).filter(|label| !label.is_empty())
+    }
+
+    #[track_caller]
+    fn validate(&self, handle: impl TrackedHandle, method: &str) {
+        if !self.validation {
+            return;
+        }
+        if let (Some(tracker), Some((kind, name))) = (&self.tracker, handle.tracked()) {
+            if let Err(problem) = tracker.check(kind, name, || self.object_exists(kind, name)) {
+                tracking::invalid_handle(method, kind, &name, problem);
+            }
+        }
+    }
+
+    #[track_caller]
+    fn validate_delete(&self, handle: impl TrackedHandle, method: &str) {
+        if let (Some(tracker), Some((kind, name))) = (&self.tracker, handle.tracked()) {
+            let result = tracker.delete(kind, name, || self.object_exists(kind, name));
+            if let (true, Err(problem)) = (self.validation, result) {
+                tracking::invalid_handle(method, kind, &name, problem);
+            }
+        }
+    }
+
+    fn track_created<H: TrackedHandle>(&self, result: Result<H, String>) -> Result<H, String> {
+        if let (Some(tracker), Ok(Some((kind, name)))) =
+            (&self.tracker, result.as_ref().map(TrackedHandle::tracked))
+        {
+            tracker.created(kind, name);
+        }
+        result
+    }
+
+    /// Returns the target a texture was first bound to, for direct state access functions.
+    unsafe fn texture_target(&self, texture: NativeTexture) -> u32 {
+        let gl = &self.raw;
+        let mut target = 0;
+        gl.GetTextureParameteriv(texture.0.get(), TEXTURE_TARGET, &mut target);
+        target as u32
+    }
+
+    /// Asks the driver whether an object exists, so that untracked objects can be adopted.
+    fn object_exists(&self, kind: ObjectKind, name: u64) -> bool {
+        let gl = &self.raw;
+        unsafe {
+            match kind {
+                ObjectKind::Shader => gl.IsShader(name as u32) != 0,
+                ObjectKind::Program => gl.IsProgram(name as u32) != 0,
+                ObjectKind::Buffer => gl.IsBuffer(name as u32) != 0,
+                ObjectKind::VertexArray => gl.IsVertexArray(name as u32) != 0,
+                ObjectKind::Texture => gl.IsTexture(name as u32) != 0,
+                ObjectKind::Sampler => gl.IsSampler(name as u32) != 0,
+                ObjectKind::Fence => gl.IsSync(name as usize as native_gl::GLsync) != 0,
+                ObjectKind::Framebuffer => gl.IsFramebuffer(name as u32) != 0,
+                ObjectKind::Renderbuffer => gl.IsRenderbuffer(name as u32) != 0,
+                ObjectKind::Query => gl.IsQuery(name as u32) != 0,
+                ObjectKind::TransformFeedback => gl.IsTransformFeedback(name as u32) != 0,
+            }
+        }
+    }
+
     unsafe fn get_parameter_gl_name(&self, parameter: u32) -> Option<NonZeroU32> {
         let value = self.get_parameter_i32(parameter) as u32;
         if value == 0 {
@@ -139,16 +615,102 @@ impl Context {
     }
 }
 
-impl std::fmt::Debug for Context {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::fmt::Debug for Context {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         write!(f, "Native_GL_Context")
     }
 }
 
+/// Loads `name`, falling back to its promoted-extension aliases (in `ARB`, `EXT`, `OES`, `KHR`,
+/// `APPLE`, `ANGLE`, `NV` order) if the driver only exports the suffixed entry point.
+unsafe fn load_with_aliases<F>(loader_function: &mut F, name: &CStr) -> *const core::ffi::c_void
+where
+    F: FnMut(&CStr) -> *const core::ffi::c_void,
+{
+    // Some loaders return small or all-ones values instead of null for missing symbols, which is
+    // also rejected by the generated bindings.
+    fn is_loaded(p: *const core::ffi::c_void) -> bool {
+        let p = p as usize;
+        p != usize::MAX && p >= 8
+    }
+
+    let p = loader_function(name);
+    if is_loaded(p) {
+        return p;
+    }
+    let aliases =
+        match gl46_aliases::ALIASES.binary_search_by(|(core, _)| core.cmp(&name.to_bytes())) {
+            Ok(index) => gl46_aliases::ALIASES[index].1,
+            Err(_) => return p,
+        };
+    aliases
+        .iter()
+        .map(|alias| loader_function(CStr::from_bytes_with_nul_unchecked(alias)))
+        .find(|&alias_p| is_loaded(alias_p))
+        .unwrap_or(p)
+}
+
+/// Reads a `glGetString` parameter, returning `None` instead of panicking if it isn't available,
+/// including when `glGetString` itself isn't loaded because the version was given explicitly.
+unsafe fn get_string_lossy(gl: &native_gl::GlFns, parameter: u32) -> Option<String> {
+    if !gl.GetString_is_loaded() {
+        return None;
+    }
+    let raw_ptr = gl.GetString(parameter);
+    if raw_ptr.is_null() {
+        None
+    } else {
+        let c_str = CStr::from_ptr(raw_ptr as *const native_gl::GLchar);
+        Some(c_str.to_string_lossy().into_owned())
+    }
+}
+
 fn non_zero_gl_name(value: native_gl::GLuint) -> NonZeroU32 {
     NonZeroU32::new(value as u32).expect("expected non-zero GL name")
 }
 
+/// A handle that can be checked by the validation mode, see [`Context::set_validation`].
+trait TrackedHandle {
+    fn tracked(&self) -> Option<(ObjectKind, u64)>;
+}
+
+impl<H: TrackedHandle> TrackedHandle for Option<H> {
+    fn tracked(&self) -> Option<(ObjectKind, u64)> {
+        self.as_ref().and_then(TrackedHandle::tracked)
+    }
+}
+
+macro_rules! tracked_handles {
+    ($($handle:ident => $kind:ident,)*) => {
+        $(
+            impl TrackedHandle for $handle {
+                fn tracked(&self) -> Option<(ObjectKind, u64)> {
+                    Some((ObjectKind::$kind, self.0.get() as u64))
+                }
+            }
+        )*
+    };
+}
+
+tracked_handles! {
+    NativeShader => Shader,
+    NativeProgram => Program,
+    NativeBuffer => Buffer,
+    NativeVertexArray => VertexArray,
+    NativeTexture => Texture,
+    NativeSampler => Sampler,
+    NativeFramebuffer => Framebuffer,
+    NativeRenderbuffer => Renderbuffer,
+    NativeQuery => Query,
+    NativeTransformFeedback => TransformFeedback,
+}
+
+impl TrackedHandle for NativeFence {
+    fn tracked(&self) -> Option<(ObjectKind, u64)> {
+        Some((ObjectKind::Fence, self.0 as usize as u64))
+    }
+}
+
 #[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct NativeShader(pub NonZeroU32);
 
@@ -201,43 +763,66 @@ impl HasContext for Context {
     type UniformLocation = NativeUniformLocation;
     type TransformFeedback = NativeTransformFeedback;
 
-    fn supported_extensions(&self) -> &HashSet<String> {
+    fn supported_extensions(&self) -> &BTreeSet<String> {
         &self.extensions
     }
 
+    fn extensions(&self) -> &Extensions {
+        &self.extension_set
+    }
+
+    fn features(&self) -> &Features {
+        &self.features
+    }
+
+    fn limits(&self) -> &Limits {
+        &self.limits
+    }
+
     fn supports_debug(&self) -> bool {
-        if self.extensions.contains("GL_KHR_debug") {
-            // Supports extension (either GL or GL ES)
-            true
-        } else if self.version.is_embedded {
-            // GL ES >= 3.2
-            self.version.major == 3 && self.version.minor >= 2
-        } else {
-            // GL >= 4.3
-            self.version.major == 4 && self.version.minor >= 3
-        }
+        self.features.debug
     }
 
     fn version(&self) -> &Version {
         &self.version
     }
 
+    fn driver_info(&self) -> &DriverInfo {
+        &self.driver_info
+    }
+
+    fn workarounds(&self) -> &Workarounds {
+        &self.workarounds
+    }
+
+    fn unpack_parameters(&self) -> PixelStore {
+        self.pixel_store.lock().unpack
+    }
+
+    fn pack_parameters(&self) -> PixelStore {
+        self.pixel_store.lock().pack
+    }
+
     unsafe fn create_framebuffer(&self) -> Result<Self::Framebuffer, String> {
         let gl = &self.raw;
         let mut name = 0;
         gl.GenFramebuffers(1, &mut name);
-        NonZeroU32::new(name)
-            .map(NativeFramebuffer)
-            .ok_or_else(|| String::from("Unable to create Framebuffer object"))
+        self.track_created(
+            NonZeroU32::new(name)
+                .map(NativeFramebuffer)
+                .ok_or_else(|| String::from("Unable to create Framebuffer object")),
+        )
     }
 
     unsafe fn create_named_framebuffer(&self) -> Result<Self::Framebuffer, String> {
         let gl = &self.raw;
         let mut name = 0;
         gl.CreateFramebuffers(1, &mut name);
-        NonZeroU32::new(name)
-            .map(NativeFramebuffer)
-            .ok_or_else(|| String::from("Unable to create Framebuffer object"))
+        self.track_created(
+            NonZeroU32::new(name)
+                .map(NativeFramebuffer)
+                .ok_or_else(|| String::from("Unable to create Framebuffer object")),
+        )
     }
 
     unsafe fn is_framebuffer(&self, framebuffer: Self::Framebuffer) -> bool {
@@ -248,23 +833,23 @@ impl HasContext for Context {
     unsafe fn create_query(&self) -> Result<Self::Query, String> {
         let gl = &self.raw;
         let mut name = 0;
-        if gl.GenQueries_is_loaded() {
-            gl.GenQueries(1, &mut name);
-        } else {
-            gl.GenQueriesEXT(1, &mut name);
-        }
-        NonZeroU32::new(name)
-            .map(NativeQuery)
-            .ok_or_else(|| String::from("Unable to create Query object"))
+        gl.GenQueries(1, &mut name);
+        self.track_created(
+            NonZeroU32::new(name)
+                .map(NativeQuery)
+                .ok_or_else(|| String::from("Unable to create Query object")),
+        )
     }
 
     unsafe fn create_renderbuffer(&self) -> Result<Self::Renderbuffer, String> {
         let gl = &self.raw;
         let mut name = 0;
         gl.GenRenderbuffers(1, &mut name);
-        NonZeroU32::new(name)
-            .map(NativeRenderbuffer)
-            .ok_or_else(|| String::from("Unable to create Renderbuffer object"))
+        self.track_created(
+            NonZeroU32::new(name)
+                .map(NativeRenderbuffer)
+                .ok_or_else(|| String::from("Unable to create Renderbuffer object")),
+        )
     }
 
     unsafe fn is_renderbuffer(&self, renderbuffer: Self::Renderbuffer) -> bool {
@@ -276,16 +861,20 @@ impl HasContext for Context {
         let gl = &self.raw;
         let mut name = 0;
         gl.GenSamplers(1, &mut name);
-        NonZeroU32::new(name)
-            .map(NativeSampler)
-            .ok_or_else(|| String::from("Unable to create Sampler object"))
+        self.track_created(
+            NonZeroU32::new(name)
+                .map(NativeSampler)
+                .ok_or_else(|| String::from("Unable to create Sampler object")),
+        )
     }
 
     unsafe fn create_shader(&self, shader_type: u32) -> Result<Self::Shader, String> {
         let gl = &self.raw;
-        NonZeroU32::new(gl.CreateShader(shader_type as u32))
-            .map(NativeShader)
-            .ok_or_else(|| String::from("Unable to create Shader object"))
+        self.track_created(
+            NonZeroU32::new(gl.CreateShader(shader_type as u32))
+                .map(NativeShader)
+                .ok_or_else(|| String::from("Unable to create Shader object")),
+        )
     }
 
     unsafe fn is_shader(&self, shader: Self::Shader) -> bool {
@@ -297,18 +886,22 @@ impl HasContext for Context {
         let gl = &self.raw;
         let mut name = 0;
         gl.GenTextures(1, &mut name);
-        NonZeroU32::new(name)
-            .map(NativeTexture)
-            .ok_or_else(|| String::from("Unable to create Texture object"))
+        self.track_created(
+            NonZeroU32::new(name)
+                .map(NativeTexture)
+                .ok_or_else(|| String::from("Unable to create Texture object")),
+        )
     }
 
     unsafe fn create_named_texture(&self, target: u32) -> Result<Self::Texture, String> {
         let gl = &self.raw;
         let mut name = 0;
         gl.CreateTextures(target, 1, &mut name);
-        NonZeroU32::new(name)
-            .map(NativeTexture)
-            .ok_or_else(|| String::from("Unable to create Texture object"))
+        self.track_created(
+            NonZeroU32::new(name)
+                .map(NativeTexture)
+                .ok_or_else(|| String::from("Unable to create Texture object")),
+        )
     }
 
     unsafe fn is_texture(&self, texture: Self::Texture) -> bool {
@@ -317,11 +910,13 @@ impl HasContext for Context {
     }
 
     unsafe fn delete_shader(&self, shader: Self::Shader) {
+        self.validate_delete(shader, "delete_shader");
         let gl = &self.raw;
         gl.DeleteShader(shader.0.get());
     }
 
     unsafe fn shader_source(&self, shader: Self::Shader, source: &str) {
+        self.validate(shader, "shader_source");
         let gl = &self.raw;
         gl.ShaderSource(
             shader.0.get(),
@@ -332,11 +927,13 @@ impl HasContext for Context {
     }
 
     unsafe fn compile_shader(&self, shader: Self::Shader) {
+        self.validate(shader, "compile_shader");
         let gl = &self.raw;
         gl.CompileShader(shader.0.get());
     }
 
     unsafe fn get_shader_completion_status(&self, shader: Self::Shader) -> bool {
+        self.validate(shader, "get_shader_completion_status");
         let gl = &self.raw;
         let mut status = 0;
         gl.GetShaderiv(shader.0.get(), COMPLETION_STATUS, &mut status);
@@ -344,6 +941,7 @@ impl HasContext for Context {
     }
 
     unsafe fn get_shader_compile_status(&self, shader: Self::Shader) -> bool {
+        self.validate(shader, "get_shader_compile_status");
         let gl = &self.raw;
         let mut status = 0;
         gl.GetShaderiv(shader.0.get(), COMPILE_STATUS, &mut status);
@@ -351,12 +949,13 @@ impl HasContext for Context {
     }
 
     unsafe fn get_shader_info_log(&self, shader: Self::Shader) -> String {
+        self.validate(shader, "get_shader_info_log");
         let gl = &self.raw;
         let mut length = 0;
         gl.GetShaderiv(shader.0.get(), INFO_LOG_LENGTH, &mut length);
         if length > 0 {
             let mut log = String::with_capacity(length as usize);
-            log.extend(std::iter::repeat('\0').take(length as usize));
+            log.extend(core::iter::repeat('\0').take(length as usize));
             gl.GetShaderInfoLog(
                 shader.0.get(),
                 length,
@@ -370,6 +969,11 @@ impl HasContext for Context {
         }
     }
 
+    unsafe fn get_shader_diagnostics(&self, shader: Self::Shader) -> Vec<ShaderDiagnostic> {
+        self.validate(shader, "get_shader_diagnostics");
+        diagnostics::parse_info_log(&self.get_shader_info_log(shader))
+    }
+
     unsafe fn get_shader_precision_format(
         &self,
         shader_type: u32,
@@ -415,8 +1019,8 @@ impl HasContext for Context {
             format,
             ty,
             match pixels {
-                PixelPackData::BufferOffset(offset) => offset as *mut std::ffi::c_void,
-                PixelPackData::Slice(Some(data)) => data.as_mut_ptr() as *mut std::ffi::c_void,
+                PixelPackData::BufferOffset(offset) => offset as *mut core::ffi::c_void,
+                PixelPackData::Slice(Some(data)) => data.as_mut_ptr() as *mut core::ffi::c_void,
                 PixelPackData::Slice(None) => ptr::null_mut(),
             },
         );
@@ -424,9 +1028,11 @@ impl HasContext for Context {
 
     unsafe fn create_program(&self) -> Result<Self::Program, String> {
         let gl = &self.raw;
-        NonZeroU32::new(gl.CreateProgram())
-            .map(NativeProgram)
-            .ok_or_else(|| String::from("Unable to create Program object"))
+        self.track_created(
+            NonZeroU32::new(gl.CreateProgram())
+                .map(NativeProgram)
+                .ok_or_else(|| String::from("Unable to create Program object")),
+        )
     }
 
     unsafe fn is_program(&self, program: Self::Program) -> bool {
@@ -435,31 +1041,43 @@ impl HasContext for Context {
     }
 
     unsafe fn delete_program(&self, program: Self::Program) {
+        self.validate_delete(program, "delete_program");
+        #[cfg(debug_assertions)]
+        self.uniforms.invalidate(program);
         let gl = &self.raw;
         gl.DeleteProgram(program.0.get());
     }
 
     unsafe fn attach_shader(&self, program: Self::Program, shader: Self::Shader) {
+        self.validate(program, "attach_shader");
+        self.validate(shader, "attach_shader");
         let gl = &self.raw;
         gl.AttachShader(program.0.get(), shader.0.get());
     }
 
     unsafe fn detach_shader(&self, program: Self::Program, shader: Self::Shader) {
+        // A deleted shader stays alive while it's attached, so only the program is checked
+        self.validate(program, "detach_shader");
         let gl = &self.raw;
         gl.DetachShader(program.0.get(), shader.0.get());
     }
 
     unsafe fn link_program(&self, program: Self::Program) {
+        self.validate(program, "link_program");
+        #[cfg(debug_assertions)]
+        self.uniforms.invalidate(program);
         let gl = &self.raw;
         gl.LinkProgram(program.0.get());
     }
 
     unsafe fn validate_program(&self, program: Self::Program) {
+        self.validate(program, "validate_program");
         let gl = &self.raw;
         gl.ValidateProgram(program.0.get());
     }
 
     unsafe fn get_program_completion_status(&self, program: Self::Program) -> bool {
+        self.validate(program, "get_program_completion_status");
         let gl = &self.raw;
         let mut status = 0;
         gl.GetProgramiv(program.0.get(), COMPLETION_STATUS, &mut status);
@@ -467,6 +1085,7 @@ impl HasContext for Context {
     }
 
     unsafe fn get_program_link_status(&self, program: Self::Program) -> bool {
+        self.validate(program, "get_program_link_status");
         let gl = &self.raw;
         let mut status = 0;
         gl.GetProgramiv(program.0.get(), LINK_STATUS, &mut status);
@@ -474,6 +1093,7 @@ impl HasContext for Context {
     }
 
     unsafe fn get_program_validate_status(&self, program: Self::Program) -> bool {
+        self.validate(program, "get_program_validate_status");
         let gl = &self.raw;
         let mut status = 0;
         gl.GetProgramiv(program.0.get(), VALIDATE_STATUS, &mut status);
@@ -481,6 +1101,7 @@ impl HasContext for Context {
     }
 
     unsafe fn get_program_parameter_i32(&self, program: Self::Program, parameter: u32) -> i32 {
+        self.validate(program, "get_program_parameter_i32");
         let gl = &self.raw;
         let mut value = 0;
         gl.GetProgramiv(program.0.get(), parameter, &mut value);
@@ -488,12 +1109,13 @@ impl HasContext for Context {
     }
 
     unsafe fn get_program_info_log(&self, program: Self::Program) -> String {
+        self.validate(program, "get_program_info_log");
         let gl = &self.raw;
         let mut length = 0;
         gl.GetProgramiv(program.0.get(), INFO_LOG_LENGTH, &mut length);
         if length > 0 {
             let mut log = String::with_capacity(length as usize);
-            log.extend(std::iter::repeat('\0').take(length as usize));
+            log.extend(core::iter::repeat('\0').take(length as usize));
             gl.GetProgramInfoLog(
                 program.0.get(),
                 length,
@@ -507,6 +1129,11 @@ impl HasContext for Context {
         }
     }
 
+    unsafe fn get_program_diagnostics(&self, program: Self::Program) -> Vec<ShaderDiagnostic> {
+        self.validate(program, "get_program_diagnostics");
+        diagnostics::parse_info_log(&self.get_program_info_log(program))
+    }
+
     unsafe fn get_program_resource_i32(
         &self,
         program: Self::Program,
@@ -514,6 +1141,7 @@ impl HasContext for Context {
         index: u32,
         properties: &[u32],
     ) -> Vec<i32> {
+        self.validate(program, "get_program_resource_i32");
         let gl = &self.raw;
         // query the number of output parameters first
         let mut length = 0i32;
@@ -527,27 +1155,71 @@ impl HasContext for Context {
             &mut length,
             ptr::null_mut(),
         );
-        // get the parameter values
-        let mut params = vec![0i32; length as usize];
+        // get the parameter values, drivers that don't report the number of values without a
+        // buffer still return at least one value per property
+        let mut params = vec![0i32; (length as usize).max(properties.len())];
+        let length_probe = params.len() as i32;
         gl.GetProgramResourceiv(
             program.0.get(),
             interface,
             index,
             properties.len() as i32,
             properties.as_ptr(),
-            length,
+            length_probe,
             &mut length,
             params.as_mut_ptr(),
         );
+        params.truncate(length.max(0) as usize);
         params
     }
 
+    unsafe fn get_program_interface_i32(
+        &self,
+        program: Self::Program,
+        interface: u32,
+        parameter: u32,
+    ) -> i32 {
+        self.validate(program, "get_program_interface_i32");
+        let gl = &self.raw;
+        let mut value = 0;
+        gl.GetProgramInterfaceiv(program.0.get(), interface, parameter, &mut value);
+        value
+    }
+
+    unsafe fn get_program_resource_name(
+        &self,
+        program: Self::Program,
+        interface: u32,
+        index: u32,
+    ) -> String {
+        self.validate(program, "get_program_resource_name");
+        let gl = &self.raw;
+        let max_length = self
+            .get_program_resource_i32(program, interface, index, &[NAME_LENGTH])
+            .first()
+            .copied()
+            .unwrap_or(0);
+        let mut name = vec![0u8; max_length.max(1) as usize];
+        let mut length = 0;
+        gl.GetProgramResourceName(
+            program.0.get(),
+            interface,
+            index,
+            name.len() as i32,
+            &mut length,
+            name.as_mut_ptr() as *mut native_gl::GLchar,
+        );
+        name.truncate(length.max(0) as usize);
+        String::from_utf8(name).unwrap()
+    }
+
     unsafe fn program_uniform_1_i32(
         &self,
         program: Self::Program,
         location: Option<&Self::UniformLocation>,
         x: i32,
     ) {
+        self.validate(program, "program_uniform_1_i32");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform1i(program.0.get(), loc.0 as i32, x);
@@ -561,6 +1233,7 @@ impl HasContext for Context {
         x: i32,
         y: i32,
     ) {
+        self.validate(program, "program_uniform_2_i32");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform2i(program.0.get(), loc.0 as i32, x, y);
@@ -575,6 +1248,7 @@ impl HasContext for Context {
         y: i32,
         z: i32,
     ) {
+        self.validate(program, "program_uniform_3_i32");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform3i(program.0.get(), loc.0 as i32, x, y, z);
@@ -590,6 +1264,7 @@ impl HasContext for Context {
         z: i32,
         w: i32,
     ) {
+        self.validate(program, "program_uniform_4_i32");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform4i(program.0.get(), loc.0 as i32, x, y, z, w);
@@ -602,6 +1277,7 @@ impl HasContext for Context {
         location: Option<&Self::UniformLocation>,
         v: &[i32],
     ) {
+        self.validate(program, "program_uniform_1_i32_slice");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform1iv(program.0.get(), loc.0 as i32, v.len() as i32, v.as_ptr());
@@ -614,6 +1290,7 @@ impl HasContext for Context {
         location: Option<&Self::UniformLocation>,
         v: &[i32],
     ) {
+        self.validate(program, "program_uniform_2_i32_slice");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform2iv(
@@ -631,6 +1308,7 @@ impl HasContext for Context {
         location: Option<&Self::UniformLocation>,
         v: &[i32],
     ) {
+        self.validate(program, "program_uniform_3_i32_slice");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform3iv(
@@ -648,6 +1326,7 @@ impl HasContext for Context {
         location: Option<&Self::UniformLocation>,
         v: &[i32],
     ) {
+        self.validate(program, "program_uniform_4_i32_slice");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform4iv(
@@ -665,6 +1344,7 @@ impl HasContext for Context {
         location: Option<&Self::UniformLocation>,
         x: u32,
     ) {
+        self.validate(program, "program_uniform_1_u32");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform1ui(program.0.get(), loc.0 as i32, x);
@@ -678,6 +1358,7 @@ impl HasContext for Context {
         x: u32,
         y: u32,
     ) {
+        self.validate(program, "program_uniform_2_u32");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform2ui(program.0.get(), loc.0 as i32, x, y);
@@ -692,6 +1373,7 @@ impl HasContext for Context {
         y: u32,
         z: u32,
     ) {
+        self.validate(program, "program_uniform_3_u32");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform3ui(program.0.get(), loc.0 as i32, x, y, z);
@@ -707,6 +1389,7 @@ impl HasContext for Context {
         z: u32,
         w: u32,
     ) {
+        self.validate(program, "program_uniform_4_u32");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform4ui(program.0.get(), loc.0 as i32, x, y, z, w);
@@ -719,6 +1402,7 @@ impl HasContext for Context {
         location: Option<&Self::UniformLocation>,
         v: &[u32],
     ) {
+        self.validate(program, "program_uniform_1_u32_slice");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform1uiv(program.0.get(), loc.0 as i32, v.len() as i32, v.as_ptr());
@@ -731,6 +1415,7 @@ impl HasContext for Context {
         location: Option<&Self::UniformLocation>,
         v: &[u32],
     ) {
+        self.validate(program, "program_uniform_2_u32_slice");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform2uiv(
@@ -748,6 +1433,7 @@ impl HasContext for Context {
         location: Option<&Self::UniformLocation>,
         v: &[u32],
     ) {
+        self.validate(program, "program_uniform_3_u32_slice");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform3uiv(
@@ -765,6 +1451,7 @@ impl HasContext for Context {
         location: Option<&Self::UniformLocation>,
         v: &[u32],
     ) {
+        self.validate(program, "program_uniform_4_u32_slice");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform4uiv(
@@ -782,6 +1469,7 @@ impl HasContext for Context {
         location: Option<&Self::UniformLocation>,
         x: f32,
     ) {
+        self.validate(program, "program_uniform_1_f32");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform1f(program.0.get(), loc.0 as i32, x);
@@ -795,6 +1483,7 @@ impl HasContext for Context {
         x: f32,
         y: f32,
     ) {
+        self.validate(program, "program_uniform_2_f32");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform2f(program.0.get(), loc.0 as i32, x, y);
@@ -809,6 +1498,7 @@ impl HasContext for Context {
         y: f32,
         z: f32,
     ) {
+        self.validate(program, "program_uniform_3_f32");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform3f(program.0.get(), loc.0 as i32, x, y, z);
@@ -824,6 +1514,7 @@ impl HasContext for Context {
         z: f32,
         w: f32,
     ) {
+        self.validate(program, "program_uniform_4_f32");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform4f(program.0.get(), loc.0 as i32, x, y, z, w);
@@ -836,6 +1527,7 @@ impl HasContext for Context {
         location: Option<&Self::UniformLocation>,
         v: &[f32],
     ) {
+        self.validate(program, "program_uniform_1_f32_slice");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform1fv(program.0.get(), loc.0 as i32, v.len() as i32, v.as_ptr());
@@ -848,6 +1540,7 @@ impl HasContext for Context {
         location: Option<&Self::UniformLocation>,
         v: &[f32],
     ) {
+        self.validate(program, "program_uniform_2_f32_slice");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform2fv(
@@ -865,6 +1558,7 @@ impl HasContext for Context {
         location: Option<&Self::UniformLocation>,
         v: &[f32],
     ) {
+        self.validate(program, "program_uniform_3_f32_slice");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform3fv(
@@ -882,6 +1576,7 @@ impl HasContext for Context {
         location: Option<&Self::UniformLocation>,
         v: &[f32],
     ) {
+        self.validate(program, "program_uniform_4_f32_slice");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniform4fv(
@@ -900,6 +1595,7 @@ impl HasContext for Context {
         transpose: bool,
         v: &[f32],
     ) {
+        self.validate(program, "program_uniform_matrix_2_f32_slice");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniformMatrix2fv(
@@ -919,6 +1615,7 @@ impl HasContext for Context {
         transpose: bool,
         v: &[f32],
     ) {
+        self.validate(program, "program_uniform_matrix_2x3_f32_slice");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniformMatrix2x3fv(
@@ -938,6 +1635,7 @@ impl HasContext for Context {
         transpose: bool,
         v: &[f32],
     ) {
+        self.validate(program, "program_uniform_matrix_2x4_f32_slice");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniformMatrix2x4fv(
@@ -957,6 +1655,7 @@ impl HasContext for Context {
         transpose: bool,
         v: &[f32],
     ) {
+        self.validate(program, "program_uniform_matrix_3x2_f32_slice");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniformMatrix3x2fv(
@@ -976,6 +1675,7 @@ impl HasContext for Context {
         transpose: bool,
         v: &[f32],
     ) {
+        self.validate(program, "program_uniform_matrix_3_f32_slice");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniformMatrix3fv(
@@ -995,6 +1695,7 @@ impl HasContext for Context {
         transpose: bool,
         v: &[f32],
     ) {
+        self.validate(program, "program_uniform_matrix_3x4_f32_slice");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniformMatrix3x4fv(
@@ -1014,6 +1715,7 @@ impl HasContext for Context {
         transpose: bool,
         v: &[f32],
     ) {
+        self.validate(program, "program_uniform_matrix_4x2_f32_slice");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniformMatrix4x2fv(
@@ -1033,6 +1735,7 @@ impl HasContext for Context {
         transpose: bool,
         v: &[f32],
     ) {
+        self.validate(program, "program_uniform_matrix_4x3_f32_slice");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniformMatrix4x3fv(
@@ -1052,6 +1755,7 @@ impl HasContext for Context {
         transpose: bool,
         v: &[f32],
     ) {
+        self.validate(program, "program_uniform_matrix_4_f32_slice");
         let gl = &self.raw;
         if let Some(loc) = location {
             gl.ProgramUniformMatrix4fv(
@@ -1065,6 +1769,7 @@ impl HasContext for Context {
     }
 
     unsafe fn program_binary_retrievable_hint(&self, program: Self::Program, value: bool) {
+        self.validate(program, "program_binary_retrievable_hint");
         let gl = &self.raw;
         gl.ProgramParameteri(
             program.0.get(),
@@ -1074,6 +1779,7 @@ impl HasContext for Context {
     }
 
     unsafe fn get_program_binary(&self, program: Self::Program) -> Option<ProgramBinary> {
+        self.validate(program, "get_program_binary");
         let gl = &self.raw;
 
         // We don't need to error check here as if the call fails, length will be returned as 0.
@@ -1099,6 +1805,9 @@ impl HasContext for Context {
     }
 
     unsafe fn program_binary(&self, program: Self::Program, binary: &ProgramBinary) {
+        self.validate(program, "program_binary");
+        #[cfg(debug_assertions)]
+        self.uniforms.invalidate(program);
         let gl = &self.raw;
 
         gl.ProgramBinary(
@@ -1110,6 +1819,7 @@ impl HasContext for Context {
     }
 
     unsafe fn get_active_uniforms(&self, program: Self::Program) -> u32 {
+        self.validate(program, "get_active_uniforms");
         let gl = &self.raw;
         let mut count = 0;
         gl.GetProgramiv(program.0.get(), ACTIVE_UNIFORMS, &mut count);
@@ -1122,6 +1832,7 @@ impl HasContext for Context {
         uniforms: &[u32],
         pname: u32,
     ) -> Vec<i32> {
+        self.validate(program, "get_active_uniforms_parameter");
         let gl = &self.raw;
         let mut results = vec![0; uniforms.len()];
         gl.GetActiveUniformsiv(
@@ -1139,6 +1850,7 @@ impl HasContext for Context {
         program: Self::Program,
         index: u32,
     ) -> Option<ActiveUniform> {
+        self.validate(program, "get_active_uniform");
         let gl = &self.raw;
         let mut uniform_max_size = 0;
         gl.GetProgramiv(
@@ -1148,7 +1860,7 @@ impl HasContext for Context {
         );
 
         let mut name = String::with_capacity(uniform_max_size as usize);
-        name.extend(std::iter::repeat('\0').take(uniform_max_size as usize));
+        name.extend(core::iter::repeat('\0').take(uniform_max_size as usize));
         let mut length = 0;
         let mut size = 0;
         let mut utype = 0;
@@ -1167,6 +1879,7 @@ impl HasContext for Context {
     }
 
     unsafe fn use_program(&self, program: Option<Self::Program>) {
+        self.validate(program, "use_program");
         let gl = &self.raw;
         gl.UseProgram(program.map(|p| p.0.get()).unwrap_or(0));
     }
@@ -1175,18 +1888,22 @@ impl HasContext for Context {
         let gl = &self.raw;
         let mut buffer = 0;
         gl.GenBuffers(1, &mut buffer);
-        NonZeroU32::new(buffer)
-            .map(NativeBuffer)
-            .ok_or_else(|| String::from("Unable to create Buffer object"))
+        self.track_created(
+            NonZeroU32::new(buffer)
+                .map(NativeBuffer)
+                .ok_or_else(|| String::from("Unable to create Buffer object")),
+        )
     }
 
     unsafe fn create_named_buffer(&self) -> Result<Self::Buffer, String> {
         let gl = &self.raw;
         let mut buffer = 0;
         gl.CreateBuffers(1, &mut buffer);
-        NonZeroU32::new(buffer)
-            .map(NativeBuffer)
-            .ok_or_else(|| String::from("Unable to create Buffer object"))
+        self.track_created(
+            NonZeroU32::new(buffer)
+                .map(NativeBuffer)
+                .ok_or_else(|| String::from("Unable to create Buffer object")),
+        )
     }
 
     unsafe fn is_buffer(&self, buffer: Self::Buffer) -> bool {
@@ -1195,11 +1912,13 @@ impl HasContext for Context {
     }
 
     unsafe fn bind_buffer(&self, target: u32, buffer: Option<Self::Buffer>) {
+        self.validate(buffer, "bind_buffer");
         let gl = &self.raw;
         gl.BindBuffer(target, buffer.map(|b| b.0.get()).unwrap_or(0));
     }
 
     unsafe fn bind_buffer_base(&self, target: u32, index: u32, buffer: Option<Self::Buffer>) {
+        self.validate(buffer, "bind_buffer_base");
         let gl = &self.raw;
         gl.BindBufferBase(target, index, buffer.map(|b| b.0.get()).unwrap_or(0));
     }
@@ -1212,6 +1931,7 @@ impl HasContext for Context {
         offset: i32,
         size: i32,
     ) {
+        self.validate(buffer, "bind_buffer_range");
         let gl = &self.raw;
         gl.BindBufferRange(
             target,
@@ -1225,10 +1945,11 @@ impl HasContext for Context {
     unsafe fn bind_vertex_buffer(
         &self,
         binding_index: u32,
-        buffer: Option<Buffer>,
+        buffer: Option<Self::Buffer>,
         offset: i32,
         stride: i32,
     ) {
+        self.validate(buffer, "bind_vertex_buffer");
         let gl = &self.raw;
         gl.BindVertexBuffer(
             binding_index,
@@ -1239,11 +1960,13 @@ impl HasContext for Context {
     }
 
     unsafe fn bind_framebuffer(&self, target: u32, framebuffer: Option<Self::Framebuffer>) {
+        self.validate(framebuffer, "bind_framebuffer");
         let gl = &self.raw;
         gl.BindFramebuffer(target, framebuffer.map(|fb| fb.0.get()).unwrap_or(0));
     }
 
     unsafe fn bind_renderbuffer(&self, target: u32, renderbuffer: Option<Self::Renderbuffer>) {
+        self.validate(renderbuffer, "bind_renderbuffer");
         let gl = &self.raw;
         gl.BindRenderbuffer(target, renderbuffer.map(|rb| rb.0.get()).unwrap_or(0));
     }
@@ -1282,6 +2005,8 @@ impl HasContext for Context {
         mask: u32,
         filter: u32,
     ) {
+        self.validate(read_buffer, "blit_named_framebuffer");
+        self.validate(draw_buffer, "blit_named_framebuffer");
         let gl = &self.raw;
         gl.BlitNamedFramebuffer(
             read_buffer.map(|f| f.0.get()).unwrap_or(0),
@@ -1302,50 +2027,35 @@ impl HasContext for Context {
     unsafe fn create_vertex_array(&self) -> Result<Self::VertexArray, String> {
         let gl = &self.raw;
         let mut vertex_array = 0;
-        if gl.GenVertexArrays_is_loaded() {
-            gl.GenVertexArrays(1, &mut vertex_array);
-        } else {
-            #[cfg(not(target_vendor = "apple"))]
-            gl.GenVertexArraysOES(1, &mut vertex_array);
-            #[cfg(target_vendor = "apple")]
-            gl.GenVertexArraysAPPLE(1, &mut vertex_array);
-        }
-        NonZeroU32::new(vertex_array)
-            .map(NativeVertexArray)
-            .ok_or_else(|| String::from("Unable to create VertexArray object"))
+        gl.GenVertexArrays(1, &mut vertex_array);
+        self.track_created(
+            NonZeroU32::new(vertex_array)
+                .map(NativeVertexArray)
+                .ok_or_else(|| String::from("Unable to create VertexArray object")),
+        )
     }
 
     unsafe fn create_named_vertex_array(&self) -> Result<Self::VertexArray, String> {
         let gl = &self.raw;
         let mut vertex_array = 0;
         gl.CreateVertexArrays(1, &mut vertex_array);
-        NonZeroU32::new(vertex_array)
-            .map(NativeVertexArray)
-            .ok_or_else(|| String::from("Unable to create VertexArray object"))
+        self.track_created(
+            NonZeroU32::new(vertex_array)
+                .map(NativeVertexArray)
+                .ok_or_else(|| String::from("Unable to create VertexArray object")),
+        )
     }
 
     unsafe fn delete_vertex_array(&self, vertex_array: Self::VertexArray) {
+        self.validate_delete(vertex_array, "delete_vertex_array");
         let gl = &self.raw;
-        if gl.DeleteVertexArrays_is_loaded() {
-            gl.DeleteVertexArrays(1, &vertex_array.0.get());
-        } else {
-            #[cfg(not(target_vendor = "apple"))]
-            gl.DeleteVertexArraysOES(1, &vertex_array.0.get());
-            #[cfg(target_vendor = "apple")]
-            gl.DeleteVertexArraysAPPLE(1, &vertex_array.0.get());
-        }
+        gl.DeleteVertexArrays(1, &vertex_array.0.get());
     }
 
     unsafe fn bind_vertex_array(&self, vertex_array: Option<Self::VertexArray>) {
+        self.validate(vertex_array, "bind_vertex_array");
         let gl = &self.raw;
-        if gl.BindVertexArray_is_loaded() {
-            gl.BindVertexArray(vertex_array.map(|va| va.0.get()).unwrap_or(0));
-        } else {
-            #[cfg(not(target_vendor = "apple"))]
-            gl.BindVertexArrayOES(vertex_array.map(|va| va.0.get()).unwrap_or(0));
-            #[cfg(target_vendor = "apple")]
-            gl.BindVertexArrayAPPLE(vertex_array.map(|va| va.0.get()).unwrap_or(0));
-        }
+        gl.BindVertexArray(vertex_array.map(|va| va.0.get()).unwrap_or(0));
     }
 
     unsafe fn clear_color(&self, red: f32, green: f32, blue: f32, alpha: f32) {
@@ -1393,6 +2103,7 @@ impl HasContext for Context {
     unsafe fn pixel_store_i32(&self, parameter: u32, value: i32) {
         let gl = &self.raw;
         gl.PixelStorei(parameter, value);
+        self.pixel_store.lock().set(parameter, value);
     }
 
     unsafe fn pixel_store_bool(&self, parameter: u32, value: bool) {
@@ -1401,6 +2112,7 @@ impl HasContext for Context {
     }
 
     unsafe fn get_frag_data_location(&self, program: Self::Program, name: &str) -> i32 {
+        self.validate(program, "get_frag_data_location");
         let gl = &self.raw;
         let name = CString::new(name).unwrap();
         gl.GetFragDataLocation(program.0.get(), name.as_ptr() as *const native_gl::GLchar)
@@ -1412,6 +2124,7 @@ impl HasContext for Context {
         color_number: u32,
         name: &str,
     ) {
+        self.validate(program, "bind_frag_data_location");
         let gl = &self.raw;
         let name = CString::new(name).unwrap();
         gl.BindFragDataLocation(
@@ -1422,31 +2135,45 @@ impl HasContext for Context {
     }
 
     unsafe fn buffer_data_size(&self, target: u32, size: i32, usage: u32) {
+        if let Some(memory) = &self.memory {
+            memory.bound_buffer(self, target, size);
+        }
         let gl = &self.raw;
-        gl.BufferData(target, size as isize, std::ptr::null(), usage);
+        gl.BufferData(target, size as isize, core::ptr::null(), usage);
     }
 
     unsafe fn named_buffer_data_size(&self, buffer: Self::Buffer, size: i32, usage: u32) {
+        self.validate(buffer, "named_buffer_data_size");
+        if let Some(memory) = &self.memory {
+            memory.buffer(buffer, size);
+        }
         let gl = &self.raw;
-        gl.NamedBufferData(buffer.0.get(), size as isize, std::ptr::null(), usage);
+        gl.NamedBufferData(buffer.0.get(), size as isize, core::ptr::null(), usage);
     }
 
     unsafe fn buffer_data_u8_slice(&self, target: u32, data: &[u8], usage: u32) {
+        if let Some(memory) = &self.memory {
+            memory.bound_buffer(self, target, data.len() as i32);
+        }
         let gl = &self.raw;
         gl.BufferData(
             target,
             data.len() as isize,
-            data.as_ptr() as *const std::ffi::c_void,
+            data.as_ptr() as *const core::ffi::c_void,
             usage,
         );
     }
 
     unsafe fn named_buffer_data_u8_slice(&self, buffer: Self::Buffer, data: &[u8], usage: u32) {
+        self.validate(buffer, "named_buffer_data_u8_slice");
+        if let Some(memory) = &self.memory {
+            memory.buffer(buffer, data.len() as i32);
+        }
         let gl = &self.raw;
         gl.NamedBufferData(
             buffer.0.get(),
             data.len() as isize,
-            data.as_ptr() as *const std::ffi::c_void,
+            data.as_ptr() as *const core::ffi::c_void,
             usage,
         );
     }
@@ -1457,7 +2184,7 @@ impl HasContext for Context {
             target,
             offset as isize,
             src_data.len() as isize,
-            src_data.as_ptr() as *const std::ffi::c_void,
+            src_data.as_ptr() as *const core::ffi::c_void,
         );
     }
 
@@ -1467,12 +2194,13 @@ impl HasContext for Context {
         offset: i32,
         src_data: &[u8],
     ) {
+        self.validate(buffer, "named_buffer_sub_data_u8_slice");
         let gl = &self.raw;
         gl.NamedBufferSubData(
             buffer.0.get(),
             offset as isize,
             src_data.len() as isize,
-            src_data.as_ptr() as *const std::ffi::c_void,
+            src_data.as_ptr() as *const core::ffi::c_void,
         );
     }
 
@@ -1482,11 +2210,12 @@ impl HasContext for Context {
             target,
             offset as isize,
             dst_data.len() as isize,
-            dst_data.as_mut_ptr() as *mut std::ffi::c_void,
+            dst_data.as_mut_ptr() as *mut core::ffi::c_void,
         );
     }
 
     unsafe fn tex_buffer(&self, target: u32, internal_format: u32, buffer: Option<Self::Buffer>) {
+        self.validate(buffer, "tex_buffer");
         let gl = &self.raw;
         gl.TexBuffer(
             target,
@@ -1496,14 +2225,14 @@ impl HasContext for Context {
     }
 
     unsafe fn buffer_storage(&self, target: u32, size: i32, data: Option<&[u8]>, flags: u32) {
+        if let Some(memory) = &self.memory {
+            memory.bound_buffer(self, target, size);
+        }
         let gl = &self.raw;
         let size = size as isize;
-        let data = data.map(|p| p.as_ptr()).unwrap_or(std::ptr::null()) as *const std::ffi::c_void;
-        if gl.BufferStorage_is_loaded() {
-            gl.BufferStorage(target, size, data, flags);
-        } else {
-            gl.BufferStorageEXT(target, size, data, flags);
-        }
+        let data =
+            data.map(|p| p.as_ptr()).unwrap_or(core::ptr::null()) as *const core::ffi::c_void;
+        gl.BufferStorage(target, size, data, flags);
     }
     unsafe fn named_buffer_storage(
         &self,
@@ -1512,8 +2241,13 @@ impl HasContext for Context {
         data: Option<&[u8]>,
         flags: u32,
     ) {
+        self.validate(buffer, "named_buffer_storage");
+        if let Some(memory) = &self.memory {
+            memory.buffer(buffer, size);
+        }
         let gl = &self.raw;
-        let data = data.map(|p| p.as_ptr()).unwrap_or(std::ptr::null()) as *const std::ffi::c_void;
+        let data =
+            data.map(|p| p.as_ptr()).unwrap_or(core::ptr::null()) as *const core::ffi::c_void;
         gl.NamedBufferStorage(buffer.0.get(), size as isize, data, flags);
     }
 
@@ -1527,6 +2261,7 @@ impl HasContext for Context {
         framebuffer: Option<Self::Framebuffer>,
         target: u32,
     ) -> u32 {
+        self.validate(framebuffer, "check_named_framebuffer_status");
         let gl = &self.raw;
         gl.CheckNamedFramebufferStatus(framebuffer.map(|f| f.0.get()).unwrap_or(0), target)
     }
@@ -1564,6 +2299,7 @@ impl HasContext for Context {
         draw_buffer: u32,
         values: &[i32],
     ) {
+        self.validate(framebuffer, "clear_named_framebuffer_i32_slice");
         let gl = &self.raw;
         gl.ClearNamedFramebufferiv(
             framebuffer.map(|f| f.0.get()).unwrap_or(0),
@@ -1580,6 +2316,7 @@ impl HasContext for Context {
         draw_buffer: u32,
         values: &[u32],
     ) {
+        self.validate(framebuffer, "clear_named_framebuffer_u32_slice");
         let gl = &self.raw;
         gl.ClearNamedFramebufferuiv(
             framebuffer.map(|f| f.0.get()).unwrap_or(0),
@@ -1596,6 +2333,7 @@ impl HasContext for Context {
         draw_buffer: u32,
         values: &[f32],
     ) {
+        self.validate(framebuffer, "clear_named_framebuffer_f32_slice");
         let gl = &self.raw;
         gl.ClearNamedFramebufferfv(
             framebuffer.map(|f| f.0.get()).unwrap_or(0),
@@ -1613,6 +2351,7 @@ impl HasContext for Context {
         depth: f32,
         stencil: i32,
     ) {
+        self.validate(framebuffer, "clear_named_framebuffer_depth_stencil");
         let gl = &self.raw;
         gl.ClearNamedFramebufferfi(
             framebuffer.map(|f| f.0.get()).unwrap_or(0),
@@ -1624,11 +2363,13 @@ impl HasContext for Context {
     }
 
     unsafe fn client_wait_sync(&self, fence: Self::Fence, flags: u32, timeout: i32) -> u32 {
+        self.validate(fence, "client_wait_sync");
         let gl = &self.raw;
         gl.ClientWaitSync(fence.0, flags, timeout as u64)
     }
 
     unsafe fn get_sync_parameter_i32(&self, fence: Self::Fence, parameter: u32) -> i32 {
+        self.validate(fence, "get_sync_parameter_i32");
         let gl = &self.raw;
         let mut v = 0;
         gl.GetSynciv(fence.0, parameter, 1, ptr::null_mut(), &mut v);
@@ -1636,6 +2377,7 @@ impl HasContext for Context {
     }
 
     unsafe fn wait_sync(&self, fence: Self::Fence, flags: u32, timeout: u64) {
+        self.validate(fence, "wait_sync");
         let gl = &self.raw;
         gl.WaitSync(fence.0, flags, timeout)
     }
@@ -1648,6 +2390,11 @@ impl HasContext for Context {
         dst_offset: i32,
         size: i32,
     ) {
+        if self.workarounds.copy_buffer_sub_data_through_temporary && src_target == dst_target {
+            return workarounds::copy_buffer_sub_data_through_temporary(
+                self, src_target, src_offset, dst_offset, size,
+            );
+        }
         let gl = &self.raw;
         gl.CopyBufferSubData(
             src_target,
@@ -1676,6 +2423,8 @@ impl HasContext for Context {
         src_height: i32,
         src_depth: i32,
     ) {
+        self.validate(src_name, "copy_image_sub_data");
+        self.validate(dst_name, "copy_image_sub_data");
         let gl = &self.raw;
         gl.CopyImageSubData(
             src_name.0.get(),
@@ -1707,6 +2456,22 @@ impl HasContext for Context {
         height: i32,
         border: i32,
     ) {
+        if let Some(memory) = &self.memory {
+            memory.bound_texture_level(
+                self,
+                target,
+                level,
+                memory::image_bytes(
+                    internal_format,
+                    internal_format,
+                    UNSIGNED_BYTE,
+                    width,
+                    height,
+                    1,
+                ),
+                (width, height, 1),
+            );
+        }
         let gl = &self.raw;
         gl.CopyTexImage2D(target, level, internal_format, x, y, width, height, border);
     }
@@ -1745,40 +2510,52 @@ impl HasContext for Context {
     }
 
     unsafe fn delete_buffer(&self, buffer: Self::Buffer) {
+        self.validate_delete(buffer, "delete_buffer");
+        if let Some(memory) = &self.memory {
+            memory.delete_buffer(buffer);
+        }
         let gl = &self.raw;
         gl.DeleteBuffers(1, &buffer.0.get());
     }
 
     unsafe fn delete_framebuffer(&self, framebuffer: Self::Framebuffer) {
+        self.validate_delete(framebuffer, "delete_framebuffer");
         let gl = &self.raw;
         gl.DeleteFramebuffers(1, &framebuffer.0.get());
     }
 
     unsafe fn delete_query(&self, query: Self::Query) {
+        self.validate_delete(query, "delete_query");
         let gl = &self.raw;
-        if gl.DeleteQueries_is_loaded() {
-            gl.DeleteQueries(1, &query.0.get());
-        } else {
-            gl.DeleteQueriesEXT(1, &query.0.get());
-        }
+        gl.DeleteQueries(1, &query.0.get());
     }
 
     unsafe fn delete_renderbuffer(&self, renderbuffer: Self::Renderbuffer) {
+        self.validate_delete(renderbuffer, "delete_renderbuffer");
+        if let Some(memory) = &self.memory {
+            memory.delete_renderbuffer(renderbuffer);
+        }
         let gl = &self.raw;
         gl.DeleteRenderbuffers(1, &renderbuffer.0.get());
     }
 
     unsafe fn delete_sampler(&self, sampler: Self::Sampler) {
+        self.validate_delete(sampler, "delete_sampler");
         let gl = &self.raw;
         gl.DeleteSamplers(1, &sampler.0.get());
     }
 
     unsafe fn delete_sync(&self, fence: Self::Fence) {
+        self.validate_delete(fence, "delete_sync");
         let gl = &self.raw;
         gl.DeleteSync(fence.0);
     }
 
     unsafe fn delete_texture(&self, texture: Self::Texture) {
+        self.validate_delete(texture, "delete_texture");
+        if let Some(memory) = &self.memory {
+            memory.delete_texture(texture);
+        }
         let gl = &self.raw;
         gl.DeleteTextures(1, &texture.0.get());
     }
@@ -1838,7 +2615,7 @@ impl HasContext for Context {
 
     unsafe fn draw_arrays_indirect_offset(&self, mode: u32, offset: i32) {
         let gl = &self.raw;
-        gl.DrawArraysIndirect(mode, offset as *const std::ffi::c_void);
+        gl.DrawArraysIndirect(mode, offset as *const core::ffi::c_void);
     }
 
     unsafe fn draw_buffer(&self, draw_buffer: u32) {
@@ -1851,6 +2628,7 @@ impl HasContext for Context {
         framebuffer: Option<Self::Framebuffer>,
         draw_buffer: u32,
     ) {
+        self.validate(framebuffer, "named_framebuffer_draw_buffer");
         let gl = &self.raw;
         gl.NamedFramebufferDrawBuffer(framebuffer.map(|f| f.0.get()).unwrap_or(0), draw_buffer);
     }
@@ -1865,6 +2643,7 @@ impl HasContext for Context {
         framebuffer: Option<Self::Framebuffer>,
         buffers: &[u32],
     ) {
+        self.validate(framebuffer, "named_framebuffer_draw_buffers");
         let gl = &self.raw;
         gl.NamedFramebufferDrawBuffers(
             framebuffer.map(|f| f.0.get()).unwrap_or(0),
@@ -1879,7 +2658,7 @@ impl HasContext for Context {
             mode as u32,
             count,
             element_type as u32,
-            offset as *const std::ffi::c_void,
+            offset as *const core::ffi::c_void,
         );
     }
 
@@ -1896,7 +2675,7 @@ impl HasContext for Context {
             mode as u32,
             count,
             element_type as u32,
-            offset as *const std::ffi::c_void,
+            offset as *const core::ffi::c_void,
             base_vertex,
         );
     }
@@ -1914,7 +2693,7 @@ impl HasContext for Context {
             mode as u32,
             count,
             element_type as u32,
-            offset as *const std::ffi::c_void,
+            offset as *const core::ffi::c_void,
             instance_count,
         );
     }
@@ -1933,7 +2712,7 @@ impl HasContext for Context {
             mode as u32,
             count,
             element_type as u32,
-            offset as *const std::ffi::c_void,
+            offset as *const core::ffi::c_void,
             instance_count,
             base_vertex,
         );
@@ -1954,7 +2733,7 @@ impl HasContext for Context {
             mode as u32,
             count,
             element_type as u32,
-            offset as *const std::ffi::c_void,
+            offset as *const core::ffi::c_void,
             instance_count,
             base_vertex,
             base_instance,
@@ -1963,7 +2742,7 @@ impl HasContext for Context {
 
     unsafe fn draw_elements_indirect_offset(&self, mode: u32, element_type: u32, offset: i32) {
         let gl = &self.raw;
-        gl.DrawElementsIndirect(mode, element_type, offset as *const std::ffi::c_void);
+        gl.DrawElementsIndirect(mode, element_type, offset as *const core::ffi::c_void);
     }
 
     unsafe fn enable(&self, parameter: u32) {
@@ -1982,6 +2761,7 @@ impl HasContext for Context {
     }
 
     unsafe fn enable_vertex_array_attrib(&self, vao: Self::VertexArray, index: u32) {
+        self.validate(vao, "enable_vertex_array_attrib");
         let gl = &self.raw;
         gl.EnableVertexArrayAttrib(vao.0.get(), index);
     }
@@ -2003,6 +2783,7 @@ impl HasContext for Context {
         renderbuffer_target: u32,
         renderbuffer: Option<Self::Renderbuffer>,
     ) {
+        self.validate(renderbuffer, "framebuffer_renderbuffer");
         let gl = &self.raw;
         gl.FramebufferRenderbuffer(
             target,
@@ -2019,6 +2800,7 @@ impl HasContext for Context {
         texture: Option<Self::Texture>,
         level: i32,
     ) {
+        self.validate(texture, "framebuffer_texture");
         let gl = &self.raw;
         gl.FramebufferTexture(
             target,
@@ -2036,6 +2818,7 @@ impl HasContext for Context {
         texture: Option<Self::Texture>,
         level: i32,
     ) {
+        self.validate(texture, "framebuffer_texture_2d");
         let gl = &self.raw;
         gl.FramebufferTexture2D(
             target,
@@ -2055,6 +2838,7 @@ impl HasContext for Context {
         level: i32,
         samples: i32,
     ) {
+        self.validate(texture, "framebuffer_texture_2d_multisample");
         let gl = &self.raw;
         gl.FramebufferTexture2DMultisampleEXT(
             target,
@@ -2075,6 +2859,7 @@ impl HasContext for Context {
         level: i32,
         layer: i32,
     ) {
+        self.validate(texture, "framebuffer_texture_3d");
         let gl = &self.raw;
         gl.FramebufferTexture3D(
             target,
@@ -2094,6 +2879,7 @@ impl HasContext for Context {
         level: i32,
         layer: i32,
     ) {
+        self.validate(texture, "framebuffer_texture_layer");
         let gl = &self.raw;
         gl.FramebufferTextureLayer(
             target,
@@ -2111,6 +2897,8 @@ impl HasContext for Context {
         renderbuffer_target: u32,
         renderbuffer: Option<Self::Renderbuffer>,
     ) {
+        self.validate(framebuffer, "named_framebuffer_renderbuffer");
+        self.validate(renderbuffer, "named_framebuffer_renderbuffer");
         let gl = &self.raw;
         gl.NamedFramebufferRenderbuffer(
             framebuffer.map(|f| f.0.get()).unwrap_or(0),
@@ -2127,6 +2915,8 @@ impl HasContext for Context {
         texture: Option<Self::Texture>,
         level: i32,
     ) {
+        self.validate(framebuffer, "named_framebuffer_texture");
+        self.validate(texture, "named_framebuffer_texture");
         let gl = &self.raw;
         gl.NamedFramebufferTexture(
             framebuffer.map(|f| f.0.get()).unwrap_or(0),
@@ -2144,6 +2934,8 @@ impl HasContext for Context {
         level: i32,
         layer: i32,
     ) {
+        self.validate(framebuffer, "named_framebuffer_texture_layer");
+        self.validate(texture, "named_framebuffer_texture_layer");
         let gl = &self.raw;
         gl.NamedFramebufferTextureLayer(
             framebuffer.map(|f| f.0.get()).unwrap_or(0),
@@ -2184,6 +2976,7 @@ impl HasContext for Context {
         level: i32,
         parameter: u32,
     ) -> i32 {
+        self.validate(texture, "get_texture_level_parameter_i32");
         let gl = &self.raw;
         let mut value = 0;
         gl.GetTextureLevelParameteriv(texture.0.get(), level, parameter, &mut value);
@@ -2196,6 +2989,7 @@ impl HasContext for Context {
         level: i32,
         parameter: u32,
     ) -> f32 {
+        self.validate(texture, "get_texture_level_parameter_f32");
         let gl = &self.raw;
         let mut value = 0.0;
         gl.GetTextureLevelParameterfv(texture.0.get(), level, parameter, &mut value);
@@ -2290,7 +3084,7 @@ impl HasContext for Context {
     unsafe fn get_parameter_indexed_string(&self, parameter: u32, index: u32) -> String {
         let gl = &self.raw;
         let raw_ptr = gl.GetStringi(parameter, index);
-        std::ffi::CStr::from_ptr(raw_ptr as *const native_gl::GLchar)
+        core::ffi::CStr::from_ptr(raw_ptr as *const native_gl::GLchar)
             .to_str()
             .unwrap()
             .to_owned()
@@ -2305,7 +3099,7 @@ impl HasContext for Context {
                 parameter
             )
         }
-        std::ffi::CStr::from_ptr(raw_ptr as *const native_gl::GLchar)
+        core::ffi::CStr::from_ptr(raw_ptr as *const native_gl::GLchar)
             .to_str()
             .unwrap()
             .to_owned()
@@ -2367,6 +3161,7 @@ impl HasContext for Context {
         framebuffer: Option<Self::Framebuffer>,
         parameter: u32,
     ) -> i32 {
+        self.validate(framebuffer, "get_named_framebuffer_parameter_i32");
         let gl = &self.raw;
         let mut value = 0;
         gl.GetNamedFramebufferParameteriv(
@@ -2395,6 +3190,10 @@ impl HasContext for Context {
         attachment: u32,
         parameter: u32,
     ) -> i32 {
+        self.validate(
+            framebuffer,
+            "get_named_framebuffer_attachment_parameter_i32",
+        );
         let gl = &self.raw;
         let mut value = 0;
         gl.GetNamedFramebufferAttachmentParameteriv(
@@ -2411,6 +3210,7 @@ impl HasContext for Context {
         program: Self::Program,
         name: &str,
     ) -> Option<Self::UniformLocation> {
+        self.validate(program, "get_uniform_location");
         let gl = &self.raw;
         let name = CString::new(name).unwrap();
         let uniform_location =
@@ -2423,6 +3223,7 @@ impl HasContext for Context {
     }
 
     unsafe fn get_attrib_location(&self, program: Self::Program, name: &str) -> Option<u32> {
+        self.validate(program, "get_attrib_location");
         let gl = &self.raw;
         let name = CString::new(name).unwrap();
         let attrib_location =
@@ -2435,6 +3236,7 @@ impl HasContext for Context {
     }
 
     unsafe fn bind_attrib_location(&self, program: Self::Program, index: u32, name: &str) {
+        self.validate(program, "bind_attrib_location");
         let gl = &self.raw;
         let name = CString::new(name).unwrap();
         gl.BindAttribLocation(
@@ -2445,6 +3247,7 @@ impl HasContext for Context {
     }
 
     unsafe fn get_active_attributes(&self, program: Self::Program) -> u32 {
+        self.validate(program, "get_active_attributes");
         let gl = &self.raw;
         let mut count = 0;
         gl.GetProgramiv(program.0.get(), ACTIVE_ATTRIBUTES, &mut count);
@@ -2456,6 +3259,7 @@ impl HasContext for Context {
         program: Self::Program,
         index: u32,
     ) -> Option<ActiveAttribute> {
+        self.validate(program, "get_active_attribute");
         let gl = &self.raw;
         let mut attribute_max_size = 0;
         gl.GetProgramiv(
@@ -2464,7 +3268,7 @@ impl HasContext for Context {
             &mut attribute_max_size,
         );
         let mut name = String::with_capacity(attribute_max_size as usize);
-        name.extend(std::iter::repeat('\0').take(attribute_max_size as usize));
+        name.extend(core::iter::repeat('\0').take(attribute_max_size as usize));
         let mut length = 0;
         let mut size = 0;
         let mut atype = 0;
@@ -2484,6 +3288,7 @@ impl HasContext for Context {
     }
 
     unsafe fn get_sync_status(&self, fence: Self::Fence) -> u32 {
+        self.validate(fence, "get_sync_status");
         let gl = &self.raw;
         let mut len = 0;
         let mut values = [UNSIGNALED as i32];
@@ -2509,6 +3314,9 @@ impl HasContext for Context {
         width: i32,
         height: i32,
     ) {
+        if let Some(memory) = &self.memory {
+            memory.bound_renderbuffer(self, 1, internal_format, width, height);
+        }
         let gl = &self.raw;
         gl.RenderbufferStorage(target, internal_format, width, height);
     }
@@ -2521,26 +3329,33 @@ impl HasContext for Context {
         width: i32,
         height: i32,
     ) {
+        if let Some(memory) = &self.memory {
+            memory.bound_renderbuffer(self, samples, internal_format, width, height);
+        }
         let gl = &self.raw;
         gl.RenderbufferStorageMultisample(target, samples, internal_format, width, height);
     }
 
     unsafe fn sampler_parameter_f32(&self, sampler: Self::Sampler, name: u32, value: f32) {
+        self.validate(sampler, "sampler_parameter_f32");
         let gl = &self.raw;
         gl.SamplerParameterf(sampler.0.get(), name, value);
     }
 
     unsafe fn sampler_parameter_f32_slice(&self, sampler: Self::Sampler, name: u32, value: &[f32]) {
+        self.validate(sampler, "sampler_parameter_f32_slice");
         let gl = &self.raw;
         gl.SamplerParameterfv(sampler.0.get(), name, value.as_ptr());
     }
 
     unsafe fn sampler_parameter_i32(&self, sampler: Self::Sampler, name: u32, value: i32) {
+        self.validate(sampler, "sampler_parameter_i32");
         let gl = &self.raw;
         gl.SamplerParameteri(sampler.0.get(), name, value);
     }
 
     unsafe fn get_sampler_parameter_i32(&self, sampler: Self::Sampler, name: u32) -> i32 {
+        self.validate(sampler, "get_sampler_parameter_i32");
         let gl = &self.raw;
         let mut value = 0;
         gl.GetSamplerParameteriv(sampler.0.get(), name, &mut value);
@@ -2548,6 +3363,7 @@ impl HasContext for Context {
     }
 
     unsafe fn get_sampler_parameter_f32(&self, sampler: Self::Sampler, name: u32) -> f32 {
+        self.validate(sampler, "get_sampler_parameter_f32");
         let gl = &self.raw;
         let mut value = 0.;
         gl.GetSamplerParameterfv(sampler.0.get(), name, &mut value);
@@ -2560,16 +3376,41 @@ impl HasContext for Context {
         name: u32,
         out: &mut [f32],
     ) {
+        self.validate(sampler, "get_sampler_parameter_f32_slice");
         let gl = &self.raw;
         gl.GetSamplerParameterfv(sampler.0.get(), name, out.as_mut_ptr());
     }
 
     unsafe fn generate_mipmap(&self, target: u32) {
+        if let Some(memory) = &self.memory {
+            memory.bound_generate_mipmap(self, target);
+        }
         let gl = &self.raw;
+        // `TEXTURE_BASE_LEVEL` doesn't exist before OpenGL 1.2 and OpenGL ES 3.0, so there's
+        // nothing to reset, even if the workaround was enabled with `set_workarounds`
+        let has_base_level = if self.version.is_embedded {
+            self.version.major >= 3
+        } else {
+            (self.version.major, self.version.minor) >= (1, 2)
+        };
+        if self.workarounds.reset_base_level_before_generate_mipmap && has_base_level {
+            let mut base_level = 0;
+            gl.GetTexParameteriv(target, TEXTURE_BASE_LEVEL, &mut base_level);
+            if base_level != 0 {
+                gl.TexParameteri(target, TEXTURE_BASE_LEVEL, 0);
+                gl.GenerateMipmap(target);
+                gl.TexParameteri(target, TEXTURE_BASE_LEVEL, base_level);
+                return;
+            }
+        }
         gl.GenerateMipmap(target);
     }
 
     unsafe fn generate_texture_mipmap(&self, texture: Self::Texture) {
+        self.validate(texture, "generate_texture_mipmap");
+        if let Some(memory) = &self.memory {
+            memory.generate_mipmap(texture);
+        }
         let gl = &self.raw;
         gl.GenerateTextureMipmap(texture.0.get());
     }
@@ -2585,6 +3426,26 @@ impl HasContext for Context {
         ty: u32,
         pixels: PixelUnpackData,
     ) {
+        format::validate_unpack(
+            self,
+            self.validation,
+            "tex_image_1d",
+            width,
+            1,
+            1,
+            format,
+            ty,
+            &pixels,
+        );
+        if let Some(memory) = &self.memory {
+            memory.bound_texture_level(
+                self,
+                target,
+                level,
+                memory::image_bytes(internal_format as u32, format, ty, width, 1, 1),
+                (width, 1, 1),
+            );
+        }
         let gl = &self.raw;
         gl.TexImage1D(
             target,
@@ -2595,8 +3456,8 @@ impl HasContext for Context {
             format,
             ty,
             match pixels {
-                PixelUnpackData::BufferOffset(offset) => offset as *const std::ffi::c_void,
-                PixelUnpackData::Slice(Some(data)) => data.as_ptr() as *const std::ffi::c_void,
+                PixelUnpackData::BufferOffset(offset) => offset as *const core::ffi::c_void,
+                PixelUnpackData::Slice(Some(data)) => data.as_ptr() as *const core::ffi::c_void,
                 PixelUnpackData::Slice(None) => ptr::null(),
             },
         );
@@ -2612,6 +3473,25 @@ impl HasContext for Context {
         image_size: i32,
         pixels: &[u8],
     ) {
+        format::validate_compressed(
+            self.validation,
+            "compressed_tex_image_1d",
+            internal_format as u32,
+            width,
+            1,
+            1,
+            image_size,
+            pixels,
+        );
+        if let Some(memory) = &self.memory {
+            memory.bound_texture_level(
+                self,
+                target,
+                level,
+                image_size.max(0) as u64,
+                (width, 1, 1),
+            );
+        }
         let gl = &self.raw;
         gl.CompressedTexImage1D(
             target,
@@ -2620,7 +3500,7 @@ impl HasContext for Context {
             width,
             border,
             image_size,
-            pixels.as_ptr() as *const std::ffi::c_void,
+            pixels.as_ptr() as *const core::ffi::c_void,
         );
     }
 
@@ -2636,6 +3516,26 @@ impl HasContext for Context {
         ty: u32,
         pixels: PixelUnpackData,
     ) {
+        format::validate_unpack(
+            self,
+            self.validation,
+            "tex_image_2d",
+            width,
+            height,
+            1,
+            format,
+            ty,
+            &pixels,
+        );
+        if let Some(memory) = &self.memory {
+            memory.bound_texture_level(
+                self,
+                target,
+                level,
+                memory::image_bytes(internal_format as u32, format, ty, width, height, 1),
+                (width, height, 1),
+            );
+        }
         let gl = &self.raw;
         gl.TexImage2D(
             target,
@@ -2647,8 +3547,8 @@ impl HasContext for Context {
             format,
             ty,
             match pixels {
-                PixelUnpackData::BufferOffset(offset) => offset as *const std::ffi::c_void,
-                PixelUnpackData::Slice(Some(data)) => data.as_ptr() as *const std::ffi::c_void,
+                PixelUnpackData::BufferOffset(offset) => offset as *const core::ffi::c_void,
+                PixelUnpackData::Slice(Some(data)) => data.as_ptr() as *const core::ffi::c_void,
                 PixelUnpackData::Slice(None) => ptr::null(),
             },
         );
@@ -2663,6 +3563,16 @@ impl HasContext for Context {
         height: i32,
         fixed_sample_locations: bool,
     ) {
+        if let Some(memory) = &self.memory {
+            memory.bound_texture_storage(
+                self,
+                target,
+                1,
+                samples,
+                internal_format as u32,
+                (width, height, 1),
+            );
+        }
         let gl = &self.raw;
         gl.TexImage2DMultisample(
             target,
@@ -2685,6 +3595,25 @@ impl HasContext for Context {
         image_size: i32,
         pixels: &[u8],
     ) {
+        format::validate_compressed(
+            self.validation,
+            "compressed_tex_image_2d",
+            internal_format as u32,
+            width,
+            height,
+            1,
+            image_size,
+            pixels,
+        );
+        if let Some(memory) = &self.memory {
+            memory.bound_texture_level(
+                self,
+                target,
+                level,
+                image_size.max(0) as u64,
+                (width, height, 1),
+            );
+        }
         let gl = &self.raw;
         gl.CompressedTexImage2D(
             target,
@@ -2694,7 +3623,7 @@ impl HasContext for Context {
             height,
             border,
             image_size,
-            pixels.as_ptr() as *const std::ffi::c_void,
+            pixels.as_ptr() as *const core::ffi::c_void,
         );
     }
 
@@ -2711,6 +3640,26 @@ impl HasContext for Context {
         ty: u32,
         pixels: PixelUnpackData,
     ) {
+        format::validate_unpack(
+            self,
+            self.validation,
+            "tex_image_3d",
+            width,
+            height,
+            depth,
+            format,
+            ty,
+            &pixels,
+        );
+        if let Some(memory) = &self.memory {
+            memory.bound_texture_level(
+                self,
+                target,
+                level,
+                memory::image_bytes(internal_format as u32, format, ty, width, height, depth),
+                (width, height, depth),
+            );
+        }
         let gl = &self.raw;
         gl.TexImage3D(
             target,
@@ -2723,8 +3672,8 @@ impl HasContext for Context {
             format,
             ty,
             match pixels {
-                PixelUnpackData::BufferOffset(offset) => offset as *const std::ffi::c_void,
-                PixelUnpackData::Slice(Some(data)) => data.as_ptr() as *const std::ffi::c_void,
+                PixelUnpackData::BufferOffset(offset) => offset as *const core::ffi::c_void,
+                PixelUnpackData::Slice(Some(data)) => data.as_ptr() as *const core::ffi::c_void,
                 PixelUnpackData::Slice(None) => ptr::null(),
             },
         );
@@ -2742,6 +3691,25 @@ impl HasContext for Context {
         image_size: i32,
         pixels: &[u8],
     ) {
+        format::validate_compressed(
+            self.validation,
+            "compressed_tex_image_3d",
+            internal_format as u32,
+            width,
+            height,
+            depth,
+            image_size,
+            pixels,
+        );
+        if let Some(memory) = &self.memory {
+            memory.bound_texture_level(
+                self,
+                target,
+                level,
+                image_size.max(0) as u64,
+                (width, height, depth),
+            );
+        }
         let gl = &self.raw;
         gl.CompressedTexImage3D(
             target,
@@ -2752,11 +3720,14 @@ impl HasContext for Context {
             depth,
             border,
             image_size,
-            pixels.as_ptr() as *const std::ffi::c_void,
+            pixels.as_ptr() as *const core::ffi::c_void,
         );
     }
 
     unsafe fn tex_storage_1d(&self, target: u32, levels: i32, internal_format: u32, width: i32) {
+        if let Some(memory) = &self.memory {
+            memory.bound_texture_storage(self, target, levels, 1, internal_format, (width, 1, 1));
+        }
         let gl = &self.raw;
         gl.TexStorage1D(target, levels, internal_format, width);
     }
@@ -2769,6 +3740,16 @@ impl HasContext for Context {
         width: i32,
         height: i32,
     ) {
+        if let Some(memory) = &self.memory {
+            memory.bound_texture_storage(
+                self,
+                target,
+                levels,
+                1,
+                internal_format,
+                (width, height, 1),
+            );
+        }
         let gl = &self.raw;
         gl.TexStorage2D(target, levels, internal_format, width, height);
     }
@@ -2781,6 +3762,17 @@ impl HasContext for Context {
         width: i32,
         height: i32,
     ) {
+        self.validate(texture, "texture_storage_2d");
+        if let Some(memory) = &self.memory {
+            memory.texture_storage(
+                texture,
+                self.texture_target(texture),
+                levels,
+                1,
+                internal_format,
+                (width, height, 1),
+            );
+        }
         let gl = &self.raw;
         gl.TextureStorage2D(texture.0.get(), levels, internal_format, width, height);
     }
@@ -2794,6 +3786,16 @@ impl HasContext for Context {
         height: i32,
         fixed_sample_locations: bool,
     ) {
+        if let Some(memory) = &self.memory {
+            memory.bound_texture_storage(
+                self,
+                target,
+                1,
+                samples,
+                internal_format,
+                (width, height, 1),
+            );
+        }
         let gl = &self.raw;
         gl.TexStorage2DMultisample(
             target,
@@ -2814,6 +3816,16 @@ impl HasContext for Context {
         height: i32,
         depth: i32,
     ) {
+        if let Some(memory) = &self.memory {
+            memory.bound_texture_storage(
+                self,
+                target,
+                levels,
+                1,
+                internal_format,
+                (width, height, depth),
+            );
+        }
         let gl = &self.raw;
         gl.TexStorage3D(target, levels, internal_format, width, height, depth);
     }
@@ -2827,6 +3839,17 @@ impl HasContext for Context {
         height: i32,
         depth: i32,
     ) {
+        self.validate(texture, "texture_storage_3d");
+        if let Some(memory) = &self.memory {
+            memory.texture_storage(
+                texture,
+                self.texture_target(texture),
+                levels,
+                1,
+                internal_format,
+                (width, height, depth),
+            );
+        }
         let gl = &self.raw;
         gl.TextureStorage3D(
             texture.0.get(),
@@ -2844,6 +3867,7 @@ impl HasContext for Context {
         location: &Self::UniformLocation,
         v: &mut [i32],
     ) {
+        self.validate(program, "get_uniform_i32");
         let gl = &self.raw;
         gl.GetUniformiv(program.0.get(), location.0 as i32, v.as_mut_ptr())
     }
@@ -2854,6 +3878,7 @@ impl HasContext for Context {
         location: &Self::UniformLocation,
         v: &mut [u32],
     ) {
+        self.validate(program, "get_uniform_u32");
         let gl = &self.raw;
         gl.GetUniformuiv(program.0.get(), location.0 as i32, v.as_mut_ptr())
     }
@@ -2864,6 +3889,7 @@ impl HasContext for Context {
         location: &Self::UniformLocation,
         v: &mut [f32],
     ) {
+        self.validate(program, "get_uniform_f32");
         let gl = &self.raw;
         gl.GetUniformfv(program.0.get(), location.0 as i32, v.as_mut_ptr())
     }
@@ -3292,6 +4318,9 @@ impl HasContext for Context {
     }
 
     unsafe fn invalidate_framebuffer(&self, target: u32, attachments: &[u32]) {
+        if self.workarounds.skip_invalidate_framebuffer {
+            return;
+        }
         let gl = &self.raw;
         gl.InvalidateFramebuffer(target, attachments.len() as i32, attachments.as_ptr());
     }
@@ -3305,6 +4334,9 @@ impl HasContext for Context {
         width: i32,
         height: i32,
     ) {
+        if self.workarounds.skip_invalidate_framebuffer {
+            return;
+        }
         let gl = &self.raw;
         gl.InvalidateSubFramebuffer(
             target,
@@ -3333,16 +4365,19 @@ impl HasContext for Context {
     }
 
     unsafe fn bind_texture(&self, target: u32, texture: Option<Self::Texture>) {
+        self.validate(texture, "bind_texture");
         let gl = &self.raw;
         gl.BindTexture(target, texture.map(|t| t.0.get()).unwrap_or(0));
     }
 
     unsafe fn bind_texture_unit(&self, unit: u32, texture: Option<Self::Texture>) {
+        self.validate(texture, "bind_texture_unit");
         let gl = &self.raw;
         gl.BindTextureUnit(unit, texture.map(|t| t.0.get()).unwrap_or(0));
     }
 
     unsafe fn bind_sampler(&self, unit: u32, sampler: Option<Self::Sampler>) {
+        self.validate(sampler, "bind_sampler");
         let gl = &self.raw;
         gl.BindSampler(unit, sampler.map(|s| s.0.get()).unwrap_or(0));
     }
@@ -3354,7 +4389,7 @@ impl HasContext for Context {
 
     unsafe fn fence_sync(&self, condition: u32, flags: u32) -> Result<Self::Fence, String> {
         let gl = &self.raw;
-        Ok(NativeFence(gl.FenceSync(condition as u32, flags)))
+        self.track_created(Ok(NativeFence(gl.FenceSync(condition as u32, flags))))
     }
 
     unsafe fn tex_parameter_f32(&self, target: u32, parameter: u32, value: f32) {
@@ -3368,6 +4403,7 @@ impl HasContext for Context {
     }
 
     unsafe fn texture_parameter_i32(&self, texture: Self::Texture, parameter: u32, value: i32) {
+        self.validate(texture, "texture_parameter_i32");
         let gl = &self.raw;
         gl.TextureParameteri(texture.0.get(), parameter, value);
     }
@@ -3394,6 +4430,17 @@ impl HasContext for Context {
         ty: u32,
         pixels: PixelUnpackData,
     ) {
+        format::validate_unpack(
+            self,
+            self.validation,
+            "tex_sub_image_2d",
+            width,
+            height,
+            1,
+            format,
+            ty,
+            &pixels,
+        );
         let gl = &self.raw;
         gl.TexSubImage2D(
             target,
@@ -3405,8 +4452,8 @@ impl HasContext for Context {
             format,
             ty,
             match pixels {
-                PixelUnpackData::BufferOffset(offset) => offset as *const std::ffi::c_void,
-                PixelUnpackData::Slice(Some(data)) => data.as_ptr() as *const std::ffi::c_void,
+                PixelUnpackData::BufferOffset(offset) => offset as *const core::ffi::c_void,
+                PixelUnpackData::Slice(Some(data)) => data.as_ptr() as *const core::ffi::c_void,
                 PixelUnpackData::Slice(None) => ptr::null(),
             },
         );
@@ -3424,6 +4471,18 @@ impl HasContext for Context {
         ty: u32,
         pixels: PixelUnpackData,
     ) {
+        self.validate(texture, "texture_sub_image_2d");
+        format::validate_unpack(
+            self,
+            self.validation,
+            "texture_sub_image_2d",
+            width,
+            height,
+            1,
+            format,
+            ty,
+            &pixels,
+        );
         let gl = &self.raw;
         gl.TextureSubImage2D(
             texture.0.get(),
@@ -3435,8 +4494,8 @@ impl HasContext for Context {
             format,
             ty,
             match pixels {
-                PixelUnpackData::BufferOffset(offset) => offset as *const std::ffi::c_void,
-                PixelUnpackData::Slice(Some(data)) => data.as_ptr() as *const std::ffi::c_void,
+                PixelUnpackData::BufferOffset(offset) => offset as *const core::ffi::c_void,
+                PixelUnpackData::Slice(Some(data)) => data.as_ptr() as *const core::ffi::c_void,
                 PixelUnpackData::Slice(None) => ptr::null(),
             },
         );
@@ -3453,14 +4512,26 @@ impl HasContext for Context {
         format: u32,
         pixels: CompressedPixelUnpackData,
     ) {
+        if let CompressedPixelUnpackData::Slice(data) = &pixels {
+            format::validate_compressed(
+                self.validation,
+                "compressed_tex_sub_image_2d",
+                format,
+                width,
+                height,
+                1,
+                data.len() as i32,
+                data,
+            );
+        }
         let gl = &self.raw;
         let (data, image_size) = match pixels {
             CompressedPixelUnpackData::BufferRange(ref range) => (
-                range.start as *const std::ffi::c_void,
+                range.start as *const core::ffi::c_void,
                 (range.end - range.start) as i32,
             ),
             CompressedPixelUnpackData::Slice(data) => {
-                (data.as_ptr() as *const std::ffi::c_void, data.len() as i32)
+                (data.as_ptr() as *const core::ffi::c_void, data.len() as i32)
             }
         };
 
@@ -3483,6 +4554,17 @@ impl HasContext for Context {
         ty: u32,
         pixels: PixelUnpackData,
     ) {
+        format::validate_unpack(
+            self,
+            self.validation,
+            "tex_sub_image_3d",
+            width,
+            height,
+            depth,
+            format,
+            ty,
+            &pixels,
+        );
         let gl = &self.raw;
         gl.TexSubImage3D(
             target,
@@ -3496,8 +4578,8 @@ impl HasContext for Context {
             format,
             ty,
             match pixels {
-                PixelUnpackData::BufferOffset(offset) => offset as *const std::ffi::c_void,
-                PixelUnpackData::Slice(Some(data)) => data.as_ptr() as *const std::ffi::c_void,
+                PixelUnpackData::BufferOffset(offset) => offset as *const core::ffi::c_void,
+                PixelUnpackData::Slice(Some(data)) => data.as_ptr() as *const core::ffi::c_void,
                 PixelUnpackData::Slice(None) => ptr::null(),
             },
         );
@@ -3517,6 +4599,18 @@ impl HasContext for Context {
         ty: u32,
         pixels: PixelUnpackData,
     ) {
+        self.validate(texture, "texture_sub_image_3d");
+        format::validate_unpack(
+            self,
+            self.validation,
+            "texture_sub_image_3d",
+            width,
+            height,
+            depth,
+            format,
+            ty,
+            &pixels,
+        );
         let gl = &self.raw;
         gl.TextureSubImage3D(
             texture.0.get(),
@@ -3530,8 +4624,8 @@ impl HasContext for Context {
             format,
             ty,
             match pixels {
-                PixelUnpackData::BufferOffset(offset) => offset as *const std::ffi::c_void,
-                PixelUnpackData::Slice(Some(data)) => data.as_ptr() as *const std::ffi::c_void,
+                PixelUnpackData::BufferOffset(offset) => offset as *const core::ffi::c_void,
+                PixelUnpackData::Slice(Some(data)) => data.as_ptr() as *const core::ffi::c_void,
                 PixelUnpackData::Slice(None) => ptr::null(),
             },
         );
@@ -3550,14 +4644,26 @@ impl HasContext for Context {
         format: u32,
         pixels: CompressedPixelUnpackData,
     ) {
+        if let CompressedPixelUnpackData::Slice(data) = &pixels {
+            format::validate_compressed(
+                self.validation,
+                "compressed_tex_sub_image_3d",
+                format,
+                width,
+                height,
+                depth,
+                data.len() as i32,
+                data,
+            );
+        }
         let gl = &self.raw;
         let (data, image_size) = match pixels {
             CompressedPixelUnpackData::BufferRange(ref range) => (
-                range.start as *const std::ffi::c_void,
+                range.start as *const core::ffi::c_void,
                 (range.end - range.start) as i32,
             ),
             CompressedPixelUnpackData::Slice(data) => {
-                (data.as_ptr() as *const std::ffi::c_void, data.len() as i32)
+                (data.as_ptr() as *const core::ffi::c_void, data.len() as i32)
             }
         };
 
@@ -3611,6 +4717,7 @@ impl HasContext for Context {
         index: u32,
         binding_index: u32,
     ) {
+        self.validate(vao, "vertex_array_attrib_binding_f32");
         let gl = &self.raw;
         gl.VertexArrayAttribBinding(vao.0.get(), index, binding_index);
     }
@@ -3624,6 +4731,7 @@ impl HasContext for Context {
         normalized: bool,
         relative_offset: u32,
     ) {
+        self.validate(vao, "vertex_array_attrib_format_f32");
         let gl = &self.raw;
         gl.VertexArrayAttribFormat(
             vao.0.get(),
@@ -3643,6 +4751,7 @@ impl HasContext for Context {
         data_type: u32,
         relative_offset: u32,
     ) {
+        self.validate(vao, "vertex_array_attrib_format_i32");
         let gl = &self.raw;
         gl.VertexArrayAttribIFormat(vao.0.get(), index, size, data_type, relative_offset);
     }
@@ -3655,6 +4764,7 @@ impl HasContext for Context {
         data_type: u32,
         relative_offset: u32,
     ) {
+        self.validate(vao, "vertex_array_attrib_format_f64");
         let gl = &self.raw;
         gl.VertexArrayAttribLFormat(vao.0.get(), index, size, data_type, relative_offset);
     }
@@ -3664,6 +4774,8 @@ impl HasContext for Context {
         vao: Self::VertexArray,
         buffer: Option<Self::Buffer>,
     ) {
+        self.validate(vao, "vertex_array_element_buffer");
+        self.validate(buffer, "vertex_array_element_buffer");
         let gl = &self.raw;
         gl.VertexArrayElementBuffer(vao.0.get(), buffer.map(|b| b.0.get()).unwrap_or(0));
     }
@@ -3676,6 +4788,8 @@ impl HasContext for Context {
         offset: i32,
         stride: i32,
     ) {
+        self.validate(vao, "vertex_array_vertex_buffer");
+        self.validate(buffer, "vertex_array_vertex_buffer");
         let gl = &self.raw;
         gl.VertexArrayVertexBuffer(
             vao.0.get(),
@@ -3717,7 +4831,7 @@ impl HasContext for Context {
             data_type,
             normalized as u8,
             stride,
-            offset as *const std::ffi::c_void,
+            offset as *const core::ffi::c_void,
         );
     }
 
@@ -3735,7 +4849,7 @@ impl HasContext for Context {
             size,
             data_type,
             stride,
-            offset as *const std::ffi::c_void,
+            offset as *const core::ffi::c_void,
         );
     }
 
@@ -3753,7 +4867,7 @@ impl HasContext for Context {
             size,
             data_type,
             stride,
-            offset as *const std::ffi::c_void,
+            offset as *const core::ffi::c_void,
         );
     }
 
@@ -3976,7 +5090,7 @@ impl HasContext for Context {
         let gl = &self.raw;
 
         let ids_ptr = if ids.is_empty() {
-            std::ptr::null()
+            core::ptr::null()
         } else {
             ids.as_ptr()
         };
@@ -4025,16 +5139,10 @@ impl HasContext for Context {
             None => {
                 let trait_object: DebugCallback = Box::new(callback);
                 let thin_ptr = Box::new(trait_object);
-                let raw_ptr = Box::into_raw(thin_ptr) as *mut _ as *mut std::ffi::c_void;
+                let raw_ptr = Box::into_raw(thin_ptr) as *mut _ as *mut core::ffi::c_void;
 
                 let gl = &self.raw;
-
-                if gl.DebugMessageCallback_is_loaded() {
-                    gl.DebugMessageCallback(Some(raw_debug_message_callback), raw_ptr);
-                } else {
-                    // Fallback to extension
-                    gl.DebugMessageCallbackKHR(Some(raw_debug_message_callback), raw_ptr);
-                }
+                gl.DebugMessageCallback(Some(raw_debug_message_callback), raw_ptr);
 
                 self.debug_callback = Some(DebugCallbackRawPtr { callback: raw_ptr });
             }
@@ -4074,7 +5182,7 @@ impl HasContext for Context {
         let mut offset = 0;
         for i in 0..received {
             let message =
-                std::ffi::CStr::from_ptr(message_log[offset..].as_ptr()).to_string_lossy();
+                core::ffi::CStr::from_ptr(message_log[offset..].as_ptr()).to_string_lossy();
             offset += lengths[i] as usize;
             entries.push(DebugMessageLogEntry {
                 source: sources[i],
@@ -4120,23 +5228,23 @@ impl HasContext for Context {
                     lbl.as_ptr() as *const native_gl::GLchar,
                 );
             }
-            None => gl.ObjectLabel(identifier, name, 0, std::ptr::null()),
+            None => gl.ObjectLabel(identifier, name, 0, core::ptr::null()),
         }
     }
 
     unsafe fn get_object_label(&self, identifier: u32, name: u32) -> String {
         let gl = &self.raw;
         let mut len = 0;
-        let mut label_buf = Vec::with_capacity(self.constants.max_label_length as usize);
+        let mut label_buf = Vec::with_capacity(self.limits.max_label_length as usize);
         gl.GetObjectLabel(
             identifier,
             name,
-            self.constants.max_label_length,
+            self.limits.max_label_length,
             &mut len,
             label_buf.as_mut_ptr(),
         );
         label_buf.set_len(len as usize);
-        std::ffi::CStr::from_ptr(label_buf.as_ptr())
+        core::ffi::CStr::from_ptr(label_buf.as_ptr())
             .to_str()
             .unwrap()
             .to_owned()
@@ -4146,6 +5254,7 @@ impl HasContext for Context {
     where
         S: AsRef<str>,
     {
+        self.validate(sync, "object_ptr_label");
         let gl = &self.raw;
 
         match label {
@@ -4153,33 +5262,35 @@ impl HasContext for Context {
                 let lbl = l.as_ref().as_bytes();
                 let length = lbl.len() as i32;
                 gl.ObjectPtrLabel(
-                    sync.0 as *mut std::ffi::c_void,
+                    sync.0 as *mut core::ffi::c_void,
                     length,
                     lbl.as_ptr() as *const native_gl::GLchar,
                 );
             }
-            None => gl.ObjectPtrLabel(sync.0 as *mut std::ffi::c_void, 0, std::ptr::null()),
+            None => gl.ObjectPtrLabel(sync.0 as *mut core::ffi::c_void, 0, core::ptr::null()),
         }
     }
 
     unsafe fn get_object_ptr_label(&self, sync: Self::Fence) -> String {
+        self.validate(sync, "get_object_ptr_label");
         let gl = &self.raw;
         let mut len = 0;
-        let mut label_buf = Vec::with_capacity(self.constants.max_label_length as usize);
+        let mut label_buf = Vec::with_capacity(self.limits.max_label_length as usize);
         gl.GetObjectPtrLabel(
-            sync.0 as *mut std::ffi::c_void,
-            self.constants.max_label_length,
+            sync.0 as *mut core::ffi::c_void,
+            self.limits.max_label_length,
             &mut len,
             label_buf.as_mut_ptr(),
         );
         label_buf.set_len(len as usize);
-        std::ffi::CStr::from_ptr(label_buf.as_ptr())
+        core::ffi::CStr::from_ptr(label_buf.as_ptr())
             .to_str()
             .unwrap()
             .to_owned()
     }
 
     unsafe fn get_uniform_block_index(&self, program: Self::Program, name: &str) -> Option<u32> {
+        self.validate(program, "get_uniform_block_index");
         let gl = &self.raw;
         let name = CString::new(name).unwrap();
         let index = gl.GetUniformBlockIndex(program.0.get(), name.as_ptr());
@@ -4195,6 +5306,7 @@ impl HasContext for Context {
         program: Self::Program,
         names: &[&str],
     ) -> Vec<Option<u32>> {
+        self.validate(program, "get_uniform_indices");
         let gl = &self.raw;
         let c_names = names
             .iter()
@@ -4223,6 +5335,7 @@ impl HasContext for Context {
     }
 
     unsafe fn uniform_block_binding(&self, program: Self::Program, index: u32, binding: u32) {
+        self.validate(program, "uniform_block_binding");
         let gl = &self.raw;
         gl.UniformBlockBinding(program.0.get(), index, binding);
     }
@@ -4232,6 +5345,7 @@ impl HasContext for Context {
         program: Self::Program,
         name: &str,
     ) -> Option<u32> {
+        self.validate(program, "get_shader_storage_block_index");
         let gl = &self.raw;
         let name = CString::new(name).unwrap();
         let index =
@@ -4249,6 +5363,7 @@ impl HasContext for Context {
         index: u32,
         binding: u32,
     ) {
+        self.validate(program, "shader_storage_block_binding");
         let gl = &self.raw;
         gl.ShaderStorageBlockBinding(program.0.get(), index, binding);
     }
@@ -4263,6 +5378,7 @@ impl HasContext for Context {
         framebuffer: Option<Self::Framebuffer>,
         src: u32,
     ) {
+        self.validate(framebuffer, "named_framebuffer_read_buffer");
         let gl = &self.raw;
         gl.NamedFramebufferReadBuffer(framebuffer.map(|f| f.0.get()).unwrap_or(0), src);
     }
@@ -4277,6 +5393,16 @@ impl HasContext for Context {
         gltype: u32,
         pixels: PixelPackData,
     ) {
+        format::validate_pack(
+            self,
+            self.validation,
+            "read_pixels",
+            width,
+            height,
+            format,
+            gltype,
+            &pixels,
+        );
         let gl = &self.raw;
         gl.ReadPixels(
             x,
@@ -4286,59 +5412,43 @@ impl HasContext for Context {
             format,
             gltype,
             match pixels {
-                PixelPackData::BufferOffset(offset) => offset as *mut std::ffi::c_void,
-                PixelPackData::Slice(Some(data)) => data.as_mut_ptr() as *mut std::ffi::c_void,
+                PixelPackData::BufferOffset(offset) => offset as *mut core::ffi::c_void,
+                PixelPackData::Slice(Some(data)) => data.as_mut_ptr() as *mut core::ffi::c_void,
                 PixelPackData::Slice(None) => ptr::null_mut(),
             },
         );
     }
 
     unsafe fn begin_query(&self, target: u32, query: Self::Query) {
+        self.validate(query, "begin_query");
         let gl = &self.raw;
-        if gl.BeginQuery_is_loaded() {
-            gl.BeginQuery(target, query.0.get());
-        } else {
-            gl.BeginQueryEXT(target, query.0.get());
-        }
+        gl.BeginQuery(target, query.0.get());
     }
 
     unsafe fn end_query(&self, target: u32) {
         let gl = &self.raw;
-        if gl.EndQuery_is_loaded() {
-            gl.EndQuery(target);
-        } else {
-            gl.EndQueryEXT(target);
-        }
+        gl.EndQuery(target);
     }
 
     unsafe fn query_counter(&self, query: Self::Query, target: u32) {
+        self.validate(query, "query_counter");
         let gl = &self.raw;
-        if gl.QueryCounter_is_loaded() {
-            gl.QueryCounter(query.0.get(), target);
-        } else {
-            gl.QueryCounterEXT(query.0.get(), target);
-        }
+        gl.QueryCounter(query.0.get(), target);
     }
 
     unsafe fn get_query_parameter_u32(&self, query: Self::Query, parameter: u32) -> u32 {
+        self.validate(query, "get_query_parameter_u32");
         let gl = &self.raw;
         let mut value = 0;
-        if gl.GetQueryBufferObjectiv_is_loaded() {
-            gl.GetQueryObjectuiv(query.0.get(), parameter, &mut value);
-        } else {
-            gl.GetQueryObjectuivEXT(query.0.get(), parameter, &mut value);
-        }
+        gl.GetQueryObjectuiv(query.0.get(), parameter, &mut value);
         value
     }
 
     unsafe fn get_query_parameter_u64(&self, query: Self::Query, parameter: u32) -> u64 {
+        self.validate(query, "get_query_parameter_u64");
         let gl = &self.raw;
         let mut value = 0;
-        if gl.GetQueryBufferObjectiv_is_loaded() {
-            gl.GetQueryObjectui64v(query.0.get(), parameter, &mut value);
-        } else {
-            gl.GetQueryObjectui64vEXT(query.0.get(), parameter, &mut value);
-        }
+        gl.GetQueryObjectui64v(query.0.get(), parameter, &mut value);
         value
     }
 
@@ -4348,21 +5458,20 @@ impl HasContext for Context {
         parameter: u32,
         offset: usize,
     ) {
+        self.validate(query, "get_query_parameter_u64_with_offset");
         let gl = &self.raw;
-        if gl.GetQueryObjectui64v_is_loaded() {
-            gl.GetQueryObjectui64v(query.0.get(), parameter, offset as *mut _);
-        } else {
-            gl.GetQueryObjectui64vEXT(query.0.get(), parameter, offset as *mut _);
-        }
+        gl.GetQueryObjectui64v(query.0.get(), parameter, offset as *mut _);
     }
 
     unsafe fn create_transform_feedback(&self) -> Result<Self::TransformFeedback, String> {
         let gl = &self.raw;
         let mut name = 0;
         gl.GenTransformFeedbacks(1, &mut name);
-        NonZeroU32::new(name)
-            .map(NativeTransformFeedback)
-            .ok_or_else(|| String::from("Unable to create TransformFeedback object"))
+        self.track_created(
+            NonZeroU32::new(name)
+                .map(NativeTransformFeedback)
+                .ok_or_else(|| String::from("Unable to create TransformFeedback object")),
+        )
     }
 
     unsafe fn is_transform_feedback(&self, transform_feedback: Self::TransformFeedback) -> bool {
@@ -4371,6 +5480,7 @@ impl HasContext for Context {
     }
 
     unsafe fn delete_transform_feedback(&self, transform_feedback: Self::TransformFeedback) {
+        self.validate_delete(transform_feedback, "delete_transform_feedback");
         let gl = &self.raw;
         gl.DeleteTransformFeedbacks(1, &transform_feedback.0.get());
     }
@@ -4380,6 +5490,7 @@ impl HasContext for Context {
         target: u32,
         transform_feedback: Option<Self::TransformFeedback>,
     ) {
+        self.validate(transform_feedback, "bind_transform_feedback");
         let gl = &self.raw;
         gl.BindTransformFeedback(target, transform_feedback.map(|tf| tf.0.get()).unwrap_or(0));
     }
@@ -4410,6 +5521,7 @@ impl HasContext for Context {
         varyings: &[&str],
         buffer_mode: u32,
     ) {
+        self.validate(program, "transform_feedback_varyings");
         let gl = &self.raw;
 
         let strings: Vec<CString> = varyings
@@ -4433,6 +5545,7 @@ impl HasContext for Context {
         program: Self::Program,
         index: u32,
     ) -> Option<ActiveTransformFeedback> {
+        self.validate(program, "get_transform_feedback_varying");
         let gl = &self.raw;
 
         const max_name_size: usize = 256;
@@ -4445,7 +5558,7 @@ impl HasContext for Context {
             program.0.get(),
             index,
             name_bytes.len() as i32,
-            std::ptr::null_mut(),
+            core::ptr::null_mut(),
             &mut size,
             &mut tftype,
             name_bytes.as_mut_ptr(),
@@ -4478,6 +5591,7 @@ impl HasContext for Context {
         access: u32,
         format: u32,
     ) {
+        self.validate(texture, "bind_image_texture");
         let gl = &self.raw;
         gl.BindImageTexture(
             unit,
@@ -4495,6 +5609,7 @@ impl HasContext for Context {
         uniform_block_index: u32,
         parameter: u32,
     ) -> i32 {
+        self.validate(program, "get_active_uniform_block_parameter_i32");
         let gl = &self.raw;
         let mut value = 0;
         gl.GetActiveUniformBlockiv(program.0.get(), uniform_block_index, parameter, &mut value);
@@ -4508,6 +5623,7 @@ impl HasContext for Context {
         parameter: u32,
         out: &mut [i32],
     ) {
+        self.validate(program, "get_active_uniform_block_parameter_i32_slice");
         let gl = &self.raw;
         gl.GetActiveUniformBlockiv(
             program.0.get(),
@@ -4521,6 +5637,7 @@ impl HasContext for Context {
         program: Self::Program,
         uniform_block_index: u32,
     ) -> String {
+        self.validate(program, "get_active_uniform_block_name");
         let gl = &self.raw;
 
         // Probe for the length of the name of the uniform block, and, failing
@@ -4550,18 +5667,18 @@ impl HasContext for Context {
 
         if length > 0 {
             assert_eq!(
-                std::mem::size_of::<u8>(),
-                std::mem::size_of::<native_gl::GLchar>(),
+                core::mem::size_of::<u8>(),
+                core::mem::size_of::<native_gl::GLchar>(),
                 "This operation is only safe in systems in which the length of \
                 a GLchar is the same as that of an u8"
             );
             assert_eq!(
-                std::mem::align_of::<u8>(),
-                std::mem::align_of::<native_gl::GLchar>(),
+                core::mem::align_of::<u8>(),
+                core::mem::align_of::<native_gl::GLchar>(),
                 "This operation is only safe in systems in which the alignment \
                 of a GLchar is the same as that of an u8"
             );
-            let buffer = std::slice::from_raw_parts(
+            let buffer = core::slice::from_raw_parts(
                 buffer.as_ptr() as *const u8,
                 (length as usize + 1).min(buffer.len()),
             );
@@ -4617,16 +5734,23 @@ impl HasContext for Context {
 
 impl Drop for Context {
     fn drop(&mut self) {
+        #[cfg(feature = "log")]
+        if self.leak_warning {
+            let census = unsafe { self.live_objects() };
+            if !census.is_empty() {
+                log::warn!(
+                    "glow: context dropped with {} live objects: {}",
+                    census.total(),
+                    census
+                );
+            }
+        }
         match self.debug_callback.take() {
             Some(_) => {
                 // Unset the debug callback before destroying the context.
                 unsafe {
                     let gl = &self.raw;
-                    if gl.DebugMessageCallback_is_loaded() {
-                        gl.DebugMessageCallback(None, std::ptr::null());
-                    } else {
-                        gl.DebugMessageCallbackKHR(None, std::ptr::null());
-                    }
+                    gl.DebugMessageCallback(None, core::ptr::null());
                 }
             }
             None => {}
@@ -4641,14 +5765,19 @@ extern "system" fn raw_debug_message_callback(
     severity: u32,
     length: i32,
     message: *const native_gl::GLchar,
-    user_param: *mut std::ffi::c_void,
+    user_param: *mut core::ffi::c_void,
 ) {
-    let _result = std::panic::catch_unwind(move || unsafe {
+    let call = move || unsafe {
         let callback: &DebugCallback = &*(user_param as *const DebugCallback);
-        let slice = std::slice::from_raw_parts(message as *const u8, length as usize);
+        let slice = core::slice::from_raw_parts(message as *const u8, length as usize);
         let msg = String::from_utf8_lossy(slice);
         (callback)(source, gltype, id, severity, &msg);
-    });
+    };
+    // Panics must not unwind into the driver
+    #[cfg(feature = "std")]
+    let _result = std::panic::catch_unwind(call);
+    #[cfg(not(feature = "std"))]
+    call();
 }
 
 #[cfg(test)]
@@ -4666,4 +5795,134 @@ mod tests {
         fn assert_sync<T: Sync>() {}
         assert_sync::<Context>();
     }
+
+    #[test]
+    fn test_try_from_loader_function() {
+        extern "system" fn get_string_null(_name: u32) -> *const u8 {
+            ptr::null()
+        }
+        extern "system" fn get_string_unknown(_name: u32) -> *const u8 {
+            b"Unknown\0".as_ptr()
+        }
+        // Called after every function with the `debug_automatic_glGetError` feature.
+        extern "system" fn get_error() -> u32 {
+            NO_ERROR
+        }
+
+        let create = |get_string: *const core::ffi::c_void| unsafe {
+            Context::try_from_loader_function(
+                |name| match name {
+                    "glGetString" => get_string,
+                    "glGetError" => get_error as *const _,
+                    _ => ptr::null(),
+                },
+                None,
+            )
+            .unwrap_err()
+        };
+        assert_eq!(create(ptr::null()), ContextCreationError::MissingVersion);
+        assert_eq!(
+            create(get_string_null as *const _),
+            ContextCreationError::MissingVersion
+        );
+        assert_eq!(
+            create(get_string_unknown as *const _),
+            ContextCreationError::UnparseableVersion("Unknown".to_string())
+        );
+    }
+
+    #[test]
+    fn test_get_string_lossy_unloaded() {
+        let raw = unsafe { native_gl::GlFns::load_lazily_with(|_| ptr::null_mut()) };
+        assert_eq!(unsafe { get_string_lossy(&raw, VENDOR) }, None);
+    }
+
+    #[test]
+    fn test_lazy_loading() {
+        let loaded = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let raw = {
+            let loaded = loaded.clone();
+            unsafe {
+                native_gl::GlFns::load_lazily_with(move |p| {
+                    let name = CStr::from_ptr(p).to_str().unwrap().to_owned();
+                    loaded.lock().unwrap().push(name);
+                    0x1000 as *mut core::ffi::c_void
+                })
+            }
+        };
+        assert!(loaded.lock().unwrap().is_empty());
+        assert!(raw.GetError_is_loaded());
+        assert!(raw.GetError_is_loaded());
+        assert_eq!(*loaded.lock().unwrap(), ["glGetError"]);
+    }
+
+    #[test]
+    fn test_lazy_loading_missing() {
+        let loaded = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let raw = {
+            let loaded = loaded.clone();
+            unsafe {
+                native_gl::GlFns::load_lazily_with(move |p| {
+                    let name = CStr::from_ptr(p).to_str().unwrap().to_owned();
+                    loaded.lock().unwrap().push(name);
+                    ptr::null_mut()
+                })
+            }
+        };
+        assert!(!raw.GetError_is_loaded());
+        assert!(!raw.GetError_is_loaded());
+        assert_eq!(*loaded.lock().unwrap(), ["glGetError"]);
+    }
+
+    #[test]
+    fn test_aliases_sorted() {
+        assert!(gl46_aliases::ALIASES.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_aliases_not_guessed() {
+        // Neither is declared as an alias in `gl.xml`, and both take different enums.
+        for core in [&b"glBindFramebuffer"[..], b"glProgramParameteri"] {
+            assert!(gl46_aliases::ALIASES.iter().all(|(name, _)| *name != core));
+        }
+    }
+
+    #[test]
+    fn test_load_with_aliases() {
+        let mut loaded = Vec::new();
+        let mut loader = |name: &CStr| {
+            loaded.push(name.to_str().unwrap().to_owned());
+            match name.to_bytes() {
+                b"glGenQueriesEXT" | b"glClear" => 0x1000 as *const core::ffi::c_void,
+                _ => ptr::null(),
+            }
+        };
+        unsafe {
+            let p = load_with_aliases(
+                &mut loader,
+                CStr::from_bytes_with_nul(b"glGenQueries\0").unwrap(),
+            );
+            assert_eq!(p as usize, 0x1000);
+            let p = load_with_aliases(
+                &mut loader,
+                CStr::from_bytes_with_nul(b"glClear\0").unwrap(),
+            );
+            assert_eq!(p as usize, 0x1000);
+            let p = load_with_aliases(
+                &mut loader,
+                CStr::from_bytes_with_nul(b"glGenQueriesEXT\0").unwrap(),
+            );
+            assert_eq!(p as usize, 0x1000);
+        }
+        assert_eq!(
+            loaded,
+            [
+                "glGenQueries",
+                "glGenQueriesARB",
+                "glGenQueriesEXT",
+                "glClear",
+                "glGenQueriesEXT"
+            ]
+        );
+    }
 }
@@ -0,0 +1,246 @@
+use crate::dl::Library;
+use crate::{Context, ContextCreationError};
+use std::marker::PhantomData;
+use std::os::raw::{c_char, c_int, c_uchar, c_void};
+use std::ptr;
+
+type OSMesaContext = *mut c_void;
+
+const OSMESA_RGBA: u32 = 0x1908;
+const OSMESA_FORMAT: c_int = 0x22;
+const OSMESA_DEPTH_BITS: c_int = 0x30;
+const OSMESA_STENCIL_BITS: c_int = 0x31;
+const OSMESA_PROFILE: c_int = 0x33;
+const OSMESA_CORE_PROFILE: c_int = 0x34;
+const OSMESA_COMPAT_PROFILE: c_int = 0x35;
+const OSMESA_CONTEXT_MAJOR_VERSION: c_int = 0x36;
+const OSMESA_CONTEXT_MINOR_VERSION: c_int = 0x37;
+const GL_UNSIGNED_BYTE: u32 = 0x1401;
+
+const LIBRARY_NAMES: [&str; 3] = ["libOSMesa.so.8", "libOSMesa.so.6", "libOSMesa.so"];
+
+struct OsMesaFns {
+    create_context_ext:
+        unsafe extern "C" fn(u32, c_int, c_int, c_int, OSMesaContext) -> OSMesaContext,
+    create_context_attribs:
+        Option<unsafe extern "C" fn(*const c_int, OSMesaContext) -> OSMesaContext>,
+    destroy_context: unsafe extern "C" fn(OSMesaContext),
+    make_current: unsafe extern "C" fn(OSMesaContext, *mut c_void, u32, c_int, c_int) -> c_uchar,
+    get_proc_address: unsafe extern "C" fn(*const c_char) -> *const c_void,
+}
+
+impl OsMesaFns {
+    unsafe fn load(library: &Library) -> Result<Self, String> {
+        Ok(OsMesaFns {
+            create_context_ext: library.symbol("OSMesaCreateContextExt")?,
+            // Added in Mesa 11.2, older versions can only create compatibility contexts
+            create_context_attribs: library.symbol("OSMesaCreateContextAttribs").ok(),
+            destroy_context: library.symbol("OSMesaDestroyContext")?,
+            make_current: library.symbol("OSMesaMakeCurrent")?,
+            get_proc_address: library.symbol("OSMesaGetProcAddress")?,
+        })
+    }
+}
+
+/// A [`Context`] created by [`Context::new_osmesa`], together with the OSMesa context behind
+/// it and the buffer it renders into. Dereferences to the [`Context`].
+///
+/// Dropping it drops the [`Context`] first, then destroys the OSMesa context and unloads
+/// `libOSMesa`.
+pub struct OsMesa<'a> {
+    // Declared first so it's dropped while the OSMesa context still exists
+    context: Context,
+    osmesa: OsMesaState<'a>,
+}
+
+impl OsMesa<'_> {
+    /// The RGBA8 pixels of the default framebuffer, with the bottom row first like
+    /// `read_pixels`.
+    ///
+    /// Call `finish` on the context first, since rendering may not have completed yet.
+    pub fn pixels(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.osmesa.buffer, self.osmesa.len) }
+    }
+}
+
+impl std::ops::Deref for OsMesa<'_> {
+    type Target = Context;
+
+    fn deref(&self) -> &Context {
+        &self.context
+    }
+}
+
+impl std::ops::DerefMut for OsMesa<'_> {
+    fn deref_mut(&mut self) -> &mut Context {
+        &mut self.context
+    }
+}
+
+impl std::fmt::Debug for OsMesa<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("OsMesa")
+            .field("context", &self.osmesa.context)
+            .field("len", &self.osmesa.len)
+            .finish()
+    }
+}
+
+/// The OSMesa context and the buffer it renders into, which is released when dropped.
+struct OsMesaState<'a> {
+    fns: OsMesaFns,
+    context: OSMesaContext,
+    buffer: *mut u8,
+    len: usize,
+    _library: Library,
+    _marker: PhantomData<&'a mut [u8]>,
+}
+
+impl Drop for OsMesaState<'_> {
+    fn drop(&mut self) {
+        if !self.context.is_null() {
+            unsafe { (self.fns.destroy_context)(self.context) };
+        }
+    }
+}
+
+impl Context {
+    /// Creates a software context with `libOSMesa` that renders into `buffer`, and makes it
+    /// current on this thread.
+    ///
+    /// The default framebuffer is `width` by `height` RGBA8 pixels with a 24-bit depth and
+    /// 8-bit stencil buffer, stored in `buffer` with the bottom row first. It can be read back
+    /// with `read_pixels` or [`OsMesa::pixels`]. Versions 3.2 and above request a core profile,
+    /// which needs Mesa 11.2 or later.
+    ///
+    /// # Safety
+    ///
+    /// The context must only be used on this thread, and must stay current while it's used.
+    pub unsafe fn new_osmesa(
+        buffer: &mut [u8],
+        width: u32,
+        height: u32,
+        version: (u8, u8),
+    ) -> Result<OsMesa<'_>, ContextCreationError> {
+        let len = width as usize * height as usize * 4;
+        if buffer.len() < len {
+            return Err(ContextCreationError::Platform(format!(
+                "A {}x{} RGBA8 buffer needs {} bytes, but only {} were provided",
+                width,
+                height,
+                len,
+                buffer.len()
+            )));
+        }
+        let library = Library::open(&LIBRARY_NAMES).map_err(ContextCreationError::Platform)?;
+        let mut osmesa = OsMesaState {
+            fns: OsMesaFns::load(&library).map_err(ContextCreationError::Platform)?,
+            context: ptr::null_mut(),
+            buffer: buffer.as_mut_ptr(),
+            len,
+            _library: library,
+            _marker: PhantomData,
+        };
+        let fns = &osmesa.fns;
+
+        osmesa.context = match fns.create_context_attribs {
+            Some(create_context_attribs) => {
+                let profile = if version >= (3, 2) {
+                    OSMESA_CORE_PROFILE
+                } else {
+                    OSMESA_COMPAT_PROFILE
+                };
+                let attributes = [
+                    OSMESA_FORMAT,
+                    OSMESA_RGBA as c_int,
+                    OSMESA_DEPTH_BITS,
+                    24,
+                    OSMESA_STENCIL_BITS,
+                    8,
+                    OSMESA_PROFILE,
+                    profile,
+                    OSMESA_CONTEXT_MAJOR_VERSION,
+                    version.0 as c_int,
+                    OSMESA_CONTEXT_MINOR_VERSION,
+                    version.1 as c_int,
+                    0,
+                ];
+                create_context_attribs(attributes.as_ptr(), ptr::null_mut())
+            }
+            None if version >= (3, 2) => {
+                return Err(ContextCreationError::Platform(
+                    "OSMesa core profiles need OSMesaCreateContextAttribs".to_string(),
+                ))
+            }
+            None => (fns.create_context_ext)(OSMESA_RGBA, 24, 8, 0, ptr::null_mut()),
+        };
+        if osmesa.context.is_null() {
+            return Err(ContextCreationError::Platform(format!(
+                "OSMesa couldn't create an OpenGL {}.{} context",
+                version.0, version.1
+            )));
+        }
+        if (fns.make_current)(
+            osmesa.context,
+            osmesa.buffer as *mut c_void,
+            GL_UNSIGNED_BYTE,
+            width as c_int,
+            height as c_int,
+        ) == 0
+        {
+            return Err(ContextCreationError::Platform(
+                "OSMesaMakeCurrent failed".to_string(),
+            ));
+        }
+
+        let get_proc_address = fns.get_proc_address;
+        let context =
+            Context::try_from_loader_function_cstr(|name| get_proc_address(name.as_ptr()), None)?;
+        Ok(OsMesa { context, osmesa })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HasContext, PixelPackData, COLOR_BUFFER_BIT, RGBA, SCISSOR_TEST, UNSIGNED_BYTE};
+
+    #[test]
+    fn test_buffer_too_small() {
+        let mut buffer = vec![0; 4 * 4 * 4 - 1];
+        let error = unsafe { Context::new_osmesa(&mut buffer, 4, 4, (3, 3)) }.unwrap_err();
+        assert!(matches!(error, ContextCreationError::Platform(_)));
+    }
+
+    #[test]
+    #[ignore = "needs libOSMesa, which most systems don't install"]
+    fn test_render() {
+        let mut buffer = vec![0; 4 * 4 * 4];
+        unsafe {
+            let gl = Context::new_osmesa(&mut buffer, 4, 4, (2, 1)).unwrap();
+            gl.clear_color(1.0, 0.0, 0.0, 1.0);
+            gl.clear(COLOR_BUFFER_BIT);
+            // The bottom left quarter is green
+            gl.enable(SCISSOR_TEST);
+            gl.scissor(0, 0, 2, 2);
+            gl.clear_color(0.0, 1.0, 0.0, 1.0);
+            gl.clear(COLOR_BUFFER_BIT);
+            gl.finish();
+
+            let mut pixels = vec![0; 4 * 4 * 4];
+            gl.read_pixels(
+                0,
+                0,
+                4,
+                4,
+                RGBA,
+                UNSIGNED_BYTE,
+                PixelPackData::Slice(Some(&mut pixels)),
+            );
+            assert_eq!(&pixels[..4], &[0, 255, 0, 255]);
+            assert_eq!(&pixels[4 * 3..4 * 4], &[255, 0, 0, 255]);
+            assert_eq!(&pixels[4 * 4 * 2..4 * 4 * 2 + 4], &[255, 0, 0, 255]);
+            assert_eq!(gl.pixels(), &pixels[..]);
+        }
+    }
+}
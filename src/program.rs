@@ -0,0 +1,198 @@
+use crate::*;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+/// An error from building a program out of shader sources.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProgramError {
+    /// `create_program` or `create_shader` failed.
+    Create(String),
+    /// A shader failed to compile.
+    Compile {
+        /// The shader type, e.g. `VERTEX_SHADER`.
+        shader_type: u32,
+        log: String,
+    },
+    /// The program failed to link.
+    Link(String),
+}
+
+impl core::fmt::Display for ProgramError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            ProgramError::Create(message) => write!(f, "failed to create program: {}", message),
+            ProgramError::Compile { shader_type, log } => {
+                write!(f, "failed to compile shader {:#06x}: {}", shader_type, log)
+            }
+            ProgramError::Link(log) => write!(f, "failed to link program: {}", log),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ProgramError {}
+
+/// A program with its shaders and their types, before it has been linked.
+pub(crate) type UnlinkedProgram<C> = (
+    <C as HasContext>::Program,
+    Vec<(u32, <C as HasContext>::Shader)>,
+);
+
+/// Compiles and attaches a shader for each `(shader_type, source)` pair, without linking.
+///
+/// Returns the program and its shaders with their types, which should be passed to
+/// [`finish_program`] after linking.
+pub(crate) unsafe fn create_program_with_shaders<C: HasContext>(
+    gl: &C,
+    sources: &[(u32, &str)],
+) -> Result<UnlinkedProgram<C>, ProgramError> {
+    let program = gl.create_program().map_err(ProgramError::Create)?;
+    let mut shaders = Vec::with_capacity(sources.len());
+    for &(shader_type, source) in sources {
+        let shader = match gl.create_shader(shader_type) {
+            Ok(shader) => shader,
+            Err(message) => {
+                delete_program_with_shaders(gl, program, &shaders);
+                return Err(ProgramError::Create(message));
+            }
+        };
+        gl.shader_source(shader, source);
+        gl.compile_shader(shader);
+        gl.attach_shader(program, shader);
+        shaders.push((shader_type, shader));
+    }
+    Ok((program, shaders))
+}
+
+/// Checks the compile status of `shaders` and the link status of `program`, and cleans up.
+///
+/// On success the shaders are detached and deleted, on failure the program is deleted too.
+pub(crate) unsafe fn finish_program<C: HasContext>(
+    gl: &C,
+    program: C::Program,
+    shaders: &[(u32, C::Shader)],
+) -> Result<C::Program, ProgramError> {
+    let result = if gl.get_program_link_status(program) {
+        Ok(program)
+    } else {
+        // A failed compile is more useful than the link error it causes
+        Err(shaders
+            .iter()
+            .find(|&&(_, shader)| !gl.get_shader_compile_status(shader))
+            .map(|&(shader_type, shader)| ProgramError::Compile {
+                shader_type,
+                log: gl.get_shader_info_log(shader),
+            })
+            .unwrap_or_else(|| ProgramError::Link(gl.get_program_info_log(program))))
+    };
+    match result {
+        Ok(program) => {
+            for &(_, shader) in shaders {
+                gl.detach_shader(program, shader);
+                gl.delete_shader(shader);
+            }
+        }
+        Err(_) => delete_program_with_shaders(gl, program, shaders),
+    }
+    result
+}
+
+unsafe fn delete_program_with_shaders<C: HasContext>(
+    gl: &C,
+    program: C::Program,
+    shaders: &[(u32, C::Shader)],
+) {
+    for &(_, shader) in shaders {
+        gl.delete_shader(shader);
+    }
+    gl.delete_program(program);
+}
+
+/// A program that is being compiled and linked, see `Context::compile_program_async`.
+///
+/// With `KHR_parallel_shader_compile` the driver compiles in the background, and
+/// [`PendingProgram::poll`] returns [`Poll::Pending`] until it's done. Without the extension the
+/// first poll blocks until the program is linked. Dropping an unfinished `PendingProgram` deletes
+/// the program.
+///
+/// `PendingProgram` also implements [`Future`]. GL has no way to signal that linking finished,
+/// so the future wakes itself whenever it's pending, and must be driven by an executor that polls
+/// woken tasks once per frame from the render loop. A general-purpose executor would poll it
+/// again immediately and spin until the program is linked.
+pub struct PendingProgram<'a, C: HasContext> {
+    gl: &'a C,
+    state: PendingState<C>,
+}
+
+enum PendingState<C: HasContext> {
+    Linking(UnlinkedProgram<C>),
+    Failed(ProgramError),
+    Finished,
+}
+
+impl<'a, C: HasContext> PendingProgram<'a, C> {
+    /// Starts compiling and linking `sources`, see `Context::compile_program_async`.
+    pub(crate) unsafe fn new(gl: &'a C, sources: &[(u32, &str)]) -> Self {
+        let state = match create_program_with_shaders(gl, sources) {
+            Ok((program, shaders)) => {
+                gl.link_program(program);
+                PendingState::Linking((program, shaders))
+            }
+            Err(error) => PendingState::Failed(error),
+        };
+        PendingProgram { gl, state }
+    }
+
+    /// Checks whether the program has finished linking, without blocking if
+    /// `KHR_parallel_shader_compile` is supported.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called again after returning [`Poll::Ready`].
+    pub fn poll(&mut self) -> Poll<Result<C::Program, ProgramError>> {
+        let gl = self.gl;
+        // Safety: the context is current, as required by `Context::compile_program_async`
+        unsafe {
+            if let PendingState::Linking((program, _)) = self.state {
+                if gl.features().parallel_shader_compile
+                    && !gl.get_program_completion_status(program)
+                {
+                    return Poll::Pending;
+                }
+            }
+            match core::mem::replace(&mut self.state, PendingState::Finished) {
+                PendingState::Linking((program, shaders)) => {
+                    Poll::Ready(finish_program(gl, program, &shaders))
+                }
+                PendingState::Failed(error) => Poll::Ready(Err(error)),
+                PendingState::Finished => panic!("`PendingProgram` polled after completion"),
+            }
+        }
+    }
+}
+
+impl<C: HasContext> Future for PendingProgram<'_, C>
+where
+    C::Program: Unpin,
+    C::Shader: Unpin,
+{
+    type Output = Result<C::Program, ProgramError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let poll = PendingProgram::poll(self.get_mut());
+        if poll.is_pending() {
+            // Ask to be polled again, which the frame loop does on the next frame
+            cx.waker().wake_by_ref();
+        }
+        poll
+    }
+}
+
+impl<C: HasContext> Drop for PendingProgram<'_, C> {
+    fn drop(&mut self) {
+        if let PendingState::Linking((program, ref shaders)) = self.state {
+            unsafe { delete_program_with_shaders(self.gl, program, shaders) };
+        }
+    }
+}
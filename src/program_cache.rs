@@ -0,0 +1,217 @@
+use crate::*;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const MAGIC: &[u8; 8] = b"GLOWPRG\0";
+const EXTENSION: &str = "glowbin";
+
+/// A cache of linked program binaries stored in a directory.
+///
+/// Binaries are keyed by a hash of the shader sources and the [`DriverInfo`] vendor, renderer
+/// and version strings, so a driver update never loads an incompatible binary. Programs are
+/// compiled from source whenever no binary is cached, the driver rejects the cached binary, or
+/// the context doesn't support program binaries (e.g. WebGL).
+#[derive(Clone, Debug)]
+pub struct ProgramCache {
+    directory: PathBuf,
+}
+
+impl ProgramCache {
+    /// Creates a cache in `directory`, which is created when the first binary is stored.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        ProgramCache {
+            directory: directory.into(),
+        }
+    }
+
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Loads a program from the cache, or compiles and links it from `sources` and stores its
+    /// binary.
+    ///
+    /// `sources` contains the shader type (e.g. `VERTEX_SHADER`) and source of each shader.
+    /// Failing to read or write the cache isn't an error, the program is compiled from source
+    /// instead.
+    pub unsafe fn get_or_compile<C: HasContext>(
+        &self,
+        gl: &C,
+        sources: &[(u32, &str)],
+    ) -> Result<C::Program, ProgramError> {
+        self.get_or_compile_with(gl, sources, |_, _| {})
+    }
+
+    /// Like [`ProgramCache::get_or_compile`], but calls `pre_link` before a program compiled
+    /// from source is linked, e.g. to call `bind_attrib_location` or
+    /// `transform_feedback_varyings`.
+    ///
+    /// `pre_link` isn't called for programs loaded from the cache, since the binary already
+    /// includes its effects. It isn't part of the cache key either, so it must always do the same
+    /// thing for the same `sources`.
+    pub unsafe fn get_or_compile_with<C: HasContext, F: FnOnce(&C, C::Program)>(
+        &self,
+        gl: &C,
+        sources: &[(u32, &str)],
+        pre_link: F,
+    ) -> Result<C::Program, ProgramError> {
+        if !gl.features().program_binary {
+            let (program, shaders) = program::create_program_with_shaders(gl, sources)?;
+            pre_link(gl, program);
+            gl.link_program(program);
+            return program::finish_program(gl, program, &shaders);
+        }
+
+        let key = cache_key(gl.driver_info(), sources);
+        let path = self.path(key);
+        if let Some(binary) = fs::read(&path).ok().and_then(|data| decode(key, &data)) {
+            let program = gl.create_program().map_err(ProgramError::Create)?;
+            gl.program_binary(program, &binary);
+            if gl.get_program_link_status(program) {
+                return Ok(program);
+            }
+            // The driver rejected the binary, so replace it
+            gl.delete_program(program);
+            let _ = fs::remove_file(&path);
+        }
+
+        let (program, shaders) = program::create_program_with_shaders(gl, sources)?;
+        pre_link(gl, program);
+        gl.program_binary_retrievable_hint(program, true);
+        gl.link_program(program);
+        let program = program::finish_program(gl, program, &shaders)?;
+        if let Some(binary) = gl.get_program_binary(program) {
+            let _ = self.store(key, &binary);
+        }
+        Ok(program)
+    }
+
+    /// Removes every cached binary, including ones stored for other drivers.
+    pub fn clear(&self) -> io::Result<()> {
+        let entries = match fs::read_dir(&self.directory) {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(()),
+            Err(error) => return Err(error),
+        };
+        for entry in entries {
+            let path = entry?.path();
+            if path
+                .extension()
+                .is_some_and(|extension| extension == EXTENSION)
+            {
+                fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn path(&self, key: u64) -> PathBuf {
+        self.directory.join(format!("{:016x}.{}", key, EXTENSION))
+    }
+
+    fn store(&self, key: u64, binary: &ProgramBinary) -> io::Result<()> {
+        if binary.buffer.is_empty() {
+            return Ok(());
+        }
+        fs::create_dir_all(&self.directory)?;
+        // Write to a temporary file first so that a crash never leaves a truncated binary
+        let temporary = self.directory.join(format!("{:016x}.tmp", key));
+        fs::write(&temporary, encode(key, binary))?;
+        fs::rename(temporary, self.path(key))
+    }
+}
+
+/// FNV-1a, which unlike `DefaultHasher` is stable across Rust versions.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    /// Writes a length-prefixed string, so that adjacent strings can't be confused.
+    fn write_str(&mut self, s: &str) {
+        self.write(&(s.len() as u64).to_le_bytes());
+        self.write(s.as_bytes());
+    }
+}
+
+fn cache_key(info: &DriverInfo, sources: &[(u32, &str)]) -> u64 {
+    let mut hasher = Fnv1a(0xcbf29ce484222325);
+    hasher.write_str(&info.vendor);
+    hasher.write_str(&info.renderer);
+    hasher.write_str(&info.version.to_string());
+    for &(shader_type, source) in sources {
+        hasher.write(&shader_type.to_le_bytes());
+        hasher.write_str(source);
+    }
+    hasher.0
+}
+
+/// Serializes a binary as the magic bytes, the key and format, and then the binary itself.
+fn encode(key: u64, binary: &ProgramBinary) -> Vec<u8> {
+    let mut data = Vec::with_capacity(MAGIC.len() + 12 + binary.buffer.len());
+    data.extend_from_slice(MAGIC);
+    data.extend_from_slice(&key.to_le_bytes());
+    data.extend_from_slice(&binary.format.to_le_bytes());
+    data.extend_from_slice(&binary.buffer);
+    data
+}
+
+fn decode(key: u64, data: &[u8]) -> Option<ProgramBinary> {
+    let data = data.strip_prefix(MAGIC)?;
+    if data.len() <= 12 || data[..8] != key.to_le_bytes() {
+        return None;
+    }
+    Some(ProgramBinary {
+        format: u32::from_le_bytes(data[8..12].try_into().unwrap()),
+        buffer: data[12..].to_vec(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_key() {
+        let info = DriverInfo {
+            vendor: "NVIDIA Corporation".to_string(),
+            renderer: "NVIDIA GeForce RTX 3080".to_string(),
+            version: "4.6.0 NVIDIA 535.54.03".parse().unwrap(),
+            glsl: None,
+            is_webgl: false,
+        };
+        let sources = [(VERTEX_SHADER, "void main() {}"), (FRAGMENT_SHADER, "")];
+        let key = cache_key(&info, &sources);
+        assert_eq!(key, cache_key(&info.clone(), &sources));
+
+        let swapped = [(FRAGMENT_SHADER, "void main() {}"), (VERTEX_SHADER, "")];
+        assert_ne!(key, cache_key(&info, &swapped));
+
+        let updated = DriverInfo {
+            version: "4.6.0 NVIDIA 545.29.06".parse().unwrap(),
+            ..info.clone()
+        };
+        assert_ne!(key, cache_key(&updated, &sources));
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let binary = ProgramBinary {
+            buffer: vec![1, 2, 3, 4, 5],
+            format: 0x8740,
+        };
+        let data = encode(42, &binary);
+        let decoded = decode(42, &data).unwrap();
+        assert_eq!(decoded.buffer, binary.buffer);
+        assert_eq!(decoded.format, binary.format);
+        assert!(decode(43, &data).is_none());
+        assert!(decode(42, &data[..MAGIC.len() + 12]).is_none());
+        assert!(decode(42, b"not a binary").is_none());
+    }
+}
@@ -0,0 +1,207 @@
+use crate::*;
+use core::task::Poll;
+
+/// Reads pixels back through pixel pack buffers, without stalling until the GPU has finished
+/// rendering.
+///
+/// [`AsyncReadback::read_pixels`] starts a readback into a buffer from a reusable pool, and
+/// [`AsyncReadback::poll`] returns the bytes once the fence after it has signaled. Buffers are
+/// read with `map_buffer_range` where available and `get_buffer_sub_data` otherwise (WebGL 2).
+///
+/// Requires pixel buffer objects and fence syncs, i.e. OpenGL 3.2, OpenGL ES 3.0 or WebGL 2.
+pub struct AsyncReadback<C: HasContext> {
+    /// Unused buffers and their sizes.
+    pool: Vec<(C::Buffer, i32)>,
+}
+
+/// A readback started by [`AsyncReadback::read_pixels`].
+///
+/// Pass it to [`AsyncReadback::poll`] until the bytes are returned, or to
+/// [`AsyncReadback::discard`] if they're no longer needed. If the [`AsyncReadback`] is gone, use
+/// [`PendingReadback::cancel`] instead.
+///
+/// Dropping an unfinished readback leaks its buffer and fence, since there's no context to delete
+/// them with.
+#[derive(Debug)]
+pub struct PendingReadback<C: HasContext> {
+    buffer: C::Buffer,
+    capacity: i32,
+    size: usize,
+    fence: Option<C::Fence>,
+}
+
+impl<C: HasContext> PendingReadback<C> {
+    /// The number of bytes that will be returned, including any row padding from
+    /// the pack parameters.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Abandons the readback and deletes its buffer, without returning it to a pool.
+    pub unsafe fn cancel(mut self, gl: &C) {
+        if let Some(fence) = self.fence.take() {
+            gl.delete_sync(fence);
+            gl.delete_buffer(self.buffer);
+        }
+    }
+}
+
+impl<C: HasContext> AsyncReadback<C> {
+    pub fn new() -> Self {
+        AsyncReadback { pool: Vec::new() }
+    }
+
+    /// Starts reading a rectangle of the current read framebuffer, like `read_pixels`.
+    ///
+    /// The pixels are laid out according to the current pack parameters.
+    /// The `PIXEL_PACK_BUFFER` binding is restored afterwards.
+    pub unsafe fn read_pixels(
+        &mut self,
+        gl: &C,
+        x: i32,
+        y: i32,
+        width: i32,
+        height: i32,
+        format: u32,
+        gltype: u32,
+    ) -> Result<PendingReadback<C>, String> {
+        let features = gl.features();
+        if !features.pixel_buffers || !features.sync {
+            return Err("Asynchronous readback requires pixel buffers and fence syncs".to_string());
+        }
+        let pixel_size = format::pixel_size(format, gltype).ok_or_else(|| {
+            format!(
+                "Invalid pixel format {:#06x} and type {:#06x}",
+                format, gltype
+            )
+        })?;
+        let size = gl
+            .pack_parameters()
+            .transfer_size(width.max(0) as u32, height.max(0) as u32, 1, pixel_size)
+            .filter(|&size| size <= i32::MAX as usize)
+            .ok_or_else(|| format!("Readback of {}x{} pixels is too large", width, height))?;
+
+        let previous = gl.get_parameter_buffer(PIXEL_PACK_BUFFER_BINDING);
+        // Reuse the smallest buffer that is large enough
+        let pooled = self
+            .pool
+            .iter()
+            .enumerate()
+            .filter(|&(_, &(_, capacity))| capacity as usize >= size)
+            .min_by_key(|&(_, &(_, capacity))| capacity)
+            .map(|(index, _)| index);
+        let (buffer, capacity) = match pooled {
+            Some(index) => {
+                let (buffer, capacity) = self.pool.swap_remove(index);
+                gl.bind_buffer(PIXEL_PACK_BUFFER, Some(buffer));
+                (buffer, capacity)
+            }
+            None => {
+                let buffer = gl.create_buffer()?;
+                gl.bind_buffer(PIXEL_PACK_BUFFER, Some(buffer));
+                gl.buffer_data_size(PIXEL_PACK_BUFFER, size as i32, STREAM_READ);
+                (buffer, size as i32)
+            }
+        };
+        gl.read_pixels(
+            x,
+            y,
+            width,
+            height,
+            format,
+            gltype,
+            PixelPackData::BufferOffset(0),
+        );
+        gl.bind_buffer(PIXEL_PACK_BUFFER, previous);
+
+        let fence = match gl.fence_sync(SYNC_GPU_COMMANDS_COMPLETE, 0) {
+            Ok(fence) => fence,
+            Err(error) => {
+                self.pool.push((buffer, capacity));
+                return Err(error);
+            }
+        };
+        // Make sure the fence is submitted, otherwise polling may never see it signal
+        gl.flush();
+
+        Ok(PendingReadback {
+            buffer,
+            capacity,
+            size,
+            fence: Some(fence),
+        })
+    }
+
+    /// Returns the pixels if the readback has finished, and returns its buffer to the pool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called again after returning [`Poll::Ready`].
+    pub unsafe fn poll(&mut self, gl: &C, pending: &mut PendingReadback<C>) -> Poll<Vec<u8>> {
+        let fence = pending
+            .fence
+            .expect("`PendingReadback` polled after completion");
+        if gl.get_sync_status(fence) != SIGNALED {
+            return Poll::Pending;
+        }
+        Poll::Ready(self.finish(gl, pending))
+    }
+
+    /// Blocks until the readback has finished and returns the pixels.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the readback has already been returned by [`AsyncReadback::poll`].
+    pub unsafe fn wait(&mut self, gl: &C, mut pending: PendingReadback<C>) -> Vec<u8> {
+        let fence = pending
+            .fence
+            .expect("`PendingReadback` polled after completion");
+        // Reading the buffer still works after a failed wait, it just stalls
+        while gl.client_wait_sync(fence, SYNC_FLUSH_COMMANDS_BIT, i32::MAX) == TIMEOUT_EXPIRED {}
+        self.finish(gl, &mut pending)
+    }
+
+    /// Abandons a readback and returns its buffer to the pool.
+    pub unsafe fn discard(&mut self, gl: &C, mut pending: PendingReadback<C>) {
+        if let Some(fence) = pending.fence.take() {
+            gl.delete_sync(fence);
+            self.pool.push((pending.buffer, pending.capacity));
+        }
+    }
+
+    /// Deletes the buffers in the pool. Buffers of pending readbacks aren't affected.
+    pub unsafe fn destroy(&mut self, gl: &C) {
+        for (buffer, _) in self.pool.drain(..) {
+            gl.delete_buffer(buffer);
+        }
+    }
+
+    unsafe fn finish(&mut self, gl: &C, pending: &mut PendingReadback<C>) -> Vec<u8> {
+        if let Some(fence) = pending.fence.take() {
+            gl.delete_sync(fence);
+        }
+
+        let mut data = vec![0; pending.size];
+        let previous = gl.get_parameter_buffer(PIXEL_PACK_BUFFER_BINDING);
+        gl.bind_buffer(PIXEL_PACK_BUFFER, Some(pending.buffer));
+        if gl.features().map_buffer_range {
+            let mapped = gl.map_buffer_range(PIXEL_PACK_BUFFER, 0, data.len() as i32, MAP_READ_BIT);
+            if !mapped.is_null() {
+                core::ptr::copy_nonoverlapping(mapped, data.as_mut_ptr(), data.len());
+            }
+            gl.unmap_buffer(PIXEL_PACK_BUFFER);
+        } else {
+            gl.get_buffer_sub_data(PIXEL_PACK_BUFFER, 0, &mut data);
+        }
+        gl.bind_buffer(PIXEL_PACK_BUFFER, previous);
+
+        self.pool.push((pending.buffer, pending.capacity));
+        data
+    }
+}
+
+impl<C: HasContext> Default for AsyncReadback<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
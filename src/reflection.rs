@@ -0,0 +1,449 @@
+use crate::*;
+
+/// The active inputs and resources of a linked program, see `Context::reflect_program`.
+#[derive(Clone, Debug)]
+pub struct ProgramReflection<C: HasContext> {
+    /// Vertex attributes, sorted by location.
+    pub attributes: Vec<ReflectedAttribute>,
+    /// Uniforms in the default uniform block, except samplers.
+    pub uniforms: Vec<ReflectedUniform<C::UniformLocation>>,
+    /// Sampler uniforms in the default uniform block.
+    pub samplers: Vec<ReflectedSampler<C::UniformLocation>>,
+    /// Uniform blocks, indexed by their uniform block index.
+    pub uniform_blocks: Vec<ReflectedBlock>,
+    /// Shader storage blocks, indexed by their resource index.
+    pub storage_blocks: Vec<ReflectedBlock>,
+    /// Transform feedback varyings, in the order they were specified.
+    pub transform_feedback_varyings: Vec<ReflectedVarying>,
+}
+
+/// An active vertex attribute.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReflectedAttribute {
+    pub name: String,
+    /// `None` for built-in attributes like `gl_VertexID`.
+    pub location: Option<u32>,
+    /// The array size, or 1.
+    pub size: i32,
+    pub atype: u32,
+}
+
+/// An active uniform in the default uniform block.
+///
+/// Arrays are reported once, with `[0]` appended to their name.
+#[derive(Clone, Debug)]
+pub struct ReflectedUniform<L> {
+    pub name: String,
+    pub location: Option<L>,
+    /// The array size, or 1.
+    pub size: i32,
+    pub utype: u32,
+}
+
+/// An active sampler uniform and the texture units it currently uses.
+#[derive(Clone, Debug)]
+pub struct ReflectedSampler<L> {
+    pub name: String,
+    /// The location of the sampler, or of the first element of an array of samplers.
+    pub location: Option<L>,
+    /// The sampler type, e.g. `SAMPLER_2D`.
+    pub utype: u32,
+    /// The texture unit of each array element.
+    pub units: Vec<i32>,
+}
+
+/// A uniform block or shader storage block.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReflectedBlock {
+    pub name: String,
+    pub index: u32,
+    /// The indexed buffer binding the block reads from.
+    pub binding: u32,
+    /// The minimum size of the buffer bound to the block, in bytes.
+    pub data_size: u32,
+    /// The members of the block, sorted by offset.
+    pub members: Vec<BlockMember>,
+}
+
+/// A member of a uniform block or shader storage block, with the layout reported by the driver.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlockMember {
+    /// The name, including the block instance and struct member names, e.g. `Lights.color[0]`.
+    pub name: String,
+    pub utype: u32,
+    /// The array size, 1 for non-arrays, or 0 for the unsized array at the end of a storage
+    /// block.
+    pub size: i32,
+    /// The offset from the start of the block, in bytes.
+    pub offset: u32,
+    /// The distance between array elements, or 0 for non-arrays.
+    pub array_stride: u32,
+    /// The distance between columns (or rows if `row_major`) of matrices, or 0.
+    pub matrix_stride: u32,
+    pub row_major: bool,
+    /// The distance between elements of the top-level array of storage block members, or 0.
+    pub top_level_array_stride: u32,
+}
+
+/// An active transform feedback varying.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReflectedVarying {
+    pub name: String,
+    pub size: i32,
+    pub vtype: u32,
+}
+
+impl<C: HasContext> ProgramReflection<C> {
+    pub fn attribute(&self, name: &str) -> Option<&ReflectedAttribute> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name == name)
+    }
+
+    /// Returns a uniform by name, which may omit the `[0]` of arrays.
+    pub fn uniform(&self, name: &str) -> Option<&ReflectedUniform<C::UniformLocation>> {
+        self.uniforms
+            .iter()
+            .find(|uniform| array_base_name(&uniform.name) == array_base_name(name))
+    }
+
+    /// Returns a sampler by name, which may omit the `[0]` of arrays.
+    pub fn sampler(&self, name: &str) -> Option<&ReflectedSampler<C::UniformLocation>> {
+        self.samplers
+            .iter()
+            .find(|sampler| array_base_name(&sampler.name) == array_base_name(name))
+    }
+
+    pub fn uniform_block(&self, name: &str) -> Option<&ReflectedBlock> {
+        self.uniform_blocks.iter().find(|block| block.name == name)
+    }
+
+    pub fn storage_block(&self, name: &str) -> Option<&ReflectedBlock> {
+        self.storage_blocks.iter().find(|block| block.name == name)
+    }
+}
+
+impl<C: HasContext> Default for ProgramReflection<C> {
+    fn default() -> Self {
+        ProgramReflection {
+            attributes: Vec::new(),
+            uniforms: Vec::new(),
+            samplers: Vec::new(),
+            uniform_blocks: Vec::new(),
+            storage_blocks: Vec::new(),
+            transform_feedback_varyings: Vec::new(),
+        }
+    }
+}
+
+/// Queries everything in [`ProgramReflection`] through the `get_active_*` and
+/// `get_program_resource_*` getters, skipping the parts the context doesn't support.
+pub(crate) unsafe fn reflect_program<C: HasContext>(
+    gl: &C,
+    program: C::Program,
+) -> ProgramReflection<C> {
+    let features = gl.features();
+    let mut reflection = ProgramReflection::default();
+
+    for index in 0..gl.get_active_attributes(program) {
+        if let Some(attribute) = gl.get_active_attribute(program, index) {
+            reflection.attributes.push(ReflectedAttribute {
+                location: gl.get_attrib_location(program, &attribute.name),
+                name: attribute.name,
+                size: attribute.size,
+                atype: attribute.atype,
+            });
+        }
+    }
+    reflection
+        .attributes
+        .sort_by(|a, b| (a.location, &a.name).cmp(&(b.location, &b.name)));
+
+    if features.uniform_buffers {
+        let count = gl.get_program_parameter_i32(program, ACTIVE_UNIFORM_BLOCKS);
+        reflection.uniform_blocks = (0..count.max(0) as u32)
+            .map(|index| reflect_uniform_block(gl, program, index))
+            .collect();
+    }
+
+    let uniforms: Vec<(u32, ActiveUniform)> = (0..gl.get_active_uniforms(program))
+        .filter_map(|index| Some((index, gl.get_active_uniform(program, index)?)))
+        .collect();
+    let block_indices = if features.uniform_buffers && !uniforms.is_empty() {
+        let indices: Vec<u32> = uniforms.iter().map(|&(index, _)| index).collect();
+        gl.get_active_uniforms_parameter(program, &indices, UNIFORM_BLOCK_INDEX)
+    } else {
+        Vec::new()
+    };
+    for (i, (_, uniform)) in uniforms.into_iter().enumerate() {
+        // Block members are listed by their block
+        if block_indices.get(i).is_some_and(|&block| block >= 0) {
+            continue;
+        }
+        if is_sampler(uniform.utype) {
+            reflection
+                .samplers
+                .push(reflect_sampler(gl, program, uniform));
+        } else {
+            reflection.uniforms.push(ReflectedUniform {
+                location: gl.get_uniform_location(program, &uniform.name),
+                name: uniform.name,
+                size: uniform.size,
+                utype: uniform.utype,
+            });
+        }
+    }
+
+    if features.shader_storage_buffers && features.program_interface_query {
+        reflection.storage_blocks = reflect_storage_blocks(gl, program);
+    }
+
+    if features.transform_feedback {
+        let count = gl.get_program_parameter_i32(program, TRANSFORM_FEEDBACK_VARYINGS);
+        for index in 0..count.max(0) as u32 {
+            if let Some(varying) = gl.get_transform_feedback_varying(program, index) {
+                reflection
+                    .transform_feedback_varyings
+                    .push(ReflectedVarying {
+                        name: varying.name,
+                        size: varying.size,
+                        vtype: varying.tftype,
+                    });
+            }
+        }
+    }
+
+    reflection
+}
+
+/// Queries a uniform block and the layout of its members.
+pub(crate) unsafe fn reflect_uniform_block<C: HasContext>(
+    gl: &C,
+    program: C::Program,
+    index: u32,
+) -> ReflectedBlock {
+    let parameter =
+        |parameter| gl.get_active_uniform_block_parameter_i32(program, index, parameter);
+    let mut indices = vec![0; parameter(UNIFORM_BLOCK_ACTIVE_UNIFORMS).max(0) as usize];
+    if !indices.is_empty() {
+        gl.get_active_uniform_block_parameter_i32_slice(
+            program,
+            index,
+            UNIFORM_BLOCK_ACTIVE_UNIFORM_INDICES,
+            &mut indices,
+        );
+    }
+    let indices: Vec<u32> = indices.into_iter().map(|index| index as u32).collect();
+    let values = |pname| {
+        if indices.is_empty() {
+            Vec::new()
+        } else {
+            gl.get_active_uniforms_parameter(program, &indices, pname)
+        }
+    };
+    let offsets = values(UNIFORM_OFFSET);
+    let array_strides = values(UNIFORM_ARRAY_STRIDE);
+    let matrix_strides = values(UNIFORM_MATRIX_STRIDE);
+    let row_major = values(UNIFORM_IS_ROW_MAJOR);
+    let value = |values: &[i32], i: usize| values.get(i).copied().unwrap_or(0).max(0) as u32;
+
+    let mut members: Vec<BlockMember> = indices
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &uniform)| {
+            let uniform = gl.get_active_uniform(program, uniform)?;
+            Some(BlockMember {
+                name: uniform.name,
+                utype: uniform.utype,
+                size: uniform.size,
+                offset: value(&offsets, i),
+                array_stride: value(&array_strides, i),
+                matrix_stride: value(&matrix_strides, i),
+                row_major: value(&row_major, i) != 0,
+                top_level_array_stride: 0,
+            })
+        })
+        .collect();
+    members.sort_by(|a, b| (a.offset, &a.name).cmp(&(b.offset, &b.name)));
+
+    ReflectedBlock {
+        name: gl.get_active_uniform_block_name(program, index),
+        index,
+        binding: parameter(UNIFORM_BLOCK_BINDING).max(0) as u32,
+        data_size: parameter(UNIFORM_BLOCK_DATA_SIZE).max(0) as u32,
+        members,
+    }
+}
+
+/// Queries the shader storage blocks of a program and the layout of their members.
+pub(crate) unsafe fn reflect_storage_blocks<C: HasContext>(
+    gl: &C,
+    program: C::Program,
+) -> Vec<ReflectedBlock> {
+    let value = |values: &[i32], i: usize| values.get(i).copied().unwrap_or(0).max(0) as u32;
+
+    let count = gl.get_program_interface_i32(program, SHADER_STORAGE_BLOCK, ACTIVE_RESOURCES);
+    let mut blocks: Vec<ReflectedBlock> = (0..count.max(0) as u32)
+        .map(|index| {
+            let values = gl.get_program_resource_i32(
+                program,
+                SHADER_STORAGE_BLOCK,
+                index,
+                &[BUFFER_BINDING, BUFFER_DATA_SIZE],
+            );
+            ReflectedBlock {
+                name: gl.get_program_resource_name(program, SHADER_STORAGE_BLOCK, index),
+                index,
+                binding: value(&values, 0),
+                data_size: value(&values, 1),
+                members: Vec::new(),
+            }
+        })
+        .collect();
+
+    // Going through all buffer variables once avoids querying the variable-length
+    // `ACTIVE_VARIABLES` property of each block
+    let count = gl.get_program_interface_i32(program, BUFFER_VARIABLE, ACTIVE_RESOURCES);
+    for variable in 0..count.max(0) as u32 {
+        let properties = gl.get_program_resource_i32(
+            program,
+            BUFFER_VARIABLE,
+            variable,
+            &[
+                BLOCK_INDEX,
+                TYPE,
+                ARRAY_SIZE,
+                OFFSET,
+                ARRAY_STRIDE,
+                MATRIX_STRIDE,
+                IS_ROW_MAJOR,
+                TOP_LEVEL_ARRAY_STRIDE,
+            ],
+        );
+        if properties.len() < 8 {
+            continue;
+        }
+        let block = usize::try_from(properties[0])
+            .ok()
+            .and_then(|block| blocks.get_mut(block));
+        if let Some(block) = block {
+            block.members.push(BlockMember {
+                name: gl.get_program_resource_name(program, BUFFER_VARIABLE, variable),
+                utype: properties[1] as u32,
+                size: properties[2],
+                offset: value(&properties, 3),
+                array_stride: value(&properties, 4),
+                matrix_stride: value(&properties, 5),
+                row_major: properties[6] != 0,
+                top_level_array_stride: value(&properties, 7),
+            });
+        }
+    }
+    for block in &mut blocks {
+        block
+            .members
+            .sort_by(|a, b| (a.offset, &a.name).cmp(&(b.offset, &b.name)));
+    }
+    blocks
+}
+
+/// Queries the texture unit of each element of a sampler uniform.
+unsafe fn reflect_sampler<C: HasContext>(
+    gl: &C,
+    program: C::Program,
+    uniform: ActiveUniform,
+) -> ReflectedSampler<C::UniformLocation> {
+    let base_name = array_base_name(&uniform.name);
+    let is_array = base_name.len() != uniform.name.len() || uniform.size > 1;
+    let locations: Vec<_> = (0..uniform.size.max(1))
+        .map(|element| {
+            if is_array {
+                gl.get_uniform_location(program, &format!("{}[{}]", base_name, element))
+            } else {
+                gl.get_uniform_location(program, &uniform.name)
+            }
+        })
+        .collect();
+    let units = locations
+        .iter()
+        .map(|location| {
+            let mut unit = [0];
+            if let Some(location) = location {
+                gl.get_uniform_i32(program, location, &mut unit);
+            }
+            unit[0]
+        })
+        .collect();
+    ReflectedSampler {
+        location: locations.into_iter().next().flatten(),
+        name: uniform.name,
+        utype: uniform.utype,
+        units,
+    }
+}
+
+/// Strips the `[0]` that GL appends to the names of array uniforms.
+pub(crate) fn array_base_name(name: &str) -> &str {
+    name.strip_suffix("[0]").unwrap_or(name)
+}
+
+pub(crate) fn is_sampler(utype: u32) -> bool {
+    matches!(
+        utype,
+        SAMPLER_1D
+            | SAMPLER_2D
+            | SAMPLER_3D
+            | SAMPLER_CUBE
+            | SAMPLER_1D_SHADOW
+            | SAMPLER_2D_SHADOW
+            | SAMPLER_2D_RECT
+            | SAMPLER_2D_RECT_SHADOW
+            | SAMPLER_1D_ARRAY
+            | SAMPLER_2D_ARRAY
+            | SAMPLER_BUFFER
+            | SAMPLER_1D_ARRAY_SHADOW
+            | SAMPLER_2D_ARRAY_SHADOW
+            | SAMPLER_CUBE_SHADOW
+            | SAMPLER_CUBE_MAP_ARRAY
+            | SAMPLER_CUBE_MAP_ARRAY_SHADOW
+            | SAMPLER_2D_MULTISAMPLE
+            | SAMPLER_2D_MULTISAMPLE_ARRAY
+            | INT_SAMPLER_1D
+            | INT_SAMPLER_2D
+            | INT_SAMPLER_3D
+            | INT_SAMPLER_CUBE
+            | INT_SAMPLER_2D_RECT
+            | INT_SAMPLER_1D_ARRAY
+            | INT_SAMPLER_2D_ARRAY
+            | INT_SAMPLER_BUFFER
+            | INT_SAMPLER_CUBE_MAP_ARRAY
+            | INT_SAMPLER_2D_MULTISAMPLE
+            | INT_SAMPLER_2D_MULTISAMPLE_ARRAY
+            | UNSIGNED_INT_SAMPLER_1D
+            | UNSIGNED_INT_SAMPLER_2D
+            | UNSIGNED_INT_SAMPLER_3D
+            | UNSIGNED_INT_SAMPLER_CUBE
+            | UNSIGNED_INT_SAMPLER_2D_RECT
+            | UNSIGNED_INT_SAMPLER_1D_ARRAY
+            | UNSIGNED_INT_SAMPLER_2D_ARRAY
+            | UNSIGNED_INT_SAMPLER_BUFFER
+            | UNSIGNED_INT_SAMPLER_CUBE_MAP_ARRAY
+            | UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE
+            | UNSIGNED_INT_SAMPLER_2D_MULTISAMPLE_ARRAY
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sampler_names() {
+        assert_eq!(array_base_name("textures[0]"), "textures");
+        assert_eq!(array_base_name("lights[2]"), "lights[2]");
+        assert_eq!(array_base_name("albedo"), "albedo");
+        assert!(is_sampler(SAMPLER_2D_SHADOW));
+        assert!(is_sampler(UNSIGNED_INT_SAMPLER_CUBE_MAP_ARRAY));
+        assert!(!is_sampler(FLOAT_VEC4));
+    }
+}
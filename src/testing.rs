@@ -0,0 +1,565 @@
+//! Helpers for golden-image tests of code that renders with glow.
+//!
+//! [`render_to_image`] renders into an offscreen framebuffer and reads it back as an
+//! [`Image`], [`compare`] checks it against an expected image with a per-channel tolerance and
+//! a budget of mismatched pixels, and [`assert_images_match`] writes the actual, expected and
+//! diff images when they don't match. Images can be written as PPM or PNG without any
+//! dependencies, and read from PPM.
+
+use crate::format::PixelStore;
+use crate::*;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// An RGBA8 image with the top row first.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Image {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    /// Creates an image from RGBA8 pixels with the top row first.
+    ///
+    /// Panics if `pixels` isn't `width * height * 4` bytes long.
+    pub fn new(width: u32, height: u32, pixels: Vec<u8>) -> Self {
+        assert_eq!(
+            pixels.len(),
+            width as usize * height as usize * 4,
+            "glow: image data doesn't match its size"
+        );
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The RGBA8 pixels with the top row first.
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    pub fn into_pixels(self) -> Vec<u8> {
+        self.pixels
+    }
+
+    /// Returns the pixel at column `x` of row `y`, counting from the top.
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        assert!(x < self.width && y < self.height);
+        let start = (y as usize * self.width as usize + x as usize) * 4;
+        let mut pixel = [0; 4];
+        pixel.copy_from_slice(&self.pixels[start..start + 4]);
+        pixel
+    }
+
+    /// Reads a binary PPM (`P6`) image with 8-bit channels. Alpha is set to 255.
+    pub fn from_ppm(data: &[u8]) -> Result<Self, String> {
+        // The header is the magic number, width, height and maximum value, separated by
+        // whitespace and optional comments, followed by a single whitespace character
+        let mut fields = Vec::new();
+        let mut position = 0;
+        while fields.len() < 4 {
+            match data.get(position) {
+                Some(b'#') => {
+                    while data.get(position).is_some_and(|&byte| byte != b'\n') {
+                        position += 1;
+                    }
+                }
+                Some(byte) if byte.is_ascii_whitespace() => position += 1,
+                Some(_) => {
+                    let start = position;
+                    while data
+                        .get(position)
+                        .is_some_and(|byte| !byte.is_ascii_whitespace())
+                    {
+                        position += 1;
+                    }
+                    fields.push(String::from_utf8_lossy(&data[start..position]).into_owned());
+                }
+                None => return Err("PPM header is truncated".to_string()),
+            }
+        }
+        if fields[0] != "P6" {
+            return Err(format!("Expected a binary PPM (P6), not {:?}", fields[0]));
+        }
+        let parse = |field: &str| {
+            field
+                .parse::<u32>()
+                .map_err(|_| format!("Invalid PPM header field {:?}", field))
+        };
+        let (width, height, max_value) =
+            (parse(&fields[1])?, parse(&fields[2])?, parse(&fields[3])?);
+        if max_value != 255 {
+            return Err(format!(
+                "Only 8-bit PPM images are supported, not a maximum value of {}",
+                max_value
+            ));
+        }
+        let size = width as usize * height as usize * 3;
+        let rgb = data
+            .get(position + 1..position + 1 + size)
+            .ok_or_else(|| format!("PPM image data is shorter than {} bytes", size))?;
+        let pixels = rgb
+            .chunks_exact(3)
+            .flat_map(|rgb| [rgb[0], rgb[1], rgb[2], 255])
+            .collect();
+        Ok(Image::new(width, height, pixels))
+    }
+
+    /// Encodes the image as a binary PPM (`P6`), dropping the alpha channel.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut data = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        for pixel in self.pixels.chunks_exact(4) {
+            data.extend_from_slice(&pixel[..3]);
+        }
+        data
+    }
+
+    /// Encodes the image as an uncompressed RGBA PNG.
+    pub fn to_png(&self) -> Vec<u8> {
+        let row_size = self.width as usize * 4;
+        // Each row starts with its filter type, which is always 0 (None)
+        let mut raw = Vec::with_capacity((row_size + 1) * self.height as usize);
+        for row in self.pixels.chunks_exact(row_size.max(1)) {
+            raw.push(0);
+            raw.extend_from_slice(row);
+        }
+
+        // A zlib stream of stored deflate blocks
+        let mut zlib = vec![0x78, 0x01];
+        let mut blocks = raw.chunks(0xFFFF).peekable();
+        if blocks.peek().is_none() {
+            zlib.extend_from_slice(&[1, 0, 0, 0xFF, 0xFF]);
+        }
+        while let Some(block) = blocks.next() {
+            let last = blocks.peek().is_none();
+            let len = block.len() as u16;
+            zlib.push(last as u8);
+            zlib.extend_from_slice(&len.to_le_bytes());
+            zlib.extend_from_slice(&(!len).to_le_bytes());
+            zlib.extend_from_slice(block);
+        }
+        zlib.extend_from_slice(&adler32(&raw).to_be_bytes());
+
+        let mut header = Vec::with_capacity(13);
+        header.extend_from_slice(&self.width.to_be_bytes());
+        header.extend_from_slice(&self.height.to_be_bytes());
+        // 8 bits per channel, RGBA, deflate, adaptive filtering, no interlacing
+        header.extend_from_slice(&[8, 6, 0, 0, 0]);
+
+        let mut data = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+        write_png_chunk(&mut data, b"IHDR", &header);
+        write_png_chunk(&mut data, b"IDAT", &zlib);
+        write_png_chunk(&mut data, b"IEND", &[]);
+        data
+    }
+
+    /// Writes the image to `path` as a PNG, or as a PPM if the extension is `ppm`.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let is_ppm = path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("ppm"));
+        let data = if is_ppm { self.to_ppm() } else { self.to_png() };
+        std::fs::write(path, data)
+    }
+}
+
+fn write_png_chunk(data: &mut Vec<u8>, kind: &[u8; 4], contents: &[u8]) {
+    data.extend_from_slice(&(contents.len() as u32).to_be_bytes());
+    let start = data.len();
+    data.extend_from_slice(kind);
+    data.extend_from_slice(contents);
+    let crc = crc32(&data[start..]);
+    data.extend_from_slice(&crc.to_be_bytes());
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    (b << 16) | a
+}
+
+/// How much an image may differ from the expected image, see [`compare`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Tolerance {
+    /// The largest difference allowed in any channel of a pixel.
+    pub channel: u8,
+    /// The number of pixels that may exceed `channel`.
+    pub max_mismatched_pixels: usize,
+}
+
+/// The result of [`compare`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Comparison {
+    /// The number of pixels with a channel that differs by more than the tolerance.
+    pub mismatched_pixels: usize,
+    /// The largest difference of any channel.
+    pub max_difference: u8,
+    /// Whether the number of mismatched pixels is within the budget.
+    pub passed: bool,
+    /// An image showing mismatched pixels in red over a faded copy of the expected image.
+    pub diff: Image,
+}
+
+/// Compares `actual` with `expected` channel by channel.
+///
+/// Returns an error if the images have different sizes.
+pub fn compare(
+    actual: &Image,
+    expected: &Image,
+    tolerance: Tolerance,
+) -> Result<Comparison, String> {
+    if (actual.width, actual.height) != (expected.width, expected.height) {
+        return Err(format!(
+            "Image is {}x{}, but the expected image is {}x{}",
+            actual.width, actual.height, expected.width, expected.height
+        ));
+    }
+    let mut mismatched_pixels = 0;
+    let mut max_difference = 0;
+    let mut diff = Vec::with_capacity(expected.pixels.len());
+    for (actual, expected) in actual
+        .pixels
+        .chunks_exact(4)
+        .zip(expected.pixels.chunks_exact(4))
+    {
+        let difference = actual
+            .iter()
+            .zip(expected)
+            .map(|(&a, &b)| a.abs_diff(b))
+            .max()
+            .unwrap_or(0);
+        max_difference = max_difference.max(difference);
+        if difference > tolerance.channel {
+            mismatched_pixels += 1;
+            diff.extend_from_slice(&[255, 0, 0, 255]);
+        } else {
+            let luma = (expected[0] as u32 * 3 + expected[1] as u32 * 6 + expected[2] as u32) / 10;
+            let faded = (luma / 4 + 192) as u8;
+            diff.extend_from_slice(&[faded, faded, faded, 255]);
+        }
+    }
+    Ok(Comparison {
+        mismatched_pixels,
+        max_difference,
+        passed: mismatched_pixels <= tolerance.max_mismatched_pixels,
+        diff: Image::new(expected.width, expected.height, diff),
+    })
+}
+
+/// Compares `actual` with `expected`, and panics if they don't match.
+///
+/// On failure `{name}-actual.png`, `{name}-expected.png` and `{name}-diff.png` are written to
+/// the directory in the `GLOW_TEST_OUTPUT` environment variable, or the temporary directory.
+pub fn assert_images_match(name: &str, actual: &Image, expected: &Image, tolerance: Tolerance) {
+    let comparison = compare(actual, expected, tolerance)
+        .unwrap_or_else(|err| panic!("glow: {}: {}", name, err));
+    if comparison.passed {
+        return;
+    }
+    let directory = std::env::var_os("GLOW_TEST_OUTPUT")
+        .map(PathBuf::from)
+        .unwrap_or_else(std::env::temp_dir);
+    let mut written = Vec::new();
+    for (suffix, image) in [
+        ("actual", actual),
+        ("expected", expected),
+        ("diff", &comparison.diff),
+    ] {
+        let path = directory.join(format!("{}-{}.png", name, suffix));
+        if image.save(&path).is_ok() {
+            written.push(path.display().to_string());
+        }
+    }
+    panic!(
+        "glow: {}: {} pixels differ by more than {} (the budget is {}, the largest difference is {}), see {}",
+        name,
+        comparison.mismatched_pixels,
+        tolerance.channel,
+        tolerance.max_mismatched_pixels,
+        comparison.max_difference,
+        written.join(", ")
+    );
+}
+
+/// Calls `render` with an offscreen `width` x `height` framebuffer bound, and reads its color
+/// buffer back as an image.
+///
+/// The framebuffer has an RGBA8 color buffer and a depth-stencil buffer, and the viewport covers
+/// all of it. OpenGL ES 2.0 and WebGL 1 have no RGBA8 renderbuffers, so an RGBA texture with
+/// unsigned bytes and a depth-only buffer are used there instead. The framebuffer,
+/// renderbuffer, texture, viewport and pack state are restored afterwards.
+pub unsafe fn render_to_image<C: HasContext, F: FnOnce(&C)>(
+    gl: &C,
+    width: u32,
+    height: u32,
+    render: F,
+) -> Result<Image, String> {
+    let version = gl.version();
+    let es2 = version.is_embedded && version.major < 3;
+    let previous_framebuffer = gl.get_parameter_framebuffer(FRAMEBUFFER_BINDING);
+    let previous_read_framebuffer = if es2 {
+        None
+    } else {
+        gl.get_parameter_framebuffer(READ_FRAMEBUFFER_BINDING)
+    };
+    let mut previous_viewport = [0; 4];
+    gl.get_parameter_i32_slice(VIEWPORT, &mut previous_viewport);
+
+    let framebuffer = gl.create_framebuffer()?;
+    let color = match ColorBuffer::create(gl, width, height, es2) {
+        Ok(color) => color,
+        Err(error) => {
+            gl.delete_framebuffer(framebuffer);
+            return Err(error);
+        }
+    };
+    let depth = match gl.create_renderbuffer() {
+        Ok(depth) => depth,
+        Err(error) => {
+            gl.delete_framebuffer(framebuffer);
+            color.delete(gl);
+            return Err(error);
+        }
+    };
+    let previous_renderbuffer = gl.get_parameter_renderbuffer(RENDERBUFFER_BINDING);
+    gl.bind_renderbuffer(RENDERBUFFER, Some(depth));
+    let (depth_format, depth_attachment) = if es2 {
+        (DEPTH_COMPONENT16, DEPTH_ATTACHMENT)
+    } else {
+        (DEPTH24_STENCIL8, DEPTH_STENCIL_ATTACHMENT)
+    };
+    gl.renderbuffer_storage(RENDERBUFFER, depth_format, width as i32, height as i32);
+    gl.bind_renderbuffer(RENDERBUFFER, previous_renderbuffer);
+    gl.bind_framebuffer(FRAMEBUFFER, Some(framebuffer));
+    color.attach(gl);
+    gl.framebuffer_renderbuffer(FRAMEBUFFER, depth_attachment, RENDERBUFFER, Some(depth));
+
+    let status = gl.check_framebuffer_status(FRAMEBUFFER);
+    let result = if status == FRAMEBUFFER_COMPLETE {
+        gl.viewport(0, 0, width as i32, height as i32);
+        render(gl);
+        read_image(gl, framebuffer, width, height, es2)
+    } else {
+        Err(format!(
+            "Offscreen framebuffer is incomplete with status {:#06x}",
+            status
+        ))
+    };
+
+    gl.bind_framebuffer(FRAMEBUFFER, previous_framebuffer);
+    if !es2 {
+        gl.bind_framebuffer(READ_FRAMEBUFFER, previous_read_framebuffer);
+    }
+    let [x, y, viewport_width, viewport_height] = previous_viewport;
+    gl.viewport(x, y, viewport_width, viewport_height);
+    gl.delete_framebuffer(framebuffer);
+    color.delete(gl);
+    gl.delete_renderbuffer(depth);
+    result
+}
+
+/// The color buffer of the framebuffer used by [`render_to_image`].
+enum ColorBuffer<C: HasContext> {
+    Renderbuffer(C::Renderbuffer),
+    Texture(C::Texture),
+}
+
+impl<C: HasContext> ColorBuffer<C> {
+    /// Creates an RGBA8 renderbuffer, or an RGBA texture on OpenGL ES 2.0 and WebGL 1, and
+    /// restores the binding it was allocated through.
+    unsafe fn create(gl: &C, width: u32, height: u32, es2: bool) -> Result<Self, String> {
+        if es2 {
+            let texture = gl.create_texture()?;
+            let previous = gl.get_parameter_texture(TEXTURE_BINDING_2D);
+            gl.bind_texture(TEXTURE_2D, Some(texture));
+            gl.tex_image_2d(
+                TEXTURE_2D,
+                0,
+                RGBA as i32,
+                width as i32,
+                height as i32,
+                0,
+                RGBA,
+                UNSIGNED_BYTE,
+                PixelUnpackData::Slice(None),
+            );
+            gl.tex_parameter_i32(TEXTURE_2D, TEXTURE_MIN_FILTER, NEAREST as i32);
+            gl.bind_texture(TEXTURE_2D, previous);
+            Ok(ColorBuffer::Texture(texture))
+        } else {
+            let renderbuffer = gl.create_renderbuffer()?;
+            let previous = gl.get_parameter_renderbuffer(RENDERBUFFER_BINDING);
+            gl.bind_renderbuffer(RENDERBUFFER, Some(renderbuffer));
+            gl.renderbuffer_storage(RENDERBUFFER, RGBA8, width as i32, height as i32);
+            gl.bind_renderbuffer(RENDERBUFFER, previous);
+            Ok(ColorBuffer::Renderbuffer(renderbuffer))
+        }
+    }
+
+    /// Attaches the color buffer to the bound framebuffer.
+    unsafe fn attach(&self, gl: &C) {
+        match *self {
+            ColorBuffer::Renderbuffer(renderbuffer) => gl.framebuffer_renderbuffer(
+                FRAMEBUFFER,
+                COLOR_ATTACHMENT0,
+                RENDERBUFFER,
+                Some(renderbuffer),
+            ),
+            ColorBuffer::Texture(texture) => gl.framebuffer_texture_2d(
+                FRAMEBUFFER,
+                COLOR_ATTACHMENT0,
+                TEXTURE_2D,
+                Some(texture),
+                0,
+            ),
+        }
+    }
+
+    unsafe fn delete(self, gl: &C) {
+        match self {
+            ColorBuffer::Renderbuffer(renderbuffer) => gl.delete_renderbuffer(renderbuffer),
+            ColorBuffer::Texture(texture) => gl.delete_texture(texture),
+        }
+    }
+}
+
+unsafe fn read_image<C: HasContext>(
+    gl: &C,
+    framebuffer: C::Framebuffer,
+    width: u32,
+    height: u32,
+    es2: bool,
+) -> Result<Image, String> {
+    // `render` may have bound other framebuffers
+    gl.bind_framebuffer(FRAMEBUFFER, Some(framebuffer));
+    let previous_pack = gl.pack_parameters();
+    PixelStore::default().apply_pack(gl);
+    let previous_pack_buffer = if es2 {
+        None
+    } else {
+        let buffer = gl.get_parameter_buffer(PIXEL_PACK_BUFFER_BINDING);
+        gl.bind_buffer(PIXEL_PACK_BUFFER, None);
+        buffer
+    };
+
+    let row_size = width as usize * 4;
+    let mut pixels = vec![0; row_size * height as usize];
+    gl.read_pixels(
+        0,
+        0,
+        width as i32,
+        height as i32,
+        RGBA,
+        UNSIGNED_BYTE,
+        PixelPackData::Slice(Some(&mut pixels)),
+    );
+
+    previous_pack.apply_pack(gl);
+    if !es2 {
+        gl.bind_buffer(PIXEL_PACK_BUFFER, previous_pack_buffer);
+    }
+
+    // Rows are read bottom to top
+    let mut flipped = Vec::with_capacity(pixels.len());
+    for row in pixels.chunks_exact(row_size.max(1)).rev() {
+        flipped.extend_from_slice(row);
+    }
+    Ok(Image::new(width, height, flipped))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+        assert_eq!(adler32(&[0xFF; 100_000]), adler32_slow(&[0xFF; 100_000]));
+    }
+
+    fn adler32_slow(data: &[u8]) -> u32 {
+        let (mut a, mut b) = (1u64, 0u64);
+        for &byte in data {
+            a = (a + byte as u64) % 65521;
+            b = (b + a) % 65521;
+        }
+        ((b << 16) | a) as u32
+    }
+
+    #[test]
+    fn test_encoding() {
+        let image = Image::new(2, 1, vec![255, 0, 0, 255, 0, 128, 255, 64]);
+        let png = image.to_png();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        // IEND always has the same CRC
+        assert_eq!(&png[png.len() - 8..], b"IEND\xAE\x42\x60\x82");
+
+        let ppm = image.to_ppm();
+        assert_eq!(ppm, b"P6\n2 1\n255\n\xFF\x00\x00\x00\x80\xFF");
+        let decoded = Image::from_ppm(b"P6 # comment\n2 1 255\n\xFF\x00\x00\x00\x80\xFF").unwrap();
+        assert_eq!(decoded.pixel(1, 0), [0, 128, 255, 255]);
+        assert!(Image::from_ppm(b"P6\n2 1\n255\n\xFF").is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let expected = Image::new(2, 2, vec![100; 16]);
+        let mut pixels = vec![100; 16];
+        pixels[0] = 103;
+        pixels[6] = 90;
+        let actual = Image::new(2, 2, pixels);
+
+        let tolerance = Tolerance {
+            channel: 3,
+            max_mismatched_pixels: 0,
+        };
+        let comparison = compare(&actual, &expected, tolerance).unwrap();
+        assert_eq!(comparison.mismatched_pixels, 1);
+        assert_eq!(comparison.max_difference, 10);
+        assert!(!comparison.passed);
+        assert_eq!(comparison.diff.pixel(1, 0), [255, 0, 0, 255]);
+
+        let tolerance = Tolerance {
+            channel: 3,
+            max_mismatched_pixels: 1,
+        };
+        assert!(compare(&actual, &expected, tolerance).unwrap().passed);
+        assert!(compare(&actual, &Image::new(1, 1, vec![0; 4]), tolerance).is_err());
+    }
+}
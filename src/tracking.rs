@@ -0,0 +1,387 @@
+#[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+use crate::sync::Mutex;
+use crate::*;
+#[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+use alloc::sync::{Arc, Weak};
+
+/// The types of GL objects that glow hands out handles for.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum ObjectKind {
+    Shader,
+    Program,
+    Buffer,
+    VertexArray,
+    Texture,
+    Sampler,
+    Fence,
+    Framebuffer,
+    Renderbuffer,
+    Query,
+    TransformFeedback,
+}
+
+impl ObjectKind {
+    pub const ALL: [ObjectKind; 11] = [
+        ObjectKind::Shader,
+        ObjectKind::Program,
+        ObjectKind::Buffer,
+        ObjectKind::VertexArray,
+        ObjectKind::Texture,
+        ObjectKind::Sampler,
+        ObjectKind::Fence,
+        ObjectKind::Framebuffer,
+        ObjectKind::Renderbuffer,
+        ObjectKind::Query,
+        ObjectKind::TransformFeedback,
+    ];
+
+    /// A lowercase name for messages, e.g. `vertex array`.
+    pub fn name(self) -> &'static str {
+        match self {
+            ObjectKind::Shader => "shader",
+            ObjectKind::Program => "program",
+            ObjectKind::Buffer => "buffer",
+            ObjectKind::VertexArray => "vertex array",
+            ObjectKind::Texture => "texture",
+            ObjectKind::Sampler => "sampler",
+            ObjectKind::Fence => "fence",
+            ObjectKind::Framebuffer => "framebuffer",
+            ObjectKind::Renderbuffer => "renderbuffer",
+            ObjectKind::Query => "query",
+            ObjectKind::TransformFeedback => "transform feedback",
+        }
+    }
+}
+
+impl core::fmt::Display for ObjectKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// An object that hasn't been deleted, see `Context::live_objects`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LiveObject<H> {
+    pub handle: H,
+    /// The label set with `object_label`, if any.
+    pub label: Option<String>,
+}
+
+/// The objects that haven't been deleted in a context, returned by `Context::live_objects`.
+#[derive(Clone, Debug)]
+pub struct ObjectCensus<C: HasContext> {
+    pub shaders: Vec<LiveObject<C::Shader>>,
+    pub programs: Vec<LiveObject<C::Program>>,
+    pub buffers: Vec<LiveObject<C::Buffer>>,
+    pub vertex_arrays: Vec<LiveObject<C::VertexArray>>,
+    pub textures: Vec<LiveObject<C::Texture>>,
+    pub samplers: Vec<LiveObject<C::Sampler>>,
+    pub fences: Vec<LiveObject<C::Fence>>,
+    pub framebuffers: Vec<LiveObject<C::Framebuffer>>,
+    pub renderbuffers: Vec<LiveObject<C::Renderbuffer>>,
+    pub queries: Vec<LiveObject<C::Query>>,
+    pub transform_feedbacks: Vec<LiveObject<C::TransformFeedback>>,
+}
+
+impl<C: HasContext> ObjectCensus<C> {
+    /// The number of live objects of a kind.
+    pub fn count(&self, kind: ObjectKind) -> usize {
+        match kind {
+            ObjectKind::Shader => self.shaders.len(),
+            ObjectKind::Program => self.programs.len(),
+            ObjectKind::Buffer => self.buffers.len(),
+            ObjectKind::VertexArray => self.vertex_arrays.len(),
+            ObjectKind::Texture => self.textures.len(),
+            ObjectKind::Sampler => self.samplers.len(),
+            ObjectKind::Fence => self.fences.len(),
+            ObjectKind::Framebuffer => self.framebuffers.len(),
+            ObjectKind::Renderbuffer => self.renderbuffers.len(),
+            ObjectKind::Query => self.queries.len(),
+            ObjectKind::TransformFeedback => self.transform_feedbacks.len(),
+        }
+    }
+
+    /// The number of live objects of every kind.
+    pub fn total(&self) -> usize {
+        ObjectKind::ALL.iter().map(|&kind| self.count(kind)).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.total() == 0
+    }
+}
+
+impl<C: HasContext> Default for ObjectCensus<C> {
+    fn default() -> Self {
+        ObjectCensus {
+            shaders: Vec::new(),
+            programs: Vec::new(),
+            buffers: Vec::new(),
+            vertex_arrays: Vec::new(),
+            textures: Vec::new(),
+            samplers: Vec::new(),
+            fences: Vec::new(),
+            framebuffers: Vec::new(),
+            renderbuffers: Vec::new(),
+            queries: Vec::new(),
+            transform_feedbacks: Vec::new(),
+        }
+    }
+}
+
+/// Lists the objects by kind, e.g. `2 buffer(s) [NativeBuffer(1) "vertices", NativeBuffer(4)]`.
+impl<C: HasContext> core::fmt::Display for ObjectCensus<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fn write_kind<H: core::fmt::Debug>(
+            f: &mut core::fmt::Formatter,
+            first: &mut bool,
+            kind: ObjectKind,
+            objects: &[LiveObject<H>],
+        ) -> core::fmt::Result {
+            if objects.is_empty() {
+                return Ok(());
+            }
+            if !core::mem::take(first) {
+                f.write_str(", ")?;
+            }
+            write!(f, "{} {}(s) [", objects.len(), kind)?;
+            for (i, object) in objects.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                write!(f, "{:?}", object.handle)?;
+                if let Some(label) = &object.label {
+                    write!(f, " {:?}", label)?;
+                }
+            }
+            f.write_str("]")
+        }
+
+        if self.is_empty() {
+            return f.write_str("no objects");
+        }
+        let first = &mut true;
+        write_kind(f, first, ObjectKind::Shader, &self.shaders)?;
+        write_kind(f, first, ObjectKind::Program, &self.programs)?;
+        write_kind(f, first, ObjectKind::Buffer, &self.buffers)?;
+        write_kind(f, first, ObjectKind::VertexArray, &self.vertex_arrays)?;
+        write_kind(f, first, ObjectKind::Texture, &self.textures)?;
+        write_kind(f, first, ObjectKind::Sampler, &self.samplers)?;
+        write_kind(f, first, ObjectKind::Fence, &self.fences)?;
+        write_kind(f, first, ObjectKind::Framebuffer, &self.framebuffers)?;
+        write_kind(f, first, ObjectKind::Renderbuffer, &self.renderbuffers)?;
+        write_kind(f, first, ObjectKind::Query, &self.queries)?;
+        write_kind(
+            f,
+            first,
+            ObjectKind::TransformFeedback,
+            &self.transform_feedbacks,
+        )
+    }
+}
+
+/// Why a handle passed to a validated method isn't usable.
+// Each backend can only tell some of these apart
+#[allow(dead_code)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum InvalidHandle {
+    /// The handle was deleted.
+    Deleted,
+    /// The handle is live in another context.
+    OtherContext,
+    /// The handle was never created.
+    NeverCreated,
+    /// The handle was deleted or is from another context, which the web backend can't tell
+    /// apart.
+    Unknown,
+}
+
+/// Panics with a message that names the method and the problem with the handle.
+#[cold]
+#[track_caller]
+pub(crate) fn invalid_handle(
+    method: &str,
+    kind: ObjectKind,
+    handle: &dyn core::fmt::Debug,
+    problem: InvalidHandle,
+) -> ! {
+    let problem = match problem {
+        InvalidHandle::Deleted => "which has been deleted",
+        InvalidHandle::OtherContext => "which belongs to another context",
+        InvalidHandle::NeverCreated => "which was never created",
+        InvalidHandle::Unknown => "which was deleted or belongs to another context",
+    };
+    panic!(
+        "glow: `{}` was called with {} {:?}, {}",
+        method, kind, handle, problem
+    )
+}
+
+/// The objects that are live in a context, used by the validation mode of native contexts.
+#[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+#[derive(Debug, Default)]
+pub(crate) struct ObjectTracker {
+    state: Mutex<TrackerState>,
+}
+
+#[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+#[derive(Debug, Default)]
+struct TrackerState {
+    live: BTreeSet<(ObjectKind, u64)>,
+    deleted: BTreeSet<(ObjectKind, u64)>,
+}
+
+/// Every tracker, so that handles from other contexts can be recognized.
+#[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+static TRACKERS: Mutex<Vec<Weak<ObjectTracker>>> = Mutex::new(Vec::new());
+
+#[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
+impl ObjectTracker {
+    pub(crate) fn register(tracker: &Arc<ObjectTracker>) {
+        let mut trackers = TRACKERS.lock();
+        trackers.retain(|tracker| tracker.strong_count() > 0);
+        trackers.push(Arc::downgrade(tracker));
+    }
+
+    pub(crate) fn created(&self, kind: ObjectKind, name: u64) {
+        let mut state = self.state.lock();
+        state.deleted.remove(&(kind, name));
+        state.live.insert((kind, name));
+    }
+
+    /// Checks that a handle is live, adopting it if it isn't tracked but `exists` says that the
+    /// driver knows it, e.g. because it was created before validation was enabled.
+    pub(crate) fn check(
+        &self,
+        kind: ObjectKind,
+        name: u64,
+        exists: impl FnOnce() -> bool,
+    ) -> Result<(), InvalidHandle> {
+        let mut state = self.state.lock();
+        if state.live.contains(&(kind, name)) {
+            return Ok(());
+        }
+        if state.deleted.contains(&(kind, name)) {
+            return Err(InvalidHandle::Deleted);
+        }
+        if exists() {
+            state.live.insert((kind, name));
+            return Ok(());
+        }
+        drop(state);
+        if self.is_live_elsewhere(kind, name) {
+            Err(InvalidHandle::OtherContext)
+        } else {
+            Err(InvalidHandle::NeverCreated)
+        }
+    }
+
+    /// Checks that a handle is live like [`ObjectTracker::check`], and marks it as deleted.
+    pub(crate) fn delete(
+        &self,
+        kind: ObjectKind,
+        name: u64,
+        exists: impl FnOnce() -> bool,
+    ) -> Result<(), InvalidHandle> {
+        self.check(kind, name, exists)?;
+        let mut state = self.state.lock();
+        state.live.remove(&(kind, name));
+        state.deleted.insert((kind, name));
+        Ok(())
+    }
+
+    /// The objects that are live, sorted by kind and name.
+    pub(crate) fn live(&self) -> Vec<(ObjectKind, u64)> {
+        let state = self.state.lock();
+        let mut live: Vec<_> = state.live.iter().copied().collect();
+        live.sort_unstable();
+        live
+    }
+
+    fn is_live_elsewhere(&self, kind: ObjectKind, name: u64) -> bool {
+        let trackers = TRACKERS.lock();
+        trackers
+            .iter()
+            .filter_map(Weak::upgrade)
+            .filter(|tracker| !core::ptr::eq(&**tracker, self))
+            .any(|tracker| {
+                let state = tracker.state.lock();
+                state.live.contains(&(kind, name))
+            })
+    }
+}
+
+#[cfg(all(test, any(not(target_arch = "wasm32"), target_os = "emscripten")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_object_tracker() {
+        let tracker = Arc::new(ObjectTracker::default());
+        let other = Arc::new(ObjectTracker::default());
+        ObjectTracker::register(&tracker);
+        ObjectTracker::register(&other);
+
+        tracker.created(ObjectKind::Texture, 1);
+        other.created(ObjectKind::Texture, 2);
+        assert_eq!(tracker.check(ObjectKind::Texture, 1, || false), Ok(()));
+        assert_eq!(
+            tracker.check(ObjectKind::Buffer, 1, || false),
+            Err(InvalidHandle::NeverCreated)
+        );
+        assert_eq!(
+            tracker.check(ObjectKind::Texture, 2, || false),
+            Err(InvalidHandle::OtherContext)
+        );
+
+        assert_eq!(tracker.delete(ObjectKind::Texture, 1, || false), Ok(()));
+        assert_eq!(
+            tracker.check(ObjectKind::Texture, 1, || true),
+            Err(InvalidHandle::Deleted)
+        );
+        assert_eq!(
+            tracker.delete(ObjectKind::Texture, 1, || false),
+            Err(InvalidHandle::Deleted)
+        );
+
+        // Objects that existed before tracking started are adopted
+        assert_eq!(tracker.check(ObjectKind::Buffer, 3, || true), Ok(()));
+        assert_eq!(tracker.check(ObjectKind::Buffer, 3, || false), Ok(()));
+
+        // Names are reused by the driver after they're deleted
+        tracker.created(ObjectKind::Texture, 1);
+        assert_eq!(tracker.check(ObjectKind::Texture, 1, || false), Ok(()));
+        assert_eq!(
+            tracker.live(),
+            vec![(ObjectKind::Buffer, 3), (ObjectKind::Texture, 1)]
+        );
+    }
+
+    #[test]
+    fn test_census_display() {
+        let name = |name| core::num::NonZeroU32::new(name).unwrap();
+        let mut census = ObjectCensus::<Context>::default();
+        assert!(census.is_empty());
+        assert_eq!(census.to_string(), "no objects");
+
+        census.buffers.push(LiveObject {
+            handle: NativeBuffer(name(1)),
+            label: Some("vertices".to_string()),
+        });
+        census.buffers.push(LiveObject {
+            handle: NativeBuffer(name(4)),
+            label: None,
+        });
+        census.textures.push(LiveObject {
+            handle: NativeTexture(name(2)),
+            label: None,
+        });
+        assert_eq!(census.count(ObjectKind::Buffer), 2);
+        assert_eq!(census.total(), 3);
+        assert_eq!(
+            census.to_string(),
+            "2 buffer(s) [NativeBuffer(1) \"vertices\", NativeBuffer(4)], \
+             1 texture(s) [NativeTexture(2)]"
+        );
+    }
+}
@@ -0,0 +1,464 @@
+use crate::reflection::is_sampler;
+#[cfg(all(
+    debug_assertions,
+    any(not(target_arch = "wasm32"), target_os = "emscripten")
+))]
+use crate::sync::Mutex;
+use crate::*;
+#[cfg(all(
+    debug_assertions,
+    any(not(target_arch = "wasm32"), target_os = "emscripten")
+))]
+use alloc::collections::BTreeMap;
+
+/// A value for a uniform of any type, see `Context::set_uniform`.
+///
+/// Matrices are column-major, e.g. `Mat2x3` has 2 columns of 3 rows.
+#[derive(Clone, Debug, PartialEq)]
+pub enum UniformValue {
+    Float(f32),
+    Vec2([f32; 2]),
+    Vec3([f32; 3]),
+    Vec4([f32; 4]),
+    Int(i32),
+    IVec2([i32; 2]),
+    IVec3([i32; 3]),
+    IVec4([i32; 4]),
+    Uint(u32),
+    UVec2([u32; 2]),
+    UVec3([u32; 3]),
+    UVec4([u32; 4]),
+    Bool(bool),
+    BVec2([bool; 2]),
+    BVec3([bool; 3]),
+    BVec4([bool; 4]),
+    Mat2([f32; 4]),
+    Mat2x3([f32; 6]),
+    Mat2x4([f32; 8]),
+    Mat3x2([f32; 6]),
+    Mat3([f32; 9]),
+    Mat3x4([f32; 12]),
+    Mat4x2([f32; 8]),
+    Mat4x3([f32; 12]),
+    Mat4([f32; 16]),
+    /// The texture unit of a sampler.
+    Sampler(i32),
+    /// Elements of an array uniform, starting at the first. All elements must have the same
+    /// type, and they can't be arrays.
+    Array(Vec<UniformValue>),
+}
+
+/// The components of a value, converted to the type of the `uniform_*` method that sets it.
+enum Components {
+    F32(Vec<f32>),
+    I32(Vec<i32>),
+    U32(Vec<u32>),
+}
+
+impl UniformValue {
+    /// Checks that the value can be set on a uniform of type `utype` with `size` array elements,
+    /// as reported by `get_active_uniform`.
+    ///
+    /// Samplers accept `Sampler` and `Int` values. Arrays may be shorter than the uniform.
+    pub fn check(&self, utype: u32, size: i32) -> Result<(), String> {
+        let (element, len) = match self {
+            UniformValue::Array(elements) => match elements.first() {
+                Some(first) => {
+                    self.element_type()?;
+                    (first, elements.len())
+                }
+                None => return Ok(()),
+            },
+            value => (value, 1),
+        };
+        let matches = match element {
+            UniformValue::Sampler(_) => is_sampler(utype),
+            UniformValue::Int(_) => utype == INT || is_sampler(utype),
+            element => element.element_type() == Ok(utype),
+        };
+        if !matches {
+            return Err(format!(
+                "a {} value can't be set on a uniform of type {:#06x}",
+                self.glsl_name(),
+                utype
+            ));
+        }
+        if len > size.max(1) as usize {
+            return Err(format!(
+                "{} elements can't be set on a uniform array of {}",
+                len, size
+            ));
+        }
+        Ok(())
+    }
+
+    /// The GLSL type of the value, or of the elements of an array, e.g. `FLOAT_VEC3`. Samplers
+    /// have the type `INT`, like the `uniform_1_i32` call that sets them.
+    fn element_type(&self) -> Result<u32, String> {
+        Ok(match self {
+            UniformValue::Float(_) => FLOAT,
+            UniformValue::Vec2(_) => FLOAT_VEC2,
+            UniformValue::Vec3(_) => FLOAT_VEC3,
+            UniformValue::Vec4(_) => FLOAT_VEC4,
+            UniformValue::Int(_) | UniformValue::Sampler(_) => INT,
+            UniformValue::IVec2(_) => INT_VEC2,
+            UniformValue::IVec3(_) => INT_VEC3,
+            UniformValue::IVec4(_) => INT_VEC4,
+            UniformValue::Uint(_) => UNSIGNED_INT,
+            UniformValue::UVec2(_) => UNSIGNED_INT_VEC2,
+            UniformValue::UVec3(_) => UNSIGNED_INT_VEC3,
+            UniformValue::UVec4(_) => UNSIGNED_INT_VEC4,
+            UniformValue::Bool(_) => BOOL,
+            UniformValue::BVec2(_) => BOOL_VEC2,
+            UniformValue::BVec3(_) => BOOL_VEC3,
+            UniformValue::BVec4(_) => BOOL_VEC4,
+            UniformValue::Mat2(_) => FLOAT_MAT2,
+            UniformValue::Mat2x3(_) => FLOAT_MAT2x3,
+            UniformValue::Mat2x4(_) => FLOAT_MAT2x4,
+            UniformValue::Mat3x2(_) => FLOAT_MAT3x2,
+            UniformValue::Mat3(_) => FLOAT_MAT3,
+            UniformValue::Mat3x4(_) => FLOAT_MAT3x4,
+            UniformValue::Mat4x2(_) => FLOAT_MAT4x2,
+            UniformValue::Mat4x3(_) => FLOAT_MAT4x3,
+            UniformValue::Mat4(_) => FLOAT_MAT4,
+            UniformValue::Array(elements) => {
+                let first = match elements.first() {
+                    Some(UniformValue::Array(_)) | None => {
+                        return Err("arrays must be non-empty and can't be nested".to_string())
+                    }
+                    Some(first) => first,
+                };
+                let same = elements.iter().all(|element| {
+                    core::mem::discriminant(element) == core::mem::discriminant(first)
+                });
+                if !same {
+                    return Err("array elements must all have the same type".to_string());
+                }
+                return first.element_type();
+            }
+        })
+    }
+
+    fn glsl_name(&self) -> String {
+        let name = match self {
+            UniformValue::Float(_) => "float",
+            UniformValue::Vec2(_) => "vec2",
+            UniformValue::Vec3(_) => "vec3",
+            UniformValue::Vec4(_) => "vec4",
+            UniformValue::Int(_) => "int",
+            UniformValue::IVec2(_) => "ivec2",
+            UniformValue::IVec3(_) => "ivec3",
+            UniformValue::IVec4(_) => "ivec4",
+            UniformValue::Uint(_) => "uint",
+            UniformValue::UVec2(_) => "uvec2",
+            UniformValue::UVec3(_) => "uvec3",
+            UniformValue::UVec4(_) => "uvec4",
+            UniformValue::Bool(_) => "bool",
+            UniformValue::BVec2(_) => "bvec2",
+            UniformValue::BVec3(_) => "bvec3",
+            UniformValue::BVec4(_) => "bvec4",
+            UniformValue::Mat2(_) => "mat2",
+            UniformValue::Mat2x3(_) => "mat2x3",
+            UniformValue::Mat2x4(_) => "mat2x4",
+            UniformValue::Mat3x2(_) => "mat3x2",
+            UniformValue::Mat3(_) => "mat3",
+            UniformValue::Mat3x4(_) => "mat3x4",
+            UniformValue::Mat4x2(_) => "mat4x2",
+            UniformValue::Mat4x3(_) => "mat4x3",
+            UniformValue::Mat4(_) => "mat4",
+            UniformValue::Sampler(_) => "sampler",
+            UniformValue::Array(elements) => {
+                return match elements.first() {
+                    Some(first) => format!("{}[{}]", first.glsl_name(), elements.len()),
+                    None => "[]".to_string(),
+                }
+            }
+        };
+        name.to_string()
+    }
+
+    /// Appends the components of the value to `components`, which has the right variant.
+    fn push_components(&self, components: &mut Components) {
+        match (self, components) {
+            (UniformValue::Float(x), Components::F32(v)) => v.push(*x),
+            (UniformValue::Vec2(x), Components::F32(v)) => v.extend(x),
+            (UniformValue::Vec3(x), Components::F32(v)) => v.extend(x),
+            (UniformValue::Vec4(x), Components::F32(v)) => v.extend(x),
+            (UniformValue::Mat2(x), Components::F32(v)) => v.extend(x),
+            (UniformValue::Mat2x3(x), Components::F32(v)) => v.extend(x),
+            (UniformValue::Mat2x4(x), Components::F32(v)) => v.extend(x),
+            (UniformValue::Mat3x2(x), Components::F32(v)) => v.extend(x),
+            (UniformValue::Mat3(x), Components::F32(v)) => v.extend(x),
+            (UniformValue::Mat3x4(x), Components::F32(v)) => v.extend(x),
+            (UniformValue::Mat4x2(x), Components::F32(v)) => v.extend(x),
+            (UniformValue::Mat4x3(x), Components::F32(v)) => v.extend(x),
+            (UniformValue::Mat4(x), Components::F32(v)) => v.extend(x),
+            (UniformValue::Int(x) | UniformValue::Sampler(x), Components::I32(v)) => v.push(*x),
+            (UniformValue::IVec2(x), Components::I32(v)) => v.extend(x),
+            (UniformValue::IVec3(x), Components::I32(v)) => v.extend(x),
+            (UniformValue::IVec4(x), Components::I32(v)) => v.extend(x),
+            (UniformValue::Bool(x), Components::I32(v)) => v.push(*x as i32),
+            (UniformValue::BVec2(x), Components::I32(v)) => v.extend(x.map(i32::from)),
+            (UniformValue::BVec3(x), Components::I32(v)) => v.extend(x.map(i32::from)),
+            (UniformValue::BVec4(x), Components::I32(v)) => v.extend(x.map(i32::from)),
+            (UniformValue::Uint(x), Components::U32(v)) => v.push(*x),
+            (UniformValue::UVec2(x), Components::U32(v)) => v.extend(x),
+            (UniformValue::UVec3(x), Components::U32(v)) => v.extend(x),
+            (UniformValue::UVec4(x), Components::U32(v)) => v.extend(x),
+            (UniformValue::Array(elements), components) => {
+                for element in elements {
+                    element.push_components(components);
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Sets a uniform of the current program, or of `program` with `program_uniform_*`, with the
+/// `*_slice` method for the type of `value`.
+pub(crate) unsafe fn set_uniform<C: HasContext>(
+    gl: &C,
+    program: Option<C::Program>,
+    location: Option<&C::UniformLocation>,
+    value: &UniformValue,
+) {
+    let utype = match value.element_type() {
+        Ok(utype) => utype,
+        Err(_) if value == &UniformValue::Array(Vec::new()) => return,
+        Err(message) => panic!(
+            "glow: `set_uniform` was called with an invalid value, {}",
+            message
+        ),
+    };
+    let mut components = match utype {
+        INT | INT_VEC2 | INT_VEC3 | INT_VEC4 | BOOL | BOOL_VEC2 | BOOL_VEC3 | BOOL_VEC4 => {
+            Components::I32(Vec::new())
+        }
+        UNSIGNED_INT | UNSIGNED_INT_VEC2 | UNSIGNED_INT_VEC3 | UNSIGNED_INT_VEC4 => {
+            Components::U32(Vec::new())
+        }
+        _ => Components::F32(Vec::new()),
+    };
+    value.push_components(&mut components);
+
+    macro_rules! set {
+        ($uniform:ident, $program_uniform:ident, $($arg:expr),*) => {
+            match program {
+                Some(program) => gl.$program_uniform(program, location, $($arg),*),
+                None => gl.$uniform(location, $($arg),*),
+            }
+        };
+    }
+    match (utype, &components) {
+        (FLOAT, Components::F32(v)) => set!(uniform_1_f32_slice, program_uniform_1_f32_slice, v),
+        (FLOAT_VEC2, Components::F32(v)) => {
+            set!(uniform_2_f32_slice, program_uniform_2_f32_slice, v)
+        }
+        (FLOAT_VEC3, Components::F32(v)) => {
+            set!(uniform_3_f32_slice, program_uniform_3_f32_slice, v)
+        }
+        (FLOAT_VEC4, Components::F32(v)) => {
+            set!(uniform_4_f32_slice, program_uniform_4_f32_slice, v)
+        }
+        (FLOAT_MAT2, Components::F32(v)) => set!(
+            uniform_matrix_2_f32_slice,
+            program_uniform_matrix_2_f32_slice,
+            false,
+            v
+        ),
+        (FLOAT_MAT2x3, Components::F32(v)) => set!(
+            uniform_matrix_2x3_f32_slice,
+            program_uniform_matrix_2x3_f32_slice,
+            false,
+            v
+        ),
+        (FLOAT_MAT2x4, Components::F32(v)) => set!(
+            uniform_matrix_2x4_f32_slice,
+            program_uniform_matrix_2x4_f32_slice,
+            false,
+            v
+        ),
+        (FLOAT_MAT3x2, Components::F32(v)) => set!(
+            uniform_matrix_3x2_f32_slice,
+            program_uniform_matrix_3x2_f32_slice,
+            false,
+            v
+        ),
+        (FLOAT_MAT3, Components::F32(v)) => set!(
+            uniform_matrix_3_f32_slice,
+            program_uniform_matrix_3_f32_slice,
+            false,
+            v
+        ),
+        (FLOAT_MAT3x4, Components::F32(v)) => set!(
+            uniform_matrix_3x4_f32_slice,
+            program_uniform_matrix_3x4_f32_slice,
+            false,
+            v
+        ),
+        (FLOAT_MAT4x2, Components::F32(v)) => set!(
+            uniform_matrix_4x2_f32_slice,
+            program_uniform_matrix_4x2_f32_slice,
+            false,
+            v
+        ),
+        (FLOAT_MAT4x3, Components::F32(v)) => set!(
+            uniform_matrix_4x3_f32_slice,
+            program_uniform_matrix_4x3_f32_slice,
+            false,
+            v
+        ),
+        (FLOAT_MAT4, Components::F32(v)) => set!(
+            uniform_matrix_4_f32_slice,
+            program_uniform_matrix_4_f32_slice,
+            false,
+            v
+        ),
+        (INT | BOOL, Components::I32(v)) => {
+            set!(uniform_1_i32_slice, program_uniform_1_i32_slice, v)
+        }
+        (INT_VEC2 | BOOL_VEC2, Components::I32(v)) => {
+            set!(uniform_2_i32_slice, program_uniform_2_i32_slice, v)
+        }
+        (INT_VEC3 | BOOL_VEC3, Components::I32(v)) => {
+            set!(uniform_3_i32_slice, program_uniform_3_i32_slice, v)
+        }
+        (INT_VEC4 | BOOL_VEC4, Components::I32(v)) => {
+            set!(uniform_4_i32_slice, program_uniform_4_i32_slice, v)
+        }
+        (UNSIGNED_INT, Components::U32(v)) => {
+            set!(uniform_1_u32_slice, program_uniform_1_u32_slice, v)
+        }
+        (UNSIGNED_INT_VEC2, Components::U32(v)) => {
+            set!(uniform_2_u32_slice, program_uniform_2_u32_slice, v)
+        }
+        (UNSIGNED_INT_VEC3, Components::U32(v)) => {
+            set!(uniform_3_u32_slice, program_uniform_3_u32_slice, v)
+        }
+        (UNSIGNED_INT_VEC4, Components::U32(v)) => {
+            set!(uniform_4_u32_slice, program_uniform_4_u32_slice, v)
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// The active uniforms of programs by location, used to check the values passed to
+/// `Context::set_uniform` in debug builds. A program's uniforms are queried the first time one of
+/// them is set, and dropped when it's linked again or deleted.
+#[cfg(all(
+    debug_assertions,
+    any(not(target_arch = "wasm32"), target_os = "emscripten")
+))]
+pub(crate) struct UniformCache<C: HasContext> {
+    programs: Mutex<BTreeMap<C::Program, ActiveUniforms<C>>>,
+}
+
+#[cfg(all(
+    debug_assertions,
+    any(not(target_arch = "wasm32"), target_os = "emscripten")
+))]
+type ActiveUniforms<C> = Vec<(<C as HasContext>::UniformLocation, ActiveUniform)>;
+
+#[cfg(all(
+    debug_assertions,
+    any(not(target_arch = "wasm32"), target_os = "emscripten")
+))]
+impl<C: HasContext> UniformCache<C> {
+    pub(crate) fn new() -> Self {
+        UniformCache {
+            programs: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    pub(crate) fn invalidate(&self, program: C::Program) {
+        self.programs.lock().remove(&program);
+    }
+
+    /// Panics if `value` doesn't match the type of the uniform at `location` in `program`, or in
+    /// the current program. Locations of array elements other than the first aren't checked.
+    #[track_caller]
+    pub(crate) unsafe fn check(
+        &self,
+        gl: &C,
+        method: &str,
+        program: Option<C::Program>,
+        location: &C::UniformLocation,
+        value: &UniformValue,
+    ) where
+        C::UniformLocation: PartialEq,
+    {
+        let program = match program.or_else(|| gl.get_parameter_program(CURRENT_PROGRAM)) {
+            Some(program) => program,
+            None => return,
+        };
+        let mut programs = self.programs.lock();
+        let uniforms = programs
+            .entry(program)
+            .or_insert_with(|| active_uniforms(gl, program));
+        if let Some((_, uniform)) = uniforms.iter().find(|(other, _)| other == location) {
+            if let Err(message) = value.check(uniform.utype, uniform.size) {
+                panic!(
+                    "glow: `{}` was called for uniform `{}` with an invalid value, {}",
+                    method, uniform.name, message
+                );
+            }
+        }
+    }
+}
+
+#[cfg(all(
+    debug_assertions,
+    any(not(target_arch = "wasm32"), target_os = "emscripten")
+))]
+unsafe fn active_uniforms<C: HasContext>(gl: &C, program: C::Program) -> ActiveUniforms<C> {
+    (0..gl.get_active_uniforms(program))
+        .filter_map(|index| gl.get_active_uniform(program, index))
+        .filter_map(|uniform| {
+            let location = gl.get_uniform_location(program, &uniform.name)?;
+            Some((location, uniform))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(UniformValue::Vec3([0.0; 3]).check(FLOAT_VEC3, 1).is_ok());
+        assert!(UniformValue::Sampler(2).check(SAMPLER_2D, 1).is_ok());
+        assert!(UniformValue::Int(2).check(SAMPLER_CUBE, 1).is_ok());
+        assert!(UniformValue::Mat4([0.0; 16]).check(FLOAT_MAT4, 1).is_ok());
+        assert_eq!(
+            UniformValue::Vec3([0.0; 3]).check(FLOAT_VEC4, 1),
+            Err("a vec3 value can't be set on a uniform of type 0x8b52".to_string())
+        );
+        assert!(UniformValue::Bool(true).check(INT, 1).is_err());
+
+        let array = UniformValue::Array(vec![UniformValue::Float(1.0); 3]);
+        assert!(array.check(FLOAT, 4).is_ok());
+        assert_eq!(
+            array.check(FLOAT, 2),
+            Err("3 elements can't be set on a uniform array of 2".to_string())
+        );
+        let mixed = UniformValue::Array(vec![UniformValue::Float(1.0), UniformValue::Int(1)]);
+        assert_eq!(
+            mixed.check(FLOAT, 2),
+            Err("array elements must all have the same type".to_string())
+        );
+        assert!(UniformValue::Array(Vec::new()).check(FLOAT, 1).is_ok());
+    }
+
+    #[test]
+    fn test_components() {
+        let mut components = Components::I32(Vec::new());
+        UniformValue::Array(vec![
+            UniformValue::BVec2([true, false]),
+            UniformValue::BVec2([false, true]),
+        ])
+        .push_components(&mut components);
+        assert!(matches!(components, Components::I32(v) if v == [1, 0, 0, 1]));
+    }
+}
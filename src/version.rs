@@ -1,3 +1,6 @@
+use alloc::borrow::ToOwned;
+use alloc::string::{String, ToString};
+
 /// A version number for a specific component of an OpenGL implementation
 #[derive(Clone, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Version {
@@ -121,8 +124,36 @@ impl Version {
     }
 }
 
-impl std::fmt::Debug for Version {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+impl core::str::FromStr for Version {
+    type Err = VersionParseError;
+
+    /// Parses a `GL_VERSION` string, e.g. `4.6.0 NVIDIA 535.54.03` or `OpenGL ES 3.2 Mesa 23.0.4`.
+    ///
+    /// WebGL versions are returned as the OpenGL ES version that they're based on, so `WebGL 2.0`
+    /// is parsed as `OpenGL ES 3.0`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Version::parse(s).map_err(|_| VersionParseError(s.to_owned()))
+    }
+}
+
+impl core::fmt::Display for Version {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if self.is_embedded {
+            write!(f, "OpenGL ES ")?;
+        }
+        write!(f, "{}.{}", self.major, self.minor)?;
+        if let Some(revision) = self.revision {
+            write!(f, ".{}", revision)?;
+        }
+        if !self.vendor_info.is_empty() {
+            write!(f, " {}", self.vendor_info)?;
+        }
+        Ok(())
+    }
+}
+
+impl core::fmt::Debug for Version {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         match (
             self.major,
             self.minor,
@@ -139,9 +170,111 @@ impl std::fmt::Debug for Version {
     }
 }
 
+/// An error returned when parsing a [`Version`] or [`ShadingLanguageVersion`] fails.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VersionParseError(String);
+
+impl VersionParseError {
+    /// The string that couldn't be parsed.
+    pub fn input(&self) -> &str {
+        &self.0
+    }
+}
+
+impl core::fmt::Display for VersionParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "invalid GL version string: {:?}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for VersionParseError {}
+
+/// The version of the shading language supported by a context, as reported by
+/// `GL_SHADING_LANGUAGE_VERSION`.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ShadingLanguageVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub is_embedded: bool,
+    pub vendor_info: String,
+}
+
+impl ShadingLanguageVersion {
+    /// The version number used in `#version` directives, e.g. `460` or `300`.
+    pub fn number(&self) -> u32 {
+        self.major * 100 + self.minor * 10
+    }
+}
+
+impl core::str::FromStr for ShadingLanguageVersion {
+    type Err = VersionParseError;
+
+    /// Parses a `GL_SHADING_LANGUAGE_VERSION` string, e.g. `4.60 NVIDIA`,
+    /// `OpenGL ES GLSL ES 3.20` or `WebGL GLSL ES 3.00 (OpenGL ES GLSL ES 3.0 Chromium)`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let version: Version = s.parse()?;
+        Ok(ShadingLanguageVersion {
+            major: version.major,
+            minor: version.minor,
+            is_embedded: version.is_embedded,
+            vendor_info: version.vendor_info,
+        })
+    }
+}
+
+impl core::fmt::Display for ShadingLanguageVersion {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if self.is_embedded {
+            write!(f, "GLSL ES ")?;
+        }
+        write!(f, "{}.{:02}", self.major, self.minor * 10)?;
+        if !self.vendor_info.is_empty() {
+            write!(f, " {}", self.vendor_info)?;
+        }
+        Ok(())
+    }
+}
+
+/// Identifies the driver behind a context, from `GL_VENDOR`, `GL_RENDERER`, `GL_VERSION` and
+/// `GL_SHADING_LANGUAGE_VERSION`.
+///
+/// On WebGL the unmasked vendor and renderer are used when `WEBGL_debug_renderer_info` is
+/// available.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DriverInfo {
+    pub vendor: String,
+    pub renderer: String,
+    pub version: Version,
+    /// `None` if the context doesn't report a shading language version that can be parsed.
+    pub glsl: Option<ShadingLanguageVersion>,
+    pub is_webgl: bool,
+}
+
+impl DriverInfo {
+    /// Whether the context is implemented by ANGLE, e.g. on top of Direct3D or Metal.
+    pub fn is_angle(&self) -> bool {
+        self.renderer.contains("ANGLE") || self.version.vendor_info.contains("ANGLE")
+    }
+
+    /// Whether the driver is part of Mesa.
+    pub fn is_mesa(&self) -> bool {
+        self.vendor == "Mesa"
+            || self.vendor.starts_with("Mesa ")
+            || self.version.vendor_info.contains("Mesa")
+    }
+
+    /// Whether the context is rendered on the CPU, e.g. by llvmpipe or SwiftShader.
+    pub fn is_software(&self) -> bool {
+        ["llvmpipe", "softpipe", "SwiftShader", "Software Rasterizer"]
+            .iter()
+            .any(|name| self.renderer.contains(name))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Version;
+    use super::{DriverInfo, ShadingLanguageVersion, Version, VersionParseError};
 
     #[test]
     fn test_version_parse() {
@@ -211,4 +344,57 @@ mod tests {
             ))
         );
     }
+
+    #[test]
+    fn test_version_from_str() {
+        assert_eq!(
+            "4.6.0 NVIDIA 535.54.03".parse(),
+            Ok(Version::new(4, 6, Some(0), "NVIDIA 535.54.03".to_string()))
+        );
+        assert_eq!(
+            "Unknown".parse::<Version>(),
+            Err(VersionParseError("Unknown".to_string()))
+        );
+        assert_eq!(
+            Version::new_embedded(3, 2, "Mesa 23.0.4".to_string()).to_string(),
+            "OpenGL ES 3.2 Mesa 23.0.4"
+        );
+    }
+
+    #[test]
+    fn test_shading_language_version() {
+        let glsl: ShadingLanguageVersion = "4.60 NVIDIA".parse().unwrap();
+        assert_eq!((glsl.number(), glsl.is_embedded), (460, false));
+        assert_eq!(glsl.to_string(), "4.60 NVIDIA");
+        let glsl: ShadingLanguageVersion = "OpenGL ES GLSL ES 3.20".parse().unwrap();
+        assert_eq!((glsl.number(), glsl.is_embedded), (320, true));
+        let glsl: ShadingLanguageVersion = "WebGL GLSL ES 1.0 (OpenGL ES GLSL ES 1.0 Chromium)"
+            .parse()
+            .unwrap();
+        assert_eq!((glsl.number(), glsl.is_embedded), (100, true));
+    }
+
+    #[test]
+    fn test_driver_info() {
+        let info = DriverInfo {
+            vendor: "Mesa".to_string(),
+            renderer: "llvmpipe (LLVM 15.0.7, 256 bits)".to_string(),
+            version: "4.5 (Core Profile) Mesa 23.0.4".parse().unwrap(),
+            glsl: "4.50".parse().ok(),
+            is_webgl: false,
+        };
+        assert!(info.is_mesa() && info.is_software() && !info.is_angle());
+
+        let info = DriverInfo {
+            vendor: "Google Inc. (NVIDIA)".to_string(),
+            renderer: "ANGLE (NVIDIA, NVIDIA GeForce RTX 3080 Direct3D11 vs_5_0 ps_5_0, D3D11)"
+                .to_string(),
+            version: "WebGL 2.0 (OpenGL ES 3.0 Chromium)".parse().unwrap(),
+            glsl: "WebGL GLSL ES 3.00 (OpenGL ES GLSL ES 3.0 Chromium)"
+                .parse()
+                .ok(),
+            is_webgl: true,
+        };
+        assert!(info.is_angle() && !info.is_mesa() && !info.is_software());
+    }
 }
@@ -0,0 +1,326 @@
+use crate::*;
+
+/// How often an attribute advances to the next element of its buffer.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum VertexStep {
+    /// Once per vertex.
+    Vertex,
+    /// Once every `n` instances, like `vertex_attrib_divisor(index, n)`.
+    Instance(u32),
+}
+
+impl VertexStep {
+    fn divisor(self) -> u32 {
+        match self {
+            VertexStep::Vertex => 0,
+            VertexStep::Instance(divisor) => divisor,
+        }
+    }
+}
+
+/// How the shader sees the components of an attribute.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AttributeConversion {
+    /// Converted to floats, like `vertex_attrib_pointer_f32` without normalization.
+    Float,
+    /// Integers normalized to `[0, 1]` or `[-1, 1]`.
+    Normalized,
+    /// Integers passed to `int`/`uint` attributes, like `vertex_attrib_pointer_i32`.
+    Integer,
+    /// Doubles passed to `double` attributes, like `vertex_attrib_pointer_f64`. Requires
+    /// [`Features::vertex_attrib_binding`].
+    Double,
+}
+
+/// A buffer that vertex attributes read from.
+#[derive(Clone, Copy, Debug)]
+pub struct VertexBufferLayout<B> {
+    pub buffer: B,
+    /// The offset of the first vertex in the buffer, in bytes.
+    pub offset: i32,
+    /// The distance between vertices in bytes. Must be positive.
+    pub stride: i32,
+    pub step: VertexStep,
+}
+
+/// A vertex attribute and where it's read from.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct VertexAttribute {
+    /// The attribute location in the shader.
+    pub location: u32,
+    /// The index of the buffer in [`VertexLayout::buffers`].
+    pub buffer: usize,
+    /// The number of components, 1 to 4.
+    pub size: i32,
+    /// The component type, e.g. `FLOAT` or `UNSIGNED_BYTE`.
+    pub data_type: u32,
+    pub conversion: AttributeConversion,
+    /// The offset from the start of a vertex, in bytes.
+    pub offset: u32,
+}
+
+/// A description of the buffers and attributes of a vertex array, see
+/// `Context::apply_vertex_layout`.
+#[derive(Clone, Debug)]
+pub struct VertexLayout<C: HasContext> {
+    pub buffers: Vec<VertexBufferLayout<C::Buffer>>,
+    pub attributes: Vec<VertexAttribute>,
+    /// The buffer bound to `ELEMENT_ARRAY_BUFFER`.
+    pub index_buffer: Option<C::Buffer>,
+}
+
+impl<C: HasContext> VertexLayout<C> {
+    pub fn new() -> Self {
+        VertexLayout {
+            buffers: Vec::new(),
+            attributes: Vec::new(),
+            index_buffer: None,
+        }
+    }
+
+    /// Adds a buffer and returns its index for [`VertexLayout::attribute`].
+    pub fn buffer(
+        &mut self,
+        buffer: C::Buffer,
+        offset: i32,
+        stride: i32,
+        step: VertexStep,
+    ) -> usize {
+        self.buffers.push(VertexBufferLayout {
+            buffer,
+            offset,
+            stride,
+            step,
+        });
+        self.buffers.len() - 1
+    }
+
+    /// Adds an attribute at `location` that reads `size` components of `data_type` from `buffer`.
+    pub fn attribute(
+        &mut self,
+        location: u32,
+        buffer: usize,
+        size: i32,
+        data_type: u32,
+        conversion: AttributeConversion,
+        offset: u32,
+    ) -> &mut Self {
+        self.attributes.push(VertexAttribute {
+            location,
+            buffer,
+            size,
+            data_type,
+            conversion,
+            offset,
+        });
+        self
+    }
+
+    fn validate(&self, features: &Features) -> Result<(), String> {
+        // Also needed to query and restore `VERTEX_ARRAY_BINDING`
+        if !features.vertex_arrays {
+            return Err("Vertex array objects are not supported by this context".to_string());
+        }
+        for buffer in &self.buffers {
+            if buffer.stride <= 0 {
+                return Err(format!(
+                    "Vertex buffer stride must be positive, not {}",
+                    buffer.stride
+                ));
+            }
+            if buffer.step != VertexStep::Vertex && !features.instancing {
+                return Err("Instanced attributes are not supported by this context".to_string());
+            }
+        }
+        for attribute in &self.attributes {
+            if attribute.buffer >= self.buffers.len() {
+                return Err(format!(
+                    "Attribute {} reads from buffer {}, but the layout has {} buffers",
+                    attribute.location,
+                    attribute.buffer,
+                    self.buffers.len()
+                ));
+            }
+            if !(1..=4).contains(&attribute.size) {
+                return Err(format!(
+                    "Attribute {} has {} components, expected 1 to 4",
+                    attribute.location, attribute.size
+                ));
+            }
+            if attribute.conversion == AttributeConversion::Double
+                && !features.vertex_attrib_binding
+            {
+                return Err(format!(
+                    "Attribute {} is a double attribute, which is not supported by this context",
+                    attribute.location
+                ));
+            }
+            let duplicate = self
+                .attributes
+                .iter()
+                .filter(|other| other.location == attribute.location)
+                .count()
+                > 1;
+            if duplicate {
+                return Err(format!(
+                    "Attribute location {} is used more than once",
+                    attribute.location
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<C: HasContext> Default for VertexLayout<C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sets up the attributes and buffers of `vao` as described by `layout`.
+///
+/// Uses `vertex_attrib_format_*` and `bind_vertex_buffer` when separate attribute formats are
+/// supported, and `vertex_attrib_pointer_*` otherwise. The vertex array and `ARRAY_BUFFER`
+/// bindings are restored afterwards.
+pub(crate) unsafe fn apply_vertex_layout<C: HasContext>(
+    gl: &C,
+    vao: C::VertexArray,
+    layout: &VertexLayout<C>,
+) -> Result<(), String> {
+    let features = gl.features();
+    layout.validate(features)?;
+    let previous_vao = gl.get_parameter_vertex_array(VERTEX_ARRAY_BINDING);
+    gl.bind_vertex_array(Some(vao));
+
+    if features.vertex_attrib_binding {
+        for (index, buffer) in layout.buffers.iter().enumerate() {
+            let index = index as u32;
+            gl.bind_vertex_buffer(index, Some(buffer.buffer), buffer.offset, buffer.stride);
+            gl.vertex_binding_divisor(index, buffer.step.divisor());
+        }
+        for attribute in &layout.attributes {
+            let (location, size, data_type, offset) = (
+                attribute.location,
+                attribute.size,
+                attribute.data_type,
+                attribute.offset,
+            );
+            match attribute.conversion {
+                AttributeConversion::Float | AttributeConversion::Normalized => gl
+                    .vertex_attrib_format_f32(
+                        location,
+                        size,
+                        data_type,
+                        attribute.conversion == AttributeConversion::Normalized,
+                        offset,
+                    ),
+                AttributeConversion::Integer => {
+                    gl.vertex_attrib_format_i32(location, size, data_type, offset)
+                }
+                AttributeConversion::Double => {
+                    gl.vertex_attrib_format_f64(location, size, data_type, offset)
+                }
+            }
+            gl.vertex_attrib_binding(location, attribute.buffer as u32);
+            gl.enable_vertex_attrib_array(location);
+        }
+    } else {
+        let previous_buffer = gl.get_parameter_buffer(ARRAY_BUFFER_BINDING);
+        for attribute in &layout.attributes {
+            let buffer = &layout.buffers[attribute.buffer];
+            let (location, size, data_type, stride) = (
+                attribute.location,
+                attribute.size,
+                attribute.data_type,
+                buffer.stride,
+            );
+            let offset = buffer.offset + attribute.offset as i32;
+            gl.bind_buffer(ARRAY_BUFFER, Some(buffer.buffer));
+            match attribute.conversion {
+                AttributeConversion::Float | AttributeConversion::Normalized => gl
+                    .vertex_attrib_pointer_f32(
+                        location,
+                        size,
+                        data_type,
+                        attribute.conversion == AttributeConversion::Normalized,
+                        stride,
+                        offset,
+                    ),
+                AttributeConversion::Integer => {
+                    gl.vertex_attrib_pointer_i32(location, size, data_type, stride, offset)
+                }
+                AttributeConversion::Double => {
+                    gl.vertex_attrib_pointer_f64(location, size, data_type, stride, offset)
+                }
+            }
+            if features.instancing {
+                gl.vertex_attrib_divisor(location, buffer.step.divisor());
+            }
+            gl.enable_vertex_attrib_array(location);
+        }
+        gl.bind_buffer(ARRAY_BUFFER, previous_buffer);
+    }
+    gl.bind_buffer(ELEMENT_ARRAY_BUFFER, layout.index_buffer);
+
+    gl.bind_vertex_array(previous_vao);
+    Ok(())
+}
+
+#[cfg(all(test, any(not(target_arch = "wasm32"), target_os = "emscripten")))]
+mod tests {
+    use super::*;
+    use core::num::NonZeroU32;
+
+    #[test]
+    fn test_validate() {
+        let basic = Features {
+            vertex_arrays: true,
+            ..Features::default()
+        };
+        let instancing = Features {
+            instancing: true,
+            ..basic.clone()
+        };
+        let mut layout = VertexLayout::<Context>::new();
+        let buffer = NativeBuffer(NonZeroU32::new(1).unwrap());
+        let vertices = layout.buffer(buffer, 0, 20, VertexStep::Vertex);
+        layout
+            .attribute(0, vertices, 3, FLOAT, AttributeConversion::Float, 0)
+            .attribute(
+                1,
+                vertices,
+                4,
+                UNSIGNED_BYTE,
+                AttributeConversion::Normalized,
+                12,
+            );
+        assert!(layout.validate(&basic).is_ok());
+        assert!(layout.validate(&Features::default()).is_err());
+
+        let instances = layout.buffer(buffer, 0, 16, VertexStep::Instance(1));
+        layout.attribute(2, instances, 4, FLOAT, AttributeConversion::Float, 0);
+        assert!(layout.validate(&instancing).is_ok());
+        assert!(layout.validate(&basic).is_err());
+
+        layout.attribute(2, vertices, 1, FLOAT, AttributeConversion::Float, 16);
+        assert!(layout.validate(&instancing).is_err());
+        layout.attributes.pop();
+
+        layout.attribute(3, 5, 1, FLOAT, AttributeConversion::Float, 0);
+        assert!(layout.validate(&instancing).is_err());
+        layout.attributes.pop();
+
+        layout.attribute(3, vertices, 5, FLOAT, AttributeConversion::Float, 0);
+        assert!(layout.validate(&instancing).is_err());
+        layout.attributes.pop();
+
+        layout.attribute(3, vertices, 1, DOUBLE, AttributeConversion::Double, 0);
+        assert!(layout.validate(&instancing).is_err());
+        let vertex_attrib_binding = Features {
+            vertex_attrib_binding: true,
+            ..instancing
+        };
+        assert!(layout.validate(&vertex_attrib_binding).is_ok());
+    }
+}
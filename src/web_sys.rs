@@ -1,8 +1,11 @@
 use super::*;
 
+use crate::format::{PixelStore, PixelStoreState};
+use crate::memory::{self, MemoryTracker};
+use crate::tracking::{self, InvalidHandle};
 use js_sys::{self, Array};
 use slotmap::{new_key_type, SlotMap};
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use web_sys::{
     self, HtmlCanvasElement, HtmlImageElement, HtmlVideoElement, ImageBitmap, ImageData,
     WebGl2RenderingContext, WebGlBuffer, WebGlFramebuffer, WebGlProgram, WebGlQuery,
@@ -21,7 +24,7 @@ enum RawRenderingContext {
 
 #[allow(dead_code)]
 #[derive(Debug)]
-struct Extensions {
+struct ExtensionObjects {
     pub angle_instanced_arrays: Option<web_sys::AngleInstancedArrays>,
     pub ext_blend_minmax: Option<web_sys::ExtBlendMinmax>,
     pub ext_color_buffer_float: Option<web_sys::ExtColorBufferFloat>,
@@ -65,12 +68,37 @@ fn tracked_resource<K: slotmap::Key, V>() -> TrackedResource<K, V> {
     RefCell::new(SlotMap::with_key())
 }
 
+/// A slotmap key for a type of GL object.
+trait ObjectKey: slotmap::Key {
+    const KIND: ObjectKind;
+}
+
+/// Looks up objects, panicking with the method name if the key isn't live.
+trait TrackedLookup<K, V> {
+    fn get_tracked(&self, key: K, method: &str) -> &V;
+}
+
+impl<K: ObjectKey, V> TrackedLookup<K, V> for SlotMap<K, V> {
+    #[track_caller]
+    fn get_tracked(&self, key: K, method: &str) -> &V {
+        match self.get(key) {
+            Some(value) => value,
+            None => tracking::invalid_handle(method, K::KIND, &key, InvalidHandle::Unknown),
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct Context {
     raw: RawRenderingContext,
-    extensions: Extensions,
+    extensions: ExtensionObjects,
     version: Version,
-    supported_extensions: HashSet<String>,
+    supported_extensions: BTreeSet<String>,
+    extension_set: Extensions,
+    features: Features,
+    limits: Limits,
+    driver_info: DriverInfo,
+    workarounds: Workarounds,
     shaders: TrackedResource<WebShaderKey, WebGlShader>,
     programs: TrackedResource<WebProgramKey, WebGlProgram>,
     buffers: TrackedResource<WebBufferKey, WebGlBuffer>,
@@ -82,6 +110,11 @@ pub struct Context {
     renderbuffers: TrackedResource<WebRenderbufferKey, WebGlRenderbuffer>,
     queries: TrackedResource<WebQueryKey, WebGlQuery>,
     transform_feedbacks: TrackedResource<WebTransformFeedbackKey, WebGlTransformFeedback>,
+    validation: bool,
+    #[cfg(feature = "log")]
+    leak_warning: bool,
+    memory: Option<MemoryTracker<Context>>,
+    pixel_store: Cell<PixelStoreState>,
 }
 
 // bindgen's gl context don't share an interface so a macro is used to deduplicate a bunch of code here
@@ -108,7 +141,7 @@ macro_rules! build_extensions {
                 .and_then(|maybe_ext| maybe_ext.map(|_| ()))
         }
 
-        let extensions = Extensions {
+        let extensions = ExtensionObjects {
             angle_instanced_arrays: get_extension::<web_sys::AngleInstancedArrays>(
                 &$context,
                 "ANGLE_instanced_arrays",
@@ -242,15 +275,17 @@ macro_rules! build_extensions {
             .unwrap()
             .iter()
             .map(|val| val.as_string().unwrap())
-            .collect::<HashSet<String>>();
+            .collect::<BTreeSet<String>>();
+        let extension_set = Extensions::from_names(supported_extensions.iter().map(String::as_str));
 
-        (extensions, supported_extensions)
+        (extensions, supported_extensions, extension_set)
     }};
 }
 
 impl Context {
     pub fn from_webgl1_context(context: WebGlRenderingContext) -> Self {
-        let (extensions, supported_extensions) = build_extensions!(context, WebGlRenderingContext);
+        let (extensions, supported_extensions, extension_set) =
+            build_extensions!(context, WebGlRenderingContext);
 
         // Retrieve and parse `GL_VERSION`
         let raw_jsvalue = context
@@ -262,10 +297,21 @@ impl Context {
         let version = Version::parse(&raw_string)
             .expect("context.get_parameter(VERSION) should be parseable as an OpenGL version");
 
-        Self {
+        let mut context = Self {
             raw: RawRenderingContext::WebGl1(context),
             extensions,
             supported_extensions,
+            extension_set,
+            features: Features::default(),
+            limits: Limits::default(),
+            driver_info: DriverInfo {
+                vendor: String::new(),
+                renderer: String::new(),
+                version: version.clone(),
+                glsl: None,
+                is_webgl: true,
+            },
+            workarounds: Workarounds::default(),
             version,
             shaders: tracked_resource(),
             programs: tracked_resource(),
@@ -278,11 +324,24 @@ impl Context {
             renderbuffers: tracked_resource(),
             queries: tracked_resource(),
             transform_feedbacks: tracked_resource(),
-        }
+            validation: false,
+            #[cfg(feature = "log")]
+            leak_warning: false,
+            memory: None,
+            pixel_store: Cell::default(),
+        };
+        context.features = Features::new(&context.version, &context.extension_set, true);
+        context.limits = unsafe { Limits::query(&context, &context.features) };
+        context
+            .pixel_store
+            .set(unsafe { PixelStoreState::query(&context) });
+        context.query_driver_info();
+        context
     }
 
     pub fn from_webgl2_context(context: WebGl2RenderingContext) -> Self {
-        let (extensions, supported_extensions) = build_extensions!(context, WebGl2RenderingContext);
+        let (extensions, supported_extensions, extension_set) =
+            build_extensions!(context, WebGl2RenderingContext);
 
         // Retrieve and parse `GL_VERSION`
         let raw_jsvalue = context
@@ -294,10 +353,21 @@ impl Context {
         let version = Version::parse(&raw_string)
             .expect("context.get_parameter(VERSION) should be parseable as an OpenGL version");
 
-        Self {
+        let mut context = Self {
             raw: RawRenderingContext::WebGl2(context),
             extensions,
             supported_extensions,
+            extension_set,
+            features: Features::default(),
+            limits: Limits::default(),
+            driver_info: DriverInfo {
+                vendor: String::new(),
+                renderer: String::new(),
+                version: version.clone(),
+                glsl: None,
+                is_webgl: true,
+            },
+            workarounds: Workarounds::default(),
             version,
             shaders: tracked_resource(),
             programs: tracked_resource(),
@@ -310,7 +380,186 @@ impl Context {
             renderbuffers: tracked_resource(),
             queries: tracked_resource(),
             transform_feedbacks: tracked_resource(),
-        }
+            validation: false,
+            #[cfg(feature = "log")]
+            leak_warning: false,
+            memory: None,
+            pixel_store: Cell::default(),
+        };
+        context.features = Features::new(&context.version, &context.extension_set, true);
+        context.limits = unsafe { Limits::query(&context, &context.features) };
+        context
+            .pixel_store
+            .set(unsafe { PixelStoreState::query(&context) });
+        context.query_driver_info();
+        context
+    }
+
+    fn query_driver_info(&mut self) {
+        // The unmasked strings are only available through `WEBGL_debug_renderer_info`, some
+        // browsers also return them for `VENDOR` and `RENDERER`
+        let (vendor, renderer) = if self.extensions.webgl_debug_renderer_info.is_some() {
+            (
+                web_sys::WebglDebugRendererInfo::UNMASKED_VENDOR_WEBGL,
+                web_sys::WebglDebugRendererInfo::UNMASKED_RENDERER_WEBGL,
+            )
+        } else {
+            (VENDOR, RENDERER)
+        };
+        unsafe {
+            self.driver_info.vendor = self.get_parameter_string(vendor);
+            self.driver_info.renderer = self.get_parameter_string(renderer);
+            self.driver_info.glsl = self
+                .get_parameter_string(SHADING_LANGUAGE_VERSION)
+                .parse()
+                .ok();
+        }
+        self.workarounds = Workarounds::new(&self.driver_info);
+    }
+
+    /// Replaces the driver workarounds detected when the context was created.
+    pub fn set_workarounds(&mut self, workarounds: Workarounds) {
+        self.workarounds = workarounds;
+    }
+
+    /// Enables or disables validation of object handles and pixel data, which is meant for debug
+    /// builds.
+    ///
+    /// Methods always panic with their name when passed a handle that has been deleted or belongs
+    /// to another context. While validation is enabled, deleting such a handle panics too instead
+    /// of being ignored.
+    ///
+    /// Pixel transfer methods always panic when given a slice that is too short for the
+    /// dimensions, format and type, taking the pixel store parameters into account. While
+    /// validation is enabled, they also panic on unknown format and type pairs and on compressed
+    /// image sizes that don't match the format. See [`format::check_unpack`] to get an error
+    /// instead.
+    pub fn set_validation(&mut self, enabled: bool) {
+        self.validation = enabled;
+    }
+
+    /// Returns whether validation of object handles is enabled, see [`Context::set_validation`].
+    pub fn validation(&self) -> bool {
+        self.validation
+    }
+
+    /// Enables or disables a warning through `log` when the context is dropped while objects
+    /// haven't been deleted, listing them like [`Context::live_objects`].
+    #[cfg(feature = "log")]
+    pub fn set_leak_warning(&mut self, enabled: bool) {
+        self.leak_warning = enabled;
+    }
+
+    /// Returns the objects that haven't been deleted.
+    ///
+    /// WebGL doesn't support object labels, so every label is `None`.
+    pub unsafe fn live_objects(&self) -> ObjectCensus<Self> {
+        fn live<K: slotmap::Key, V>(resource: &TrackedResource<K, V>) -> Vec<LiveObject<K>> {
+            resource
+                .borrow()
+                .keys()
+                .map(|handle| LiveObject {
+                    handle,
+                    label: None,
+                })
+                .collect()
+        }
+
+        ObjectCensus {
+            shaders: live(&self.shaders),
+            programs: live(&self.programs),
+            buffers: live(&self.buffers),
+            vertex_arrays: live(&self.vertex_arrays),
+            textures: live(&self.textures),
+            samplers: live(&self.samplers),
+            fences: live(&self.fences),
+            framebuffers: live(&self.framebuffers),
+            renderbuffers: live(&self.renderbuffers),
+            queries: live(&self.queries),
+            transform_feedbacks: live(&self.transform_feedbacks),
+        }
+    }
+
+    /// Enables or disables estimating the memory used by buffers, textures and renderbuffers, see
+    /// [`Context::memory_usage`].
+    ///
+    /// Only storage allocated while tracking is enabled is counted. Allocations through a target
+    /// are attributed to the object bound to it, which is looked up on every allocation.
+    pub fn set_memory_tracking(&mut self, enabled: bool) {
+        if !enabled {
+            self.memory = None;
+        } else if self.memory.is_none() {
+            self.memory = Some(MemoryTracker::new());
+        }
+    }
+
+    /// Returns the estimated size of every buffer, texture and renderbuffer allocated while
+    /// memory tracking was enabled. WebGL doesn't report driver totals.
+    pub fn memory_usage(&self) -> MemoryUsage<Self> {
+        match &self.memory {
+            Some(memory) => memory.usage(),
+            None => MemoryUsage::default(),
+        }
+    }
+
+    /// Returns the header for a shader using `features`, see [`ShaderFeature`].
+    pub fn glsl_preamble(&self, features: &[ShaderFeature]) -> String {
+        glsl::preamble(&self.driver_info, &self.extension_set, features)
+    }
+
+    /// Starts compiling and linking a program from `(shader_type, source)` pairs without waiting
+    /// for the driver, see [`PendingProgram`].
+    ///
+    /// Without `KHR_parallel_shader_compile` this falls back to blocking compilation when the
+    /// `PendingProgram` is first polled.
+    ///
+    /// # Safety
+    ///
+    /// The context must be current whenever the returned `PendingProgram` is polled or dropped.
+    pub unsafe fn compile_program_async(
+        &self,
+        sources: &[(u32, &str)],
+    ) -> PendingProgram<'_, Self> {
+        PendingProgram::new(self, sources)
+    }
+
+    /// Returns the attributes, uniforms, samplers, uniform and storage blocks and transform
+    /// feedback varyings of a linked program, see [`ProgramReflection`].
+    ///
+    /// Parts that the context doesn't support, e.g. storage blocks on WebGL 2, are left empty.
+    pub unsafe fn reflect_program(&self, program: WebProgramKey) -> ProgramReflection<Self> {
+        reflection::reflect_program(self, program)
+    }
+
+    /// Sets the uniform at `location` in the current program to `value`, using the
+    /// `uniform_*` method for its type.
+    ///
+    /// WebGL uniform locations can't be matched to the uniforms they belong to, so the value isn't
+    /// checked against the type of the uniform. Use [`UniformValue::check`] with the type from
+    /// [`Context::reflect_program`] instead.
+    ///
+    /// Panics if `value` is an array with nested arrays or elements of different types.
+    pub unsafe fn set_uniform(
+        &self,
+        location: Option<&WebGlUniformLocation>,
+        value: &UniformValue,
+    ) {
+        uniform::set_uniform(self, None, location, value)
+    }
+
+    /// Sets up the buffers and attributes of `vao` as described by `layout`.
+    ///
+    /// Uses `vertex_attrib_pointer_*`, since WebGL has no separate attribute formats. The current
+    /// vertex array and `ARRAY_BUFFER` bindings are left unchanged.
+    ///
+    /// Returns an error without touching `vao` if the layout is invalid or needs features the
+    /// context doesn't support, e.g. double attributes.
+    pub unsafe fn apply_vertex_layout(
+        &self,
+        vao: WebVertexArrayKey,
+        layout: &VertexLayout<Self>,
+    ) -> Result<(), String> {
+        vertex_layout::apply_vertex_layout(self, vao, layout)
     }
 
     // These functions are defined in this order:
@@ -1473,7 +1722,8 @@ impl Context {
         num_views: i32,
     ) {
         let textures = self.textures.borrow();
-        let raw_texture = texture.map(|t| textures.get_unchecked(t));
+        let raw_texture =
+            texture.map(|t| textures.get_tracked(t, "framebuffer_texture_multiview_ovr"));
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => {
                 panic!("OVR_multiview2 is not supported in WebGL1")
@@ -1556,7 +1806,9 @@ impl Context {
             RawRenderingContext::WebGl1(ref gl) => gl.get_parameter(parameter),
             RawRenderingContext::WebGl2(ref gl) => gl.get_parameter(parameter),
         }
-        .ok();
+        .ok()
+        // Nothing is bound
+        .filter(|value| !value.is_null());
         match parameter_value {
             Some(pv) => {
                 let resource: TResource = pv.into();
@@ -1578,6 +1830,22 @@ impl Context {
     }
 }
 
+#[cfg(feature = "log")]
+impl Drop for Context {
+    fn drop(&mut self) {
+        if self.leak_warning {
+            let census = unsafe { self.live_objects() };
+            if !census.is_empty() {
+                log::warn!(
+                    "glow: context dropped with {} live objects: {}",
+                    census.total(),
+                    census
+                );
+            }
+        }
+    }
+}
+
 new_key_type! { pub struct WebShaderKey; }
 new_key_type! { pub struct WebProgramKey; }
 new_key_type! { pub struct WebBufferKey; }
@@ -1590,6 +1858,30 @@ new_key_type! { pub struct WebRenderbufferKey; }
 new_key_type! { pub struct WebQueryKey; }
 new_key_type! { pub struct WebTransformFeedbackKey; }
 
+macro_rules! object_keys {
+    ($($key:ident => $kind:ident,)*) => {
+        $(
+            impl ObjectKey for $key {
+                const KIND: ObjectKind = ObjectKind::$kind;
+            }
+        )*
+    };
+}
+
+object_keys! {
+    WebShaderKey => Shader,
+    WebProgramKey => Program,
+    WebBufferKey => Buffer,
+    WebVertexArrayKey => VertexArray,
+    WebTextureKey => Texture,
+    WebSamplerKey => Sampler,
+    WebFenceKey => Fence,
+    WebFramebufferKey => Framebuffer,
+    WebRenderbufferKey => Renderbuffer,
+    WebQueryKey => Query,
+    WebTransformFeedbackKey => TransformFeedback,
+}
+
 impl crate::__private::Sealed for Context {}
 
 impl HasContext for Context {
@@ -1606,10 +1898,22 @@ impl HasContext for Context {
     type UniformLocation = WebGlUniformLocation;
     type TransformFeedback = WebTransformFeedbackKey;
 
-    fn supported_extensions(&self) -> &HashSet<String> {
+    fn supported_extensions(&self) -> &BTreeSet<String> {
         &self.supported_extensions
     }
 
+    fn extensions(&self) -> &Extensions {
+        &self.extension_set
+    }
+
+    fn features(&self) -> &Features {
+        &self.features
+    }
+
+    fn limits(&self) -> &Limits {
+        &self.limits
+    }
+
     fn supports_debug(&self) -> bool {
         false
     }
@@ -1618,6 +1922,22 @@ impl HasContext for Context {
         &self.version
     }
 
+    fn driver_info(&self) -> &DriverInfo {
+        &self.driver_info
+    }
+
+    fn workarounds(&self) -> &Workarounds {
+        &self.workarounds
+    }
+
+    fn unpack_parameters(&self) -> PixelStore {
+        self.pixel_store.get().unpack
+    }
+
+    fn pack_parameters(&self) -> PixelStore {
+        self.pixel_store.get().pack
+    }
+
     unsafe fn create_framebuffer(&self) -> Result<Self::Framebuffer, String> {
         let raw_framebuffer = match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.create_framebuffer(),
@@ -1773,12 +2093,19 @@ impl HasContext for Context {
                 RawRenderingContext::WebGl1(ref gl) => gl.delete_shader(Some(s)),
                 RawRenderingContext::WebGl2(ref gl) => gl.delete_shader(Some(s)),
             }
+        } else if self.validation {
+            tracking::invalid_handle(
+                "delete_shader",
+                ObjectKind::Shader,
+                &shader,
+                InvalidHandle::Unknown,
+            );
         }
     }
 
     unsafe fn shader_source(&self, shader: Self::Shader, source: &str) {
         let shaders = self.shaders.borrow();
-        let raw_shader = shaders.get_unchecked(shader);
+        let raw_shader = shaders.get_tracked(shader, "shader_source");
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.shader_source(raw_shader, source),
             RawRenderingContext::WebGl2(ref gl) => gl.shader_source(raw_shader, source),
@@ -1787,7 +2114,7 @@ impl HasContext for Context {
 
     unsafe fn compile_shader(&self, shader: Self::Shader) {
         let shaders = self.shaders.borrow();
-        let raw_shader = shaders.get_unchecked(shader);
+        let raw_shader = shaders.get_tracked(shader, "compile_shader");
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.compile_shader(raw_shader),
             RawRenderingContext::WebGl2(ref gl) => gl.compile_shader(raw_shader),
@@ -1796,7 +2123,7 @@ impl HasContext for Context {
 
     unsafe fn get_shader_completion_status(&self, shader: Self::Shader) -> bool {
         let shaders = self.shaders.borrow();
-        let raw_shader = shaders.get_unchecked(shader);
+        let raw_shader = shaders.get_tracked(shader, "get_shader_completion_status");
         if self.extensions.khr_parallel_shader_compile.is_none() {
             panic!("Parallel shader compile is not supported")
         }
@@ -1814,7 +2141,7 @@ impl HasContext for Context {
 
     unsafe fn get_shader_compile_status(&self, shader: Self::Shader) -> bool {
         let shaders = self.shaders.borrow();
-        let raw_shader = shaders.get_unchecked(shader);
+        let raw_shader = shaders.get_tracked(shader, "get_shader_compile_status");
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => {
                 gl.get_shader_parameter(raw_shader, COMPILE_STATUS)
@@ -1829,7 +2156,7 @@ impl HasContext for Context {
 
     unsafe fn get_shader_info_log(&self, shader: Self::Shader) -> String {
         let shaders = self.shaders.borrow();
-        let raw_shader = shaders.get_unchecked(shader);
+        let raw_shader = shaders.get_tracked(shader, "get_shader_info_log");
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.get_shader_info_log(raw_shader),
             RawRenderingContext::WebGl2(ref gl) => gl.get_shader_info_log(raw_shader),
@@ -1837,6 +2164,10 @@ impl HasContext for Context {
         .unwrap_or_else(|| String::from(""))
     }
 
+    unsafe fn get_shader_diagnostics(&self, shader: Self::Shader) -> Vec<ShaderDiagnostic> {
+        diagnostics::parse_info_log(&self.get_shader_info_log(shader))
+    }
+
     unsafe fn get_shader_precision_format(
         &self,
         shader_type: u32,
@@ -1902,14 +2233,21 @@ impl HasContext for Context {
                 RawRenderingContext::WebGl1(ref gl) => gl.delete_program(Some(p)),
                 RawRenderingContext::WebGl2(ref gl) => gl.delete_program(Some(p)),
             }
+        } else if self.validation {
+            tracking::invalid_handle(
+                "delete_program",
+                ObjectKind::Program,
+                &program,
+                InvalidHandle::Unknown,
+            );
         }
     }
 
     unsafe fn attach_shader(&self, program: Self::Program, shader: Self::Shader) {
         let programs = self.programs.borrow();
         let shaders = self.shaders.borrow();
-        let raw_program = programs.get_unchecked(program);
-        let raw_shader = shaders.get_unchecked(shader);
+        let raw_program = programs.get_tracked(program, "attach_shader");
+        let raw_shader = shaders.get_tracked(shader, "attach_shader");
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.attach_shader(raw_program, raw_shader),
             RawRenderingContext::WebGl2(ref gl) => gl.attach_shader(raw_program, raw_shader),
@@ -1919,8 +2257,8 @@ impl HasContext for Context {
     unsafe fn detach_shader(&self, program: Self::Program, shader: Self::Shader) {
         let programs = self.programs.borrow();
         let shaders = self.shaders.borrow();
-        let raw_program = programs.get_unchecked(program);
-        let raw_shader = shaders.get_unchecked(shader);
+        let raw_program = programs.get_tracked(program, "detach_shader");
+        let raw_shader = shaders.get_tracked(shader, "detach_shader");
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.detach_shader(raw_program, raw_shader),
             RawRenderingContext::WebGl2(ref gl) => gl.detach_shader(raw_program, raw_shader),
@@ -1929,7 +2267,7 @@ impl HasContext for Context {
 
     unsafe fn link_program(&self, program: Self::Program) {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "link_program");
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.link_program(raw_program),
             RawRenderingContext::WebGl2(ref gl) => gl.link_program(raw_program),
@@ -1938,7 +2276,7 @@ impl HasContext for Context {
 
     unsafe fn validate_program(&self, program: Self::Program) {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "validate_program");
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.validate_program(raw_program),
             RawRenderingContext::WebGl2(ref gl) => gl.validate_program(raw_program),
@@ -1947,7 +2285,7 @@ impl HasContext for Context {
 
     unsafe fn get_program_completion_status(&self, program: Self::Program) -> bool {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "get_program_completion_status");
         if self.extensions.khr_parallel_shader_compile.is_none() {
             panic!("Parallel shader compile is not supported")
         }
@@ -1965,7 +2303,7 @@ impl HasContext for Context {
 
     unsafe fn get_program_link_status(&self, program: Self::Program) -> bool {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "get_program_link_status");
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => {
                 gl.get_program_parameter(raw_program, LINK_STATUS)
@@ -1980,7 +2318,7 @@ impl HasContext for Context {
 
     unsafe fn get_program_validate_status(&self, program: Self::Program) -> bool {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "get_program_validate_status");
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => {
                 gl.get_program_parameter(raw_program, VALIDATE_STATUS)
@@ -1995,7 +2333,7 @@ impl HasContext for Context {
 
     unsafe fn get_program_parameter_i32(&self, program: Self::Program, parameter: u32) -> i32 {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "get_program_parameter_i32");
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.get_program_parameter(raw_program, parameter),
             RawRenderingContext::WebGl2(ref gl) => gl.get_program_parameter(raw_program, parameter),
@@ -2009,7 +2347,7 @@ impl HasContext for Context {
 
     unsafe fn get_program_info_log(&self, program: Self::Program) -> String {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "get_program_info_log");
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.get_program_info_log(raw_program),
             RawRenderingContext::WebGl2(ref gl) => gl.get_program_info_log(raw_program),
@@ -2017,6 +2355,10 @@ impl HasContext for Context {
         .unwrap_or_else(|| String::from(""))
     }
 
+    unsafe fn get_program_diagnostics(&self, program: Self::Program) -> Vec<ShaderDiagnostic> {
+        diagnostics::parse_info_log(&self.get_program_info_log(program))
+    }
+
     unsafe fn get_program_resource_i32(
         &self,
         _program: Self::Program,
@@ -2027,6 +2369,24 @@ impl HasContext for Context {
         panic!("get_program_resource_i32 not supported on webgl");
     }
 
+    unsafe fn get_program_interface_i32(
+        &self,
+        _program: Self::Program,
+        _interface: u32,
+        _parameter: u32,
+    ) -> i32 {
+        panic!("get_program_interface_i32 not supported on webgl");
+    }
+
+    unsafe fn get_program_resource_name(
+        &self,
+        _program: Self::Program,
+        _interface: u32,
+        _index: u32,
+    ) -> String {
+        panic!("get_program_resource_name not supported on webgl");
+    }
+
     unsafe fn program_binary_retrievable_hint(&self, _program: Self::Program, _value: bool) {
         panic!("Program binaries are not supported");
     }
@@ -2365,7 +2725,7 @@ impl HasContext for Context {
 
     unsafe fn get_active_uniforms(&self, program: Self::Program) -> u32 {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "get_active_uniforms");
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => {
                 gl.get_program_parameter(raw_program, WebGlRenderingContext::ACTIVE_UNIFORMS)
@@ -2381,11 +2741,32 @@ impl HasContext for Context {
 
     unsafe fn get_active_uniforms_parameter(
         &self,
-        _program: Self::Program,
-        _uniforms: &[u32],
-        _pname: u32,
+        program: Self::Program,
+        uniforms: &[u32],
+        pname: u32,
     ) -> Vec<i32> {
-        panic!("GetActiveUniformsiv is not supported")
+        let programs = self.programs.borrow();
+        let raw_program = programs.get_tracked(program, "get_active_uniforms_parameter");
+        match self.raw {
+            RawRenderingContext::WebGl1(ref _gl) => {
+                panic!("GetActiveUniformsiv is not supported")
+            }
+            RawRenderingContext::WebGl2(ref gl) => {
+                let indices = js_sys::Uint32Array::from(uniforms);
+                let values = gl.get_active_uniforms(raw_program, &indices, pname);
+                js_sys::Array::from(&values)
+                    .iter()
+                    .map(|value| {
+                        // `UNIFORM_IS_ROW_MAJOR` returns booleans
+                        value
+                            .as_f64()
+                            .map(|v| v as i32)
+                            .or_else(|| value.as_bool().map(i32::from))
+                            .unwrap_or(0)
+                    })
+                    .collect()
+            }
+        }
     }
 
     unsafe fn get_active_uniform(
@@ -2394,7 +2775,7 @@ impl HasContext for Context {
         index: u32,
     ) -> Option<ActiveUniform> {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "get_active_uniform");
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => {
                 gl.get_active_uniform(raw_program, index)
@@ -2417,7 +2798,7 @@ impl HasContext for Context {
 
     unsafe fn use_program(&self, program: Option<Self::Program>) {
         let programs = self.programs.borrow();
-        let raw_program = program.map(|p| programs.get_unchecked(p));
+        let raw_program = program.map(|p| programs.get_tracked(p, "use_program"));
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.use_program(raw_program),
             RawRenderingContext::WebGl2(ref gl) => gl.use_program(raw_program),
@@ -2457,7 +2838,7 @@ impl HasContext for Context {
 
     unsafe fn bind_buffer(&self, target: u32, buffer: Option<Self::Buffer>) {
         let buffers = self.buffers.borrow();
-        let raw_buffer = buffer.map(|b| buffers.get_unchecked(b));
+        let raw_buffer = buffer.map(|b| buffers.get_tracked(b, "bind_buffer"));
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.bind_buffer(target, raw_buffer),
             RawRenderingContext::WebGl2(ref gl) => gl.bind_buffer(target, raw_buffer),
@@ -2466,7 +2847,7 @@ impl HasContext for Context {
 
     unsafe fn bind_buffer_base(&self, target: u32, index: u32, buffer: Option<Self::Buffer>) {
         let buffers = self.buffers.borrow();
-        let raw_buffer = buffer.map(|b| buffers.get_unchecked(b));
+        let raw_buffer = buffer.map(|b| buffers.get_tracked(b, "bind_buffer_base"));
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => {
                 panic!("bind_buffer_base not supported on webgl1")
@@ -2484,7 +2865,7 @@ impl HasContext for Context {
         size: i32,
     ) {
         let buffers = self.buffers.borrow();
-        let raw_buffer = buffer.map(|b| buffers.get_unchecked(b));
+        let raw_buffer = buffer.map(|b| buffers.get_tracked(b, "bind_buffer_range"));
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => {
                 panic!("bind_buffer_range not supported on webgl1");
@@ -2498,7 +2879,7 @@ impl HasContext for Context {
     unsafe fn bind_vertex_buffer(
         &self,
         _binding_index: u32,
-        _buffer: Option<Buffer>,
+        _buffer: Option<Self::Buffer>,
         _offset: i32,
         _stride: i32,
     ) {
@@ -2507,7 +2888,7 @@ impl HasContext for Context {
 
     unsafe fn bind_framebuffer(&self, target: u32, framebuffer: Option<Self::Framebuffer>) {
         let framebuffers = self.framebuffers.borrow();
-        let raw_framebuffer = framebuffer.map(|f| framebuffers.get_unchecked(f));
+        let raw_framebuffer = framebuffer.map(|f| framebuffers.get_tracked(f, "bind_framebuffer"));
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.bind_framebuffer(target, raw_framebuffer),
             RawRenderingContext::WebGl2(ref gl) => gl.bind_framebuffer(target, raw_framebuffer),
@@ -2516,7 +2897,8 @@ impl HasContext for Context {
 
     unsafe fn bind_renderbuffer(&self, target: u32, renderbuffer: Option<Self::Renderbuffer>) {
         let renderbuffers = self.renderbuffers.borrow();
-        let raw_renderbuffer = renderbuffer.map(|r| renderbuffers.get_unchecked(r));
+        let raw_renderbuffer =
+            renderbuffer.map(|r| renderbuffers.get_tracked(r, "bind_renderbuffer"));
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.bind_renderbuffer(target, raw_renderbuffer),
             RawRenderingContext::WebGl2(ref gl) => gl.bind_renderbuffer(target, raw_renderbuffer),
@@ -2601,12 +2983,20 @@ impl HasContext for Context {
                 }
                 RawRenderingContext::WebGl2(ref gl) => gl.delete_vertex_array(Some(va)),
             }
+        } else if self.validation {
+            tracking::invalid_handle(
+                "delete_vertex_array",
+                ObjectKind::VertexArray,
+                &vertex_array,
+                InvalidHandle::Unknown,
+            );
         }
     }
 
     unsafe fn bind_vertex_array(&self, vertex_array: Option<Self::VertexArray>) {
         let vertex_arrays = self.vertex_arrays.borrow();
-        let raw_vertex_array = vertex_array.map(|va| vertex_arrays.get_unchecked(va));
+        let raw_vertex_array =
+            vertex_array.map(|va| vertex_arrays.get_tracked(va, "bind_vertex_array"));
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => {
                 match &self.extensions.oes_vertex_array_object {
@@ -2667,6 +3057,9 @@ impl HasContext for Context {
             RawRenderingContext::WebGl1(ref gl) => gl.pixel_storei(parameter, value),
             RawRenderingContext::WebGl2(ref gl) => gl.pixel_storei(parameter, value),
         }
+        let mut pixel_store = self.pixel_store.get();
+        pixel_store.set(parameter, value);
+        self.pixel_store.set(pixel_store);
     }
 
     unsafe fn pixel_store_bool(&self, parameter: u32, value: bool) {
@@ -2678,7 +3071,7 @@ impl HasContext for Context {
 
     unsafe fn get_frag_data_location(&self, program: Self::Program, name: &str) -> i32 {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "get_frag_data_location");
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => {
                 panic!("Get frag data location is not supported")
@@ -2697,6 +3090,9 @@ impl HasContext for Context {
     }
 
     unsafe fn buffer_data_size(&self, target: u32, size: i32, usage: u32) {
+        if let Some(memory) = &self.memory {
+            memory.bound_buffer(self, target, size);
+        }
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.buffer_data_with_i32(target, size, usage),
             RawRenderingContext::WebGl2(ref gl) => gl.buffer_data_with_i32(target, size, usage),
@@ -2708,6 +3104,9 @@ impl HasContext for Context {
     }
 
     unsafe fn buffer_data_u8_slice(&self, target: u32, data: &[u8], usage: u32) {
+        if let Some(memory) = &self.memory {
+            memory.bound_buffer(self, target, data.len() as i32);
+        }
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => {
                 let array = js_sys::Uint8Array::view(data);
@@ -2882,7 +3281,7 @@ impl HasContext for Context {
 
     unsafe fn client_wait_sync(&self, fence: Self::Fence, flags: u32, timeout: i32) -> u32 {
         let fences = self.fences.borrow();
-        let raw_fence = fences.get_unchecked(fence);
+        let raw_fence = fences.get_tracked(fence, "client_wait_sync");
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => panic!("Client wait sync is not supported"),
             RawRenderingContext::WebGl2(ref gl) => {
@@ -2893,7 +3292,7 @@ impl HasContext for Context {
 
     unsafe fn get_sync_parameter_i32(&self, fence: Self::Fence, parameter: u32) -> i32 {
         let fences = self.fences.borrow();
-        let raw_fence = fences.get_unchecked(fence);
+        let raw_fence = fences.get_tracked(fence, "get_sync_parameter_i32");
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => panic!("get sync parameter is not supported"),
             RawRenderingContext::WebGl2(ref gl) => gl.get_sync_parameter(raw_fence, parameter),
@@ -2907,7 +3306,7 @@ impl HasContext for Context {
 
     unsafe fn wait_sync(&self, fence: Self::Fence, flags: u32, timeout: u64) {
         let fences = self.fences.borrow();
-        let raw_fence = fences.get_unchecked(fence);
+        let raw_fence = fences.get_tracked(fence, "wait_sync");
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => panic!("Wait sync is not supported"),
             RawRenderingContext::WebGl2(ref gl) => {
@@ -2924,6 +3323,11 @@ impl HasContext for Context {
         dst_offset: i32,
         size: i32,
     ) {
+        if self.workarounds.copy_buffer_sub_data_through_temporary && src_target == dst_target {
+            return workarounds::copy_buffer_sub_data_through_temporary(
+                self, src_target, src_offset, dst_offset, size,
+            );
+        }
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => panic!("Copy buffer subdata is not supported"),
             RawRenderingContext::WebGl2(ref gl) => gl
@@ -2965,6 +3369,22 @@ impl HasContext for Context {
         height: i32,
         border: i32,
     ) {
+        if let Some(memory) = &self.memory {
+            memory.bound_texture_level(
+                self,
+                target,
+                level,
+                memory::image_bytes(
+                    internal_format,
+                    internal_format,
+                    UNSIGNED_BYTE,
+                    width,
+                    height,
+                    1,
+                ),
+                (width, height, 1),
+            );
+        }
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => {
                 gl.copy_tex_image_2d(target, level, internal_format, x, y, width, height, border);
@@ -3021,12 +3441,22 @@ impl HasContext for Context {
     }
 
     unsafe fn delete_buffer(&self, buffer: Self::Buffer) {
+        if let Some(memory) = &self.memory {
+            memory.delete_buffer(buffer);
+        }
         let mut buffers = self.buffers.borrow_mut();
         if let Some(ref b) = buffers.remove(buffer) {
             match self.raw {
                 RawRenderingContext::WebGl1(ref gl) => gl.delete_buffer(Some(b)),
                 RawRenderingContext::WebGl2(ref gl) => gl.delete_buffer(Some(b)),
             }
+        } else if self.validation {
+            tracking::invalid_handle(
+                "delete_buffer",
+                ObjectKind::Buffer,
+                &buffer,
+                InvalidHandle::Unknown,
+            );
         }
     }
 
@@ -3037,6 +3467,13 @@ impl HasContext for Context {
                 RawRenderingContext::WebGl1(ref gl) => gl.delete_framebuffer(Some(f)),
                 RawRenderingContext::WebGl2(ref gl) => gl.delete_framebuffer(Some(f)),
             }
+        } else if self.validation {
+            tracking::invalid_handle(
+                "delete_framebuffer",
+                ObjectKind::Framebuffer,
+                &framebuffer,
+                InvalidHandle::Unknown,
+            );
         }
     }
 
@@ -3047,16 +3484,33 @@ impl HasContext for Context {
                 RawRenderingContext::WebGl1(ref _gl) => panic!("Query objects are not supported"),
                 RawRenderingContext::WebGl2(ref gl) => gl.delete_query(Some(r)),
             }
+        } else if self.validation {
+            tracking::invalid_handle(
+                "delete_query",
+                ObjectKind::Query,
+                &query,
+                InvalidHandle::Unknown,
+            );
         }
     }
 
     unsafe fn delete_renderbuffer(&self, renderbuffer: Self::Renderbuffer) {
+        if let Some(memory) = &self.memory {
+            memory.delete_renderbuffer(renderbuffer);
+        }
         let mut renderbuffers = self.renderbuffers.borrow_mut();
         if let Some(ref r) = renderbuffers.remove(renderbuffer) {
             match self.raw {
                 RawRenderingContext::WebGl1(ref gl) => gl.delete_renderbuffer(Some(r)),
                 RawRenderingContext::WebGl2(ref gl) => gl.delete_renderbuffer(Some(r)),
             }
+        } else if self.validation {
+            tracking::invalid_handle(
+                "delete_renderbuffer",
+                ObjectKind::Renderbuffer,
+                &renderbuffer,
+                InvalidHandle::Unknown,
+            );
         }
     }
 
@@ -3067,6 +3521,13 @@ impl HasContext for Context {
                 RawRenderingContext::WebGl1(ref _gl) => panic!("Samplers are not supported"),
                 RawRenderingContext::WebGl2(ref gl) => gl.delete_sampler(Some(s)),
             }
+        } else if self.validation {
+            tracking::invalid_handle(
+                "delete_sampler",
+                ObjectKind::Sampler,
+                &sampler,
+                InvalidHandle::Unknown,
+            );
         }
     }
 
@@ -3077,16 +3538,33 @@ impl HasContext for Context {
                 RawRenderingContext::WebGl1(ref _gl) => panic!("Fences are not supported"),
                 RawRenderingContext::WebGl2(ref gl) => gl.delete_sync(Some(f)),
             }
+        } else if self.validation {
+            tracking::invalid_handle(
+                "delete_sync",
+                ObjectKind::Fence,
+                &fence,
+                InvalidHandle::Unknown,
+            );
         }
     }
 
     unsafe fn delete_texture(&self, texture: Self::Texture) {
+        if let Some(memory) = &self.memory {
+            memory.delete_texture(texture);
+        }
         let mut textures = self.textures.borrow_mut();
         if let Some(ref t) = textures.remove(texture) {
             match self.raw {
                 RawRenderingContext::WebGl1(ref gl) => gl.delete_texture(Some(t)),
                 RawRenderingContext::WebGl2(ref gl) => gl.delete_texture(Some(t)),
             }
+        } else if self.validation {
+            tracking::invalid_handle(
+                "delete_texture",
+                ObjectKind::Texture,
+                &texture,
+                InvalidHandle::Unknown,
+            );
         }
     }
 
@@ -3332,7 +3810,8 @@ impl HasContext for Context {
         renderbuffer: Option<Self::Renderbuffer>,
     ) {
         let renderbuffers = self.renderbuffers.borrow();
-        let raw_renderbuffer = renderbuffer.map(|r| renderbuffers.get_unchecked(r));
+        let raw_renderbuffer =
+            renderbuffer.map(|r| renderbuffers.get_tracked(r, "framebuffer_renderbuffer"));
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.framebuffer_renderbuffer(
                 target,
@@ -3368,7 +3847,7 @@ impl HasContext for Context {
         level: i32,
     ) {
         let textures = self.textures.borrow();
-        let raw_texture = texture.map(|t| textures.get_unchecked(t));
+        let raw_texture = texture.map(|t| textures.get_tracked(t, "framebuffer_texture_2d"));
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => {
                 gl.framebuffer_texture_2d(target, attachment, texture_target, raw_texture, level);
@@ -3412,7 +3891,7 @@ impl HasContext for Context {
         layer: i32,
     ) {
         let textures = self.textures.borrow();
-        let raw_texture = texture.map(|t| textures.get_unchecked(t));
+        let raw_texture = texture.map(|t| textures.get_tracked(t, "framebuffer_texture_layer"));
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => {
                 panic!("Framebuffer texture layer is not supported");
@@ -3803,7 +4282,7 @@ impl HasContext for Context {
         name: &str,
     ) -> Option<Self::UniformLocation> {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "get_uniform_location");
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.get_uniform_location(raw_program, name),
             RawRenderingContext::WebGl2(ref gl) => gl.get_uniform_location(raw_program, name),
@@ -3812,7 +4291,7 @@ impl HasContext for Context {
 
     unsafe fn get_attrib_location(&self, program: Self::Program, name: &str) -> Option<u32> {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "get_attrib_location");
         let attrib_location = match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.get_attrib_location(raw_program, name),
             RawRenderingContext::WebGl2(ref gl) => gl.get_attrib_location(raw_program, name),
@@ -3826,7 +4305,7 @@ impl HasContext for Context {
 
     unsafe fn bind_attrib_location(&self, program: Self::Program, index: u32, name: &str) {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "bind_attrib_location");
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => {
                 gl.bind_attrib_location(raw_program, index, name)
@@ -3839,7 +4318,7 @@ impl HasContext for Context {
 
     unsafe fn get_active_attributes(&self, program: Self::Program) -> u32 {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "get_active_attributes");
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => {
                 gl.get_program_parameter(raw_program, WebGlRenderingContext::ACTIVE_ATTRIBUTES)
@@ -3859,7 +4338,7 @@ impl HasContext for Context {
         index: u32,
     ) -> Option<ActiveAttribute> {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "get_active_attribute");
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => {
                 gl.get_active_attrib(raw_program, index)
@@ -3882,7 +4361,7 @@ impl HasContext for Context {
 
     unsafe fn get_sync_status(&self, fence: Self::Fence) -> u32 {
         let fences = self.fences.borrow();
-        let raw_fence = fences.get_unchecked(fence);
+        let raw_fence = fences.get_tracked(fence, "get_sync_status");
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => panic!("Sync is not supported"),
             RawRenderingContext::WebGl2(ref gl) => gl
@@ -3895,10 +4374,13 @@ impl HasContext for Context {
 
     unsafe fn is_sync(&self, fence: Self::Fence) -> bool {
         let fences = self.fences.borrow();
-        let raw_fence = fences.get_unchecked(fence);
-        match self.raw {
-            RawRenderingContext::WebGl1(ref _gl) => panic!("Sync is not supported"),
-            RawRenderingContext::WebGl2(ref gl) => gl.is_sync(Some(raw_fence)),
+        if let Some(f) = fences.get(fence) {
+            match self.raw {
+                RawRenderingContext::WebGl1(ref _gl) => panic!("Sync is not supported"),
+                RawRenderingContext::WebGl2(ref gl) => gl.is_sync(Some(f)),
+            }
+        } else {
+            false
         }
     }
 
@@ -3909,6 +4391,9 @@ impl HasContext for Context {
         width: i32,
         height: i32,
     ) {
+        if let Some(memory) = &self.memory {
+            memory.bound_renderbuffer(self, 1, internal_format, width, height);
+        }
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => {
                 gl.renderbuffer_storage(target, internal_format, width, height);
@@ -3927,6 +4412,9 @@ impl HasContext for Context {
         width: i32,
         height: i32,
     ) {
+        if let Some(memory) = &self.memory {
+            memory.bound_renderbuffer(self, samples, internal_format, width, height);
+        }
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => {
                 panic!("Renderbuffer storage multisample is not supported");
@@ -3945,7 +4433,7 @@ impl HasContext for Context {
 
     unsafe fn sampler_parameter_f32(&self, sampler: Self::Sampler, name: u32, value: f32) {
         let samplers = self.samplers.borrow();
-        let raw_sampler = samplers.get_unchecked(sampler);
+        let raw_sampler = samplers.get_tracked(sampler, "sampler_parameter_f32");
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => {
                 panic!("Samper parameter for `f32` is not supported")
@@ -3967,7 +4455,7 @@ impl HasContext for Context {
 
     unsafe fn sampler_parameter_i32(&self, sampler: Self::Sampler, name: u32, value: i32) {
         let samplers = self.samplers.borrow();
-        let raw_sampler = samplers.get_unchecked(sampler);
+        let raw_sampler = samplers.get_tracked(sampler, "sampler_parameter_i32");
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => {
                 panic!("Samper parameter for `i32` is not supported")
@@ -3980,7 +4468,7 @@ impl HasContext for Context {
 
     unsafe fn get_sampler_parameter_i32(&self, sampler: Self::Sampler, name: u32) -> i32 {
         let samplers = self.samplers.borrow();
-        let raw_sampler = samplers.get_unchecked(sampler);
+        let raw_sampler = samplers.get_tracked(sampler, "get_sampler_parameter_i32");
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => {
                 panic!("Samper parameter for `i32` is not supported")
@@ -3996,7 +4484,7 @@ impl HasContext for Context {
 
     unsafe fn get_sampler_parameter_f32(&self, sampler: Self::Sampler, name: u32) -> f32 {
         let samplers = self.samplers.borrow();
-        let raw_sampler = samplers.get_unchecked(sampler);
+        let raw_sampler = samplers.get_tracked(sampler, "get_sampler_parameter_f32");
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => {
                 panic!("Samper parameter for `i32` is not supported")
@@ -4021,11 +4509,27 @@ impl HasContext for Context {
     }
 
     unsafe fn generate_mipmap(&self, target: u32) {
+        if let Some(memory) = &self.memory {
+            memory.bound_generate_mipmap(self, target);
+        }
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => {
                 gl.generate_mipmap(target);
             }
             RawRenderingContext::WebGl2(ref gl) => {
+                if self.workarounds.reset_base_level_before_generate_mipmap {
+                    let base_level = gl
+                        .get_tex_parameter(target, TEXTURE_BASE_LEVEL)
+                        .as_f64()
+                        .map(|level| level as i32)
+                        .unwrap_or(0);
+                    if base_level != 0 {
+                        gl.tex_parameteri(target, TEXTURE_BASE_LEVEL, 0);
+                        gl.generate_mipmap(target);
+                        gl.tex_parameteri(target, TEXTURE_BASE_LEVEL, base_level);
+                        return;
+                    }
+                }
                 gl.generate_mipmap(target);
             }
         }
@@ -4074,6 +4578,26 @@ impl HasContext for Context {
         ty: u32,
         pixels: PixelUnpackData,
     ) {
+        format::validate_unpack(
+            self,
+            self.validation,
+            "tex_image_2d",
+            width,
+            height,
+            1,
+            format,
+            ty,
+            &pixels,
+        );
+        if let Some(memory) = &self.memory {
+            memory.bound_texture_level(
+                self,
+                target,
+                level,
+                memory::image_bytes(internal_format as u32, format, ty, width, height, 1),
+                (width, height, 1),
+            );
+        }
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => {
                 match pixels {
@@ -4149,9 +4673,28 @@ impl HasContext for Context {
         width: i32,
         height: i32,
         border: i32,
-        _image_size: i32,
+        image_size: i32,
         pixels: &[u8],
     ) {
+        format::validate_compressed(
+            self.validation,
+            "compressed_tex_image_2d",
+            internal_format as u32,
+            width,
+            height,
+            1,
+            image_size,
+            pixels,
+        );
+        if let Some(memory) = &self.memory {
+            memory.bound_texture_level(
+                self,
+                target,
+                level,
+                image_size.max(0) as u64,
+                (width, height, 1),
+            );
+        }
         let src_data = js_sys::Uint8Array::view(pixels);
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl
@@ -4190,6 +4733,26 @@ impl HasContext for Context {
         ty: u32,
         pixels: PixelUnpackData,
     ) {
+        format::validate_unpack(
+            self,
+            self.validation,
+            "tex_image_3d",
+            width,
+            height,
+            depth,
+            format,
+            ty,
+            &pixels,
+        );
+        if let Some(memory) = &self.memory {
+            memory.bound_texture_level(
+                self,
+                target,
+                level,
+                memory::image_bytes(internal_format as u32, format, ty, width, height, depth),
+                (width, height, depth),
+            );
+        }
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => panic!("3d textures are not supported"),
             RawRenderingContext::WebGl2(ref gl) => {
@@ -4236,9 +4799,28 @@ impl HasContext for Context {
         height: i32,
         depth: i32,
         border: i32,
-        _image_size: i32,
+        image_size: i32,
         pixels: &[u8],
     ) {
+        format::validate_compressed(
+            self.validation,
+            "compressed_tex_image_3d",
+            internal_format as u32,
+            width,
+            height,
+            depth,
+            image_size,
+            pixels,
+        );
+        if let Some(memory) = &self.memory {
+            memory.bound_texture_level(
+                self,
+                target,
+                level,
+                pixels.len() as u64,
+                (width, height, depth),
+            );
+        }
         let src_data = js_sys::Uint8Array::view(pixels);
         match self.raw {
             RawRenderingContext::WebGl1(_) => {
@@ -4276,6 +4858,16 @@ impl HasContext for Context {
         width: i32,
         height: i32,
     ) {
+        if let Some(memory) = &self.memory {
+            memory.bound_texture_storage(
+                self,
+                target,
+                levels,
+                1,
+                internal_format,
+                (width, height, 1),
+            );
+        }
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => panic!("Tex storage 2D is not supported"),
             RawRenderingContext::WebGl2(ref gl) => {
@@ -4316,6 +4908,16 @@ impl HasContext for Context {
         height: i32,
         depth: i32,
     ) {
+        if let Some(memory) = &self.memory {
+            memory.bound_texture_storage(
+                self,
+                target,
+                levels,
+                1,
+                internal_format,
+                (width, height, depth),
+            );
+        }
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => panic!("Tex storage 3D is not supported"),
             RawRenderingContext::WebGl2(ref gl) => {
@@ -4855,6 +5457,9 @@ impl HasContext for Context {
     }
 
     unsafe fn invalidate_framebuffer(&self, target: u32, attachments: &[u32]) {
+        if self.workarounds.skip_invalidate_framebuffer {
+            return;
+        }
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => {
                 panic!("Invalidate framebuffer is not supported");
@@ -4878,6 +5483,9 @@ impl HasContext for Context {
         width: i32,
         height: i32,
     ) {
+        if self.workarounds.skip_invalidate_framebuffer {
+            return;
+        }
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => {
                 panic!("Invalidate sub framebuffer is not supported");
@@ -4915,7 +5523,7 @@ impl HasContext for Context {
 
     unsafe fn bind_texture(&self, target: u32, texture: Option<Self::Texture>) {
         let textures = self.textures.borrow();
-        let raw_texture = texture.map(|t| textures.get_unchecked(t));
+        let raw_texture = texture.map(|t| textures.get_tracked(t, "bind_texture"));
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.bind_texture(target, raw_texture),
             RawRenderingContext::WebGl2(ref gl) => gl.bind_texture(target, raw_texture),
@@ -4928,7 +5536,7 @@ impl HasContext for Context {
 
     unsafe fn bind_sampler(&self, unit: u32, sampler: Option<Self::Sampler>) {
         let samplers = self.samplers.borrow();
-        let raw_sampler = sampler.map(|s| samplers.get_unchecked(s));
+        let raw_sampler = sampler.map(|s| samplers.get_tracked(s, "bind_sampler"));
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => panic!("Bind sampler is not supported"),
             RawRenderingContext::WebGl2(ref gl) => gl.bind_sampler(unit, raw_sampler),
@@ -4995,6 +5603,17 @@ impl HasContext for Context {
         ty: u32,
         pixels: PixelUnpackData,
     ) {
+        format::validate_unpack(
+            self,
+            self.validation,
+            "tex_sub_image_2d",
+            width,
+            height,
+            1,
+            format,
+            ty,
+            &pixels,
+        );
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => {
                 match pixels {
@@ -5079,6 +5698,18 @@ impl HasContext for Context {
         format: u32,
         pixels: CompressedPixelUnpackData,
     ) {
+        if let CompressedPixelUnpackData::Slice(data) = &pixels {
+            format::validate_compressed(
+                self.validation,
+                "compressed_tex_sub_image_2d",
+                format,
+                width,
+                height,
+                1,
+                data.len() as i32,
+                data,
+            );
+        }
         match self.raw {
             RawRenderingContext::WebGl1(ref gl) => match pixels {
                 CompressedPixelUnpackData::BufferRange(_) => {
@@ -5128,6 +5759,17 @@ impl HasContext for Context {
         ty: u32,
         pixels: PixelUnpackData,
     ) {
+        format::validate_unpack(
+            self,
+            self.validation,
+            "tex_sub_image_3d",
+            width,
+            height,
+            depth,
+            format,
+            ty,
+            &pixels,
+        );
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => {
                 panic!("Sub image 3D is not supported");
@@ -5182,6 +5824,18 @@ impl HasContext for Context {
         format: u32,
         pixels: CompressedPixelUnpackData,
     ) {
+        if let CompressedPixelUnpackData::Slice(data) = &pixels {
+            format::validate_compressed(
+                self.validation,
+                "compressed_tex_sub_image_3d",
+                format,
+                width,
+                height,
+                depth,
+                data.len() as i32,
+                data,
+            );
+        }
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => {
                 panic!("Compressed sub image 3D is not supported");
@@ -5712,7 +6366,7 @@ impl HasContext for Context {
 
     unsafe fn get_uniform_block_index(&self, program: Self::Program, name: &str) -> Option<u32> {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "get_uniform_block_index");
         let index = match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => panic!("Uniform blocks are not supported"),
             RawRenderingContext::WebGl2(ref gl) => gl.get_uniform_block_index(raw_program, name),
@@ -5730,7 +6384,7 @@ impl HasContext for Context {
         names: &[&str],
     ) -> Vec<Option<u32>> {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "get_uniform_indices");
         let indices = match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => panic!("Uniform blocks are not supported"),
             RawRenderingContext::WebGl2(ref gl) => {
@@ -5762,7 +6416,7 @@ impl HasContext for Context {
             }
             RawRenderingContext::WebGl2(ref gl) => {
                 let programs = self.programs.borrow();
-                let raw_program = programs.get_unchecked(program);
+                let raw_program = programs.get_tracked(program, "uniform_block_binding");
                 gl.uniform_block_binding(raw_program, index, binding);
             }
         }
@@ -5810,6 +6464,16 @@ impl HasContext for Context {
         gltype: u32,
         pixels: PixelPackData,
     ) {
+        format::validate_pack(
+            self,
+            self.validation,
+            "read_pixels",
+            width,
+            height,
+            format,
+            gltype,
+            &pixels,
+        );
         match pixels {
             PixelPackData::BufferOffset(offset) => match self.raw {
                 RawRenderingContext::WebGl1(ref _gl) => {
@@ -5868,7 +6532,7 @@ impl HasContext for Context {
         v: &mut [i32],
     ) {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "get_uniform_i32");
         let value = match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.get_uniform(&raw_program, location),
             RawRenderingContext::WebGl2(ref gl) => gl.get_uniform(&raw_program, location),
@@ -5888,7 +6552,7 @@ impl HasContext for Context {
         v: &mut [u32],
     ) {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "get_uniform_u32");
         let value = match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.get_uniform(&raw_program, location),
             RawRenderingContext::WebGl2(ref gl) => gl.get_uniform(&raw_program, location),
@@ -5908,7 +6572,7 @@ impl HasContext for Context {
         v: &mut [f32],
     ) {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "get_uniform_f32");
         let value = match self.raw {
             RawRenderingContext::WebGl1(ref gl) => gl.get_uniform(&raw_program, location),
             RawRenderingContext::WebGl2(ref gl) => gl.get_uniform(&raw_program, location),
@@ -5923,7 +6587,7 @@ impl HasContext for Context {
 
     unsafe fn begin_query(&self, target: u32, query: Self::Query) {
         let queries = self.queries.borrow();
-        let raw_query = queries.get_unchecked(query);
+        let raw_query = queries.get_tracked(query, "begin_query");
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => panic!("Query objects are not supported"),
             RawRenderingContext::WebGl2(ref gl) => gl.begin_query(target, raw_query),
@@ -5939,7 +6603,7 @@ impl HasContext for Context {
 
     unsafe fn query_counter(&self, query: Self::Query, target: u32) {
         let queries = self.queries.borrow();
-        let raw_query = queries.get_unchecked(query);
+        let raw_query = queries.get_tracked(query, "query_counter");
         match self.extensions.ext_disjoint_timer_query_webgl2 {
             Some(ref ext) => ext.query_counter_ext(raw_query, target),
             None => {
@@ -5950,7 +6614,7 @@ impl HasContext for Context {
 
     unsafe fn get_query_parameter_u32(&self, query: Self::Query, parameter: u32) -> u32 {
         let queries = self.queries.borrow();
-        let raw_query = queries.get_unchecked(query);
+        let raw_query = queries.get_tracked(query, "get_query_parameter_u32");
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => panic!("Query objects are not supported"),
             RawRenderingContext::WebGl2(ref gl) => {
@@ -5965,7 +6629,7 @@ impl HasContext for Context {
 
     unsafe fn get_query_parameter_u64(&self, query: Self::Query, parameter: u32) -> u64 {
         let queries = self.queries.borrow();
-        let raw_query = queries.get_unchecked(query);
+        let raw_query = queries.get_tracked(query, "get_query_parameter_u64");
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => panic!("Query objects are not supported"),
             RawRenderingContext::WebGl2(ref gl) => gl
@@ -6023,6 +6687,13 @@ impl HasContext for Context {
                 }
                 RawRenderingContext::WebGl2(ref gl) => gl.delete_transform_feedback(Some(t)),
             }
+        } else if self.validation {
+            tracking::invalid_handle(
+                "delete_transform_feedback",
+                ObjectKind::TransformFeedback,
+                &transform_feedback,
+                InvalidHandle::Unknown,
+            );
         }
     }
 
@@ -6032,8 +6703,8 @@ impl HasContext for Context {
         transform_feedback: Option<Self::TransformFeedback>,
     ) {
         let transform_feedbacks = self.transform_feedbacks.borrow();
-        let raw_transform_feedback =
-            transform_feedback.map(|tf| transform_feedbacks.get_unchecked(tf));
+        let raw_transform_feedback = transform_feedback
+            .map(|tf| transform_feedbacks.get_tracked(tf, "bind_transform_feedback"));
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => {
                 panic!("TransformFeedback objects are not supported")
@@ -6087,7 +6758,7 @@ impl HasContext for Context {
         buffer_mode: u32,
     ) {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "transform_feedback_varyings");
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => {
                 panic!("TransformFeedback objects are not supported")
@@ -6108,7 +6779,7 @@ impl HasContext for Context {
         index: u32,
     ) -> Option<ActiveTransformFeedback> {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "get_transform_feedback_varying");
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => {
                 panic!("TransformFeedback objects are not supported")
@@ -6151,7 +6822,7 @@ impl HasContext for Context {
         parameter: u32,
     ) -> i32 {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "get_active_uniform_block_parameter_i32");
 
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => panic!("Uniform blocks are not supported"),
@@ -6174,7 +6845,8 @@ impl HasContext for Context {
         out: &mut [i32],
     ) {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program =
+            programs.get_tracked(program, "get_active_uniform_block_parameter_i32_slice");
 
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => panic!("Uniform blocks are not supported"),
@@ -6205,7 +6877,7 @@ impl HasContext for Context {
         uniform_block_index: u32,
     ) -> String {
         let programs = self.programs.borrow();
-        let raw_program = programs.get_unchecked(program);
+        let raw_program = programs.get_tracked(program, "get_active_uniform_block_name");
 
         match self.raw {
             RawRenderingContext::WebGl1(ref _gl) => panic!("Uniform blocks are not supported"),
@@ -6313,3 +6985,62 @@ unsafe fn texture_data_view(ty: u32, bytes: &[u8]) -> js_sys::Object {
         UNSIGNED_BYTE | _ => js_sys::Uint8Array::view(bytes).into(),
     }
 }
+
+#[cfg(all(test, target_arch = "wasm32", not(target_os = "emscripten")))]
+pub(crate) mod tests {
+    use super::*;
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    pub(crate) fn webgl2_context() -> Context {
+        let canvas: HtmlCanvasElement = web_sys::window()
+            .unwrap()
+            .document()
+            .unwrap()
+            .create_element("canvas")
+            .unwrap()
+            .dyn_into()
+            .unwrap();
+        let context: WebGl2RenderingContext = canvas
+            .get_context("webgl2")
+            .unwrap()
+            .unwrap()
+            .dyn_into()
+            .unwrap();
+        Context::from_webgl2_context(context)
+    }
+
+    #[wasm_bindgen_test]
+    fn test_unbound_parameters() {
+        let gl = webgl2_context();
+        unsafe {
+            assert_eq!(gl.get_parameter_buffer(PIXEL_PACK_BUFFER_BINDING), None);
+            assert_eq!(gl.get_parameter_framebuffer(FRAMEBUFFER_BINDING), None);
+
+            let mut readback = AsyncReadback::new();
+            let pending = readback
+                .read_pixels(&gl, 0, 0, 1, 1, RGBA, UNSIGNED_BYTE)
+                .unwrap();
+            assert_eq!(readback.wait(&gl, pending).len(), 4);
+            assert_eq!(gl.get_parameter_buffer(PIXEL_PACK_BUFFER_BINDING), None);
+            readback.destroy(&gl);
+        }
+    }
+
+    #[wasm_bindgen_test]
+    fn test_apply_vertex_layout() {
+        let gl = webgl2_context();
+        unsafe {
+            let vao = gl.create_vertex_array().unwrap();
+            let buffer = gl.create_buffer().unwrap();
+            let mut layout = VertexLayout::new();
+            let vertices = layout.buffer(buffer, 0, 12, VertexStep::Vertex);
+            layout.attribute(0, vertices, 3, FLOAT, AttributeConversion::Float, 0);
+            gl.apply_vertex_layout(vao, &layout).unwrap();
+            assert_eq!(gl.get_parameter_vertex_array(VERTEX_ARRAY_BINDING), None);
+            assert_eq!(gl.get_parameter_buffer(ARRAY_BUFFER_BINDING), None);
+        }
+    }
+}
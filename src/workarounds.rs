@@ -0,0 +1,140 @@
+use crate::*;
+
+/// Driver bugs that glow works around, detected from the [`DriverInfo`] of a context.
+///
+/// The workarounds are applied transparently by the affected [`HasContext`] methods. They can be
+/// replaced with `Context::set_workarounds`, e.g. to test the workarounds on a driver that
+/// doesn't need them.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Workarounds {
+    /// Adreno 3xx–5xx drivers write the generated levels relative to level 0 instead of
+    /// `TEXTURE_BASE_LEVEL`, so `generate_mipmap` temporarily resets the base level to 0. Only
+    /// enabled for OpenGL ES 3.0 and later, which is where `TEXTURE_BASE_LEVEL` was added.
+    pub reset_base_level_before_generate_mipmap: bool,
+    /// Adreno 3xx and 4xx drivers can crash in `glInvalidateFramebuffer`, so
+    /// `invalidate_framebuffer` and `invalidate_sub_framebuffer` do nothing. Invalidation is only
+    /// a hint, so skipping it doesn't change the rendered result.
+    pub skip_invalidate_framebuffer: bool,
+    /// ANGLE's Direct3D 11 backend can return stale data when copying between two ranges of the
+    /// same buffer, so `copy_buffer_sub_data` with the same source and destination target copies
+    /// through a temporary buffer instead. Only enabled for OpenGL ES 3.0 and later, which is
+    /// where `copy_buffer_sub_data` was added.
+    pub copy_buffer_sub_data_through_temporary: bool,
+}
+
+impl Workarounds {
+    /// Detects the workarounds needed by a driver.
+    pub fn new(info: &DriverInfo) -> Self {
+        let adreno = adreno_generation(&info.renderer);
+        let angle_d3d11 = info.is_angle()
+            && (info.renderer.contains("Direct3D11") || info.renderer.contains("D3D11"));
+        let es3 = info.version.is_embedded && info.version.major >= 3;
+
+        Workarounds {
+            reset_base_level_before_generate_mipmap: es3 && matches!(adreno, Some(3..=5)),
+            skip_invalidate_framebuffer: matches!(adreno, Some(3..=4)),
+            copy_buffer_sub_data_through_temporary: es3 && angle_d3d11,
+        }
+    }
+}
+
+/// Returns the generation of an Adreno GPU, e.g. 5 for `Adreno (TM) 540`.
+fn adreno_generation(renderer: &str) -> Option<u32> {
+    let model = renderer.split("Adreno").nth(1)?;
+    let model = model.trim_start_matches(|c: char| !c.is_ascii_digit());
+    let digits = model.bytes().take_while(u8::is_ascii_digit).count();
+    if digits == 3 {
+        model[..1].parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Copies `size` bytes within the buffer bound to `target` through a temporary buffer.
+///
+/// The temporary buffer is bound to whichever of `COPY_READ_BUFFER` and `COPY_WRITE_BUFFER` isn't
+/// `target`, and the previous binding is restored afterwards.
+pub(crate) unsafe fn copy_buffer_sub_data_through_temporary<C: HasContext>(
+    gl: &C,
+    target: u32,
+    src_offset: i32,
+    dst_offset: i32,
+    size: i32,
+) {
+    let temporary_target = if target == COPY_READ_BUFFER {
+        COPY_WRITE_BUFFER
+    } else {
+        COPY_READ_BUFFER
+    };
+    let temporary = match gl.create_buffer() {
+        Ok(buffer) => buffer,
+        Err(_) => return,
+    };
+    let previous = gl.get_parameter_buffer(temporary_target);
+    gl.bind_buffer(temporary_target, Some(temporary));
+    gl.buffer_data_size(temporary_target, size, STREAM_COPY);
+    gl.copy_buffer_sub_data(target, temporary_target, src_offset, 0, size);
+    gl.copy_buffer_sub_data(temporary_target, target, 0, dst_offset, size);
+    gl.bind_buffer(temporary_target, previous);
+    gl.delete_buffer(temporary);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn driver_info(vendor: &str, renderer: &str, version: &str) -> DriverInfo {
+        DriverInfo {
+            vendor: vendor.to_string(),
+            renderer: renderer.to_string(),
+            version: version.parse().unwrap(),
+            glsl: None,
+            is_webgl: false,
+        }
+    }
+
+    #[test]
+    fn test_adreno_generation() {
+        assert_eq!(adreno_generation("Adreno (TM) 540"), Some(5));
+        assert_eq!(adreno_generation("Adreno (TM) 306"), Some(3));
+        assert_eq!(adreno_generation("Adreno (TM) 7xx"), None);
+        assert_eq!(adreno_generation("Mali-G78"), None);
+    }
+
+    #[test]
+    fn test_workarounds() {
+        let info = driver_info(
+            "Qualcomm",
+            "Adreno (TM) 430",
+            "OpenGL ES 3.1 V@145.0 (GIT@I9b8a5aa9b6)",
+        );
+        let workarounds = Workarounds::new(&info);
+        assert!(workarounds.reset_base_level_before_generate_mipmap);
+        assert!(workarounds.skip_invalidate_framebuffer);
+        assert!(!workarounds.copy_buffer_sub_data_through_temporary);
+
+        let info = driver_info(
+            "Google Inc. (NVIDIA)",
+            "ANGLE (NVIDIA, NVIDIA GeForce RTX 3080 Direct3D11 vs_5_0 ps_5_0, D3D11)",
+            "OpenGL ES 3.0.0 (ANGLE 2.1.0)",
+        );
+        let workarounds = Workarounds::new(&info);
+        assert!(workarounds.copy_buffer_sub_data_through_temporary);
+        assert!(!workarounds.skip_invalidate_framebuffer);
+
+        let info = driver_info("Qualcomm", "Adreno (TM) 430", "OpenGL ES 2.0 V@145.0");
+        let workarounds = Workarounds::new(&info);
+        assert!(!workarounds.reset_base_level_before_generate_mipmap);
+        assert!(workarounds.skip_invalidate_framebuffer);
+
+        let info = driver_info(
+            "Google Inc. (NVIDIA)",
+            "ANGLE (NVIDIA, NVIDIA GeForce RTX 3080 Direct3D11 vs_5_0 ps_5_0, D3D11)",
+            "OpenGL ES 2.0.0 (ANGLE 2.1.0)",
+        );
+        assert_eq!(Workarounds::new(&info), Workarounds::default());
+
+        let info = driver_info("NVIDIA Corporation", "NVIDIA GeForce RTX 3080", "4.6.0");
+        assert_eq!(Workarounds::new(&info), Workarounds::default());
+    }
+}